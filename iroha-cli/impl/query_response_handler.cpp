@@ -46,6 +46,10 @@ namespace iroha_cli {
     error_handler_map_[ErrorResponse::NOT_SUPPORTED] = "Query not supported";
     error_handler_map_[ErrorResponse::NO_ROLES] = "No roles in the system";
     error_handler_map_[ErrorResponse::NO_ASSET] = "No asset found";
+    error_handler_map_[ErrorResponse::TOO_EXPENSIVE] =
+        "Query is too expensive, add filters or paginate";
+    error_handler_map_[ErrorResponse::TIMEOUT] =
+        "Query has timed out, add filters or paginate";
   }
 
   void QueryResponseHandler::handle(
@@ -0,0 +1,239 @@
+/**
+ * Copyright Soramitsu Co., Ltd. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+#include "inspect.hpp"
+
+#include <fmt/format.h>
+#include <charconv>
+#include <google/protobuf/util/json_util.h>
+#include "backend/protobuf/block.hpp"
+#include "backend/protobuf/transaction.hpp"
+#include "interfaces/commands/command.hpp"
+
+namespace {
+  using Settings = google::protobuf::Map<std::string, std::string>;
+
+  std::string messageToJson(const google::protobuf::Message &message) {
+    std::string result;
+    google::protobuf::util::MessageToJsonString(message, &result);
+    return result;
+  }
+
+  template <typename Signatures>
+  void describeSignatures(std::string &out,
+                          const Signatures &signatures,
+                          const char *indent) {
+    out += fmt::format("{}signatures:\n", indent);
+    for (auto const &signature : signatures) {
+      out += fmt::format("{}  {} {}\n",
+                         indent,
+                         signature.public_key(),
+                         signature.signature());
+    }
+  }
+
+  /// Describe transaction contents, the lines are prefixed with indent
+  void describeTransaction(std::string &out,
+                           const iroha::protocol::Transaction &transaction,
+                           const char *indent) {
+    shared_model::proto::Transaction tx(transaction);
+    out += fmt::format("{}Transaction {}\n", indent, tx.hash().hex());
+    out += fmt::format("{}  creator: {}\n", indent, tx.creatorAccountId());
+    out += fmt::format("{}  created time: {}\n", indent, tx.createdTime());
+    out += fmt::format("{}  quorum: {}\n", indent, tx.quorum());
+    out += fmt::format("{}  commands:\n", indent);
+    size_t index = 0;
+    for (auto const &command : tx.commands()) {
+      out += fmt::format("{}    #{} {}\n", indent, index++, command.toString());
+    }
+    describeSignatures(
+        out, transaction.signatures(), fmt::format("{}  ", indent).c_str());
+  }
+
+  /// Add a description of the exceeded limit if the value is over it
+  void checkLimit(std::vector<std::string> &exceeded,
+                  const Settings &settings,
+                  const std::string &key,
+                  size_t value) {
+    auto it = settings.find(key);
+    if (it == settings.end()) {
+      return;
+    }
+    size_t limit = 0;
+    auto const &setting = it->second;
+    auto [end, error] =
+        std::from_chars(setting.data(), setting.data() + setting.size(), limit);
+    if (error != std::errc{} or end != setting.data() + setting.size()) {
+      return;
+    }
+    if (value > limit) {
+      exceeded.push_back(fmt::format("{} is {}, got {}", key, limit, value));
+    }
+  }
+}  // namespace
+
+namespace iroha_cli {
+  namespace inspect {
+
+    std::string describe(const TransactionDetails &details) {
+      std::string out;
+      describeTransaction(out, details.transaction, "");
+      if (details.status) {
+        auto const &status = *details.status;
+        out += fmt::format("  status: {}\n",
+                           iroha::protocol::TxStatus_Name(status.tx_status()));
+        if (not status.err_or_cmd_name().empty()) {
+          out += fmt::format("  rejected by command #{} {}, error code {}\n",
+                             status.failed_cmd_index(),
+                             status.err_or_cmd_name(),
+                             status.error_code());
+        }
+      }
+      if (not details.receipts.empty()) {
+        out += fmt::format("  engine receipts:\n");
+        for (auto const &receipt : details.receipts) {
+          out += fmt::format("    command #{} called by {}\n",
+                             receipt.command_index(),
+                             receipt.caller());
+          if (receipt.has_call_result()) {
+            out += fmt::format("      callee: {}\n      result: {}\n",
+                               receipt.call_result().callee(),
+                               receipt.call_result().result_data());
+          } else {
+            out += fmt::format("      deployed contract: {}\n",
+                               receipt.contract_address());
+          }
+          for (auto const &log : receipt.logs()) {
+            out += fmt::format("      log of {}: data {}, topics [{}]\n",
+                               log.address(),
+                               log.data(),
+                               fmt::join(log.topics(), ", "));
+          }
+        }
+      }
+      return out;
+    }
+
+    std::string toJson(const TransactionDetails &details) {
+      std::vector<std::string> receipts;
+      for (auto const &receipt : details.receipts) {
+        receipts.push_back(messageToJson(receipt));
+      }
+      return fmt::format(
+          R"({{"hash":"{}","transaction":{},"status":{},"receipts":[{}]}})",
+          shared_model::proto::Transaction(details.transaction).hash().hex(),
+          messageToJson(details.transaction),
+          details.status ? messageToJson(*details.status) : "null",
+          fmt::join(receipts, ","));
+    }
+
+    std::string describe(const iroha::protocol::Block_v1 &block) {
+      auto const &payload = block.payload();
+      std::string out;
+      out += fmt::format("Block {} at height {}\n",
+                         shared_model::proto::Block(block).hash().hex(),
+                         payload.height());
+      out += fmt::format("  previous block: {}\n", payload.prev_block_hash());
+      out += fmt::format("  created time: {}\n", payload.created_time());
+      describeSignatures(out, block.signatures(), "  ");
+      out += fmt::format("  transactions: {}\n", payload.transactions_size());
+      for (auto const &transaction : payload.transactions()) {
+        describeTransaction(out, transaction, "    ");
+      }
+      out += fmt::format("  rejected transactions: {}\n",
+                         payload.rejected_transactions_hashes_size());
+      for (auto const &hash : payload.rejected_transactions_hashes()) {
+        out += fmt::format("    {}\n", hash);
+      }
+      return out;
+    }
+
+    std::string toJson(const iroha::protocol::Block_v1 &block) {
+      return fmt::format(R"({{"hash":"{}","block":{}}})",
+                         shared_model::proto::Block(block).hash().hex(),
+                         messageToJson(block));
+    }
+
+    TransactionWeight weigh(const iroha::protocol::Transaction &transaction) {
+      TransactionWeight weight;
+      weight.hash = shared_model::proto::Transaction(transaction).hash().hex();
+      weight.size = transaction.ByteSizeLong();
+      auto const &commands = transaction.payload().reduced_payload().commands();
+      weight.commands = commands.size();
+      for (auto const &command : commands) {
+        size_t detail_value_size = 0;
+        if (command.has_transfer_asset()) {
+          weight.max_description_size =
+              std::max(weight.max_description_size,
+                       command.transfer_asset().description().size());
+        } else if (command.has_set_account_detail()) {
+          detail_value_size = command.set_account_detail().value().size();
+        } else if (command.has_compare_and_set_account_detail()) {
+          detail_value_size =
+              command.compare_and_set_account_detail().value().size();
+        } else if (command.has_set_account_detail_at_path()) {
+          detail_value_size =
+              command.set_account_detail_at_path().value().size();
+        }
+        weight.max_detail_value_size =
+            std::max(weight.max_detail_value_size, detail_value_size);
+      }
+      return weight;
+    }
+
+    std::vector<std::string> checkLimits(
+        const TransactionWeight &weight,
+        const google::protobuf::Map<std::string, std::string> &settings) {
+      std::vector<std::string> exceeded;
+      checkLimit(exceeded,
+                 settings,
+                 "MaxDescriptionSize",
+                 weight.max_description_size);
+      checkLimit(exceeded,
+                 settings,
+                 "MaxAccountDetailValueSize",
+                 weight.max_detail_value_size);
+      return exceeded;
+    }
+
+    std::string describe(const TransactionWeight &weight) {
+      std::string out;
+      out += fmt::format("Transaction {}\n", weight.hash);
+      out += fmt::format("  commands: {}\n", weight.commands);
+      out += fmt::format("  size: {} bytes\n", weight.size);
+      out += fmt::format("  longest transfer description: {} bytes\n",
+                         weight.max_description_size);
+      out += fmt::format("  longest account detail value: {} bytes\n",
+                         weight.max_detail_value_size);
+      if (weight.exceeded.empty()) {
+        out += "  fits the limits of the peer\n";
+      } else {
+        out += "  exceeded limits:\n";
+        for (auto const &limit : weight.exceeded) {
+          out += fmt::format("    {}\n", limit);
+        }
+      }
+      return out;
+    }
+
+    std::string toJson(const TransactionWeight &weight) {
+      std::vector<std::string> exceeded;
+      for (auto const &limit : weight.exceeded) {
+        exceeded.push_back(fmt::format(R"("{}")", limit));
+      }
+      return fmt::format(
+          R"({{"hash":"{}","commands":{},"size":{},)"
+          R"("max_description_size":{},"max_detail_value_size":{},)"
+          R"("exceeded":[{}]}})",
+          weight.hash,
+          weight.commands,
+          weight.size,
+          weight.max_description_size,
+          weight.max_detail_value_size,
+          fmt::join(exceeded, ","));
+    }
+
+  }  // namespace inspect
+}  // namespace iroha_cli
@@ -0,0 +1,82 @@
+/**
+ * Copyright Soramitsu Co., Ltd. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+#ifndef IROHACLI_INSPECT_HPP
+#define IROHACLI_INSPECT_HPP
+
+#include <optional>
+#include <string>
+#include <vector>
+
+#include "block.pb.h"
+#include "endpoint.pb.h"
+#include "primitive.pb.h"
+#include "qry_responses.pb.h"
+#include "transaction.pb.h"
+
+namespace iroha_cli {
+  namespace inspect {
+
+    /// Transaction fetched from a peer with its status and engine receipts
+    struct TransactionDetails {
+      iroha::protocol::Transaction transaction;
+      std::optional<iroha::protocol::ToriiResponse> status;
+      std::vector<iroha::protocol::EngineReceipt> receipts;
+    };
+
+    /**
+     * Human readable description of the transaction: commands, signatures,
+     * status with the rejection reason and the logs emitted by the engine
+     */
+    std::string describe(const TransactionDetails &details);
+
+    /// Same as describe, but in JSON format for scripting
+    std::string toJson(const TransactionDetails &details);
+
+    /**
+     * Human readable description of the block: header, signatures, committed
+     * transactions and hashes of the rejected ones
+     */
+    std::string describe(const iroha::protocol::Block_v1 &block);
+
+    /// Same as describe, but in JSON format for scripting
+    std::string toJson(const iroha::protocol::Block_v1 &block);
+
+    /// Resources taken by a transaction, which are limited by the ledger
+    struct TransactionWeight {
+      std::string hash;
+      size_t commands = 0;
+      /// size of the serialized transaction in bytes
+      size_t size = 0;
+      /// longest description of a transfer in bytes
+      size_t max_description_size = 0;
+      /// longest account detail value in bytes
+      size_t max_detail_value_size = 0;
+      /// limits of the peer exceeded by the transaction
+      std::vector<std::string> exceeded;
+    };
+
+    /// Measure the transaction, exceeded limits are left empty
+    TransactionWeight weigh(const iroha::protocol::Transaction &transaction);
+
+    /**
+     * Check the weight against the ledger settings returned by GetSettings
+     * @return descriptions of the exceeded limits, empty if the transaction
+     * fits
+     */
+    std::vector<std::string> checkLimits(
+        const TransactionWeight &weight,
+        const google::protobuf::Map<std::string, std::string> &settings);
+
+    /// Human readable description of the weight and the exceeded limits
+    std::string describe(const TransactionWeight &weight);
+
+    /// Same as describe, but in JSON format for scripting
+    std::string toJson(const TransactionWeight &weight);
+
+  }  // namespace inspect
+}  // namespace iroha_cli
+
+#endif  // IROHACLI_INSPECT_HPP
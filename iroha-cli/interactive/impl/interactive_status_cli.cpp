@@ -33,7 +33,9 @@ namespace iroha_cli {
             {iroha::protocol::TxStatus::MST_PENDING,
              "Transaction has not collected quorum of signatures."},
             {iroha::protocol::TxStatus::ENOUGH_SIGNATURES_COLLECTED,
-             "Transaction has collected all signatures."}};
+             "Transaction has collected all signatures."},
+            {iroha::protocol::TxStatus::EXPIRED,
+             "Transaction has expired before getting into a block."}};
 
     InteractiveStatusCli::InteractiveStatusCli(
         const std::string &default_peer_ip,
@@ -6,21 +6,32 @@
 #include <gflags/gflags.h>
 #include <rapidjson/istreamwrapper.h>
 #include <rapidjson/rapidjson.h>
+#include <boost/algorithm/string/split.hpp>
 #include <boost/filesystem.hpp>
 #include <iostream>
 #include <fstream>
+#include <map>
 
+#include "backend/protobuf/block.hpp"
 #include "backend/protobuf/proto_block_json_converter.hpp"
 #include "backend/protobuf/queries/proto_query.hpp"
 #include "backend/protobuf/transaction.hpp"
+#include "builders/protobuf/queries.hpp"
 #include "client.hpp"
+#include "common/files.hpp"
+#include "common/hexutils.hpp"
 #include "common/result.hpp"
 #include "converters/protobuf/json_proto_converter.hpp"
 #include "crypto/keys_manager_impl.hpp"
+#include "cryptography/ed25519_sha3_impl/crypto_provider.hpp"
+#include "datetime/time.hpp"
 #include "grpc_response_handler.hpp"
+#include "inspect.hpp"
 #include "interactive/interactive_cli.hpp"
 #include "logger/logger.hpp"
 #include "logger/logger_manager.hpp"
+#include "main/genesis_signatures.hpp"
+#include "main/secret_source.hpp"
 #include "model/converters/json_block_factory.hpp"
 #include "model/converters/json_query_factory.hpp"
 #include "model/converters/pb_block_factory.hpp"
@@ -28,6 +39,9 @@
 #include "model/converters/pb_transaction_factory.hpp"
 #include "model/generators/block_generator.hpp"
 #include "model/model_crypto_provider_impl.hpp"
+#include "peer_admin.hpp"
+#include "statement.hpp"
+#include "wallet.hpp"
 
 // Account information
 DEFINE_bool(new_account,
@@ -59,6 +73,81 @@ DEFINE_string(peers_address,
               "",
               "File with peers address for new Iroha network");
 
+// Multi-signature genesis block:
+DEFINE_string(sign_genesis_block,
+              "",
+              "Sign the genesis block file with the keys of --account_name");
+DEFINE_string(merge_genesis_signatures,
+              "",
+              "Comma-separated genesis block files signed by different "
+              "founders to merge");
+DEFINE_string(genesis_private_key,
+              "",
+              "Founder private key to sign the genesis block with instead of "
+              "the keys of --account_name, or a reference to it: "
+              "file:<path>, env:<variable>, exec:<command> or "
+              "vault:<url>#<field>");
+DEFINE_string(genesis_output,
+              "genesis.block",
+              "File to save the signed or merged genesis block to");
+
+// Offline wallet:
+DEFINE_string(wallet,
+              "",
+              "Wallet command: create, import, list, balance, transfer, sign, "
+              "send or statement");
+DEFINE_string(private_key,
+              "",
+              "Private key to import to the wallet as --account_name, or a "
+              "reference to it: file:<path>, env:<variable>, exec:<command> "
+              "or vault:<url>#<field>");
+DEFINE_string(dest_account_id, "", "Recipient of the transferred asset");
+DEFINE_string(asset_id, "", "Asset to transfer, e.g. coin#test");
+DEFINE_string(amount, "", "Amount to transfer, e.g. 10.00");
+DEFINE_string(description, "", "Description of the transfer");
+DEFINE_string(transaction_file,
+              "transaction.json",
+              "File with the transaction built by transfer, signed by sign "
+              "and broadcast by send");
+DEFINE_string(statement_account_id,
+              "",
+              "Account of the statement, --account_name by default");
+DEFINE_uint64(first_height,
+              0,
+              "First block of the statement period, 0 for no bound");
+DEFINE_uint64(last_height,
+              0,
+              "Last block of the statement period, 0 for no bound");
+DEFINE_uint64(first_time,
+              0,
+              "Start of the statement period in milliseconds since epoch, 0 "
+              "for no bound");
+DEFINE_uint64(last_time,
+              0,
+              "End of the statement period in milliseconds since epoch, 0 for "
+              "no bound");
+DEFINE_string(statement_file,
+              "statement.csv",
+              "File to export the statement built by statement to in CSV "
+              "format");
+DEFINE_string(inspect,
+              "",
+              "Fetch and print a transaction or a block: tx or block, or "
+              "check --transaction_file against the limits of the peer: "
+              "weight");
+DEFINE_string(hash, "", "Hash of the inspected transaction or block");
+DEFINE_uint64(height, 0, "Height of the inspected block");
+DEFINE_bool(json, false, "Print the inspected object in JSON format");
+
+// Administration of a running peer:
+DEFINE_string(log_level,
+              "",
+              "List the log levels of the peer: list, or set the level of a "
+              "logger and its children: <logger>=<level>");
+DEFINE_int32(http_port,
+             50508,
+             "Port of the HTTP server of the node, its healthcheck_port");
+
 // Run iroha-cli in interactive mode
 DEFINE_bool(interactive, true, "Run iroha-cli in interactive mode");
 
@@ -75,6 +164,526 @@ iroha::keypair_t *makeOldModel(const shared_model::crypto::Keypair &keypair) {
           .assumeValue()};
 }
 
+iroha::expected::Result<std::unique_ptr<shared_model::interface::Block>,
+                        std::string>
+readGenesisBlock(const std::string &path) {
+  return iroha::readTextFile(path) | [](const auto &json) {
+    return shared_model::proto::ProtoBlockJsonConverter().deserialize(json);
+  };
+}
+
+iroha::expected::Result<void, std::string> writeGenesisBlock(
+    const shared_model::interface::Block &block, const std::string &path) {
+  auto json = shared_model::proto::ProtoBlockJsonConverter().serialize(block);
+  if (auto e = iroha::expected::resultToOptionalError(json)) {
+    return std::move(e).value();
+  }
+  std::ofstream output_file(path);
+  output_file << json.assumeValue();
+  if (not output_file) {
+    return "Failed to write " + path;
+  }
+  return {};
+}
+
+/// Keys of the genesis block founder, either from --genesis_private_key or
+/// from the key files of --account_name
+iroha::expected::Result<shared_model::crypto::Keypair, std::string>
+loadFounderKeypair(logger::LoggerPtr keys_manager_log) {
+  if (not FLAGS_genesis_private_key.empty()) {
+    return iroha::main::resolveSecret(FLAGS_genesis_private_key) |
+        [](auto &&private_key) {
+          return iroha_cli::wallet::keypairFromPrivateKey(private_key);
+        };
+  }
+  if (FLAGS_account_name.empty()) {
+    return iroha::expected::makeError(std::string{
+        "Specify the account name or the private key of the founder"});
+  }
+  iroha::KeysManagerImpl manager(
+      (fs::path(FLAGS_key_path) / FLAGS_account_name).string(),
+      std::move(keys_manager_log));
+  return FLAGS_pass_phrase.size() != 0 ? manager.loadKeys(FLAGS_pass_phrase)
+                                       : manager.loadKeys(boost::none);
+}
+
+/**
+ * Make the function sending the query built by its argument, signed by the
+ * keys of --account_name
+ * @return the function returning the response, or nullopt on connection
+ * failure
+ */
+auto makeQuerySender(iroha_cli::CliClient &client,
+                     const shared_model::crypto::Keypair &keypair,
+                     logger::LoggerPtr logger) {
+  return [&client, &keypair, logger = std::move(logger), query_counter = 0ull](
+             auto &&make_query) mutable
+         -> std::optional<iroha::protocol::QueryResponse> {
+    auto builder = shared_model::proto::QueryBuilder()
+                       .creatorAccountId(FLAGS_account_name)
+                       .createdTime(iroha::time::now())
+                       .queryCounter(++query_counter);
+    auto response = client.sendQuery(
+        make_query(builder).build().signAndAddSignature(keypair).finish());
+    if (not response.status.ok()) {
+      logger->error("Failed to query {}:{}: {}",
+                    FLAGS_peer_ip,
+                    FLAGS_torii_port,
+                    response.status.error_message());
+      return std::nullopt;
+    }
+    return std::move(response.answer);
+  };
+}
+
+/**
+ * Export the statement of the account in the asset over the period given by
+ * --wallet statement. The running balance is computed from the history of
+ * the asset, the transactions before the period give the opening balance.
+ */
+int runStatement(const logger::LoggerManagerTreePtr &log_manager,
+                 const logger::LoggerPtr &logger,
+                 const shared_model::crypto::Keypair &keypair) {
+  using shared_model::interface::types::HeightType;
+  using shared_model::interface::types::TimestampType;
+
+  if (FLAGS_asset_id.empty()) {
+    logger->error("Specify the asset with --asset_id");
+    return EXIT_FAILURE;
+  }
+  if ((FLAGS_first_height != 0 or FLAGS_last_height != 0)
+      and (FLAGS_first_time != 0 or FLAGS_last_time != 0)) {
+    logger->error("Specify the period either by heights or by times");
+    return EXIT_FAILURE;
+  }
+  auto const account_id = FLAGS_statement_account_id.empty()
+      ? FLAGS_account_name
+      : FLAGS_statement_account_id;
+  auto bound = [](uint64_t value) {
+    return value == 0 ? std::nullopt : std::make_optional(value);
+  };
+
+  iroha_cli::CliClient client(
+      FLAGS_peer_ip,
+      FLAGS_torii_port,
+      log_manager->getChild("PbQueryFactory")->getLogger());
+  auto send_query = makeQuerySender(client, keypair, logger);
+
+  auto asset = send_query(
+      [](auto &builder) { return builder.getAssetInfo(FLAGS_asset_id); });
+  if (not asset) {
+    return EXIT_FAILURE;
+  }
+  if (not asset->has_asset_response()) {
+    logger->error("Failed to get the asset {}: {}",
+                  FLAGS_asset_id,
+                  asset->error_response().message());
+    return EXIT_FAILURE;
+  }
+
+  iroha_cli::statement::StatementBuilder statement(
+      account_id,
+      FLAGS_asset_id,
+      asset->asset_response().asset().precision());
+  // read the history of the asset within the bounds page by page
+  auto read_history = [&](std::optional<TimestampType> first_time,
+                          std::optional<TimestampType> last_time,
+                          std::optional<HeightType> first_height,
+                          std::optional<HeightType> last_height,
+                          auto &&apply) {
+    std::optional<shared_model::crypto::Hash> first_hash;
+    do {
+      auto response = send_query([&](auto &builder) {
+        return builder.getAccountAssetTransactions(account_id,
+                                                   FLAGS_asset_id,
+                                                   100,
+                                                   first_hash,
+                                                   nullptr,
+                                                   first_time,
+                                                   last_time,
+                                                   first_height,
+                                                   last_height);
+      });
+      if (not response) {
+        return false;
+      }
+      if (not response->has_transactions_page_response()) {
+        logger->error("Failed to get the transactions of {} in {}: {}",
+                      account_id,
+                      FLAGS_asset_id,
+                      response->error_response().message());
+        return false;
+      }
+      auto const &page = response->transactions_page_response();
+      // burn fees charged by the transfers of the page by transaction
+      std::map<std::string, iroha_cli::statement::TransferFees> fees;
+      for (auto const &fee : page.transfer_fees()) {
+        fees[fee.tx_hash()][fee.command_index()] = fee.fee();
+      }
+      for (auto const &transaction : page.transactions()) {
+        auto const hash =
+            shared_model::proto::Transaction(transaction).hash().hex();
+        if (auto e = iroha::expected::resultToOptionalError(
+                apply(transaction, fees[hash]))) {
+          logger->error("{}", *e);
+          return false;
+        }
+      }
+      first_hash = page.next_tx_hash().empty()
+          ? std::nullopt
+          : std::make_optional(
+              shared_model::crypto::Hash::fromHexString(page.next_tx_hash()));
+    } while (first_hash);
+    return true;
+  };
+
+  auto add_before = [&statement](const auto &transaction,
+                                 const auto &fees) {
+    return statement.addBefore(transaction, fees);
+  };
+  if (FLAGS_first_height > 1
+      and not read_history(std::nullopt,
+                           std::nullopt,
+                           std::nullopt,
+                           FLAGS_first_height - 1,
+                           add_before)) {
+    return EXIT_FAILURE;
+  }
+  if (FLAGS_first_time > 0
+      and not read_history(std::nullopt,
+                           FLAGS_first_time - 1,
+                           std::nullopt,
+                           std::nullopt,
+                           add_before)) {
+    return EXIT_FAILURE;
+  }
+  if (not read_history(bound(FLAGS_first_time),
+                       bound(FLAGS_last_time),
+                       bound(FLAGS_first_height),
+                       bound(FLAGS_last_height),
+                       [&statement](const auto &transaction,
+                                    const auto &fees) {
+                         return statement.add(transaction, fees);
+                       })) {
+    return EXIT_FAILURE;
+  }
+
+  auto const result = statement.statement();
+  std::ofstream output_file(FLAGS_statement_file);
+  output_file << iroha_cli::statement::toCsv(result);
+  if (not output_file) {
+    logger->error("Failed to write {}", FLAGS_statement_file);
+    return EXIT_FAILURE;
+  }
+  logger->info("Statement of {} in {} with {} entries saved to {}",
+               account_id,
+               FLAGS_asset_id,
+               result.entries.size(),
+               FLAGS_statement_file);
+  return EXIT_SUCCESS;
+}
+
+/// Run the wallet command given by --wallet
+int runWallet(const logger::LoggerManagerTreePtr &log_manager) {
+  const auto logger = log_manager->getChild("Wallet")->getLogger();
+  boost::optional<std::string> pass_phrase;
+  if (not FLAGS_pass_phrase.empty()) {
+    pass_phrase = FLAGS_pass_phrase;
+  }
+
+  if (FLAGS_wallet == "list") {
+    for (auto const &identity : iroha_cli::wallet::listIdentities(
+             fs::path(FLAGS_key_path))) {
+      std::cout << identity.account_id << " " << identity.public_key
+                << std::endl;
+    }
+    return EXIT_SUCCESS;
+  }
+  if (FLAGS_wallet == "send") {
+    auto tx = iroha_cli::wallet::readTransaction(FLAGS_transaction_file);
+    if (auto e = iroha::expected::resultToOptionalError(tx)) {
+      logger->error("{}", e.value());
+      return EXIT_FAILURE;
+    }
+    logger->info("Send transaction {} to {}:{}",
+                 tx.assumeValue().hash().hex(),
+                 FLAGS_peer_ip,
+                 FLAGS_torii_port);
+    iroha_cli::CliClient client(
+        FLAGS_peer_ip,
+        FLAGS_torii_port,
+        log_manager->getChild("PbQueryFactory")->getLogger());
+    iroha_cli::GrpcResponseHandler(log_manager->getChild("ResponseHandler"))
+        .handle(client.sendTx(tx.assumeValue()));
+    return EXIT_SUCCESS;
+  }
+
+  if (FLAGS_account_name.empty()) {
+    logger->error("Specify the account with --account_name");
+    return EXIT_FAILURE;
+  }
+  iroha::KeysManagerImpl manager(
+      FLAGS_account_name,
+      fs::path(FLAGS_key_path),
+      log_manager->getChild("KeysManager")->getLogger());
+  auto const public_key_path = fs::path(FLAGS_key_path)
+      / (FLAGS_account_name + iroha::KeysManagerImpl::kPublicKeyExtension);
+
+  if (FLAGS_wallet == "create" or FLAGS_wallet == "import") {
+    if (fs::exists(public_key_path)) {
+      logger->error("Keys of {} already exist", FLAGS_account_name);
+      return EXIT_FAILURE;
+    }
+    if (FLAGS_wallet == "create") {
+      if (not manager.createKeys(pass_phrase)) {
+        logger->error("Failed to save keys to {}", FLAGS_key_path);
+        return EXIT_FAILURE;
+      }
+    } else {
+      auto keypair = iroha::main::resolveSecret(FLAGS_private_key) |
+          [](auto &&private_key) {
+            return iroha_cli::wallet::keypairFromPrivateKey(private_key);
+          };
+      if (auto e = iroha::expected::resultToOptionalError(keypair)) {
+        logger->error("{}", e.value());
+        return EXIT_FAILURE;
+      }
+      if (not manager.importKeys(keypair.assumeValue(), pass_phrase)) {
+        logger->error("Failed to save keys to {}", FLAGS_key_path);
+        return EXIT_FAILURE;
+      }
+    }
+    logger->info("Keys of {} saved to {}", FLAGS_account_name, FLAGS_key_path);
+    return EXIT_SUCCESS;
+  }
+
+  auto keypair = manager.loadKeys(pass_phrase);
+  if (auto e = iroha::expected::resultToOptionalError(keypair)) {
+    logger->error("Keypair error: {}", e.value());
+    return EXIT_FAILURE;
+  }
+
+  if (FLAGS_wallet == "balance") {
+    iroha_cli::CliClient client(
+        FLAGS_peer_ip,
+        FLAGS_torii_port,
+        log_manager->getChild("PbQueryFactory")->getLogger());
+    iroha_cli::GrpcResponseHandler handler(
+        log_manager->getChild("ResponseHandler"));
+    // print the balances page by page
+    std::optional<shared_model::interface::types::AssetIdType> first_asset_id;
+    uint64_t query_counter = 0;
+    do {
+      auto query =
+          shared_model::proto::QueryBuilder()
+              .creatorAccountId(FLAGS_account_name)
+              .createdTime(iroha::time::now())
+              .queryCounter(++query_counter)
+              .getAccountAssets(FLAGS_account_name, 100, first_asset_id)
+              .build()
+              .signAndAddSignature(keypair.assumeValue())
+              .finish();
+      auto response = client.sendQuery(query);
+      first_asset_id.reset();
+      if (response.status.ok()
+          and response.answer.has_account_assets_response()) {
+        auto const &page = response.answer.account_assets_response();
+        if (not page.next_asset_id().empty()) {
+          first_asset_id = page.next_asset_id();
+        }
+      }
+      handler.handle(std::move(response));
+    } while (first_asset_id);
+    return EXIT_SUCCESS;
+  }
+  if (FLAGS_wallet == "statement") {
+    return runStatement(log_manager, logger, keypair.assumeValue());
+  }
+  if (FLAGS_wallet == "transfer" or FLAGS_wallet == "sign") {
+    auto tx = FLAGS_wallet == "transfer"
+        ? iroha_cli::wallet::makeTransferTransaction(FLAGS_account_name,
+                                                     FLAGS_dest_account_id,
+                                                     FLAGS_asset_id,
+                                                     FLAGS_amount,
+                                                     FLAGS_description,
+                                                     keypair.assumeValue())
+        : iroha_cli::wallet::readTransaction(FLAGS_transaction_file);
+    auto result = std::move(tx) |
+        [&keypair](auto &&tx) -> iroha::expected::Result<void, std::string> {
+      if (FLAGS_wallet == "sign") {
+        if (auto e = iroha::expected::resultToOptionalError(
+                iroha_cli::wallet::signTransaction(tx,
+                                                   keypair.assumeValue()))) {
+          return iroha::expected::makeError(std::move(e).value());
+        }
+      }
+      return iroha_cli::wallet::writeTransaction(tx, FLAGS_transaction_file);
+    };
+    if (auto e = iroha::expected::resultToOptionalError(result)) {
+      logger->error("{}", e.value());
+      return EXIT_FAILURE;
+    }
+    logger->info("Signed transaction saved to {}", FLAGS_transaction_file);
+    return EXIT_SUCCESS;
+  }
+
+  logger->error("Unknown wallet command {}", FLAGS_wallet);
+  return EXIT_FAILURE;
+}
+
+/// Fetch the transaction or the block given by --inspect and print it, or
+/// check the weight of a transaction against the limits of the peer
+int runInspect(const logger::LoggerManagerTreePtr &log_manager) {
+  const auto logger = log_manager->getChild("Inspect")->getLogger();
+  if (FLAGS_account_name.empty()) {
+    logger->error("Specify the account signing queries with --account_name");
+    return EXIT_FAILURE;
+  }
+  iroha::KeysManagerImpl manager(
+      FLAGS_account_name,
+      fs::path(FLAGS_key_path),
+      log_manager->getChild("KeysManager")->getLogger());
+  auto keypair = FLAGS_pass_phrase.size() != 0
+      ? manager.loadKeys(FLAGS_pass_phrase)
+      : manager.loadKeys(boost::none);
+  if (auto e = iroha::expected::resultToOptionalError(keypair)) {
+    logger->error("Keypair error: {}", e.value());
+    return EXIT_FAILURE;
+  }
+
+  iroha_cli::CliClient client(
+      FLAGS_peer_ip,
+      FLAGS_torii_port,
+      log_manager->getChild("PbQueryFactory")->getLogger());
+  auto send_query = makeQuerySender(client, keypair.assumeValue(), logger);
+  auto print = [](const auto &object) {
+    std::cout << (FLAGS_json ? iroha_cli::inspect::toJson(object) + "\n"
+                             : iroha_cli::inspect::describe(object));
+  };
+
+  if (FLAGS_inspect == "tx") {
+    auto hash = shared_model::crypto::Hash::fromHexString(FLAGS_hash);
+    if (hash.size() == 0) {
+      logger->error("Specify the transaction hash with --hash");
+      return EXIT_FAILURE;
+    }
+    auto response = send_query(
+        [&hash](auto &builder) { return builder.getTransactions(hash); });
+    if (not response) {
+      return EXIT_FAILURE;
+    }
+    if (not response->has_transactions_response()
+        or response->transactions_response().transactions_size() == 0) {
+      logger->error("Transaction {} is not found: {}",
+                    FLAGS_hash,
+                    response->error_response().message());
+      return EXIT_FAILURE;
+    }
+    iroha_cli::inspect::TransactionDetails details{
+        response->transactions_response().transactions(0), std::nullopt, {}};
+    auto status = client.getTxStatus(FLAGS_hash);
+    if (status.status.ok()) {
+      details.status = std::move(status.answer);
+    }
+    auto receipts = send_query([&hash](auto &builder) {
+      return builder.getEngineReceipts(hash.hex());
+    });
+    if (receipts and receipts->has_engine_receipts_response()) {
+      auto const &engine_receipts =
+          receipts->engine_receipts_response().engine_receipts();
+      details.receipts.assign(engine_receipts.begin(), engine_receipts.end());
+    }
+    print(details);
+    return EXIT_SUCCESS;
+  }
+  if (FLAGS_inspect == "block") {
+    if (FLAGS_height == 0 and FLAGS_hash.empty()) {
+      logger->error("Specify the block with --height or --hash");
+      return EXIT_FAILURE;
+    }
+    // there is no query of a block by hash, so blocks are scanned from the
+    // first one until the hash is found
+    for (auto height = FLAGS_height == 0 ? 1 : FLAGS_height;; ++height) {
+      auto response = send_query(
+          [height](auto &builder) { return builder.getBlock(height); });
+      if (not response) {
+        return EXIT_FAILURE;
+      }
+      if (not response->has_block_response()) {
+        logger->error("Block {} is not found: {}",
+                      FLAGS_height == 0 ? FLAGS_hash : std::to_string(height),
+                      response->error_response().message());
+        return EXIT_FAILURE;
+      }
+      auto const &block = response->block_response().block().block_v1();
+      if (FLAGS_height != 0
+          or shared_model::proto::Block(block).hash()
+              == shared_model::crypto::Hash::fromHexString(FLAGS_hash)) {
+        print(block);
+        return EXIT_SUCCESS;
+      }
+    }
+  }
+
+  if (FLAGS_inspect == "weight") {
+    auto tx = iroha_cli::wallet::readTransaction(FLAGS_transaction_file);
+    if (auto e = iroha::expected::resultToOptionalError(tx)) {
+      logger->error("{}", e.value());
+      return EXIT_FAILURE;
+    }
+    auto weight = iroha_cli::inspect::weigh(tx.assumeValue().getTransport());
+    auto response =
+        send_query([](auto &builder) { return builder.getSettings(); });
+    if (not response) {
+      return EXIT_FAILURE;
+    }
+    if (not response->has_settings_response()) {
+      logger->error("Failed to get the limits of the peer: {}",
+                    response->error_response().message());
+      return EXIT_FAILURE;
+    }
+    weight.exceeded = iroha_cli::inspect::checkLimits(
+        weight, response->settings_response().settings());
+    print(weight);
+    return weight.exceeded.empty() ? EXIT_SUCCESS : EXIT_FAILURE;
+  }
+
+  logger->error("Unknown inspect command {}", FLAGS_inspect);
+  return EXIT_FAILURE;
+}
+
+/// List or set the log levels of the peer as given by --log_level
+int runLogLevel(const logger::LoggerManagerTreePtr &log_manager) {
+  const auto logger = log_manager->getChild("PeerAdmin")->getLogger();
+  std::optional<iroha_cli::peer_admin::LogLevelChange> change;
+  if (FLAGS_log_level != "list") {
+    auto parsed =
+        iroha_cli::peer_admin::parseLogLevelChange(FLAGS_log_level);
+    if (auto e = iroha::expected::resultToOptionalError(parsed)) {
+      logger->error("{}", e.value());
+      return EXIT_FAILURE;
+    }
+    change = std::move(parsed).assumeValue();
+  }
+  if (FLAGS_http_port <= 0 or FLAGS_http_port > 65535) {
+    logger->error("Invalid --http_port {}", FLAGS_http_port);
+    return EXIT_FAILURE;
+  }
+  auto levels = iroha_cli::peer_admin::requestLogLevels(
+      FLAGS_peer_ip, static_cast<uint16_t>(FLAGS_http_port), change);
+  if (auto e = iroha::expected::resultToOptionalError(levels)) {
+    logger->error("{}", e.value());
+    return EXIT_FAILURE;
+  }
+  if (change) {
+    logger->info("Log level of {} set to {}", change->logger, change->level);
+  }
+  for (auto const &[name, level] : levels.assumeValue()) {
+    std::cout << name << " " << level << std::endl;
+  }
+  return EXIT_SUCCESS;
+}
+
 int main(int argc, char *argv[]) {
   gflags::ParseCommandLineFlags(&argc, &argv, true);
   gflags::ShutDownCommandLineFlags();
@@ -91,6 +700,15 @@ int main(int argc, char *argv[]) {
       log_manager->getChild("JsonQueryFactory")->getLogger();
   const auto keys_manager_log =
       log_manager->getChild("KeysManager")->getLogger();
+  if (not FLAGS_wallet.empty()) {
+    return runWallet(log_manager);
+  }
+  if (not FLAGS_inspect.empty()) {
+    return runInspect(log_manager);
+  }
+  if (not FLAGS_log_level.empty()) {
+    return runLogLevel(log_manager);
+  }
   // Generate new genesis block now Iroha network
   if (FLAGS_genesis_block) {
     BlockGenerator generator;
@@ -141,6 +759,61 @@ int main(int argc, char *argv[]) {
           std::exit(EXIT_FAILURE);
         });
   }
+  // Add the signature of a founder to the genesis block
+  else if (not FLAGS_sign_genesis_block.empty()) {
+    auto keypair = loadFounderKeypair(keys_manager_log);
+    if (auto e = iroha::expected::resultToOptionalError(keypair)) {
+      logger->error("Keypair error: {}", e.value());
+      return EXIT_FAILURE;
+    }
+    auto block = readGenesisBlock(FLAGS_sign_genesis_block);
+    if (auto e = iroha::expected::resultToOptionalError(block)) {
+      logger->error("Failed to read the genesis block: {}", e.value());
+      return EXIT_FAILURE;
+    }
+    auto result = iroha::main::signGenesisBlock(*block.assumeValue(),
+                                                keypair.assumeValue())
+        | [&block] {
+            return writeGenesisBlock(*block.assumeValue(),
+                                     FLAGS_genesis_output);
+          };
+    if (auto e = iroha::expected::resultToOptionalError(result)) {
+      logger->error("Failed to sign the genesis block: {}", e.value());
+      return EXIT_FAILURE;
+    }
+    logger->info("Signed genesis block saved to {}", FLAGS_genesis_output);
+  }
+  // Merge the signatures of independently signed genesis block copies
+  else if (not FLAGS_merge_genesis_signatures.empty()) {
+    std::vector<std::string> paths;
+    boost::split(paths,
+                 FLAGS_merge_genesis_signatures,
+                 [](char c) { return c == ','; });
+    auto merged = readGenesisBlock(paths.front());
+    for (auto it = std::next(paths.begin());
+         it != paths.end() and iroha::expected::hasValue(merged);
+         ++it) {
+      auto signed_copy = readGenesisBlock(*it);
+      if (auto e = iroha::expected::resultToOptionalError(signed_copy)) {
+        logger->error("Failed to read {}: {}", *it, e.value());
+        return EXIT_FAILURE;
+      }
+      if (auto e = iroha::expected::resultToOptionalError(
+              iroha::main::mergeGenesisSignatures(
+                  *merged.assumeValue(), *signed_copy.assumeValue()))) {
+        logger->error("Failed to merge {}: {}", *it, e.value());
+        return EXIT_FAILURE;
+      }
+    }
+    auto result = std::move(merged) | [](auto &&block) {
+      return writeGenesisBlock(*block, FLAGS_genesis_output);
+    };
+    if (auto e = iroha::expected::resultToOptionalError(result)) {
+      logger->error("Failed to merge genesis signatures: {}", e.value());
+      return EXIT_FAILURE;
+    }
+    logger->info("Merged genesis block saved to {}", FLAGS_genesis_output);
+  }
   // Create new pub/priv key, register in Iroha Network
   else if (FLAGS_new_account) {
     if (FLAGS_account_name.empty()) {
@@ -0,0 +1,113 @@
+/**
+ * Copyright Soramitsu Co., Ltd. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+#include "peer_admin.hpp"
+
+#include <fmt/format.h>
+#include <rapidjson/document.h>
+#include <boost/asio/ip/tcp.hpp>
+#include <iterator>
+#include "main/iroha_conf_literals.hpp"
+
+using iroha::expected::makeError;
+using iroha::expected::Result;
+
+namespace iroha_cli {
+  namespace peer_admin {
+
+    Result<LogLevelChange, std::string> parseLogLevelChange(
+        std::string_view text) {
+      auto separator = text.find('=');
+      if (separator == std::string_view::npos) {
+        return makeError(
+            fmt::format("Expected <logger>=<level>, got `{}'", text));
+      }
+      LogLevelChange change{std::string{text.substr(0, separator)},
+                            std::string{text.substr(separator + 1)}};
+      if (change.logger.empty()) {
+        return makeError(std::string{"Logger name is empty"});
+      }
+      // the name is sent as a query parameter without escaping
+      if (change.logger.find_first_of(" \t&#?=%+") != std::string::npos) {
+        return makeError(
+            fmt::format("Invalid logger name `{}'", change.logger));
+      }
+      if (config_members::LogLevels.count(change.level) == 0) {
+        std::string levels;
+        for (auto const &level : config_members::LogLevels) {
+          levels += (levels.empty() ? "" : ", ") + level.first;
+        }
+        return makeError(fmt::format(
+            "Unknown log level `{}', use one of: {}", change.level, levels));
+      }
+      return change;
+    }
+
+    Result<LogLevels, std::string> requestLogLevels(
+        const std::string &host,
+        uint16_t port,
+        const std::optional<LogLevelChange> &change,
+        std::chrono::milliseconds timeout) {
+      boost::asio::ip::tcp::iostream stream;
+      stream.expires_after(timeout);
+      stream.connect(host, std::to_string(port));
+      if (not stream) {
+        return makeError(fmt::format("Failed to connect to {}:{}: {}",
+                                     host,
+                                     port,
+                                     stream.error().message()));
+      }
+      if (change) {
+        stream << "POST /log_level?logger=" << change->logger
+               << "&level=" << change->level << " HTTP/1.1\r\n"
+               << "Content-Length: 0\r\n";
+      } else {
+        stream << "GET /log_level HTTP/1.1\r\n";
+      }
+      stream << "Host: " << host << "\r\n"
+             << "Connection: close\r\n\r\n"
+             << std::flush;
+
+      std::string version;
+      unsigned status = 0;
+      if (not(stream >> version >> status)) {
+        return makeError(fmt::format("No response from {}:{}", host, port));
+      }
+      if (status != 200) {
+        return makeError(
+            fmt::format("{}:{} responded with status {}", host, port, status));
+      }
+      // skip the rest of the status line and the headers
+      std::string line;
+      while (std::getline(stream, line) and line != "\r" and not line.empty())
+        ;
+      std::string body{std::istreambuf_iterator<char>(stream),
+                       std::istreambuf_iterator<char>()};
+
+      rapidjson::Document document;
+      document.Parse(body.data(), body.size());
+      if (document.HasParseError() or not document.IsObject()
+          or not document.HasMember("loggers")
+          or not document["loggers"].IsArray()) {
+        return makeError(fmt::format(
+            "Unexpected response from {}:{}: {}", host, port, body));
+      }
+      if (document.HasMember("error") and document["error"].IsString()) {
+        return makeError(std::string{document["error"].GetString()});
+      }
+      LogLevels levels;
+      for (auto const &item : document["loggers"].GetArray()) {
+        if (item.IsObject() and item.HasMember("logger")
+            and item["logger"].IsString() and item.HasMember("level")
+            and item["level"].IsString()) {
+          levels.emplace_back(item["logger"].GetString(),
+                              item["level"].GetString());
+        }
+      }
+      return levels;
+    }
+
+  }  // namespace peer_admin
+}  // namespace iroha_cli
@@ -0,0 +1,57 @@
+/**
+ * Copyright Soramitsu Co., Ltd. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+#ifndef IROHACLI_PEER_ADMIN_HPP
+#define IROHACLI_PEER_ADMIN_HPP
+
+#include <chrono>
+#include <optional>
+#include <string>
+#include <string_view>
+#include <utility>
+#include <vector>
+
+#include "common/result.hpp"
+
+namespace iroha_cli {
+  namespace peer_admin {
+
+    /// New level of a logger of the peer and all its children
+    struct LogLevelChange {
+      std::string logger;
+      std::string level;
+    };
+
+    /**
+     * Parse the change of a log level given as <logger>=<level>, for example
+     * Irohad/Storage=debug
+     * @return the change, or the description of the syntax error
+     */
+    iroha::expected::Result<LogLevelChange, std::string> parseLogLevelChange(
+        std::string_view text);
+
+    /// Loggers of the peer with their levels
+    using LogLevels = std::vector<std::pair<std::string, std::string>>;
+
+    /**
+     * Get the log levels from the HTTP server of the peer, setting the given
+     * level first if any
+     * @param host - address of the peer
+     * @param port - healthcheck_port of the peer
+     * @param change - level to set
+     * @param timeout - time to wait for the response
+     * @return log levels after the change, or an error if the peer is
+     * unreachable or rejects the change
+     */
+    iroha::expected::Result<LogLevels, std::string> requestLogLevels(
+        const std::string &host,
+        uint16_t port,
+        const std::optional<LogLevelChange> &change,
+        std::chrono::milliseconds timeout = std::chrono::seconds(5));
+
+  }  // namespace peer_admin
+}  // namespace iroha_cli
+
+#endif  // IROHACLI_PEER_ADMIN_HPP
@@ -0,0 +1,301 @@
+/**
+ * Copyright Soramitsu Co., Ltd. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+#include "statement.hpp"
+
+#include <fmt/format.h>
+#include <boost/algorithm/string/case_conv.hpp>
+#include <ctime>
+#include "ametsuchi/asset_lock.hpp"
+#include "backend/protobuf/transaction.hpp"
+
+using iroha::expected::makeError;
+using iroha::expected::Result;
+using shared_model::interface::Amount;
+
+namespace {
+  /// Quote the CSV field if it contains a separator, a quote or a newline
+  std::string csvField(const std::string &value) {
+    if (value.find_first_of(",\"\r\n") == std::string::npos) {
+      return value;
+    }
+    std::string quoted = "\"";
+    for (auto c : value) {
+      quoted += c;
+      if (c == '"') {
+        quoted += '"';
+      }
+    }
+    return quoted + "\"";
+  }
+
+  /// ISO 8601 UTC time of milliseconds since epoch
+  std::string formatTime(uint64_t time_ms) {
+    std::time_t seconds = time_ms / 1000;
+    std::tm tm{};
+    gmtime_r(&seconds, &tm);
+    char date[32];
+    std::strftime(date, sizeof(date), "%Y-%m-%dT%H:%M:%S", &tm);
+    return fmt::format("{}.{:03}Z", date, time_ms % 1000);
+  }
+
+  /// Zero with all the digits of the precision, as the computed amounts
+  Amount zeroAmount(shared_model::interface::types::PrecisionType precision) {
+    return *Amount{precision}.checkedAdd(Amount{"0"});
+  }
+}  // namespace
+
+namespace iroha_cli {
+  namespace statement {
+
+    StatementBuilder::StatementBuilder(
+        std::string account_id,
+        std::string asset_id,
+        shared_model::interface::types::PrecisionType precision)
+        : account_id_(std::move(account_id)),
+          asset_id_(std::move(asset_id)),
+          opening_balance_(zeroAmount(precision)),
+          opening_locked_(zeroAmount(precision)),
+          balance_(zeroAmount(precision)),
+          locked_(zeroAmount(precision)) {}
+
+    Result<void, std::string> StatementBuilder::addBefore(
+        const iroha::protocol::Transaction &transaction,
+        const TransferFees &fees) {
+      if (not entries_.empty()) {
+        return makeError(std::string{
+            "Transactions before the period follow the ones within it"});
+      }
+      auto result = apply(transaction, fees, false);
+      opening_balance_ = balance_;
+      opening_locked_ = locked_;
+      return result;
+    }
+
+    Result<void, std::string> StatementBuilder::add(
+        const iroha::protocol::Transaction &transaction,
+        const TransferFees &fees) {
+      return apply(transaction, fees, true);
+    }
+
+    Statement StatementBuilder::statement() const {
+      return Statement{opening_balance_.toStringRepr(),
+                       opening_locked_.toStringRepr(),
+                       entries_,
+                       balance_.toStringRepr(),
+                       locked_.toStringRepr()};
+    }
+
+    Result<void, std::string> StatementBuilder::apply(
+        const iroha::protocol::Transaction &transaction,
+        const TransferFees &fees,
+        bool record) {
+      auto const &payload = transaction.payload().reduced_payload();
+      std::optional<std::string> tx_hash;
+      const Amount zero{balance_.precision()};
+      shared_model::interface::types::CommandIndexType cmd_index = 0;
+      for (auto const &command : payload.commands()) {
+        auto const index = cmd_index++;
+        Entry entry;
+        std::optional<Amount> fee;
+        bool outgoing = false;
+        std::string amount_repr;
+        // the amount is locked by the account
+        bool locks = false;
+        // amount of the lock of the account which is released
+        std::optional<Amount> released;
+        if (command.has_add_asset_quantity()
+            and command.add_asset_quantity().asset_id() == asset_id_
+            and payload.creator_account_id() == account_id_) {
+          entry.type = "issue";
+          amount_repr = command.add_asset_quantity().amount();
+        } else if (command.has_grant_vested_asset()
+                   and command.grant_vested_asset().asset_id() == asset_id_
+                   and command.grant_vested_asset().dest_account_id()
+                       == account_id_) {
+          // the granted amount is a part of the balance, the schedule only
+          // limits the spendings until the amount vests
+          auto const &grant = command.grant_vested_asset();
+          entry.type = "grant";
+          entry.counterparty = payload.creator_account_id();
+          entry.description =
+              fmt::format("vesting with cliff of {} and duration of {} blocks",
+                          grant.cliff(),
+                          grant.duration());
+          amount_repr = grant.amount();
+        } else if (command.has_subtract_asset_quantity()
+                   and command.subtract_asset_quantity().asset_id()
+                       == asset_id_
+                   and payload.creator_account_id() == account_id_) {
+          entry.type = "burn";
+          amount_repr = command.subtract_asset_quantity().amount();
+          outgoing = true;
+        } else if (command.has_transfer_asset()
+                   and command.transfer_asset().asset_id() == asset_id_
+                   and command.transfer_asset().src_account_id()
+                       != command.transfer_asset().dest_account_id()
+                   and (command.transfer_asset().src_account_id()
+                            == account_id_
+                        or command.transfer_asset().dest_account_id()
+                            == account_id_)) {
+          auto const &transfer = command.transfer_asset();
+          outgoing = transfer.src_account_id() == account_id_;
+          entry.type = outgoing ? "transfer_out" : "transfer_in";
+          entry.counterparty = outgoing ? transfer.dest_account_id()
+                                        : transfer.src_account_id();
+          entry.description = transfer.description();
+          amount_repr = transfer.amount();
+          // the fee is the one charged on execution, the policy can change
+          if (auto it = fees.find(index); not outgoing and it != fees.end()) {
+            fee = zero.checkedAdd(Amount{it->second});
+            if (not fee) {
+              return makeError(fmt::format(
+                  "Invalid fee {} of {}", it->second, asset_id_));
+            }
+          }
+        } else if (command.has_lock_asset()
+                   and command.lock_asset().asset_id() == asset_id_
+                   and (command.lock_asset().src_account_id() == account_id_
+                        or command.lock_asset().dest_account_id()
+                            == account_id_)) {
+          auto const &lock = command.lock_asset();
+          auto amount = zero.checkedAdd(Amount{lock.amount()});
+          if (not amount) {
+            return makeError(fmt::format(
+                "Invalid amount {} of {}", lock.amount(), asset_id_));
+          }
+          locks_.insert_or_assign(
+              boost::algorithm::to_lower_copy(lock.hash()),
+              Lock{lock.src_account_id(), lock.dest_account_id(), *amount});
+          if (lock.src_account_id() != account_id_) {
+            // the lock for the account changes its balance once claimed
+            continue;
+          }
+          entry.type = "lock_out";
+          entry.counterparty = lock.dest_account_id();
+          amount_repr = lock.amount();
+          outgoing = true;
+          locks = true;
+        } else if (command.has_claim_with_preimage()
+                   or command.has_refund_after_timeout()) {
+          // the locks of other assets and accounts are not in the history
+          std::string hash;
+          if (command.has_claim_with_preimage()) {
+            auto claimed = iroha::ametsuchi::assetLockHash(
+                command.claim_with_preimage().preimage());
+            if (iroha::expected::hasError(claimed)) {
+              continue;
+            }
+            hash = std::move(claimed).assumeValue();
+          } else {
+            hash = boost::algorithm::to_lower_copy(
+                command.refund_after_timeout().hash());
+          }
+          auto it = locks_.find(hash);
+          if (it == locks_.end()) {
+            continue;
+          }
+          auto const lock = std::move(it->second);
+          locks_.erase(it);
+          amount_repr = lock.amount.toStringRepr();
+          if (lock.src_account_id == account_id_) {
+            released = lock.amount;
+          }
+          if (command.has_refund_after_timeout()) {
+            if (lock.src_account_id != account_id_) {
+              continue;
+            }
+            entry.type = "refund_in";
+            entry.counterparty = lock.dest_account_id;
+          } else if (lock.dest_account_id == account_id_) {
+            entry.type = "claim_in";
+            entry.counterparty = lock.src_account_id;
+          } else {
+            // the locked amount leaves the account without a balance change
+            entry.type = "lock_claimed";
+            entry.counterparty = lock.dest_account_id;
+            amount_repr = "0";
+          }
+        } else {
+          continue;
+        }
+
+        // the amounts take the precision of the asset
+        auto amount = zero.checkedAdd(Amount{amount_repr});
+        if (not amount) {
+          return makeError(fmt::format(
+              "Invalid amount {} of {}", amount_repr, asset_id_));
+        }
+        auto balance = outgoing ? balance_.checkedSub(*amount)
+                                : balance_.checkedAdd(*amount);
+        if (balance and fee) {
+          balance = balance->checkedSub(*fee);
+        }
+        if (not balance) {
+          return makeError(fmt::format(
+              "Balance of {} becomes negative, the history is incomplete, "
+              "e.g. smart contracts are not accounted",
+              asset_id_));
+        }
+        std::optional<Amount> locked = locked_;
+        if (locks) {
+          locked = locked_.checkedAdd(*amount);
+        } else if (released) {
+          locked = locked_.checkedSub(*released);
+        }
+        if (not locked) {
+          return makeError(fmt::format(
+              "Invalid amount of {} in asset locks", asset_id_));
+        }
+        balance_ = *balance;
+        locked_ = *locked;
+        if (not record) {
+          continue;
+        }
+
+        if (not tx_hash) {
+          tx_hash =
+              shared_model::proto::Transaction(transaction).hash().hex();
+        }
+        entry.tx_hash = *tx_hash;
+        entry.created_time = payload.created_time();
+        entry.amount = (outgoing ? "-" : "") + amount->toStringRepr();
+        if (fee) {
+          entry.fee = fee->toStringRepr();
+        }
+        entry.balance = balance_.toStringRepr();
+        entry.locked = locked_.toStringRepr();
+        entries_.push_back(std::move(entry));
+      }
+      return {};
+    }
+
+    std::string toCsv(const Statement &statement) {
+      std::string out =
+          "tx_hash,created_time,type,counterparty,description,amount,fee,"
+          "balance,locked\n";
+      out += fmt::format(",,opening,,,,,{},{}\n",
+                         statement.opening_balance,
+                         statement.opening_locked);
+      for (auto const &entry : statement.entries) {
+        out += fmt::format("{},{},{},{},{},{},{},{},{}\n",
+                           entry.tx_hash,
+                           formatTime(entry.created_time),
+                           entry.type,
+                           csvField(entry.counterparty),
+                           csvField(entry.description),
+                           entry.amount,
+                           entry.fee,
+                           entry.balance,
+                           entry.locked);
+      }
+      out += fmt::format(",,closing,,,,,{},{}\n",
+                         statement.closing_balance,
+                         statement.closing_locked);
+      return out;
+    }
+
+  }  // namespace statement
+}  // namespace iroha_cli
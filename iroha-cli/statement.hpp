@@ -0,0 +1,130 @@
+/**
+ * Copyright Soramitsu Co., Ltd. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+#ifndef IROHACLI_STATEMENT_HPP
+#define IROHACLI_STATEMENT_HPP
+
+#include <map>
+#include <string>
+#include <vector>
+
+#include "common/result.hpp"
+#include "interfaces/common_objects/amount.hpp"
+#include "transaction.pb.h"
+
+namespace iroha_cli {
+  namespace statement {
+
+    /// Change of the balance of the account made by a command
+    struct Entry {
+      std::string tx_hash;
+      /// creation time of the transaction in milliseconds since epoch
+      uint64_t created_time = 0;
+      /// issue, burn, grant, transfer_in, transfer_out, lock_out, claim_in,
+      /// refund_in or lock_claimed
+      std::string type;
+      /// other account of a transfer or a lock or the granter, empty for
+      /// issue and burn
+      std::string counterparty;
+      /// description of a transfer or the schedule of a grant
+      std::string description;
+      /// change of the balance, negative for outgoing amounts
+      std::string amount;
+      /// burn fee deducted from an incoming transfer, empty if there is none
+      std::string fee;
+      /// balance after the command
+      std::string balance;
+      /// amount of the account in asset locks after the command
+      std::string locked;
+    };
+
+    struct Statement {
+      std::string opening_balance;
+      std::string opening_locked;
+      std::vector<Entry> entries;
+      std::string closing_balance;
+      std::string closing_locked;
+    };
+
+    /// Burn fees charged by the transfers of a transaction by the index of
+    /// the command, as recorded by the peer on execution
+    using TransferFees =
+        std::map<shared_model::interface::types::CommandIndexType,
+                 std::string>;
+
+    /**
+     * Computes the running balance of the account from the history of the
+     * asset, which is given in the order of commit. AddAssetQuantity,
+     * SubtractAssetQuantity, GrantVestedAsset, TransferAsset and the asset
+     * locks are accounted, the other commands of the transactions are
+     * skipped. The amount locked by the account is not a part of the
+     * balance until the lock is refunded.
+     */
+    class StatementBuilder {
+     public:
+      /**
+       * @param precision - precision of the asset
+       */
+      StatementBuilder(
+          std::string account_id,
+          std::string asset_id,
+          shared_model::interface::types::PrecisionType precision);
+
+      /**
+       * Account the transaction committed before the period of the
+       * statement, it only changes the opening balance
+       * @param fees - burn fees charged by the transfers of the transaction
+       * @return error message if the balance becomes invalid
+       */
+      iroha::expected::Result<void, std::string> addBefore(
+          const iroha::protocol::Transaction &transaction,
+          const TransferFees &fees = {});
+
+      /**
+       * Account the transaction within the period of the statement, with an
+       * entry for every command changing the balance
+       * @param fees - burn fees charged by the transfers of the transaction
+       * @return error message if the balance becomes invalid
+       */
+      iroha::expected::Result<void, std::string> add(
+          const iroha::protocol::Transaction &transaction,
+          const TransferFees &fees = {});
+
+      Statement statement() const;
+
+     private:
+      /// Asset lock made by or for the account
+      struct Lock {
+        std::string src_account_id;
+        std::string dest_account_id;
+        shared_model::interface::Amount amount;
+      };
+
+      iroha::expected::Result<void, std::string> apply(
+          const iroha::protocol::Transaction &transaction,
+          const TransferFees &fees,
+          bool record);
+
+      std::string account_id_;
+      std::string asset_id_;
+      shared_model::interface::Amount opening_balance_;
+      shared_model::interface::Amount opening_locked_;
+      shared_model::interface::Amount balance_;
+      shared_model::interface::Amount locked_;
+      /// unreleased locks of the asset by the lowercase hex of the lock hash
+      std::map<std::string, Lock> locks_;
+      std::vector<Entry> entries_;
+    };
+
+    /**
+     * Statement in CSV format, one row per entry, preceded by the opening
+     * balance and followed by the closing balance rows
+     */
+    std::string toCsv(const Statement &statement);
+
+  }  // namespace statement
+}  // namespace iroha_cli
+
+#endif  // IROHACLI_STATEMENT_HPP
@@ -0,0 +1,137 @@
+/**
+ * Copyright Soramitsu Co., Ltd. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+#include "wallet.hpp"
+
+#include <algorithm>
+#include <fstream>
+#include <stdexcept>
+
+#include <fmt/core.h>
+#include <boost/filesystem/operations.hpp>
+#include "builders/protobuf/transaction.hpp"
+#include "common/files.hpp"
+#include "common/hexutils.hpp"
+#include "common/result.hpp"
+#include "converters/protobuf/json_proto_converter.hpp"
+#include "crypto/keys_manager_impl.hpp"
+#include "cryptography/crypto_provider/crypto_signer.hpp"
+#include "cryptography/ed25519_sha3_impl/crypto_provider.hpp"
+#include "datetime/time.hpp"
+
+using iroha::expected::makeError;
+using iroha::expected::Result;
+using namespace shared_model::interface::types;
+
+namespace iroha_cli {
+  namespace wallet {
+
+    std::vector<Identity> listIdentities(
+        const boost::filesystem::path &key_path) {
+      namespace fs = boost::filesystem;
+      using iroha::KeysManagerImpl;
+      std::vector<Identity> identities;
+      boost::system::error_code ec;
+      for (fs::directory_iterator it(key_path, ec), end; not ec and it != end;
+           it.increment(ec)) {
+        auto path = it->path();
+        if (path.extension() != KeysManagerImpl::kPublicKeyExtension) {
+          continue;
+        }
+        auto public_key = iroha::readTextFile(path.string());
+        auto private_key_path = fs::path(path).replace_extension(
+            KeysManagerImpl::kPrivateKeyExtension);
+        if (iroha::expected::hasValue(public_key)
+            and fs::exists(private_key_path)) {
+          identities.push_back(
+              Identity{path.stem().string(), public_key.assumeValue()});
+        }
+      }
+      std::sort(identities.begin(),
+                identities.end(),
+                [](auto const &a, auto const &b) {
+                  return a.account_id < b.account_id;
+                });
+      return identities;
+    }
+
+    Result<shared_model::crypto::Keypair, std::string> keypairFromPrivateKey(
+        const std::string &private_key_hex) {
+      auto bytes = iroha::hexstringToBytestringResult(private_key_hex);
+      if (auto e = iroha::expected::resultToOptionalError(bytes)) {
+        return makeError(fmt::format("Invalid private key: {}", e.value()));
+      }
+      return shared_model::crypto::CryptoProviderEd25519Sha3::generateKeypair(
+          shared_model::crypto::PrivateKey{bytes.assumeValue()});
+    }
+
+    Result<shared_model::proto::Transaction, std::string>
+    makeTransferTransaction(const std::string &creator_account_id,
+                            const std::string &dest_account_id,
+                            const std::string &asset_id,
+                            const std::string &amount,
+                            const std::string &description,
+                            const shared_model::crypto::Keypair &keypair) {
+      try {
+        return shared_model::proto::TransactionBuilder()
+            .creatorAccountId(creator_account_id)
+            .createdTime(iroha::time::now())
+            .quorum(1)
+            .transferAsset(creator_account_id,
+                           dest_account_id,
+                           asset_id,
+                           description,
+                           amount)
+            .build()
+            .signAndAddSignature(keypair)
+            .finish();
+      } catch (const std::invalid_argument &e) {
+        return makeError(std::string{e.what()});
+      }
+    }
+
+    Result<void, std::string> signTransaction(
+        shared_model::proto::Transaction &transaction,
+        const shared_model::crypto::Keypair &keypair) {
+      auto signature = shared_model::crypto::CryptoSigner::sign(
+          shared_model::crypto::Blob(transaction.payload()), keypair);
+      if (not transaction.addSignature(
+              SignedHexStringView{signature},
+              PublicKeyHexStringView{keypair.publicKey()})) {
+        return fmt::format("Transaction is already signed by {}",
+                           keypair.publicKey());
+      }
+      return {};
+    }
+
+    Result<shared_model::proto::Transaction, std::string> readTransaction(
+        const std::string &path) {
+      auto json = iroha::readTextFile(path);
+      if (auto e = iroha::expected::resultToOptionalError(json)) {
+        return makeError(std::move(e).value());
+      }
+      auto transport = shared_model::converters::protobuf::jsonToProto<
+          iroha::protocol::Transaction>(std::move(json).assumeValue());
+      if (auto e = iroha::expected::resultToOptionalError(transport)) {
+        return makeError(fmt::format(
+            "{} is not a valid transaction: {}", path, e.value()));
+      }
+      return shared_model::proto::Transaction(
+          std::move(transport).assumeValue());
+    }
+
+    Result<void, std::string> writeTransaction(
+        const shared_model::proto::Transaction &transaction,
+        const std::string &path) {
+      std::ofstream file(path);
+      file << shared_model::converters::protobuf::modelToJson(transaction);
+      if (not file) {
+        return fmt::format("Failed to write {}", path);
+      }
+      return {};
+    }
+
+  }  // namespace wallet
+}  // namespace iroha_cli
@@ -0,0 +1,78 @@
+/**
+ * Copyright Soramitsu Co., Ltd. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+#ifndef IROHACLI_WALLET_HPP
+#define IROHACLI_WALLET_HPP
+
+#include <string>
+#include <vector>
+
+#include <boost/filesystem/path.hpp>
+#include "backend/protobuf/transaction.hpp"
+#include "common/result_fwd.hpp"
+#include "cryptography/keypair.hpp"
+
+namespace iroha_cli {
+  namespace wallet {
+
+    /// Account which keys are stored in the key directory
+    struct Identity {
+      std::string account_id;
+      std::string public_key;
+    };
+
+    /**
+     * List identities of the key directory, i.e. pairs of <account>.pub and
+     * <account>.priv files
+     * @param key_path - key directory
+     * @return identities sorted by account id
+     */
+    std::vector<Identity> listIdentities(
+        const boost::filesystem::path &key_path);
+
+    /**
+     * Restore the keypair from a private key
+     * @param private_key_hex - hex encoded private key
+     * @return keypair or error message
+     */
+    iroha::expected::Result<shared_model::crypto::Keypair, std::string>
+    keypairFromPrivateKey(const std::string &private_key_hex);
+
+    /**
+     * Build a transaction transferring the asset and sign it with the keys of
+     * the creator, no connection to a peer is needed
+     * @return signed transaction or error message if the transaction is not
+     * valid
+     */
+    iroha::expected::Result<shared_model::proto::Transaction, std::string>
+    makeTransferTransaction(const std::string &creator_account_id,
+                            const std::string &dest_account_id,
+                            const std::string &asset_id,
+                            const std::string &amount,
+                            const std::string &description,
+                            const shared_model::crypto::Keypair &keypair);
+
+    /**
+     * Add the signature of the keypair to the transaction, e.g. to collect
+     * signatures of a multisignature account offline
+     * @return error message if the transaction is already signed by the key
+     */
+    iroha::expected::Result<void, std::string> signTransaction(
+        shared_model::proto::Transaction &transaction,
+        const shared_model::crypto::Keypair &keypair);
+
+    /// Read a transaction in protobuf JSON format
+    iroha::expected::Result<shared_model::proto::Transaction, std::string>
+    readTransaction(const std::string &path);
+
+    /// Write a transaction in protobuf JSON format
+    iroha::expected::Result<void, std::string> writeTransaction(
+        const shared_model::proto::Transaction &transaction,
+        const std::string &path);
+
+  }  // namespace wallet
+}  // namespace iroha_cli
+
+#endif  // IROHACLI_WALLET_HPP
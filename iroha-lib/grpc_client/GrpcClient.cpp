@@ -83,4 +83,36 @@ ToriiResponse GrpcClient::getTxStatus(const std::string& tx_hash)
                 &toriiResponse);
     return toriiResponse;
 }
+
+HealthcheckData GrpcClient::healthcheck()
+{
+    google::protobuf::Empty empty;
+    HealthcheckData healthcheckData;
+    grpc::ClientContext context;
+    query_stub_->Healthcheck(
+                &context,
+                empty,
+                &healthcheckData);
+    return healthcheckData;
+}
+
+grpc::Status GrpcClient::fetchCommits(
+        const BlocksQuery& query,
+        const std::function<bool(const BlockQueryResponse&)>& handler,
+        std::chrono::milliseconds timeout)
+{
+    grpc::ClientContext context;
+    context.set_deadline(std::chrono::system_clock::now() + timeout);
+    auto reader = query_stub_->FetchCommits(
+                &context,
+                query);
+    BlockQueryResponse response;
+    while (reader->Read(&response)) {
+        if (not handler(response)) {
+            context.TryCancel();
+            break;
+        }
+    }
+    return reader->Finish();
+}
 }  // namespace iroha_lib
@@ -1,23 +1,27 @@
 #pragma once
 
-#include <endpoint.grpc.pb.h>
-#include <grpc++/grpc++.h>
+#include "IrohaClient.hpp"
 
 
 namespace iroha_lib {
 
 using namespace iroha::protocol;
 
-class GrpcClient {
+class GrpcClient : public IrohaClient {
 
 public:
     GrpcClient(
             const std::string& target_ip,
             const uint16_t port);
-    grpc::Status send(const Transaction& tx);
-    grpc::Status send(const TxList& tx_list);
-    QueryResponse send(const iroha::protocol::Query& query);
-    ToriiResponse getTxStatus(const std::string& tx_hash);
+    grpc::Status send(const Transaction& tx) override;
+    grpc::Status send(const TxList& tx_list) override;
+    QueryResponse send(const iroha::protocol::Query& query) override;
+    ToriiResponse getTxStatus(const std::string& tx_hash) override;
+    HealthcheckData healthcheck() override;
+    grpc::Status fetchCommits(
+            const BlocksQuery& query,
+            const std::function<bool(const BlockQueryResponse&)>& handler,
+            std::chrono::milliseconds timeout) override;
 
 private:
     std::shared_ptr<CommandService_v1::StubInterface> command_stub_;
@@ -0,0 +1,39 @@
+#pragma once
+
+#include <chrono>
+#include <functional>
+
+#include <endpoint.grpc.pb.h>
+#include <grpc++/grpc++.h>
+
+
+namespace iroha_lib {
+
+using namespace iroha::protocol;
+
+/**
+ * Interface of a client of an Iroha peer. Application code may depend on it
+ * instead of GrpcClient, so that MockClient can be used in its unit tests.
+ */
+class IrohaClient {
+
+public:
+    virtual ~IrohaClient() = default;
+
+    virtual grpc::Status send(const Transaction& tx) = 0;
+    virtual grpc::Status send(const TxList& tx_list) = 0;
+    virtual QueryResponse send(const iroha::protocol::Query& query) = 0;
+    virtual ToriiResponse getTxStatus(const std::string& tx_hash) = 0;
+    virtual HealthcheckData healthcheck() = 0;
+
+    /**
+     * Stream the committed blocks to the handler until it returns false, the
+     * stream is closed by the peer or the timeout elapses
+     */
+    virtual grpc::Status fetchCommits(
+            const BlocksQuery& query,
+            const std::function<bool(const BlockQueryResponse&)>& handler,
+            std::chrono::milliseconds timeout) = 0;
+};
+
+}
@@ -0,0 +1,80 @@
+#include "Chain.hpp"
+
+#include "model/converters/pb_common.hpp"
+#include "cryptography/ed25519_sha3_impl/internal/ed25519_impl.hpp"
+
+
+namespace iroha_lib {
+
+Chain::Chain(
+        IrohaClient& client,
+        const std::string& account_id,
+        const iroha::keypair_t& keypair)
+    : client_(client),
+      account_id_(account_id),
+      keypair_(keypair)
+{}
+
+std::optional<ChainStatus> Chain::status(std::chrono::milliseconds timeout)
+{
+    const auto healthcheck = client_.healthcheck();
+    if (not healthcheck.has_last_block_height()) {
+        return std::nullopt;
+    }
+
+    std::optional<ChainStatus> status;
+    client_.fetchCommits(
+                blocksQuery(healthcheck.last_block_height()),
+                [&](const BlockQueryResponse& response) {
+                    if (response.has_block_response()) {
+                        const auto& block = response.block_response().block().block_v1();
+                        const auto hash = iroha::hash(block).to_hexstring();
+                        status = ChainStatus{block.payload().height(), hash, hash};
+                    }
+                    return false;
+                },
+                timeout);
+    return status;
+}
+
+bool Chain::waitForHeight(
+        uint64_t height,
+        std::chrono::milliseconds timeout)
+{
+    // blocks from the requested height are replayed before the new ones, so
+    // a block committed in between the calls is not missed
+    bool reached = false;
+    client_.fetchCommits(
+                blocksQuery(height),
+                [&](const BlockQueryResponse& response) {
+                    reached = response.has_block_response()
+                            and response.block_response().block().block_v1().payload().height() >= height;
+                    return false;
+                },
+                timeout);
+    return reached;
+}
+
+BlocksQuery Chain::blocksQuery(uint64_t from_height) const
+{
+    BlocksQuery query;
+    auto meta = query.mutable_meta();
+    meta->set_creator_account_id(account_id_);
+    meta->set_query_counter(1);
+    meta->set_created_time(
+                std::chrono::duration_cast<std::chrono::milliseconds>(
+                    std::chrono::system_clock::now().time_since_epoch()).count());
+    query.set_from_height(from_height);
+
+    // the signed payload is the query without the signature
+    auto signature = iroha::sign(
+                iroha::sha3_256(query.SerializeAsString()).to_string(),
+                keypair_.pubkey,
+                keypair_.privkey);
+    auto sig = query.mutable_signature();
+    sig->set_signature(signature.to_hexstring());
+    sig->set_public_key(keypair_.pubkey.to_hexstring());
+    return query;
+}
+
+}
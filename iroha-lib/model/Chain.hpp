@@ -0,0 +1,49 @@
+#pragma once
+
+#include <chrono>
+#include <optional>
+#include <string>
+
+#include "crypto/keypair.hpp"
+#include "grpc_client/IrohaClient.hpp"
+
+
+namespace iroha_lib {
+
+struct ChainStatus {
+    uint64_t height;
+    std::string latest_block_hash;
+    /// Committed blocks are final in Iroha, so it is the latest block hash
+    std::string finalized_block_hash;
+};
+
+/**
+ * Observes the chain of a peer through the block stream. The account needs
+ * the can_get_blocks permission.
+ */
+class Chain {
+
+public:
+    Chain(
+            IrohaClient& client,
+            const std::string& account_id,
+            const iroha::keypair_t& keypair);
+
+    /// Height and hash of the top block, nullopt if the peer does not know them
+    std::optional<ChainStatus> status(
+            std::chrono::milliseconds timeout = std::chrono::seconds(10));
+
+    /// Wait until a block of the given height is committed, false on timeout or error
+    bool waitForHeight(
+            uint64_t height,
+            std::chrono::milliseconds timeout = std::chrono::seconds(60));
+
+private:
+    BlocksQuery blocksQuery(uint64_t from_height) const;
+
+    IrohaClient& client_;
+    std::string account_id_;
+    iroha::keypair_t keypair_;
+};
+
+}
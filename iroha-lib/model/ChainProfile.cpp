@@ -0,0 +1,96 @@
+#include "ChainProfile.hpp"
+
+#include <stdexcept>
+
+#include "grpc_client/GrpcClient.hpp"
+
+
+namespace iroha_lib {
+
+ChainRegistry& ChainRegistry::add(const ChainProfile& profile)
+{
+    if (profile.name.empty() or profile.chain_id.empty()) {
+        throw std::invalid_argument("chain profile: name and chain id must be set");
+    }
+    if (profile.peers.empty()) {
+        throw std::invalid_argument("chain profile " + profile.name + ": no peers");
+    }
+    for (const auto& [name, known] : profiles_) {
+        if (name == profile.name or known.chain_id == profile.chain_id) {
+            throw std::invalid_argument(
+                        "chain profile " + profile.name + " duplicates " + name);
+        }
+    }
+    profiles_.emplace(profile.name, profile);
+    return *this;
+}
+
+ChainRegistry& ChainRegistry::use(const std::string& name)
+{
+    profile(name);
+    current_ = name;
+    return *this;
+}
+
+const ChainProfile& ChainRegistry::profile(const std::string& name) const
+{
+    auto it = profiles_.find(name);
+    if (it == profiles_.end()) {
+        throw std::invalid_argument("unknown chain profile " + name);
+    }
+    return it->second;
+}
+
+const ChainProfile& ChainRegistry::current() const
+{
+    if (not current_) {
+        throw std::runtime_error("no chain profile is selected");
+    }
+    return profile(*current_);
+}
+
+std::unique_ptr<IrohaClient> ChainRegistry::connect() const
+{
+    const auto& chain = current();
+    for (const auto& peer : chain.peers) {
+        auto client = std::make_unique<GrpcClient>(peer.host, peer.port);
+        const auto healthcheck = client->healthcheck();
+        if (not healthcheck.has_chain_id()) {
+            // unreachable, or the chain id is not set in the ledger yet
+            continue;
+        }
+        if (healthcheck.chain_id() != chain.chain_id) {
+            throw std::runtime_error(
+                        "peer " + peer.host + ":" + std::to_string(peer.port)
+                        + " of profile " + chain.name + " belongs to chain "
+                        + healthcheck.chain_id() + " instead of " + chain.chain_id);
+        }
+        return client;
+    }
+    throw std::runtime_error(
+                "no peer of profile " + chain.name + " announces chain " + chain.chain_id);
+}
+
+Tx ChainRegistry::tx(const iroha::keypair_t& keypair) const
+{
+    const auto& chain = current();
+    if (chain.default_account.empty()) {
+        throw std::invalid_argument("chain profile " + chain.name + ": no default account");
+    }
+    Tx tx(chain.default_account, keypair);
+    tx.chainId(chain.chain_id);
+    return tx;
+}
+
+void ChainRegistry::check(const iroha::protocol::Transaction& tx) const
+{
+    const auto& chain = current();
+    const auto& chain_id = tx.payload().reduced_payload().chain_id();
+    if (chain_id != chain.chain_id) {
+        throw std::invalid_argument(
+                    "transaction is bound to chain " + (chain_id.empty() ? "<none>" : chain_id)
+                    + " instead of " + chain.chain_id + " of profile " + chain.name);
+    }
+}
+
+}
@@ -0,0 +1,70 @@
+#pragma once
+
+#include <map>
+#include <memory>
+#include <optional>
+#include <string>
+#include <vector>
+
+#include "crypto/keypair.hpp"
+#include "grpc_client/IrohaClient.hpp"
+#include "Tx.hpp"
+
+
+namespace iroha_lib {
+
+struct PeerAddress {
+    std::string host;
+    uint16_t port;
+};
+
+/**
+ * Everything the application needs to talk to one network: the chain id its
+ * peers announce, the peers themselves and the account acting by default
+ */
+struct ChainProfile {
+    std::string name;
+    std::string chain_id;
+    std::vector<PeerAddress> peers;
+    std::string default_account;
+};
+
+/**
+ * Profiles of the networks known to the application, for example staging and
+ * production. Transactions and clients are made for the current profile only,
+ * and it is switched explicitly with use(), so one network can't be used by
+ * mistake instead of another. Errors are reported with std::invalid_argument
+ * and std::runtime_error.
+ */
+class ChainRegistry {
+
+public:
+    /// Register a profile, names and chain ids are unique
+    ChainRegistry& add(const ChainProfile& profile);
+
+    /// Make the named profile current
+    ChainRegistry& use(const std::string& name);
+
+    const ChainProfile& profile(const std::string& name) const;
+    /// Throws std::runtime_error if no profile is selected with use()
+    const ChainProfile& current() const;
+
+    /**
+     * Client of the first peer of the current profile that announces its chain
+     * id. Throws std::runtime_error if a peer announces another chain id or no
+     * peer announces any.
+     */
+    std::unique_ptr<IrohaClient> connect() const;
+
+    /// Transaction of the default account bound to the chain of the current profile
+    Tx tx(const iroha::keypair_t& keypair) const;
+
+    /// Throws std::invalid_argument if the transaction is not bound to the current chain
+    void check(const iroha::protocol::Transaction& tx) const;
+
+private:
+    std::map<std::string, ChainProfile> profiles_;
+    std::optional<std::string> current_;
+};
+
+}
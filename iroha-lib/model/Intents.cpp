@@ -0,0 +1,193 @@
+#include "Intents.hpp"
+
+#include <regex>
+#include <stdexcept>
+
+#include "Tx.hpp"
+
+
+namespace iroha_lib {
+
+namespace {
+
+const std::regex kAccountIdPattern{R"([a-z_0-9]{1,32}@[a-zA-Z0-9.-]+)"};
+const std::regex kAssetIdPattern{R"([a-z_0-9]{1,32}#[a-zA-Z0-9.-]+)"};
+const std::regex kAmountPattern{R"(\d+(\.\d+)?)"};
+
+const std::string& require(
+        const std::optional<std::string>& value,
+        const std::string& intent,
+        const std::string& field)
+{
+    if (not value) {
+        throw std::invalid_argument(intent + ": " + field + " is not set");
+    }
+    return *value;
+}
+
+void check(
+        const std::string& value,
+        const std::regex& pattern,
+        const std::string& intent,
+        const std::string& field)
+{
+    if (not std::regex_match(value, pattern)) {
+        throw std::invalid_argument(
+                    intent + ": malformed " + field + " '" + value + "'");
+    }
+}
+
+void checkAmount(
+        const std::string& amount,
+        const std::string& intent)
+{
+    check(amount, kAmountPattern, intent, "amount");
+    if (amount.find_first_not_of("0.") == std::string::npos) {
+        throw std::invalid_argument(intent + ": amount must be positive");
+    }
+}
+
+}
+
+
+TransferIntent::TransferIntent(
+        Tx& tx,
+        const std::string& asset_id)
+    : tx_(tx),
+      asset_id_(asset_id)
+{}
+
+TransferIntent& TransferIntent::amount(const std::string& amount)
+{
+    amount_ = amount;
+    return *this;
+}
+
+TransferIntent& TransferIntent::amount(uint64_t amount)
+{
+    return this->amount(std::to_string(amount));
+}
+
+TransferIntent& TransferIntent::from(const std::string& account_id)
+{
+    src_account_id_ = account_id;
+    return *this;
+}
+
+TransferIntent& TransferIntent::to(const std::string& account_id)
+{
+    dest_account_id_ = account_id;
+    return *this;
+}
+
+TransferIntent& TransferIntent::description(const std::string& description)
+{
+    description_ = description;
+    return *this;
+}
+
+Tx& TransferIntent::add()
+{
+    const auto& amount = require(amount_, "transfer", "amount");
+    const auto& src = require(src_account_id_, "transfer", "source account");
+    const auto& dest = require(dest_account_id_, "transfer", "destination account");
+    check(asset_id_, kAssetIdPattern, "transfer", "asset id");
+    check(src, kAccountIdPattern, "transfer", "source account id");
+    check(dest, kAccountIdPattern, "transfer", "destination account id");
+    checkAmount(amount, "transfer");
+    if (src == dest) {
+        throw std::invalid_argument("transfer: source and destination accounts are the same");
+    }
+    return tx_.transferAsset(
+                src,
+                dest,
+                asset_id_,
+                description_,
+                amount);
+}
+
+
+MintIntent::MintIntent(
+        Tx& tx,
+        const std::string& asset_id)
+    : tx_(tx),
+      asset_id_(asset_id)
+{}
+
+MintIntent& MintIntent::amount(const std::string& amount)
+{
+    amount_ = amount;
+    return *this;
+}
+
+MintIntent& MintIntent::amount(uint64_t amount)
+{
+    return this->amount(std::to_string(amount));
+}
+
+Tx& MintIntent::add()
+{
+    const auto& amount = require(amount_, "mint", "amount");
+    check(asset_id_, kAssetIdPattern, "mint", "asset id");
+    checkAmount(amount, "mint");
+    return tx_.addAssetQuantity(
+                asset_id_,
+                amount);
+}
+
+
+BurnIntent::BurnIntent(
+        Tx& tx,
+        const std::string& asset_id)
+    : tx_(tx),
+      asset_id_(asset_id)
+{}
+
+BurnIntent& BurnIntent::amount(const std::string& amount)
+{
+    amount_ = amount;
+    return *this;
+}
+
+BurnIntent& BurnIntent::amount(uint64_t amount)
+{
+    return this->amount(std::to_string(amount));
+}
+
+Tx& BurnIntent::add()
+{
+    const auto& amount = require(amount_, "burn", "amount");
+    check(asset_id_, kAssetIdPattern, "burn", "asset id");
+    checkAmount(amount, "burn");
+    return tx_.subtractAssetQuantity(
+                asset_id_,
+                amount);
+}
+
+
+GrantIntent::GrantIntent(
+        Tx& tx,
+        iroha::protocol::GrantablePermission permission)
+    : tx_(tx),
+      permission_(permission)
+{}
+
+GrantIntent& GrantIntent::to(const std::string& account_id)
+{
+    account_id_ = account_id;
+    return *this;
+}
+
+Tx& GrantIntent::add()
+{
+    const auto& account_id = require(account_id_, "grant", "account");
+    check(account_id, kAccountIdPattern, "grant", "account id");
+    if (not iroha::protocol::GrantablePermission_IsValid(permission_)) {
+        throw std::invalid_argument("grant: unknown permission");
+    }
+    return tx_.grantPermission(
+                account_id,
+                permission_);
+}
+
+}
@@ -0,0 +1,101 @@
+#ifndef INTENTS_HPP
+#define INTENTS_HPP
+
+#include <cstdint>
+#include <optional>
+#include <string>
+
+#include "primitive.pb.h"
+
+
+namespace iroha_lib {
+
+class Tx;
+
+/**
+ * Intents are a fluent way to describe the most common operations without
+ * knowing the exact commands behind them. An intent is filled in step by step
+ * and compiled into a command of the transaction by add(), which validates the
+ * collected values locally and throws std::invalid_argument if they are
+ * incomplete or malformed.
+ */
+class TransferIntent {
+
+private:
+    Tx& tx_;
+    std::string asset_id_;
+    std::optional<std::string> amount_;
+    std::optional<std::string> src_account_id_;
+    std::optional<std::string> dest_account_id_;
+    std::string description_;
+
+public:
+    TransferIntent(
+            Tx& tx,
+            const std::string& asset_id);
+
+    TransferIntent& amount(const std::string& amount);
+    TransferIntent& amount(uint64_t amount);
+    TransferIntent& from(const std::string& account_id);
+    TransferIntent& to(const std::string& account_id);
+    TransferIntent& description(const std::string& description);
+
+    Tx& add();
+};
+
+class MintIntent {
+
+private:
+    Tx& tx_;
+    std::string asset_id_;
+    std::optional<std::string> amount_;
+
+public:
+    MintIntent(
+            Tx& tx,
+            const std::string& asset_id);
+
+    MintIntent& amount(const std::string& amount);
+    MintIntent& amount(uint64_t amount);
+
+    Tx& add();
+};
+
+class BurnIntent {
+
+private:
+    Tx& tx_;
+    std::string asset_id_;
+    std::optional<std::string> amount_;
+
+public:
+    BurnIntent(
+            Tx& tx,
+            const std::string& asset_id);
+
+    BurnIntent& amount(const std::string& amount);
+    BurnIntent& amount(uint64_t amount);
+
+    Tx& add();
+};
+
+class GrantIntent {
+
+private:
+    Tx& tx_;
+    iroha::protocol::GrantablePermission permission_;
+    std::optional<std::string> account_id_;
+
+public:
+    GrantIntent(
+            Tx& tx,
+            iroha::protocol::GrantablePermission permission);
+
+    GrantIntent& to(const std::string& account_id);
+
+    Tx& add();
+};
+
+}
+
+#endif
@@ -18,6 +18,21 @@ void Tx::addCommand(const iroha::protocol::Command& command)
             iroha::protocol::Command(command);
 }
 
+Tx& Tx::chainId(const std::string& chain_id)
+{
+    protobuf_transaction_.mutable_payload()->mutable_reduced_payload()->set_chain_id(chain_id);
+    return *this;
+}
+
+Tx& Tx::chainId(IrohaClient& client)
+{
+    const auto healthcheck = client.healthcheck();
+    if (healthcheck.has_chain_id()) {
+        chainId(healthcheck.chain_id());
+    }
+    return *this;
+}
+
 Tx& Tx::addAssetQuantity(
         const std::string& asset_id,
         const std::string& amount)
@@ -223,6 +238,26 @@ Tx& Tx::compareAndSetAccountDetail(
     return *this;
 }
 
+TransferIntent Tx::transfer(const std::string& asset_id)
+{
+    return TransferIntent(*this, asset_id);
+}
+
+MintIntent Tx::mint(const std::string& asset_id)
+{
+    return MintIntent(*this, asset_id);
+}
+
+BurnIntent Tx::burn(const std::string& asset_id)
+{
+    return BurnIntent(*this, asset_id);
+}
+
+GrantIntent Tx::grant(const iroha::protocol::GrantablePermission permission)
+{
+    return GrantIntent(*this, permission);
+}
+
 const iroha::protocol::Transaction Tx::signAndAddSignature()
 {
     auto signature = iroha::sign(
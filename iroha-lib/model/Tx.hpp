@@ -5,6 +5,8 @@
 #include <boost/bimap.hpp>
 #include "crypto/keypair.hpp"
 #include "generators/CommandGenerator.hpp"
+#include "grpc_client/IrohaClient.hpp"
+#include "Intents.hpp"
 
 
 namespace iroha_lib {
@@ -32,6 +34,11 @@ public:
 
     void addCommand(const iroha::protocol::Command& command);
 
+    /// Bind the transaction to a network, peers of other networks reject it
+    Tx& chainId(const std::string& chain_id);
+    /// Bind the transaction to the network announced by the peer, if any
+    Tx& chainId(IrohaClient& client);
+
     Tx& addAssetQuantity(
             const std::string& account_id,
             const std::string& role_name);
@@ -97,6 +104,11 @@ public:
             const std::optional<std::string>& old_value,
             bool check_empty);
 
+    TransferIntent transfer(const std::string& asset_id);
+    MintIntent mint(const std::string& asset_id);
+    BurnIntent burn(const std::string& asset_id);
+    GrantIntent grant(const iroha::protocol::GrantablePermission permission);
+
     const iroha::protocol::Transaction signAndAddSignature();
 };
 
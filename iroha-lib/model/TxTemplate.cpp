@@ -0,0 +1,114 @@
+#include "TxTemplate.hpp"
+
+#include <regex>
+#include <stdexcept>
+
+#include <google/protobuf/util/json_util.h>
+
+
+namespace iroha_lib {
+
+namespace {
+
+const std::regex kParameter(R"(\{\{([A-Za-z0-9_]+)\}\})");
+
+std::string escapeJson(const std::string& value)
+{
+    std::string escaped;
+    for (char c : value) {
+        if (c == '"' or c == '\\') {
+            escaped += '\\';
+        } else if (static_cast<unsigned char>(c) < 0x20) {
+            throw std::invalid_argument("template: control character in a parameter value");
+        }
+        escaped += c;
+    }
+    return escaped;
+}
+
+}
+
+TxTemplate& TxTemplate::addCommand(const iroha::protocol::Command& command)
+{
+    *commands_.add_commands() = command;
+    return *this;
+}
+
+TxTemplate TxTemplate::fromTransaction(const iroha::protocol::Transaction& tx)
+{
+    TxTemplate result;
+    for (const auto& command : tx.payload().reduced_payload().commands()) {
+        result.addCommand(command);
+    }
+    return result;
+}
+
+TxTemplate TxTemplate::fromJson(const std::string& json)
+{
+    TxTemplate result;
+    auto status = google::protobuf::util::JsonStringToMessage(json, &result.commands_);
+    if (not status.ok()) {
+        throw std::invalid_argument("template: " + status.ToString());
+    }
+    if (result.commands_.commands().empty()) {
+        throw std::invalid_argument("template: no commands");
+    }
+    // only the commands are taken from the stored template
+    auto commands = std::move(*result.commands_.mutable_commands());
+    result.commands_.Clear();
+    *result.commands_.mutable_commands() = std::move(commands);
+    return result;
+}
+
+std::string TxTemplate::toJson() const
+{
+    std::string json;
+    google::protobuf::util::JsonPrintOptions options;
+    options.add_whitespace = true;
+    google::protobuf::util::MessageToJsonString(commands_, &json, options);
+    return json;
+}
+
+std::set<std::string> TxTemplate::parameters() const
+{
+    std::set<std::string> names;
+    const auto json = toJson();
+    for (std::sregex_iterator it(json.begin(), json.end(), kParameter), end; it != end; ++it) {
+        names.insert((*it)[1].str());
+    }
+    return names;
+}
+
+Tx& TxTemplate::instantiate(
+        Tx& tx,
+        const std::map<std::string, std::string>& values) const
+{
+    const auto names = parameters();
+    for (const auto& name : names) {
+        if (values.count(name) == 0) {
+            throw std::invalid_argument("template: parameter " + name + " is not set");
+        }
+    }
+    for (const auto& [name, value] : values) {
+        if (names.count(name) == 0) {
+            throw std::invalid_argument("template: unknown parameter " + name);
+        }
+    }
+
+    const auto json = toJson();
+    std::string substituted;
+    auto last = json.cbegin();
+    for (std::sregex_iterator it(json.begin(), json.end(), kParameter), end; it != end; ++it) {
+        substituted.append(last, (*it)[0].first);
+        substituted += escapeJson(values.at((*it)[1].str()));
+        last = (*it)[0].second;
+    }
+    substituted.append(last, json.cend());
+
+    for (const auto& command : fromJson(substituted).commands_.commands()) {
+        tx.addCommand(command);
+    }
+    return tx;
+}
+
+}
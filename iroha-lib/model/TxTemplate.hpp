@@ -0,0 +1,48 @@
+#pragma once
+
+#include <map>
+#include <set>
+#include <string>
+
+#include "transaction.pb.h"
+#include "Tx.hpp"
+
+
+namespace iroha_lib {
+
+/**
+ * Recurring set of commands, such as a payroll run or an issuance, with
+ * parameters in place of the values that change between the runs. A
+ * parameter is written as {{name}} inside a string value of a command, for
+ * example the amount of a transfer. Templates are stored as JSON and
+ * instantiated into transactions with the actual values. Errors are reported
+ * with std::invalid_argument.
+ */
+class TxTemplate {
+
+public:
+    TxTemplate& addCommand(const iroha::protocol::Command& command);
+
+    /// Template with the commands of the transaction, which may contain parameters
+    static TxTemplate fromTransaction(const iroha::protocol::Transaction& tx);
+
+    static TxTemplate fromJson(const std::string& json);
+    std::string toJson() const;
+
+    /// Names of the parameters used by the commands
+    std::set<std::string> parameters() const;
+
+    /**
+     * Add the commands to the transaction with the parameters replaced by the
+     * given values. Every parameter of the template must be given, and only
+     * them, so a misspelled name is not left in the ledger as is.
+     */
+    Tx& instantiate(
+            Tx& tx,
+            const std::map<std::string, std::string>& values) const;
+
+private:
+    iroha::protocol::Transaction::Payload::ReducedPayload commands_;
+};
+
+}
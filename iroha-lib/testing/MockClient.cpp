@@ -0,0 +1,124 @@
+#include "MockClient.hpp"
+
+
+namespace iroha_lib {
+
+grpc::Status MockClient::send(const Transaction& tx)
+{
+    sent_transactions_.push_back(tx);
+    return send_status_;
+}
+
+grpc::Status MockClient::send(const TxList& tx_list)
+{
+    sent_transactions_.insert(
+                sent_transactions_.end(),
+                tx_list.transactions().begin(),
+                tx_list.transactions().end());
+    return send_status_;
+}
+
+QueryResponse MockClient::send(const iroha::protocol::Query& query)
+{
+    sent_queries_.push_back(query);
+    if (not query_responses_.empty()) {
+        auto response = std::move(query_responses_.front());
+        query_responses_.pop_front();
+        return response;
+    }
+    if (query_handler_) {
+        return query_handler_(query);
+    }
+    return QueryResponse();
+}
+
+ToriiResponse MockClient::getTxStatus(const std::string& tx_hash)
+{
+    ToriiResponse response;
+    response.set_tx_hash(tx_hash);
+    const auto status = tx_statuses_.find(tx_hash);
+    response.set_tx_status(
+                status == tx_statuses_.end()
+                ? TxStatus::NOT_RECEIVED
+                : status->second);
+    return response;
+}
+
+HealthcheckData MockClient::healthcheck()
+{
+    return healthcheck_;
+}
+
+grpc::Status MockClient::fetchCommits(
+        const BlocksQuery& query,
+        const std::function<bool(const BlockQueryResponse&)>& handler,
+        std::chrono::milliseconds)
+{
+    for (const auto& response: block_responses_) {
+        if (response.has_block_response()
+                and response.block_response().block().block_v1().payload().height()
+                < query.from_height()) {
+            continue;
+        }
+        if (not handler(response)) {
+            return grpc::Status::CANCELLED;
+        }
+    }
+    return grpc::Status::OK;
+}
+
+MockClient& MockClient::addQueryResponse(QueryResponse response)
+{
+    query_responses_.push_back(std::move(response));
+    return *this;
+}
+
+MockClient& MockClient::setQueryHandler(QueryHandler handler)
+{
+    query_handler_ = std::move(handler);
+    return *this;
+}
+
+MockClient& MockClient::setTxStatus(
+        const std::string& tx_hash,
+        TxStatus status)
+{
+    tx_statuses_[tx_hash] = status;
+    return *this;
+}
+
+MockClient& MockClient::setHealthcheck(HealthcheckData data)
+{
+    healthcheck_ = std::move(data);
+    return *this;
+}
+
+MockClient& MockClient::addBlockResponse(BlockQueryResponse response)
+{
+    block_responses_.push_back(std::move(response));
+    return *this;
+}
+
+MockClient& MockClient::setSendStatus(grpc::Status status)
+{
+    send_status_ = std::move(status);
+    return *this;
+}
+
+const std::vector<Transaction>& MockClient::sentTransactions() const
+{
+    return sent_transactions_;
+}
+
+const std::vector<iroha::protocol::Query>& MockClient::sentQueries() const
+{
+    return sent_queries_;
+}
+
+void MockClient::clear()
+{
+    sent_transactions_.clear();
+    sent_queries_.clear();
+}
+
+}  // namespace iroha_lib
@@ -0,0 +1,74 @@
+#pragma once
+
+#include <deque>
+#include <functional>
+#include <unordered_map>
+#include <vector>
+
+#include "grpc_client/IrohaClient.hpp"
+
+
+namespace iroha_lib {
+
+/**
+ * In-process client for unit tests of applications which use IrohaClient.
+ * Sent transactions and queries are captured, queries are answered with the
+ * programmed responses and statuses of transactions are looked up in the
+ * programmed ones.
+ */
+class MockClient : public IrohaClient {
+
+public:
+    using QueryHandler = std::function<QueryResponse(const iroha::protocol::Query&)>;
+
+    grpc::Status send(const Transaction& tx) override;
+    grpc::Status send(const TxList& tx_list) override;
+    QueryResponse send(const iroha::protocol::Query& query) override;
+    ToriiResponse getTxStatus(const std::string& tx_hash) override;
+    HealthcheckData healthcheck() override;
+    grpc::Status fetchCommits(
+            const BlocksQuery& query,
+            const std::function<bool(const BlockQueryResponse&)>& handler,
+            std::chrono::milliseconds timeout) override;
+
+    /// Answer the next query with the response, responses are used in the order of adding
+    MockClient& addQueryResponse(QueryResponse response);
+
+    /// Answer the queries with the handler once the added responses run out
+    MockClient& setQueryHandler(QueryHandler handler);
+
+    /// Return the status on requests of the transaction, NOT_RECEIVED is returned for unknown ones
+    MockClient& setTxStatus(
+            const std::string& tx_hash,
+            TxStatus status);
+
+    /// Return the data on healthcheck requests
+    MockClient& setHealthcheck(HealthcheckData data);
+
+    /// Stream the block responses to every fetchCommits call, blocks below from_height of the query are skipped
+    MockClient& addBlockResponse(BlockQueryResponse response);
+
+    /// Return the status on submissions, e.g. UNAVAILABLE to simulate a peer which is down
+    MockClient& setSendStatus(grpc::Status status);
+
+    /// Transactions sent so far, transactions of the lists are included one by one
+    const std::vector<Transaction>& sentTransactions() const;
+
+    /// Queries sent so far
+    const std::vector<iroha::protocol::Query>& sentQueries() const;
+
+    /// Forget the sent transactions and queries
+    void clear();
+
+private:
+    std::deque<QueryResponse> query_responses_;
+    QueryHandler query_handler_;
+    std::unordered_map<std::string, TxStatus> tx_statuses_;
+    HealthcheckData healthcheck_;
+    std::vector<BlockQueryResponse> block_responses_;
+    grpc::Status send_status_;
+    std::vector<Transaction> sent_transactions_;
+    std::vector<iroha::protocol::Query> sent_queries_;
+};
+
+}
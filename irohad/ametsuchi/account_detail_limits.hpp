@@ -0,0 +1,48 @@
+/**
+ * Copyright Soramitsu Co., Ltd. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+#ifndef IROHA_ACCOUNT_DETAIL_LIMITS_HPP
+#define IROHA_ACCOUNT_DETAIL_LIMITS_HPP
+
+#include <cstdint>
+#include <string>
+#include <string_view>
+
+#include "common/result.hpp"
+#include "interfaces/common_objects/types.hpp"
+
+namespace iroha {
+  namespace ametsuchi {
+
+    /// setting key of the maximum number of details of an account, counted
+    /// over all writers
+    extern const shared_model::interface::types::SettingKeyType
+        kMaxAccountDetailKeysKey;
+
+    /// setting key of the maximum size of an account detail value in bytes
+    extern const shared_model::interface::types::SettingKeyType
+        kMaxAccountDetailValueSizeKey;
+
+    /// setting key of the maximum nesting depth of JSON account details, set
+    /// with SetAccountDetailAtPath
+    extern const shared_model::interface::types::SettingKeyType
+        kMaxAccountDetailDepthKey;
+
+    /// whether the setting key is one of the account detail limits
+    bool isAccountDetailLimitKey(
+        const shared_model::interface::types::SettingKeyType &key);
+
+    /**
+     * Parse the value of an account detail limit setting
+     * @param value - decimal unsigned integer
+     * @return the limit or error message
+     */
+    expected::Result<uint64_t, std::string> parseAccountDetailLimit(
+        std::string_view value);
+
+  }  // namespace ametsuchi
+}  // namespace iroha
+
+#endif  // IROHA_ACCOUNT_DETAIL_LIMITS_HPP
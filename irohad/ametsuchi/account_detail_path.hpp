@@ -0,0 +1,55 @@
+/**
+ * Copyright Soramitsu Co., Ltd. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+#ifndef IROHA_ACCOUNT_DETAIL_PATH_HPP
+#define IROHA_ACCOUNT_DETAIL_PATH_HPP
+
+#include <cstdint>
+#include <optional>
+#include <string>
+#include <string_view>
+
+#include "common/result.hpp"
+
+namespace iroha {
+  namespace ametsuchi {
+
+    struct AccountDetailPathError {
+      enum class Code {
+        /// the value at the path does not match the expected one
+        kOldValueMismatch,
+        /// the detail or a value on the path is not a JSON object, or the
+        /// value is not JSON
+        kNotAnObject,
+        /// the detail would be nested deeper than allowed
+        kTooDeep,
+      };
+
+      Code code;
+      std::string message;
+    };
+
+    /**
+     * Set the JSON value at the path inside of the account detail. Missing
+     * objects on the path are created.
+     * @param detail - current value of the detail, absent detail is treated
+     * as an empty object
+     * @param path - dot separated keys, validated statelessly
+     * @param value - JSON value to set
+     * @param old_value - JSON value expected at the path, if any
+     * @param max_depth - maximum nesting depth of the resulting detail
+     * @return the new value of the detail or error
+     */
+    expected::Result<std::string, AccountDetailPathError>
+    setAccountDetailAtPath(std::optional<std::string_view> detail,
+                           std::string_view path,
+                           std::string_view value,
+                           std::optional<std::string_view> old_value,
+                           std::optional<uint64_t> max_depth);
+
+  }  // namespace ametsuchi
+}  // namespace iroha
+
+#endif  // IROHA_ACCOUNT_DETAIL_PATH_HPP
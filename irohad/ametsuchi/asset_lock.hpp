@@ -0,0 +1,39 @@
+/**
+ * Copyright Soramitsu Co., Ltd. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+#ifndef IROHA_ASSET_LOCK_HPP
+#define IROHA_ASSET_LOCK_HPP
+
+#include <string>
+#include <string_view>
+
+#include "common/hexutils.hpp"
+#include "common/result.hpp"
+#include "cryptography/hash_providers/sha3_256.hpp"
+
+namespace iroha {
+  namespace ametsuchi {
+
+    /**
+     * Hash identifying the asset lock which is claimed by the preimage
+     * @param preimage_hex hex string of the preimage
+     * @return hex string of SHA3-256 of the preimage bytes
+     */
+    inline expected::Result<std::string, std::string> assetLockHash(
+        std::string_view preimage_hex) {
+      auto preimage = hexstringToBytestringResult(preimage_hex);
+      if (auto e = expected::resultToOptionalError(preimage)) {
+        return expected::makeError(std::string{"Malformed preimage: "} + *e);
+      }
+      return expected::makeValue(
+          shared_model::crypto::Sha3_256::makeHash(
+              shared_model::crypto::Blob(preimage.assumeValue()))
+              .hex());
+    }
+
+  }  // namespace ametsuchi
+}  // namespace iroha
+
+#endif  // IROHA_ASSET_LOCK_HPP
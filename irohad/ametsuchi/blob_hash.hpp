@@ -0,0 +1,25 @@
+/**
+ * Copyright Soramitsu Co., Ltd. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+#ifndef IROHA_BLOB_HASH_HPP
+#define IROHA_BLOB_HASH_HPP
+
+#include <string>
+#include <string_view>
+
+namespace iroha {
+  namespace ametsuchi {
+
+    /**
+     * Hash of a private payload which is stored as an account detail instead
+     * of the payload and checked by VerifyBlob query
+     * @return lowercase hex of SHA3-256 of the salt followed by the payload
+     */
+    std::string saltedBlobHash(std::string_view salt, std::string_view blob);
+
+  }  // namespace ametsuchi
+}  // namespace iroha
+
+#endif  // IROHA_BLOB_HASH_HPP
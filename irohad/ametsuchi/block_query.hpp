@@ -24,6 +24,7 @@ namespace iroha {
         enum class Code {
           kNoBlock,
           kInternalError,
+          kPruned,
         };
         Code code;
         std::string message;
@@ -50,6 +51,14 @@ namespace iroha {
       virtual shared_model::interface::types::HeightType
       getTopBlockHeight() = 0;
 
+      /**
+       * Get height of the oldest block kept besides the genesis block, the
+       * blocks between them are pruned and can not be retrieved.
+       * @return height, or std::nullopt if every block is kept
+       */
+      virtual std::optional<shared_model::interface::types::HeightType>
+      getFirstRetainedHeight() = 0;
+
       /**
        * Reloads blockstore
        */
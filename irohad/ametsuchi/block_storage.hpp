@@ -10,6 +10,8 @@
 #include <cstdint>
 #include <functional>
 #include <memory>
+#include <optional>
+#include <string>
 
 #include "common/result_fwd.hpp"
 #include "interfaces/iroha_internal/block.hpp"
@@ -61,9 +63,41 @@ namespace iroha {
       virtual expected::Result<void, std::string> forEach(
           FunctionType function) const = 0;
 
+      /**
+       * Get the height of the oldest block kept besides the genesis block,
+       * the blocks between them were pruned
+       * @return the height, or std::nullopt if no block is pruned
+       */
+      virtual std::optional<shared_model::interface::types::HeightType>
+      firstRetainedHeight() const {
+        return std::nullopt;
+      }
+
       virtual ~BlockStorage() = default;
     };
 
+    /**
+     * @param first_retained - the oldest block kept besides the genesis block
+     * @param height - height of the block
+     * @return true if the block was pruned
+     */
+    inline bool isPrunedBlock(
+        std::optional<shared_model::interface::types::HeightType>
+            first_retained,
+        shared_model::interface::types::HeightType height) {
+      return first_retained and height > 1 and height < *first_retained;
+    }
+
+    /// @return the description of the error of getting a pruned block
+    inline std::string prunedBlockMessage(
+        shared_model::interface::types::HeightType height,
+        shared_model::interface::types::HeightType first_retained) {
+      return "Block " + std::to_string(height)
+          + " is pruned, the peer keeps the genesis block and the blocks "
+            "from height "
+          + std::to_string(first_retained);
+    }
+
   }  // namespace ametsuchi
 }  // namespace iroha
 
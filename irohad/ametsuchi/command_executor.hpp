@@ -13,6 +13,7 @@
 namespace shared_model {
   namespace interface {
     class Command;
+    class Transaction;
   }  // namespace interface
 }  // namespace shared_model
 
@@ -57,6 +58,29 @@ namespace iroha {
           shared_model::interface::types::CommandIndexType cmd_index,
           bool do_validation) = 0;
 
+      /**
+       * Remember the idempotency key of the transaction until the
+       * IdempotencyWindow setting passes since its created time, and forget
+       * the expired keys of its creator
+       * @param transaction - transaction with a non-empty idempotency key
+       * @param do_validation - if true, fail when a transaction of the same
+       * creator with another hash has used the key within the window
+       * @return error with code 3 if the key is used
+       */
+      virtual CommandResult recordIdempotencyKey(
+          const shared_model::interface::Transaction &transaction,
+          bool do_validation) = 0;
+
+      /**
+       * Check that the transaction is signed for the chain set by the ChainId
+       * setting. Once the setting is set, transactions without a chain id are
+       * rejected too, and while it is unset only such transactions pass.
+       * @param transaction - transaction to check
+       * @return error with code 3 if the chain ids differ
+       */
+      virtual CommandResult checkChainId(
+          const shared_model::interface::Transaction &transaction) = 0;
+
       virtual void skipChanges() = 0;
 
       virtual DatabaseTransaction &dbSession() = 0;
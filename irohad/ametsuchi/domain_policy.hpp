@@ -0,0 +1,48 @@
+/**
+ * Copyright Soramitsu Co., Ltd. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+#ifndef IROHA_DOMAIN_POLICY_HPP
+#define IROHA_DOMAIN_POLICY_HPP
+
+#include <map>
+#include <string>
+#include <string_view>
+
+#include "common/result.hpp"
+#include "interfaces/common_objects/types.hpp"
+#include "interfaces/permissions.hpp"
+
+namespace iroha {
+  namespace ametsuchi {
+
+    /**
+     * Defaults applied to the accounts created in a domain, set with
+     * SetSettingValue under domainPolicyKey(domain_id). The value is JSON:
+     * {"grants": {"custodian@bank": ["can_transfer_my_assets"]}}
+     * All fields are optional.
+     */
+    struct DomainPolicy {
+      /// grantable permissions which every new account of the domain grants
+      /// to the listed accounts
+      std::map<shared_model::interface::types::AccountIdType,
+               shared_model::interface::GrantablePermissionSet>
+          grants;
+
+      static expected::Result<DomainPolicy, std::string> parse(
+          std::string_view json);
+    };
+
+    /// setting key of the policy of the domain
+    shared_model::interface::types::SettingKeyType domainPolicyKey(
+        const shared_model::interface::types::DomainIdType &domain_id);
+
+    /// whether the setting key belongs to a domain policy
+    bool isDomainPolicyKey(
+        const shared_model::interface::types::SettingKeyType &key);
+
+  }  // namespace ametsuchi
+}  // namespace iroha
+
+#endif  // IROHA_DOMAIN_POLICY_HPP
@@ -0,0 +1,44 @@
+/**
+ * Copyright Soramitsu Co., Ltd. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+#ifndef IROHA_FEE_ESTIMATION_HPP
+#define IROHA_FEE_ESTIMATION_HPP
+
+#include <functional>
+#include <optional>
+#include <string>
+#include <vector>
+
+#include "ametsuchi/supply_policy.hpp"
+#include "common/result.hpp"
+#include "interfaces/queries/estimate_fee.hpp"
+#include "interfaces/query_responses/fee_estimation_response.hpp"
+
+namespace iroha {
+  namespace ametsuchi {
+
+    /// Supply policy of the asset, nullopt if it is not set
+    using SupplyPolicyGetter = std::function<
+        expected::Result<std::optional<SupplyPolicy>, std::string>(
+            const shared_model::interface::types::AssetIdType &)>;
+
+    /**
+     * Fees which would be charged for the commands, the same way as on
+     * execution. At the moment the only fee is the burn fee of TransferAsset
+     * set by the supply policy of the asset.
+     * @param commands - commands of the estimated transaction
+     * @param get_policy - reader of the supply policies, called once per asset
+     * @return fee of every command, or the error of get_policy
+     */
+    expected::Result<
+        std::vector<shared_model::interface::FeeEstimationResponse::CommandFee>,
+        std::string>
+    estimateFees(shared_model::interface::EstimateFee::CommandsType commands,
+                 const SupplyPolicyGetter &get_policy);
+
+  }  // namespace ametsuchi
+}  // namespace iroha
+
+#endif  // IROHA_FEE_ESTIMATION_HPP
@@ -0,0 +1,50 @@
+/**
+ * Copyright Soramitsu Co., Ltd. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+#ifndef IROHA_GOVERNANCE_POLICY_HPP
+#define IROHA_GOVERNANCE_POLICY_HPP
+
+#include <cstdint>
+#include <map>
+#include <optional>
+#include <string>
+#include <string_view>
+
+#include "common/result.hpp"
+#include "interfaces/common_objects/types.hpp"
+
+namespace iroha {
+  namespace ametsuchi {
+
+    /**
+     * Accounts voting on setting changes proposed with ProposeSettingValue,
+     * set with SetSettingValue under kGovernancePolicyKey. The value is JSON:
+     * {"voters": {"alice@gov": 2, "bob@gov": 1}, "threshold": 2,
+     *  "voting_period": 100}
+     * A proposal is enacted once the total weight of its votes reaches the
+     * threshold within the voting period, counted in blocks.
+     */
+    struct GovernancePolicy {
+      std::map<shared_model::interface::types::AccountIdType, uint64_t> voters;
+      uint64_t threshold;
+      shared_model::interface::types::HeightType voting_period;
+
+      static expected::Result<GovernancePolicy, std::string> parse(
+          std::string_view json);
+
+      /// voting weight of the account, if it is a voter
+      std::optional<uint64_t> weight(
+          const shared_model::interface::types::AccountIdType &account_id)
+          const;
+    };
+
+    /// setting key of the governance policy
+    extern const shared_model::interface::types::SettingKeyType
+        kGovernancePolicyKey;
+
+  }  // namespace ametsuchi
+}  // namespace iroha
+
+#endif  // IROHA_GOVERNANCE_POLICY_HPP
@@ -0,0 +1,56 @@
+/**
+ * Copyright Soramitsu Co., Ltd. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+#ifndef IROHA_IDEMPOTENCY_KEY_HPP
+#define IROHA_IDEMPOTENCY_KEY_HPP
+
+#include <optional>
+#include <string>
+#include <string_view>
+
+#include "interfaces/common_objects/types.hpp"
+
+namespace iroha {
+  namespace ametsuchi {
+
+    /// Setting with the window in milliseconds of transaction created time,
+    /// within which idempotency keys are not reused, 0 disables the check
+    extern const shared_model::interface::types::SettingKeyType
+        kIdempotencyWindowKey;
+
+    /// Window used when the setting is not set, 10 minutes
+    constexpr uint64_t kDefaultIdempotencyWindow = 10 * 60 * 1000;
+
+    /// Longest window, transactions older than a day are stateless invalid
+    constexpr uint64_t kMaxIdempotencyWindow = 24 * 60 * 60 * 1000;
+
+    /**
+     * Idempotency key of a committed transaction, kept in WSV until a
+     * transaction of the same creator is created after the window.
+     */
+    struct IdempotencyRecord {
+      /// hex hash of the transaction which used the key
+      std::string tx_hash;
+      /// created time of the transaction
+      shared_model::interface::types::TimestampType created_time;
+
+      /// @return true if a transaction created at the time may reuse the key
+      bool expired(shared_model::interface::types::TimestampType time,
+                   uint64_t window) const;
+
+      /// @return the record as tx_hash|created_time
+      std::string encode() const;
+
+      /// @return the record encoded by encode(), nullopt if malformed
+      static std::optional<IdempotencyRecord> decode(std::string_view value);
+    };
+
+    /// @return the window of the setting value, the default one if unset
+    uint64_t parseIdempotencyWindow(std::optional<std::string_view> value);
+
+  }  // namespace ametsuchi
+}  // namespace iroha
+
+#endif  // IROHA_IDEMPOTENCY_KEY_HPP
@@ -0,0 +1,40 @@
+/**
+ * Copyright Soramitsu Co., Ltd. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+#include "ametsuchi/account_detail_limits.hpp"
+
+#include <charconv>
+
+#include <fmt/core.h>
+
+const shared_model::interface::types::SettingKeyType
+    iroha::ametsuchi::kMaxAccountDetailKeysKey = "MaxAccountDetailKeys";
+
+const shared_model::interface::types::SettingKeyType
+    iroha::ametsuchi::kMaxAccountDetailValueSizeKey =
+        "MaxAccountDetailValueSize";
+
+const shared_model::interface::types::SettingKeyType
+    iroha::ametsuchi::kMaxAccountDetailDepthKey = "MaxAccountDetailDepth";
+
+bool iroha::ametsuchi::isAccountDetailLimitKey(
+    const shared_model::interface::types::SettingKeyType &key) {
+  return key == kMaxAccountDetailKeysKey
+      or key == kMaxAccountDetailValueSizeKey
+      or key == kMaxAccountDetailDepthKey;
+}
+
+iroha::expected::Result<uint64_t, std::string>
+iroha::ametsuchi::parseAccountDetailLimit(std::string_view value) {
+  uint64_t limit;
+  auto [ptr, ec] =
+      std::from_chars(value.data(), value.data() + value.size(), limit);
+  if (value.empty() or ec != std::errc()
+      or ptr != value.data() + value.size()) {
+    return iroha::expected::makeError(fmt::format(
+        "Account detail limit must be an unsigned integer, got `{}'", value));
+  }
+  return iroha::expected::makeValue(limit);
+}
@@ -0,0 +1,169 @@
+/**
+ * Copyright Soramitsu Co., Ltd. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+#include "ametsuchi/account_detail_path.hpp"
+
+#include <algorithm>
+#include <vector>
+
+#include <fmt/core.h>
+#include <rapidjson/document.h>
+#include <rapidjson/error/en.h>
+#include <rapidjson/stringbuffer.h>
+#include <rapidjson/writer.h>
+
+using iroha::ametsuchi::AccountDetailPathError;
+
+namespace {
+  iroha::expected::Result<std::string, AccountDetailPathError> makePathError(
+      AccountDetailPathError::Code code, std::string message) {
+    return iroha::expected::makeError(
+        AccountDetailPathError{code, std::move(message)});
+  }
+
+  std::vector<std::string_view> splitPath(std::string_view path) {
+    std::vector<std::string_view> keys;
+    while (true) {
+      auto const dot = path.find('.');
+      keys.push_back(path.substr(0, dot));
+      if (dot == std::string_view::npos) {
+        return keys;
+      }
+      path.remove_prefix(dot + 1);
+    }
+  }
+
+  /// Non-owning JSON string to look up a member
+  rapidjson::Value makeName(std::string_view key) {
+    return rapidjson::Value(rapidjson::StringRef(key.data(), key.size()));
+  }
+
+  /// Nesting depth of the value: 0 for scalars, 1 for flat containers
+  uint64_t depth(const rapidjson::Value &value) {
+    uint64_t result = 0;
+    if (value.IsObject()) {
+      for (auto const &member : value.GetObject()) {
+        result = std::max(result, depth(member.value));
+      }
+      return result + 1;
+    }
+    if (value.IsArray()) {
+      for (auto const &item : value.GetArray()) {
+        result = std::max(result, depth(item));
+      }
+      return result + 1;
+    }
+    return result;
+  }
+
+  /// Value at the path or nullptr if there is none
+  const rapidjson::Value *find(const rapidjson::Value &root,
+                               const std::vector<std::string_view> &keys) {
+    const rapidjson::Value *node = &root;
+    for (auto const &key : keys) {
+      if (not node->IsObject()) {
+        return nullptr;
+      }
+      auto it = node->FindMember(makeName(key));
+      if (it == node->MemberEnd()) {
+        return nullptr;
+      }
+      node = &it->value;
+    }
+    return node;
+  }
+}  // namespace
+
+iroha::expected::Result<std::string, AccountDetailPathError>
+iroha::ametsuchi::setAccountDetailAtPath(
+    std::optional<std::string_view> detail,
+    std::string_view path,
+    std::string_view value,
+    std::optional<std::string_view> old_value,
+    std::optional<uint64_t> max_depth) {
+  using Code = AccountDetailPathError::Code;
+
+  rapidjson::Document document;
+  if (detail) {
+    document.Parse(detail->data(), detail->size());
+    if (document.HasParseError() or not document.IsObject()) {
+      return makePathError(Code::kNotAnObject,
+                           "Account detail is not a JSON object");
+    }
+  } else {
+    document.SetObject();
+  }
+  auto &allocator = document.GetAllocator();
+
+  rapidjson::Document new_value(&allocator);
+  new_value.Parse(value.data(), value.size());
+  if (new_value.HasParseError()) {
+    return makePathError(
+        Code::kNotAnObject,
+        fmt::format("Malformed JSON value: {}",
+                    rapidjson::GetParseError_En(new_value.GetParseError())));
+  }
+
+  auto const keys = splitPath(path);
+
+  if (old_value) {
+    rapidjson::Document expected;
+    expected.Parse(old_value->data(), old_value->size());
+    if (expected.HasParseError()) {
+      return makePathError(
+          Code::kNotAnObject,
+          fmt::format("Malformed JSON old value: {}",
+                      rapidjson::GetParseError_En(expected.GetParseError())));
+    }
+    auto const *current = find(document, keys);
+    if (current == nullptr or *current != expected) {
+      return makePathError(Code::kOldValueMismatch,
+                           fmt::format("Value at {} does not match", path));
+    }
+  }
+
+  rapidjson::Value *node = &document;
+  auto copy_name = [&allocator](std::string_view key) {
+    return rapidjson::Value(
+        key.data(), static_cast<rapidjson::SizeType>(key.size()), allocator);
+  };
+  for (size_t i = 0; i + 1 < keys.size(); ++i) {
+    auto it = node->FindMember(makeName(keys[i]));
+    if (it == node->MemberEnd()) {
+      node->AddMember(copy_name(keys[i]),
+                      rapidjson::Value(rapidjson::kObjectType),
+                      allocator);
+      it = node->FindMember(makeName(keys[i]));
+    } else if (not it->value.IsObject()) {
+      return makePathError(
+          Code::kNotAnObject,
+          fmt::format("Value at {} is not a JSON object", keys[i]));
+    }
+    node = &it->value;
+  }
+  if (auto it = node->FindMember(makeName(keys.back()));
+      it != node->MemberEnd()) {
+    it->value = new_value.Move();
+  } else {
+    node->AddMember(copy_name(keys.back()), new_value.Move(), allocator);
+  }
+
+  if (max_depth) {
+    if (auto const detail_depth = depth(document); detail_depth > *max_depth) {
+      return makePathError(
+          Code::kTooDeep,
+          fmt::format("Account detail would be nested {} levels deep, the "
+                      "limit is {}",
+                      detail_depth,
+                      *max_depth));
+    }
+  }
+
+  rapidjson::StringBuffer buffer;
+  rapidjson::Writer<rapidjson::StringBuffer> writer(buffer);
+  document.Accept(writer);
+  return iroha::expected::makeValue(
+      std::string{buffer.GetString(), buffer.GetSize()});
+}
@@ -0,0 +1,18 @@
+/**
+ * Copyright Soramitsu Co., Ltd. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+#include "ametsuchi/blob_hash.hpp"
+
+#include "cryptography/default_hash_provider.hpp"
+
+std::string iroha::ametsuchi::saltedBlobHash(std::string_view salt,
+                                             std::string_view blob) {
+  std::string salted;
+  salted.reserve(salt.size() + blob.size());
+  salted.append(salt).append(blob);
+  return shared_model::crypto::DefaultHashProvider::makeHash(
+             shared_model::crypto::Blob(salted))
+      .hex();
+}
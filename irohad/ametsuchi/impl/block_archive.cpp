@@ -0,0 +1,198 @@
+/**
+ * Copyright Soramitsu Co., Ltd. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+#include "ametsuchi/impl/block_archive.hpp"
+
+#include <istream>
+#include <optional>
+#include <ostream>
+
+#include <fmt/core.h>
+#include <rapidjson/document.h>
+#include <rapidjson/error/en.h>
+#include <rapidjson/istreamwrapper.h>
+#include <rapidjson/ostreamwrapper.h>
+#include <rapidjson/stringbuffer.h>
+#include <rapidjson/writer.h>
+#include "common/result_try.hpp"
+#include "cryptography/hash.hpp"
+
+using namespace iroha::ametsuchi;
+using shared_model::interface::types::HeightType;
+
+namespace {
+  constexpr auto kVersion = "version";
+  constexpr auto kFirstHeight = "first_height";
+  constexpr auto kLastHeight = "last_height";
+  constexpr auto kBlocks = "blocks";
+  constexpr auto kHeight = "height";
+  constexpr auto kHash = "hash";
+  constexpr auto kBlock = "block";
+
+  std::optional<uint64_t> getUint64(rapidjson::Value const &object,
+                                    char const *key) {
+    auto it = object.FindMember(key);
+    if (it == object.MemberEnd() or not it->value.IsUint64()) {
+      return std::nullopt;
+    }
+    return it->value.GetUint64();
+  }
+}  // namespace
+
+iroha::expected::Result<void, std::string>
+iroha::ametsuchi::exportBlockArchive(
+    BlockStorage const &storage,
+    shared_model::interface::BlockJsonConverter const &converter,
+    HeightType first_height,
+    HeightType last_height,
+    std::ostream &out) {
+  if (first_height == 0 or first_height > last_height) {
+    return fmt::format(
+        "Invalid height range [{}, {}]", first_height, last_height);
+  }
+
+  rapidjson::OStreamWrapper stream(out);
+  rapidjson::Writer<rapidjson::OStreamWrapper> writer(stream);
+  writer.StartObject();
+  writer.Key(kVersion);
+  writer.Uint64(kBlockArchiveVersion);
+  writer.Key(kFirstHeight);
+  writer.Uint64(first_height);
+  writer.Key(kLastHeight);
+  writer.Uint64(last_height);
+  writer.Key(kBlocks);
+  writer.StartArray();
+  for (auto height = first_height; height <= last_height; ++height) {
+    auto block = storage.fetch(height);
+    if (not block) {
+      return fmt::format("Block {} is not in the block storage", height);
+    }
+    IROHA_EXPECTED_TRY_GET_VALUE(block_json, converter.serialize(**block));
+    writer.StartObject();
+    writer.Key(kHeight);
+    writer.Uint64(height);
+    writer.Key(kHash);
+    writer.String((*block)->hash().hex());
+    writer.Key(kBlock);
+    writer.RawValue(
+        block_json.data(), block_json.size(), rapidjson::kObjectType);
+    writer.EndObject();
+  }
+  writer.EndArray();
+  writer.EndObject();
+  stream.Flush();
+
+  if (not out) {
+    return iroha::expected::makeError("Failed to write the block archive");
+  }
+  return {};
+}
+
+iroha::expected::Result<size_t, std::string>
+iroha::ametsuchi::importBlockArchive(
+    std::istream &in,
+    shared_model::interface::BlockJsonConverter const &converter,
+    BlockStorage &storage) {
+  rapidjson::IStreamWrapper stream(in);
+  rapidjson::Document archive;
+  archive.ParseStream(stream);
+  if (archive.HasParseError()) {
+    return fmt::format("Block archive is not valid JSON at offset {}: {}",
+                       archive.GetErrorOffset(),
+                       rapidjson::GetParseError_En(archive.GetParseError()));
+  }
+  if (not archive.IsObject()) {
+    return iroha::expected::makeError("Block archive must be a JSON object");
+  }
+
+  auto version = getUint64(archive, kVersion);
+  if (version != kBlockArchiveVersion) {
+    return fmt::format("Unsupported block archive version, expected {}",
+                       kBlockArchiveVersion);
+  }
+  auto first_height = getUint64(archive, kFirstHeight);
+  auto last_height = getUint64(archive, kLastHeight);
+  auto blocks = archive.FindMember(kBlocks);
+  if (not first_height or not last_height or blocks == archive.MemberEnd()
+      or not blocks->value.IsArray()) {
+    return fmt::format("Block archive must contain `{}', `{}' and `{}'",
+                       kFirstHeight,
+                       kLastHeight,
+                       kBlocks);
+  }
+  if (*first_height == 0 or *first_height > *last_height
+      or blocks->value.Size() != *last_height - *first_height + 1) {
+    return fmt::format("Block archive manifest is inconsistent: {} blocks "
+                       "for height range [{}, {}]",
+                       blocks->value.Size(),
+                       *first_height,
+                       *last_height);
+  }
+
+  HeightType expected_height = storage.size() + 1;
+  if (*first_height != expected_height) {
+    return fmt::format(
+        "Block archive starts at height {}, but the next block in the block "
+        "storage has height {}",
+        *first_height,
+        expected_height);
+  }
+
+  std::optional<shared_model::interface::types::HashType> prev_hash;
+  if (expected_height > 1) {
+    auto top_block = storage.fetch(expected_height - 1);
+    if (not top_block) {
+      return fmt::format("Failed to fetch top block {}", expected_height - 1);
+    }
+    prev_hash = (*top_block)->hash();
+  }
+
+  size_t imported = 0;
+  for (auto const &entry : blocks->value.GetArray()) {
+    auto height = getUint64(entry, kHeight);
+    auto hash = entry.FindMember(kHash);
+    auto block_json = entry.FindMember(kBlock);
+    if (not height or hash == entry.MemberEnd() or not hash->value.IsString()
+        or block_json == entry.MemberEnd()) {
+      return fmt::format(
+          "Block {} in the archive is malformed", expected_height);
+    }
+    if (*height != expected_height) {
+      return fmt::format(
+          "Expected block {}, got block {}", expected_height, *height);
+    }
+
+    rapidjson::StringBuffer buffer;
+    rapidjson::Writer<rapidjson::StringBuffer> writer(buffer);
+    block_json->value.Accept(writer);
+    IROHA_EXPECTED_TRY_GET_VALUE(
+        block, converter.deserialize(buffer.GetString()));
+
+    if (block->height() != expected_height) {
+      return fmt::format("Block {} has height {} in its payload",
+                         expected_height,
+                         block->height());
+    }
+    if (block->hash().hex() != hash->value.GetString()) {
+      return fmt::format(
+          "Block {} hash {} does not match the manifest hash {}",
+          expected_height,
+          block->hash().hex(),
+          hash->value.GetString());
+    }
+    if (prev_hash and block->prevHash() != *prev_hash) {
+      return fmt::format(
+          "Block {} does not follow the previous block", expected_height);
+    }
+
+    prev_hash = block->hash();
+    if (not storage.insert(std::move(block))) {
+      return fmt::format("Failed to insert block {}", expected_height);
+    }
+    ++imported;
+    ++expected_height;
+  }
+  return imported;
+}
@@ -0,0 +1,67 @@
+/**
+ * Copyright Soramitsu Co., Ltd. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+#ifndef IROHA_BLOCK_ARCHIVE_HPP
+#define IROHA_BLOCK_ARCHIVE_HPP
+
+#include <iosfwd>
+#include <string>
+
+#include "ametsuchi/block_storage.hpp"
+#include "common/result.hpp"
+#include "interfaces/common_objects/types.hpp"
+#include "interfaces/iroha_internal/block_json_converter.hpp"
+
+namespace iroha {
+  namespace ametsuchi {
+
+    /**
+     * Block archive is a single JSON document holding a contiguous range of
+     * blocks together with a manifest:
+     * {
+     *   "version": 1,
+     *   "first_height": <height>,
+     *   "last_height": <height>,
+     *   "blocks": [{"height": <height>, "hash": <hex>, "block": <block>}]
+     * }
+     * where <block> is the JSON representation of the block.
+     */
+    constexpr uint64_t kBlockArchiveVersion = 1;
+
+    /**
+     * Write blocks from the given height range to an archive
+     * @param storage - block storage to read the blocks from
+     * @param converter - block JSON serializer
+     * @param first_height - the first block to export
+     * @param last_height - the last block to export (inclusive)
+     * @param out - stream to write the archive to
+     * @return error message if any block could not be exported
+     */
+    expected::Result<void, std::string> exportBlockArchive(
+        BlockStorage const &storage,
+        shared_model::interface::BlockJsonConverter const &converter,
+        shared_model::interface::types::HeightType first_height,
+        shared_model::interface::types::HeightType last_height,
+        std::ostream &out);
+
+    /**
+     * Read blocks from an archive and append them to the storage. Each block
+     * is checked against the hash in the manifest and against the hash of
+     * the preceding block, which is the top block of the storage for the
+     * first archived block.
+     * @param in - stream to read the archive from
+     * @param converter - block JSON deserializer
+     * @param storage - block storage to append the blocks to
+     * @return number of imported blocks or error message
+     */
+    expected::Result<size_t, std::string> importBlockArchive(
+        std::istream &in,
+        shared_model::interface::BlockJsonConverter const &converter,
+        BlockStorage &storage);
+
+  }  // namespace ametsuchi
+}  // namespace iroha
+
+#endif  // IROHA_BLOCK_ARCHIVE_HPP
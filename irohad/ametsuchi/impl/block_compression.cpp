@@ -0,0 +1,59 @@
+/**
+ * Copyright Soramitsu Co., Ltd. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+#include "ametsuchi/impl/block_compression.hpp"
+
+#include <algorithm>
+#include <array>
+
+#include <fmt/core.h>
+#include <boost/iostreams/device/back_inserter.hpp>
+#include <boost/iostreams/filter/zstd.hpp>
+#include <boost/iostreams/filtering_stream.hpp>
+
+using namespace iroha::ametsuchi;
+using Bytes = KeyValueStorage::Bytes;
+
+namespace {
+  /// zstd frame magic number 0xFD2FB528 in little endian
+  constexpr std::array<uint8_t, 4> kZstdMagic{0x28, 0xB5, 0x2F, 0xFD};
+
+  /// Pass the data through a boost::iostreams filter
+  template <typename Filter>
+  iroha::expected::Result<Bytes, std::string> applyFilter(
+      Bytes const &data, Filter filter, char const *operation) {
+    std::string result;
+    try {
+      boost::iostreams::filtering_ostream out;
+      out.push(std::move(filter));
+      out.push(boost::iostreams::back_inserter(result));
+      out.write(reinterpret_cast<char const *>(data.data()), data.size());
+      // closes the chain and flushes the last frame
+      out.reset();
+    } catch (std::exception const &e) {
+      return fmt::format("Block {} failed: {}", operation, e.what());
+    }
+    return Bytes(result.begin(), result.end());
+  }
+}  // namespace
+
+bool iroha::ametsuchi::isCompressedBlock(Bytes const &data) {
+  return data.size() >= kZstdMagic.size()
+      and std::equal(kZstdMagic.begin(), kZstdMagic.end(), data.begin());
+}
+
+iroha::expected::Result<Bytes, std::string> iroha::ametsuchi::compressBlock(
+    Bytes const &data, uint32_t level) {
+  return applyFilter(
+      data,
+      boost::iostreams::zstd_compressor(boost::iostreams::zstd_params(level)),
+      "compression");
+}
+
+iroha::expected::Result<Bytes, std::string> iroha::ametsuchi::decompressBlock(
+    Bytes const &data) {
+  return applyFilter(
+      data, boost::iostreams::zstd_decompressor(), "decompression");
+}
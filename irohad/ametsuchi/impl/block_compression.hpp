@@ -0,0 +1,49 @@
+/**
+ * Copyright Soramitsu Co., Ltd. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+#ifndef IROHA_BLOCK_COMPRESSION_HPP
+#define IROHA_BLOCK_COMPRESSION_HPP
+
+#include <string>
+
+#include "ametsuchi/key_value_storage.hpp"
+#include "common/result.hpp"
+
+namespace iroha {
+  namespace ametsuchi {
+
+    /// Highest zstd compression level
+    constexpr uint32_t kMaxBlockCompressionLevel = 22;
+
+    /**
+     * Check if the data is a single zstd frame produced by compressBlock. A
+     * serialized block never starts with the zstd magic number, so compressed
+     * and uncompressed blocks can be kept in the same storage.
+     * @param data - stored block
+     * @return true if the data is compressed
+     */
+    bool isCompressedBlock(KeyValueStorage::Bytes const &data);
+
+    /**
+     * Compress a serialized block into a single zstd frame
+     * @param data - serialized block
+     * @param level - zstd compression level, 1 to kMaxBlockCompressionLevel
+     * @return compressed data or error message
+     */
+    expected::Result<KeyValueStorage::Bytes, std::string> compressBlock(
+        KeyValueStorage::Bytes const &data, uint32_t level);
+
+    /**
+     * Decompress a block produced by compressBlock
+     * @param data - compressed block
+     * @return serialized block or error message
+     */
+    expected::Result<KeyValueStorage::Bytes, std::string> decompressBlock(
+        KeyValueStorage::Bytes const &data);
+
+  }  // namespace ametsuchi
+}  // namespace iroha
+
+#endif  // IROHA_BLOCK_COMPRESSION_HPP
@@ -8,9 +8,16 @@
 #include <boost/range/adaptor/filtered.hpp>
 #include <boost/range/adaptor/indexed.hpp>
 #include <boost/range/adaptor/transformed.hpp>
+#include "ametsuchi/asset_lock.hpp"
 #include "ametsuchi/tx_cache_response.hpp"
 #include "common/visitor.hpp"
+#include "interfaces/commands/add_asset_quantity.hpp"
+#include "interfaces/commands/claim_with_preimage.hpp"
 #include "interfaces/commands/command_variant.hpp"
+#include "interfaces/commands/grant_vested_asset.hpp"
+#include "interfaces/commands/lock_asset.hpp"
+#include "interfaces/commands/refund_after_timeout.hpp"
+#include "interfaces/commands/subtract_asset_quantity.hpp"
 #include "interfaces/commands/transfer_asset.hpp"
 #include "interfaces/iroha_internal/block.hpp"
 #include "logger/logger.hpp"
@@ -33,11 +40,76 @@ namespace {
         },
         [](const auto &) -> ReturnType { return boost::none; });
   }
+
+  // Return asset id if command issues or burns the asset of the creator
+  boost::optional<AssetIdType> getCreatorAssetId(
+      const shared_model::interface::Command &cmd) noexcept {
+    using ReturnType = boost::optional<AssetIdType>;
+    return iroha::visit_in_place(
+        cmd.get(),
+        [](const shared_model::interface::AddAssetQuantity &c) {
+          return ReturnType(c.assetId());
+        },
+        [](const shared_model::interface::SubtractAssetQuantity &c) {
+          return ReturnType(c.assetId());
+        },
+        [](const shared_model::interface::GrantVestedAsset &c) {
+          return ReturnType(c.assetId());
+        },
+        [](const auto &) -> ReturnType { return boost::none; });
+  }
+
+  // Return grant of vested asset if command contains it
+  boost::optional<const shared_model::interface::GrantVestedAsset &>
+  getGrantVestedAsset(const shared_model::interface::Command &cmd) noexcept {
+    using ReturnType =
+        boost::optional<const shared_model::interface::GrantVestedAsset &>;
+    return iroha::visit_in_place(
+        cmd.get(),
+        [](const shared_model::interface::GrantVestedAsset &c) {
+          return ReturnType(c);
+        },
+        [](const auto &) -> ReturnType { return boost::none; });
+  }
 }  // namespace
 
+std::optional<Indexer::AssetLockParties> BlockIndexImpl::getAssetLock(
+    const shared_model::interface::Command &command) {
+  using ReturnType = std::optional<Indexer::AssetLockParties>;
+  // the released lock is not in WSV anymore, its parties are kept on release
+  auto get_release = [this](const std::string &hash) -> ReturnType {
+    auto release = indexer_->assetLockRelease(hash);
+    if (auto e = expected::resultToOptionalError(release)) {
+      log_->error("Failed to get the release of asset lock {}: {}", hash, *e);
+      return std::nullopt;
+    }
+    return std::move(release).assumeValue();
+  };
+  return iroha::visit_in_place(
+      command.get(),
+      [](const shared_model::interface::LockAsset &c) -> ReturnType {
+        return Indexer::AssetLockParties{
+            c.srcAccountId(), c.destAccountId(), c.assetId()};
+      },
+      [&](const shared_model::interface::ClaimWithPreimage &c) -> ReturnType {
+        auto hash = assetLockHash(c.preimage());
+        if (auto e = expected::resultToOptionalError(hash)) {
+          log_->error("{}", *e);
+          return std::nullopt;
+        }
+        return get_release(hash.assumeValue());
+      },
+      [&](const shared_model::interface::RefundAfterTimeout &c) {
+        return get_release(c.hash());
+      },
+      [](const auto &) -> ReturnType { return std::nullopt; });
+}
+
 // Collect all assets belonging to creator, sender, and receiver
 // to make account_id:height:asset_id -> list of tx indexes
-// for transfer asset in command
+// for transfer asset in command, for the assets issued or burned by
+// the creator, for the vested assets granted to an account, and for
+// the asset locks made, claimed or refunded
 void BlockIndexImpl::makeAccountAssetIndex(
     const AccountIdType &account_id,
     shared_model::interface::types::HashType const &hash,
@@ -65,6 +137,22 @@ void BlockIndexImpl::makeAccountAssetIndex(
       indexer_->txPositions(account_id, hash, asset_id, ts, position);
     }
   }
+  for (const auto &command : commands) {
+    if (auto asset_id = getCreatorAssetId(command)) {
+      indexer_->txPositions(
+          account_id, hash, std::move(asset_id), ts, position);
+    }
+    if (auto grant = getGrantVestedAsset(command);
+        grant and grant->destAccountId() != account_id) {
+      indexer_->txPositions(
+          grant->destAccountId(), hash, grant->assetId(), ts, position);
+    }
+    if (auto lock = getAssetLock(command)) {
+      for (const auto &id : {lock->src_account_id, lock->dest_account_id}) {
+        indexer_->txPositions(id, hash, lock->asset_id, ts, position);
+      }
+    }
+  }
 }
 
 BlockIndexImpl::BlockIndexImpl(std::unique_ptr<Indexer> indexer,
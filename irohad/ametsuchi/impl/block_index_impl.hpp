@@ -26,6 +26,10 @@ namespace iroha {
      *     c. destination account
      *   2. account -> block for source and destination accounts
      *   3. (account, height) -> list of txes
+     *
+     * Lock Asset commands and the claims and the refunds of the locks are
+     * indexed as (account, asset) -> block for the source and the
+     * destination accounts of the lock.
      */
     class BlockIndexImpl : public BlockIndex {
      public:
@@ -43,6 +47,10 @@ namespace iroha {
           Indexer::TxPosition position,
           const shared_model::interface::Transaction::CommandsType &commands);
 
+      /// Accounts and asset of the asset lock made or released by the command
+      std::optional<Indexer::AssetLockParties> getAssetLock(
+          const shared_model::interface::Command &command);
+
       std::unique_ptr<Indexer> indexer_;
       logger::LoggerPtr log_;
     };
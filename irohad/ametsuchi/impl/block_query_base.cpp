@@ -19,6 +19,12 @@ namespace iroha::ametsuchi {
       shared_model::interface::types::HeightType height) {
     auto block = block_storage_.fetch(height);
     if (not block) {
+      if (auto first_retained = block_storage_.firstRetainedHeight();
+          isPrunedBlock(first_retained, height)) {
+        return expected::makeError(
+            GetBlockError{GetBlockError::Code::kPruned,
+                          prunedBlockMessage(height, *first_retained)});
+      }
       return expected::makeError(GetBlockError{
           GetBlockError::Code::kNoBlock,
           fmt::format("Failed to retrieve block with height {}", height)});
@@ -31,6 +37,11 @@ namespace iroha::ametsuchi {
     return block_storage_.size();
   }
 
+  std::optional<shared_model::interface::types::HeightType>
+  BlockQueryBase::getFirstRetainedHeight() {
+    return block_storage_.firstRetainedHeight();
+  }
+
   void BlockQueryBase::reloadBlockstore() {
     block_storage_.reload();
   }
@@ -26,6 +26,9 @@ namespace iroha::ametsuchi {
 
     shared_model::interface::types::HeightType getTopBlockHeight() override;
 
+    std::optional<shared_model::interface::types::HeightType>
+    getFirstRetainedHeight() override;
+
     void reloadBlockstore() override;
 
     std::optional<TxCacheStatusType> checkTxPresence(
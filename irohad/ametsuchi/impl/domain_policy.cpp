@@ -0,0 +1,77 @@
+/**
+ * Copyright Soramitsu Co., Ltd. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+#include "ametsuchi/domain_policy.hpp"
+
+#include <fmt/core.h>
+#include <rapidjson/document.h>
+#include <rapidjson/error/en.h>
+#include "backend/protobuf/permissions.hpp"
+#include "primitive.pb.h"
+
+using iroha::ametsuchi::DomainPolicy;
+
+namespace {
+  const std::string kDomainPolicyKeyPrefix = "DomainPolicy:";
+}  // namespace
+
+iroha::expected::Result<DomainPolicy, std::string> DomainPolicy::parse(
+    std::string_view json) {
+  rapidjson::Document document;
+  document.Parse(json.data(), json.size());
+  if (document.HasParseError()) {
+    return iroha::expected::makeError(
+        fmt::format("Malformed domain policy: {}",
+                    rapidjson::GetParseError_En(document.GetParseError())));
+  }
+  if (not document.IsObject()) {
+    return iroha::expected::makeError(
+        std::string{"Domain policy must be an object"});
+  }
+
+  DomainPolicy policy;
+  if (auto grants = document.FindMember("grants");
+      grants != document.MemberEnd()) {
+    if (not grants->value.IsObject()) {
+      return iroha::expected::makeError(std::string{
+          "`grants' must be an object of account ids to permission lists"});
+    }
+    for (auto const &permittee : grants->value.GetObject()) {
+      if (not permittee.value.IsArray()) {
+        return iroha::expected::makeError(
+            fmt::format("Permissions granted to `{}' must be an array",
+                        permittee.name.GetString()));
+      }
+      shared_model::interface::GrantablePermissionSet permissions;
+      for (auto const &name : permittee.value.GetArray()) {
+        iroha::protocol::GrantablePermission permission;
+        if (not name.IsString()
+            or not iroha::protocol::GrantablePermission_Parse(
+                name.GetString(), &permission)) {
+          return iroha::expected::makeError(
+              fmt::format("Unknown grantable permission granted to `{}'",
+                          permittee.name.GetString()));
+        }
+        permissions.set(
+            shared_model::proto::permissions::fromTransport(permission));
+      }
+      policy.grants.emplace(std::string{permittee.name.GetString(),
+                                        permittee.name.GetStringLength()},
+                            permissions);
+    }
+  }
+  return iroha::expected::makeValue(std::move(policy));
+}
+
+shared_model::interface::types::SettingKeyType
+iroha::ametsuchi::domainPolicyKey(
+    const shared_model::interface::types::DomainIdType &domain_id) {
+  return kDomainPolicyKeyPrefix + domain_id;
+}
+
+bool iroha::ametsuchi::isDomainPolicyKey(
+    const shared_model::interface::types::SettingKeyType &key) {
+  return key.rfind(kDomainPolicyKeyPrefix, 0) == 0;
+}
@@ -7,7 +7,31 @@
 
 #include <algorithm>
 
+#include "common/visitor.hpp"
+#include "interfaces/commands/add_asset_quantity.hpp"
+#include "interfaces/commands/add_signatory.hpp"
+#include "interfaces/commands/append_role.hpp"
+#include "interfaces/commands/authorize_session_key.hpp"
+#include "interfaces/commands/command_variant.hpp"
+#include "interfaces/commands/compare_and_set_account_detail.hpp"
+#include "interfaces/commands/deactivate_account.hpp"
+#include "interfaces/commands/detach_role.hpp"
+#include "interfaces/commands/grant_permission.hpp"
+#include "interfaces/commands/grant_vested_asset.hpp"
+#include "interfaces/commands/lock_asset.hpp"
+#include "interfaces/commands/recover_account.hpp"
+#include "interfaces/commands/remove_signatory.hpp"
+#include "interfaces/commands/revoke_permission.hpp"
+#include "interfaces/commands/rotate_key.hpp"
+#include "interfaces/commands/set_account_detail.hpp"
+#include "interfaces/commands/set_account_detail_at_path.hpp"
+#include "interfaces/commands/set_quorum.hpp"
+#include "interfaces/commands/set_spending_limit.hpp"
+#include "interfaces/commands/subtract_asset_quantity.hpp"
+#include "interfaces/commands/transfer_asset.hpp"
+#include "interfaces/permission_to_string.hpp"
 #include "interfaces/permissions.hpp"
+#include "interfaces/transaction.hpp"
 
 namespace iroha {
   namespace ametsuchi {
@@ -26,6 +50,23 @@ namespace iroha {
       return split(id, "@#");
     }
 
+    shared_model::interface::RolePermissionSet filterPermissions(
+        const shared_model::interface::RolePermissionSet &permissions,
+        std::string_view name_filter,
+        shared_model::interface::PermissionToString &perm_converter) {
+      if (name_filter.empty()) {
+        return permissions;
+      }
+      shared_model::interface::RolePermissionSet filtered;
+      permissions.iterate([&](auto permission) {
+        if (perm_converter.toString(permission).find(name_filter)
+            != std::string::npos) {
+          filtered.set(permission);
+        }
+      });
+      return filtered;
+    }
+
     std::vector<std::string_view> split(std::string_view str,
                                         std::string_view delims) {
       std::vector<std::string_view> output;
@@ -45,5 +86,68 @@ namespace iroha {
       return output;
     }
 
+    std::vector<std::string_view> getWrittenDomains(
+        shared_model::interface::Command const &command) {
+      using namespace shared_model::interface;
+      auto domain_of = [](std::string_view id) {
+        return staticSplitId<2>(id)[1];
+      };
+      auto account = [&](auto const &c) {
+        return std::vector{domain_of(c.accountId())};
+      };
+      // the balance of the creator changes together with the supply
+      auto asset = [&](auto const &c) {
+        return std::vector{domain_of(c.assetId())};
+      };
+      auto parties = [&](auto const &c) {
+        return std::vector{domain_of(c.srcAccountId()),
+                           domain_of(c.destAccountId())};
+      };
+      return iroha::visit_in_place(
+          command.get(),
+          [&](AddAssetQuantity const &c) { return asset(c); },
+          [&](SubtractAssetQuantity const &c) { return asset(c); },
+          [&](GrantVestedAsset const &c) {
+            return std::vector{domain_of(c.destAccountId()),
+                               domain_of(c.assetId())};
+          },
+          [&](TransferAsset const &c) { return parties(c); },
+          [&](LockAsset const &c) { return parties(c); },
+          [&](AddSignatory const &c) { return account(c); },
+          [&](RemoveSignatory const &c) { return account(c); },
+          [&](RotateKey const &c) { return account(c); },
+          [&](RecoverAccount const &c) { return account(c); },
+          [&](SetQuorum const &c) { return account(c); },
+          [&](AppendRole const &c) { return account(c); },
+          [&](DetachRole const &c) { return account(c); },
+          [&](GrantPermission const &c) { return account(c); },
+          [&](RevokePermission const &c) { return account(c); },
+          [&](AuthorizeSessionKey const &c) { return account(c); },
+          [&](DeactivateAccount const &c) { return account(c); },
+          [&](SetSpendingLimit const &c) { return account(c); },
+          [&](SetAccountDetail const &c) { return account(c); },
+          [&](SetAccountDetailAtPath const &c) { return account(c); },
+          [&](CompareAndSetAccountDetail const &c) { return account(c); },
+          [](auto const &) { return std::vector<std::string_view>{}; });
+    }
+
+    std::vector<shared_model::interface::types::CommandIndexType>
+    getAssetTransferIndices(
+        shared_model::interface::Transaction const &transaction,
+        shared_model::interface::types::AssetIdType const &asset_id) {
+      std::vector<shared_model::interface::types::CommandIndexType> indices;
+      shared_model::interface::types::CommandIndexType index = 0;
+      for (auto const &command : transaction.commands()) {
+        if (auto transfer =
+                boost::get<shared_model::interface::TransferAsset const &>(
+                    &command.get());
+            transfer and transfer->assetId() == asset_id) {
+          indices.push_back(index);
+        }
+        ++index;
+      }
+      return indices;
+    }
+
   }  // namespace ametsuchi
 }  // namespace iroha
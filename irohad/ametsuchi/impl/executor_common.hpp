@@ -7,10 +7,17 @@
 #define IROHA_AMETSUCHI_EXECUTOR_COMMON_HPP
 
 #include "interfaces/common_objects/types.hpp"
+#include "interfaces/permissions.hpp"
 
 #include <algorithm>
 #include <array>
 
+namespace shared_model::interface {
+  class Command;
+  class PermissionToString;
+  class Transaction;
+}  // namespace shared_model::interface
+
 namespace iroha::ametsuchi {
 
   extern const std::string kRootRolePermStr;
@@ -19,9 +26,43 @@ namespace iroha::ametsuchi {
 
   std::vector<std::string_view> splitId(std::string_view id);
 
+  /**
+   * Select the permissions with names containing the filter
+   * @param permissions to select from
+   * @param name_filter substring of the names, empty to keep all permissions
+   * @param perm_converter to get the names of permissions
+   * @return selected permissions
+   */
+  shared_model::interface::RolePermissionSet filterPermissions(
+      const shared_model::interface::RolePermissionSet &permissions,
+      std::string_view name_filter,
+      shared_model::interface::PermissionToString &perm_converter);
+
   std::vector<std::string_view> split(std::string_view str,
                                       std::string_view delims);
 
+  /**
+   * Domains of the existing accounts and assets the command writes to, which
+   * must not be archived. Domains of the created accounts and assets are
+   * checked by CreateAccount and CreateAsset themselves
+   * @param command to get the domains of
+   * @return domains, possibly repeated
+   */
+  std::vector<std::string_view> getWrittenDomains(
+      shared_model::interface::Command const &command);
+
+  /**
+   * Positions of the transfers of the asset in the transaction, which may
+   * have charged a burn fee
+   * @param transaction to look through
+   * @param asset_id of the transfers
+   * @return indices of the transfer commands
+   */
+  std::vector<shared_model::interface::types::CommandIndexType>
+  getAssetTransferIndices(
+      shared_model::interface::Transaction const &transaction,
+      shared_model::interface::types::AssetIdType const &asset_id);
+
   template <size_t C>
   std::array<std::string_view, C> staticSplitId(
       std::string_view const str, std::string_view const delims = "@#") {
@@ -0,0 +1,48 @@
+/**
+ * Copyright Soramitsu Co., Ltd. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+#include "ametsuchi/fee_estimation.hpp"
+
+#include <map>
+
+#include "interfaces/commands/transfer_asset.hpp"
+
+using shared_model::interface::Amount;
+using shared_model::interface::FeeEstimationResponse;
+
+iroha::expected::Result<std::vector<FeeEstimationResponse::CommandFee>,
+                        std::string>
+iroha::ametsuchi::estimateFees(
+    shared_model::interface::EstimateFee::CommandsType commands,
+    const SupplyPolicyGetter &get_policy) {
+  std::map<shared_model::interface::types::AssetIdType,
+           std::optional<SupplyPolicy>>
+      policies;
+  std::vector<FeeEstimationResponse::CommandFee> fees;
+  shared_model::interface::types::CommandIndexType index = 0;
+  for (const auto &command : commands) {
+    FeeEstimationResponse::CommandFee fee{index++, {}, Amount{"0"}};
+    if (auto transfer =
+            boost::get<const shared_model::interface::TransferAsset &>(
+                &command.get())) {
+      auto it = policies.find(transfer->assetId());
+      if (it == policies.end()) {
+        auto policy = get_policy(transfer->assetId());
+        if (auto e = expected::resultToOptionalError(policy)) {
+          return expected::makeError(std::move(e).value());
+        }
+        it = policies
+                 .emplace(transfer->assetId(), std::move(policy).assumeValue())
+                 .first;
+      }
+      fee.asset_id = transfer->assetId();
+      fee.fee = it->second and it->second->burn_fee_basis_points > 0
+          ? it->second->burnFee(transfer->amount())
+          : Amount{transfer->amount().precision()};
+    }
+    fees.push_back(std::move(fee));
+  }
+  return expected::makeValue(std::move(fees));
+}
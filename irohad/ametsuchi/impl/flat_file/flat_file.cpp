@@ -10,6 +10,7 @@
 #include <boost/iostreams/stream.hpp>
 #include <boost/range/adaptor/indexed.hpp>
 #include <boost/range/algorithm/find_if.hpp>
+#include <algorithm>
 #include <ciso646>
 #include <iomanip>
 #include <iostream>
@@ -32,6 +33,14 @@ using namespace iroha::ametsuchi;
 using Identifier = FlatFile::Identifier;
 using BlockIdCollectionType = FlatFile::BlockIdCollectionType;
 
+namespace {
+  uint64_t fileSize(boost::filesystem::path const &path) {
+    boost::system::error_code error_code;
+    auto size = boost::filesystem::file_size(path, error_code);
+    return error_code ? 0 : size;
+  }
+}  // namespace
+
 const std::string FlatFile::kTempFileExtension = ".tmp";
 const std::regex FlatFile::kBlockFilenameRegex = std::regex("[0-9]{16}");
 
@@ -81,54 +90,15 @@ bool FlatFile::add(Identifier id, const Bytes &block) {
     log_->warn("insertion for {} failed, because file already exists", id);
     return false;
   }
-  // New file will be created
-  boost::iostreams::stream<boost::iostreams::file_descriptor_sink> file;
-  try {
-    file.open(tmp_file_name, std::ofstream::binary);
-  } catch (std::ios_base::failure const &e) {
-    log_->warn("Cannot open file by index {} for writing: {}", id, e.what());
-    return false;
-  }
-  if (not file.is_open()) {
-    log_->warn("Cannot open file by index {} for writing", id);
-    return false;
-  }
-
-  auto val_size =
-      sizeof(std::remove_reference<decltype(block)>::type::value_type);
-
-  if (not file.write(reinterpret_cast<const char *>(block.data()),
-                     block.size() * val_size)) {
-    log_->warn("Cannot write file by index {}", id);
-    return false;
-  }
-
-  if (not file.flush()) {
-    log_->warn("Cannot flush file by index {}", id);
-    return false;
-  }
-
-#ifdef _WIN32
-  if (not FlushFileBuffers(file->handle())) {
-#else
-  if (fsync(file->handle())) {
-#endif
-    log_->warn("Cannot fsync file by index {}", id);
-    return false;
-  }
-
-  file->close();
+  return write(id, block);
+}
 
-  boost::system::error_code error_code;
-  boost::filesystem::rename(tmp_file_name, file_name, error_code);
-  if (error_code != boost::system::errc::success) {
-    log_->error(
-        "insertion for {} failed, because {}", id, error_code.message());
+bool FlatFile::replace(Identifier id, const Bytes &block) {
+  if (available_blocks_.count(id) == 0) {
+    log_->warn("replacement for {} failed, because file does not exist", id);
     return false;
   }
-
-  available_blocks_.insert(id);
-  return true;
+  return write(id, block);
 }
 
 boost::optional<FlatFile::Bytes> FlatFile::get(Identifier id) const {
@@ -152,9 +122,18 @@ Identifier FlatFile::last_id() const {
 
 void FlatFile::reload() {
   available_blocks_.clear();
+  total_size_ = 0;
   for (auto it = boost::filesystem::directory_iterator{dump_dir_};
        it != boost::filesystem::directory_iterator{};
        ++it) {
+    // remove leftovers of writes interrupted by an unclean shutdown, they
+    // would prevent the block from being written again
+    if (it->path().extension() == kTempFileExtension) {
+      log_->warn("removing incomplete block file {}", it->path().string());
+      boost::system::error_code error_code;
+      boost::filesystem::remove(it->path(), error_code);
+      continue;
+    }
     // skip non-block files
     if (!std::regex_match(it->path().filename().string(),
                           kBlockFilenameRegex)) {
@@ -162,6 +141,7 @@ void FlatFile::reload() {
     }
     if (auto id = FlatFile::name_to_id(it->path().filename().string())) {
       available_blocks_.insert(*id);
+      total_size_ += fileSize(it->path());
     } else {
       boost::filesystem::remove(it->path());
     }
@@ -171,12 +151,36 @@ void FlatFile::reload() {
 void FlatFile::dropAll() {
   iroha::remove_dir_contents(dump_dir_, log_);
   available_blocks_.clear();
+  total_size_ = 0;
+}
+
+bool FlatFile::remove(Identifier id) {
+  const auto file_name = boost::filesystem::path{dump_dir_} / id_to_name(id);
+  const auto size = fileSize(file_name);
+  boost::system::error_code error_code;
+  boost::filesystem::remove(file_name, error_code);
+  if (error_code != boost::system::errc::success) {
+    log_->error("removal of {} failed, because {}", id, error_code.message());
+    return false;
+  }
+  if (available_blocks_.erase(id) > 0) {
+    total_size_ -= std::min(size, total_size_);
+  }
+  return true;
 }
 
 const BlockIdCollectionType &FlatFile::blockIdentifiers() const {
   return available_blocks_;
 }
 
+uint64_t FlatFile::totalSize() const {
+  return total_size_;
+}
+
+std::chrono::microseconds FlatFile::lastFsyncDuration() const {
+  return last_fsync_duration_;
+}
+
 // ----------| private API |----------
 
 FlatFile::FlatFile(std::string path,
@@ -185,3 +189,65 @@ FlatFile::FlatFile(std::string path,
     : dump_dir_(std::move(path)), log_{std::move(log)} {
   reload();
 }
+
+bool FlatFile::write(Identifier id, const Bytes &block) {
+  const auto tmp_file_name = boost::filesystem::path{dump_dir_}
+      / (id_to_name(id) + kTempFileExtension);
+  const auto file_name = boost::filesystem::path{dump_dir_} / id_to_name(id);
+
+  boost::iostreams::stream<boost::iostreams::file_descriptor_sink> file;
+  try {
+    file.open(tmp_file_name, std::ofstream::binary);
+  } catch (std::ios_base::failure const &e) {
+    log_->warn("Cannot open file by index {} for writing: {}", id, e.what());
+    return false;
+  }
+  if (not file.is_open()) {
+    log_->warn("Cannot open file by index {} for writing", id);
+    return false;
+  }
+
+  auto val_size =
+      sizeof(std::remove_reference<decltype(block)>::type::value_type);
+
+  if (not file.write(reinterpret_cast<const char *>(block.data()),
+                     block.size() * val_size)) {
+    log_->warn("Cannot write file by index {}", id);
+    return false;
+  }
+
+  if (not file.flush()) {
+    log_->warn("Cannot flush file by index {}", id);
+    return false;
+  }
+
+  const auto fsync_start = std::chrono::steady_clock::now();
+#ifdef _WIN32
+  if (not FlushFileBuffers(file->handle())) {
+#else
+  if (fsync(file->handle())) {
+#endif
+    log_->warn("Cannot fsync file by index {}", id);
+    return false;
+  }
+  last_fsync_duration_ =
+      std::chrono::duration_cast<std::chrono::microseconds>(
+          std::chrono::steady_clock::now() - fsync_start);
+
+  file->close();
+
+  const auto replaced_size =
+      available_blocks_.count(id) > 0 ? fileSize(file_name) : 0;
+  boost::system::error_code error_code;
+  boost::filesystem::rename(tmp_file_name, file_name, error_code);
+  if (error_code != boost::system::errc::success) {
+    log_->error(
+        "insertion for {} failed, because {}", id, error_code.message());
+    return false;
+  }
+
+  available_blocks_.insert(id);
+  total_size_ += block.size() * val_size;
+  total_size_ -= std::min(replaced_size, total_size_);
+  return true;
+}
@@ -6,6 +6,7 @@
 #ifndef IROHA_FLAT_FILE_HPP
 #define IROHA_FLAT_FILE_HPP
 
+#include <chrono>
 #include <memory>
 #include <regex>
 #include <set>
@@ -80,11 +81,36 @@ namespace iroha {
 
       void dropAll() override;
 
+      /**
+       * Remove the file with the given id from the storage
+       * @param id - reference key
+       * @return true if the file was removed, false otherwise
+       */
+      bool remove(Identifier id);
+
+      /**
+       * Atomically overwrite the contents of an existing file
+       * @param id - reference key
+       * @param blob - new data associated with key
+       * @return true if the file was replaced, false otherwise
+       */
+      bool replace(Identifier id, const Bytes &blob);
+
       /**
        * @return collection of available block ids
        */
       const BlockIdCollectionType &blockIdentifiers() const;
 
+      /**
+       * @return total size of the stored files in bytes
+       */
+      uint64_t totalSize() const;
+
+      /**
+       * @return time taken to sync the most recently written file to disk
+       */
+      std::chrono::microseconds lastFsyncDuration() const;
+
       // ----------| modify operations |----------
 
       FlatFile(const FlatFile &rhs) = delete;
@@ -107,6 +133,12 @@ namespace iroha {
                logger::LoggerPtr log);
 
      private:
+      /**
+       * Write data to a temporary file and move it to the place of the file
+       * with the given id, so that a reader never observes a partial write
+       */
+      bool write(Identifier id, const Bytes &blob);
+
       /**
        * Folder of storage
        */
@@ -117,6 +149,10 @@ namespace iroha {
        */
       BlockIdCollectionType available_blocks_;
 
+      uint64_t total_size_{0};
+
+      std::chrono::microseconds last_fsync_duration_{0};
+
       logger::LoggerPtr log_;
 
      public:
@@ -5,28 +5,54 @@
 
 #include "ametsuchi/impl/flat_file_block_storage.hpp"
 
+#include <vector>
+
 #include <boost/filesystem.hpp>
 
+#include "ametsuchi/impl/block_compression.hpp"
 #include "backend/protobuf/block.hpp"
 #include "common/byteutils.hpp"
 #include "logger/logger.hpp"
+#include "main/block_store_status.hpp"
+#include "main/subscription.hpp"
 
 using namespace iroha::ametsuchi;
 
 FlatFileBlockStorage::FlatFileBlockStorage(
     std::unique_ptr<FlatFile> flat_file,
     std::shared_ptr<shared_model::interface::BlockJsonConverter> json_converter,
-    logger::LoggerPtr log)
+    logger::LoggerPtr log,
+    std::optional<size_t> keep_blocks,
+    std::optional<uint32_t> compression_level)
     : flat_file_storage_(std::move(flat_file)),
       json_converter_(std::move(json_converter)),
-      log_(std::move(log)) {}
+      log_(std::move(log)),
+      keep_blocks_(keep_blocks),
+      compression_level_(compression_level) {}
 
 bool FlatFileBlockStorage::insert(
     std::shared_ptr<const shared_model::interface::Block> block) {
   return json_converter_->serialize(*block).match(
       [&](const auto &block_json) {
-        return flat_file_storage_->add(block->height(),
-                                       stringToBytes(block_json.value));
+        auto data = stringToBytes(block_json.value);
+        if (compression_level_) {
+          auto compressed = compressBlock(data, *compression_level_);
+          if (iroha::expected::hasError(compressed)) {
+            log_->warn("Error while block compression: {}",
+                       compressed.assumeError());
+            return false;
+          }
+          data = std::move(compressed).assumeValue();
+        }
+        if (not flat_file_storage_->add(block->height(), data)) {
+          return false;
+        }
+        prune(block->height());
+        iroha::getSubscription()->notify(
+            iroha::EventTypes::kOnBlockStoreStats,
+            iroha::BlockStoreStatus{flat_file_storage_->totalSize(),
+                                    flat_file_storage_->lastFsyncDuration()});
+        return true;
       },
       [this](const auto &error) {
         log_->warn("Error while block serialization: {}", error.error);
@@ -41,6 +67,15 @@ FlatFileBlockStorage::fetch(
   if (not storage_block) {
     return boost::none;
   }
+  if (isCompressedBlock(*storage_block)) {
+    auto decompressed = decompressBlock(*storage_block);
+    if (iroha::expected::hasError(decompressed)) {
+      log_->warn("Error while block decompression: {}",
+                 decompressed.assumeError());
+      return boost::none;
+    }
+    storage_block = std::move(decompressed).assumeValue();
+  }
 
   return json_converter_->deserialize(bytesToString(*storage_block))
       .match(
@@ -84,3 +119,69 @@ iroha::expected::Result<void, std::string> FlatFileBlockStorage::forEach(
   }
   return {};
 }
+
+std::optional<shared_model::interface::types::HeightType>
+FlatFileBlockStorage::firstRetainedHeight() const {
+  for (auto block_id : flat_file_storage_->blockIdentifiers()) {
+    if (block_id == 1) {
+      continue;
+    }
+    if (block_id > 2) {
+      return block_id;
+    }
+    break;
+  }
+  return std::nullopt;
+}
+
+size_t FlatFileBlockStorage::truncateCorruptedTail() {
+  size_t removed = 0;
+  while (auto top_height = flat_file_storage_->last_id()) {
+    auto block = fetch(top_height);
+    if (block and (*block)->height() == top_height) {
+      break;
+    }
+    log_->warn("Block {} is corrupted, removing it", top_height);
+    if (not flat_file_storage_->remove(top_height)) {
+      break;
+    }
+    ++removed;
+  }
+  if (removed > 0) {
+    log_->warn(
+        "Removed {} corrupted blocks, the block store now ends at height {}. "
+        "The missing blocks will be fetched from other peers.",
+        removed,
+        flat_file_storage_->last_id());
+  }
+  return removed;
+}
+
+void FlatFileBlockStorage::prune(
+    shared_model::interface::types::HeightType top_height) {
+  static constexpr shared_model::interface::types::HeightType kGenesisHeight =
+      1;
+  if (not keep_blocks_ or top_height <= *keep_blocks_) {
+    return;
+  }
+  const auto keep_from = top_height - *keep_blocks_ + 1;
+  // collect first, since removal invalidates the identifiers collection
+  std::vector<FlatFile::Identifier> outdated;
+  for (auto block_id : flat_file_storage_->blockIdentifiers()) {
+    if (block_id >= keep_from) {
+      break;
+    }
+    if (block_id != kGenesisHeight) {
+      outdated.push_back(block_id);
+    }
+  }
+  for (auto block_id : outdated) {
+    if (not flat_file_storage_->remove(block_id)) {
+      log_->warn("Failed to prune block {}", block_id);
+      return;
+    }
+  }
+  if (not outdated.empty()) {
+    log_->info("Pruned {} blocks below height {}", outdated.size(), keep_from);
+  }
+}
@@ -8,6 +8,8 @@
 
 #include "ametsuchi/block_storage.hpp"
 
+#include <optional>
+
 #include "ametsuchi/impl/flat_file/flat_file.hpp"
 #include "interfaces/iroha_internal/block_json_converter.hpp"
 #include "logger/logger_fwd.hpp"
@@ -16,11 +18,23 @@ namespace iroha {
   namespace ametsuchi {
     class FlatFileBlockStorage : public BlockStorage {
      public:
+      /**
+       * @param flat_file - files storage to keep the blocks in
+       * @param json_converter - block serializer
+       * @param log - logger
+       * @param keep_blocks - if set, only this number of the most recent
+       * blocks and the genesis block are kept, older blocks are removed on
+       * insertion of new ones
+       * @param compression_level - if set, new blocks are stored compressed
+       * with zstd at this level; blocks are read in both formats regardless
+       */
       FlatFileBlockStorage(
           std::unique_ptr<FlatFile> flat_file,
           std::shared_ptr<shared_model::interface::BlockJsonConverter>
               json_converter,
-          logger::LoggerPtr log);
+          logger::LoggerPtr log,
+          std::optional<size_t> keep_blocks = std::nullopt,
+          std::optional<uint32_t> compression_level = std::nullopt);
 
       bool insert(
           std::shared_ptr<const shared_model::interface::Block> block) override;
@@ -37,11 +51,32 @@ namespace iroha {
       expected::Result<void, std::string> forEach(
           FunctionType function) const override;
 
+      /// The blocks are pruned if files are missing after the genesis block
+      std::optional<shared_model::interface::types::HeightType>
+      firstRetainedHeight() const override;
+
+      /**
+       * Remove the blocks at the top of the storage which cannot be read, for
+       * example after a torn write on unclean shutdown, so that the storage
+       * ends with the last valid block. The removed blocks are fetched from
+       * other peers during synchronization.
+       * @return number of removed blocks
+       */
+      size_t truncateCorruptedTail();
+
      private:
+      /**
+       * Remove the blocks which fall out of the retention window ending at
+       * the given height
+       */
+      void prune(shared_model::interface::types::HeightType top_height);
+
       std::unique_ptr<FlatFile> flat_file_storage_;
       std::shared_ptr<shared_model::interface::BlockJsonConverter>
           json_converter_;
       logger::LoggerPtr log_;
+      std::optional<size_t> keep_blocks_;
+      std::optional<uint32_t> compression_level_;
     };
   }  // namespace ametsuchi
 }  // namespace iroha
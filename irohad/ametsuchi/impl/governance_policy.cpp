@@ -0,0 +1,75 @@
+/**
+ * Copyright Soramitsu Co., Ltd. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+#include "ametsuchi/governance_policy.hpp"
+
+#include <fmt/core.h>
+#include <rapidjson/document.h>
+#include <rapidjson/error/en.h>
+
+using iroha::ametsuchi::GovernancePolicy;
+
+const shared_model::interface::types::SettingKeyType
+    iroha::ametsuchi::kGovernancePolicyKey = "GovernancePolicy";
+
+iroha::expected::Result<GovernancePolicy, std::string> GovernancePolicy::parse(
+    std::string_view json) {
+  rapidjson::Document document;
+  document.Parse(json.data(), json.size());
+  if (document.HasParseError()) {
+    return iroha::expected::makeError(
+        fmt::format("Malformed governance policy: {}",
+                    rapidjson::GetParseError_En(document.GetParseError())));
+  }
+  if (not document.IsObject()) {
+    return iroha::expected::makeError(
+        std::string{"Governance policy must be an object"});
+  }
+
+  GovernancePolicy policy;
+  auto voters = document.FindMember("voters");
+  if (voters == document.MemberEnd() or not voters->value.IsObject()
+      or voters->value.ObjectEmpty()) {
+    return iroha::expected::makeError(std::string{
+        "`voters' must be a non-empty object of account ids to weights"});
+  }
+  for (auto const &voter : voters->value.GetObject()) {
+    if (not voter.value.IsUint64() or voter.value.GetUint64() == 0) {
+      return iroha::expected::makeError(
+          fmt::format("Weight of `{}' must be a positive integer",
+                      voter.name.GetString()));
+    }
+    policy.voters.emplace(
+        std::string{voter.name.GetString(), voter.name.GetStringLength()},
+        voter.value.GetUint64());
+  }
+
+  auto threshold = document.FindMember("threshold");
+  if (threshold == document.MemberEnd() or not threshold->value.IsUint64()
+      or threshold->value.GetUint64() == 0) {
+    return iroha::expected::makeError(
+        std::string{"`threshold' must be a positive integer"});
+  }
+  policy.threshold = threshold->value.GetUint64();
+
+  auto voting_period = document.FindMember("voting_period");
+  if (voting_period == document.MemberEnd()
+      or not voting_period->value.IsUint64()
+      or voting_period->value.GetUint64() == 0) {
+    return iroha::expected::makeError(
+        std::string{"`voting_period' must be a positive number of blocks"});
+  }
+  policy.voting_period = voting_period->value.GetUint64();
+
+  return iroha::expected::makeValue(std::move(policy));
+}
+
+std::optional<uint64_t> GovernancePolicy::weight(
+    const shared_model::interface::types::AccountIdType &account_id) const {
+  if (auto it = voters.find(account_id); it != voters.end()) {
+    return it->second;
+  }
+  return std::nullopt;
+}
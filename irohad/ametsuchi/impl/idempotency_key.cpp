@@ -0,0 +1,57 @@
+/**
+ * Copyright Soramitsu Co., Ltd. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+#include "ametsuchi/idempotency_key.hpp"
+
+#include <charconv>
+
+#include <fmt/core.h>
+
+using iroha::ametsuchi::IdempotencyRecord;
+
+const shared_model::interface::types::SettingKeyType
+    iroha::ametsuchi::kIdempotencyWindowKey = "IdempotencyWindow";
+
+namespace {
+  bool parseNumber(std::string_view value, uint64_t &number) {
+    auto [ptr, ec] =
+        std::from_chars(value.data(), value.data() + value.size(), number);
+    return not value.empty() and ec == std::errc()
+        and ptr == value.data() + value.size();
+  }
+}  // namespace
+
+bool IdempotencyRecord::expired(
+    shared_model::interface::types::TimestampType time,
+    uint64_t window) const {
+  return created_time + window <= time;
+}
+
+std::string IdempotencyRecord::encode() const {
+  return fmt::format("{}|{}", tx_hash, created_time);
+}
+
+std::optional<IdempotencyRecord> IdempotencyRecord::decode(
+    std::string_view value) {
+  auto const pos = value.find('|');
+  if (pos == std::string_view::npos) {
+    return std::nullopt;
+  }
+  IdempotencyRecord record;
+  if (not parseNumber(value.substr(pos + 1), record.created_time)) {
+    return std::nullopt;
+  }
+  record.tx_hash = value.substr(0, pos);
+  return record;
+}
+
+uint64_t iroha::ametsuchi::parseIdempotencyWindow(
+    std::optional<std::string_view> value) {
+  uint64_t window;
+  if (value and parseNumber(*value, window)) {
+    return window;
+  }
+  return kDefaultIdempotencyWindow;
+}
@@ -19,6 +19,7 @@
 #include "ametsuchi/tx_executor.hpp"
 #include "interfaces/commands/command.hpp"
 #include "interfaces/iroha_internal/block.hpp"
+#include "logger/log_fields.hpp"
 #include "logger/logger.hpp"
 #include "logger/logger_manager.hpp"
 
@@ -49,6 +50,7 @@ namespace iroha::ametsuchi {
       std::shared_ptr<const shared_model::interface::Block> block,
       MutableStoragePredicate predicate) {
     auto execute_transaction = [this](auto &transaction) -> bool {
+      logger::LogFieldGuard tx_hash_field{"tx_hash", transaction.hash().hex()};
       auto result = transaction_executor_->execute(transaction, false);
       auto error = expected::resultToOptionalError(result);
       if (error) {
@@ -58,6 +60,8 @@ namespace iroha::ametsuchi {
       return ok;
     };
 
+    logger::LogFieldGuard height_field{"height",
+                                       std::to_string(block->height())};
     log_->info("Applying block: height {}, hash {}",
                block->height(),
                block->hash().hex());
@@ -14,6 +14,12 @@
 #include <boost/algorithm/string.hpp>
 #include <boost/algorithm/string/join.hpp>
 #include <boost/format.hpp>
+#include "ametsuchi/account_detail_limits.hpp"
+#include "ametsuchi/account_detail_path.hpp"
+#include "ametsuchi/asset_lock.hpp"
+#include "ametsuchi/domain_policy.hpp"
+#include "ametsuchi/governance_policy.hpp"
+#include "ametsuchi/idempotency_key.hpp"
 #include "ametsuchi/impl/executor_common.hpp"
 #include "ametsuchi/impl/postgres_block_storage.hpp"
 #include "ametsuchi/impl/postgres_burrow_storage.hpp"
@@ -22,31 +28,51 @@
 #include "ametsuchi/impl/soci_string_view.hpp"
 #include "ametsuchi/impl/soci_utils.hpp"
 #include "ametsuchi/setting_query.hpp"
+#include "ametsuchi/setting_registry.hpp"
+#include "ametsuchi/spending_limit.hpp"
+#include "ametsuchi/supply_policy.hpp"
+#include "ametsuchi/transfer_policy.hpp"
+#include "ametsuchi/vesting_schedule.hpp"
 #include "ametsuchi/vm_caller.hpp"
 #include "interfaces/commands/add_asset_quantity.hpp"
 #include "interfaces/commands/add_peer.hpp"
 #include "interfaces/commands/add_signatory.hpp"
 #include "interfaces/commands/append_role.hpp"
+#include "interfaces/commands/archive_domain.hpp"
+#include "interfaces/commands/authorize_session_key.hpp"
 #include "interfaces/commands/call_engine.hpp"
+#include "interfaces/commands/claim_with_preimage.hpp"
 #include "interfaces/commands/command.hpp"
 #include "interfaces/commands/compare_and_set_account_detail.hpp"
 #include "interfaces/commands/create_account.hpp"
 #include "interfaces/commands/create_asset.hpp"
 #include "interfaces/commands/create_domain.hpp"
 #include "interfaces/commands/create_role.hpp"
+#include "interfaces/commands/deactivate_account.hpp"
 #include "interfaces/commands/detach_role.hpp"
 #include "interfaces/commands/grant_permission.hpp"
+#include "interfaces/commands/grant_vested_asset.hpp"
+#include "interfaces/commands/include_role.hpp"
+#include "interfaces/commands/lock_asset.hpp"
+#include "interfaces/commands/propose_setting_value.hpp"
+#include "interfaces/commands/recover_account.hpp"
+#include "interfaces/commands/refund_after_timeout.hpp"
 #include "interfaces/commands/remove_peer.hpp"
 #include "interfaces/commands/remove_signatory.hpp"
 #include "interfaces/commands/revoke_permission.hpp"
+#include "interfaces/commands/rotate_key.hpp"
 #include "interfaces/commands/set_account_detail.hpp"
+#include "interfaces/commands/set_account_detail_at_path.hpp"
 #include "interfaces/commands/set_quorum.hpp"
 #include "interfaces/commands/set_setting_value.hpp"
+#include "interfaces/commands/set_spending_limit.hpp"
 #include "interfaces/commands/subtract_asset_quantity.hpp"
 #include "interfaces/commands/transfer_asset.hpp"
+#include "interfaces/commands/vote_for_proposal.hpp"
 #include "interfaces/common_objects/types.hpp"
 #include "interfaces/permission_to_string.hpp"
 #include "interfaces/permissions.hpp"
+#include "interfaces/transaction.hpp"
 #include "utils/string_builder.hpp"
 
 using shared_model::interface::permissions::Grantable;
@@ -104,6 +130,8 @@ namespace {
                      std::map<int, int>{std::make_pair(0, 3)}),
       std::make_pair("SetAccountDetail",
                      std::map<int, int>{std::make_pair(0, 3)}),
+      std::make_pair("SetAccountDetailAtPath",
+                     std::map<int, int>{std::make_pair(0, 3)}),
       std::make_pair("SetQuorum", std::map<int, int>{std::make_pair(0, 3)}),
       std::make_pair("GrantPermission",
                      std::map<int, int>{std::make_pair(1, 3)}),
@@ -374,6 +402,412 @@ namespace {
             % creator_id % account_id)
         .str();
   }
+
+  /**
+   * Check the transfer against the policy of the asset, if it is set
+   * @tparam Command TransferAsset or LockAsset
+   * @return error with code 9 if the transfer is not allowed
+   */
+  template <typename Command>
+  iroha::ametsuchi::CommandResult checkTransferPolicy(
+      soci::session &sql,
+      const std::string &command_name,
+      const Command &command) {
+    auto const key = iroha::ametsuchi::transferPolicyKey(command.assetId());
+    std::optional<std::string> value;
+    sql << "SELECT setting_value FROM setting WHERE setting_key = :key",
+        soci::use(key, "key"), soci::into(value);
+    if (not value) {
+      return {};
+    }
+    auto policy = iroha::ametsuchi::TransferPolicy::parse(*value);
+    if (auto e = iroha::expected::resultToOptionalError(policy)) {
+      return makeCommandError(command_name, 1, std::move(*e));
+    }
+    auto not_allowed = [&](std::string_view reason) {
+      return makeCommandError(
+          command_name,
+          9,
+          fmt::format("Transfer of {} from {} to {} is not allowed: {}",
+                      command.assetId(),
+                      command.srcAccountId(),
+                      command.destAccountId(),
+                      reason));
+    };
+    for (auto const &account_id :
+         {command.srcAccountId(), command.destAccountId()}) {
+      if (not policy.assumeValue().isWhitelisted(account_id)) {
+        return not_allowed(fmt::format("{} is not whitelisted", account_id));
+      }
+      if (auto const &kyc = policy.assumeValue().kyc) {
+        std::optional<std::string> verified;
+        sql << "SELECT data->:writer->>:key FROM account "
+               "WHERE account_id = :account_id",
+            soci::use(kyc->writer, "writer"), soci::use(kyc->key, "key"),
+            soci::use(account_id, "account_id"), soci::into(verified);
+        if (verified != iroha::ametsuchi::kTransferPolicyKycVerified) {
+          return not_allowed(fmt::format("{} has not passed KYC", account_id));
+        }
+      }
+    }
+    return {};
+  }
+
+  /// Read the supply policy of the asset, if it is set
+  iroha::expected::Result<std::optional<iroha::ametsuchi::SupplyPolicy>,
+                          std::string>
+  getSupplyPolicy(soci::session &sql,
+                  const shared_model::interface::types::AssetIdType &asset_id) {
+    auto const key = iroha::ametsuchi::supplyPolicyKey(asset_id);
+    std::optional<std::string> value;
+    sql << "SELECT setting_value FROM setting WHERE setting_key = :key",
+        soci::use(key, "key"), soci::into(value);
+    if (not value) {
+      return iroha::expected::makeValue(std::nullopt);
+    }
+    return iroha::ametsuchi::SupplyPolicy::parse(*value) | [](auto policy) {
+      return iroha::expected::makeValue(
+          std::optional<iroha::ametsuchi::SupplyPolicy>{std::move(policy)});
+    };
+  }
+
+  /**
+   * Check the issuance against the supply policy of the asset, if it is set
+   * @tparam Command AddAssetQuantity or GrantVestedAsset
+   * @return error with code 5 if the supply cannot be increased
+   */
+  template <typename Command>
+  iroha::ametsuchi::CommandResult checkSupplyPolicy(
+      soci::session &sql,
+      const std::string &command_name,
+      const Command &command) {
+    auto policy = getSupplyPolicy(sql, command.assetId());
+    if (auto e = iroha::expected::resultToOptionalError(policy)) {
+      return makeCommandError(command_name, 1, std::move(*e));
+    }
+    if (not policy.assumeValue()) {
+      return {};
+    }
+    if (policy.assumeValue()->decreasing_only) {
+      return makeCommandError(
+          command_name,
+          5,
+          fmt::format("Supply of {} can only decrease", command.assetId()));
+    }
+    if (not policy.assumeValue()->max_supply) {
+      return {};
+    }
+    // locked assets are still in circulation
+    auto const quantity = command.amount().toStringRepr();
+    auto const max_supply = policy.assumeValue()->max_supply->toStringRepr();
+    int allowed = 0;
+    sql << "SELECT CASE WHEN COALESCE(SUM(amount), 0) + :quantity::decimal "
+           "<= :max_supply::decimal THEN 1 ELSE 0 END FROM ("
+           "SELECT amount FROM account_has_asset WHERE asset_id = :asset_id "
+           "UNION ALL "
+           "SELECT amount FROM asset_lock WHERE asset_id = :lock_asset_id) t",
+        soci::use(quantity, "quantity"), soci::use(max_supply, "max_supply"),
+        soci::use(command.assetId(), "asset_id"),
+        soci::use(command.assetId(), "lock_asset_id"), soci::into(allowed);
+    if (allowed == 0) {
+      return makeCommandError(
+          command_name,
+          5,
+          fmt::format("Supply of {} would exceed the cap of {}",
+                      command.assetId(),
+                      max_supply));
+    }
+    return {};
+  }
+
+  /**
+   * Add the quantity to the total minted or burned quantity of the asset
+   * @param minted whether the quantity is added to accounts or burned
+   */
+  void addToAssetStats(
+      soci::session &sql,
+      const shared_model::interface::types::AssetIdType &asset_id,
+      const std::string &quantity,
+      bool minted) {
+    sql << fmt::format(
+        "INSERT INTO asset_stats(asset_id, {0}) "
+        "VALUES (:asset_id, :quantity::decimal) "
+        "ON CONFLICT (asset_id) DO UPDATE "
+        "SET {0} = asset_stats.{0} + EXCLUDED.{0}",
+        minted ? "minted" : "burned"),
+        soci::use(asset_id, "asset_id"), soci::use(quantity, "quantity");
+  }
+
+  /**
+   * Read the vesting schedule of the asset granted to the account, if it is
+   * set, and the height of the block being built
+   */
+  iroha::expected::Result<
+      std::pair<std::optional<iroha::ametsuchi::VestingSchedule>,
+                shared_model::interface::types::HeightType>,
+      std::string>
+  getVestingSchedule(
+      soci::session &sql,
+      const shared_model::interface::types::AccountIdType &account_id,
+      const shared_model::interface::types::AssetIdType &asset_id) {
+    std::optional<std::string> schedule;
+    size_t height = 0;
+    sql << "SELECT COALESCE((SELECT height FROM top_block_info), 0) + 1, "
+           "(SELECT concat_ws('|', amount, start_height, cliff, duration) "
+           "FROM vesting_schedule "
+           "WHERE account_id = :account_id AND asset_id = :asset_id)",
+        soci::use(account_id, "account_id"), soci::use(asset_id, "asset_id"),
+        soci::into(height), soci::into(schedule);
+    using ReturnType =
+        std::pair<std::optional<iroha::ametsuchi::VestingSchedule>,
+                  shared_model::interface::types::HeightType>;
+    if (not schedule) {
+      return iroha::expected::makeValue(ReturnType{std::nullopt, height});
+    }
+    return iroha::ametsuchi::VestingSchedule::decode(*schedule) |
+        [height](auto decoded) {
+          return iroha::expected::makeValue(
+              ReturnType{std::move(decoded), height});
+        };
+  }
+
+  /**
+   * Check that the balance of the account left after spending does not go
+   * below the amount of the asset locked by the vesting schedule, if it is set
+   * @param error_code code of the command error if the balance is too low
+   */
+  iroha::ametsuchi::CommandResult checkVestingSchedule(
+      soci::session &sql,
+      const std::string &command_name,
+      iroha::ametsuchi::CommandError::ErrorCodeType error_code,
+      const shared_model::interface::types::AccountIdType &account_id,
+      const shared_model::interface::types::AssetIdType &asset_id) {
+    auto schedule = getVestingSchedule(sql, account_id, asset_id);
+    if (auto e = iroha::expected::resultToOptionalError(schedule)) {
+      return makeCommandError(command_name, 1, std::move(*e));
+    }
+    auto const &[opt_schedule, height] = schedule.assumeValue();
+    if (not opt_schedule) {
+      return {};
+    }
+    auto const locked = opt_schedule->locked(height).toStringRepr();
+    int allowed = 0;
+    sql << "SELECT CASE WHEN COALESCE(SUM(amount), 0) >= :locked::decimal "
+           "THEN 1 ELSE 0 END FROM account_has_asset "
+           "WHERE account_id = :account_id AND asset_id = :asset_id",
+        soci::use(locked, "locked"), soci::use(account_id, "account_id"),
+        soci::use(asset_id, "asset_id"), soci::into(allowed);
+    if (allowed == 0) {
+      return makeCommandError(
+          command_name,
+          error_code,
+          fmt::format("Not enough assets: {} of {} is locked until vested",
+                      locked,
+                      asset_id));
+    }
+    return {};
+  }
+
+  /**
+   * Read the spending limit of the asset of the account, if it is set, and
+   * the height of the block being built
+   */
+  iroha::expected::Result<
+      std::pair<std::optional<iroha::ametsuchi::SpendingLimit>,
+                shared_model::interface::types::HeightType>,
+      std::string>
+  getSpendingLimit(
+      soci::session &sql,
+      const shared_model::interface::types::AccountIdType &account_id,
+      const shared_model::interface::types::AssetIdType &asset_id) {
+    std::optional<std::string> limit;
+    size_t height = 0;
+    sql << "SELECT COALESCE((SELECT height FROM top_block_info), 0) + 1, "
+           "(SELECT concat_ws('|', amount, window_blocks, spendings) "
+           "FROM spending_limit "
+           "WHERE account_id = :account_id AND asset_id = :asset_id)",
+        soci::use(account_id, "account_id"), soci::use(asset_id, "asset_id"),
+        soci::into(height), soci::into(limit);
+    using ReturnType =
+        std::pair<std::optional<iroha::ametsuchi::SpendingLimit>,
+                  shared_model::interface::types::HeightType>;
+    if (not limit) {
+      return iroha::expected::makeValue(ReturnType{std::nullopt, height});
+    }
+    return iroha::ametsuchi::SpendingLimit::decode(*limit) |
+        [height](auto decoded) {
+          return iroha::expected::makeValue(
+              ReturnType{std::move(decoded), height});
+        };
+  }
+
+  /**
+   * Record the amount spent by the account if its spending limit of the
+   * asset is set
+   * @param do_validation whether the limit is checked before recording
+   * @return error with code 10 if the limit is exceeded
+   */
+  iroha::ametsuchi::CommandResult spendWithinLimit(
+      soci::session &sql,
+      const std::string &command_name,
+      const shared_model::interface::types::AccountIdType &account_id,
+      const shared_model::interface::types::AssetIdType &asset_id,
+      const shared_model::interface::Amount &amount,
+      bool do_validation) {
+    auto limit = getSpendingLimit(sql, account_id, asset_id);
+    if (auto e = iroha::expected::resultToOptionalError(limit)) {
+      return makeCommandError(command_name, 1, std::move(*e));
+    }
+    auto &[opt_limit, height] = limit.assumeValue();
+    if (not opt_limit) {
+      return {};
+    }
+    if (do_validation and not opt_limit->allows(height, amount)) {
+      return makeCommandError(
+          command_name,
+          10,
+          fmt::format("Spending limit of {} exceeded: {} of {} is spent "
+                      "within {} blocks",
+                      asset_id,
+                      opt_limit->spent(height).toStringRepr(),
+                      opt_limit->amount.toStringRepr(),
+                      opt_limit->window));
+    }
+    opt_limit->spend(height, amount);
+    auto const spendings = opt_limit->encodeSpendings();
+    sql << "UPDATE spending_limit SET spendings = :spendings "
+           "WHERE account_id = :account_id AND asset_id = :asset_id",
+        soci::use(spendings, "spendings"), soci::use(account_id, "account_id"),
+        soci::use(asset_id, "asset_id");
+    return {};
+  }
+
+  /**
+   * Apply the policy of the domain, if it is set, to the created account:
+   * grant the permissions of the policy to the permittees which exist
+   */
+  iroha::expected::Result<void, std::string> applyDomainPolicy(
+      soci::session &sql,
+      const shared_model::interface::types::AccountIdType &account_id,
+      const shared_model::interface::types::DomainIdType &domain_id) {
+    auto const key = iroha::ametsuchi::domainPolicyKey(domain_id);
+    std::optional<std::string> setting_value;
+    sql << "SELECT setting_value FROM setting WHERE setting_key = :key",
+        soci::use(key, "key"), soci::into(setting_value);
+    if (not setting_value) {
+      return {};
+    }
+    auto policy = iroha::ametsuchi::DomainPolicy::parse(*setting_value);
+    if (auto e = iroha::expected::resultToOptionalError(policy)) {
+      return iroha::expected::makeError(std::move(*e));
+    }
+    for (auto const &[permittee, permissions] : policy.assumeValue().grants) {
+      auto const bits = permissions.toBitstring();
+      sql << "INSERT INTO account_has_grantable_permissions"
+             "(permittee_account_id, account_id, permission) "
+             "SELECT :permittee, :account_id, :permission "
+             "WHERE EXISTS (SELECT * FROM account "
+             "WHERE account_id = :permittee)",
+          soci::use(permittee, "permittee"),
+          soci::use(account_id, "account_id"), soci::use(bits, "permission");
+    }
+    return {};
+  }
+
+  /// Read the account detail limit setting, if it is set
+  iroha::expected::Result<std::optional<uint64_t>, std::string>
+  getAccountDetailLimit(soci::session &sql, const std::string &setting_key) {
+    std::optional<std::string> setting_value;
+    sql << "SELECT setting_value FROM setting WHERE setting_key = :key",
+        soci::use(setting_key, "key"), soci::into(setting_value);
+    if (not setting_value) {
+      return iroha::expected::makeValue(std::nullopt);
+    }
+    return iroha::ametsuchi::parseAccountDetailLimit(*setting_value) |
+        [](auto limit) {
+          return iroha::expected::makeValue(std::optional<uint64_t>{limit});
+        };
+  }
+
+  /**
+   * Check the account detail against the limits, if they are set
+   * @param writer - account which sets the detail
+   * @return error with code 5 if the value is too big and with code 6 if the
+   * account has too many details
+   */
+  iroha::ametsuchi::CommandResult checkAccountDetailLimits(
+      soci::session &sql,
+      const std::string &command_name,
+      const shared_model::interface::types::AccountIdType &account_id,
+      const shared_model::interface::types::AccountIdType &writer,
+      const shared_model::interface::types::AccountDetailKeyType &key,
+      const shared_model::interface::types::AccountDetailValueType &value) {
+    auto max_value_size = getAccountDetailLimit(
+        sql, iroha::ametsuchi::kMaxAccountDetailValueSizeKey);
+    if (auto e = iroha::expected::resultToOptionalError(max_value_size)) {
+      return makeCommandError(command_name, 1, std::move(*e));
+    }
+    if (auto const &limit = max_value_size.assumeValue();
+        limit and value.size() > *limit) {
+      return makeCommandError(
+          command_name,
+          5,
+          fmt::format("Value of account detail {} is {} bytes, the limit is {}",
+                      key,
+                      value.size(),
+                      *limit));
+    }
+
+    auto max_keys =
+        getAccountDetailLimit(sql, iroha::ametsuchi::kMaxAccountDetailKeysKey);
+    if (auto e = iroha::expected::resultToOptionalError(max_keys)) {
+      return makeCommandError(command_name, 1, std::move(*e));
+    }
+    if (auto const &limit = max_keys.assumeValue()) {
+      int exists = 0;
+      sql << "SELECT count(1) FROM account "
+             "WHERE account_id = :account_id AND data->:writer ? :key",
+          soci::use(account_id, "account_id"), soci::use(writer, "writer"),
+          soci::use(key, "key"), soci::into(exists);
+      if (exists) {
+        return {};
+      }
+      int count = 0;
+      sql << "SELECT count(1) FROM account, jsonb_each(data) AS writers, "
+             "jsonb_object_keys(writers.value) "
+             "WHERE account_id = :account_id",
+          soci::use(account_id, "account_id"), soci::into(count);
+      if (static_cast<uint64_t>(count) >= *limit) {
+        return makeCommandError(
+            command_name,
+            6,
+            fmt::format("Account {} has {} details, the limit is {}",
+                        account_id,
+                        count,
+                        *limit));
+      }
+    }
+    return {};
+  }
+
+  /// Read the governance policy, if it is set
+  iroha::expected::Result<std::optional<iroha::ametsuchi::GovernancePolicy>,
+                          std::string>
+  getGovernancePolicy(soci::session &sql) {
+    std::optional<std::string> setting_value;
+    sql << "SELECT setting_value FROM setting WHERE setting_key = :key",
+        soci::use(iroha::ametsuchi::kGovernancePolicyKey, "key"),
+        soci::into(setting_value);
+    if (not setting_value) {
+      return iroha::expected::makeValue(std::nullopt);
+    }
+    return iroha::ametsuchi::GovernancePolicy::parse(*setting_value) |
+        [](auto policy) {
+          return iroha::expected::makeValue(
+              std::optional<iroha::ametsuchi::GovernancePolicy>{
+                  std::move(policy)});
+        };
+  }
 }  // namespace
 
 namespace iroha {
@@ -648,6 +1082,97 @@ namespace iroha {
            "WHERE (SELECT * FROM has_perm)",
            "WHEN NOT (SELECT * from has_perm) THEN 2"});
 
+      authorize_session_key_statements_ = makeCommandStatements(
+          sql_,
+          R"(
+          WITH %s
+            target AS (
+                SELECT account_id FROM account
+                WHERE account_id = :target %s
+            ),
+            removed AS (
+                DELETE FROM account_session_key
+                WHERE :validity::bigint = 0
+                  AND account_id IN (SELECT account_id FROM target)
+                  AND public_key = lower(:pubkey)
+                RETURNING (1)
+            ),
+            asset_allowed AS (
+                SELECT :allowed_asset_id = ''
+                    OR EXISTS (SELECT * FROM asset
+                               WHERE asset_id = :allowed_asset_id)
+            ),
+            signatory AS (
+                SELECT public_key FROM account_has_signatory
+                WHERE account_id = :target
+                  AND public_key = lower(:pubkey)
+            ),
+            upserted AS (
+                INSERT INTO account_session_key(account_id, public_key,
+                    permissions, allowed_asset_id, expires_height)
+                (
+                    SELECT account_id, lower(:pubkey), :permissions,
+                        :allowed_asset_id,
+                        COALESCE((SELECT height FROM top_block_info), 0) + 1
+                            + :validity::bigint
+                    FROM target
+                    WHERE :validity::bigint > 0
+                      AND (SELECT * FROM asset_allowed)
+                      AND NOT EXISTS (SELECT * FROM signatory)
+                )
+                ON CONFLICT (account_id, public_key)
+                  DO UPDATE SET permissions = excluded.permissions,
+                      allowed_asset_id = excluded.allowed_asset_id,
+                      expires_height = excluded.expires_height
+                RETURNING (1)
+            )
+          SELECT CASE
+            WHEN EXISTS (SELECT * FROM upserted) THEN 0
+            WHEN :validity::bigint = 0
+                AND EXISTS (SELECT * FROM target) THEN 0
+            WHEN NOT EXISTS
+                    (SELECT * FROM account WHERE account_id = :target) THEN 3
+            %s
+            WHEN NOT (SELECT * FROM asset_allowed) THEN 4
+            WHEN EXISTS (SELECT * FROM signatory) THEN 5
+            ELSE 1
+          END AS result)",
+          {(boost::format(R"(
+            has_perm AS (%s),)")
+            % checkAccountHasRoleOrGrantablePerm(Role::kAddSignatory,
+                                                 Grantable::kAddMySignatory,
+                                                 ":creator",
+                                                 ":target"))
+               .str(),
+           R"( AND (SELECT * FROM has_perm))",
+           R"( WHEN NOT (SELECT * FROM has_perm) THEN 2 )"});
+
+      archive_domain_statements_ = makeCommandStatements(
+          sql_,
+          R"(
+          WITH %s
+            updated AS (
+                UPDATE domain SET archived = true
+                WHERE domain_id = :domain AND NOT archived
+                %s
+                RETURNING (1)
+            )
+          SELECT CASE
+            WHEN EXISTS (SELECT * FROM updated) THEN 0
+            %s
+            WHEN NOT EXISTS
+                    (SELECT * FROM domain WHERE domain_id = :domain) THEN 3
+            WHEN EXISTS (SELECT * FROM domain
+                         WHERE domain_id = :domain AND archived) THEN 4
+            ELSE 1
+          END AS result)",
+          {(boost::format(R"(
+              has_perm AS (%s),)")
+            % checkAccountRolePermission(Role::kArchiveDomain, ":creator"))
+               .str(),
+           R"( AND (SELECT * FROM has_perm))",
+           R"( WHEN NOT (SELECT * FROM has_perm) THEN 2 )"});
+
       append_role_statements_ = makeCommandStatements(
           sql_,
           R"(
@@ -799,6 +1324,10 @@ namespace iroha {
               R"(
           WITH get_domain_default_role AS (SELECT default_role FROM domain
                                              WHERE domain_id = :domain),
+            archived AS (
+                SELECT EXISTS (SELECT * FROM domain
+                               WHERE domain_id = :domain AND archived)
+            ),
             %s
             insert_signatory AS
             (
@@ -806,6 +1335,7 @@ namespace iroha {
                 (
                     SELECT lower(:pubkey)
                     WHERE EXISTS (SELECT * FROM get_domain_default_role)
+                      AND NOT (SELECT * FROM archived)
                       %s
                 )
                 ON CONFLICT (public_key)
@@ -843,6 +1373,7 @@ namespace iroha {
             WHEN EXISTS (SELECT * FROM insert_account_role) THEN 0
             WHEN NOT EXISTS (SELECT * FROM get_domain_default_role) THEN 3
             %s
+            WHEN (SELECT * FROM archived) THEN 5
             ELSE 1
           END AS result)",
               {(boost::format(R"(
@@ -878,24 +1409,30 @@ namespace iroha {
           sql_,
           R"(
           WITH %s
+            archived AS (
+                SELECT EXISTS (SELECT * FROM domain
+                               WHERE domain_id = :domain AND archived)
+            ),
             inserted AS
             (
                 INSERT INTO asset(asset_id, domain_id, precision)
                 (
                     SELECT :asset_id, :domain, :precision
+                    WHERE NOT (SELECT * FROM archived)
                     %s
                 ) RETURNING (1)
             )
           SELECT CASE
             WHEN EXISTS (SELECT * FROM inserted) THEN 0
             %s
+            WHEN (SELECT * FROM archived) THEN 5
             ELSE 1
           END AS result)",
           {(boost::format(R"(
               has_perm AS (%s),)")
             % checkAccountRolePermission(Role::kCreateAsset, ":creator"))
                .str(),
-           R"(WHERE (SELECT * FROM has_perm))",
+           R"(AND (SELECT * FROM has_perm))",
            R"(WHEN NOT (SELECT * FROM has_perm) THEN 2)"});
 
       create_domain_statements_ = makeCommandStatements(
@@ -963,6 +1500,33 @@ namespace iroha {
                AND NOT (SELECT * FROM has_root_perm) THEN 2
               WHEN NOT (SELECT * FROM has_perm) THEN 2)"});
 
+      deactivate_account_statements_ = makeCommandStatements(
+          sql_,
+          R"(
+          WITH %s
+            updated AS (
+                UPDATE account SET deactivated = true
+                WHERE account_id = :target AND NOT deactivated
+                %s
+                RETURNING (1)
+            )
+          SELECT CASE
+            WHEN EXISTS (SELECT * FROM updated) THEN 0
+            WHEN NOT EXISTS
+                    (SELECT * FROM account WHERE account_id = :target) THEN 3
+            %s
+            WHEN EXISTS (SELECT * FROM account
+                         WHERE account_id = :target AND deactivated) THEN 4
+            ELSE 1
+          END AS result)",
+          {(boost::format(R"(
+            has_perm AS (SELECT :creator = :target OR (%s)),)")
+            % checkAccountGrantablePermission(
+                  Grantable::kRecoverMyAccount, ":creator", ":target"))
+               .str(),
+           R"( AND (SELECT * FROM has_perm))",
+           R"( WHEN NOT (SELECT * FROM has_perm) THEN 2 )"});
+
       detach_role_statements_ = makeCommandStatements(
           sql_,
           R"(
@@ -1018,61 +1582,281 @@ namespace iroha {
            R"( WHERE (SELECT * FROM has_perm))",
            R"(WHEN NOT (SELECT * FROM has_perm) THEN 2)"});
 
-      remove_peer_statements_ = makeCommandStatements(
+      grant_vested_asset_statements_ = makeCommandStatements(
           sql_,
           R"(
           WITH %s
-          removed AS (
-              DELETE FROM peer WHERE public_key = lower(:pubkey)
-              %s
-              RETURNING (1)
-          )
+             new_quantity AS
+             (
+                 SELECT :quantity::decimal + coalesce(sum(amount), 0) as value
+                 FROM account_has_asset
+                 WHERE asset_id = :asset_id
+                     AND account_id = :dest_account_id
+             ),
+             checks AS -- error code and check result
+             (
+                 -- dest account exists
+                 SELECT 3 code, count(1) = 1 result
+                 FROM account
+                 WHERE account_id = :dest_account_id
+
+                 -- asset exists
+                 UNION
+                 SELECT 4, count(1) = 1
+                 FROM asset
+                 WHERE asset_id = :asset_id
+                    AND precision >= :precision
+
+                 -- quantity overflow
+                 UNION
+                 SELECT
+                    7,
+                    value < (2::decimal ^ 256) / (10::decimal ^ precision)
+                 FROM new_quantity, asset
+                 WHERE asset_id = :asset_id
+             ),
+             inserted AS
+             (
+                INSERT INTO account_has_asset(account_id, asset_id, amount)
+                (
+                    SELECT :dest_account_id, :asset_id, value FROM new_quantity
+                    WHERE (SELECT bool_and(checks.result) FROM checks) %s
+                )
+                ON CONFLICT (account_id, asset_id) DO UPDATE
+                SET amount = EXCLUDED.amount
+                RETURNING (1)
+             ),
+             inserted_schedule AS
+             (
+                INSERT INTO vesting_schedule(account_id, asset_id, amount,
+                                             start_height, cliff, duration)
+                (
+                    SELECT :dest_account_id, :asset_id, :quantity::decimal,
+                        COALESCE((SELECT height FROM top_block_info), 0) + 1,
+                        :cliff, :duration
+                    WHERE EXISTS (SELECT * FROM inserted)
+                )
+                ON CONFLICT (account_id, asset_id) DO UPDATE
+                SET amount = EXCLUDED.amount,
+                    start_height = EXCLUDED.start_height,
+                    cliff = EXCLUDED.cliff,
+                    duration = EXCLUDED.duration
+                RETURNING (1)
+             )
           SELECT CASE
-            WHEN EXISTS (SELECT * FROM removed) THEN 0
-            %s
-            ELSE 1
-          END AS result)",
+              %s
+              WHEN EXISTS (SELECT * FROM inserted_schedule LIMIT 1) THEN 0
+              ELSE (SELECT code FROM checks WHERE not result ORDER BY code ASC LIMIT 1)
+          END AS result;)",
           {(boost::format(R"(
-            has_perm AS (%s),
-            get_peer AS (
-              SELECT * from peer WHERE public_key = lower(:pubkey) LIMIT 1
-            ),
-            check_peers AS (
-              SELECT 1 WHERE (SELECT COUNT(*) FROM peer) > 1
-            ),)")
-            % checkAccountRolePermission(
-                  Role::kAddPeer, Role::kRemovePeer, ":creator"))
+              has_role_perm AS (%s),
+              dest_can_receive AS (%s),
+              has_perm AS
+              (
+                  SELECT (SELECT * FROM has_role_perm)
+                      AND (SELECT * FROM dest_can_receive)
+              ),
+              )")
+            % checkAccountDomainRoleOrGlobalRolePermission(
+                  Role::kAddAssetQty,
+                  Role::kAddDomainAssetQty,
+                  ":creator",
+                  ":asset_id")
+            % checkAccountRolePermission(Role::kReceive, ":dest_account_id"))
                .str(),
-           R"(
-             AND (SELECT * FROM has_perm)
-             AND EXISTS (SELECT * FROM get_peer)
-             AND EXISTS (SELECT * FROM check_peers))",
-           R"(
-             WHEN NOT EXISTS (SELECT * from get_peer) THEN 3
-             WHEN NOT EXISTS (SELECT * from check_peers) THEN 4
-             WHEN NOT (SELECT * from has_perm) THEN 2)"});
+           "AND (SELECT * from has_perm)",
+           "WHEN NOT (SELECT * from has_perm) THEN 2"});
 
-      remove_signatory_statements_ = makeCommandStatements(
+      include_role_statements_ = makeCommandStatements(
           sql_,
           R"(
           WITH %s
-            delete_account_signatory AS (DELETE FROM account_has_signatory
-                WHERE account_id = :target
-                AND public_key = lower(:pubkey)
-                %s
-                RETURNING (1)),
-            delete_signatory AS
-            (
-                DELETE FROM signatory WHERE public_key = lower(:pubkey) AND
-                    NOT EXISTS (SELECT 1 FROM account_has_signatory
-                                WHERE public_key = lower(:pubkey))
-                    AND NOT EXISTS (SELECT 1 FROM peer
-                                    WHERE public_key = lower(:pubkey))
-                RETURNING (1)
-            )
-          SELECT CASE
-            WHEN EXISTS (SELECT * FROM delete_account_signatory) THEN
-            CASE
+            role_exists AS (SELECT * FROM role WHERE role_id = :role),
+            included_role_permissions AS (
+                SELECT permission FROM role_has_permissions
+                WHERE role_id = :included_role
+            ),
+            already_included AS (
+                SELECT * FROM role_includes_role
+                WHERE role_id = :role AND included_role_id = :included_role
+            ),
+            -- the role itself and all the roles which include it
+            including_roles AS (
+                WITH RECURSIVE including(role_id) AS (
+                    SELECT role_id FROM role WHERE role_id = :role
+                    UNION
+                    SELECT ri.role_id FROM role_includes_role AS ri
+                    JOIN including ON ri.included_role_id = including.role_id
+                )
+                SELECT role_id FROM including
+            ),
+            inserted AS (
+                INSERT INTO role_includes_role(role_id, included_role_id)
+                (
+                    SELECT :role, :included_role
+                    WHERE EXISTS (SELECT * FROM role_exists)
+                        AND EXISTS (SELECT * FROM included_role_permissions)
+                        AND NOT EXISTS (SELECT * FROM already_included)
+                        AND NOT EXISTS (SELECT * FROM including_roles
+                                        WHERE role_id = :included_role)
+                        %s
+                ) RETURNING (1)
+            ),
+            updated AS (
+                UPDATE role_has_permissions
+                SET permission = permission
+                    | (SELECT * FROM included_role_permissions)
+                WHERE role_id IN (SELECT * FROM including_roles)
+                    AND EXISTS (SELECT * FROM inserted)
+                RETURNING (1)
+            )
+          SELECT CASE
+            WHEN EXISTS (SELECT * FROM updated) THEN 0
+            %s
+            WHEN NOT EXISTS (SELECT * FROM role_exists) THEN 3
+            WHEN NOT EXISTS (SELECT * FROM included_role_permissions) THEN 3
+            WHEN EXISTS (SELECT * FROM already_included) THEN 4
+            WHEN EXISTS (SELECT * FROM including_roles
+                         WHERE role_id = :included_role) THEN 5
+            ELSE 1
+          END AS result)",
+          {(boost::format(R"(
+          account_has_role_permissions AS (
+                SELECT COALESCE(bit_or(rp.permission), '0'::bit(%1%)) &
+                    (SELECT permission FROM role_has_permissions
+                     WHERE role_id = :included_role) =
+                    (SELECT permission FROM role_has_permissions
+                     WHERE role_id = :included_role)
+                FROM role_has_permissions AS rp
+                JOIN account_has_roles AS ar on ar.role_id = rp.role_id
+                WHERE ar.account_id = :creator),
+          has_perm AS (%2%),
+          has_root_perm AS (%3%),)")
+            % kRolePermissionSetSize
+            % checkAccountRolePermission(Role::kCreateRole, ":creator")
+            % checkAccountRolePermission(Role::kRoot, ":creator"))
+               .str(),
+           R"(AND ((SELECT * FROM has_root_perm) OR
+                    ((SELECT * FROM account_has_role_permissions)
+                     AND (SELECT * FROM has_perm))))",
+           R"(WHEN NOT (SELECT * FROM account_has_role_permissions)
+               AND NOT (SELECT * FROM has_root_perm) THEN 2
+              WHEN NOT (SELECT * FROM has_perm)
+               AND NOT (SELECT * FROM has_root_perm) THEN 2)"});
+
+      recover_account_statements_ = makeCommandStatements(
+          sql_,
+          R"(
+          WITH %s
+            target_account AS (
+                SELECT account_id FROM account
+                WHERE account_id = :target %s
+            ),
+            insert_signatory AS (
+                INSERT INTO signatory(public_key)
+                (
+                    SELECT lower(:pubkey)
+                    WHERE EXISTS (SELECT * FROM target_account)
+                )
+                ON CONFLICT (public_key)
+                  DO UPDATE SET public_key = excluded.public_key
+                RETURNING (1)
+            ),
+            removed_signatories AS (
+                DELETE FROM account_has_signatory
+                WHERE account_id IN (SELECT account_id FROM target_account)
+                  AND public_key <> lower(:pubkey)
+                RETURNING (1)
+            ),
+            removed_retired_signatories AS (
+                DELETE FROM account_retired_signatory
+                WHERE account_id IN (SELECT account_id FROM target_account)
+                RETURNING (1)
+            ),
+            insert_account_signatory AS (
+                INSERT INTO account_has_signatory(account_id, public_key)
+                (
+                    SELECT :target, lower(:pubkey)
+                    WHERE EXISTS (SELECT * FROM insert_signatory)
+                )
+                ON CONFLICT (account_id, public_key) DO NOTHING
+                RETURNING (1)
+            ),
+            updated AS (
+                UPDATE account SET quorum = 1, deactivated = false
+                WHERE account_id IN (SELECT account_id FROM target_account)
+                RETURNING (1)
+            )
+          SELECT CASE
+            WHEN EXISTS (SELECT * FROM updated) THEN 0
+            WHEN NOT EXISTS
+                    (SELECT * FROM account WHERE account_id = :target) THEN 3
+            %s
+            ELSE 1
+          END AS result)",
+          {(boost::format(R"(
+            has_perm AS (%s),)")
+            % checkAccountGrantablePermission(
+                  Grantable::kRecoverMyAccount, ":creator", ":target"))
+               .str(),
+           R"( AND (SELECT * FROM has_perm))",
+           R"( WHEN NOT (SELECT * FROM has_perm) THEN 2 )"});
+
+      remove_peer_statements_ = makeCommandStatements(
+          sql_,
+          R"(
+          WITH %s
+          removed AS (
+              DELETE FROM peer WHERE public_key = lower(:pubkey)
+              %s
+              RETURNING (1)
+          )
+          SELECT CASE
+            WHEN EXISTS (SELECT * FROM removed) THEN 0
+            %s
+            ELSE 1
+          END AS result)",
+          {(boost::format(R"(
+            has_perm AS (%s),
+            get_peer AS (
+              SELECT * from peer WHERE public_key = lower(:pubkey) LIMIT 1
+            ),
+            check_peers AS (
+              SELECT 1 WHERE (SELECT COUNT(*) FROM peer) > 1
+            ),)")
+            % checkAccountRolePermission(
+                  Role::kAddPeer, Role::kRemovePeer, ":creator"))
+               .str(),
+           R"(
+             AND (SELECT * FROM has_perm)
+             AND EXISTS (SELECT * FROM get_peer)
+             AND EXISTS (SELECT * FROM check_peers))",
+           R"(
+             WHEN NOT EXISTS (SELECT * from get_peer) THEN 3
+             WHEN NOT EXISTS (SELECT * from check_peers) THEN 4
+             WHEN NOT (SELECT * from has_perm) THEN 2)"});
+
+      remove_signatory_statements_ = makeCommandStatements(
+          sql_,
+          R"(
+          WITH %s
+            delete_account_signatory AS (DELETE FROM account_has_signatory
+                WHERE account_id = :target
+                AND public_key = lower(:pubkey)
+                %s
+                RETURNING (1)),
+            delete_signatory AS
+            (
+                DELETE FROM signatory WHERE public_key = lower(:pubkey) AND
+                    NOT EXISTS (SELECT 1 FROM account_has_signatory
+                                WHERE public_key = lower(:pubkey))
+                    AND NOT EXISTS (SELECT 1 FROM peer
+                                    WHERE public_key = lower(:pubkey))
+                RETURNING (1)
+            )
+          SELECT CASE
+            WHEN EXISTS (SELECT * FROM delete_account_signatory) THEN
+            CASE
                 WHEN EXISTS (SELECT * FROM delete_signatory) THEN 0
                 WHEN EXISTS (SELECT 1 FROM account_has_signatory
                              WHERE public_key = lower(:pubkey)) THEN 0
@@ -1160,6 +1944,281 @@ namespace iroha {
            R"( AND (SELECT * FROM has_perm))",
            R"( WHEN NOT (SELECT * FROM has_perm) THEN 2 )"});
 
+      rotate_key_statements_ = makeCommandStatements(
+          sql_,
+          R"(
+          WITH %s
+            old_signatory AS (
+                SELECT public_key FROM account_has_signatory
+                WHERE account_id = :target
+                AND public_key = lower(:old_pubkey)
+            ),
+            new_signatory AS (
+                SELECT public_key FROM account_has_signatory
+                WHERE account_id = :target
+                AND public_key = lower(:new_pubkey)
+            ),
+            can_rotate AS (
+                SELECT EXISTS (SELECT * FROM old_signatory)
+                    AND NOT EXISTS (SELECT * FROM new_signatory) %s
+            ),
+            insert_signatory AS (
+                INSERT INTO signatory(public_key)
+                (SELECT lower(:new_pubkey) WHERE (SELECT * FROM can_rotate))
+                ON CONFLICT (public_key)
+                  DO UPDATE SET public_key = excluded.public_key
+                RETURNING (1)
+            ),
+            insert_account_signatory AS (
+                INSERT INTO account_has_signatory(account_id, public_key)
+                (
+                    SELECT :target, lower(:new_pubkey)
+                    WHERE EXISTS (SELECT * FROM insert_signatory)
+                )
+                RETURNING (1)
+            ),
+            delete_account_signatory AS (
+                DELETE FROM account_has_signatory
+                WHERE account_id = :target
+                AND public_key = lower(:old_pubkey)
+                AND EXISTS (SELECT * FROM insert_account_signatory)
+                RETURNING (1)
+            ),
+            retire_signatory AS (
+                INSERT INTO account_retired_signatory
+                    (account_id, public_key, new_public_key, expires_height)
+                (
+                    SELECT :target, lower(:old_pubkey), lower(:new_pubkey),
+                        COALESCE((SELECT height FROM top_block_info), 0) + 1
+                            + :grace_period::bigint
+                    WHERE :grace_period::bigint > 0
+                    AND EXISTS (SELECT * FROM delete_account_signatory)
+                )
+                ON CONFLICT (account_id, public_key)
+                  DO UPDATE SET new_public_key = excluded.new_public_key,
+                    expires_height = excluded.expires_height
+                RETURNING (1)
+            )
+          SELECT CASE
+            WHEN EXISTS (SELECT * FROM delete_account_signatory) THEN 0
+            WHEN NOT EXISTS
+                    (SELECT * FROM account WHERE account_id = :target) THEN 3
+            %s
+            WHEN NOT EXISTS (SELECT * FROM old_signatory) THEN 4
+            WHEN EXISTS (SELECT * FROM new_signatory) THEN 5
+            ELSE 1
+          END AS result)",
+          {(boost::format(R"(
+            has_perm AS (SELECT (%s) AND (%s)),)")
+            % checkAccountHasRoleOrGrantablePerm(Role::kAddSignatory,
+                                                 Grantable::kAddMySignatory,
+                                                 ":creator",
+                                                 ":target")
+            % checkAccountHasRoleOrGrantablePerm(Role::kRemoveSignatory,
+                                                 Grantable::kRemoveMySignatory,
+                                                 ":creator",
+                                                 ":target"))
+               .str(),
+           R"( AND (SELECT * FROM has_perm))",
+           R"( WHEN NOT (SELECT * FROM has_perm) THEN 2 )"});
+
+      lock_asset_statements_ = makeCommandStatements(
+          sql_,
+          R"(
+          WITH %s
+            new_src_quantity AS
+            (
+                SELECT coalesce(sum(amount), 0) - :quantity::decimal as value
+                FROM account_has_asset
+                   WHERE asset_id = :asset_id AND
+                   account_id = :source_account_id
+            ),
+            checks AS -- error code and check result
+            (
+                -- source and dest accounts exist
+                SELECT 3 code, count(1) = 2 result
+                FROM account
+                WHERE account_id IN (:source_account_id, :dest_account_id)
+
+                -- asset exists
+                UNION
+                SELECT 4, count(1) = 1
+                FROM asset
+                WHERE asset_id = :asset_id
+                   AND precision >= :precision
+
+                -- lock with the hash does not exist
+                UNION
+                SELECT 5, count(1) = 0
+                FROM asset_lock
+                WHERE hash = lower(:hash)
+
+                -- enough source quantity
+                UNION
+                SELECT 6, value >= 0
+                FROM new_src_quantity
+            ),
+            update_src AS
+            (
+                UPDATE account_has_asset
+                SET amount = value
+                FROM new_src_quantity
+                WHERE
+                    account_id = :source_account_id
+                    AND asset_id = :asset_id
+                    AND (SELECT bool_and(checks.result) FROM checks) %s
+                RETURNING (1)
+            ),
+            insert_lock AS
+            (
+                INSERT INTO asset_lock(hash, src_account_id, dest_account_id,
+                                       asset_id, amount, timeout_height)
+                (
+                    SELECT lower(:hash), :source_account_id,
+                        :dest_account_id, :asset_id, :quantity::decimal,
+                        COALESCE((SELECT height FROM top_block_info), 0) + 1
+                            + :timeout::bigint
+                    WHERE EXISTS (SELECT * FROM update_src)
+                )
+                RETURNING (1)
+            )
+          SELECT CASE
+              WHEN EXISTS (SELECT * FROM insert_lock LIMIT 1) THEN 0
+              %s
+              ELSE (SELECT code FROM checks WHERE not result ORDER BY code ASC LIMIT 1)
+          END AS result)",
+          {(boost::format(R"(
+              has_role_perm AS (%s),
+              has_grantable_perm AS (%s),
+              dest_can_receive AS (%s),
+              has_perm AS
+              (
+                  SELECT (SELECT * FROM dest_can_receive)
+                      AND CASE WHEN :creator = :source_account_id
+                          THEN (SELECT * FROM has_role_perm)
+                          ELSE (SELECT * FROM has_grantable_perm) END
+              ),
+              )")
+            % checkAccountRolePermission(Role::kTransfer, ":creator")
+            % checkAccountGrantablePermission(Grantable::kTransferMyAssets,
+                                              ":creator",
+                                              ":source_account_id")
+            % checkAccountRolePermission(Role::kReceive, ":dest_account_id"))
+               .str(),
+           R"( AND (SELECT * FROM has_perm))",
+           R"( WHEN NOT (SELECT * FROM has_perm) THEN 2 )"});
+
+      // the lock is released to the recipient before the timeout and to the
+      // source account after it
+      auto release_asset_lock_statement = [](const char *account_column,
+                                             const char *timeout_check) {
+        return fmt::format(
+            R"(
+          WITH
+            current_height AS
+            (
+                SELECT COALESCE((SELECT height FROM top_block_info), 0) + 1
+                    AS value
+            ),
+            lock_entry AS
+            (
+                SELECT {0} AS account_id, asset_id, amount, timeout_height
+                FROM asset_lock
+                WHERE hash = lower(:hash)
+            ),
+            new_quantity AS
+            (
+                SELECT coalesce(sum(account_has_asset.amount), 0)
+                    + (SELECT amount FROM lock_entry) as value
+                FROM account_has_asset
+                WHERE asset_id = (SELECT asset_id FROM lock_entry)
+                   AND account_id = (SELECT account_id FROM lock_entry)
+            ),
+            checks AS -- error code and check result
+            (
+                -- lock exists
+                SELECT 3 code, count(1) = 1 result
+                FROM lock_entry
+
+                -- timeout
+                UNION
+                SELECT 4, count(1) = 1
+                FROM lock_entry, current_height
+                WHERE {1}
+
+                -- quantity overflow
+                UNION
+                SELECT
+                    7,
+                    value < (2::decimal ^ 256) / (10::decimal ^ precision)
+                FROM new_quantity, asset
+                WHERE asset_id = (SELECT asset_id FROM lock_entry)
+
+                -- account is not of an archived domain
+                UNION
+                SELECT 11, NOT EXISTS (
+                    SELECT * FROM account
+                    JOIN domain ON domain.domain_id = account.domain_id
+                    WHERE account.account_id =
+                        (SELECT account_id FROM lock_entry)
+                      AND archived
+                )
+            ),
+            deleted AS
+            (
+                DELETE FROM asset_lock
+                WHERE hash = lower(:hash)
+                   AND (SELECT bool_and(checks.result) FROM checks)
+                RETURNING {0} AS account_id, asset_id, src_account_id,
+                    dest_account_id
+            ),
+            -- the parties are kept to index the release for both accounts
+            insert_release AS
+            (
+                INSERT INTO asset_lock_release(hash, src_account_id,
+                                               dest_account_id, asset_id)
+                (
+                    SELECT lower(:hash), src_account_id, dest_account_id,
+                        asset_id
+                    FROM deleted
+                )
+                ON CONFLICT (hash)
+                DO UPDATE SET src_account_id = EXCLUDED.src_account_id,
+                    dest_account_id = EXCLUDED.dest_account_id,
+                    asset_id = EXCLUDED.asset_id
+                RETURNING (1)
+            ),
+            insert_dest AS
+            (
+                INSERT INTO account_has_asset(account_id, asset_id, amount)
+                (
+                    SELECT account_id, asset_id, value
+                    FROM deleted, new_quantity
+                )
+                ON CONFLICT (account_id, asset_id)
+                DO UPDATE SET amount = EXCLUDED.amount
+                RETURNING (1)
+            )
+          SELECT CASE
+              WHEN EXISTS (SELECT * FROM insert_dest LIMIT 1) THEN 0
+              ELSE (SELECT code FROM checks WHERE not result ORDER BY code ASC LIMIT 1)
+          END AS result)",
+            account_column,
+            timeout_check);
+      };
+
+      claim_with_preimage_statements_ = makeCommandStatements(
+          sql_,
+          release_asset_lock_statement(
+              "dest_account_id", "timeout_height >= current_height.value"),
+          {});
+
+      refund_after_timeout_statements_ = makeCommandStatements(
+          sql_,
+          release_asset_lock_statement(
+              "src_account_id", "timeout_height < current_height.value"),
+          {});
+
       set_account_detail_statements_ = makeCommandStatements(
           sql_,
           R"(
@@ -1271,6 +2330,57 @@ namespace iroha {
               WHEN NOT EXISTS (SELECT * FROM check_account_signatories) THEN 5
               )"});
 
+      set_spending_limit_statements_ = makeCommandStatements(
+          sql_,
+          R"(
+          WITH %s
+            target AS (
+                SELECT account_id FROM account
+                WHERE account_id = :target
+                  AND EXISTS (SELECT * FROM asset
+                              WHERE asset_id = :asset_id
+                                AND precision = :precision)
+                %s
+            ),
+            removed AS (
+                DELETE FROM spending_limit
+                WHERE :window_blocks = 0
+                  AND account_id IN (SELECT account_id FROM target)
+                  AND asset_id = :asset_id
+                RETURNING (1)
+            ),
+            upserted AS (
+                INSERT INTO spending_limit(
+                    account_id, asset_id, amount, window_blocks)
+                (
+                    SELECT account_id, :asset_id, :amount::decimal,
+                        :window_blocks
+                    FROM target WHERE :window_blocks > 0
+                )
+                ON CONFLICT (account_id, asset_id)
+                  DO UPDATE SET amount = EXCLUDED.amount,
+                      window_blocks = EXCLUDED.window_blocks
+                RETURNING (1)
+            )
+          SELECT CASE
+            WHEN EXISTS (SELECT * FROM upserted) THEN 0
+            WHEN :window_blocks = 0 AND EXISTS (SELECT * FROM target) THEN 0
+            WHEN NOT EXISTS
+                    (SELECT * FROM account WHERE account_id = :target) THEN 3
+            %s
+            WHEN NOT EXISTS (SELECT * FROM asset
+                             WHERE asset_id = :asset_id
+                               AND precision = :precision) THEN 4
+            ELSE 1
+          END AS result)",
+          {(boost::format(R"(
+            has_perm AS (%s),)")
+            % checkAccountGrantablePermission(
+                  Grantable::kSetMySpendingLimit, ":creator", ":target"))
+               .str(),
+           R"( AND (SELECT * FROM has_perm))",
+           R"( WHEN NOT (SELECT * FROM has_perm) THEN 2 )"});
+
       store_engine_response_statements_ = makeCommandStatements(sql_,
                                                                 R"(
           WITH
@@ -1480,6 +2590,103 @@ namespace iroha {
                  DO UPDATE SET setting_value = EXCLUDED.setting_value
              RETURNING 0)",
           {});
+
+      propose_setting_value_statements_ = makeCommandStatements(
+          sql_,
+          R"(
+          WITH
+            proposal_exists AS
+            (
+                SELECT * FROM governance_proposal
+                WHERE proposal_id = :proposal_id
+            ),
+            inserted AS
+            (
+                INSERT INTO governance_proposal(proposal_id, setting_key,
+                    setting_value, proposer_id, expires_height)
+                (
+                    SELECT :proposal_id, :setting_key, :setting_value,
+                        :creator,
+                        COALESCE((SELECT height FROM top_block_info), 0) + 1
+                            + :voting_period
+                    WHERE NOT EXISTS (SELECT * FROM proposal_exists)
+                ) RETURNING (1)
+            )
+          SELECT CASE
+            WHEN EXISTS (SELECT * FROM inserted) THEN 0
+            WHEN EXISTS (SELECT * FROM proposal_exists) THEN 4
+            ELSE 1
+          END AS result)",
+          {});
+
+      // the vote which reaches the threshold enacts the proposal
+      vote_for_proposal_statements_ = makeCommandStatements(
+          sql_,
+          R"(
+          WITH
+            current_height AS
+            (
+                SELECT COALESCE((SELECT height FROM top_block_info), 0) + 1
+                    AS value
+            ),
+            proposal AS
+            (
+                SELECT * FROM governance_proposal
+                WHERE proposal_id = :proposal_id
+            ),
+            open_proposal AS
+            (
+                SELECT * FROM proposal, current_height
+                WHERE NOT proposal.enacted
+                    AND proposal.expires_height >= current_height.value
+            ),
+            already_voted AS
+            (
+                SELECT * FROM governance_vote
+                WHERE proposal_id = :proposal_id AND voter_id = :creator
+            ),
+            inserted AS
+            (
+                INSERT INTO governance_vote(proposal_id, voter_id, weight)
+                (
+                    SELECT :proposal_id, :creator, :weight
+                    WHERE EXISTS (SELECT * FROM open_proposal)
+                        AND NOT EXISTS (SELECT * FROM already_voted)
+                ) RETURNING weight
+            ),
+            total_weight AS
+            (
+                SELECT COALESCE(sum(weight), 0)
+                    + (SELECT COALESCE(sum(weight), 0) FROM inserted) AS value
+                FROM governance_vote
+                WHERE proposal_id = :proposal_id
+            ),
+            enactment AS
+            (
+                UPDATE governance_proposal SET enacted = true
+                WHERE proposal_id = :proposal_id
+                    AND EXISTS (SELECT * FROM inserted)
+                    AND (SELECT value FROM total_weight) >= :threshold
+                RETURNING setting_key, setting_value
+            ),
+            setting_updated AS
+            (
+                INSERT INTO setting(setting_key, setting_value)
+                (
+                    SELECT setting_key, setting_value FROM enactment
+                )
+                ON CONFLICT (setting_key)
+                    DO UPDATE SET setting_value = EXCLUDED.setting_value
+                RETURNING (1)
+            )
+          SELECT CASE
+            WHEN EXISTS (SELECT * FROM inserted) THEN 0
+            WHEN NOT EXISTS (SELECT * FROM proposal) THEN 3
+            WHEN EXISTS (SELECT * FROM already_voted) THEN 4
+            WHEN NOT EXISTS (SELECT * FROM open_proposal) THEN 6
+            ELSE 1
+          END AS result)",
+          {});
     }
 
     PostgresCommandExecutor::PostgresCommandExecutor(
@@ -1506,6 +2713,10 @@ namespace iroha {
         const std::string &tx_hash,
         shared_model::interface::types::CommandIndexType cmd_index,
         bool do_validation) {
+      if (auto result = checkArchivedDomains(cmd);
+          expected::hasError(result)) {
+        return result;
+      }
       return boost::apply_visitor(
           [this, &creator_account_id, &tx_hash, cmd_index, do_validation](
               const auto &command) {
@@ -1515,6 +2726,29 @@ namespace iroha {
           cmd.get());
     }
 
+    CommandResult PostgresCommandExecutor::checkArchivedDomains(
+        const shared_model::interface::Command &cmd) {
+      // accounts and assets of archived domains are read only
+      for (auto domain : getWrittenDomains(cmd)) {
+        std::string const domain_id{domain};
+        int archived = 0;
+        try {
+          *sql_ << "SELECT count(*) FROM domain "
+                   "WHERE domain_id = :domain AND archived",
+              soci::into(archived), soci::use(domain_id, "domain");
+        } catch (const std::exception &e) {
+          return makeCommandError(cmd.toString(), 1, e.what());
+        }
+        if (archived != 0) {
+          return makeCommandError(
+              cmd.toString(),
+              11,
+              fmt::format("Domain {} is archived.", domain_id));
+        }
+      }
+      return {};
+    }
+
     soci::session &PostgresCommandExecutor::getSession() {
       return *sql_;
     }
@@ -1523,6 +2757,83 @@ namespace iroha {
       return db_transaction_;
     }
 
+    CommandResult PostgresCommandExecutor::recordIdempotencyKey(
+        const shared_model::interface::Transaction &transaction,
+        bool do_validation) {
+      auto const &creator_id = transaction.creatorAccountId();
+      auto const &key = transaction.idempotencyKey();
+      uint64_t const created_time = transaction.createdTime();
+      auto const hash = transaction.hash().hex();
+      try {
+        std::optional<std::string> setting_value;
+        *sql_ << "SELECT setting_value FROM setting WHERE setting_key = :key",
+            soci::use(kIdempotencyWindowKey, "key"),
+            soci::into(setting_value);
+        uint64_t const window = parseIdempotencyWindow(setting_value);
+        if (window == 0) {
+          return {};
+        }
+
+        if (do_validation) {
+          std::optional<std::string> tx_hash;
+          std::optional<uint64_t> record_time;
+          *sql_ << "SELECT tx_hash, created_time FROM idempotency_key "
+                   "WHERE creator_id = :creator_id "
+                   "AND idempotency_key = :key",
+              soci::use(creator_id, "creator_id"), soci::use(key, "key"),
+              soci::into(tx_hash), soci::into(record_time);
+          if (tx_hash and record_time and *tx_hash != hash
+              and not IdempotencyRecord{*tx_hash, *record_time}.expired(
+                  created_time, window)) {
+            return makeCommandError(
+                "IdempotencyKey",
+                3,
+                fmt::format("Duplicate of transaction {} with the same "
+                            "idempotency key",
+                            *tx_hash));
+          }
+        }
+
+        *sql_ << "DELETE FROM idempotency_key "
+                 "WHERE creator_id = :creator_id "
+                 "AND created_time + :window <= :time",
+            soci::use(creator_id, "creator_id"), soci::use(window, "window"),
+            soci::use(created_time, "time");
+        *sql_ << "INSERT INTO idempotency_key"
+                 "(creator_id, idempotency_key, tx_hash, created_time) "
+                 "VALUES (:creator_id, :key, :tx_hash, :time) "
+                 "ON CONFLICT (creator_id, idempotency_key) DO UPDATE "
+                 "SET tx_hash = EXCLUDED.tx_hash, "
+                 "created_time = EXCLUDED.created_time",
+            soci::use(creator_id, "creator_id"), soci::use(key, "key"),
+            soci::use(hash, "tx_hash"), soci::use(created_time, "time");
+      } catch (const std::exception &e) {
+        return makeCommandError("IdempotencyKey", 1, e.what());
+      }
+      return {};
+    }
+
+    CommandResult PostgresCommandExecutor::checkChainId(
+        const shared_model::interface::Transaction &transaction) {
+      std::optional<std::string> chain_id;
+      try {
+        *sql_ << "SELECT setting_value FROM setting WHERE setting_key = :key",
+            soci::use(kChainIdKey, "key"), soci::into(chain_id);
+      } catch (const std::exception &e) {
+        return makeCommandError("ChainId", 1, e.what());
+      }
+      if (transaction.chainId() != chain_id.value_or(std::string{})) {
+        return makeCommandError(
+            "ChainId",
+            3,
+            fmt::format("Transaction chain id '{}' does not match the chain "
+                        "id '{}' of the ledger",
+                        transaction.chainId(),
+                        chain_id.value_or(std::string{})));
+      }
+      return {};
+    }
+
     CommandResult PostgresCommandExecutor::operator()(
         const shared_model::interface::AddAssetQuantity &command,
         const shared_model::interface::types::AccountIdType &creator_account_id,
@@ -1533,6 +2844,18 @@ namespace iroha {
       auto quantity = command.amount().toStringRepr();
       int precision = command.amount().precision();
 
+      if (do_validation) {
+        try {
+          auto policy_result =
+              checkSupplyPolicy(*sql_, "AddAssetQuantity", command);
+          if (iroha::expected::hasError(policy_result)) {
+            return policy_result;
+          }
+        } catch (const std::exception &e) {
+          return makeCommandError("AddAssetQuantity", 1, e.what());
+        }
+      }
+
       StatementExecutor executor(add_asset_quantity_statements_,
                                  do_validation,
                                  "AddAssetQuantity",
@@ -1542,7 +2865,16 @@ namespace iroha {
       executor.use("precision", precision);
       executor.use("quantity", quantity);
 
-      return executor.execute();
+      auto result = executor.execute();
+      if (iroha::expected::hasError(result)) {
+        return result;
+      }
+      try {
+        addToAssetStats(*sql_, asset_id, quantity, true);
+      } catch (const std::exception &e) {
+        return makeCommandError("AddAssetQuantity", 1, e.what());
+      }
+      return result;
     }
 
     CommandResult PostgresCommandExecutor::operator()(
@@ -1607,6 +2939,45 @@ namespace iroha {
       return executor.execute();
     }
 
+    CommandResult PostgresCommandExecutor::operator()(
+        const shared_model::interface::ArchiveDomain &command,
+        const shared_model::interface::types::AccountIdType &creator_account_id,
+        const std::string &tx_hash,
+        shared_model::interface::types::CommandIndexType cmd_index,
+        bool do_validation) {
+      StatementExecutor executor(archive_domain_statements_,
+                                 do_validation,
+                                 "ArchiveDomain",
+                                 perm_converter_);
+      executor.use("creator", creator_account_id);
+      executor.use("domain", command.domainId());
+
+      return executor.execute();
+    }
+
+    CommandResult PostgresCommandExecutor::operator()(
+        const shared_model::interface::AuthorizeSessionKey &command,
+        const shared_model::interface::types::AccountIdType &creator_account_id,
+        const std::string &tx_hash,
+        shared_model::interface::types::CommandIndexType cmd_index,
+        bool do_validation) {
+      auto permissions = command.permissions().toBitstring();
+      shared_model::interface::types::HeightType validity = command.validity();
+
+      StatementExecutor executor(authorize_session_key_statements_,
+                                 do_validation,
+                                 "AuthorizeSessionKey",
+                                 perm_converter_);
+      executor.use("creator", creator_account_id);
+      executor.use("target", command.accountId());
+      executor.use("pubkey", command.pubkey());
+      executor.use("permissions", permissions);
+      executor.use("allowed_asset_id", command.allowedAssetId());
+      executor.use("validity", validity);
+
+      return executor.execute();
+    }
+
     CommandResult PostgresCommandExecutor::operator()(
         const shared_model::interface::CallEngine &command,
         const shared_model::interface::types::AccountIdType &creator_account_id,
@@ -1695,6 +3066,23 @@ namespace iroha {
       const std::string expected_json_value =
           makeJsonString(command.oldValue().value_or(""));
 
+      if (do_validation) {
+        try {
+          auto limits_result =
+              checkAccountDetailLimits(*sql_,
+                                       "CompareAndSetAccountDetail",
+                                       command.accountId(),
+                                       creator_account_id,
+                                       command.key(),
+                                       command.value());
+          if (iroha::expected::hasError(limits_result)) {
+            return limits_result;
+          }
+        } catch (const std::exception &e) {
+          return makeCommandError("CompareAndSetAccountDetail", 1, e.what());
+        }
+      }
+
       StatementExecutor executor(compare_and_set_account_detail_statements_,
                                  do_validation,
                                  "CompareAndSetAccountDetail",
@@ -1736,7 +3124,19 @@ namespace iroha {
       executor.use("domain", domain_id);
       executor.use("pubkey", pubkey);
 
-      return executor.execute();
+      auto result = executor.execute();
+      if (iroha::expected::hasError(result)) {
+        return result;
+      }
+      try {
+        if (auto e = iroha::expected::resultToOptionalError(
+                applyDomainPolicy(*sql_, account_id, domain_id))) {
+          return makeCommandError("CreateAccount", 1, std::move(*e));
+        }
+      } catch (const std::exception &e) {
+        return makeCommandError("CreateAccount", 1, e.what());
+      }
+      return result;
     }
 
     CommandResult PostgresCommandExecutor::operator()(
@@ -1802,6 +3202,24 @@ namespace iroha {
       return executor.execute();
     }
 
+    CommandResult PostgresCommandExecutor::operator()(
+        const shared_model::interface::DeactivateAccount &command,
+        const shared_model::interface::types::AccountIdType &creator_account_id,
+        const std::string &tx_hash,
+        shared_model::interface::types::CommandIndexType cmd_index,
+        bool do_validation) {
+      auto &account_id = command.accountId();
+
+      StatementExecutor executor(deactivate_account_statements_,
+                                 do_validation,
+                                 "DeactivateAccount",
+                                 perm_converter_);
+      executor.use("creator", creator_account_id);
+      executor.use("target", account_id);
+
+      return executor.execute();
+    }
+
     CommandResult PostgresCommandExecutor::operator()(
         const shared_model::interface::DetachRole &command,
         const shared_model::interface::types::AccountIdType &creator_account_id,
@@ -1845,6 +3263,105 @@ namespace iroha {
       return executor.execute();
     }
 
+    CommandResult PostgresCommandExecutor::operator()(
+        const shared_model::interface::GrantVestedAsset &command,
+        const shared_model::interface::types::AccountIdType &creator_account_id,
+        const std::string &tx_hash,
+        shared_model::interface::types::CommandIndexType cmd_index,
+        bool do_validation) {
+      auto &dest_account_id = command.destAccountId();
+      auto &asset_id = command.assetId();
+      auto quantity = command.amount().toStringRepr();
+      uint32_t precision = command.amount().precision();
+      shared_model::interface::types::HeightType cliff = command.cliff();
+      shared_model::interface::types::HeightType duration = command.duration();
+
+      try {
+        auto schedule = getVestingSchedule(*sql_, dest_account_id, asset_id);
+        if (auto e = iroha::expected::resultToOptionalError(schedule)) {
+          return makeCommandError("GrantVestedAsset", 1, std::move(*e));
+        }
+        auto const &[opt_schedule, height] = schedule.assumeValue();
+        if (opt_schedule and not opt_schedule->isVested(height)) {
+          return makeCommandError(
+              "GrantVestedAsset",
+              6,
+              fmt::format("Vesting schedule of {} for {} is not vested yet",
+                          asset_id,
+                          dest_account_id));
+        }
+        if (do_validation) {
+          auto policy_result =
+              checkSupplyPolicy(*sql_, "GrantVestedAsset", command);
+          if (iroha::expected::hasError(policy_result)) {
+            return policy_result;
+          }
+        }
+      } catch (const std::exception &e) {
+        return makeCommandError("GrantVestedAsset", 1, e.what());
+      }
+
+      StatementExecutor executor(grant_vested_asset_statements_,
+                                 do_validation,
+                                 "GrantVestedAsset",
+                                 perm_converter_);
+      executor.use("creator", creator_account_id);
+      executor.use("dest_account_id", dest_account_id);
+      executor.use("asset_id", asset_id);
+      executor.use("precision", precision);
+      executor.use("quantity", quantity);
+      executor.use("cliff", cliff);
+      executor.use("duration", duration);
+
+      auto result = executor.execute();
+      if (iroha::expected::hasError(result)) {
+        return result;
+      }
+      try {
+        addToAssetStats(*sql_, asset_id, quantity, true);
+      } catch (const std::exception &e) {
+        return makeCommandError("GrantVestedAsset", 1, e.what());
+      }
+      return result;
+    }
+
+    CommandResult PostgresCommandExecutor::operator()(
+        const shared_model::interface::IncludeRole &command,
+        const shared_model::interface::types::AccountIdType &creator_account_id,
+        const std::string &tx_hash,
+        shared_model::interface::types::CommandIndexType cmd_index,
+        bool do_validation) {
+      StatementExecutor executor(include_role_statements_,
+                                 do_validation,
+                                 "IncludeRole",
+                                 perm_converter_);
+      executor.use("creator", creator_account_id);
+      executor.use("role", command.roleName());
+      executor.use("included_role", command.includedRoleName());
+
+      return executor.execute();
+    }
+
+    CommandResult PostgresCommandExecutor::operator()(
+        const shared_model::interface::RecoverAccount &command,
+        const shared_model::interface::types::AccountIdType &creator_account_id,
+        const std::string &tx_hash,
+        shared_model::interface::types::CommandIndexType cmd_index,
+        bool do_validation) {
+      auto &account_id = command.accountId();
+      auto &pubkey = command.pubkey();
+
+      StatementExecutor executor(recover_account_statements_,
+                                 do_validation,
+                                 "RecoverAccount",
+                                 perm_converter_);
+      executor.use("creator", creator_account_id);
+      executor.use("target", account_id);
+      executor.use("pubkey", pubkey);
+
+      return executor.execute();
+    }
+
     CommandResult PostgresCommandExecutor::operator()(
         const shared_model::interface::RemovePeer &command,
         const shared_model::interface::types::AccountIdType &creator_account_id,
@@ -1913,6 +3430,126 @@ namespace iroha {
       return executor.execute();
     }
 
+    CommandResult PostgresCommandExecutor::operator()(
+        const shared_model::interface::RotateKey &command,
+        const shared_model::interface::types::AccountIdType &creator_account_id,
+        const std::string &tx_hash,
+        shared_model::interface::types::CommandIndexType cmd_index,
+        bool do_validation) {
+      auto &account_id = command.accountId();
+      auto &old_pubkey = command.oldPubkey();
+      auto &new_pubkey = command.newPubkey();
+      shared_model::interface::types::HeightType grace_period =
+          command.gracePeriod();
+
+      StatementExecutor executor(
+          rotate_key_statements_, do_validation, "RotateKey", perm_converter_);
+      executor.use("creator", creator_account_id);
+      executor.use("target", account_id);
+      executor.use("old_pubkey", old_pubkey);
+      executor.use("new_pubkey", new_pubkey);
+      executor.use("grace_period", grace_period);
+
+      return executor.execute();
+    }
+
+    CommandResult PostgresCommandExecutor::operator()(
+        const shared_model::interface::LockAsset &command,
+        const shared_model::interface::types::AccountIdType &creator_account_id,
+        const std::string &tx_hash,
+        shared_model::interface::types::CommandIndexType cmd_index,
+        bool do_validation) {
+      auto quantity = command.amount().toStringRepr();
+      uint32_t precision = command.amount().precision();
+      shared_model::interface::types::HeightType timeout = command.timeout();
+
+      if (do_validation) {
+        try {
+          auto policy_result = checkTransferPolicy(*sql_, "LockAsset", command);
+          if (iroha::expected::hasError(policy_result)) {
+            return policy_result;
+          }
+        } catch (const std::exception &e) {
+          return makeCommandError("LockAsset", 1, e.what());
+        }
+      }
+
+      StatementExecutor executor(
+          lock_asset_statements_, do_validation, "LockAsset", perm_converter_);
+      executor.use("creator", creator_account_id);
+      executor.use("source_account_id", command.srcAccountId());
+      executor.use("dest_account_id", command.destAccountId());
+      executor.use("asset_id", command.assetId());
+      executor.use("quantity", quantity);
+      executor.use("precision", precision);
+      executor.use("hash", command.hash());
+      executor.use("timeout", timeout);
+
+      auto result = executor.execute();
+      if (iroha::expected::hasError(result)) {
+        return result;
+      }
+      try {
+        if (do_validation) {
+          auto vesting_result = checkVestingSchedule(*sql_,
+                                                     "LockAsset",
+                                                     6,
+                                                     command.srcAccountId(),
+                                                     command.assetId());
+          if (iroha::expected::hasError(vesting_result)) {
+            return vesting_result;
+          }
+        }
+        auto limit_result = spendWithinLimit(*sql_,
+                                             "LockAsset",
+                                             command.srcAccountId(),
+                                             command.assetId(),
+                                             command.amount(),
+                                             do_validation);
+        if (iroha::expected::hasError(limit_result)) {
+          return limit_result;
+        }
+      } catch (const std::exception &e) {
+        return makeCommandError("LockAsset", 1, e.what());
+      }
+      return result;
+    }
+
+    CommandResult PostgresCommandExecutor::operator()(
+        const shared_model::interface::ClaimWithPreimage &command,
+        const shared_model::interface::types::AccountIdType &creator_account_id,
+        const std::string &tx_hash,
+        shared_model::interface::types::CommandIndexType cmd_index,
+        bool do_validation) {
+      auto hash = assetLockHash(command.preimage());
+      if (auto e = iroha::expected::resultToOptionalError(hash)) {
+        return makeCommandError("ClaimWithPreimage", 1, std::move(*e));
+      }
+
+      StatementExecutor executor(claim_with_preimage_statements_,
+                                 do_validation,
+                                 "ClaimWithPreimage",
+                                 perm_converter_);
+      executor.use("hash", hash.assumeValue());
+
+      return executor.execute();
+    }
+
+    CommandResult PostgresCommandExecutor::operator()(
+        const shared_model::interface::RefundAfterTimeout &command,
+        const shared_model::interface::types::AccountIdType &creator_account_id,
+        const std::string &tx_hash,
+        shared_model::interface::types::CommandIndexType cmd_index,
+        bool do_validation) {
+      StatementExecutor executor(refund_after_timeout_statements_,
+                                 do_validation,
+                                 "RefundAfterTimeout",
+                                 perm_converter_);
+      executor.use("hash", command.hash());
+
+      return executor.execute();
+    }
+
     CommandResult PostgresCommandExecutor::operator()(
         const shared_model::interface::SetAccountDetail &command,
         const shared_model::interface::types::AccountIdType &creator_account_id,
@@ -1924,6 +3561,22 @@ namespace iroha {
       auto &value = command.value();
       std::string json_value = makeJsonString(value);
 
+      if (do_validation) {
+        try {
+          auto limits_result = checkAccountDetailLimits(*sql_,
+                                                        "SetAccountDetail",
+                                                        account_id,
+                                                        creator_account_id,
+                                                        key,
+                                                        value);
+          if (iroha::expected::hasError(limits_result)) {
+            return limits_result;
+          }
+        } catch (const std::exception &e) {
+          return makeCommandError("SetAccountDetail", 1, e.what());
+        }
+      }
+
       StatementExecutor executor(set_account_detail_statements_,
                                  do_validation,
                                  "SetAccountDetail",
@@ -1942,6 +3595,78 @@ namespace iroha {
       return executor.execute();
     }
 
+    CommandResult PostgresCommandExecutor::operator()(
+        const shared_model::interface::SetAccountDetailAtPath &command,
+        const shared_model::interface::types::AccountIdType &creator_account_id,
+        const std::string &tx_hash,
+        shared_model::interface::types::CommandIndexType cmd_index,
+        bool do_validation) {
+      auto &account_id = command.accountId();
+      auto &key = command.key();
+      // When creator is not known, it is genesis block
+      static const std::string genesis_creator_account_id = "genesis";
+      auto &writer = not creator_account_id.empty()
+          ? creator_account_id
+          : genesis_creator_account_id;
+
+      std::string new_detail;
+      try {
+        std::optional<std::string> detail;
+        *sql_ << "SELECT data->:writer->>:key FROM account "
+                 "WHERE account_id = :account_id",
+            soci::use(writer, "writer"), soci::use(key, "key"),
+            soci::use(account_id, "account_id"), soci::into(detail);
+
+        std::optional<uint64_t> max_depth;
+        if (do_validation) {
+          auto limit = getAccountDetailLimit(*sql_, kMaxAccountDetailDepthKey);
+          if (auto e = iroha::expected::resultToOptionalError(limit)) {
+            return makeCommandError("SetAccountDetailAtPath", 1, std::move(*e));
+          }
+          max_depth = limit.assumeValue();
+        }
+
+        auto const old_value = command.oldValue();
+        auto result = setAccountDetailAtPath(
+            detail, command.path(), command.value(), old_value, max_depth);
+        if (auto e = iroha::expected::resultToOptionalError(result)) {
+          auto const code =
+              e->code == AccountDetailPathError::Code::kOldValueMismatch ? 4
+                                                                         : 7;
+          return makeCommandError(
+              "SetAccountDetailAtPath", code, std::move(e->message));
+        }
+        new_detail = std::move(result).assumeValue();
+
+        if (do_validation) {
+          auto limits_result =
+              checkAccountDetailLimits(*sql_,
+                                       "SetAccountDetailAtPath",
+                                       account_id,
+                                       creator_account_id,
+                                       key,
+                                       new_detail);
+          if (iroha::expected::hasError(limits_result)) {
+            return limits_result;
+          }
+        }
+      } catch (const std::exception &e) {
+        return makeCommandError("SetAccountDetailAtPath", 1, e.what());
+      }
+      std::string json_value = makeJsonString(new_detail);
+
+      StatementExecutor executor(set_account_detail_statements_,
+                                 do_validation,
+                                 "SetAccountDetailAtPath",
+                                 perm_converter_);
+      executor.use("creator", writer);
+      executor.use("target", account_id);
+      executor.use("key", key);
+      executor.use("value", json_value);
+
+      return executor.execute();
+    }
+
     CommandResult PostgresCommandExecutor::operator()(
         const shared_model::interface::SetQuorum &command,
         const shared_model::interface::types::AccountIdType &creator_account_id,
@@ -1960,6 +3685,31 @@ namespace iroha {
       return executor.execute();
     }
 
+    CommandResult PostgresCommandExecutor::operator()(
+        const shared_model::interface::SetSpendingLimit &command,
+        const shared_model::interface::types::AccountIdType &creator_account_id,
+        const std::string &tx_hash,
+        shared_model::interface::types::CommandIndexType cmd_index,
+        bool do_validation) {
+      auto amount = command.amount().toStringRepr();
+      uint32_t precision = command.amount().precision();
+      shared_model::interface::types::HeightType window_blocks =
+          command.window();
+
+      StatementExecutor executor(set_spending_limit_statements_,
+                                 do_validation,
+                                 "SetSpendingLimit",
+                                 perm_converter_);
+      executor.use("creator", creator_account_id);
+      executor.use("target", command.accountId());
+      executor.use("asset_id", command.assetId());
+      executor.use("amount", amount);
+      executor.use("precision", precision);
+      executor.use("window_blocks", window_blocks);
+
+      return executor.execute();
+    }
+
     CommandResult PostgresCommandExecutor::operator()(
         const shared_model::interface::SubtractAssetQuantity &command,
         const shared_model::interface::types::AccountIdType &creator_account_id,
@@ -1979,7 +3729,23 @@ namespace iroha {
       executor.use("quantity", quantity);
       executor.use("precision", precision);
 
-      return executor.execute();
+      auto result = executor.execute();
+      if (iroha::expected::hasError(result)) {
+        return result;
+      }
+      try {
+        if (do_validation) {
+          auto vesting_result = checkVestingSchedule(
+              *sql_, "SubtractAssetQuantity", 4, creator_account_id, asset_id);
+          if (iroha::expected::hasError(vesting_result)) {
+            return vesting_result;
+          }
+        }
+        addToAssetStats(*sql_, asset_id, quantity, false);
+      } catch (const std::exception &e) {
+        return makeCommandError("SubtractAssetQuantity", 1, e.what());
+      }
+      return result;
     }
 
     CommandResult PostgresCommandExecutor::operator()(
@@ -1994,6 +3760,18 @@ namespace iroha {
       auto quantity = command.amount().toStringRepr();
       uint32_t precision = command.amount().precision();
 
+      if (do_validation) {
+        try {
+          auto policy_result =
+              checkTransferPolicy(*sql_, "TransferAsset", command);
+          if (iroha::expected::hasError(policy_result)) {
+            return policy_result;
+          }
+        } catch (const std::exception &e) {
+          return makeCommandError("TransferAsset", 1, e.what());
+        }
+      }
+
       StatementExecutor executor(transfer_asset_statements_,
                                  do_validation,
                                  "TransferAsset",
@@ -2006,7 +3784,66 @@ namespace iroha {
       executor.use("precision", precision);
       executor.use("description_length", command.description().size());
 
-      return executor.execute();
+      auto result = executor.execute();
+      if (iroha::expected::hasError(result)) {
+        return result;
+      }
+
+      if (do_validation) {
+        try {
+          auto vesting_result = checkVestingSchedule(
+              *sql_, "TransferAsset", 6, src_account_id, asset_id);
+          if (iroha::expected::hasError(vesting_result)) {
+            return vesting_result;
+          }
+        } catch (const std::exception &e) {
+          return makeCommandError("TransferAsset", 1, e.what());
+        }
+      }
+
+      // the spendings and the burn fee are a part of the state transition, so
+      // they are applied without validation as well
+      try {
+        auto limit_result = spendWithinLimit(*sql_,
+                                             "TransferAsset",
+                                             src_account_id,
+                                             asset_id,
+                                             command.amount(),
+                                             do_validation);
+        if (iroha::expected::hasError(limit_result)) {
+          return limit_result;
+        }
+
+        auto policy = getSupplyPolicy(*sql_, asset_id);
+        if (auto e = iroha::expected::resultToOptionalError(policy)) {
+          return makeCommandError("TransferAsset", 1, std::move(*e));
+        }
+        if (policy.assumeValue()
+            and policy.assumeValue()->burn_fee_basis_points > 0) {
+          auto fee =
+              policy.assumeValue()->burnFee(command.amount()).toStringRepr();
+          *sql_ << "UPDATE account_has_asset SET amount = amount - "
+                   ":fee::decimal "
+                   "WHERE account_id = :dest_account_id "
+                   "AND asset_id = :asset_id",
+              soci::use(fee, "fee"),
+              soci::use(dest_account_id, "dest_account_id"),
+              soci::use(asset_id, "asset_id");
+          addToAssetStats(*sql_, asset_id, fee, false);
+          // the fee is kept for the statements of the account, the policy
+          // can change
+          *sql_ << "INSERT INTO transfer_fee(tx_hash, cmd_index, fee) "
+                   "VALUES (:tx_hash, :cmd_index, :fee::decimal) "
+                   "ON CONFLICT (tx_hash, cmd_index) "
+                   "DO UPDATE SET fee = EXCLUDED.fee",
+              soci::use(tx_hash, "tx_hash"),
+              soci::use(cmd_index, "cmd_index"),
+              soci::use(fee, "fee");
+        }
+      } catch (const std::exception &e) {
+        return makeCommandError("TransferAsset", 1, e.what());
+      }
+      return result;
     }
 
     CommandResult PostgresCommandExecutor::operator()(
@@ -2025,6 +3862,9 @@ namespace iroha {
 
       auto &key = command.key();
       auto &value = command.value();
+      if (auto e = checkSettingValue(key, value)) {
+        return makeCommandError("SetSettingValue", 3, std::move(*e));
+      }
 
       StatementExecutor executor(set_setting_value_statements_,
                                  do_validation,
@@ -2037,5 +3877,95 @@ namespace iroha {
       return executor.execute();
     }
 
+    CommandResult PostgresCommandExecutor::operator()(
+        const shared_model::interface::ProposeSettingValue &command,
+        const shared_model::interface::types::AccountIdType &creator_account_id,
+        const std::string &,
+        shared_model::interface::types::CommandIndexType,
+        bool do_validation) {
+      auto &proposal_id = command.proposalId();
+      auto &key = command.settingKey();
+      auto &value = command.settingValue();
+
+      std::optional<GovernancePolicy> policy;
+      try {
+        auto result = getGovernancePolicy(*sql_);
+        if (auto e = iroha::expected::resultToOptionalError(result)) {
+          return makeCommandError("ProposeSettingValue", 1, std::move(*e));
+        }
+        policy = std::move(result).assumeValue();
+      } catch (const std::exception &e) {
+        return makeCommandError("ProposeSettingValue", 1, e.what());
+      }
+      if (not policy) {
+        return makeCommandError(
+            "ProposeSettingValue", 5, "Governance policy is not set");
+      }
+      if (do_validation and not policy->weight(creator_account_id)) {
+        return makeCommandError(
+            "ProposeSettingValue",
+            2,
+            fmt::format("{} is not a governance voter", creator_account_id));
+      }
+      if (auto e = checkSettingValue(key, value)) {
+        return makeCommandError("ProposeSettingValue", 3, std::move(*e));
+      }
+
+      StatementExecutor executor(propose_setting_value_statements_,
+                                 do_validation,
+                                 "ProposeSettingValue",
+                                 perm_converter_);
+      executor.use("creator", creator_account_id);
+      executor.use("proposal_id", proposal_id);
+      executor.use("setting_key", key);
+      executor.use("setting_value", value);
+      executor.use("voting_period", policy->voting_period);
+
+      return executor.execute();
+    }
+
+    CommandResult PostgresCommandExecutor::operator()(
+        const shared_model::interface::VoteForProposal &command,
+        const shared_model::interface::types::AccountIdType &creator_account_id,
+        const std::string &,
+        shared_model::interface::types::CommandIndexType,
+        bool do_validation) {
+      auto &proposal_id = command.proposalId();
+
+      std::optional<GovernancePolicy> policy;
+      try {
+        auto result = getGovernancePolicy(*sql_);
+        if (auto e = iroha::expected::resultToOptionalError(result)) {
+          return makeCommandError("VoteForProposal", 1, std::move(*e));
+        }
+        policy = std::move(result).assumeValue();
+      } catch (const std::exception &e) {
+        return makeCommandError("VoteForProposal", 1, e.what());
+      }
+      if (not policy) {
+        return makeCommandError(
+            "VoteForProposal", 5, "Governance policy is not set");
+      }
+      auto weight = policy->weight(creator_account_id);
+      if (do_validation and not weight) {
+        return makeCommandError(
+            "VoteForProposal",
+            2,
+            fmt::format("{} is not a governance voter", creator_account_id));
+      }
+      uint64_t vote_weight = weight.value_or(0);
+
+      StatementExecutor executor(vote_for_proposal_statements_,
+                                 do_validation,
+                                 "VoteForProposal",
+                                 perm_converter_);
+      executor.use("creator", creator_account_id);
+      executor.use("proposal_id", proposal_id);
+      executor.use("weight", vote_weight);
+      executor.use("threshold", policy->threshold);
+
+      return executor.execute();
+    }
+
   }  // namespace ametsuchi
 }  // namespace iroha
@@ -22,23 +22,37 @@ namespace shared_model {
     class AddPeer;
     class AddSignatory;
     class AppendRole;
+    class ArchiveDomain;
+    class AuthorizeSessionKey;
     class CompareAndSetAccountDetail;
     class CallEngine;
+    class ClaimWithPreimage;
     class CreateAccount;
     class CreateAsset;
     class CreateDomain;
     class CreateRole;
+    class DeactivateAccount;
     class DetachRole;
     class GrantPermission;
+    class GrantVestedAsset;
+    class IncludeRole;
+    class LockAsset;
     class PermissionToString;
+    class ProposeSettingValue;
+    class RecoverAccount;
+    class RefundAfterTimeout;
     class RemovePeer;
     class RemoveSignatory;
     class RevokePermission;
+    class RotateKey;
     class SetAccountDetail;
+    class SetAccountDetailAtPath;
     class SetQuorum;
+    class SetSpendingLimit;
     class SubtractAssetQuantity;
     class TransferAsset;
     class SetSettingValue;
+    class VoteForProposal;
   }  // namespace interface
 }  // namespace shared_model
 
@@ -68,6 +82,13 @@ namespace iroha {
           shared_model::interface::types::CommandIndexType cmd_index,
           bool do_validation) override;
 
+      CommandResult recordIdempotencyKey(
+          const shared_model::interface::Transaction &transaction,
+          bool do_validation) override;
+
+      CommandResult checkChainId(
+          const shared_model::interface::Transaction &transaction) override;
+
       void skipChanges() override;
 
       DatabaseTransaction &dbSession() override;
@@ -114,6 +135,22 @@ namespace iroha {
           shared_model::interface::types::CommandIndexType cmd_index,
           bool do_validation);
 
+      CommandResult operator()(
+          const shared_model::interface::ArchiveDomain &command,
+          const shared_model::interface::types::AccountIdType
+              &creator_account_id,
+          const std::string &tx_hash,
+          shared_model::interface::types::CommandIndexType cmd_index,
+          bool do_validation);
+
+      CommandResult operator()(
+          const shared_model::interface::AuthorizeSessionKey &command,
+          const shared_model::interface::types::AccountIdType
+              &creator_account_id,
+          const std::string &tx_hash,
+          shared_model::interface::types::CommandIndexType cmd_index,
+          bool do_validation);
+
       CommandResult operator()(
           const shared_model::interface::CompareAndSetAccountDetail &command,
           const shared_model::interface::types::AccountIdType
@@ -154,6 +191,14 @@ namespace iroha {
           shared_model::interface::types::CommandIndexType cmd_index,
           bool do_validation);
 
+      CommandResult operator()(
+          const shared_model::interface::DeactivateAccount &command,
+          const shared_model::interface::types::AccountIdType
+              &creator_account_id,
+          const std::string &tx_hash,
+          shared_model::interface::types::CommandIndexType cmd_index,
+          bool do_validation);
+
       CommandResult operator()(
           const shared_model::interface::DetachRole &command,
           const shared_model::interface::types::AccountIdType
@@ -170,6 +215,38 @@ namespace iroha {
           shared_model::interface::types::CommandIndexType cmd_index,
           bool do_validation);
 
+      CommandResult operator()(
+          const shared_model::interface::GrantVestedAsset &command,
+          const shared_model::interface::types::AccountIdType
+              &creator_account_id,
+          const std::string &tx_hash,
+          shared_model::interface::types::CommandIndexType cmd_index,
+          bool do_validation);
+
+      CommandResult operator()(
+          const shared_model::interface::IncludeRole &command,
+          const shared_model::interface::types::AccountIdType
+              &creator_account_id,
+          const std::string &tx_hash,
+          shared_model::interface::types::CommandIndexType cmd_index,
+          bool do_validation);
+
+      CommandResult operator()(
+          const shared_model::interface::ProposeSettingValue &command,
+          const shared_model::interface::types::AccountIdType
+              &creator_account_id,
+          const std::string &tx_hash,
+          shared_model::interface::types::CommandIndexType cmd_index,
+          bool do_validation);
+
+      CommandResult operator()(
+          const shared_model::interface::RecoverAccount &command,
+          const shared_model::interface::types::AccountIdType
+              &creator_account_id,
+          const std::string &tx_hash,
+          shared_model::interface::types::CommandIndexType cmd_index,
+          bool do_validation);
+
       CommandResult operator()(
           const shared_model::interface::RemovePeer &command,
           const shared_model::interface::types::AccountIdType
@@ -194,6 +271,38 @@ namespace iroha {
           shared_model::interface::types::CommandIndexType cmd_index,
           bool do_validation);
 
+      CommandResult operator()(
+          const shared_model::interface::RotateKey &command,
+          const shared_model::interface::types::AccountIdType
+              &creator_account_id,
+          const std::string &tx_hash,
+          shared_model::interface::types::CommandIndexType cmd_index,
+          bool do_validation);
+
+      CommandResult operator()(
+          const shared_model::interface::LockAsset &command,
+          const shared_model::interface::types::AccountIdType
+              &creator_account_id,
+          const std::string &tx_hash,
+          shared_model::interface::types::CommandIndexType cmd_index,
+          bool do_validation);
+
+      CommandResult operator()(
+          const shared_model::interface::ClaimWithPreimage &command,
+          const shared_model::interface::types::AccountIdType
+              &creator_account_id,
+          const std::string &tx_hash,
+          shared_model::interface::types::CommandIndexType cmd_index,
+          bool do_validation);
+
+      CommandResult operator()(
+          const shared_model::interface::RefundAfterTimeout &command,
+          const shared_model::interface::types::AccountIdType
+              &creator_account_id,
+          const std::string &tx_hash,
+          shared_model::interface::types::CommandIndexType cmd_index,
+          bool do_validation);
+
       CommandResult operator()(
           const shared_model::interface::SetAccountDetail &command,
           const shared_model::interface::types::AccountIdType
@@ -202,6 +311,14 @@ namespace iroha {
           shared_model::interface::types::CommandIndexType cmd_index,
           bool do_validation);
 
+      CommandResult operator()(
+          const shared_model::interface::SetAccountDetailAtPath &command,
+          const shared_model::interface::types::AccountIdType
+              &creator_account_id,
+          const std::string &tx_hash,
+          shared_model::interface::types::CommandIndexType cmd_index,
+          bool do_validation);
+
       CommandResult operator()(
           const shared_model::interface::SetQuorum &command,
           const shared_model::interface::types::AccountIdType
@@ -210,6 +327,14 @@ namespace iroha {
           shared_model::interface::types::CommandIndexType cmd_index,
           bool do_validation);
 
+      CommandResult operator()(
+          const shared_model::interface::SetSpendingLimit &command,
+          const shared_model::interface::types::AccountIdType
+              &creator_account_id,
+          const std::string &tx_hash,
+          shared_model::interface::types::CommandIndexType cmd_index,
+          bool do_validation);
+
       CommandResult operator()(
           const shared_model::interface::SubtractAssetQuantity &command,
           const shared_model::interface::types::AccountIdType
@@ -234,12 +359,24 @@ namespace iroha {
           shared_model::interface::types::CommandIndexType,
           bool do_validation);
 
+      CommandResult operator()(
+          const shared_model::interface::VoteForProposal &command,
+          const shared_model::interface::types::AccountIdType
+              &creator_account_id,
+          const std::string &tx_hash,
+          shared_model::interface::types::CommandIndexType cmd_index,
+          bool do_validation);
+
      private:
       class CommandStatements;
       class StatementExecutor;
 
       void initStatements();
 
+      /// Fail the command writing to accounts or assets of archived domains
+      CommandResult checkArchivedDomains(
+          const shared_model::interface::Command &cmd);
+
       std::unique_ptr<CommandStatements> makeCommandStatements(
           const std::unique_ptr<soci::session> &session,
           const std::string &base_statement,
@@ -258,24 +395,37 @@ namespace iroha {
       std::unique_ptr<CommandStatements> add_sync_peer_statements_;
       std::unique_ptr<CommandStatements> add_signatory_statements_;
       std::unique_ptr<CommandStatements> append_role_statements_;
+      std::unique_ptr<CommandStatements> archive_domain_statements_;
+      std::unique_ptr<CommandStatements> authorize_session_key_statements_;
+      std::unique_ptr<CommandStatements> claim_with_preimage_statements_;
       std::unique_ptr<CommandStatements>
           compare_and_set_account_detail_statements_;
       std::unique_ptr<CommandStatements> create_account_statements_;
       std::unique_ptr<CommandStatements> create_asset_statements_;
       std::unique_ptr<CommandStatements> create_domain_statements_;
       std::unique_ptr<CommandStatements> create_role_statements_;
+      std::unique_ptr<CommandStatements> deactivate_account_statements_;
       std::unique_ptr<CommandStatements> detach_role_statements_;
       std::unique_ptr<CommandStatements> grant_permission_statements_;
+      std::unique_ptr<CommandStatements> grant_vested_asset_statements_;
+      std::unique_ptr<CommandStatements> include_role_statements_;
+      std::unique_ptr<CommandStatements> lock_asset_statements_;
+      std::unique_ptr<CommandStatements> propose_setting_value_statements_;
+      std::unique_ptr<CommandStatements> recover_account_statements_;
+      std::unique_ptr<CommandStatements> refund_after_timeout_statements_;
       std::unique_ptr<CommandStatements> remove_peer_statements_;
       std::unique_ptr<CommandStatements> remove_sync_peer_statements_;
       std::unique_ptr<CommandStatements> remove_signatory_statements_;
       std::unique_ptr<CommandStatements> revoke_permission_statements_;
+      std::unique_ptr<CommandStatements> rotate_key_statements_;
       std::unique_ptr<CommandStatements> set_account_detail_statements_;
       std::unique_ptr<CommandStatements> set_quorum_statements_;
+      std::unique_ptr<CommandStatements> set_spending_limit_statements_;
       std::unique_ptr<CommandStatements> store_engine_response_statements_;
       std::unique_ptr<CommandStatements> subtract_asset_quantity_statements_;
       std::unique_ptr<CommandStatements> transfer_asset_statements_;
       std::unique_ptr<CommandStatements> set_setting_value_statements_;
+      std::unique_ptr<CommandStatements> vote_for_proposal_statements_;
     };
   }  // namespace ametsuchi
 }  // namespace iroha
@@ -8,6 +8,7 @@
 #include <fmt/core.h>
 #include <soci/soci.h>
 #include <boost/format.hpp>
+#include "ametsuchi/impl/soci_std_optional.hpp"
 #include "cryptography/hash.hpp"
 
 using namespace iroha::ametsuchi;
@@ -48,6 +49,29 @@ void PostgresIndexer::txPositions(
   tx_positions_.index.emplace_back(position.index);
 }
 
+iroha::expected::Result<std::optional<Indexer::AssetLockParties>, std::string>
+PostgresIndexer::assetLockRelease(std::string_view hash) {
+  try {
+    std::string const lock_hash{hash};
+    std::optional<AccountIdType> src_account_id;
+    std::optional<AccountIdType> dest_account_id;
+    std::optional<AssetIdType> asset_id;
+    sql_ << "SELECT src_account_id, dest_account_id, asset_id "
+            "FROM asset_lock_release WHERE hash = lower(:hash)",
+        soci::use(lock_hash, "hash"), soci::into(src_account_id),
+        soci::into(dest_account_id), soci::into(asset_id);
+    if (not src_account_id or not dest_account_id or not asset_id) {
+      return std::optional<AssetLockParties>{};
+    }
+    return std::optional<AssetLockParties>{AssetLockParties{
+        std::move(*src_account_id),
+        std::move(*dest_account_id),
+        std::move(*asset_id)}};
+  } catch (const std::exception &e) {
+    return e.what();
+  }
+}
+
 iroha::expected::Result<void, std::string> PostgresIndexer::flush() {
   try {
     cache_.clear();
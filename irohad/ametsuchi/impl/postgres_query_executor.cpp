@@ -38,6 +38,14 @@ namespace iroha::ametsuchi {
     return validateSignaturesImpl(query);
   }
 
+  void PostgresQueryExecutor::setTimeout(std::chrono::milliseconds timeout) {
+    try {
+      *sql_ << "SET statement_timeout = " << timeout.count();
+    } catch (const std::exception &e) {
+      log_->error("Failed to set query timeout: {}", e.what());
+    }
+  }
+
   template <class Q>
   bool PostgresQueryExecutor::validateSignaturesImpl(const Q &query) {
     auto keys_range =
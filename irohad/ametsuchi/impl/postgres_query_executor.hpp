@@ -35,6 +35,8 @@ namespace iroha::ametsuchi {
     bool validateSignatures(
         const shared_model::interface::BlocksQuery &query) override;
 
+    void setTimeout(std::chrono::milliseconds timeout) override;
+
    private:
     template <class Q>
     bool validateSignaturesImpl(const Q &query);
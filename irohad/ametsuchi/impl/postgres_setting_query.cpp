@@ -57,6 +57,7 @@ PostgresSettingQuery::update(
 
   try {
     get_and_log(kMaxDescriptionSizeKey, base->max_description_size);
+    get_and_log(kChainIdKey, base->chain_id);
   } catch (std::exception &e) {
     return expected::makeError(e.what());
   }
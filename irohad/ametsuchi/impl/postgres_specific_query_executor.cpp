@@ -5,19 +5,25 @@
 
 #include "ametsuchi/impl/postgres_specific_query_executor.hpp"
 
+#include <boost/algorithm/string/case_conv.hpp>
 #include <boost/algorithm/string/join.hpp>
 #include <boost/algorithm/string/split.hpp>
 #include <boost/range/adaptor/filtered.hpp>
 #include <boost/range/adaptor/transformed.hpp>
 #include <boost/range/algorithm/transform.hpp>
 #include <boost/range/irange.hpp>
+#include <soci/postgresql/soci-postgresql.h>
 #include <tuple>
 #include <unordered_map>
 
+#include "ametsuchi/blob_hash.hpp"
+#include "ametsuchi/fee_estimation.hpp"
 #include "ametsuchi/block_storage.hpp"
 #include "ametsuchi/impl/executor_common.hpp"
 #include "ametsuchi/impl/soci_std_optional.hpp"
 #include "ametsuchi/impl/soci_utils.hpp"
+#include "ametsuchi/setting_registry.hpp"
+#include "ametsuchi/vesting_schedule.hpp"
 #include "backend/plain/account_detail_record_id.hpp"
 #include "backend/plain/engine_receipt.hpp"
 #include "backend/plain/peer.hpp"
@@ -29,22 +35,28 @@
 #include "interfaces/iroha_internal/block.hpp"
 #include "interfaces/permission_to_string.hpp"
 #include "interfaces/queries/asset_pagination_meta.hpp"
+#include "interfaces/queries/estimate_fee.hpp"
 #include "interfaces/queries/get_account.hpp"
 #include "interfaces/queries/get_account_asset_transactions.hpp"
 #include "interfaces/queries/get_account_assets.hpp"
 #include "interfaces/queries/get_account_detail.hpp"
+#include "interfaces/queries/get_account_permissions.hpp"
 #include "interfaces/queries/get_account_transactions.hpp"
 #include "interfaces/queries/get_asset_info.hpp"
+#include "interfaces/queries/get_asset_stats.hpp"
 #include "interfaces/queries/get_block.hpp"
 #include "interfaces/queries/get_engine_receipts.hpp"
 #include "interfaces/queries/get_peers.hpp"
 #include "interfaces/queries/get_pending_transactions.hpp"
 #include "interfaces/queries/get_role_permissions.hpp"
 #include "interfaces/queries/get_roles.hpp"
+#include "interfaces/queries/get_settings.hpp"
 #include "interfaces/queries/get_signatories.hpp"
 #include "interfaces/queries/get_transactions.hpp"
+#include "interfaces/queries/get_vesting_schedules.hpp"
 #include "interfaces/queries/query.hpp"
 #include "interfaces/queries/tx_pagination_meta.hpp"
+#include "interfaces/queries/verify_blob.hpp"
 #include "interfaces/transaction.hpp"
 #include "logger/logger.hpp"
 #include "pending_txs_storage/pending_txs_storage.hpp"
@@ -366,6 +378,15 @@ namespace iroha {
               return std::forward<ResponseCreator>(response_creator)(
                   query_range, perms...);
             });
+      } catch (const soci::postgresql_soci_error &e) {
+        // query_canceled is reported when statement_timeout is exceeded
+        static const std::string kQueryCanceled{"57014"};
+        return this->logAndReturnErrorResponse(
+            e.sqlstate() == kQueryCanceled ? QueryErrorType::kTimeout
+                                           : QueryErrorType::kStatefulFailed,
+            e.what(),
+            1,
+            query_hash);
       } catch (const std::exception &e) {
         return this->logAndReturnErrorResponse(
             QueryErrorType::kStatefulFailed, e.what(), 1, query_hash);
@@ -551,15 +572,58 @@ namespace iroha {
             // page size + 1, it means that the last transaction is the
             // first one in the next page and we need to return it as
             // the next hash
+            std::optional<shared_model::crypto::Hash> next_hash;
             if (response_txs.size() == query_size) {
-              auto next_hash = response_txs.back()->hash();
+              next_hash = response_txs.back()->hash();
               response_txs.pop_back();
-              return query_response_factory_->createTransactionsPageResponse(
-                  std::move(response_txs), next_hash, total_size, query_hash);
             }
 
+            std::vector<
+                shared_model::interface::TransactionsPageResponse::TransferFee>
+                transfer_fees;
+            if constexpr (std::is_same_v<
+                              Query,
+                              shared_model::interface::
+                                  GetAccountAssetTransactions>) {
+              try {
+                for (auto const &tx : response_txs) {
+                  auto const tx_hash = tx->hash().hex();
+                  for (auto index :
+                       getAssetTransferIndices(*tx, q.assetId())) {
+                    std::optional<std::string> fee;
+                    sql_ << "SELECT fee::text FROM transfer_fee "
+                            "WHERE tx_hash = :tx_hash "
+                            "AND cmd_index = :cmd_index",
+                        soci::use(tx_hash, "tx_hash"),
+                        soci::use(index, "cmd_index"), soci::into(fee);
+                    if (fee) {
+                      transfer_fees.push_back(
+                          {tx->hash(),
+                           index,
+                           shared_model::interface::Amount{*fee}});
+                    }
+                  }
+                }
+              } catch (const std::exception &e) {
+                return this->logAndReturnErrorResponse(
+                    QueryErrorType::kStatefulFailed, e.what(), 1, query_hash);
+              }
+            }
+
+            if (next_hash) {
+              return query_response_factory_->createTransactionsPageResponse(
+                  std::move(response_txs),
+                  *next_hash,
+                  total_size,
+                  std::move(transfer_fees),
+                  query_hash);
+            }
             return query_response_factory_->createTransactionsPageResponse(
-                std::move(response_txs), std::nullopt, total_size, query_hash);
+                std::move(response_txs),
+                std::nullopt,
+                total_size,
+                std::move(transfer_fees),
+                query_hash);
           },
           notEnoughPermissionsResponse(perm_converter_, perms...));
     }
@@ -660,6 +724,14 @@ namespace iroha {
             + std::to_string(height);
       };
       auto block = block_store_.fetch(q.height());
+      if (auto first_retained = block_store_.firstRetainedHeight();
+          not block and isPrunedBlock(first_retained, q.height())) {
+        return logAndReturnErrorResponse(
+            QueryErrorType::kStatefulFailed,
+            prunedBlockMessage(q.height(), *first_retained),
+            4,
+            query_hash);
+      }
       if (not block) {
         // for some reason, block with such height was not retrieved
         return logAndReturnErrorResponse(QueryErrorType::kStatefulFailed,
@@ -1668,6 +1740,384 @@ namespace iroha {
                                        Role::kGetDomainEngineReceipts));
     }
 
+    QueryExecutorResult PostgresSpecificQueryExecutor::operator()(
+        const shared_model::interface::GetSettings &q,
+        const shared_model::interface::types::AccountIdType &creator_id,
+        const shared_model::interface::types::HashType &query_hash) {
+      using QueryTuple =
+          QueryType<shared_model::interface::types::SettingKeyType,
+                    shared_model::interface::types::SettingValueType>;
+      using PermissionTuple = boost::tuple<int>;
+
+      auto cmd = fmt::format(
+          R"(WITH has_perms AS ({})
+      SELECT setting_key, setting_value, perm FROM setting
+      RIGHT OUTER JOIN has_perms ON TRUE
+      )",
+          getAccountRolePermissionCheckSql(Role::kGetSettings));
+
+      return executeQuery<QueryTuple, PermissionTuple>(
+          [&] {
+            return (sql_.prepare << cmd,
+                    soci::use(creator_id, "role_account_id"));
+          },
+          query_hash,
+          [&](auto range, auto &) {
+            shared_model::interface::SettingsMapType settings;
+            for (const auto &row : range) {
+              iroha::ametsuchi::apply(row, [&settings](auto &key, auto &value) {
+                if (key and value) {
+                  settings.emplace(*std::move(key), *std::move(value));
+                }
+              });
+            }
+            return query_response_factory_->createSettingsResponse(
+                std::move(settings), settingDescriptors(), query_hash);
+          },
+          notEnoughPermissionsResponse(perm_converter_, Role::kGetSettings));
+    }
+
+    QueryExecutorResult PostgresSpecificQueryExecutor::operator()(
+        const shared_model::interface::GetAssetStats &q,
+        const shared_model::interface::types::AccountIdType &creator_id,
+        const shared_model::interface::types::HashType &query_hash) {
+      using QueryTuple = QueryType<uint32_t,
+                                   std::string,
+                                   std::string,
+                                   std::string,
+                                   uint64_t>;
+      using PermissionTuple = boost::tuple<int>;
+
+      // locked assets are still in circulation
+      auto cmd = fmt::format(
+          R"(WITH has_perms AS ({}),
+      target AS (SELECT asset_id, precision FROM asset
+                 WHERE asset_id = :asset_id),
+      stats AS (
+          SELECT target.precision,
+              COALESCE(asset_stats.minted, 0)::text AS minted,
+              COALESCE(asset_stats.burned, 0)::text AS burned,
+              ((SELECT COALESCE(SUM(amount), 0) FROM account_has_asset
+                WHERE account_has_asset.asset_id = target.asset_id)
+               + (SELECT COALESCE(SUM(amount), 0) FROM asset_lock
+                  WHERE asset_lock.asset_id = target.asset_id))::text
+                  AS circulating,
+              (SELECT COUNT(*) FROM account_has_asset
+               WHERE account_has_asset.asset_id = target.asset_id
+                   AND amount > 0) AS holders_count
+          FROM target
+          LEFT JOIN asset_stats ON asset_stats.asset_id = target.asset_id
+      )
+      SELECT precision, minted, burned, circulating, holders_count, perm
+      FROM stats
+      RIGHT OUTER JOIN has_perms ON TRUE
+      )",
+          getAccountRolePermissionCheckSql(Role::kReadAssets));
+
+      return executeQuery<QueryTuple, PermissionTuple>(
+          [&] {
+            return (sql_.prepare << cmd,
+                    soci::use(creator_id, "role_account_id"),
+                    soci::use(q.assetId(), "asset_id"));
+          },
+          query_hash,
+          [this, &q, &creator_id, &query_hash](auto range, auto &) {
+            auto range_without_nulls = resultWithoutNulls(std::move(range));
+            if (range_without_nulls.empty()) {
+              return this->logAndReturnErrorResponse(
+                  QueryErrorType::kNoAsset,
+                  "{" + q.assetId() + ", " + creator_id + "}",
+                  0,
+                  query_hash);
+            }
+
+            return iroha::ametsuchi::apply(
+                range_without_nulls.front(),
+                [this, &q, &query_hash](auto &precision,
+                                        auto &minted,
+                                        auto &burned,
+                                        auto &circulating,
+                                        auto &holders_count) {
+                  // the sums have the scale of the asset precision at most
+                  auto to_amount = [precision](const std::string &value) {
+                    shared_model::interface::Amount amount(precision);
+                    amount += shared_model::interface::Amount(value);
+                    return amount;
+                  };
+                  return query_response_factory_->createAssetStatsResponse(
+                      q.assetId(),
+                      to_amount(minted),
+                      to_amount(burned),
+                      to_amount(circulating),
+                      holders_count,
+                      query_hash);
+                });
+          },
+          notEnoughPermissionsResponse(perm_converter_, Role::kReadAssets));
+    }
+
+    QueryExecutorResult PostgresSpecificQueryExecutor::operator()(
+        const shared_model::interface::GetAccountPermissions &q,
+        const shared_model::interface::types::AccountIdType &creator_id,
+        const shared_model::interface::types::HashType &query_hash) {
+      using QueryTuple = QueryType<std::string>;
+      using PermissionTuple = boost::tuple<int>;
+
+      // included roles are already merged into role_has_permissions
+      auto cmd = fmt::format(
+          R"(WITH {},
+      t AS (
+          SELECT COALESCE(bit_or(rp.permission), '0'::bit({}))
+              AS permission
+          FROM account AS a
+          LEFT JOIN account_has_roles AS ar ON ar.account_id = a.account_id
+          LEFT JOIN role_has_permissions AS rp ON rp.role_id = ar.role_id
+          WHERE a.account_id = :target_account_id
+          GROUP BY a.account_id
+      )
+      SELECT permission, perm
+      FROM t RIGHT OUTER JOIN has_perms AS p ON TRUE
+      )",
+          hasQueryPermissionTarget(creator_id,
+                                   q.accountId(),
+                                   Role::kGetMyAccount,
+                                   Role::kGetAllAccounts,
+                                   Role::kGetDomainAccounts),
+          shared_model::interface::RolePermissionSet::size());
+
+      return executeQuery<QueryTuple, PermissionTuple>(
+          [&] {
+            return (sql_.prepare << cmd,
+                    soci::use(q.accountId(), "target_account_id"));
+          },
+          query_hash,
+          [this, &q, &query_hash](auto range, auto &) {
+            auto range_without_nulls = resultWithoutNulls(std::move(range));
+            if (range_without_nulls.empty()) {
+              return this->logAndReturnErrorResponse(
+                  QueryErrorType::kNoAccount, q.accountId(), 0, query_hash);
+            }
+
+            return iroha::ametsuchi::apply(
+                range_without_nulls.front(),
+                [this, &q, &query_hash](auto &permission) {
+                  return query_response_factory_->createRolePermissionsResponse(
+                      filterPermissions(
+                          shared_model::interface::RolePermissionSet(
+                              permission),
+                          q.nameFilter(),
+                          *perm_converter_),
+                      query_hash);
+                });
+          },
+          notEnoughPermissionsResponse(perm_converter_,
+                                       Role::kGetMyAccount,
+                                       Role::kGetAllAccounts,
+                                       Role::kGetDomainAccounts));
+    }
+
+    QueryExecutorResult PostgresSpecificQueryExecutor::operator()(
+        const shared_model::interface::VerifyBlob &q,
+        const shared_model::interface::types::AccountIdType &creator_id,
+        const shared_model::interface::types::HashType &query_hash) {
+      using QueryTuple = QueryType<int, std::string>;
+      using PermissionTuple = boost::tuple<int>;
+
+      auto cmd = fmt::format(
+          R"(WITH {},
+      t AS (
+          SELECT
+              CASE WHEN data -> :writer ->> :key IS NULL THEN 0 ELSE 1 END
+                  AS detail_exists,
+              COALESCE(data -> :writer ->> :key, '') AS stored_hash
+          FROM account
+          WHERE account_id = :target_account_id
+      )
+      SELECT detail_exists, stored_hash, perm
+      FROM t RIGHT OUTER JOIN has_perms AS p ON TRUE
+      )",
+          hasQueryPermissionTarget(creator_id,
+                                   q.accountId(),
+                                   Role::kGetMyAccDetail,
+                                   Role::kGetAllAccDetail,
+                                   Role::kGetDomainAccDetail));
+
+      return executeQuery<QueryTuple, PermissionTuple>(
+          [&] {
+            return (sql_.prepare << cmd,
+                    soci::use(q.accountId(), "target_account_id"),
+                    soci::use(q.writer(), "writer"),
+                    soci::use(q.key(), "key"));
+          },
+          query_hash,
+          [this, &q, &query_hash](auto range, auto &) {
+            auto range_without_nulls = resultWithoutNulls(std::move(range));
+            if (range_without_nulls.empty()) {
+              return this->logAndReturnErrorResponse(
+                  QueryErrorType::kNoAccount, q.accountId(), 0, query_hash);
+            }
+
+            return iroha::ametsuchi::apply(
+                range_without_nulls.front(),
+                [this, &q, &query_hash](auto &detail_exists,
+                                        auto &stored_hash) {
+                  if (detail_exists == 0) {
+                    return this->logAndReturnErrorResponse(
+                        QueryErrorType::kNoAccountDetail,
+                        q.toString(),
+                        0,
+                        query_hash);
+                  }
+                  return query_response_factory_
+                      ->createBlobVerificationResponse(
+                          boost::algorithm::to_lower_copy(stored_hash)
+                              == saltedBlobHash(q.salt(), q.blob()),
+                          query_hash);
+                });
+          },
+          notEnoughPermissionsResponse(perm_converter_,
+                                       Role::kGetMyAccDetail,
+                                       Role::kGetAllAccDetail,
+                                       Role::kGetDomainAccDetail));
+    }
+
+    QueryExecutorResult PostgresSpecificQueryExecutor::operator()(
+        const shared_model::interface::EstimateFee &q,
+        const shared_model::interface::types::AccountIdType &creator_id,
+        const shared_model::interface::types::HashType &query_hash) {
+      using QueryTuple =
+          QueryType<shared_model::interface::types::SettingKeyType,
+                    shared_model::interface::types::SettingValueType>;
+      using PermissionTuple = boost::tuple<int>;
+
+      auto cmd = fmt::format(
+          R"(WITH has_perms AS ({}),
+      policies AS (
+          SELECT setting_key, setting_value FROM setting
+          WHERE setting_key LIKE :prefix
+      )
+      SELECT setting_key, setting_value, perm FROM policies
+      RIGHT OUTER JOIN has_perms ON TRUE
+      )",
+          getAccountRolePermissionCheckSql(Role::kTransfer));
+      // the supply policies are few, so all of them are read at once
+      const auto prefix = supplyPolicyKey("") + "%";
+
+      return executeQuery<QueryTuple, PermissionTuple>(
+          [&] {
+            return (sql_.prepare << cmd,
+                    soci::use(creator_id, "role_account_id"),
+                    soci::use(prefix, "prefix"));
+          },
+          query_hash,
+          [&](auto range, auto &) {
+            std::unordered_map<std::string, std::string> policies;
+            for (const auto &row : range) {
+              iroha::ametsuchi::apply(row, [&policies](auto &key, auto &value) {
+                if (key and value) {
+                  policies.emplace(*std::move(key), *std::move(value));
+                }
+              });
+            }
+            auto fees = estimateFees(
+                q.commands(),
+                [&policies](const auto &asset_id)
+                    -> expected::Result<std::optional<SupplyPolicy>,
+                                        std::string> {
+                  auto it = policies.find(supplyPolicyKey(asset_id));
+                  if (it == policies.end()) {
+                    return expected::makeValue(std::optional<SupplyPolicy>{});
+                  }
+                  return SupplyPolicy::parse(it->second) | [](auto policy) {
+                    return expected::makeValue(
+                        std::optional<SupplyPolicy>{std::move(policy)});
+                  };
+                });
+            if (auto e = expected::resultToOptionalError(fees)) {
+              return this->logAndReturnErrorResponse(
+                  QueryErrorType::kStatefulFailed, *e, 1, query_hash);
+            }
+            return query_response_factory_->createFeeEstimationResponse(
+                std::move(fees).assumeValue(), query_hash);
+          },
+          notEnoughPermissionsResponse(perm_converter_, Role::kTransfer));
+    }
+
+    QueryExecutorResult PostgresSpecificQueryExecutor::operator()(
+        const shared_model::interface::GetVestingSchedules &q,
+        const shared_model::interface::types::AccountIdType &creator_id,
+        const shared_model::interface::types::HashType &query_hash) {
+      using QueryTuple =
+          QueryType<shared_model::interface::types::AssetIdType,
+                    std::string,
+                    shared_model::interface::types::HeightType,
+                    shared_model::interface::types::HeightType,
+                    shared_model::interface::types::HeightType,
+                    shared_model::interface::types::HeightType>;
+      using PermissionTuple = boost::tuple<int>;
+
+      auto cmd = fmt::format(R"(
+      WITH {},
+      schedules AS (
+          SELECT asset_id, amount::text, start_height, cliff, duration,
+              COALESCE((SELECT height FROM top_block_info), 0) + 1 AS height
+          FROM vesting_schedule
+          WHERE account_id = :account_id
+      )
+      SELECT asset_id, amount, start_height, cliff, duration, height, perm
+      FROM schedules
+      RIGHT OUTER JOIN has_perms ON TRUE
+      ORDER BY asset_id
+      )",
+                             hasQueryPermissionTarget(creator_id,
+                                                      q.accountId(),
+                                                      Role::kGetMyAccAst,
+                                                      Role::kGetAllAccAst,
+                                                      Role::kGetDomainAccAst));
+
+      return executeQuery<QueryTuple, PermissionTuple>(
+          [&] {
+            return (sql_.prepare << cmd,
+                    soci::use(q.accountId(), "account_id"));
+          },
+          query_hash,
+          [&](auto range, auto &) {
+            auto range_without_nulls = resultWithoutNulls(std::move(range));
+            std::vector<
+                shared_model::interface::VestingSchedulesResponse::Schedule>
+                schedules;
+            for (const auto &row : range_without_nulls) {
+              iroha::ametsuchi::apply(
+                  row,
+                  [&schedules](auto &asset_id,
+                               auto &amount,
+                               auto &start_height,
+                               auto &cliff,
+                               auto &duration,
+                               auto &height) {
+                    VestingSchedule schedule{
+                        shared_model::interface::Amount(amount),
+                        start_height,
+                        cliff,
+                        duration};
+                    schedules.push_back({std::move(asset_id),
+                                         schedule.amount,
+                                         start_height,
+                                         cliff,
+                                         duration,
+                                         schedule.vested(height),
+                                         schedule.locked(height)});
+                  });
+            }
+            return query_response_factory_->createVestingSchedulesResponse(
+                std::move(schedules), query_hash);
+          },
+          notEnoughPermissionsResponse(perm_converter_,
+                                       Role::kGetMyAccAst,
+                                       Role::kGetAllAccAst,
+                                       Role::kGetDomainAccAst));
+    }
+
     template <typename ReturnValueType>
     bool PostgresSpecificQueryExecutor::existsInDb(
         const std::string &table_name,
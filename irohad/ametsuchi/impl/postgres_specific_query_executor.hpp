@@ -30,6 +30,12 @@ namespace shared_model {
     class GetPendingTransactions;
     class GetPeers;
     class GetEngineReceipts;
+    class GetSettings;
+    class GetAssetStats;
+    class GetAccountPermissions;
+    class VerifyBlob;
+    class EstimateFee;
+    class GetVestingSchedules;
   }  // namespace interface
 }  // namespace shared_model
 
@@ -137,6 +143,36 @@ namespace iroha {
           const shared_model::interface::types::AccountIdType &creator_id,
           const shared_model::interface::types::HashType &query_hash);
 
+      QueryExecutorResult operator()(
+          const shared_model::interface::GetSettings &q,
+          const shared_model::interface::types::AccountIdType &creator_id,
+          const shared_model::interface::types::HashType &query_hash);
+
+      QueryExecutorResult operator()(
+          const shared_model::interface::GetAssetStats &q,
+          const shared_model::interface::types::AccountIdType &creator_id,
+          const shared_model::interface::types::HashType &query_hash);
+
+      QueryExecutorResult operator()(
+          const shared_model::interface::GetAccountPermissions &q,
+          const shared_model::interface::types::AccountIdType &creator_id,
+          const shared_model::interface::types::HashType &query_hash);
+
+      QueryExecutorResult operator()(
+          const shared_model::interface::VerifyBlob &q,
+          const shared_model::interface::types::AccountIdType &creator_id,
+          const shared_model::interface::types::HashType &query_hash);
+
+      QueryExecutorResult operator()(
+          const shared_model::interface::EstimateFee &q,
+          const shared_model::interface::types::AccountIdType &creator_id,
+          const shared_model::interface::types::HashType &query_hash);
+
+      QueryExecutorResult operator()(
+          const shared_model::interface::GetVestingSchedules &q,
+          const shared_model::interface::types::AccountIdType &creator_id,
+          const shared_model::interface::types::HashType &query_hash);
+
      private:
       /**
        * Get transactions from block using range from range_gen and filtered by
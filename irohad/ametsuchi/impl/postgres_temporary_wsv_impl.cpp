@@ -5,12 +5,15 @@
 
 #include "ametsuchi/impl/postgres_temporary_wsv_impl.hpp"
 
-#include <boost/algorithm/string/join.hpp>
-#include <boost/format.hpp>
-#include <boost/range/adaptor/transformed.hpp>
+#include <unordered_map>
+#include <unordered_set>
+
+#include <soci/boost-tuple.h>
 #include "ametsuchi/impl/postgres_command_executor.hpp"
 #include "ametsuchi/impl/postgres_db_transaction.hpp"
+#include "ametsuchi/session_key.hpp"
 #include "ametsuchi/tx_executor.hpp"
+#include "common/to_lower.hpp"
 #include "interfaces/commands/command.hpp"
 #include "interfaces/permission_to_string.hpp"
 #include "interfaces/transaction.hpp"
@@ -28,31 +31,80 @@ namespace iroha::ametsuchi {
   expected::Result<void, validation::CommandError>
   PostgresTemporaryWsvImpl::validateSignatures(
       const shared_model::interface::Transaction &transaction) {
-    auto keys_range = transaction.signatures()
-        | boost::adaptors::transformed(
-                          [](const auto &s) { return s.publicKey(); });
-    auto keys = boost::algorithm::join(keys_range, "'), ('");
-    // not using bool since it is not supported by SOCI
-    boost::optional<uint8_t> signatories_valid;
-
-    boost::format query(R"(SELECT sum(count) = :signatures_count
-                          AND sum(quorum) <= :signatures_count
-                  FROM
-                      (SELECT count(public_key)
-                      FROM ( VALUES ('%s') ) AS CTE1(public_key)
-                      WHERE lower(public_key) IN
-                          (SELECT public_key
-                          FROM account_has_signatory
-                          WHERE account_id = :account_id ) ) AS CTE2(count),
-                          (SELECT quorum
-                          FROM account
-                          WHERE account_id = :account_id) AS CTE3(quorum))");
+    auto const &account_id = transaction.creatorAccountId();
+    size_t quorum = 0;
+    size_t top_height = 0;
+    std::unordered_set<std::string> signatories;
+    std::unordered_map<std::string, std::string> replaced_by;
+    std::unordered_map<std::string, std::string> session_keys;
 
     try {
-      auto keys_range_size = boost::size(keys_range);
-      sql_ << (query % keys).str(), soci::into(signatories_valid),
-          soci::use(keys_range_size, "signatures_count"),
-          soci::use(transaction.creatorAccountId(), "account_id");
+      int deactivated = 0;
+      sql_ << "SELECT count(*) FROM account "
+              "WHERE account_id = :account_id AND deactivated",
+          soci::into(deactivated), soci::use(account_id, "account_id");
+      if (deactivated != 0) {
+        return expected::makeError(validation::CommandError{
+            "signatures validation",
+            3,
+            "Transaction " + transaction.toString()
+                + " is created by deactivated account",
+            false});
+      }
+
+      int archived = 0;
+      sql_ << "SELECT count(*) FROM account "
+              "JOIN domain ON domain.domain_id = account.domain_id "
+              "WHERE account_id = :account_id AND archived",
+          soci::into(archived), soci::use(account_id, "account_id");
+      if (archived != 0) {
+        return expected::makeError(validation::CommandError{
+            "signatures validation",
+            3,
+            "Transaction " + transaction.toString()
+                + " is created by account of archived domain",
+            false});
+      }
+
+      sql_ << "SELECT COALESCE((SELECT height FROM top_block_info), 0), "
+              "COALESCE((SELECT quorum FROM account "
+              "WHERE account_id = :account_id), 0)",
+          soci::into(top_height), soci::into(quorum),
+          soci::use(account_id, "account_id");
+
+      // rotated keys are accepted until the end of the grace period
+      soci::rowset<std::string> signatory_rows =
+          (sql_.prepare << "SELECT public_key FROM account_has_signatory "
+                           "WHERE account_id = :account_id "
+                           "UNION "
+                           "SELECT public_key FROM account_retired_signatory "
+                           "WHERE account_id = :account_id "
+                           "AND expires_height > :top_height",
+           soci::use(account_id, "account_id"),
+           soci::use(top_height, "top_height"));
+      for (const auto &public_key : signatory_rows) {
+        signatories.insert(public_key);
+      }
+
+      soci::rowset<boost::tuple<std::string, std::string>> retired_rows =
+          (sql_.prepare << "SELECT public_key, new_public_key "
+                           "FROM account_retired_signatory "
+                           "WHERE account_id = :account_id",
+           soci::use(account_id, "account_id"));
+      for (const auto &row : retired_rows) {
+        replaced_by.emplace(row.get<0>(), row.get<1>());
+      }
+
+      soci::rowset<boost::tuple<std::string, std::string>> session_key_rows =
+          (sql_.prepare << "SELECT public_key, "
+                           "concat_ws('|', expires_height, permissions::text, "
+                           "allowed_asset_id) "
+                           "FROM account_session_key "
+                           "WHERE account_id = :account_id",
+           soci::use(account_id, "account_id"));
+      for (const auto &row : session_key_rows) {
+        session_keys.emplace(row.get<0>(), row.get<1>());
+      }
     } catch (const std::exception &e) {
       auto error_str = "Transaction " + transaction.toString()
           + " failed signatures validation with db error: " + e.what();
@@ -62,16 +114,57 @@ namespace iroha::ametsuchi {
           "signatures validation", 1, error_str, false});
     }
 
-    if (signatories_valid and *signatories_valid) {
-      return {};
-    } else {
+    auto signatures_error = [&transaction] {
       auto error_str = "Transaction " + transaction.toString()
           + " failed signatures validation";
       // TODO [IR-1816] Akvinikym 29.10.18: substitute error code magic number
       // with named constant
       return expected::makeError(validation::CommandError{
           "signatures validation", 2, error_str, false});
+    };
+
+    // only the signatories count towards the quorum
+    std::vector<std::string> signed_by;
+    bool signed_by_session_key = false;
+    std::string pk;
+    for (auto const &signature : transaction.signatures()) {
+      pk.clear();
+      toLowerAppend(signature.publicKey(), pk);
+      if (signatories.count(pk) != 0) {
+        signed_by.push_back(pk);
+        continue;
+      }
+      // a session key authorizes the transactions within its scope alone
+      if (auto it = session_keys.find(pk); it != session_keys.end()) {
+        auto key = SessionKey::decode(it->second);
+        if (expected::hasError(key)) {
+          return expected::makeError(
+              validation::CommandError{"signatures validation",
+                                       1,
+                                       std::move(key).assumeError(),
+                                       false});
+        }
+        if (auto reason =
+                key.assumeValue().checkSigning(transaction, top_height)) {
+          return expected::makeError(validation::CommandError{
+              "signatures validation",
+              4,
+              "Transaction " + transaction.toString()
+                  + " is signed by session key " + pk + " of account "
+                  + account_id + ", but " + *reason,
+              false});
+        }
+        signed_by_session_key = true;
+        continue;
+      }
+      return signatures_error();
+    }
+
+    if (not signed_by_session_key
+        and countSignatories(signed_by, replaced_by) < quorum) {
+      return signatures_error();
     }
+    return {};
   }
 
 }  // namespace iroha::ametsuchi
@@ -49,8 +49,10 @@ namespace iroha::ametsuchi {
       log_->error("query signatories did not pass validation");
       return false;
     }
+    auto const permission =
+        query.accountScoped() ? Role::kGetMyAccTxs : Role::kGetBlocks;
     if (not specific_query_executor_->hasAccountRolePermission(
-            Role::kGetBlocks, query.creatorAccountId())) {
+            permission, query.creatorAccountId())) {
       log_->error("query creator does not have enough permissions");
       return false;
     }
@@ -5,38 +5,67 @@
 
 #include "ametsuchi/impl/rocksdb_command_executor.hpp"
 
+#include <algorithm>
+
 #include <fmt/core.h>
 #include <rocksdb/utilities/transaction.h>
 #include <boost/algorithm/string.hpp>
 #include <boost/variant/apply_visitor.hpp>
+#include "ametsuchi/account_detail_limits.hpp"
+#include "ametsuchi/account_detail_path.hpp"
+#include "ametsuchi/asset_lock.hpp"
+#include "ametsuchi/domain_policy.hpp"
+#include "ametsuchi/governance_policy.hpp"
+#include "ametsuchi/idempotency_key.hpp"
 #include "ametsuchi/impl/executor_common.hpp"
 #include "ametsuchi/impl/rocksdb_burrow_storage.hpp"
 #include "ametsuchi/impl/rocksdb_specific_query_executor.hpp"
+#include "ametsuchi/session_key.hpp"
 #include "ametsuchi/setting_query.hpp"
+#include "ametsuchi/setting_registry.hpp"
+#include "ametsuchi/spending_limit.hpp"
+#include "ametsuchi/supply_policy.hpp"
+#include "ametsuchi/transfer_policy.hpp"
+#include "ametsuchi/vesting_schedule.hpp"
 #include "ametsuchi/vm_caller.hpp"
 #include "common/to_lower.hpp"
 #include "interfaces/commands/add_asset_quantity.hpp"
 #include "interfaces/commands/add_peer.hpp"
 #include "interfaces/commands/add_signatory.hpp"
 #include "interfaces/commands/append_role.hpp"
+#include "interfaces/commands/archive_domain.hpp"
+#include "interfaces/commands/authorize_session_key.hpp"
 #include "interfaces/commands/call_engine.hpp"
+#include "interfaces/commands/claim_with_preimage.hpp"
 #include "interfaces/commands/command.hpp"
 #include "interfaces/commands/compare_and_set_account_detail.hpp"
 #include "interfaces/commands/create_account.hpp"
 #include "interfaces/commands/create_asset.hpp"
 #include "interfaces/commands/create_domain.hpp"
 #include "interfaces/commands/create_role.hpp"
+#include "interfaces/commands/deactivate_account.hpp"
 #include "interfaces/commands/detach_role.hpp"
 #include "interfaces/commands/grant_permission.hpp"
+#include "interfaces/commands/grant_vested_asset.hpp"
+#include "interfaces/commands/include_role.hpp"
+#include "interfaces/commands/lock_asset.hpp"
+#include "interfaces/commands/propose_setting_value.hpp"
+#include "interfaces/commands/recover_account.hpp"
+#include "interfaces/commands/refund_after_timeout.hpp"
 #include "interfaces/commands/remove_peer.hpp"
 #include "interfaces/commands/remove_signatory.hpp"
 #include "interfaces/commands/revoke_permission.hpp"
+#include "interfaces/commands/rotate_key.hpp"
 #include "interfaces/commands/set_account_detail.hpp"
+#include "interfaces/commands/set_account_detail_at_path.hpp"
 #include "interfaces/commands/set_quorum.hpp"
 #include "interfaces/commands/set_setting_value.hpp"
+#include "interfaces/commands/set_spending_limit.hpp"
 #include "interfaces/commands/subtract_asset_quantity.hpp"
 #include "interfaces/commands/transfer_asset.hpp"
+#include "interfaces/commands/vote_for_proposal.hpp"
 #include "interfaces/common_objects/string_view_types.hpp"
+#include "interfaces/transaction.hpp"
 #include "main/rdb_status.hpp"
 #include "main/subscription.hpp"
 
@@ -49,6 +78,493 @@ using shared_model::interface::permissions::Role;
 using shared_model::interface::GrantablePermissionSet;
 using shared_model::interface::RolePermissionSet;
 
+namespace {
+  /// Check the transfer of TransferAsset or LockAsset command against the
+  /// policy of the asset, if it is set
+  template <typename Command>
+  RocksDbCommandExecutor::ExecutionResult checkTransferPolicy(
+      RocksDbCommon &common, const Command &command) {
+    RDB_TRY_GET_VALUE(opt_value,
+                      forSettings<kDbOperation::kGet, kDbEntry::kCanExist>(
+                          common, transferPolicyKey(command.assetId())));
+    if (not opt_value) {
+      return {};
+    }
+    auto policy = TransferPolicy::parse(*opt_value);
+    if (auto e = expected::resultToOptionalError(policy)) {
+      return makeError<void>(RocksDbCommandExecutor::kException, "{}", *e);
+    }
+    for (auto const &account_id :
+         {command.srcAccountId(), command.destAccountId()}) {
+      if (not policy.assumeValue().isWhitelisted(account_id)) {
+        return makeError<void>(RocksDbCommandExecutor::kTransferNotAllowed,
+                               "Transfer of {} is not allowed: {} is not "
+                               "whitelisted",
+                               command.assetId(),
+                               account_id);
+      }
+      if (auto const &kyc = policy.assumeValue().kyc) {
+        auto const &[account_name, domain_id] = staticSplitId<2>(account_id);
+        RDB_TRY_GET_VALUE(
+            opt_verified,
+            forAccountDetail<kDbOperation::kGet, kDbEntry::kCanExist>(
+                common, account_name, domain_id, kyc->writer, kyc->key));
+        if (not opt_verified or *opt_verified != kTransferPolicyKycVerified) {
+          return makeError<void>(RocksDbCommandExecutor::kTransferNotAllowed,
+                                 "Transfer of {} is not allowed: {} has not "
+                                 "passed KYC",
+                                 command.assetId(),
+                                 account_id);
+        }
+      }
+    }
+    return {};
+  }
+
+  /// Read the supply policy of the asset, if it is set
+  expected::Result<std::optional<SupplyPolicy>, DbError> getSupplyPolicy(
+      RocksDbCommon &common,
+      shared_model::interface::types::AssetIdType const &asset_id) {
+    RDB_TRY_GET_VALUE(opt_value,
+                      forSettings<kDbOperation::kGet, kDbEntry::kCanExist>(
+                          common, supplyPolicyKey(asset_id)));
+    if (not opt_value) {
+      return std::optional<SupplyPolicy>{};
+    }
+    auto policy = SupplyPolicy::parse(*opt_value);
+    if (auto e = expected::resultToOptionalError(policy)) {
+      return makeError<std::optional<SupplyPolicy>>(
+          RocksDbCommandExecutor::kException, "{}", *e);
+    }
+    return std::optional<SupplyPolicy>{std::move(policy.assumeValue())};
+  }
+
+  /**
+   * Apply the policy of the domain, if it is set, to the created account:
+   * grant the permissions of the policy to the permittees which exist
+   */
+  RocksDbCommandExecutor::ExecutionResult applyDomainPolicy(
+      RocksDbCommon &common,
+      shared_model::interface::types::AccountIdType const &account_id,
+      shared_model::interface::types::DomainIdType const &domain_id) {
+    RDB_TRY_GET_VALUE(opt_value,
+                      forSettings<kDbOperation::kGet, kDbEntry::kCanExist>(
+                          common, domainPolicyKey(domain_id)));
+    if (not opt_value)
+      return {};
+    auto policy = DomainPolicy::parse(*opt_value);
+    if (auto e = expected::resultToOptionalError(policy))
+      return makeError<void>(RocksDbCommandExecutor::kException, "{}", *e);
+
+    for (auto const &[permittee, permissions] : policy.assumeValue().grants) {
+      auto const &[permittee_name, permittee_domain] =
+          staticSplitId<2>(permittee);
+      RDB_TRY_GET_VALUE(
+          opt_permittee,
+          forAccount<kDbOperation::kGet, kDbEntry::kCanExist>(
+              common, permittee_name, permittee_domain));
+      if (not opt_permittee)
+        continue;
+
+      common.valueBuffer().assign(permissions.toBitstring());
+      RDB_ERROR_CHECK(
+          forGrantablePermissions<kDbOperation::kPut, kDbEntry::kMustExist>(
+              common, permittee_name, permittee_domain, account_id));
+    }
+    return {};
+  }
+
+  /**
+   * Change the total supply of the asset and add the amount to the total
+   * minted or burned quantity
+   * @param amount issued or burned quantity
+   * @param issued whether the supply is increased
+   */
+  RocksDbCommandExecutor::ExecutionResult updateAssetSupply(
+      RocksDbCommon &common,
+      shared_model::interface::types::AssetIdType const &asset_id,
+      shared_model::interface::Amount const &amount,
+      bool issued) {
+    auto const &[asset_name, domain_id] = staticSplitId<2>(asset_id);
+    RDB_TRY_GET_VALUE(precision,
+                      forAsset<kDbOperation::kGet, kDbEntry::kMustExist>(
+                          common, asset_name, domain_id));
+    RDB_TRY_GET_VALUE(supply, getAssetSupply(common, asset_id, *precision));
+
+    if (issued) {
+      auto issued_supply = supply.checkedAdd(amount);
+      if (not issued_supply)
+        return makeError<void>(RocksDbCommandExecutor::kInvalidAssetAmount,
+                               "Invalid supply of asset {}",
+                               asset_id);
+      supply = std::move(*issued_supply);
+    } else {
+      supply = supply.saturatingSub(amount);
+    }
+
+    common.valueBuffer().assign(supply.toStringRepr());
+    RDB_ERROR_CHECK(forAssetSupply<kDbOperation::kPut>(common, asset_id));
+
+    RDB_TRY_GET_VALUE(
+        opt_total,
+        issued ? forAssetMinted<kDbOperation::kGet, kDbEntry::kCanExist>(
+                     common, asset_id)
+               : forAssetBurned<kDbOperation::kGet, kDbEntry::kCanExist>(
+                     common, asset_id));
+    shared_model::interface::Amount total(*precision);
+    if (opt_total)
+      total = std::move(*opt_total);
+
+    total += amount;
+    if (total.toStringRepr()[0] == 'N')
+      return makeError<void>(RocksDbCommandExecutor::kInvalidAssetAmount,
+                             "Invalid total of asset {}",
+                             asset_id);
+
+    common.valueBuffer().assign(total.toStringRepr());
+    if (issued) {
+      RDB_ERROR_CHECK(forAssetMinted<kDbOperation::kPut>(common, asset_id));
+    } else {
+      RDB_ERROR_CHECK(forAssetBurned<kDbOperation::kPut>(common, asset_id));
+    }
+    return {};
+  }
+
+  /**
+   * Update the number of accounts holding the asset after a balance change
+   * @param was_held whether the balance was positive before the change
+   * @param balance the balance after the change
+   */
+  RocksDbCommandExecutor::ExecutionResult updateAssetHolders(
+      RocksDbCommon &common,
+      std::string_view asset_id,
+      bool was_held,
+      shared_model::interface::Amount const &balance) {
+    bool const is_held = balance.sign() > 0;
+    if (was_held == is_held)
+      return {};
+
+    RDB_TRY_GET_VALUE(
+        opt_count,
+        forAssetHoldersCount<kDbOperation::kGet, kDbEntry::kCanExist>(
+            common, asset_id));
+    uint64_t count = opt_count ? *opt_count : 0ull;
+    if (is_held)
+      ++count;
+    else if (count > 0ull)
+      --count;
+
+    common.encode(count);
+    RDB_ERROR_CHECK(
+        forAssetHoldersCount<kDbOperation::kPut>(common, asset_id));
+    return {};
+  }
+
+  /**
+   * Check that the balance of the account left after spending does not go
+   * below the amount of the asset locked by the vesting schedule, if it is set
+   * @param balance the balance after spending
+   * @param error_code code of the command error if the balance is too low
+   */
+  RocksDbCommandExecutor::ExecutionResult checkVestingSchedule(
+      RocksDbCommon &common,
+      std::string_view account_name,
+      std::string_view domain_id,
+      std::string_view asset_id,
+      shared_model::interface::Amount const &balance,
+      uint32_t error_code) {
+    RDB_TRY_GET_VALUE(
+        opt_schedule,
+        forAccountVesting<kDbOperation::kGet, kDbEntry::kCanExist>(
+            common, account_name, domain_id, asset_id));
+    if (not opt_schedule)
+      return {};
+
+    auto schedule = VestingSchedule::decode(*opt_schedule);
+    if (auto e = expected::resultToOptionalError(schedule))
+      return makeError<void>(RocksDbCommandExecutor::kException, "{}", *e);
+
+    RDB_TRY_GET_VALUE(top_height, forTopBlockHeight(common));
+    auto const locked = schedule.assumeValue().locked(top_height + 1);
+    auto rest = balance;
+    rest -= locked;
+    if (rest.toStringRepr()[0] == 'N')
+      return makeError<void>(error_code,
+                             "Not enough assets: {} of {} is locked until "
+                             "vested",
+                             locked.toStringRepr(),
+                             asset_id);
+    return {};
+  }
+
+  /**
+   * Record the amount spent by the account if its spending limit of the
+   * asset is set
+   * @param do_validation whether the limit is checked before recording
+   */
+  RocksDbCommandExecutor::ExecutionResult spendWithinLimit(
+      RocksDbCommon &common,
+      std::string_view account_name,
+      std::string_view domain_id,
+      std::string_view asset_id,
+      shared_model::interface::Amount const &amount,
+      bool do_validation) {
+    RDB_TRY_GET_VALUE(
+        opt_limit,
+        forAccountSpendingLimit<kDbOperation::kGet, kDbEntry::kCanExist>(
+            common, account_name, domain_id, asset_id));
+    if (not opt_limit)
+      return {};
+
+    auto limit = SpendingLimit::decode(*opt_limit);
+    if (auto e = expected::resultToOptionalError(limit))
+      return makeError<void>(RocksDbCommandExecutor::kException, "{}", *e);
+
+    RDB_TRY_GET_VALUE(top_height, forTopBlockHeight(common));
+    auto const height = top_height + 1;
+    if (do_validation and not limit.assumeValue().allows(height, amount))
+      return makeError<void>(
+          RocksDbCommandExecutor::kSpendingLimitExceeded,
+          "Spending limit of {} exceeded: {} of {} is spent within {} blocks",
+          asset_id,
+          limit.assumeValue().spent(height).toStringRepr(),
+          limit.assumeValue().amount.toStringRepr(),
+          limit.assumeValue().window);
+
+    limit.assumeValue().spend(height, amount);
+    common.valueBuffer().assign(limit.assumeValue().encode());
+    RDB_ERROR_CHECK(forAccountSpendingLimit<kDbOperation::kPut>(
+        common, account_name, domain_id, asset_id));
+    return {};
+  }
+
+  /// Read the account detail limit setting, if it is set
+  expected::Result<std::optional<uint64_t>, DbError> getAccountDetailLimit(
+      RocksDbCommon &common,
+      shared_model::interface::types::SettingKeyType const &key) {
+    RDB_TRY_GET_VALUE(
+        opt_value,
+        forSettings<kDbOperation::kGet, kDbEntry::kCanExist>(common, key));
+    if (not opt_value) {
+      return std::optional<uint64_t>{};
+    }
+    auto limit = parseAccountDetailLimit(*opt_value);
+    if (auto e = expected::resultToOptionalError(limit)) {
+      return makeError<std::optional<uint64_t>>(
+          RocksDbCommandExecutor::kException, "{}", *e);
+    }
+    return std::optional<uint64_t>{limit.assumeValue()};
+  }
+
+  /// Check the new value of the account detail against the limits, if they
+  /// are set
+  RocksDbCommandExecutor::ExecutionResult checkAccountDetailLimits(
+      RocksDbCommon &common,
+      std::string_view account_name,
+      std::string_view domain_id,
+      std::string_view writer,
+      std::string_view key,
+      std::string_view value) {
+    RDB_TRY_GET_VALUE(
+        max_value_size,
+        getAccountDetailLimit(common, kMaxAccountDetailValueSizeKey));
+    if (max_value_size and value.size() > *max_value_size) {
+      return makeError<void>(RocksDbCommandExecutor::kAccountDetailTooBig,
+                             "Value of account detail {} is {} bytes, the "
+                             "limit is {}",
+                             key,
+                             value.size(),
+                             *max_value_size);
+    }
+
+    RDB_TRY_GET_VALUE(max_keys,
+                      getAccountDetailLimit(common, kMaxAccountDetailKeysKey));
+    if (not max_keys) {
+      return {};
+    }
+    RDB_TRY_GET_VALUE(
+        opt_detail,
+        forAccountDetail<kDbOperation::kGet, kDbEntry::kCanExist>(
+            common, account_name, domain_id, writer, key));
+    if (opt_detail) {
+      return {};
+    }
+    RDB_TRY_GET_VALUE(
+        opt_count,
+        forAccountDetailsCount<kDbOperation::kGet, kDbEntry::kCanExist>(
+            common, account_name, domain_id));
+    uint64_t const count = opt_count ? *opt_count : 0ull;
+    if (count >= *max_keys) {
+      return makeError<void>(RocksDbCommandExecutor::kTooManyAccountDetails,
+                             "Account {}@{} has {} details, the limit is {}",
+                             account_name,
+                             domain_id,
+                             count,
+                             *max_keys);
+    }
+    return {};
+  }
+
+  /**
+   * Release the asset lock: transfer the assets to the recipient before the
+   * timeout or return them to the source account after it
+   * @param common @see RocksDbCommon
+   * @param hash lowercase hex of the lock hash
+   * @param claim whether the lock is claimed by the recipient
+   */
+  /// Accounts and assets of archived domains are read only, database errors
+  /// of the check are returned as is
+  RocksDbCommandExecutor::ExecutionResult checkDomainNotArchived(
+      RocksDbCommon &common, std::string_view domain_id) {
+    auto result =
+        forDomainArchived<kDbOperation::kCheck, kDbEntry::kMustNotExist>(
+            common, domain_id);
+    if (expected::hasError(result)) {
+      if (result.assumeError().code != DbErrorCode::kMustNotExist)
+        return result.assumeError();
+      return makeError<void>(RocksDbCommandExecutor::kWritesArchivedDomain,
+                             "Domain {} is archived.",
+                             domain_id);
+    }
+    return {};
+  }
+
+  RocksDbCommandExecutor::ExecutionResult releaseAssetLock(
+      RocksDbCommon &common, std::string_view hash, bool claim) {
+    RDB_TRY_GET_VALUE(
+        opt_lock,
+        forAssetLock<kDbOperation::kGet, kDbEntry::kCanExist>(common, hash));
+    if (not opt_lock)
+      return makeError<void>(RocksDbCommandExecutor::kNoAssetLock,
+                             "Asset lock {} does not exist.",
+                             hash);
+
+    std::string const lock{*opt_lock};
+    auto const &[src_account_id, dest_account_id, asset_id, amount, timeout] =
+        staticSplitId<5>(lock, "|");
+    uint64_t timeout_height;
+    auto [ptr, ec]{std::from_chars(
+        timeout.data(), timeout.data() + timeout.size(), timeout_height)};
+    if (ec != std::errc())
+      return makeError<void>(RocksDbCommandExecutor::kException,
+                             "Malformed asset lock {}.",
+                             hash);
+
+    RDB_TRY_GET_VALUE(top_height, forTopBlockHeight(common));
+    if (claim and top_height + 1 > timeout_height)
+      return makeError<void>(RocksDbCommandExecutor::kAssetLockTimedOut,
+                             "Asset lock {} timed out at height {}.",
+                             hash,
+                             timeout_height);
+    if (not claim and top_height + 1 <= timeout_height)
+      return makeError<void>(RocksDbCommandExecutor::kAssetLockNotTimedOut,
+                             "Asset lock {} times out at height {}.",
+                             hash,
+                             timeout_height);
+
+    auto const &[account_name, account_domain_id] =
+        staticSplitId<2>(claim ? dest_account_id : src_account_id);
+    auto const &[asset_name, domain_id] = staticSplitId<2>(asset_id);
+
+    RDB_ERROR_CHECK(checkDomainNotArchived(common, account_domain_id));
+
+    RDB_TRY_GET_VALUE(precision,
+                      forAsset<kDbOperation::kGet, kDbEntry::kMustExist>(
+                          common, asset_name, domain_id));
+    shared_model::interface::Amount balance(*precision);
+
+    RDB_TRY_GET_VALUE(
+        opt_account_asset_size,
+        forAccountAssetSize<kDbOperation::kGet, kDbEntry::kCanExist>(
+            common, account_name, account_domain_id));
+    uint64_t account_asset_size =
+        opt_account_asset_size ? *opt_account_asset_size : 0ull;
+
+    RDB_TRY_GET_VALUE(opt_balance,
+                      forAccountAsset<kDbOperation::kGet, kDbEntry::kCanExist>(
+                          common, account_name, account_domain_id, asset_id));
+    if (opt_balance)
+      balance = *opt_balance;
+    else
+      ++account_asset_size;
+    bool const was_held = balance.sign() > 0;
+
+    balance += shared_model::interface::Amount(amount);
+    if (balance.toStringRepr()[0] == 'N')
+      return makeError<void>(RocksDbCommandExecutor::kIncorrectBalance,
+                             "Incorrect balance");
+
+    common.valueBuffer().assign(balance.toStringRepr());
+    RDB_ERROR_CHECK(forAccountAsset<kDbOperation::kPut>(
+        common, account_name, account_domain_id, asset_id));
+
+    common.encode(account_asset_size);
+    RDB_ERROR_CHECK(forAccountAssetSize<kDbOperation::kPut>(
+        common, account_name, account_domain_id));
+
+    RDB_ERROR_CHECK(updateAssetHolders(common, asset_id, was_held, balance));
+
+    RDB_ERROR_CHECK(
+        forAssetLock<kDbOperation::kDel, kDbEntry::kCanExist>(common, hash));
+
+    // the parties are kept to index the release for both accounts
+    common.valueBuffer().assign(fmt::format(
+        "{}|{}|{}", src_account_id, dest_account_id, asset_id));
+    RDB_ERROR_CHECK(forAssetLockRelease<kDbOperation::kPut>(common, hash));
+
+    return {};
+  }
+
+  /// Read the governance policy, if it is set
+  expected::Result<std::optional<GovernancePolicy>, DbError>
+  getGovernancePolicy(RocksDbCommon &common) {
+    RDB_TRY_GET_VALUE(opt_value,
+                      forSettings<kDbOperation::kGet, kDbEntry::kCanExist>(
+                          common, kGovernancePolicyKey));
+    if (not opt_value) {
+      return std::optional<GovernancePolicy>{};
+    }
+    auto policy = GovernancePolicy::parse(*opt_value);
+    if (auto e = expected::resultToOptionalError(policy)) {
+      return makeError<std::optional<GovernancePolicy>>(
+          RocksDbCommandExecutor::kException, "{}", *e);
+    }
+    return std::optional<GovernancePolicy>{std::move(policy.assumeValue())};
+  }
+
+  /// Governance proposal as stored in the proposal file
+  struct ProposalState {
+    uint64_t expires_height;
+    bool enacted;
+    uint64_t votes;
+    std::string setting_key;
+
+    static std::optional<ProposalState> parse(std::string_view value) {
+      auto const &[expires, enacted, votes] = staticSplitId<3>(value, "|");
+      auto const key_pos = expires.size() + enacted.size() + votes.size() + 3;
+      if (key_pos > value.size())
+        return std::nullopt;
+      ProposalState state;
+      state.enacted = enacted == "1";
+      auto [expires_ptr, expires_ec]{
+          std::from_chars(expires.data(),
+                          expires.data() + expires.size(),
+                          state.expires_height)};
+      auto [votes_ptr, votes_ec]{std::from_chars(
+          votes.data(), votes.data() + votes.size(), state.votes)};
+      if (expires_ec != std::errc() or votes_ec != std::errc())
+        return std::nullopt;
+      state.setting_key = value.substr(key_pos);
+      return state;
+    }
+
+    std::string toString() const {
+      return fmt::format(
+          "{}|{}|{}|{}", expires_height, enacted ? 1 : 0, votes, setting_key);
+    }
+  };
+}  // namespace
+
 RocksDbCommandExecutor::RocksDbCommandExecutor(
     std::shared_ptr<RocksDBContext> db_context,
     std::shared_ptr<shared_model::interface::PermissionToString> perm_converter,
@@ -73,7 +589,11 @@ RocksDbCommandExecutor::RocksDbCommandExecutor(
                             common.propGetBlockCacheUsage(),
                             common.propGetCurSzAllMemTables(),
                             common.propGetNumSnapshots(),
-                            common.propGetTotalSSTFilesSize()});
+                            common.propGetTotalSSTFilesSize(),
+                            common.propGetLiveDataSize(
+                                RocksDBPort::ColumnFamilyType::kWsv),
+                            common.propGetLiveDataSize(
+                                RocksDBPort::ColumnFamilyType::kStore)});
         }
       },
       []() { return true; });
@@ -90,6 +610,107 @@ DatabaseTransaction &RocksDbCommandExecutor::dbSession() {
   return db_transaction_;
 }
 
+CommandResult RocksDbCommandExecutor::recordIdempotencyKey(
+    const shared_model::interface::Transaction &transaction,
+    bool do_validation) {
+  RocksDbCommon common(db_context_);
+  auto const &key = transaction.idempotencyKey();
+  auto const created_time = transaction.createdTime();
+  auto const hash = transaction.hash().hex();
+  auto const &[account_name, domain_id] =
+      staticSplitId<2ull>(transaction.creatorAccountId());
+
+  auto result = [&]() -> ExecutionResult {
+    RDB_TRY_GET_VALUE(
+        opt_window,
+        forSettings<kDbOperation::kGet, kDbEntry::kCanExist>(
+            common, kIdempotencyWindowKey));
+    auto const window = parseIdempotencyWindow(opt_window);
+    if (window == 0) {
+      return {};
+    }
+
+    if (do_validation) {
+      RDB_TRY_GET_VALUE(
+          opt_record,
+          forIdempotencyKey<kDbOperation::kGet, kDbEntry::kCanExist>(
+              common, account_name, domain_id, key));
+      if (opt_record) {
+        auto record = IdempotencyRecord::decode(*opt_record);
+        if (record and record->tx_hash != hash
+            and not record->expired(created_time, window)) {
+          return makeError<void>(ErrorCodes::kIdempotencyKeyUsed,
+                                 "Duplicate of transaction {} with the same "
+                                 "idempotency key",
+                                 record->tx_hash);
+        }
+      }
+    }
+
+    std::vector<std::string> expired;
+    auto status = enumerateKeysAndValues(
+        common,
+        [&](auto stored_key, auto value) {
+          auto record = IdempotencyRecord::decode(value.ToStringView());
+          if (not record or record->expired(created_time, window)) {
+            expired.emplace_back(stored_key.ToStringView());
+          }
+          return true;
+        },
+        RocksDBPort::ColumnFamilyType::kWsv,
+        fmtstrings::kPathIdempotencyKeys,
+        domain_id,
+        account_name);
+    RDB_ERROR_CHECK(canExist(status, [&]() {
+      return fmt::format("Enumerate idempotency keys of {}",
+                         transaction.creatorAccountId());
+    }));
+    for (auto const &expired_key : expired) {
+      RDB_ERROR_CHECK(
+          forIdempotencyKey<kDbOperation::kDel, kDbEntry::kCanExist>(
+              common, account_name, domain_id, expired_key));
+    }
+
+    common.valueBuffer() = IdempotencyRecord{hash, created_time}.encode();
+    RDB_ERROR_CHECK(forIdempotencyKey<kDbOperation::kPut>(
+        common, account_name, domain_id, key));
+    return {};
+  }();
+
+  if (expected::hasError(result)) {
+    return expected::makeError(CommandError{"IdempotencyKey",
+                                            result.assumeError().code,
+                                            result.assumeError().description});
+  }
+  return {};
+}
+
+CommandResult RocksDbCommandExecutor::checkChainId(
+    const shared_model::interface::Transaction &transaction) {
+  RocksDbCommon common(db_context_);
+  auto result = [&]() -> ExecutionResult {
+    RDB_TRY_GET_VALUE(opt_chain_id,
+                      forSettings<kDbOperation::kGet, kDbEntry::kCanExist>(
+                          common, kChainIdKey));
+    auto const chain_id = opt_chain_id.value_or(std::string_view{});
+    if (transaction.chainId() != chain_id) {
+      return makeError<void>(ErrorCodes::kChainIdMismatch,
+                             "Transaction chain id '{}' does not match the "
+                             "chain id '{}' of the ledger",
+                             transaction.chainId(),
+                             chain_id);
+    }
+    return {};
+  }();
+
+  if (expected::hasError(result)) {
+    return expected::makeError(CommandError{"ChainId",
+                                            result.assumeError().code,
+                                            result.assumeError().description});
+  }
+  return {};
+}
+
 std::shared_ptr<RocksDBContext> RocksDbCommandExecutor::getSession() {
   return db_context_;
 }
@@ -101,7 +722,7 @@ CommandResult RocksDbCommandExecutor::execute(
     shared_model::interface::types::CommandIndexType cmd_index,
     bool do_validation) {
   return boost::apply_visitor(
-      [this, &creator_account_id, &tx_hash, cmd_index, do_validation](
+      [this, &cmd, &creator_account_id, &tx_hash, cmd_index, do_validation](
           const auto &command) -> CommandResult {
         // TODO(iceseer): remove try-catch when commands will be implemented
         try {
@@ -123,6 +744,17 @@ CommandResult RocksDbCommandExecutor::execute(
               creator_permissions = result.assumeValue();
           }
 
+          for (auto domain_id : getWrittenDomains(cmd)) {
+            if (auto result = checkDomainNotArchived(common, domain_id);
+                expected::hasError(result))
+              return expected::makeError(
+                  CommandError{command.toString(),
+                               result.assumeError().code,
+                               fmt::format("Command: {}. {}",
+                                           command.toString(),
+                                           result.assumeError().description)});
+          }
+
           if (auto result = (*this)(common,
                                     command,
                                     creator_account_id,
@@ -191,15 +823,37 @@ RocksDbCommandExecutor::ExecutionResult RocksDbCommandExecutor::operator()(
     else
       result = std::move(*balance);
   }
+  bool const was_held = result.sign() > 0;
 
   result += amount;
-  common.valueBuffer().assign(result.toStringRepr());
-  if (common.valueBuffer()[0] == 'N')
+  if (result.toStringRepr()[0] == 'N')
     return makeError<void>(ErrorCodes::kInvalidAssetAmount,
                            "Invalid asset {} amount {}",
                            command.assetId(),
                            result.toString());
 
+  if (do_validation) {
+    RDB_TRY_GET_VALUE(policy, getSupplyPolicy(common, command.assetId()));
+    if (policy and policy->decreasing_only)
+      return makeError<void>(ErrorCodes::kSupplyNotAllowed,
+                             "Supply of {} can only decrease",
+                             command.assetId());
+    if (policy and policy->max_supply) {
+      RDB_TRY_GET_VALUE(
+          supply, getAssetSupply(common, command.assetId(), *asset_amount));
+      supply += amount;
+      if (not policy->allowsSupply(supply))
+        return makeError<void>(ErrorCodes::kSupplyNotAllowed,
+                               "Supply of {} would exceed the cap of {}",
+                               command.assetId(),
+                               policy->max_supply->toStringRepr());
+    }
+  }
+
+  RDB_ERROR_CHECK(
+      updateAssetSupply(common, command.assetId(), amount, true));
+
+  common.valueBuffer().assign(result.toStringRepr());
   RDB_ERROR_CHECK(forAccountAsset<kDbOperation::kPut>(
       common, creator_account_name, creator_domain_id, command.assetId()));
 
@@ -207,6 +861,9 @@ RocksDbCommandExecutor::ExecutionResult RocksDbCommandExecutor::operator()(
   RDB_ERROR_CHECK(forAccountAssetSize<kDbOperation::kPut>(
       common, creator_account_name, creator_domain_id));
 
+  RDB_ERROR_CHECK(
+      updateAssetHolders(common, command.assetId(), was_held, result));
+
   return {};
 }
 
@@ -342,6 +999,108 @@ RocksDbCommandExecutor::ExecutionResult RocksDbCommandExecutor::operator()(
   return {};
 }
 
+RocksDbCommandExecutor::ExecutionResult RocksDbCommandExecutor::operator()(
+    RocksDbCommon &common,
+    const shared_model::interface::ArchiveDomain &command,
+    const shared_model::interface::types::AccountIdType &creator_account_id,
+    const std::string &tx_hash,
+    shared_model::interface::types::CommandIndexType /*cmd_index*/,
+    bool do_validation,
+    shared_model::interface::RolePermissionSet const &creator_permissions) {
+  auto const &domain_id = command.domainId();
+
+  if (do_validation)
+    RDB_ERROR_CHECK(
+        checkPermissions(creator_permissions, {Role::kArchiveDomain}));
+
+  RDB_ERROR_CHECK(
+      forDomain<kDbOperation::kCheck, kDbEntry::kMustExist>(common, domain_id));
+
+  RDB_ERROR_CHECK(
+      forDomainArchived<kDbOperation::kCheck, kDbEntry::kMustNotExist>(
+          common, domain_id));
+
+  common.valueBuffer().clear();
+  RDB_ERROR_CHECK(forDomainArchived<kDbOperation::kPut>(common, domain_id));
+
+  return {};
+}
+
+RocksDbCommandExecutor::ExecutionResult RocksDbCommandExecutor::operator()(
+    RocksDbCommon &common,
+    const shared_model::interface::AuthorizeSessionKey &command,
+    const shared_model::interface::types::AccountIdType &creator_account_id,
+    const std::string &tx_hash,
+    shared_model::interface::types::CommandIndexType /*cmd_index*/,
+    bool do_validation,
+    shared_model::interface::RolePermissionSet const &creator_permissions) {
+  auto const &[creator_account_name, creator_domain_id] =
+      staticSplitId<2>(creator_account_id);
+  auto const &[account_name, domain_id] = staticSplitId<2>(command.accountId());
+
+  RDB_ERROR_CHECK(forAccount<kDbOperation::kCheck, kDbEntry::kMustExist>(
+      common, account_name, domain_id));
+
+  if (do_validation) {
+    GrantablePermissionSet granted_account_permissions;
+    RDB_TRY_GET_VALUE(
+        opt_permissions,
+        forGrantablePermissions<kDbOperation::kGet, kDbEntry::kCanExist>(
+            common,
+            creator_account_name,
+            creator_domain_id,
+            command.accountId()));
+    if (opt_permissions)
+      granted_account_permissions = *opt_permissions;
+
+    if (creator_account_id == command.accountId()) {
+      RDB_ERROR_CHECK(
+          checkPermissions(creator_permissions, {Role::kAddSignatory}));
+    } else {
+      RDB_ERROR_CHECK(checkGrantablePermissions(creator_permissions,
+                                                granted_account_permissions,
+                                                Grantable::kAddMySignatory));
+    }
+  }
+
+  std::string pk;
+  toLowerAppend(command.pubkey(), pk);
+
+  if (command.validity() == 0) {
+    RDB_ERROR_CHECK(forSessionKey<kDbOperation::kDel, kDbEntry::kCanExist>(
+        common, account_name, domain_id, pk));
+    return {};
+  }
+
+  if (not command.allowedAssetId().empty()) {
+    auto const &[asset_name, asset_domain_id] =
+        staticSplitId<2>(command.allowedAssetId());
+    if (auto result = forAsset<kDbOperation::kCheck, kDbEntry::kMustExist>(
+            common, asset_name, asset_domain_id);
+        expected::hasError(result))
+      return makeError<void>(ErrorCodes::kNoAsset,
+                             std::move(result.assumeError()));
+  }
+
+  if (auto result = forSignatory<kDbOperation::kCheck, kDbEntry::kMustNotExist>(
+          common, account_name, domain_id, pk);
+      expected::hasError(result))
+    return makeError<void>(ErrorCodes::kNewSignatoryExists,
+                           "Signatory {} of account {} already exists.",
+                           pk,
+                           command.accountId());
+
+  RDB_TRY_GET_VALUE(top_height, forTopBlockHeight(common));
+  SessionKey const key{command.permissions(),
+                       command.allowedAssetId(),
+                       top_height + 1 + command.validity()};
+  common.valueBuffer().assign(key.encode());
+  RDB_ERROR_CHECK(
+      forSessionKey<kDbOperation::kPut>(common, account_name, domain_id, pk));
+
+  return {};
+}
+
 RocksDbCommandExecutor::ExecutionResult RocksDbCommandExecutor::operator()(
     RocksDbCommon &common,
     const shared_model::interface::CallEngine &command,
@@ -464,6 +1223,14 @@ RocksDbCommandExecutor::ExecutionResult RocksDbCommandExecutor::operator()(
   RDB_ERROR_CHECK(forAccount<kDbOperation::kCheck, kDbEntry::kMustExist>(
       common, account_name, domain_id));
 
+  if (do_validation)
+    RDB_ERROR_CHECK(checkAccountDetailLimits(common,
+                                             account_name,
+                                             domain_id,
+                                             creator_id,
+                                             command.key(),
+                                             command.value()));
+
   RDB_TRY_GET_VALUE(
       opt_detail,
       forAccountDetail<kDbOperation::kGet, kDbEntry::kCanExist>(
@@ -538,6 +1305,13 @@ RocksDbCommandExecutor::ExecutionResult RocksDbCommandExecutor::operator()(
     return makeError<void>(ErrorCodes::kNoPermissions,
                            "Insufficient permissions");
 
+  if (auto result =
+          forDomainArchived<kDbOperation::kCheck, kDbEntry::kMustNotExist>(
+              common, domain_id);
+      expected::hasError(result))
+    return makeError<void>(
+        ErrorCodes::kDomainArchived, "Domain {} is archived.", domain_id);
+
   common.valueBuffer() = "";
   RDB_ERROR_CHECK(forAccountRole<kDbOperation::kPut>(
       common, account_name, domain_id, default_role));
@@ -558,6 +1332,9 @@ RocksDbCommandExecutor::ExecutionResult RocksDbCommandExecutor::operator()(
   RDB_ERROR_CHECK(
       forQuorum<kDbOperation::kPut>(common, account_name, domain_id));
 
+  RDB_ERROR_CHECK(
+      applyDomainPolicy(common, account_name + "@" + domain_id, domain_id));
+
   return {};
 }
 
@@ -585,6 +1362,13 @@ RocksDbCommandExecutor::ExecutionResult RocksDbCommandExecutor::operator()(
         common, domain_id));
   }
 
+  if (auto result =
+          forDomainArchived<kDbOperation::kCheck, kDbEntry::kMustNotExist>(
+              common, domain_id);
+      expected::hasError(result))
+    return makeError<void>(
+        ErrorCodes::kDomainArchived, "Domain {} is archived.", domain_id);
+
   common.encode(command.precision());
   RDB_ERROR_CHECK(forAsset<kDbOperation::kPut>(common, asset_name, domain_id));
 
@@ -666,34 +1450,81 @@ RocksDbCommandExecutor::ExecutionResult RocksDbCommandExecutor::operator()(
 
 RocksDbCommandExecutor::ExecutionResult RocksDbCommandExecutor::operator()(
     RocksDbCommon &common,
-    const shared_model::interface::DetachRole &command,
+    const shared_model::interface::DeactivateAccount &command,
     const shared_model::interface::types::AccountIdType &creator_account_id,
     const std::string &tx_hash,
     shared_model::interface::types::CommandIndexType /*cmd_index*/,
     bool do_validation,
     shared_model::interface::RolePermissionSet const &creator_permissions) {
+  auto const &[creator_account_name, creator_domain_id] =
+      staticSplitId<2>(creator_account_id);
   auto const &[account_name, domain_id] = staticSplitId<2>(command.accountId());
-  auto const &role_name = command.roleName();
 
-  if (do_validation)
-    RDB_ERROR_CHECK(checkPermissions(creator_permissions, {Role::kDetachRole}));
+  RDB_ERROR_CHECK(forAccount<kDbOperation::kCheck, kDbEntry::kMustExist>(
+      common, account_name, domain_id));
 
-  RDB_ERROR_CHECK(
-      forRole<kDbOperation::kCheck, kDbEntry::kMustExist>(common, role_name));
+  if (do_validation and creator_account_id != command.accountId()) {
+    GrantablePermissionSet granted_account_permissions;
+    RDB_TRY_GET_VALUE(
+        opt_permissions,
+        forGrantablePermissions<kDbOperation::kGet, kDbEntry::kCanExist>(
+            common,
+            creator_account_name,
+            creator_domain_id,
+            command.accountId()));
+    if (opt_permissions)
+      granted_account_permissions = *opt_permissions;
 
-  if (do_validation)
-    RDB_ERROR_CHECK(forAccountRole<kDbOperation::kCheck, kDbEntry::kMustExist>(
-        common, account_name, domain_id, role_name));
+    RDB_ERROR_CHECK(checkGrantablePermissions(creator_permissions,
+                                              granted_account_permissions,
+                                              Grantable::kRecoverMyAccount));
+  }
 
-  RDB_ERROR_CHECK(forAccountRole<kDbOperation::kDel, kDbEntry::kCanExist>(
-      common, account_name, domain_id, role_name));
+  if (auto result =
+          forAccountDeactivated<kDbOperation::kCheck, kDbEntry::kMustNotExist>(
+              common, account_name, domain_id);
+      expected::hasError(result))
+    return makeError<void>(ErrorCodes::kAccountDeactivated,
+                           "Account {} is already deactivated.",
+                           command.accountId());
+
+  common.valueBuffer().clear();
+  RDB_ERROR_CHECK(forAccountDeactivated<kDbOperation::kPut>(
+      common, account_name, domain_id));
 
   return {};
 }
 
 RocksDbCommandExecutor::ExecutionResult RocksDbCommandExecutor::operator()(
     RocksDbCommon &common,
-    const shared_model::interface::GrantPermission &command,
+    const shared_model::interface::DetachRole &command,
+    const shared_model::interface::types::AccountIdType &creator_account_id,
+    const std::string &tx_hash,
+    shared_model::interface::types::CommandIndexType /*cmd_index*/,
+    bool do_validation,
+    shared_model::interface::RolePermissionSet const &creator_permissions) {
+  auto const &[account_name, domain_id] = staticSplitId<2>(command.accountId());
+  auto const &role_name = command.roleName();
+
+  if (do_validation)
+    RDB_ERROR_CHECK(checkPermissions(creator_permissions, {Role::kDetachRole}));
+
+  RDB_ERROR_CHECK(
+      forRole<kDbOperation::kCheck, kDbEntry::kMustExist>(common, role_name));
+
+  if (do_validation)
+    RDB_ERROR_CHECK(forAccountRole<kDbOperation::kCheck, kDbEntry::kMustExist>(
+        common, account_name, domain_id, role_name));
+
+  RDB_ERROR_CHECK(forAccountRole<kDbOperation::kDel, kDbEntry::kCanExist>(
+      common, account_name, domain_id, role_name));
+
+  return {};
+}
+
+RocksDbCommandExecutor::ExecutionResult RocksDbCommandExecutor::operator()(
+    RocksDbCommon &common,
+    const shared_model::interface::GrantPermission &command,
     const shared_model::interface::types::AccountIdType &creator_account_id,
     const std::string &tx_hash,
     shared_model::interface::types::CommandIndexType /*cmd_index*/,
@@ -735,6 +1566,309 @@ RocksDbCommandExecutor::ExecutionResult RocksDbCommandExecutor::operator()(
   return {};
 }
 
+RocksDbCommandExecutor::ExecutionResult RocksDbCommandExecutor::operator()(
+    RocksDbCommon &common,
+    const shared_model::interface::GrantVestedAsset &command,
+    const shared_model::interface::types::AccountIdType &creator_account_id,
+    const std::string &tx_hash,
+    shared_model::interface::types::CommandIndexType /*cmd_index*/,
+    bool do_validation,
+    shared_model::interface::RolePermissionSet const &creator_permissions) {
+  auto const &[creator_account_name, creator_domain_id] =
+      staticSplitId<2>(creator_account_id);
+  auto const &[destination_account_name, destination_domain_id] =
+      staticSplitId<2>(command.destAccountId());
+  auto const &[asset_name, domain_id] = staticSplitId<2>(command.assetId());
+  auto const &amount = command.amount();
+
+  RDB_ERROR_CHECK(forAccount<kDbOperation::kCheck, kDbEntry::kMustExist>(
+      common, destination_account_name, destination_domain_id));
+
+  if (do_validation) {
+    RDB_ERROR_CHECK(checkPermissions(domain_id,
+                                     creator_domain_id,
+                                     creator_permissions,
+                                     Role::kAddAssetQty,
+                                     Role::kAddDomainAssetQty));
+
+    RDB_TRY_GET_VALUE(
+        destination_permissions,
+        accountPermissions(
+            common, destination_account_name, destination_domain_id));
+    if (!destination_permissions.isSet(Role::kReceive))
+      return makeError<void>(ErrorCodes::kNoPermissions,
+                             "Not enough permissions. {}",
+                             command.toString());
+  }
+
+  auto opt_precision = forAsset<kDbOperation::kGet, kDbEntry::kMustExist>(
+      common, asset_name, domain_id);
+  if (expected::hasError(opt_precision))
+    return makeError<void>(ErrorCodes::kNoAsset,
+                           std::move(opt_precision.assumeError()));
+  auto const precision = *opt_precision.assumeValue();
+
+  RDB_TRY_GET_VALUE(top_height, forTopBlockHeight(common));
+  auto const height = top_height + 1;
+
+  RDB_TRY_GET_VALUE(
+      opt_schedule,
+      forAccountVesting<kDbOperation::kGet, kDbEntry::kCanExist>(
+          common,
+          destination_account_name,
+          destination_domain_id,
+          command.assetId()));
+  if (opt_schedule) {
+    auto schedule = VestingSchedule::decode(*opt_schedule);
+    if (auto e = expected::resultToOptionalError(schedule))
+      return makeError<void>(ErrorCodes::kException, "{}", *e);
+    if (not schedule.assumeValue().isVested(height))
+      return makeError<void>(ErrorCodes::kVestingScheduleExists,
+                             "Vesting schedule of {} for {} is not vested yet",
+                             command.assetId(),
+                             command.destAccountId());
+  }
+
+  shared_model::interface::Amount result(precision);
+
+  RDB_TRY_GET_VALUE(
+      opt_account_asset_size,
+      forAccountAssetSize<kDbOperation::kGet, kDbEntry::kCanExist>(
+          common, destination_account_name, destination_domain_id));
+  uint64_t account_asset_size =
+      opt_account_asset_size ? *opt_account_asset_size : 0ull;
+
+  RDB_TRY_GET_VALUE(opt_balance,
+                    forAccountAsset<kDbOperation::kGet, kDbEntry::kCanExist>(
+                        common,
+                        destination_account_name,
+                        destination_domain_id,
+                        command.assetId()));
+  if (opt_balance)
+    result = std::move(*opt_balance);
+  else
+    ++account_asset_size;
+  bool const was_held = result.sign() > 0;
+
+  result += amount;
+  if (result.toStringRepr()[0] == 'N')
+    return makeError<void>(ErrorCodes::kIncorrectBalance,
+                           "Invalid asset {} amount {}",
+                           command.assetId(),
+                           result.toString());
+
+  if (do_validation) {
+    RDB_TRY_GET_VALUE(policy, getSupplyPolicy(common, command.assetId()));
+    if (policy and policy->decreasing_only)
+      return makeError<void>(ErrorCodes::kSupplyNotAllowed,
+                             "Supply of {} can only decrease",
+                             command.assetId());
+    if (policy and policy->max_supply) {
+      RDB_TRY_GET_VALUE(supply,
+                        getAssetSupply(common, command.assetId(), precision));
+      supply += amount;
+      if (not policy->allowsSupply(supply))
+        return makeError<void>(ErrorCodes::kSupplyNotAllowed,
+                               "Supply of {} would exceed the cap of {}",
+                               command.assetId(),
+                               policy->max_supply->toStringRepr());
+    }
+  }
+
+  RDB_ERROR_CHECK(
+      updateAssetSupply(common, command.assetId(), amount, true));
+
+  common.valueBuffer().assign(result.toStringRepr());
+  RDB_ERROR_CHECK(forAccountAsset<kDbOperation::kPut>(common,
+                                                      destination_account_name,
+                                                      destination_domain_id,
+                                                      command.assetId()));
+
+  common.encode(account_asset_size);
+  RDB_ERROR_CHECK(forAccountAssetSize<kDbOperation::kPut>(
+      common, destination_account_name, destination_domain_id));
+
+  RDB_ERROR_CHECK(
+      updateAssetHolders(common, command.assetId(), was_held, result));
+
+  common.valueBuffer().assign(
+      VestingSchedule{amount, height, command.cliff(), command.duration()}
+          .encode());
+  RDB_ERROR_CHECK(forAccountVesting<kDbOperation::kPut>(
+      common,
+      destination_account_name,
+      destination_domain_id,
+      command.assetId()));
+
+  return {};
+}
+
+RocksDbCommandExecutor::ExecutionResult RocksDbCommandExecutor::operator()(
+    RocksDbCommon &common,
+    const shared_model::interface::IncludeRole &command,
+    const shared_model::interface::types::AccountIdType &creator_account_id,
+    const std::string &tx_hash,
+    shared_model::interface::types::CommandIndexType /*cmd_index*/,
+    bool do_validation,
+    shared_model::interface::RolePermissionSet const &creator_permissions) {
+  auto const &role_name = command.roleName();
+  auto const &included_role_name = command.includedRoleName();
+
+  if (do_validation)
+    RDB_ERROR_CHECK(checkPermissions(creator_permissions, {Role::kCreateRole}));
+
+  RDB_ERROR_CHECK(
+      forRole<kDbOperation::kCheck, kDbEntry::kMustExist>(common, role_name));
+  RDB_TRY_GET_VALUE(opt_included_permissions,
+                    forRole<kDbOperation::kGet, kDbEntry::kMustExist>(
+                        common, included_role_name));
+  auto const included_permissions = *opt_included_permissions;
+
+  if (do_validation && !included_permissions.isSubsetOf(creator_permissions))
+    return makeError<void>(ErrorCodes::kNoPermissions,
+                           "Insufficient permissions");
+
+  RDB_ERROR_CHECK(forRoleIncludedBy<kDbOperation::kCheck,
+                                    kDbEntry::kMustNotExist>(
+      common, included_role_name, role_name));
+
+  // the role itself and all the roles which include it, directly or not
+  std::vector<std::string> including_roles{role_name};
+  for (size_t ix = 0; ix < including_roles.size(); ++ix) {
+    if (including_roles[ix] == included_role_name)
+      return makeError<void>(ErrorCodes::kRoleInclusionCycle,
+                             "Including role {} into role {} makes a cycle.",
+                             included_role_name,
+                             role_name);
+
+    std::vector<std::string> roles;
+    auto status = enumerateKeys(common,
+                                [&](auto role) {
+                                  roles.emplace_back(role.ToStringView());
+                                  return true;
+                                },
+                                RocksDBPort::ColumnFamilyType::kWsv,
+                                fmtstrings::kPathRoleIncludedBy,
+                                including_roles[ix]);
+    RDB_ERROR_CHECK(canExist(status, [&]() {
+      return fmt::format("Enumerate roles including {}", including_roles[ix]);
+    }));
+
+    for (auto &role : roles)
+      if (std::find(including_roles.begin(), including_roles.end(), role)
+          == including_roles.end())
+        including_roles.emplace_back(std::move(role));
+  }
+
+  for (auto const &role : including_roles) {
+    RDB_TRY_GET_VALUE(
+        opt_permissions,
+        forRole<kDbOperation::kGet, kDbEntry::kMustExist>(common, role));
+    auto permissions = *opt_permissions;
+    permissions |= included_permissions;
+    common.valueBuffer().assign(permissions.toBitstring());
+    RDB_ERROR_CHECK(forRole<kDbOperation::kPut>(common, role));
+  }
+
+  common.valueBuffer().clear();
+  RDB_ERROR_CHECK(forRoleIncludedBy<kDbOperation::kPut>(
+      common, included_role_name, role_name));
+
+  return {};
+}
+
+RocksDbCommandExecutor::ExecutionResult RocksDbCommandExecutor::operator()(
+    RocksDbCommon &common,
+    const shared_model::interface::RecoverAccount &command,
+    const shared_model::interface::types::AccountIdType &creator_account_id,
+    const std::string &tx_hash,
+    shared_model::interface::types::CommandIndexType /*cmd_index*/,
+    bool do_validation,
+    shared_model::interface::RolePermissionSet const &creator_permissions) {
+  auto const &[creator_account_name, creator_domain_id] =
+      staticSplitId<2>(creator_account_id);
+  auto const &[account_name, domain_id] = staticSplitId<2>(command.accountId());
+
+  RDB_ERROR_CHECK(forAccount<kDbOperation::kCheck, kDbEntry::kMustExist>(
+      common, account_name, domain_id));
+
+  if (do_validation) {
+    GrantablePermissionSet granted_account_permissions;
+    RDB_TRY_GET_VALUE(
+        opt_permissions,
+        forGrantablePermissions<kDbOperation::kGet, kDbEntry::kCanExist>(
+            common,
+            creator_account_name,
+            creator_domain_id,
+            command.accountId()));
+    if (opt_permissions)
+      granted_account_permissions = *opt_permissions;
+
+    RDB_ERROR_CHECK(checkGrantablePermissions(creator_permissions,
+                                              granted_account_permissions,
+                                              Grantable::kRecoverMyAccount));
+  }
+
+  std::vector<std::string> signatories;
+  auto const status =
+      enumerateKeys(common,
+                    [&](auto const &signatory) {
+                      signatories.emplace_back(signatory.ToStringView());
+                      return true;
+                    },
+                    RocksDBPort::ColumnFamilyType::kWsv,
+                    fmtstrings::kPathSignatories,
+                    domain_id,
+                    account_name);
+  RDB_ERROR_CHECK(canExist(status, [&]() {
+    return fmt::format("Enumerate signatories for account {}",
+                       command.accountId());
+  }));
+
+  for (auto const &signatory : signatories)
+    RDB_ERROR_CHECK(forSignatory<kDbOperation::kDel, kDbEntry::kCanExist>(
+        common, account_name, domain_id, signatory));
+
+  std::vector<std::string> retired_signatories;
+  auto const retired_status =
+      enumerateKeys(common,
+                    [&](auto const &signatory) {
+                      retired_signatories.emplace_back(
+                          signatory.ToStringView());
+                      return true;
+                    },
+                    RocksDBPort::ColumnFamilyType::kWsv,
+                    fmtstrings::kPathRetiredSignatories,
+                    domain_id,
+                    account_name);
+  RDB_ERROR_CHECK(canExist(retired_status, [&]() {
+    return fmt::format("Enumerate retired signatories for account {}",
+                       command.accountId());
+  }));
+
+  for (auto const &signatory : retired_signatories)
+    RDB_ERROR_CHECK(
+        forRetiredSignatory<kDbOperation::kDel, kDbEntry::kCanExist>(
+            common, account_name, domain_id, signatory));
+
+  std::string pk;
+  toLowerAppend(command.pubkey(), pk);
+
+  common.valueBuffer().clear();
+  RDB_ERROR_CHECK(
+      forSignatory<kDbOperation::kPut>(common, account_name, domain_id, pk));
+
+  common.encode(1);
+  RDB_ERROR_CHECK(
+      forQuorum<kDbOperation::kPut>(common, account_name, domain_id));
+
+  RDB_ERROR_CHECK(
+      forAccountDeactivated<kDbOperation::kDel, kDbEntry::kCanExist>(
+          common, account_name, domain_id));
+
+  return {};
+}
+
 RocksDbCommandExecutor::ExecutionResult RocksDbCommandExecutor::operator()(
     RocksDbCommon &common,
     const shared_model::interface::RemovePeer &command,
@@ -825,76 +1959,298 @@ RocksDbCommandExecutor::ExecutionResult RocksDbCommandExecutor::operator()(
     }
   }
 
-  std::string pk;
-  toLowerAppend(command.pubkey(), pk);
+  std::string pk;
+  toLowerAppend(command.pubkey(), pk);
+
+  if (auto result = forSignatory<kDbOperation::kCheck, kDbEntry::kMustExist>(
+          common, account_name, domain_id, pk);
+      expected::hasError(result))
+    return makeError<void>(ErrorCodes::kNoSignatory,
+                           std::move(result.assumeError()));
+
+  uint64_t counter = 0;
+  auto status = enumerateKeys(common,
+                              [&](auto key) {
+                                ++counter;
+                                return true;
+                              },
+                              RocksDBPort::ColumnFamilyType::kWsv,
+                              fmtstrings::kPathSignatories,
+                              domain_id,
+                              account_name);
+
+  if (counter <= quorum)
+    return makeError<void>(
+        ErrorCodes::kCountNotEnough,
+        "Remove signatory {} for account {} with quorum {} failed.",
+        pk,
+        command.accountId(),
+        quorum);
+
+  RDB_ERROR_CHECK(forSignatory<kDbOperation::kDel, kDbEntry::kCanExist>(
+      common, account_name, domain_id, pk));
+
+  return {};
+}
+
+RocksDbCommandExecutor::ExecutionResult RocksDbCommandExecutor::operator()(
+    RocksDbCommon &common,
+    const shared_model::interface::RevokePermission &command,
+    const shared_model::interface::types::AccountIdType &creator_account_id,
+    const std::string &tx_hash,
+    shared_model::interface::types::CommandIndexType /*cmd_index*/,
+    bool do_validation,
+    shared_model::interface::RolePermissionSet const &creator_permissions) {
+  auto const &[account_name, domain_id] = staticSplitId<2>(command.accountId());
+  auto const revoked_perm = command.permissionName();
+
+  if (do_validation) {
+    // check if account exists
+    RDB_ERROR_CHECK(forAccount<kDbOperation::kCheck, kDbEntry::kMustExist>(
+        common, account_name, domain_id));
+  }
+
+  GrantablePermissionSet granted_account_permissions;
+  RDB_TRY_GET_VALUE(
+      opt_permissions,
+      forGrantablePermissions<kDbOperation::kGet, kDbEntry::kCanExist>(
+          common, account_name, domain_id, creator_account_id));
+  if (opt_permissions)
+    granted_account_permissions = *opt_permissions;
+
+  // check if not granted
+  if (!granted_account_permissions.isSet(revoked_perm))
+    return makeError<void>(ErrorCodes::kNoPermissions, "Permission not set");
+
+  granted_account_permissions.unset(revoked_perm);
+  common.valueBuffer().assign(granted_account_permissions.toBitstring());
+  RDB_ERROR_CHECK(
+      forGrantablePermissions<kDbOperation::kPut, kDbEntry::kMustExist>(
+          common, account_name, domain_id, creator_account_id));
+
+  return {};
+}
+
+RocksDbCommandExecutor::ExecutionResult RocksDbCommandExecutor::operator()(
+    RocksDbCommon &common,
+    const shared_model::interface::RotateKey &command,
+    const shared_model::interface::types::AccountIdType &creator_account_id,
+    const std::string &tx_hash,
+    shared_model::interface::types::CommandIndexType /*cmd_index*/,
+    bool do_validation,
+    shared_model::interface::RolePermissionSet const &creator_permissions) {
+  auto const &[creator_account_name, creator_domain_id] =
+      staticSplitId<2>(creator_account_id);
+  auto const &[account_name, domain_id] = staticSplitId<2>(command.accountId());
+
+  RDB_ERROR_CHECK(forAccount<kDbOperation::kCheck, kDbEntry::kMustExist>(
+      common, account_name, domain_id));
+
+  if (do_validation) {
+    GrantablePermissionSet granted_account_permissions;
+    RDB_TRY_GET_VALUE(
+        opt_permissions,
+        forGrantablePermissions<kDbOperation::kGet, kDbEntry::kCanExist>(
+            common,
+            creator_account_name,
+            creator_domain_id,
+            command.accountId()));
+    if (opt_permissions)
+      granted_account_permissions = *opt_permissions;
+
+    if (creator_account_id == command.accountId()) {
+      RDB_ERROR_CHECK(
+          checkPermissions(creator_permissions, {Role::kAddSignatory}));
+      RDB_ERROR_CHECK(
+          checkPermissions(creator_permissions, {Role::kRemoveSignatory}));
+    } else {
+      RDB_ERROR_CHECK(checkGrantablePermissions(creator_permissions,
+                                                granted_account_permissions,
+                                                Grantable::kAddMySignatory));
+      RDB_ERROR_CHECK(checkGrantablePermissions(creator_permissions,
+                                                granted_account_permissions,
+                                                Grantable::kRemoveMySignatory));
+    }
+  }
+
+  std::string old_pk;
+  toLowerAppend(command.oldPubkey(), old_pk);
+  std::string new_pk;
+  toLowerAppend(command.newPubkey(), new_pk);
+
+  if (auto result = forSignatory<kDbOperation::kCheck, kDbEntry::kMustExist>(
+          common, account_name, domain_id, old_pk);
+      expected::hasError(result))
+    return makeError<void>(ErrorCodes::kNoSignatory,
+                           std::move(result.assumeError()));
+
+  if (auto result = forSignatory<kDbOperation::kCheck, kDbEntry::kMustNotExist>(
+          common, account_name, domain_id, new_pk);
+      expected::hasError(result))
+    return makeError<void>(ErrorCodes::kNewSignatoryExists,
+                           "Signatory {} of account {} already exists.",
+                           new_pk,
+                           command.accountId());
+
+  RDB_ERROR_CHECK(forSignatory<kDbOperation::kDel, kDbEntry::kCanExist>(
+      common, account_name, domain_id, old_pk));
+
+  common.valueBuffer().clear();
+  RDB_ERROR_CHECK(forSignatory<kDbOperation::kPut>(
+      common, account_name, domain_id, new_pk));
+
+  if (command.gracePeriod() > 0) {
+    RDB_TRY_GET_VALUE(top_height, forTopBlockHeight(common));
+    common.valueBuffer().assign(
+        fmt::format("{}|{}", top_height + 1 + command.gracePeriod(), new_pk));
+    RDB_ERROR_CHECK(forRetiredSignatory<kDbOperation::kPut>(
+        common, account_name, domain_id, old_pk));
+  }
+
+  return {};
+}
+
+RocksDbCommandExecutor::ExecutionResult RocksDbCommandExecutor::operator()(
+    RocksDbCommon &common,
+    const shared_model::interface::LockAsset &command,
+    const shared_model::interface::types::AccountIdType &creator_account_id,
+    const std::string &tx_hash,
+    shared_model::interface::types::CommandIndexType /*cmd_index*/,
+    bool do_validation,
+    shared_model::interface::RolePermissionSet const &creator_permissions) {
+  auto const &[creator_account_name, creator_domain_id] =
+      staticSplitId<2>(creator_account_id);
+  auto const &[source_account_name, source_domain_id] =
+      staticSplitId<2>(command.srcAccountId());
+  auto const &[destination_account_name, destination_domain_id] =
+      staticSplitId<2>(command.destAccountId());
+  auto const &[asset_name, domain_id] = staticSplitId<2>(command.assetId());
+  auto const &amount = command.amount();
+
+  RDB_ERROR_CHECK(forAccount<kDbOperation::kCheck, kDbEntry::kMustExist>(
+      common, source_account_name, source_domain_id));
+  RDB_ERROR_CHECK(forAccount<kDbOperation::kCheck, kDbEntry::kMustExist>(
+      common, destination_account_name, destination_domain_id));
+
+  if (do_validation) {
+    RDB_TRY_GET_VALUE(
+        destination_permissions,
+        accountPermissions(
+            common, destination_account_name, destination_domain_id));
+    if (!destination_permissions.isSet(Role::kReceive))
+      return makeError<void>(ErrorCodes::kNoPermissions,
+                             "Not enough permissions. {}",
+                             command.toString());
+
+    if (command.srcAccountId() != creator_account_id) {
+      GrantablePermissionSet granted_account_permissions;
+      RDB_TRY_GET_VALUE(
+          opt_permissions,
+          forGrantablePermissions<kDbOperation::kGet, kDbEntry::kCanExist>(
+              common,
+              creator_account_name,
+              creator_domain_id,
+              command.srcAccountId()));
+      if (opt_permissions)
+        granted_account_permissions = *opt_permissions;
+
+      RDB_ERROR_CHECK(checkGrantablePermissions(creator_permissions,
+                                                granted_account_permissions,
+                                                Grantable::kTransferMyAssets));
+    } else
+      RDB_ERROR_CHECK(checkPermissions(creator_permissions, {Role::kTransfer}));
+
+    RDB_ERROR_CHECK(checkTransferPolicy(common, command));
+  }
 
-  if (auto result = forSignatory<kDbOperation::kCheck, kDbEntry::kMustExist>(
-          common, account_name, domain_id, pk);
+  if (auto result = forAsset<kDbOperation::kCheck, kDbEntry::kMustExist>(
+          common, asset_name, domain_id);
       expected::hasError(result))
-    return makeError<void>(ErrorCodes::kNoSignatory,
+    return makeError<void>(ErrorCodes::kNoAsset,
                            std::move(result.assumeError()));
 
-  uint64_t counter = 0;
-  auto status = enumerateKeys(common,
-                              [&](auto key) {
-                                ++counter;
-                                return true;
-                              },
-                              RocksDBPort::ColumnFamilyType::kWsv,
-                              fmtstrings::kPathSignatories,
-                              domain_id,
-                              account_name);
-
-  if (counter <= quorum)
+  std::string hash;
+  toLowerAppend(command.hash(), hash);
+  if (auto result = forAssetLock<kDbOperation::kCheck, kDbEntry::kMustNotExist>(
+          common, hash);
+      expected::hasError(result))
     return makeError<void>(
-        ErrorCodes::kCountNotEnough,
-        "Remove signatory {} for account {} with quorum {} failed.",
-        pk,
-        command.accountId(),
-        quorum);
+        ErrorCodes::kAssetLockExists, "Asset lock {} already exists.", hash);
 
-  RDB_ERROR_CHECK(forSignatory<kDbOperation::kDel, kDbEntry::kCanExist>(
-      common, account_name, domain_id, pk));
+  RDB_TRY_GET_VALUE(
+      opt_source_balance,
+      forAccountAsset<kDbOperation::kGet, kDbEntry::kCanExist>(
+          common, source_account_name, source_domain_id, command.assetId()));
+  if (not opt_source_balance)
+    return makeError<void>(ErrorCodes::kNotEnoughAssets, "Not enough assets");
+  shared_model::interface::Amount source_balance(
+      std::move(*opt_source_balance));
+
+  source_balance -= amount;
+  if (source_balance.toStringRepr()[0] == 'N')
+    return makeError<void>(ErrorCodes::kNotEnoughAssets, "Not enough assets");
+
+  if (do_validation)
+    RDB_ERROR_CHECK(checkVestingSchedule(common,
+                                         source_account_name,
+                                         source_domain_id,
+                                         command.assetId(),
+                                         source_balance,
+                                         ErrorCodes::kNotEnoughAssets));
+
+  RDB_ERROR_CHECK(spendWithinLimit(common,
+                                   source_account_name,
+                                   source_domain_id,
+                                   command.assetId(),
+                                   amount,
+                                   do_validation));
+
+  common.valueBuffer().assign(source_balance.toStringRepr());
+  RDB_ERROR_CHECK(forAccountAsset<kDbOperation::kPut>(
+      common, source_account_name, source_domain_id, command.assetId()));
+
+  RDB_ERROR_CHECK(
+      updateAssetHolders(common, command.assetId(), true, source_balance));
+
+  RDB_TRY_GET_VALUE(top_height, forTopBlockHeight(common));
+  common.valueBuffer().assign(fmt::format("{}|{}|{}|{}|{}",
+                                          command.srcAccountId(),
+                                          command.destAccountId(),
+                                          command.assetId(),
+                                          amount.toStringRepr(),
+                                          top_height + 1 + command.timeout()));
+  RDB_ERROR_CHECK(forAssetLock<kDbOperation::kPut>(common, hash));
 
   return {};
 }
 
 RocksDbCommandExecutor::ExecutionResult RocksDbCommandExecutor::operator()(
     RocksDbCommon &common,
-    const shared_model::interface::RevokePermission &command,
+    const shared_model::interface::ClaimWithPreimage &command,
     const shared_model::interface::types::AccountIdType &creator_account_id,
     const std::string &tx_hash,
     shared_model::interface::types::CommandIndexType /*cmd_index*/,
     bool do_validation,
     shared_model::interface::RolePermissionSet const &creator_permissions) {
-  auto const &[account_name, domain_id] = staticSplitId<2>(command.accountId());
-  auto const revoked_perm = command.permissionName();
-
-  if (do_validation) {
-    // check if account exists
-    RDB_ERROR_CHECK(forAccount<kDbOperation::kCheck, kDbEntry::kMustExist>(
-        common, account_name, domain_id));
-  }
-
-  GrantablePermissionSet granted_account_permissions;
-  RDB_TRY_GET_VALUE(
-      opt_permissions,
-      forGrantablePermissions<kDbOperation::kGet, kDbEntry::kCanExist>(
-          common, account_name, domain_id, creator_account_id));
-  if (opt_permissions)
-    granted_account_permissions = *opt_permissions;
+  auto hash = assetLockHash(command.preimage());
+  if (auto e = expected::resultToOptionalError(hash))
+    return makeError<void>(ErrorCodes::kException, "{}", *e);
 
-  // check if not granted
-  if (!granted_account_permissions.isSet(revoked_perm))
-    return makeError<void>(ErrorCodes::kNoPermissions, "Permission not set");
+  return releaseAssetLock(common, hash.assumeValue(), true);
+}
 
-  granted_account_permissions.unset(revoked_perm);
-  common.valueBuffer().assign(granted_account_permissions.toBitstring());
-  RDB_ERROR_CHECK(
-      forGrantablePermissions<kDbOperation::kPut, kDbEntry::kMustExist>(
-          common, account_name, domain_id, creator_account_id));
+RocksDbCommandExecutor::ExecutionResult RocksDbCommandExecutor::operator()(
+    RocksDbCommon &common,
+    const shared_model::interface::RefundAfterTimeout &command,
+    const shared_model::interface::types::AccountIdType &creator_account_id,
+    const std::string &tx_hash,
+    shared_model::interface::types::CommandIndexType /*cmd_index*/,
+    bool do_validation,
+    shared_model::interface::RolePermissionSet const &creator_permissions) {
+  std::string hash;
+  toLowerAppend(command.hash(), hash);
 
-  return {};
+  return releaseAssetLock(common, hash, false);
 }
 
 RocksDbCommandExecutor::ExecutionResult RocksDbCommandExecutor::operator()(
@@ -931,6 +2287,13 @@ RocksDbCommandExecutor::ExecutionResult RocksDbCommandExecutor::operator()(
     // check if account exists
     RDB_ERROR_CHECK(forAccount<kDbOperation::kCheck, kDbEntry::kMustExist>(
         common, account_name, domain_id));
+
+    RDB_ERROR_CHECK(checkAccountDetailLimits(common,
+                                             account_name,
+                                             domain_id,
+                                             creator_account_id,
+                                             command.key(),
+                                             command.value()));
   }
 
   RDB_TRY_GET_VALUE(
@@ -966,6 +2329,97 @@ RocksDbCommandExecutor::ExecutionResult RocksDbCommandExecutor::operator()(
   return {};
 }
 
+RocksDbCommandExecutor::ExecutionResult RocksDbCommandExecutor::operator()(
+    RocksDbCommon &common,
+    const shared_model::interface::SetAccountDetailAtPath &command,
+    const shared_model::interface::types::AccountIdType &creator_account_id,
+    const std::string &tx_hash,
+    shared_model::interface::types::CommandIndexType /*cmd_index*/,
+    bool do_validation,
+    shared_model::interface::RolePermissionSet const &creator_permissions) {
+  auto const &[creator_account_name, creator_domain_id] =
+      staticSplitId<2>(creator_account_id);
+  auto const &[account_name, domain_id] = staticSplitId<2>(command.accountId());
+  std::string_view const writer = !creator_account_id.empty()
+      ? creator_account_id
+      : std::string_view{"genesis"};
+
+  std::optional<uint64_t> max_depth;
+  if (do_validation) {
+    if (command.accountId() != creator_account_id) {
+      GrantablePermissionSet granted_account_permissions;
+      RDB_TRY_GET_VALUE(
+          opt_permissions,
+          forGrantablePermissions<kDbOperation::kGet, kDbEntry::kCanExist>(
+              common,
+              creator_account_name,
+              creator_domain_id,
+              command.accountId()));
+      if (opt_permissions)
+        granted_account_permissions = *opt_permissions;
+
+      RDB_ERROR_CHECK(checkPermissions(creator_permissions,
+                                       granted_account_permissions,
+                                       Role::kSetDetail,
+                                       Grantable::kSetMyAccountDetail));
+    }
+
+    // check if account exists
+    RDB_ERROR_CHECK(forAccount<kDbOperation::kCheck, kDbEntry::kMustExist>(
+        common, account_name, domain_id));
+
+    RDB_TRY_GET_VALUE(limit,
+                      getAccountDetailLimit(common, kMaxAccountDetailDepthKey));
+    max_depth = limit;
+  }
+
+  RDB_TRY_GET_VALUE(
+      opt_detail,
+      forAccountDetail<kDbOperation::kGet, kDbEntry::kCanExist>(
+          common, account_name, domain_id, writer, command.key()));
+  std::optional<std::string> detail;
+  if (opt_detail)
+    detail = std::string{*opt_detail};
+
+  auto const old_value = command.oldValue();
+  auto new_detail = setAccountDetailAtPath(
+      detail, command.path(), command.value(), old_value, max_depth);
+  if (auto e = expected::resultToOptionalError(new_detail))
+    return makeError<void>(
+        e->code == AccountDetailPathError::Code::kOldValueMismatch
+            ? ErrorCodes::kIncorrectOldValue
+            : ErrorCodes::kInvalidDetailPath,
+        "{}",
+        e->message);
+
+  if (do_validation)
+    RDB_ERROR_CHECK(checkAccountDetailLimits(common,
+                                             account_name,
+                                             domain_id,
+                                             writer,
+                                             command.key(),
+                                             new_detail.assumeValue()));
+
+  common.valueBuffer().assign(new_detail.assumeValue());
+  RDB_ERROR_CHECK(forAccountDetail<kDbOperation::kPut>(
+      common, account_name, domain_id, writer, command.key()));
+
+  if (!detail) {
+    RDB_TRY_GET_VALUE(
+        opt_acc_details_count,
+        forAccountDetailsCount<kDbOperation::kGet, kDbEntry::kCanExist>(
+            common, account_name, domain_id));
+    const uint64_t count =
+        opt_acc_details_count ? *opt_acc_details_count : 0ull;
+
+    common.encode(count + 1ull);
+    RDB_ERROR_CHECK(forAccountDetailsCount<kDbOperation::kPut>(
+        common, account_name, domain_id));
+  }
+
+  return {};
+}
+
 RocksDbCommandExecutor::ExecutionResult RocksDbCommandExecutor::operator()(
     RocksDbCommon &common,
     const shared_model::interface::SetQuorum &command,
@@ -1025,6 +2479,82 @@ RocksDbCommandExecutor::ExecutionResult RocksDbCommandExecutor::operator()(
   return {};
 }
 
+RocksDbCommandExecutor::ExecutionResult RocksDbCommandExecutor::operator()(
+    RocksDbCommon &common,
+    const shared_model::interface::SetSpendingLimit &command,
+    const shared_model::interface::types::AccountIdType &creator_account_id,
+    const std::string &tx_hash,
+    shared_model::interface::types::CommandIndexType /*cmd_index*/,
+    bool do_validation,
+    shared_model::interface::RolePermissionSet const &creator_permissions) {
+  auto const &[creator_account_name, creator_domain_id] =
+      staticSplitId<2>(creator_account_id);
+  auto const &[account_name, domain_id] = staticSplitId<2>(command.accountId());
+  auto const &[asset_name, asset_domain_id] =
+      staticSplitId<2>(command.assetId());
+
+  RDB_ERROR_CHECK(forAccount<kDbOperation::kCheck, kDbEntry::kMustExist>(
+      common, account_name, domain_id));
+
+  if (do_validation) {
+    // the limited account cannot change its own limit unless it is granted
+    GrantablePermissionSet granted_account_permissions;
+    RDB_TRY_GET_VALUE(
+        opt_permissions,
+        forGrantablePermissions<kDbOperation::kGet, kDbEntry::kCanExist>(
+            common,
+            creator_account_name,
+            creator_domain_id,
+            command.accountId()));
+    if (opt_permissions)
+      granted_account_permissions = *opt_permissions;
+
+    RDB_ERROR_CHECK(checkGrantablePermissions(creator_permissions,
+                                              granted_account_permissions,
+                                              Grantable::kSetMySpendingLimit));
+  }
+
+  auto opt_precision = forAsset<kDbOperation::kGet, kDbEntry::kMustExist>(
+      common, asset_name, asset_domain_id);
+  if (expected::hasError(opt_precision))
+    return makeError<void>(ErrorCodes::kNoAsset,
+                           std::move(opt_precision.assumeError()));
+  if (*opt_precision.assumeValue() != command.amount().precision())
+    return makeError<void>(ErrorCodes::kNoAsset,
+                           "Precision of {} is {}, not {}",
+                           command.assetId(),
+                           *opt_precision.assumeValue(),
+                           command.amount().precision());
+
+  RDB_TRY_GET_VALUE(
+      opt_limit,
+      forAccountSpendingLimit<kDbOperation::kGet, kDbEntry::kCanExist>(
+          common, account_name, domain_id, command.assetId()));
+
+  if (command.window() == 0) {
+    if (opt_limit)
+      RDB_ERROR_CHECK(
+          forAccountSpendingLimit<kDbOperation::kDel, kDbEntry::kCanExist>(
+              common, account_name, domain_id, command.assetId()));
+    return {};
+  }
+
+  // the spendings are kept, so that the new limit applies to them as well
+  SpendingLimit limit{command.amount(), command.window(), {}};
+  if (opt_limit) {
+    auto previous = SpendingLimit::decode(*opt_limit);
+    if (auto e = expected::resultToOptionalError(previous))
+      return makeError<void>(ErrorCodes::kException, "{}", *e);
+    limit.spendings = std::move(previous.assumeValue().spendings);
+  }
+
+  common.valueBuffer().assign(limit.encode());
+  RDB_ERROR_CHECK(forAccountSpendingLimit<kDbOperation::kPut>(
+      common, account_name, domain_id, command.assetId()));
+
+  return {};
+}
+
 RocksDbCommandExecutor::ExecutionResult RocksDbCommandExecutor::operator()(
     RocksDbCommon &common,
     const shared_model::interface::SubtractAssetQuantity &command,
@@ -1067,19 +2597,34 @@ RocksDbCommandExecutor::ExecutionResult RocksDbCommandExecutor::operator()(
           common, creator_account_name, creator_domain_id, command.assetId()));
   if (opt_amount)
     result = std::move(*opt_amount);
+  bool const was_held = result.sign() > 0;
 
   result -= amount;
-  common.valueBuffer().assign(result.toStringRepr());
-  if (common.valueBuffer()[0] == 'N')
+  if (result.toStringRepr()[0] == 'N')
     return makeError<void>(ErrorCodes::kInvalidAmount,
                            "Invalid {} amount {} from {}",
                            command.toString(),
                            result.toString(),
                            creator_account_id);
 
+  if (do_validation)
+    RDB_ERROR_CHECK(checkVestingSchedule(common,
+                                         creator_account_name,
+                                         creator_domain_id,
+                                         command.assetId(),
+                                         result,
+                                         ErrorCodes::kInvalidAssetAmount));
+
+  common.valueBuffer().assign(result.toStringRepr());
   RDB_ERROR_CHECK(forAccountAsset<kDbOperation::kPut>(
       common, creator_account_name, creator_domain_id, command.assetId()));
 
+  RDB_ERROR_CHECK(
+      updateAssetSupply(common, command.assetId(), amount, false));
+
+  RDB_ERROR_CHECK(
+      updateAssetHolders(common, command.assetId(), was_held, result));
+
   return {};
 }
 
@@ -1088,7 +2633,7 @@ RocksDbCommandExecutor::ExecutionResult RocksDbCommandExecutor::operator()(
     const shared_model::interface::TransferAsset &command,
     const shared_model::interface::types::AccountIdType &creator_account_id,
     const std::string &tx_hash,
-    shared_model::interface::types::CommandIndexType /*cmd_index*/,
+    shared_model::interface::types::CommandIndexType cmd_index,
     bool do_validation,
     shared_model::interface::RolePermissionSet const &creator_permissions) {
   auto const &[creator_account_name, creator_domain_id] =
@@ -1156,6 +2701,8 @@ RocksDbCommandExecutor::ExecutionResult RocksDbCommandExecutor::operator()(
         return makeError<void>(ErrorCodes::kInvalidFieldSize,
                                "Too big description");
     }
+
+    RDB_ERROR_CHECK(checkTransferPolicy(common, command));
   }
 
   RDB_TRY_GET_VALUE(
@@ -1169,6 +2716,14 @@ RocksDbCommandExecutor::ExecutionResult RocksDbCommandExecutor::operator()(
   if (source_balance.toStringRepr()[0] == 'N')
     return makeError<void>(ErrorCodes::kNotEnoughAssets, "Not enough assets");
 
+  if (do_validation)
+    RDB_ERROR_CHECK(checkVestingSchedule(common,
+                                         source_account_name,
+                                         source_domain_id,
+                                         command.assetId(),
+                                         source_balance,
+                                         ErrorCodes::kNotEnoughAssets));
+
   RDB_TRY_GET_VALUE(
       opt_account_asset_size,
       forAccountAssetSize<kDbOperation::kGet, kDbEntry::kCanExist>(
@@ -1190,11 +2745,28 @@ RocksDbCommandExecutor::ExecutionResult RocksDbCommandExecutor::operator()(
     destination_balance = *opt_amount;
   else
     ++account_asset_size;
+  bool const destination_was_holder = destination_balance.sign() > 0;
+
+  // the burn fee is a part of the state transition, so it is applied without
+  // validation as well
+  RDB_TRY_GET_VALUE(policy, getSupplyPolicy(common, command.assetId()));
+  std::optional<shared_model::interface::Amount> burn_fee;
+  if (policy and policy->burn_fee_basis_points > 0)
+    burn_fee = policy->burnFee(amount);
 
   destination_balance += amount;
+  if (burn_fee)
+    destination_balance -= *burn_fee;
   if (destination_balance.toStringRepr()[0] == 'N')
     return makeError<void>(ErrorCodes::kIncorrectBalance, "Incorrect balance");
 
+  RDB_ERROR_CHECK(spendWithinLimit(common,
+                                   source_account_name,
+                                   source_domain_id,
+                                   command.assetId(),
+                                   amount,
+                                   do_validation));
+
   common.valueBuffer().assign(source_balance.toStringRepr());
   RDB_ERROR_CHECK(forAccountAsset<kDbOperation::kPut>(
       common, source_account_name, source_domain_id, command.assetId()));
@@ -1209,6 +2781,22 @@ RocksDbCommandExecutor::ExecutionResult RocksDbCommandExecutor::operator()(
   RDB_ERROR_CHECK(forAccountAssetSize<kDbOperation::kPut>(
       common, destination_account_name, destination_domain_id));
 
+  // the fee is kept for the statements of the account, the policy can change
+  if (burn_fee) {
+    RDB_ERROR_CHECK(
+        updateAssetSupply(common, command.assetId(), *burn_fee, false));
+    common.valueBuffer().assign(burn_fee->toStringRepr());
+    RDB_ERROR_CHECK(
+        forTransferFee<kDbOperation::kPut>(common, tx_hash, cmd_index));
+  }
+
+  RDB_ERROR_CHECK(
+      updateAssetHolders(common, command.assetId(), true, source_balance));
+  RDB_ERROR_CHECK(updateAssetHolders(common,
+                                     command.assetId(),
+                                     destination_was_holder,
+                                     destination_balance));
+
   return {};
 }
 
@@ -1222,9 +2810,123 @@ RocksDbCommandExecutor::ExecutionResult RocksDbCommandExecutor::operator()(
     shared_model::interface::RolePermissionSet const &creator_permissions) {
   auto const &key = command.key();
   auto const &value = command.value();
+  if (auto e = checkSettingValue(key, value))
+    return makeError<void>(ErrorCodes::kInvalidSettingValue, "{}", *e);
 
   common.valueBuffer().assign(value);
   RDB_ERROR_CHECK(forSettings<kDbOperation::kPut>(common, key));
 
   return {};
 }
+
+RocksDbCommandExecutor::ExecutionResult RocksDbCommandExecutor::operator()(
+    RocksDbCommon &common,
+    const shared_model::interface::ProposeSettingValue &command,
+    const shared_model::interface::types::AccountIdType &creator_account_id,
+    const std::string &,
+    shared_model::interface::types::CommandIndexType,
+    bool do_validation,
+    shared_model::interface::RolePermissionSet const &creator_permissions) {
+  auto const &proposal_id = command.proposalId();
+  auto const &key = command.settingKey();
+  auto const &value = command.settingValue();
+
+  RDB_TRY_GET_VALUE(policy, getGovernancePolicy(common));
+  if (not policy)
+    return makeError<void>(ErrorCodes::kNoGovernancePolicy,
+                           "Governance policy is not set.");
+  if (do_validation and not policy->weight(creator_account_id))
+    return makeError<void>(ErrorCodes::kNoPermissions,
+                           "{} is not a governance voter.",
+                           creator_account_id);
+  if (auto e = checkSettingValue(key, value))
+    return makeError<void>(ErrorCodes::kInvalidSettingValue, "{}", *e);
+
+  if (auto result = forProposal<kDbOperation::kCheck, kDbEntry::kMustNotExist>(
+          common, proposal_id);
+      expected::hasError(result))
+    return makeError<void>(ErrorCodes::kProposalExists,
+                           "Proposal {} already exists.",
+                           proposal_id);
+
+  RDB_TRY_GET_VALUE(top_height, forTopBlockHeight(common));
+  common.valueBuffer().assign(value);
+  RDB_ERROR_CHECK(
+      forProposalSettingValue<kDbOperation::kPut>(common, proposal_id));
+
+  common.valueBuffer().assign(
+      ProposalState{top_height + 1 + policy->voting_period, false, 0, key}
+          .toString());
+  RDB_ERROR_CHECK(forProposal<kDbOperation::kPut>(common, proposal_id));
+
+  return {};
+}
+
+RocksDbCommandExecutor::ExecutionResult RocksDbCommandExecutor::operator()(
+    RocksDbCommon &common,
+    const shared_model::interface::VoteForProposal &command,
+    const shared_model::interface::types::AccountIdType &creator_account_id,
+    const std::string &,
+    shared_model::interface::types::CommandIndexType,
+    bool do_validation,
+    shared_model::interface::RolePermissionSet const &creator_permissions) {
+  auto const &proposal_id = command.proposalId();
+
+  RDB_TRY_GET_VALUE(policy, getGovernancePolicy(common));
+  if (not policy)
+    return makeError<void>(ErrorCodes::kNoGovernancePolicy,
+                           "Governance policy is not set.");
+  auto const weight = policy->weight(creator_account_id);
+  if (do_validation and not weight)
+    return makeError<void>(ErrorCodes::kNoPermissions,
+                           "{} is not a governance voter.",
+                           creator_account_id);
+
+  RDB_TRY_GET_VALUE(
+      opt_proposal,
+      forProposal<kDbOperation::kGet, kDbEntry::kCanExist>(common,
+                                                           proposal_id));
+  if (not opt_proposal)
+    return makeError<void>(
+        ErrorCodes::kNoProposal, "Proposal {} does not exist.", proposal_id);
+  auto state = ProposalState::parse(*opt_proposal);
+  if (not state)
+    return makeError<void>(
+        ErrorCodes::kException, "Malformed proposal {}.", proposal_id);
+
+  if (auto result =
+          forProposalVote<kDbOperation::kCheck, kDbEntry::kMustNotExist>(
+              common, proposal_id, creator_account_id);
+      expected::hasError(result))
+    return makeError<void>(ErrorCodes::kAlreadyVoted,
+                           "{} already voted for proposal {}.",
+                           creator_account_id,
+                           proposal_id);
+
+  RDB_TRY_GET_VALUE(top_height, forTopBlockHeight(common));
+  if (state->enacted or top_height + 1 > state->expires_height)
+    return makeError<void>(
+        ErrorCodes::kProposalClosed, "Proposal {} is closed.", proposal_id);
+
+  uint64_t const vote_weight = weight.value_or(0ull);
+  common.encode(vote_weight);
+  RDB_ERROR_CHECK(forProposalVote<kDbOperation::kPut>(
+      common, proposal_id, creator_account_id));
+
+  state->votes += vote_weight;
+  if (state->votes >= policy->threshold) {
+    RDB_TRY_GET_VALUE(
+        setting_value,
+        forProposalSettingValue<kDbOperation::kGet, kDbEntry::kMustExist>(
+            common, proposal_id));
+    common.valueBuffer().assign(*setting_value);
+    RDB_ERROR_CHECK(
+        forSettings<kDbOperation::kPut>(common, state->setting_key));
+    state->enacted = true;
+  }
+
+  common.valueBuffer().assign(state->toString());
+  RDB_ERROR_CHECK(forProposal<kDbOperation::kPut>(common, proposal_id));
+
+  return {};
+}
@@ -23,23 +23,37 @@ namespace shared_model::interface {
   class AddPeer;
   class AddSignatory;
   class AppendRole;
+  class ArchiveDomain;
+  class AuthorizeSessionKey;
   class CompareAndSetAccountDetail;
   class CallEngine;
+  class ClaimWithPreimage;
   class CreateAccount;
   class CreateAsset;
   class CreateDomain;
   class CreateRole;
+  class DeactivateAccount;
   class DetachRole;
   class GrantPermission;
+  class GrantVestedAsset;
+  class IncludeRole;
+  class LockAsset;
   class PermissionToString;
+  class ProposeSettingValue;
+  class RecoverAccount;
+  class RefundAfterTimeout;
   class RemovePeer;
   class RemoveSignatory;
   class RevokePermission;
+  class RotateKey;
   class SetAccountDetail;
+  class SetAccountDetailAtPath;
   class SetQuorum;
+  class SetSpendingLimit;
   class SubtractAssetQuantity;
   class TransferAsset;
   class SetSettingValue;
+  class VoteForProposal;
 }  // namespace shared_model::interface
 
 namespace iroha::ametsuchi {
@@ -58,13 +72,38 @@ namespace iroha::ametsuchi {
       kInvalidAmount = 3,
       kRoleAlreadyExists = 3,
       kSignatoryMustNotExist = 3,
+      kInvalidSettingValue = 3,
+      kNoAssetLock = 3,
+      kNoProposal = 3,
+      kIdempotencyKeyUsed = 3,
+      kChainIdMismatch = 3,
       kInvalidAssetAmount = 4,
       kIncorrectOldValue = 4,
       kPeersCountIsNotEnough = 4,
       kNoSignatory = 4,
+      kAccountDeactivated = 4,
+      kNoAsset = 4,
+      kAssetLockTimedOut = 4,
+      kAssetLockNotTimedOut = 4,
+      kProposalExists = 4,
+      kAlreadyVoted = 4,
       kCountNotEnough = 5,
+      kNewSignatoryExists = 5,
+      kAssetLockExists = 5,
+      kAccountDetailTooBig = 5,
+      kSupplyNotAllowed = 5,
+      kDomainArchived = 5,
+      kRoleInclusionCycle = 5,
+      kNoGovernancePolicy = 5,
       kNotEnoughAssets = 6,
+      kTooManyAccountDetails = 6,
+      kProposalClosed = 6,
+      kVestingScheduleExists = 6,
       kIncorrectBalance = 7,
+      kInvalidDetailPath = 7,
+      kTransferNotAllowed = 9,
+      kSpendingLimitExceeded = 10,
+      kWritesArchivedDomain = 11,
       kException = 1002,
       kNoImplementation = 1005,
       kPermissionIsAlreadySet = 1007,
@@ -88,6 +127,13 @@ namespace iroha::ametsuchi {
         shared_model::interface::types::CommandIndexType cmd_index,
         bool do_validation) override;
 
+    CommandResult recordIdempotencyKey(
+        const shared_model::interface::Transaction &transaction,
+        bool do_validation) override;
+
+    CommandResult checkChainId(
+        const shared_model::interface::Transaction &transaction) override;
+
     void skipChanges() override;
     DatabaseTransaction &dbSession() override;
     std::shared_ptr<RocksDBContext> getSession();
@@ -137,6 +183,24 @@ namespace iroha::ametsuchi {
         bool do_validation,
         shared_model::interface::RolePermissionSet const &creator_permissions);
 
+    ExecutionResult operator()(
+        RocksDbCommon &common,
+        const shared_model::interface::ArchiveDomain &command,
+        const shared_model::interface::types::AccountIdType &creator_account_id,
+        const std::string &tx_hash,
+        shared_model::interface::types::CommandIndexType cmd_index,
+        bool do_validation,
+        shared_model::interface::RolePermissionSet const &creator_permissions);
+
+    ExecutionResult operator()(
+        RocksDbCommon &common,
+        const shared_model::interface::AuthorizeSessionKey &command,
+        const shared_model::interface::types::AccountIdType &creator_account_id,
+        const std::string &tx_hash,
+        shared_model::interface::types::CommandIndexType cmd_index,
+        bool do_validation,
+        shared_model::interface::RolePermissionSet const &creator_permissions);
+
     ExecutionResult operator()(
         RocksDbCommon &common,
         const shared_model::interface::CompareAndSetAccountDetail &command,
@@ -182,6 +246,15 @@ namespace iroha::ametsuchi {
         bool do_validation,
         shared_model::interface::RolePermissionSet const &creator_permissions);
 
+    ExecutionResult operator()(
+        RocksDbCommon &common,
+        const shared_model::interface::DeactivateAccount &command,
+        const shared_model::interface::types::AccountIdType &creator_account_id,
+        const std::string &tx_hash,
+        shared_model::interface::types::CommandIndexType cmd_index,
+        bool do_validation,
+        shared_model::interface::RolePermissionSet const &creator_permissions);
+
     ExecutionResult operator()(
         RocksDbCommon &common,
         const shared_model::interface::DetachRole &command,
@@ -200,6 +273,42 @@ namespace iroha::ametsuchi {
         bool do_validation,
         shared_model::interface::RolePermissionSet const &creator_permissions);
 
+    ExecutionResult operator()(
+        RocksDbCommon &common,
+        const shared_model::interface::GrantVestedAsset &command,
+        const shared_model::interface::types::AccountIdType &creator_account_id,
+        const std::string &tx_hash,
+        shared_model::interface::types::CommandIndexType cmd_index,
+        bool do_validation,
+        shared_model::interface::RolePermissionSet const &creator_permissions);
+
+    ExecutionResult operator()(
+        RocksDbCommon &common,
+        const shared_model::interface::IncludeRole &command,
+        const shared_model::interface::types::AccountIdType &creator_account_id,
+        const std::string &tx_hash,
+        shared_model::interface::types::CommandIndexType cmd_index,
+        bool do_validation,
+        shared_model::interface::RolePermissionSet const &creator_permissions);
+
+    ExecutionResult operator()(
+        RocksDbCommon &common,
+        const shared_model::interface::ProposeSettingValue &command,
+        const shared_model::interface::types::AccountIdType &creator_account_id,
+        const std::string &tx_hash,
+        shared_model::interface::types::CommandIndexType cmd_index,
+        bool do_validation,
+        shared_model::interface::RolePermissionSet const &creator_permissions);
+
+    ExecutionResult operator()(
+        RocksDbCommon &common,
+        const shared_model::interface::RecoverAccount &command,
+        const shared_model::interface::types::AccountIdType &creator_account_id,
+        const std::string &tx_hash,
+        shared_model::interface::types::CommandIndexType cmd_index,
+        bool do_validation,
+        shared_model::interface::RolePermissionSet const &creator_permissions);
+
     ExecutionResult operator()(
         RocksDbCommon &common,
         const shared_model::interface::RemovePeer &command,
@@ -227,6 +336,42 @@ namespace iroha::ametsuchi {
         bool do_validation,
         shared_model::interface::RolePermissionSet const &creator_permissions);
 
+    ExecutionResult operator()(
+        RocksDbCommon &common,
+        const shared_model::interface::RotateKey &command,
+        const shared_model::interface::types::AccountIdType &creator_account_id,
+        const std::string &tx_hash,
+        shared_model::interface::types::CommandIndexType cmd_index,
+        bool do_validation,
+        shared_model::interface::RolePermissionSet const &creator_permissions);
+
+    ExecutionResult operator()(
+        RocksDbCommon &common,
+        const shared_model::interface::LockAsset &command,
+        const shared_model::interface::types::AccountIdType &creator_account_id,
+        const std::string &tx_hash,
+        shared_model::interface::types::CommandIndexType cmd_index,
+        bool do_validation,
+        shared_model::interface::RolePermissionSet const &creator_permissions);
+
+    ExecutionResult operator()(
+        RocksDbCommon &common,
+        const shared_model::interface::ClaimWithPreimage &command,
+        const shared_model::interface::types::AccountIdType &creator_account_id,
+        const std::string &tx_hash,
+        shared_model::interface::types::CommandIndexType cmd_index,
+        bool do_validation,
+        shared_model::interface::RolePermissionSet const &creator_permissions);
+
+    ExecutionResult operator()(
+        RocksDbCommon &common,
+        const shared_model::interface::RefundAfterTimeout &command,
+        const shared_model::interface::types::AccountIdType &creator_account_id,
+        const std::string &tx_hash,
+        shared_model::interface::types::CommandIndexType cmd_index,
+        bool do_validation,
+        shared_model::interface::RolePermissionSet const &creator_permissions);
+
     ExecutionResult operator()(
         RocksDbCommon &common,
         const shared_model::interface::SetAccountDetail &command,
@@ -236,6 +381,15 @@ namespace iroha::ametsuchi {
         bool do_validation,
         shared_model::interface::RolePermissionSet const &creator_permissions);
 
+    ExecutionResult operator()(
+        RocksDbCommon &common,
+        const shared_model::interface::SetAccountDetailAtPath &command,
+        const shared_model::interface::types::AccountIdType &creator_account_id,
+        const std::string &tx_hash,
+        shared_model::interface::types::CommandIndexType cmd_index,
+        bool do_validation,
+        shared_model::interface::RolePermissionSet const &creator_permissions);
+
     ExecutionResult operator()(
         RocksDbCommon &common,
         const shared_model::interface::SetQuorum &command,
@@ -245,6 +399,15 @@ namespace iroha::ametsuchi {
         bool do_validation,
         shared_model::interface::RolePermissionSet const &creator_permissions);
 
+    ExecutionResult operator()(
+        RocksDbCommon &common,
+        const shared_model::interface::SetSpendingLimit &command,
+        const shared_model::interface::types::AccountIdType &creator_account_id,
+        const std::string &tx_hash,
+        shared_model::interface::types::CommandIndexType cmd_index,
+        bool do_validation,
+        shared_model::interface::RolePermissionSet const &creator_permissions);
+
     ExecutionResult operator()(
         RocksDbCommon &common,
         const shared_model::interface::SubtractAssetQuantity &command,
@@ -272,6 +435,15 @@ namespace iroha::ametsuchi {
         bool do_validation,
         shared_model::interface::RolePermissionSet const &creator_permissions);
 
+    ExecutionResult operator()(
+        RocksDbCommon &common,
+        const shared_model::interface::VoteForProposal &command,
+        const shared_model::interface::types::AccountIdType &creator_account_id,
+        const std::string &tx_hash,
+        shared_model::interface::types::CommandIndexType cmd_index,
+        bool do_validation,
+        shared_model::interface::RolePermissionSet const &creator_permissions);
+
    private:
     std::shared_ptr<RocksDBContext> db_context_;
     std::shared_ptr<shared_model::interface::PermissionToString>
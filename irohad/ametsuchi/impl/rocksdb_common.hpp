@@ -12,12 +12,15 @@
 #include <string>
 #include <string_view>
 #include <utility>
+#include <vector>
 
 #include <fmt/compile.h>
 #include <fmt/format.h>
 #include <rocksdb/db.h>
 #include <rocksdb/filter_policy.h>
+#include <rocksdb/snapshot.h>
 #include <rocksdb/table.h>
+#include <rocksdb/utilities/checkpoint.h>
 #include <rocksdb/utilities/transaction.h>
 #include <rocksdb/utilities/transaction_db.h>
 #include "ametsuchi/impl/database_cache/cache.hpp"
@@ -62,10 +65,33 @@
  *                |            +-<key_2, value_2>
  *                |            +-<key_3, value_3>
  *                |
+ *                +-|ASSET_LOCKS|-+-<hash_1, value:src|dest|asset|amount|timeout height>
+ *                |               +-<hash_2, value:src|dest|asset|amount|timeout height>
+ *                |
+ *                +-|ASSET_SUPPLY|-+-<asset_1, value:total quantity>
+ *                |                +-<asset_1>-+-<minted, value:quantity>
+ *                |                |           +-<burned, value:quantity>
+ *                |                |           +-<holders, value:count>
+ *                |                |
+ *                |                +-<asset_2, value:total quantity>
+ *                |                +-<asset_2>-+-<minted, value:quantity>
+ *                |                            +-<burned, value:quantity>
+ *                |                            +-<holders, value:count>
+ *                |
  *                +-|ROLES|-+-<role_1, value:permissions bitfield>
  *                |         +-<role_2, value:permissions bitfield>
  *                |         +-<role_3, value:permissions bitfield>
  *                |
+ *                +-|INCLUDED_BY|-+-<role_1>-+-<role_2>
+ *                |               |          +-<role_3>
+ *                |               |
+ *                |               +-<role_2>-+-<role_3>
+ *                |
+ *                +-|PROPOSALS|-+-<proposal_1, value:expiration height|enacted|votes weight|setting key>
+ *                |             +-<proposal_1>-+-<setting_value, value>
+ *                |                            +-<voter_1, value:weight>
+ *                |                            +-<voter_2, value:weight>
+ *                |
  *                +-|TRANSACTIONS|-+-|ACCOUNTS|-+-<account_1>-+-|POSITION|-+-<height_index, value:tx_hash_1>
  *                |                |            |             |            +-<height_index, value:tx_hash_2>
  *                |                |            |             |            +-<height_index, value:tx_hash_3>
@@ -94,11 +120,14 @@
  *                +-|DOMAIN|-+-|DOMAIN_1|-+-|ASSETS|-+-<asset_1, value:precision>
  *                |          |            |          +-<asset_2, value:precision>
  *                |          |            |
+ *                |          |            +-|OPTIONS|-+-<archived>
+ *                |          |            |
  *                |          |            +-|ACCOUNTS|-|NAME_1|-+-|ASSETS|-+-<asset_1, value:quantity>
  *                |          |                                  |          +-<asset_2, value:quantity>
  *                |          |                                  |
  *                |          |                                  +-|OPTIONS|-+-<quorum>
  *                |          |                                  |           +-<asset_size>
+ *                |          |                                  |           +-<deactivated>
  *                |          |                                  |           +-<total, value: count>
  *                |          |                                  |
  *                |          |                                  +-|DETAILS|-+-<writer>-<key, value>
@@ -109,8 +138,19 @@
  *                |          |                                  +-|GRANTABLE_PER|-+-<permitee_id_1, value:permissions>
  *                |          |                                  |                 +-<permitee_id_2, value:permissions>
  *                |          |                                  |
+ *                |          |                                  +-|IDEMPOTENCY|-+-<key_1, value:tx_hash|created time>
+ *                |          |                                  |               +-<key_2, value:tx_hash|created time>
+ *                |          |                                  |
  *                |          |                                  +-|SIGNATORIES|-+-<signatory_1>
- *                |          |                                                  +-<signatory_2>
+ *                |          |                                  |               +-<signatory_2>
+ *                |          |                                  |
+ *                |          |                                  +-|RETIRED_SIG|-+-<signatory_3, value:expiration height>
+ *                |          |                                  |
+ *                |          |                                  +-|SESSION_KEY|-+-<signatory_4, value:expiration height|permissions|asset>
+ *                |          |                                  |
+ *                |          |                                  +-|VESTING|-+-<asset_1, value:amount|start height|cliff|duration>
+ *                |          |                                  |
+ *                |          |                                  +-|SPEND_LIMIT|-+-<asset_1, value:amount|window|height:spent,...>
  *                |          |
  *                |          +-<domain_1, value: default_role>
  *                |          +-<total_count, value>
@@ -177,6 +217,17 @@
  * ### EC_CON_CALLS  ##       R       ###
  * ### LOGS          ##       y       ###
  * ### TOPICS        ##       Y       ###
+ * ### RETIRED_SIG   ##       K       ###
+ * ### ASSET_LOCKS   ##       L       ###
+ * ### ASSET_SUPPLY  ##       U       ###
+ * ### INCLUDED_BY   ##       c       ###
+ * ### PROPOSALS     ##       G       ###
+ * ### VESTING       ##       J       ###
+ * ### SPEND_LIMIT   ##       H       ###
+ * ### SESSION_KEY   ##       C       ###
+ * ### IDEMPOTENCY   ##       F       ###
+ * ### LOCK_RELEASES ##       j       ###
+ * ### TRANSFER_FEES ##       o       ###
  * ######################################
  *
  * ######################################
@@ -189,6 +240,12 @@
  * ### F_TOTAL COUNT ##       V       ###
  * ### F_VERSION     ##       v       ###
  * ### F_NEXT_ID     ##       X       ###
+ * ### F_DEACTIVATED ##       B       ###
+ * ### F_MINTED      ##       m       ###
+ * ### F_BURNED      ##       b       ###
+ * ### F_HOLDERS     ##       h       ###
+ * ### F_SETTING_VAL ##       f       ###
+ * ### F_ARCHIVED    ##       z       ###
  * ######################################
  *
  * ######################################
@@ -231,6 +288,17 @@
 #define RDB_EC_CON_CALLS "R"
 #define RDB_LOGS "y"
 #define RDB_TOPICS "Y"
+#define RDB_RETIRED_SIGNATORIES "K"
+#define RDB_ASSET_LOCKS "L"
+#define RDB_ASSET_SUPPLY "U"
+#define RDB_INCLUDED_BY "c"
+#define RDB_PROPOSALS "G"
+#define RDB_VESTING "J"
+#define RDB_SPENDING_LIMIT "H"
+#define RDB_SESSION_KEYS "C"
+#define RDB_IDEMPOTENCY_KEYS "F"
+#define RDB_LOCK_RELEASES "j"
+#define RDB_TRANSFER_FEES "o"
 
 #define RDB_F_QUORUM "q"
 #define RDB_F_ASSET_SIZE "I"
@@ -239,6 +307,12 @@
 #define RDB_F_TOTAL_COUNT "V"
 #define RDB_F_VERSION "v"
 #define RDB_F_NEXT_ID "X"
+#define RDB_F_DEACTIVATED "B"
+#define RDB_F_MINTED "m"
+#define RDB_F_BURNED "b"
+#define RDB_F_HOLDERS "h"
+#define RDB_F_SETTING_VALUE "f"
+#define RDB_F_ARCHIVED "z"
 
 #define RDB_PATH_DOMAIN RDB_ROOT /**/ RDB_WSV /**/ RDB_DOMAIN /**/ RDB_XXX
 #define RDB_PATH_ACCOUNT RDB_PATH_DOMAIN /**/ RDB_ACCOUNTS /**/ RDB_XXX
@@ -264,6 +338,14 @@ namespace iroha::ametsuchi::fmtstrings {
 
   static auto constexpr kPathStore{FMT_STRING(RDB_ROOT /**/ RDB_STORE)};
 
+  // no params
+  static auto constexpr kPathDomains{
+      FMT_STRING(RDB_ROOT /**/ RDB_WSV /**/ RDB_DOMAIN)};
+
+  // no params
+  static auto constexpr kPathAssetLocks{
+      FMT_STRING(RDB_ROOT /**/ RDB_WSV /**/ RDB_ASSET_LOCKS)};
+
   // domain_id/account_name
   static auto constexpr kPathAccount{FMT_STRING(RDB_PATH_ACCOUNT)};
 
@@ -276,6 +358,10 @@ namespace iroha::ametsuchi::fmtstrings {
       FMT_STRING(RDB_ROOT /**/ RDB_WSV /**/ RDB_NETWORK /**/ RDB_S_PEERS /**/
                      RDB_ADDRESS)};
 
+  // no params
+  static auto constexpr kPathSettings{
+      FMT_STRING(RDB_ROOT /**/ RDB_WSV /**/ RDB_SETTINGS)};
+
   // hash ➡️ call_id
   static auto constexpr kPathEngineCallIds{
       FMT_STRING(RDB_ROOT /**/ RDB_WSV /**/ RDB_EVM_STORAGE /**/
@@ -285,10 +371,22 @@ namespace iroha::ametsuchi::fmtstrings {
   static auto constexpr kPathSignatories{
       FMT_STRING(RDB_PATH_ACCOUNT /**/ RDB_SIGNATORIES)};
 
+  // domain_id/account_name
+  static auto constexpr kPathRetiredSignatories{
+      FMT_STRING(RDB_PATH_ACCOUNT /**/ RDB_RETIRED_SIGNATORIES)};
+
+  // domain_id/account_name
+  static auto constexpr kPathAccountVesting{
+      FMT_STRING(RDB_PATH_ACCOUNT /**/ RDB_VESTING)};
+
   // no param
   static auto constexpr kPathRoles{
       FMT_STRING(RDB_ROOT /**/ RDB_WSV /**/ RDB_ROLES)};
 
+  // included_role_name
+  static auto constexpr kPathRoleIncludedBy{
+      FMT_STRING(RDB_ROOT /**/ RDB_WSV /**/ RDB_INCLUDED_BY /**/ RDB_XXX)};
+
   // call_id ➡️ log_ix/address/data
   static auto constexpr kPathEngineLogs{
       FMT_STRING(RDB_ROOT /**/ RDB_WSV /**/ RDB_EVM_STORAGE /**/
@@ -313,6 +411,10 @@ namespace iroha::ametsuchi::fmtstrings {
   static auto constexpr kPathAccountDetail{
       FMT_STRING(RDB_PATH_ACCOUNT /**/ RDB_DETAILS)};
 
+  // domain_id/account_name ➡️ idempotency keys
+  static auto constexpr kPathIdempotencyKeys{
+      FMT_STRING(RDB_PATH_ACCOUNT /**/ RDB_IDEMPOTENCY_KEYS)};
+
   // account_domain_id/account_name/asset_id
   static auto constexpr kPathAccountAssets{
       FMT_STRING(RDB_PATH_ACCOUNT /**/ RDB_ASSETS)};
@@ -400,10 +502,36 @@ namespace iroha::ametsuchi::fmtstrings {
       FMT_STRING(RDB_ROOT /**/ RDB_WSV /**/ RDB_ROLES /**/
                      RDB_XXX)};
 
+  // included_role_name/role_name
+  static auto constexpr kRoleIncludedBy{
+      FMT_STRING(RDB_ROOT /**/ RDB_WSV /**/ RDB_INCLUDED_BY /**/
+                     RDB_XXX /**/ RDB_XXX)};
+
   // domain_id/account_name/pubkey ➡️ ""
   static auto constexpr kSignatory{
       FMT_STRING(RDB_PATH_ACCOUNT /**/ RDB_SIGNATORIES /**/ RDB_XXX)};
 
+  // domain_id/account_name/pubkey ➡️ expiration height|new pubkey
+  static auto constexpr kRetiredSignatory{
+      FMT_STRING(RDB_PATH_ACCOUNT /**/ RDB_RETIRED_SIGNATORIES /**/ RDB_XXX)};
+
+  // domain_id/account_name/pubkey ➡️
+  // expiration height|permissions|allowed asset
+  static auto constexpr kSessionKey{
+      FMT_STRING(RDB_PATH_ACCOUNT /**/ RDB_SESSION_KEYS /**/ RDB_XXX)};
+
+  // domain_id/account_name/idempotency_key ➡️ tx_hash|created time
+  static auto constexpr kIdempotencyKey{
+      FMT_STRING(RDB_PATH_ACCOUNT /**/ RDB_IDEMPOTENCY_KEYS /**/ RDB_XXX)};
+
+  // domain_id/account_name/asset_id ➡️ amount|start height|cliff|duration
+  static auto constexpr kAccountVesting{
+      FMT_STRING(RDB_PATH_ACCOUNT /**/ RDB_VESTING /**/ RDB_XXX)};
+
+  // domain_id/account_name/asset_id ➡️ amount|window|height:spent,...
+  static auto constexpr kAccountSpendingLimit{
+      FMT_STRING(RDB_PATH_ACCOUNT /**/ RDB_SPENDING_LIMIT /**/ RDB_XXX)};
+
   // domain_id/asset_name ➡️ precision
   static auto constexpr kAsset{
       FMT_STRING(RDB_PATH_DOMAIN /**/ RDB_ASSETS /**/ RDB_XXX)};
@@ -446,6 +574,51 @@ namespace iroha::ametsuchi::fmtstrings {
   static auto constexpr kSetting{
       FMT_STRING(RDB_ROOT /**/ RDB_WSV /**/ RDB_SETTINGS /**/ RDB_XXX)};
 
+  // hash ➡️ src|dest|asset|amount|timeout height
+  static auto constexpr kAssetLock{
+      FMT_STRING(RDB_ROOT /**/ RDB_WSV /**/ RDB_ASSET_LOCKS /**/ RDB_XXX)};
+
+  // hash ➡️ src|dest|asset of the released lock
+  static auto constexpr kAssetLockRelease{
+      FMT_STRING(RDB_ROOT /**/ RDB_WSV /**/ RDB_LOCK_RELEASES /**/ RDB_XXX)};
+
+  // tx_hash/cmd_index ➡️ burn fee
+  static auto constexpr kTransferFee{FMT_STRING(
+      RDB_ROOT /**/ RDB_WSV /**/ RDB_TRANSFER_FEES /**/ RDB_XXX /**/ RDB_XXX)};
+
+  // asset_id ➡️ amount
+  static auto constexpr kAssetSupply{
+      FMT_STRING(RDB_ROOT /**/ RDB_WSV /**/ RDB_ASSET_SUPPLY /**/ RDB_XXX)};
+
+  // asset_id ➡️ amount
+  static auto constexpr kAssetMinted{FMT_STRING(
+      RDB_ROOT /**/ RDB_WSV /**/ RDB_ASSET_SUPPLY /**/ RDB_XXX /**/
+          RDB_F_MINTED)};
+
+  // asset_id ➡️ amount
+  static auto constexpr kAssetBurned{FMT_STRING(
+      RDB_ROOT /**/ RDB_WSV /**/ RDB_ASSET_SUPPLY /**/ RDB_XXX /**/
+          RDB_F_BURNED)};
+
+  // asset_id ➡️ count
+  static auto constexpr kAssetHoldersCount{FMT_STRING(
+      RDB_ROOT /**/ RDB_WSV /**/ RDB_ASSET_SUPPLY /**/ RDB_XXX /**/
+          RDB_F_HOLDERS)};
+
+  // proposal_id ➡️ expiration height|enacted|votes weight|setting key
+  static auto constexpr kProposal{
+      FMT_STRING(RDB_ROOT /**/ RDB_WSV /**/ RDB_PROPOSALS /**/ RDB_XXX)};
+
+  // proposal_id ➡️ setting value
+  static auto constexpr kProposalSettingValue{
+      FMT_STRING(RDB_ROOT /**/ RDB_WSV /**/ RDB_PROPOSALS /**/ RDB_XXX /**/
+                     RDB_F_SETTING_VALUE)};
+
+  // proposal_id/voter_id ➡️ weight
+  static auto constexpr kProposalVote{
+      FMT_STRING(RDB_ROOT /**/ RDB_WSV /**/ RDB_PROPOSALS /**/ RDB_XXX /**/
+                     RDB_XXX)};
+
   /**
    * ######################################
    * ############## FILES #################
@@ -454,6 +627,10 @@ namespace iroha::ametsuchi::fmtstrings {
   // domain_id ➡️ default role
   static auto constexpr kDomain{FMT_STRING(RDB_PATH_DOMAIN)};
 
+  // domain_id ➡️ ""
+  static auto constexpr kDomainArchived{
+      FMT_STRING(RDB_PATH_DOMAIN /**/ RDB_OPTIONS /**/ RDB_F_ARCHIVED)};
+
   // "" ➡️ next_call_id
   static auto constexpr kEngineNextCallId{
       FMT_STRING(RDB_ROOT /**/ RDB_WSV /**/ RDB_EVM_STORAGE /**/
@@ -477,6 +654,10 @@ namespace iroha::ametsuchi::fmtstrings {
   static auto constexpr kAccountAssetSize{
       FMT_STRING(RDB_PATH_ACCOUNT /**/ RDB_OPTIONS /**/ RDB_F_ASSET_SIZE)};
 
+  // domain_id/account_name ➡️ ""
+  static auto constexpr kAccountDeactivated{
+      FMT_STRING(RDB_PATH_ACCOUNT /**/ RDB_OPTIONS /**/ RDB_F_DEACTIVATED)};
+
   static auto constexpr kPeersCount{
       FMT_STRING(RDB_ROOT /**/ RDB_WSV /**/ RDB_NETWORK /**/ RDB_PEERS /**/
                      RDB_F_PEERS_COUNT)};
@@ -541,9 +722,18 @@ namespace iroha::ametsuchi {
 
     ~RocksDBContext() {
       transaction.reset();
+      read_snapshot.reset();
       db_port.reset();
     }
 
+    /**
+     * Creates a context reading the state of the database committed at the
+     * moment of the call. Changes made afterwards, including the ones of a
+     * block being applied through this context, are not visible through it,
+     * and its reads do not wait for this context.
+     */
+    std::shared_ptr<RocksDBContext> makeReadView() const;
+
    private:
     friend class RocksDbCommon;
     friend struct RocksDBPort;
@@ -563,6 +753,9 @@ namespace iroha::ametsuchi {
     /// Database port
     std::shared_ptr<RocksDBPort> db_port;
 
+    /// Snapshot all the reads are made from, the latest state if null
+    std::unique_ptr<rocksdb::ManagedSnapshot> read_snapshot;
+
     /// Mutex to guard multithreaded access to this context
     std::recursive_mutex this_context_cs;
   };
@@ -742,6 +935,65 @@ namespace iroha::ametsuchi {
       }
     }
 
+    /**
+     * Creates a consistent snapshot of the database in a new directory. Table
+     * files are hard-linked when the directory is on the same filesystem, so
+     * the snapshot is cheap to take while the database is in use.
+     * @param path - directory for the snapshot, must not exist
+     */
+    expected::Result<void, DbError> createCheckpoint(std::string const &path) {
+      if (!transaction_db_)
+        return makeError<void>(DbErrorCode::kOperationFailed,
+                               "Db is not initialized.");
+
+      rocksdb::Checkpoint *checkpoint_ptr = nullptr;
+      auto status =
+          rocksdb::Checkpoint::Create(transaction_db_.get(), &checkpoint_ptr);
+      std::unique_ptr<rocksdb::Checkpoint> checkpoint(checkpoint_ptr);
+      if (status.ok())
+        status = checkpoint->CreateCheckpoint(path);
+
+      if (!status.ok())
+        return makeError<void>(
+            DbErrorCode::kOperationFailed,
+            "Checkpoint '{}' creation failed with status: {}.",
+            path,
+            status.ToString());
+      return {};
+    }
+
+    /**
+     * Writes the memtables of all column families to the table files and
+     * syncs the write-ahead log, so that nothing is replayed when the
+     * database is opened again.
+     */
+    expected::Result<void, DbError> flush() {
+      if (!transaction_db_)
+        return makeError<void>(DbErrorCode::kOperationFailed,
+                               "Db is not initialized.");
+
+      std::vector<rocksdb::ColumnFamilyHandle *> handles;
+      for (auto &cf : cf_handles)
+        if (nullptr != cf.handle)
+          handles.push_back(cf.handle);
+
+      auto status = transaction_db_->Flush(rocksdb::FlushOptions(), handles);
+      if (status.ok())
+        status = transaction_db_->FlushWAL(true);
+
+      if (!status.ok())
+        return makeError<void>(DbErrorCode::kOperationFailed,
+                               "Flush failed with status: {}.",
+                               status.ToString());
+      return {};
+    }
+
+    /// Takes a snapshot of the committed state of the database
+    std::unique_ptr<rocksdb::ManagedSnapshot> makeSnapshot() {
+      assert(transaction_db_);
+      return std::make_unique<rocksdb::ManagedSnapshot>(transaction_db_.get());
+    }
+
     std::optional<uint64_t> getPropUInt64(const rocksdb::Slice &property) {
       if (transaction_db_) {
         uint64_t value;
@@ -751,6 +1003,17 @@ namespace iroha::ametsuchi {
       return std::nullopt;
     }
 
+    std::optional<uint64_t> getPropUInt64(ColumnFamilyType type,
+                                          const rocksdb::Slice &property) {
+      assert(type < ColumnFamilyType::kTotal);
+      uint64_t value;
+      if (transaction_db_ && cf_handles[type].handle
+          && transaction_db_->GetIntProperty(
+              cf_handles[type].handle, property, &value))
+        return value;
+      return std::nullopt;
+    }
+
    private:
     std::unique_ptr<rocksdb::TransactionDB> transaction_db_;
     std::optional<std::string> db_name_;
@@ -771,6 +1034,12 @@ namespace iroha::ametsuchi {
     }
   };
 
+  inline std::shared_ptr<RocksDBContext> RocksDBContext::makeReadView() const {
+    auto context = std::make_shared<RocksDBContext>(db_port);
+    context->read_snapshot = db_port->makeSnapshot();
+    return context;
+  }
+
 #define RDB_ERROR_CHECK(...)                   \
   if (auto _tmp_gen_var = (__VA_ARGS__);       \
       iroha::expected::hasError(_tmp_gen_var)) \
@@ -926,10 +1195,25 @@ namespace iroha::ametsuchi {
       return port()->getPropUInt64("rocksdb.block-cache-capacity");
     }
 
+    /// Estimated size of the data in the column family, in bytes
+    auto propGetLiveDataSize(RocksDBPort::ColumnFamilyType type) {
+      return port()->getPropUInt64(type, "rocksdb.estimate-live-data-size");
+    }
+
     auto reinit() {
       return port()->reinitDB();
     }
 
+    /// Makes a snapshot of the whole database in the given directory
+    auto createCheckpoint(std::string const &path) {
+      return port()->createCheckpoint(path);
+    }
+
+    /// Persists the memtables and the write-ahead log
+    auto flush() {
+      return port()->flush();
+    }
+
     /// Makes commit to DB
     auto commit() {
       rocksdb::Status status;
@@ -1037,6 +1321,8 @@ namespace iroha::ametsuchi {
 
       rocksdb::ReadOptions ro;
       ro.fill_cache = false;
+      if (tx_context_->read_snapshot)
+        ro.snapshot = tx_context_->read_snapshot->snapshot();
 
       auto status =
           transaction()->Get(ro, getHandle(cf_type), slice, &valueBuffer());
@@ -1096,6 +1382,8 @@ namespace iroha::ametsuchi {
 
       rocksdb::ReadOptions ro;
       ro.fill_cache = false;
+      if (tx_context_->read_snapshot)
+        ro.snapshot = tx_context_->read_snapshot->snapshot();
 
       std::unique_ptr<rocksdb::Iterator> it(
           transaction()->GetIterator(ro, getHandle(cf_type)));
@@ -1609,6 +1897,28 @@ namespace iroha::ametsuchi {
                                       account);
   }
 
+  /**
+   * Access to account deactivation flag file.
+   * @tparam kOp @see kDbOperation
+   * @tparam kSc @see kDbEntry
+   * @param common @see RocksDbCommon
+   * @param account name
+   * @param domain id
+   * @return operation result
+   */
+  template <kDbOperation kOp = kDbOperation::kGet,
+            kDbEntry kSc = kDbEntry::kMustExist>
+  inline expected::Result<std::optional<bool>, DbError> forAccountDeactivated(
+      RocksDbCommon &common,
+      std::string_view account,
+      std::string_view domain) {
+    return dbCall<bool, kOp, kSc>(common,
+                                  RocksDBPort::ColumnFamilyType::kWsv,
+                                  fmtstrings::kAccountDeactivated,
+                                  domain,
+                                  account);
+  }
+
   /**
    * Access to account's txs total count.
    * @tparam kOp @see kDbOperation
@@ -1693,6 +2003,28 @@ namespace iroha::ametsuchi {
         common, RocksDBPort::ColumnFamilyType::kWsv, fmtstrings::kRole, role);
   }
 
+  /**
+   * Access to the file marking that one role includes another
+   * @tparam kOp @see kDbOperation
+   * @tparam kSc @see kDbEntry
+   * @param common @see RocksDbCommon
+   * @param included_role id of the included role
+   * @param role id of the including role
+   * @return operation result
+   */
+  template <kDbOperation kOp = kDbOperation::kGet,
+            kDbEntry kSc = kDbEntry::kMustExist>
+  inline expected::Result<std::optional<bool>, DbError> forRoleIncludedBy(
+      RocksDbCommon &common,
+      std::string_view included_role,
+      std::string_view role) {
+    return dbCall<bool, kOp, kSc>(common,
+                                  RocksDBPort::ColumnFamilyType::kWsv,
+                                  fmtstrings::kRoleIncludedBy,
+                                  included_role,
+                                  role);
+  }
+
   /**
    * Access to peers and syncing peers count file
    * @tparam kOp @see kDbOperation
@@ -1981,6 +2313,261 @@ namespace iroha::ametsuchi {
         common, RocksDBPort::ColumnFamilyType::kWsv, fmtstrings::kSetting, key);
   }
 
+  /**
+   * Access to asset lock file
+   * @tparam kOp @see kDbOperation
+   * @tparam kSc @see kDbEntry
+   * @param common @see RocksDbCommon
+   * @param hash hex of the lock hash
+   * @return operation result
+   */
+  template <kDbOperation kOp = kDbOperation::kGet,
+            kDbEntry kSc = kDbEntry::kMustExist>
+  inline expected::Result<std::optional<std::string_view>, DbError>
+  forAssetLock(RocksDbCommon &common, std::string_view hash) {
+    return dbCall<std::string_view, kOp, kSc>(
+        common,
+        RocksDBPort::ColumnFamilyType::kWsv,
+        fmtstrings::kAssetLock,
+        hash);
+  }
+
+  /**
+   * Access to the accounts and the asset of the released asset lock
+   * @tparam kOp @see kDbOperation
+   * @tparam kSc @see kDbEntry
+   * @param common @see RocksDbCommon
+   * @param hash hex of the lock hash
+   * @return operation result
+   */
+  template <kDbOperation kOp = kDbOperation::kGet,
+            kDbEntry kSc = kDbEntry::kMustExist>
+  inline expected::Result<std::optional<std::string_view>, DbError>
+  forAssetLockRelease(RocksDbCommon &common, std::string_view hash) {
+    return dbCall<std::string_view, kOp, kSc>(
+        common,
+        RocksDBPort::ColumnFamilyType::kWsv,
+        fmtstrings::kAssetLockRelease,
+        hash);
+  }
+
+  /**
+   * Access to the burn fee charged by the transfer
+   * @tparam kOp @see kDbOperation
+   * @tparam kSc @see kDbEntry
+   * @param common @see RocksDbCommon
+   * @param tx_hash hex of the transaction hash
+   * @param cmd_index of the transfer inside the tx
+   * @return operation result
+   */
+  template <kDbOperation kOp = kDbOperation::kGet,
+            kDbEntry kSc = kDbEntry::kMustExist>
+  inline expected::Result<std::optional<std::string_view>, DbError>
+  forTransferFee(RocksDbCommon &common,
+                 std::string_view tx_hash,
+                 uint32_t cmd_index) {
+    return dbCall<std::string_view, kOp, kSc>(
+        common,
+        RocksDBPort::ColumnFamilyType::kWsv,
+        fmtstrings::kTransferFee,
+        tx_hash,
+        cmd_index);
+  }
+
+  /**
+   * Access to asset supply file. Locked assets are included in the supply.
+   * @tparam kOp @see kDbOperation
+   * @tparam kSc @see kDbEntry
+   * @param common @see RocksDbCommon
+   * @param asset_id full id of the asset
+   * @return operation result
+   */
+  template <kDbOperation kOp = kDbOperation::kGet,
+            kDbEntry kSc = kDbEntry::kCanExist>
+  inline expected::Result<std::optional<shared_model::interface::Amount>,
+                          DbError>
+  forAssetSupply(RocksDbCommon &common, std::string_view asset_id) {
+    return dbCall<shared_model::interface::Amount, kOp, kSc>(
+        common,
+        RocksDBPort::ColumnFamilyType::kWsv,
+        fmtstrings::kAssetSupply,
+        asset_id);
+  }
+
+  /**
+   * Read the supply of the asset. The supply of an asset which has no supply
+   * file yet is the sum of its balances and locked quantities, as it is
+   * computed by the PostgreSQL backend.
+   * @param common @see RocksDbCommon
+   * @param asset_id full id of the asset
+   * @param precision precision of the asset
+   * @return the supply of the asset
+   */
+  inline expected::Result<shared_model::interface::Amount, DbError>
+  getAssetSupply(RocksDbCommon &common,
+                 std::string_view asset_id,
+                 shared_model::interface::types::PrecisionType precision) {
+    RDB_TRY_GET_VALUE(
+        opt_supply,
+        forAssetSupply<kDbOperation::kGet, kDbEntry::kCanExist>(common,
+                                                                 asset_id));
+    if (opt_supply)
+      return std::move(*opt_supply);
+
+    shared_model::interface::Amount supply(precision);
+    auto status = enumerateKeysAndValues(
+        common,
+        [&](auto path, auto value) {
+          // domain_id/accounts/account_name/assets/asset_id
+          auto const &[_0, accounts, _2, assets, id, tail] =
+              staticSplitId<6>(path.ToStringView(), fmtstrings::kDelimiter);
+          if (accounts == RDB_ACCOUNTS and assets == RDB_ASSETS
+              and id == asset_id and tail.empty())
+            supply += shared_model::interface::Amount(value.ToStringView());
+          return true;
+        },
+        RocksDBPort::ColumnFamilyType::kWsv,
+        fmtstrings::kPathDomains);
+    RDB_ERROR_CHECK(canExist(
+        status, [&] { return fmt::format("Sum balances of {}", asset_id); }));
+
+    status = enumerateKeysAndValues(
+        common,
+        [&](auto, auto value) {
+          auto const &[_0, _1, lock_asset_id, amount, _4] =
+              staticSplitId<5>(value.ToStringView(), "|");
+          if (lock_asset_id == asset_id)
+            supply += shared_model::interface::Amount(amount);
+          return true;
+        },
+        RocksDBPort::ColumnFamilyType::kWsv,
+        fmtstrings::kPathAssetLocks);
+    RDB_ERROR_CHECK(canExist(
+        status, [&] { return fmt::format("Sum locks of {}", asset_id); }));
+
+    return supply;
+  }
+
+  /**
+   * Access to the total quantity of the asset added to accounts
+   * @tparam kOp @see kDbOperation
+   * @tparam kSc @see kDbEntry
+   * @param common @see RocksDbCommon
+   * @param asset_id full id of the asset
+   * @return operation result
+   */
+  template <kDbOperation kOp = kDbOperation::kGet,
+            kDbEntry kSc = kDbEntry::kCanExist>
+  inline expected::Result<std::optional<shared_model::interface::Amount>,
+                          DbError>
+  forAssetMinted(RocksDbCommon &common, std::string_view asset_id) {
+    return dbCall<shared_model::interface::Amount, kOp, kSc>(
+        common,
+        RocksDBPort::ColumnFamilyType::kWsv,
+        fmtstrings::kAssetMinted,
+        asset_id);
+  }
+
+  /**
+   * Access to the total quantity of the asset subtracted from accounts or
+   * burned by transfer fees
+   * @tparam kOp @see kDbOperation
+   * @tparam kSc @see kDbEntry
+   * @param common @see RocksDbCommon
+   * @param asset_id full id of the asset
+   * @return operation result
+   */
+  template <kDbOperation kOp = kDbOperation::kGet,
+            kDbEntry kSc = kDbEntry::kCanExist>
+  inline expected::Result<std::optional<shared_model::interface::Amount>,
+                          DbError>
+  forAssetBurned(RocksDbCommon &common, std::string_view asset_id) {
+    return dbCall<shared_model::interface::Amount, kOp, kSc>(
+        common,
+        RocksDBPort::ColumnFamilyType::kWsv,
+        fmtstrings::kAssetBurned,
+        asset_id);
+  }
+
+  /**
+   * Access to the number of accounts with positive balance of the asset
+   * @tparam kOp @see kDbOperation
+   * @tparam kSc @see kDbEntry
+   * @param common @see RocksDbCommon
+   * @param asset_id full id of the asset
+   * @return operation result
+   */
+  template <kDbOperation kOp = kDbOperation::kGet,
+            kDbEntry kSc = kDbEntry::kCanExist>
+  inline expected::Result<std::optional<uint64_t>, DbError>
+  forAssetHoldersCount(RocksDbCommon &common, std::string_view asset_id) {
+    return dbCall<uint64_t, kOp, kSc>(common,
+                                      RocksDBPort::ColumnFamilyType::kWsv,
+                                      fmtstrings::kAssetHoldersCount,
+                                      asset_id);
+  }
+
+  /**
+   * Access to governance proposal file
+   * @tparam kOp @see kDbOperation
+   * @tparam kSc @see kDbEntry
+   * @param common @see RocksDbCommon
+   * @param proposal_id id of the proposal
+   * @return operation result
+   */
+  template <kDbOperation kOp = kDbOperation::kGet,
+            kDbEntry kSc = kDbEntry::kMustExist>
+  inline expected::Result<std::optional<std::string_view>, DbError>
+  forProposal(RocksDbCommon &common, std::string_view proposal_id) {
+    return dbCall<std::string_view, kOp, kSc>(
+        common,
+        RocksDBPort::ColumnFamilyType::kWsv,
+        fmtstrings::kProposal,
+        proposal_id);
+  }
+
+  /**
+   * Access to the setting value of governance proposal
+   * @tparam kOp @see kDbOperation
+   * @tparam kSc @see kDbEntry
+   * @param common @see RocksDbCommon
+   * @param proposal_id id of the proposal
+   * @return operation result
+   */
+  template <kDbOperation kOp = kDbOperation::kGet,
+            kDbEntry kSc = kDbEntry::kMustExist>
+  inline expected::Result<std::optional<std::string_view>, DbError>
+  forProposalSettingValue(RocksDbCommon &common,
+                          std::string_view proposal_id) {
+    return dbCall<std::string_view, kOp, kSc>(
+        common,
+        RocksDBPort::ColumnFamilyType::kWsv,
+        fmtstrings::kProposalSettingValue,
+        proposal_id);
+  }
+
+  /**
+   * Access to the vote of an account for governance proposal
+   * @tparam kOp @see kDbOperation
+   * @tparam kSc @see kDbEntry
+   * @param common @see RocksDbCommon
+   * @param proposal_id id of the proposal
+   * @param voter_id id of the voting account
+   * @return operation result
+   */
+  template <kDbOperation kOp = kDbOperation::kGet,
+            kDbEntry kSc = kDbEntry::kMustExist>
+  inline expected::Result<std::optional<uint64_t>, DbError> forProposalVote(
+      RocksDbCommon &common,
+      std::string_view proposal_id,
+      std::string_view voter_id) {
+    return dbCall<uint64_t, kOp, kSc>(common,
+                                      RocksDBPort::ColumnFamilyType::kWsv,
+                                      fmtstrings::kProposalVote,
+                                      proposal_id,
+                                      voter_id);
+  }
+
   /**
    * Access to peer and syncing peer address file
    * @tparam kOp @see kDbOperation
@@ -2074,6 +2661,29 @@ namespace iroha::ametsuchi {
         common, RocksDBPort::ColumnFamilyType::kWsv, fmtstrings::kTopBlock);
   }
 
+  /**
+   * Height of the top block.
+   * @param common @see RocksDbCommon
+   * @return the height, 0 if no block is committed yet
+   */
+  inline expected::Result<uint64_t, DbError> forTopBlockHeight(
+      RocksDbCommon &common) {
+    RDB_TRY_GET_VALUE(
+        opt_top_block,
+        forTopBlockInfo<kDbOperation::kGet, kDbEntry::kCanExist>(common));
+    if (not opt_top_block)
+      return expected::makeValue(uint64_t{0});
+
+    auto const height_str = staticSplitId<2ull>(*opt_top_block).at(0);
+    uint64_t height;
+    auto [ptr, ec]{std::from_chars(
+        height_str.data(), height_str.data() + height_str.size(), height)};
+    if (ec != std::errc())
+      return makeError<uint64_t>(DbErrorCode::kInvalidStatus,
+                                 "Malformed top block info.");
+    return expected::makeValue(height);
+  }
+
   /**
    * Access to account role file
    * @tparam kOp @see kDbOperation
@@ -2154,6 +2764,137 @@ namespace iroha::ametsuchi {
                                   pubkey);
   }
 
+  /**
+   * Access to account retired signatory file, which keeps the height the key
+   * is valid until and the key which replaced it
+   * @tparam kOp @see kDbOperation
+   * @tparam kSc @see kDbEntry
+   * @param common @see RocksDbCommon
+   * @param domain id
+   * @param account name
+   * @param pubkey public key of the retired signatory
+   * @return operation result
+   */
+  template <kDbOperation kOp = kDbOperation::kGet,
+            kDbEntry kSc = kDbEntry::kMustExist>
+  inline expected::Result<std::optional<std::string_view>, DbError>
+  forRetiredSignatory(RocksDbCommon &common,
+                      std::string_view account,
+                      std::string_view domain,
+                      std::string_view pubkey) {
+    return dbCall<std::string_view, kOp, kSc>(
+        common,
+        RocksDBPort::ColumnFamilyType::kWsv,
+        fmtstrings::kRetiredSignatory,
+        domain,
+        account,
+        pubkey);
+  }
+
+  /**
+   * Access to account session key file
+   * @tparam kOp @see kDbOperation
+   * @tparam kSc @see kDbEntry
+   * @param common @see RocksDbCommon
+   * @param account name
+   * @param domain id
+   * @param pubkey session key
+   * @return operation result
+   */
+  template <kDbOperation kOp = kDbOperation::kGet,
+            kDbEntry kSc = kDbEntry::kMustExist>
+  inline expected::Result<std::optional<std::string_view>, DbError>
+  forSessionKey(RocksDbCommon &common,
+                std::string_view account,
+                std::string_view domain,
+                std::string_view pubkey) {
+    return dbCall<std::string_view, kOp, kSc>(
+        common,
+        RocksDBPort::ColumnFamilyType::kWsv,
+        fmtstrings::kSessionKey,
+        domain,
+        account,
+        pubkey);
+  }
+
+  /**
+   * Access to account vesting schedule file of the asset
+   * @tparam kOp @see kDbOperation
+   * @tparam kSc @see kDbEntry
+   * @param common @see RocksDbCommon
+   * @param account name
+   * @param domain id
+   * @param asset id
+   * @return operation result
+   */
+  template <kDbOperation kOp = kDbOperation::kGet,
+            kDbEntry kSc = kDbEntry::kMustExist>
+  inline expected::Result<std::optional<std::string_view>, DbError>
+  forAccountVesting(RocksDbCommon &common,
+                    std::string_view account,
+                    std::string_view domain,
+                    std::string_view asset) {
+    return dbCall<std::string_view, kOp, kSc>(
+        common,
+        RocksDBPort::ColumnFamilyType::kWsv,
+        fmtstrings::kAccountVesting,
+        domain,
+        account,
+        asset);
+  }
+
+  /**
+   * Access to idempotency key file of the account
+   * @tparam kOp @see kDbOperation
+   * @tparam kSc @see kDbEntry
+   * @param common @see RocksDbCommon
+   * @param account name
+   * @param domain id
+   * @param key idempotency key
+   * @return operation result
+   */
+  template <kDbOperation kOp = kDbOperation::kGet,
+            kDbEntry kSc = kDbEntry::kMustExist>
+  inline expected::Result<std::optional<std::string_view>, DbError>
+  forIdempotencyKey(RocksDbCommon &common,
+                    std::string_view account,
+                    std::string_view domain,
+                    std::string_view key) {
+    return dbCall<std::string_view, kOp, kSc>(
+        common,
+        RocksDBPort::ColumnFamilyType::kWsv,
+        fmtstrings::kIdempotencyKey,
+        domain,
+        account,
+        key);
+  }
+
+  /**
+   * Access to account spending limit file of the asset
+   * @tparam kOp @see kDbOperation
+   * @tparam kSc @see kDbEntry
+   * @param common @see RocksDbCommon
+   * @param account name
+   * @param domain id
+   * @param asset id
+   * @return operation result
+   */
+  template <kDbOperation kOp = kDbOperation::kGet,
+            kDbEntry kSc = kDbEntry::kMustExist>
+  inline expected::Result<std::optional<std::string_view>, DbError>
+  forAccountSpendingLimit(RocksDbCommon &common,
+                          std::string_view account,
+                          std::string_view domain,
+                          std::string_view asset) {
+    return dbCall<std::string_view, kOp, kSc>(
+        common,
+        RocksDBPort::ColumnFamilyType::kWsv,
+        fmtstrings::kAccountSpendingLimit,
+        domain,
+        account,
+        asset);
+  }
+
   /**
    * Access to domain file
    * @tparam kOp @see kDbOperation
@@ -2173,6 +2914,24 @@ namespace iroha::ametsuchi {
         domain);
   }
 
+  /**
+   * Access to domain archivation flag file.
+   * @tparam kOp @see kDbOperation
+   * @tparam kSc @see kDbEntry
+   * @param common @see RocksDbCommon
+   * @param domain id
+   * @return operation result
+   */
+  template <kDbOperation kOp = kDbOperation::kGet,
+            kDbEntry kSc = kDbEntry::kMustExist>
+  inline expected::Result<std::optional<bool>, DbError> forDomainArchived(
+      RocksDbCommon &common, std::string_view domain) {
+    return dbCall<bool, kOp, kSc>(common,
+                                  RocksDBPort::ColumnFamilyType::kWsv,
+                                  fmtstrings::kDomainArchived,
+                                  domain);
+  }
+
   /**
    * Access to account size file
    * @tparam kOp @see kDbOperation
@@ -92,6 +92,27 @@ void RocksDBIndexer::txPositions(
   forTxsTotalCount<kDbOperation::kPut>(common, account);
 }
 
+iroha::expected::Result<std::optional<Indexer::AssetLockParties>, std::string>
+RocksDBIndexer::assetLockRelease(std::string_view hash) {
+  RocksDbCommon common(db_context_);
+
+  std::string lock_hash;
+  toLowerAppend(hash, lock_hash);
+  auto result = forAssetLockRelease<kDbOperation::kGet, kDbEntry::kCanExist>(
+      common, lock_hash);
+  if (auto e = expected::resultToOptionalError(result))
+    return expected::makeError(std::move(e->description));
+  if (not result.assumeValue())
+    return std::optional<AssetLockParties>{};
+
+  auto const &[src_account_id, dest_account_id, asset_id] =
+      staticSplitId<3>(*result.assumeValue(), "|");
+  return std::optional<AssetLockParties>{
+      AssetLockParties{AccountIdType{src_account_id},
+                       AccountIdType{dest_account_id},
+                       AssetIdType{asset_id}}};
+}
+
 iroha::expected::Result<void, std::string> RocksDBIndexer::flush() {
   return {};
 }
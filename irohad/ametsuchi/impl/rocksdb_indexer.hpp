@@ -37,6 +37,9 @@ namespace iroha::ametsuchi {
         shared_model::interface::types::TimestampType const ts,
         TxPosition const &position) override;
 
+    iroha::expected::Result<std::optional<AssetLockParties>, std::string>
+    assetLockRelease(std::string_view hash) override;
+
     iroha::expected::Result<void, std::string> flush() override;
 
    private:
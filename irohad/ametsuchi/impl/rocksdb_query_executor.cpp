@@ -36,6 +36,8 @@ namespace iroha::ametsuchi {
     return validateSignaturesImpl(query);
   }
 
+  void RocksDbQueryExecutor::setTimeout(std::chrono::milliseconds) {}
+
   template <class Q>
   bool RocksDbQueryExecutor::validateSignaturesImpl(const Q &query) {
     auto const &[account, domain] = staticSplitId<2>(query.creatorAccountId());
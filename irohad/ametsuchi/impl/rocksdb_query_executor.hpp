@@ -34,6 +34,9 @@ namespace iroha::ametsuchi {
     bool validateSignatures(
         const shared_model::interface::BlocksQuery &query) override;
 
+    /// query timeouts are not supported by RocksDB backend, so it is ignored
+    void setTimeout(std::chrono::milliseconds timeout) override;
+
    private:
     template <class Q>
     bool validateSignaturesImpl(const Q &query);
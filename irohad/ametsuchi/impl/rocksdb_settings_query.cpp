@@ -22,7 +22,7 @@ namespace {
     RocksDbCommon common(db_context);
     auto status = common.get(RocksDBPort::ColumnFamilyType::kWsv,
                              fmtstrings::kSetting,
-                             kMaxDescriptionSizeKey);
+                             key);
 
     if (auto result = iroha::ametsuchi::canExist(
             status, [&] { return fmt::format("Max description size key"); });
@@ -37,6 +37,27 @@ namespace {
     return false;
   }
 
+  expected::Result<bool, std::string> getValueFromDb(
+      std::shared_ptr<RocksDBContext> db_context,
+      const shared_model::interface::types::SettingKeyType &key,
+      std::string &destination) {
+    RocksDbCommon common(db_context);
+    auto status = common.get(
+        RocksDBPort::ColumnFamilyType::kWsv, fmtstrings::kSetting, key);
+
+    if (auto result = iroha::ametsuchi::canExist(
+            status, [&] { return fmt::format("Setting {}", key); });
+        expected::hasError(result))
+      return expected::makeError(result.assumeError().description);
+
+    if (status.ok()) {
+      destination = common.valueBuffer();
+      return true;
+    }
+
+    return false;
+  }
+
 }  // namespace
 
 namespace iroha::ametsuchi {
@@ -70,6 +91,14 @@ namespace iroha::ametsuchi {
                  base->max_description_size);
     }
 
+    if (auto res = getValueFromDb(db_context_, kChainIdKey, base->chain_id);
+        expected::hasError(res))
+      return expected::makeError(res.assumeError());
+    else if (res.assumeValue())
+      log_->info("Updated value for " + kChainIdKey + ": {}", base->chain_id);
+    else
+      log_->info("Kept value for " + kChainIdKey + ": {}", base->chain_id);
+
     return base;
   }
 
@@ -9,9 +9,13 @@
 #include <rapidjson/stringbuffer.h>
 #include <rapidjson/writer.h>
 #include <rocksdb/utilities/transaction.h>
+#include "ametsuchi/blob_hash.hpp"
 #include "ametsuchi/block_storage.hpp"
+#include "ametsuchi/fee_estimation.hpp"
 #include "ametsuchi/impl/executor_common.hpp"
 #include "ametsuchi/impl/rocksdb_common.hpp"
+#include "ametsuchi/setting_registry.hpp"
+#include "ametsuchi/vesting_schedule.hpp"
 #include "backend/plain/account_detail_record_id.hpp"
 #include "backend/plain/engine_receipt.hpp"
 #include "backend/plain/peer.hpp"
@@ -20,22 +24,28 @@
 #include "common/to_lower.hpp"
 #include "interfaces/common_objects/amount.hpp"
 #include "interfaces/queries/asset_pagination_meta.hpp"
+#include "interfaces/queries/estimate_fee.hpp"
 #include "interfaces/queries/get_account.hpp"
 #include "interfaces/queries/get_account_asset_transactions.hpp"
 #include "interfaces/queries/get_account_assets.hpp"
 #include "interfaces/queries/get_account_detail.hpp"
+#include "interfaces/queries/get_account_permissions.hpp"
 #include "interfaces/queries/get_account_transactions.hpp"
 #include "interfaces/queries/get_asset_info.hpp"
+#include "interfaces/queries/get_asset_stats.hpp"
 #include "interfaces/queries/get_block.hpp"
 #include "interfaces/queries/get_engine_receipts.hpp"
 #include "interfaces/queries/get_peers.hpp"
 #include "interfaces/queries/get_pending_transactions.hpp"
 #include "interfaces/queries/get_role_permissions.hpp"
 #include "interfaces/queries/get_roles.hpp"
+#include "interfaces/queries/get_settings.hpp"
 #include "interfaces/queries/get_signatories.hpp"
 #include "interfaces/queries/get_transactions.hpp"
+#include "interfaces/queries/get_vesting_schedules.hpp"
 #include "interfaces/queries/query.hpp"
 #include "interfaces/queries/tx_pagination_meta.hpp"
+#include "interfaces/queries/verify_blob.hpp"
 #include "interfaces/transaction.hpp"
 #include "pending_txs_storage/pending_txs_storage.hpp"
 
@@ -227,6 +237,12 @@ operator()(
         + std::to_string(height);
   };
   auto block = block_store_.fetch(query.height());
+  if (auto first_retained = block_store_.firstRetainedHeight();
+      !block and isPrunedBlock(first_retained, query.height()))
+    return makeError<QueryExecutorResult>(
+        ErrorCodes::kBlockPruned,
+        "{}",
+        prunedBlockMessage(query.height(), *first_retained));
   if (!block)
     return makeError<QueryExecutorResult>(ErrorCodes::kFetchBlockFailed,
                                           "Block deserialization error: {}",
@@ -510,10 +526,30 @@ RocksDbSpecificQueryExecutor::readTxs(
                        query.accountId());
   }));
 
+  std::vector<shared_model::interface::TransactionsPageResponse::TransferFee>
+      transfer_fees;
+  if constexpr (readTxsWithAssets) {
+    for (auto const &tx : response_txs) {
+      auto const tx_hash = tx->hash().hex();
+      for (auto index : getAssetTransferIndices(*tx, query.assetId())) {
+        RDB_TRY_GET_VALUE(
+            opt_fee,
+            forTransferFee<kDbOperation::kGet, kDbEntry::kCanExist>(
+                common, tx_hash, index));
+        if (opt_fee)
+          transfer_fees.push_back(
+              {tx->hash(),
+               index,
+               shared_model::interface::Amount{std::string{*opt_fee}}});
+      }
+    }
+  }
+
   return query_response_factory->createTransactionsPageResponse(
       std::move(response_txs),
       next_page,
       opt_txs_total ? *opt_txs_total : 0ull,
+      std::move(transfer_fees),
       query_hash);
 }
 
@@ -1115,3 +1151,270 @@ operator()(
   return query_response_factory_->createEngineReceiptsResponse(records,
                                                                query_hash);
 }
+
+RocksDbSpecificQueryExecutor::ExecutionResult RocksDbSpecificQueryExecutor::
+operator()(
+    RocksDbCommon &common,
+    const shared_model::interface::GetSettings &query,
+    const shared_model::interface::types::AccountIdType &creator_id,
+    const shared_model::interface::types::HashType &query_hash,
+    shared_model::interface::RolePermissionSet const &creator_permissions) {
+  RDB_ERROR_CHECK(checkPermissions(creator_permissions, {Role::kGetSettings}));
+
+  shared_model::interface::SettingsMapType settings;
+  auto status = enumerateKeysAndValues(
+      common,
+      [&](auto key, auto value) {
+        settings.emplace(key.ToString(), value.ToString());
+        return true;
+      },
+      RocksDBPort::ColumnFamilyType::kWsv,
+      fmtstrings::kPathSettings);
+  RDB_ERROR_CHECK(
+      canExist(status, [&]() { return fmt::format("Enumerate settings"); }));
+
+  return query_response_factory_->createSettingsResponse(
+      std::move(settings), settingDescriptors(), query_hash);
+}
+
+RocksDbSpecificQueryExecutor::ExecutionResult RocksDbSpecificQueryExecutor::
+operator()(
+    RocksDbCommon &common,
+    const shared_model::interface::GetAssetStats &query,
+    const shared_model::interface::types::AccountIdType &creator_id,
+    const shared_model::interface::types::HashType &query_hash,
+    shared_model::interface::RolePermissionSet const &creator_permissions) {
+  RDB_ERROR_CHECK(checkPermissions(creator_permissions, {Role::kReadAssets}));
+  auto const &[asset_name, domain_id] = staticSplitId<2ull>(query.assetId());
+
+  auto precision = forAsset<kDbOperation::kGet, kDbEntry::kMustExist>(
+      common, asset_name, domain_id);
+  if (expected::hasError(precision))
+    return query_response_factory_->createErrorQueryResponse(
+        ErrorQueryType::kNoAsset,
+        fmt::format("Query: {}, message: {}",
+                    query.toString(),
+                    precision.assumeError().description),
+        precision.assumeError().code,
+        query_hash);
+  shared_model::interface::Amount const zero(
+      static_cast<shared_model::interface::types::PrecisionType>(
+          *precision.assumeValue()));
+
+  RDB_TRY_GET_VALUE(opt_minted,
+                    forAssetMinted<kDbOperation::kGet, kDbEntry::kCanExist>(
+                        common, query.assetId()));
+  RDB_TRY_GET_VALUE(opt_burned,
+                    forAssetBurned<kDbOperation::kGet, kDbEntry::kCanExist>(
+                        common, query.assetId()));
+  RDB_TRY_GET_VALUE(
+      supply,
+      getAssetSupply(
+          common,
+          query.assetId(),
+          static_cast<shared_model::interface::types::PrecisionType>(
+              *precision.assumeValue())));
+  RDB_TRY_GET_VALUE(
+      opt_holders_count,
+      forAssetHoldersCount<kDbOperation::kGet, kDbEntry::kCanExist>(
+          common, query.assetId()));
+
+  return query_response_factory_->createAssetStatsResponse(
+      query.assetId(),
+      opt_minted ? std::move(*opt_minted) : zero,
+      opt_burned ? std::move(*opt_burned) : zero,
+      std::move(supply),
+      opt_holders_count ? *opt_holders_count : 0ull,
+      query_hash);
+}
+
+RocksDbSpecificQueryExecutor::ExecutionResult RocksDbSpecificQueryExecutor::
+operator()(
+    RocksDbCommon &common,
+    const shared_model::interface::GetAccountPermissions &query,
+    const shared_model::interface::types::AccountIdType &creator_id,
+    const shared_model::interface::types::HashType &query_hash,
+    shared_model::interface::RolePermissionSet const &creator_permissions) {
+  auto const &[creator_account_name, creator_domain_id] =
+      staticSplitId<2ull>(creator_id);
+  auto const &[account_name, domain_id] =
+      staticSplitId<2ull>(query.accountId());
+
+  RDB_ERROR_CHECK(checkPermissions(domain_id,
+                                   creator_domain_id,
+                                   query.accountId(),
+                                   creator_id,
+                                   creator_permissions,
+                                   Role::kGetAllAccounts,
+                                   Role::kGetDomainAccounts,
+                                   Role::kGetMyAccount));
+
+  if (auto result = forAccount<kDbOperation::kCheck, kDbEntry::kMustExist>(
+          common, account_name, domain_id);
+      expected::hasError(result))
+    return query_response_factory_->createErrorQueryResponse(
+        ErrorQueryType::kNoAccount,
+        fmt::format("Query: {}, message: {}",
+                    query.toString(),
+                    result.assumeError().description),
+        result.assumeError().code,
+        query_hash);
+
+  RDB_TRY_GET_VALUE(permissions,
+                    accountPermissions(common, account_name, domain_id));
+
+  return query_response_factory_->createRolePermissionsResponse(
+      filterPermissions(permissions, query.nameFilter(), *perm_converter_),
+      query_hash);
+}
+
+RocksDbSpecificQueryExecutor::ExecutionResult RocksDbSpecificQueryExecutor::
+operator()(
+    RocksDbCommon &common,
+    const shared_model::interface::VerifyBlob &query,
+    const shared_model::interface::types::AccountIdType &creator_id,
+    const shared_model::interface::types::HashType &query_hash,
+    shared_model::interface::RolePermissionSet const &creator_permissions) {
+  auto const &[creator_account_name, creator_domain_id] =
+      staticSplitId<2ull>(creator_id);
+  auto const &[account_name, domain_id] =
+      staticSplitId<2ull>(query.accountId());
+
+  if (auto r = forAccount<kDbOperation::kCheck, kDbEntry::kMustExist>(
+          common, account_name, domain_id);
+      expected::hasError(r))
+    return query_response_factory_->createErrorQueryResponse(
+        ErrorQueryType::kNoAccount,
+        fmt::format("Query: {}, message: {}",
+                    query.toString(),
+                    r.assumeError().description),
+        r.assumeError().code,
+        query_hash);
+
+  RDB_ERROR_CHECK(checkPermissions(domain_id,
+                                   creator_domain_id,
+                                   query.accountId(),
+                                   creator_id,
+                                   creator_permissions,
+                                   Role::kGetAllAccDetail,
+                                   Role::kGetDomainAccDetail,
+                                   Role::kGetMyAccDetail));
+
+  RDB_TRY_GET_VALUE(
+      opt_stored_hash,
+      forAccountDetail<kDbOperation::kGet, kDbEntry::kCanExist>(
+          common, account_name, domain_id, query.writer(), query.key()));
+  if (not opt_stored_hash)
+    return query_response_factory_->createErrorQueryResponse(
+        ErrorQueryType::kNoAccountDetail,
+        fmt::format("Query: {}, message: no detail with the hash",
+                    query.toString()),
+        0,
+        query_hash);
+
+  std::string stored_hash;
+  toLowerAppend(*opt_stored_hash, stored_hash);
+  return query_response_factory_->createBlobVerificationResponse(
+      stored_hash == saltedBlobHash(query.salt(), query.blob()), query_hash);
+}
+
+RocksDbSpecificQueryExecutor::ExecutionResult RocksDbSpecificQueryExecutor::
+operator()(
+    RocksDbCommon &common,
+    const shared_model::interface::EstimateFee &query,
+    const shared_model::interface::types::AccountIdType &creator_id,
+    const shared_model::interface::types::HashType &query_hash,
+    shared_model::interface::RolePermissionSet const &creator_permissions) {
+  RDB_ERROR_CHECK(checkPermissions(creator_permissions, {Role::kTransfer}));
+
+  auto fees = estimateFees(
+      query.commands(),
+      [&common](auto const &asset_id)
+          -> expected::Result<std::optional<SupplyPolicy>, std::string> {
+        auto opt_value = forSettings<kDbOperation::kGet, kDbEntry::kCanExist>(
+            common, supplyPolicyKey(asset_id));
+        if (expected::hasError(opt_value)) {
+          return expected::makeError(
+              std::move(opt_value.assumeError().description));
+        }
+        if (not opt_value.assumeValue()) {
+          return expected::makeValue(std::optional<SupplyPolicy>{});
+        }
+        return SupplyPolicy::parse(*opt_value.assumeValue()) |
+            [](auto policy) {
+              return expected::makeValue(
+                  std::optional<SupplyPolicy>{std::move(policy)});
+            };
+      });
+  if (auto e = expected::resultToOptionalError(fees))
+    return query_response_factory_->createErrorQueryResponse(
+        ErrorQueryType::kStatefulFailed,
+        fmt::format("Query: {}, message: {}", query.toString(), *e),
+        1,
+        query_hash);
+
+  return query_response_factory_->createFeeEstimationResponse(
+      std::move(fees).assumeValue(), query_hash);
+}
+
+RocksDbSpecificQueryExecutor::ExecutionResult RocksDbSpecificQueryExecutor::
+operator()(
+    RocksDbCommon &common,
+    const shared_model::interface::GetVestingSchedules &query,
+    const shared_model::interface::types::AccountIdType &creator_id,
+    const shared_model::interface::types::HashType &query_hash,
+    shared_model::interface::RolePermissionSet const &creator_permissions) {
+  auto const &[creator_account_name, creator_domain_id] =
+      staticSplitId<2ull>(creator_id);
+  auto const &[account_name, domain_id] =
+      staticSplitId<2ull>(query.accountId());
+
+  RDB_ERROR_CHECK(checkPermissions(domain_id,
+                                   creator_domain_id,
+                                   query.accountId(),
+                                   creator_id,
+                                   creator_permissions,
+                                   Role::kGetAllAccAst,
+                                   Role::kGetDomainAccAst,
+                                   Role::kGetMyAccAst));
+
+  RDB_TRY_GET_VALUE(top_height, forTopBlockHeight(common));
+
+  std::vector<shared_model::interface::VestingSchedulesResponse::Schedule>
+      schedules;
+  std::optional<std::string> error;
+  auto status = enumerateKeysAndValues(
+      common,
+      [&](auto asset, auto value) {
+        auto schedule = VestingSchedule::decode(value.ToStringView());
+        if (auto e = expected::resultToOptionalError(schedule)) {
+          error = std::move(*e);
+          return false;
+        }
+        auto const &s = schedule.assumeValue();
+        schedules.push_back({std::string{asset.ToStringView()},
+                             s.amount,
+                             s.start_height,
+                             s.cliff,
+                             s.duration,
+                             s.vested(top_height + 1),
+                             s.locked(top_height + 1)});
+        return true;
+      },
+      RocksDBPort::ColumnFamilyType::kWsv,
+      fmtstrings::kPathAccountVesting,
+      domain_id,
+      account_name);
+  RDB_ERROR_CHECK(canExist(status, [&] {
+    return fmt::format("Account {} vesting schedules", query.accountId());
+  }));
+  if (error)
+    return query_response_factory_->createErrorQueryResponse(
+        ErrorQueryType::kStatefulFailed,
+        fmt::format("Query: {}, message: {}", query.toString(), *error),
+        1,
+        query_hash);
+
+  return query_response_factory_->createVestingSchedulesResponse(
+      std::move(schedules), query_hash);
+}
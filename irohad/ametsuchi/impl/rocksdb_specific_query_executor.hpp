@@ -33,6 +33,12 @@ namespace shared_model::interface {
   class GetPendingTransactions;
   class GetPeers;
   class GetEngineReceipts;
+  class GetSettings;
+  class GetAssetStats;
+  class GetAccountPermissions;
+  class VerifyBlob;
+  class EstimateFee;
+  class GetVestingSchedules;
   class PermissionToString;
 }  // namespace shared_model::interface
 
@@ -51,6 +57,7 @@ namespace iroha::ametsuchi {
       kFetchBlockFailed = 1,
       kQueryHeightOverflow = 3,
       kAssetNotFound = 4,
+      kBlockPruned = 4,
       kNoTransaction = 4,
       kGetReceipts = 5,
       kRetrieveTransactionsFailed = 1010,
@@ -172,6 +179,48 @@ namespace iroha::ametsuchi {
         const shared_model::interface::types::HashType &query_hash,
         shared_model::interface::RolePermissionSet const &creator_permissions);
 
+    ExecutionResult operator()(
+        RocksDbCommon &common,
+        const shared_model::interface::GetSettings &query,
+        const shared_model::interface::types::AccountIdType &creator_id,
+        const shared_model::interface::types::HashType &query_hash,
+        shared_model::interface::RolePermissionSet const &creator_permissions);
+
+    ExecutionResult operator()(
+        RocksDbCommon &common,
+        const shared_model::interface::GetAssetStats &query,
+        const shared_model::interface::types::AccountIdType &creator_id,
+        const shared_model::interface::types::HashType &query_hash,
+        shared_model::interface::RolePermissionSet const &creator_permissions);
+
+    ExecutionResult operator()(
+        RocksDbCommon &common,
+        const shared_model::interface::GetAccountPermissions &query,
+        const shared_model::interface::types::AccountIdType &creator_id,
+        const shared_model::interface::types::HashType &query_hash,
+        shared_model::interface::RolePermissionSet const &creator_permissions);
+
+    ExecutionResult operator()(
+        RocksDbCommon &common,
+        const shared_model::interface::VerifyBlob &query,
+        const shared_model::interface::types::AccountIdType &creator_id,
+        const shared_model::interface::types::HashType &query_hash,
+        shared_model::interface::RolePermissionSet const &creator_permissions);
+
+    ExecutionResult operator()(
+        RocksDbCommon &common,
+        const shared_model::interface::EstimateFee &query,
+        const shared_model::interface::types::AccountIdType &creator_id,
+        const shared_model::interface::types::HashType &query_hash,
+        shared_model::interface::RolePermissionSet const &creator_permissions);
+
+    ExecutionResult operator()(
+        RocksDbCommon &common,
+        const shared_model::interface::GetVestingSchedules &query,
+        const shared_model::interface::types::AccountIdType &creator_id,
+        const shared_model::interface::types::HashType &query_hash,
+        shared_model::interface::RolePermissionSet const &creator_permissions);
+
    private:
     mutable std::shared_ptr<RocksDBContext> db_context_;
     BlockStorage &block_store_;
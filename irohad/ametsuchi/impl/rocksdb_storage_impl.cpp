@@ -78,10 +78,12 @@ namespace iroha::ametsuchi {
       std::shared_ptr<shared_model::interface::QueryResponseFactory>
           response_factory) const {
     auto log_manager = logManager()->getChild("QueryExecutor");
+    // queries read the last committed state and do not wait for the block
+    // being applied
     return std::make_unique<RocksDbQueryExecutor>(
         response_factory,
         std::make_shared<RocksDbSpecificQueryExecutor>(
-            db_context_,
+            db_context_->makeReadView(),
             *blockStore(),
             std::move(pending_txs_storage),
             response_factory,
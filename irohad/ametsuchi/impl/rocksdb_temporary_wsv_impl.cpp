@@ -5,8 +5,11 @@
 
 #include "ametsuchi/impl/rocksdb_temporary_wsv_impl.hpp"
 
+#include <charconv>
+
 #include "ametsuchi/impl/rocksdb_command_executor.hpp"
 #include "ametsuchi/impl/rocksdb_common.hpp"
+#include "ametsuchi/session_key.hpp"
 #include "ametsuchi/tx_executor.hpp"
 #include "common/to_lower.hpp"
 #include "interfaces/commands/command.hpp"
@@ -42,6 +45,72 @@ namespace iroha::ametsuchi {
     else
       quorum = *result.assumeValue();
 
+    if (auto result =
+            forAccountDeactivated<kDbOperation::kCheck,
+                                  kDbEntry::kMustNotExist>(
+                common, account, domain);
+        expected::hasError(result))
+      return expected::makeError(validation::CommandError{
+          "signatures validation",
+          3,
+          "Transaction " + transaction.toString()
+              + " is created by deactivated account",
+          false});
+
+    if (auto result =
+            forDomainArchived<kDbOperation::kCheck, kDbEntry::kMustNotExist>(
+                common, domain);
+        expected::hasError(result))
+      return expected::makeError(validation::CommandError{
+          "signatures validation",
+          3,
+          "Transaction " + transaction.toString()
+              + " is created by account of archived domain",
+          false});
+
+    uint64_t top_height;
+    if (auto result = forTopBlockHeight(common); expected::hasError(result))
+      return expected::makeError(
+          validation::CommandError{"signatures validation",
+                                   1,
+                                   result.assumeError().description,
+                                   false});
+    else
+      top_height = result.assumeValue();
+
+    // retired keys mapped to their replacements and expiration heights
+    std::unordered_map<std::string, std::string> replaced_by;
+    std::unordered_map<std::string, uint64_t> retired_until;
+    auto const retired_status = enumerateKeysAndValues(
+        common,
+        [&](auto pubkey, auto value) {
+          auto const &[height_str, new_pk] =
+              staticSplitId<2>(value.ToStringView(), "|");
+          uint64_t height = 0;
+          std::from_chars(
+              height_str.data(), height_str.data() + height_str.size(), height);
+          retired_until.emplace(pubkey.ToStringView(), height);
+          replaced_by.emplace(pubkey.ToStringView(), new_pk);
+          return true;
+        },
+        RocksDBPort::ColumnFamilyType::kWsv,
+        fmtstrings::kPathRetiredSignatories,
+        domain,
+        account);
+    if (auto result = canExist(retired_status, [&] {
+          return fmt::format("Enumerate retired signatories for account {}",
+                             transaction.creatorAccountId());
+        });
+        expected::hasError(result))
+      return expected::makeError(
+          validation::CommandError{"signatures validation",
+                                   1,
+                                   result.assumeError().description,
+                                   false});
+
+    // only the signatories count towards the quorum
+    std::vector<std::string> signed_by;
+    bool signed_by_session_key = false;
     std::string pk;
     for (auto &signatory : transaction.signatures()) {
       pk.clear();
@@ -49,15 +118,48 @@ namespace iroha::ametsuchi {
       if (auto result =
               forSignatory<kDbOperation::kCheck, kDbEntry::kMustExist>(
                   common, account, domain, pk);
-          expected::hasError(result))
+          expected::hasError(result)) {
+        // rotated keys are accepted until the end of the grace period
+        if (auto it = retired_until.find(pk);
+            it != retired_until.end() and it->second > top_height) {
+          signed_by.push_back(pk);
+          continue;
+        }
+        // a session key authorizes the transactions within its scope alone
+        auto session_key =
+            forSessionKey<kDbOperation::kGet, kDbEntry::kCanExist>(
+                common, account, domain, pk);
+        if (expected::hasValue(session_key) and session_key.assumeValue()) {
+          auto key = SessionKey::decode(*session_key.assumeValue());
+          if (expected::hasError(key))
+            return expected::makeError(
+                validation::CommandError{"signatures validation",
+                                         1,
+                                         std::move(key).assumeError(),
+                                         false});
+          if (auto reason =
+                  key.assumeValue().checkSigning(transaction, top_height))
+            return expected::makeError(validation::CommandError{
+                "signatures validation",
+                4,
+                "Transaction " + transaction.toString()
+                    + " is signed by session key " + pk + " of account "
+                    + transaction.creatorAccountId() + ", but " + *reason,
+                false});
+          signed_by_session_key = true;
+          continue;
+        }
         return expected::makeError(
             validation::CommandError{"signatures validation",
                                      1,
                                      result.assumeError().description,
                                      false});
+      }
+      signed_by.push_back(pk);
     }
 
-    if (boost::size(transaction.signatures()) < quorum) {
+    if (not signed_by_session_key
+        and countSignatories(signed_by, replaced_by) < quorum) {
       auto error_str = "Transaction " + transaction.toString()
           + " failed signatures validation";
       return expected::makeError(validation::CommandError{
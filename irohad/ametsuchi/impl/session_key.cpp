@@ -0,0 +1,99 @@
+/**
+ * Copyright Soramitsu Co., Ltd. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+#include "ametsuchi/session_key.hpp"
+
+#include <algorithm>
+#include <charconv>
+
+#include <fmt/core.h>
+#include "ametsuchi/impl/executor_common.hpp"
+#include "common/visitor.hpp"
+#include "interfaces/commands/add_asset_quantity.hpp"
+#include "interfaces/commands/command.hpp"
+#include "interfaces/commands/lock_asset.hpp"
+#include "interfaces/commands/set_account_detail.hpp"
+#include "interfaces/commands/subtract_asset_quantity.hpp"
+#include "interfaces/commands/transfer_asset.hpp"
+#include "interfaces/transaction.hpp"
+
+using iroha::ametsuchi::SessionKey;
+using shared_model::interface::RolePermissionSet;
+using shared_model::interface::permissions::Role;
+
+bool SessionKey::allows(const shared_model::interface::Command &command) const {
+  using namespace shared_model::interface;
+  auto spends = [this](Role permission, auto const &asset_id) {
+    return permissions.isSet(permission)
+        and (allowed_asset_id.empty() or allowed_asset_id == asset_id);
+  };
+  return iroha::visit_in_place(
+      command.get(),
+      [&](TransferAsset const &c) {
+        return spends(Role::kTransfer, c.assetId());
+      },
+      [&](LockAsset const &c) { return spends(Role::kTransfer, c.assetId()); },
+      [&](AddAssetQuantity const &c) {
+        return spends(Role::kAddAssetQty, c.assetId());
+      },
+      [&](SubtractAssetQuantity const &c) {
+        return spends(Role::kSubtractAssetQty, c.assetId());
+      },
+      [&](SetAccountDetail const &) {
+        return permissions.isSet(Role::kSetDetail);
+      },
+      [](auto const &) { return false; });
+}
+
+bool SessionKey::allows(
+    const shared_model::interface::Transaction &transaction) const {
+  auto commands = transaction.commands();
+  return std::all_of(commands.begin(),
+                     commands.end(),
+                     [this](auto const &command) { return allows(command); });
+}
+
+std::optional<std::string> SessionKey::checkSigning(
+    const shared_model::interface::Transaction &transaction,
+    shared_model::interface::types::HeightType top_height) const {
+  if (expires_height <= top_height) {
+    return fmt::format("the session key expired at height {}",
+                       expires_height);
+  }
+  size_t index = 0;
+  for (auto const &command : transaction.commands()) {
+    if (not allows(command)) {
+      return fmt::format(
+          "command {} is out of the scope of the session key", index);
+    }
+    ++index;
+  }
+  return std::nullopt;
+}
+
+std::string SessionKey::encode() const {
+  return fmt::format(
+      "{}|{}|{}", expires_height, permissions.toBitstring(), allowed_asset_id);
+}
+
+iroha::expected::Result<SessionKey, std::string> SessionKey::decode(
+    std::string_view value) {
+  auto const &[expires_height, permissions, allowed_asset_id] =
+      iroha::ametsuchi::staticSplitId<3>(value, "|");
+  SessionKey key{{}, std::string{allowed_asset_id}, 0};
+  auto const end = expires_height.data() + expires_height.size();
+  auto [ptr, ec] =
+      std::from_chars(expires_height.data(), end, key.expires_height);
+  if (ec != std::errc() or ptr != end
+      or permissions.size() != RolePermissionSet::size()
+      or not std::all_of(permissions.begin(),
+                         permissions.end(),
+                         [](char c) { return c == '0' or c == '1'; })) {
+    return iroha::expected::makeError(
+        fmt::format("Malformed session key {}", value));
+  }
+  key.permissions = RolePermissionSet{permissions};
+  return iroha::expected::makeValue(std::move(key));
+}
@@ -0,0 +1,134 @@
+/**
+ * Copyright Soramitsu Co., Ltd. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+#include "ametsuchi/setting_registry.hpp"
+
+#include <charconv>
+
+#include <fmt/core.h>
+#include "ametsuchi/account_detail_limits.hpp"
+#include "ametsuchi/domain_policy.hpp"
+#include "ametsuchi/governance_policy.hpp"
+#include "ametsuchi/idempotency_key.hpp"
+#include "ametsuchi/setting_query.hpp"
+#include "ametsuchi/supply_policy.hpp"
+#include "ametsuchi/transfer_policy.hpp"
+#include "validators/field_validator.hpp"
+#include "validators/settings.hpp"
+
+using iroha::ametsuchi::SettingDescriptor;
+using Type = SettingDescriptor::Type;
+
+namespace {
+
+  std::vector<SettingDescriptor> makeSettingDescriptors() {
+    using namespace iroha::ametsuchi;
+    return {
+        {kMaxDescriptionSizeKey,
+         Type::kUnsigned,
+         std::to_string(shared_model::validation::kDefaultDescriptionSize),
+         0,
+         // longer descriptions are rejected by stateless validation anyway
+         shared_model::validation::FieldValidator::kMaxDescriptionSize},
+        {kChainIdKey, Type::kString, "", std::nullopt, std::nullopt},
+        // zero limits would forbid account details at all
+        {kMaxAccountDetailKeysKey, Type::kUnsigned, "", 1, std::nullopt},
+        {kMaxAccountDetailValueSizeKey, Type::kUnsigned, "", 1, std::nullopt},
+        {kMaxAccountDetailDepthKey, Type::kUnsigned, "", 1, std::nullopt},
+        {kGovernancePolicyKey, Type::kPolicy, "", std::nullopt, std::nullopt},
+        {kIdempotencyWindowKey,
+         Type::kUnsigned,
+         std::to_string(kDefaultIdempotencyWindow),
+         0,
+         kMaxIdempotencyWindow},
+        {transferPolicyKey(""), Type::kPolicy, "", std::nullopt, std::nullopt},
+        {supplyPolicyKey(""), Type::kPolicy, "", std::nullopt, std::nullopt},
+        {domainPolicyKey(""), Type::kPolicy, "", std::nullopt, std::nullopt},
+    };
+  }
+
+  bool isPrefix(const SettingDescriptor &descriptor) {
+    return not descriptor.key.empty() and descriptor.key.back() == ':';
+  }
+
+  std::optional<std::string> checkBounds(const SettingDescriptor &descriptor,
+                                         uint64_t value,
+                                         const char *what) {
+    if ((descriptor.min and value < *descriptor.min)
+        or (descriptor.max and value > *descriptor.max)) {
+      return fmt::format("{} of setting {} must be within [{}, {}], got {}",
+                         what,
+                         descriptor.key,
+                         descriptor.min.value_or(0),
+                         descriptor.max ? std::to_string(*descriptor.max)
+                                        : std::string{"unbounded"},
+                         value);
+    }
+    return std::nullopt;
+  }
+
+  std::optional<std::string> checkPolicy(
+      const shared_model::interface::types::SettingKeyType &key,
+      const shared_model::interface::types::SettingValueType &value) {
+    using namespace iroha::ametsuchi;
+    if (isTransferPolicyKey(key))
+      return iroha::expected::resultToOptionalError(
+          TransferPolicy::parse(value));
+    if (isSupplyPolicyKey(key))
+      return iroha::expected::resultToOptionalError(SupplyPolicy::parse(value));
+    if (isDomainPolicyKey(key))
+      return iroha::expected::resultToOptionalError(DomainPolicy::parse(value));
+    if (key == kGovernancePolicyKey)
+      return iroha::expected::resultToOptionalError(
+          GovernancePolicy::parse(value));
+    return std::nullopt;
+  }
+
+}  // namespace
+
+const std::vector<SettingDescriptor> &
+iroha::ametsuchi::settingDescriptors() {
+  static const std::vector<SettingDescriptor> descriptors =
+      makeSettingDescriptors();
+  return descriptors;
+}
+
+const SettingDescriptor *iroha::ametsuchi::findSettingDescriptor(
+    const shared_model::interface::types::SettingKeyType &key) {
+  for (const auto &descriptor : settingDescriptors()) {
+    if (descriptor.key == key
+        or (isPrefix(descriptor) and key.rfind(descriptor.key, 0) == 0)) {
+      return &descriptor;
+    }
+  }
+  return nullptr;
+}
+
+std::optional<std::string> iroha::ametsuchi::checkSettingValue(
+    const shared_model::interface::types::SettingKeyType &key,
+    const shared_model::interface::types::SettingValueType &value) {
+  auto const *descriptor = findSettingDescriptor(key);
+  if (not descriptor) {
+    return std::nullopt;
+  }
+  switch (descriptor->type) {
+    case Type::kUnsigned: {
+      uint64_t number;
+      auto [ptr, ec] =
+          std::from_chars(value.data(), value.data() + value.size(), number);
+      if (value.empty() or ec != std::errc()
+          or ptr != value.data() + value.size()) {
+        return fmt::format(
+            "Setting {} must be an unsigned integer, got `{}'", key, value);
+      }
+      return checkBounds(*descriptor, number, "Value");
+    }
+    case Type::kString:
+      return checkBounds(*descriptor, value.size(), "Length");
+    case Type::kPolicy:
+      return checkPolicy(key, value);
+  }
+  return std::nullopt;
+}
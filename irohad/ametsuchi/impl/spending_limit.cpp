@@ -0,0 +1,98 @@
+/**
+ * Copyright Soramitsu Co., Ltd. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+#include "ametsuchi/spending_limit.hpp"
+
+#include <algorithm>
+#include <charconv>
+
+#include <fmt/core.h>
+#include "ametsuchi/impl/executor_common.hpp"
+
+using iroha::ametsuchi::SpendingLimit;
+using shared_model::interface::Amount;
+using shared_model::interface::types::HeightType;
+
+namespace {
+  bool parseHeight(std::string_view value, HeightType &height) {
+    auto [ptr, ec] =
+        std::from_chars(value.data(), value.data() + value.size(), height);
+    return ec == std::errc() and ptr == value.data() + value.size();
+  }
+}  // namespace
+
+Amount SpendingLimit::spent(HeightType height) const {
+  Amount spent{amount.precision()};
+  for (auto const &spending : spendings) {
+    if (spending.height + window > height) {
+      spent += spending.amount;
+    }
+  }
+  return spent;
+}
+
+bool SpendingLimit::allows(HeightType height, Amount const &amount) const {
+  auto rest = this->amount;
+  rest -= spent(height);
+  rest -= amount;
+  return not rest.isNaN();
+}
+
+void SpendingLimit::spend(HeightType height, Amount const &amount) {
+  spendings.erase(std::remove_if(spendings.begin(),
+                                 spendings.end(),
+                                 [&](auto const &spending) {
+                                   return spending.height + window <= height;
+                                 }),
+                  spendings.end());
+  if (not spendings.empty() and spendings.back().height == height) {
+    spendings.back().amount += amount;
+  } else {
+    spendings.push_back(Spending{height, amount});
+  }
+}
+
+std::string SpendingLimit::encodeSpendings() const {
+  std::string result;
+  for (auto const &spending : spendings) {
+    if (not result.empty()) {
+      result += ',';
+    }
+    result += fmt::format(
+        "{}:{}", spending.height, spending.amount.toStringRepr());
+  }
+  return result;
+}
+
+std::string SpendingLimit::encode() const {
+  return fmt::format(
+      "{}|{}|{}", amount.toStringRepr(), window, encodeSpendings());
+}
+
+iroha::expected::Result<SpendingLimit, std::string> SpendingLimit::decode(
+    std::string_view value) {
+  auto const malformed = [&] {
+    return iroha::expected::makeError(
+        fmt::format("Malformed spending limit {}", value));
+  };
+  auto const &[amount, window, spendings] =
+      iroha::ametsuchi::staticSplitId<3>(value, "|");
+  SpendingLimit limit{Amount{amount}, 0, {}};
+  if (limit.amount.isNaN() or not parseHeight(window, limit.window)) {
+    return malformed();
+  }
+  for (std::string_view rest = spendings; not rest.empty();) {
+    auto const end = std::min(rest.find(','), rest.size());
+    auto const &[height, spent] =
+        iroha::ametsuchi::staticSplitId<2>(rest.substr(0, end), ":");
+    Spending spending{0, Amount{spent}};
+    if (spending.amount.isNaN() or not parseHeight(height, spending.height)) {
+      return malformed();
+    }
+    limit.spendings.push_back(std::move(spending));
+    rest.remove_prefix(std::min(end + 1, rest.size()));
+  }
+  return iroha::expected::makeValue(std::move(limit));
+}
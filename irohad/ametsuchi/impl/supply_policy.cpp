@@ -0,0 +1,113 @@
+/**
+ * Copyright Soramitsu Co., Ltd. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+#include "ametsuchi/supply_policy.hpp"
+
+#include <algorithm>
+
+#include <boost/multiprecision/cpp_int.hpp>
+#include <fmt/core.h>
+#include <rapidjson/document.h>
+#include <rapidjson/error/en.h>
+
+using iroha::ametsuchi::SupplyPolicy;
+using shared_model::interface::Amount;
+
+namespace {
+  const std::string kSupplyPolicyKeyPrefix = "SupplyPolicy:";
+
+  constexpr uint32_t kBasisPointsInWhole = 10000;
+}  // namespace
+
+iroha::expected::Result<SupplyPolicy, std::string> SupplyPolicy::parse(
+    std::string_view json) {
+  rapidjson::Document document;
+  document.Parse(json.data(), json.size());
+  if (document.HasParseError()) {
+    return iroha::expected::makeError(
+        fmt::format("Malformed supply policy: {}",
+                    rapidjson::GetParseError_En(document.GetParseError())));
+  }
+  if (not document.IsObject()) {
+    return iroha::expected::makeError(
+        std::string{"Supply policy must be an object"});
+  }
+
+  SupplyPolicy policy;
+  if (auto it = document.FindMember("max_supply");
+      it != document.MemberEnd()) {
+    if (not it->value.IsString()) {
+      return iroha::expected::makeError(
+          std::string{"`max_supply' must be a decimal string"});
+    }
+    Amount max_supply{
+        std::string_view{it->value.GetString(), it->value.GetStringLength()}};
+    if (max_supply.toStringRepr()[0] == 'N') {
+      return iroha::expected::makeError(
+          fmt::format("`max_supply' is not a valid amount: {}",
+                      it->value.GetString()));
+    }
+    policy.max_supply = std::move(max_supply);
+  }
+  if (auto it = document.FindMember("decreasing_only");
+      it != document.MemberEnd()) {
+    if (not it->value.IsBool()) {
+      return iroha::expected::makeError(
+          std::string{"`decreasing_only' must be a boolean"});
+    }
+    policy.decreasing_only = it->value.GetBool();
+  }
+  if (auto it = document.FindMember("burn_fee_basis_points");
+      it != document.MemberEnd()) {
+    if (not it->value.IsUint() or it->value.GetUint() > kBasisPointsInWhole) {
+      return iroha::expected::makeError(
+          fmt::format("`burn_fee_basis_points' must be an integer from 0 to {}",
+                      kBasisPointsInWhole));
+    }
+    policy.burn_fee_basis_points = it->value.GetUint();
+  }
+  return iroha::expected::makeValue(std::move(policy));
+}
+
+Amount SupplyPolicy::burnFee(const Amount &amount) const {
+  auto const precision = amount.precision();
+  std::string digits = amount.toStringRepr();
+  digits.erase(std::remove(digits.begin(), digits.end(), '.'), digits.end());
+
+  boost::multiprecision::cpp_int fee{digits};
+  fee *= burn_fee_basis_points;
+  fee /= kBasisPointsInWhole;
+
+  auto fee_digits = fee.str();
+  if (fee_digits.size() <= precision) {
+    fee_digits.insert(0, precision + 1 - fee_digits.size(), '0');
+  }
+  if (precision > 0) {
+    fee_digits.insert(fee_digits.size() - precision, 1, '.');
+  }
+  return Amount{fee_digits};
+}
+
+bool SupplyPolicy::allowsSupply(const Amount &supply) const {
+  if (not max_supply) {
+    return true;
+  }
+  // amounts of greater precision cannot be subtracted from the cap directly
+  Amount rest{std::max(supply.precision(), max_supply->precision())};
+  rest += *max_supply;
+  rest -= supply;
+  return rest.toStringRepr()[0] != 'N';
+}
+
+shared_model::interface::types::SettingKeyType
+iroha::ametsuchi::supplyPolicyKey(
+    const shared_model::interface::types::AssetIdType &asset_id) {
+  return kSupplyPolicyKeyPrefix + asset_id;
+}
+
+bool iroha::ametsuchi::isSupplyPolicyKey(
+    const shared_model::interface::types::SettingKeyType &key) {
+  return key.rfind(kSupplyPolicyKeyPrefix, 0) == 0;
+}
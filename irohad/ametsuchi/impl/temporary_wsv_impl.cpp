@@ -5,6 +5,8 @@
 
 #include "ametsuchi/impl/temporary_wsv_impl.hpp"
 
+#include <unordered_set>
+
 #include "ametsuchi/impl/postgres_command_executor.hpp"
 #include "ametsuchi/tx_executor.hpp"
 #include "interfaces/commands/command.hpp"
@@ -67,6 +69,26 @@ namespace iroha::ametsuchi {
     return tx_;
   }
 
+  size_t TemporaryWsvImpl::countSignatories(
+      std::vector<std::string> const &keys,
+      std::unordered_map<std::string, std::string> const &replaced_by) {
+    std::unordered_set<std::string> signatories;
+    for (auto const &key : keys) {
+      // follow the rotations to the current key, a chain can not be longer
+      // than the number of retired keys
+      auto const *current = &key;
+      for (size_t step = 0; step < replaced_by.size(); ++step) {
+        auto it = replaced_by.find(*current);
+        if (it == replaced_by.end()) {
+          break;
+        }
+        current = &it->second;
+      }
+      signatories.insert(*current);
+    }
+    return signatories.size();
+  }
+
   TemporaryWsvImpl::SavepointWrapperImpl::SavepointWrapperImpl(
       DatabaseTransaction &tx,
       std::string savepoint_name,
@@ -8,6 +8,9 @@
 
 #include "ametsuchi/temporary_wsv.hpp"
 
+#include <unordered_map>
+#include <vector>
+
 #include "ametsuchi/command_executor.hpp"
 #include "ametsuchi/impl/db_transaction.hpp"
 #include "logger/logger_fwd.hpp"
@@ -62,6 +65,18 @@ namespace iroha::ametsuchi {
     virtual expected::Result<void, validation::CommandError> validateSignatures(
         const shared_model::interface::Transaction &transaction) = 0;
 
+    /**
+     * Counts the signatories which signed the transaction. A key rotated
+     * during its grace period and the key which replaced it are counted once
+     * @param keys signatory and retired keys of the transaction signatures
+     * @param replaced_by retired keys of the account mapped to their
+     * replacements
+     * @return number of distinct signatories
+     */
+    static size_t countSignatories(
+        std::vector<std::string> const &keys,
+        std::unordered_map<std::string, std::string> const &replaced_by);
+
     DatabaseTransaction &tx_;
     std::unique_ptr<TransactionExecutor> transaction_executor_;
     logger::LoggerManagerTreePtr log_manager_;
@@ -0,0 +1,105 @@
+/**
+ * Copyright Soramitsu Co., Ltd. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+#include "ametsuchi/transfer_policy.hpp"
+
+#include <algorithm>
+
+#include <fmt/core.h>
+#include <rapidjson/document.h>
+#include <rapidjson/error/en.h>
+
+using iroha::ametsuchi::TransferPolicy;
+
+namespace {
+  const std::string kTransferPolicyKeyPrefix = "TransferPolicy:";
+
+  /// Load the optional array of strings
+  iroha::expected::Result<void, std::string> loadList(
+      const rapidjson::Value &object,
+      const char *name,
+      std::vector<std::string> &dest) {
+    auto it = object.FindMember(name);
+    if (it == object.MemberEnd()) {
+      return {};
+    }
+    if (not it->value.IsArray()) {
+      return fmt::format("`{}' must be an array of strings", name);
+    }
+    for (auto const &item : it->value.GetArray()) {
+      if (not item.IsString()) {
+        return fmt::format("`{}' must be an array of strings", name);
+      }
+      dest.emplace_back(item.GetString(), item.GetStringLength());
+    }
+    return {};
+  }
+}  // namespace
+
+const char *iroha::ametsuchi::kTransferPolicyKycVerified = "true";
+
+iroha::expected::Result<TransferPolicy, std::string> TransferPolicy::parse(
+    std::string_view json) {
+  rapidjson::Document document;
+  document.Parse(json.data(), json.size());
+  if (document.HasParseError()) {
+    return iroha::expected::makeError(
+        fmt::format("Malformed transfer policy: {}",
+                    rapidjson::GetParseError_En(document.GetParseError())));
+  }
+  if (not document.IsObject()) {
+    return iroha::expected::makeError(
+        std::string{"Transfer policy must be an object"});
+  }
+
+  TransferPolicy policy;
+  if (auto e = iroha::expected::resultToOptionalError(
+          loadList(document, "domains", policy.domains))) {
+    return iroha::expected::makeError(std::move(*e));
+  }
+  if (auto e = iroha::expected::resultToOptionalError(
+          loadList(document, "accounts", policy.accounts))) {
+    return iroha::expected::makeError(std::move(*e));
+  }
+  if (auto kyc = document.FindMember("kyc"); kyc != document.MemberEnd()) {
+    auto const &value = kyc->value;
+    if (not value.IsObject() or not value.HasMember("writer")
+        or not value["writer"].IsString() or not value.HasMember("key")
+        or not value["key"].IsString()) {
+      return iroha::expected::makeError(std::string{
+          "`kyc' must be an object with `writer' and `key' strings"});
+    }
+    policy.kyc = Kyc{value["writer"].GetString(), value["key"].GetString()};
+  }
+  return iroha::expected::makeValue(std::move(policy));
+}
+
+bool TransferPolicy::isWhitelisted(
+    const shared_model::interface::types::AccountIdType &account_id) const {
+  if (domains.empty() and accounts.empty()) {
+    return true;
+  }
+  if (std::find(accounts.begin(), accounts.end(), account_id)
+      != accounts.end()) {
+    return true;
+  }
+  auto const at = account_id.find('@');
+  if (at == std::string::npos) {
+    return false;
+  }
+  auto const domain = account_id.substr(at + 1);
+  return std::find(domains.begin(), domains.end(), domain) != domains.end();
+}
+
+shared_model::interface::types::SettingKeyType
+iroha::ametsuchi::transferPolicyKey(
+    const shared_model::interface::types::AssetIdType &asset_id) {
+  return kTransferPolicyKeyPrefix + asset_id;
+}
+
+bool iroha::ametsuchi::isTransferPolicyKey(
+    const shared_model::interface::types::SettingKeyType &key) {
+  return key.rfind(kTransferPolicyKeyPrefix, 0) == 0;
+}
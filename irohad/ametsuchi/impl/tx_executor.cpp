@@ -19,6 +19,21 @@ iroha::expected::Result<void, TxExecutionError> TransactionExecutor::execute(
     bool do_validation) const {
   const auto &hash = transaction.hash().hex();
   const auto &creator_account_id = transaction.creatorAccountId();
+  if (do_validation) {
+    if (auto error = iroha::expected::resultToOptionalError(
+            command_executor_->checkChainId(transaction))) {
+      return iroha::expected::makeError(
+          TxExecutionError{std::move(error.value()), 0});
+    }
+  }
+  if (not transaction.idempotencyKey().empty()) {
+    if (auto error = iroha::expected::resultToOptionalError(
+            command_executor_->recordIdempotencyKey(transaction,
+                                                    do_validation))) {
+      return iroha::expected::makeError(
+          TxExecutionError{std::move(error.value()), 0});
+    }
+  }
   size_t cmd_index = 0;
   for (const auto &cmd : transaction.commands()) {
     if (auto cmd_error =
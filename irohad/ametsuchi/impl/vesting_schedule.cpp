@@ -0,0 +1,81 @@
+/**
+ * Copyright Soramitsu Co., Ltd. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+#include "ametsuchi/vesting_schedule.hpp"
+
+#include <algorithm>
+#include <charconv>
+
+#include <boost/multiprecision/cpp_int.hpp>
+#include <fmt/core.h>
+#include "ametsuchi/impl/executor_common.hpp"
+
+using iroha::ametsuchi::VestingSchedule;
+using shared_model::interface::Amount;
+using shared_model::interface::types::HeightType;
+
+Amount VestingSchedule::locked(HeightType height) const {
+  auto const elapsed = height > start_height ? height - start_height : 0;
+  if (elapsed < cliff) {
+    return amount;
+  }
+
+  std::string digits = amount.toStringRepr();
+  digits.erase(std::remove(digits.begin(), digits.end(), '.'), digits.end());
+  boost::multiprecision::cpp_int const total{digits};
+  boost::multiprecision::cpp_int locked = 0;
+  if (elapsed < duration) {
+    locked = total - total * elapsed / duration;
+  }
+
+  auto const precision = amount.precision();
+  auto locked_digits = locked.str();
+  if (locked_digits.size() <= precision) {
+    locked_digits.insert(0, precision + 1 - locked_digits.size(), '0');
+  }
+  if (precision > 0) {
+    locked_digits.insert(locked_digits.size() - precision, 1, '.');
+  }
+  return Amount{locked_digits};
+}
+
+Amount VestingSchedule::vested(HeightType height) const {
+  Amount vested{amount};
+  vested -= locked(height);
+  return vested;
+}
+
+bool VestingSchedule::isVested(HeightType height) const {
+  return locked(height).sign() == 0;
+}
+
+std::string VestingSchedule::encode() const {
+  return fmt::format("{}|{}|{}|{}",
+                     amount.toStringRepr(),
+                     start_height,
+                     cliff,
+                     duration);
+}
+
+iroha::expected::Result<VestingSchedule, std::string> VestingSchedule::decode(
+    std::string_view value) {
+  auto const &[amount, start_height, cliff, duration] =
+      iroha::ametsuchi::staticSplitId<4>(value, "|");
+  Amount parsed_amount{amount};
+  HeightType heights[3];
+  bool valid = not parsed_amount.isNaN();
+  std::string_view const fields[] = {start_height, cliff, duration};
+  for (size_t i = 0; valid and i < 3; ++i) {
+    auto [ptr, ec] = std::from_chars(
+        fields[i].data(), fields[i].data() + fields[i].size(), heights[i]);
+    valid = ec == std::errc() and ptr == fields[i].data() + fields[i].size();
+  }
+  if (not valid) {
+    return iroha::expected::makeError(
+        fmt::format("Malformed vesting schedule {}", value));
+  }
+  return iroha::expected::makeValue(VestingSchedule{
+      std::move(parsed_amount), heights[0], heights[1], heights[2]});
+}
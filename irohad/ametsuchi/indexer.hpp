@@ -6,7 +6,9 @@
 #ifndef AMETSUCHI_INDEXER_HPP
 #define AMETSUCHI_INDEXER_HPP
 
+#include <optional>
 #include <string>
+#include <string_view>
 
 #include "common/result.hpp"
 #include "interfaces/common_objects/types.hpp"
@@ -29,6 +31,13 @@ namespace iroha {
         size_t index;  ///< the number of this transaction in the block
       };
 
+      /// Accounts and asset of an asset lock.
+      struct AssetLockParties {
+        shared_model::interface::types::AccountIdType src_account_id;
+        shared_model::interface::types::AccountIdType dest_account_id;
+        shared_model::interface::types::AssetIdType asset_id;
+      };
+
       /// Store a committed tx hash.
       virtual void committedTxHash(
           const TxPosition &position,
@@ -51,6 +60,17 @@ namespace iroha {
           shared_model::interface::types::TimestampType const ts,
           TxPosition const &position) = 0;
 
+      /**
+       * Get the accounts and the asset of the released asset lock. The
+       * release is stored in WSV when the claim or the refund is executed.
+       * @param hash - hex of the lock hash
+       * @return the release, nullopt if the lock was not released, or string
+       * Error on failure
+       */
+      virtual iroha::expected::Result<std::optional<AssetLockParties>,
+                                      std::string>
+      assetLockRelease(std::string_view hash) = 0;
+
       /**
        * Flush the indices to storage.
        * Makes the effects of new indices (that were created before this call)
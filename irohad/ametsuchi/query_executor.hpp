@@ -6,6 +6,7 @@
 #ifndef IROHA_QUERY_EXECUTOR_HPP
 #define IROHA_QUERY_EXECUTOR_HPP
 
+#include <chrono>
 #include <memory>
 
 namespace shared_model {
@@ -43,6 +44,13 @@ namespace iroha {
        */
       virtual bool validate(const shared_model::interface::BlocksQuery &query,
                             const bool validate_signatories) = 0;
+
+      /**
+       * Abort the following queries running longer than the timeout with
+       * the timeout error
+       * @param timeout - execution time limit of each query
+       */
+      virtual void setTimeout(std::chrono::milliseconds timeout) = 0;
     };
   }  // namespace ametsuchi
 }  // namespace iroha
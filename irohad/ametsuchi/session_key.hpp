@@ -0,0 +1,73 @@
+/**
+ * Copyright Soramitsu Co., Ltd. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+#ifndef IROHA_SESSION_KEY_HPP
+#define IROHA_SESSION_KEY_HPP
+
+#include <optional>
+#include <string>
+#include <string_view>
+
+#include "common/result.hpp"
+#include "interfaces/common_objects/types.hpp"
+#include "interfaces/permissions.hpp"
+
+namespace shared_model {
+  namespace interface {
+    class Command;
+    class Transaction;
+  }  // namespace interface
+}  // namespace shared_model
+
+namespace iroha {
+  namespace ametsuchi {
+
+    /**
+     * Key authorized by AuthorizeSessionKey to sign the transactions of an
+     * account, which consist only of the commands within its scope, until
+     * the expiration height. The key alone authorizes a transaction within
+     * its scope, regardless of the quorum of the account. A transaction
+     * with a command out of its scope is rejected, even if the signatories
+     * meet the quorum.
+     */
+    struct SessionKey {
+      /// permissions of the commands the key may sign
+      shared_model::interface::RolePermissionSet permissions;
+      /// the only asset the key may spend, any asset if empty
+      shared_model::interface::types::AssetIdType allowed_asset_id;
+      /// height of the first block in which the key is not accepted
+      shared_model::interface::types::HeightType expires_height;
+
+      /**
+       * Whether the command may be signed by the key. Only the commands
+       * spending assets and SetAccountDetail can be signed by a session key.
+       */
+      bool allows(const shared_model::interface::Command &command) const;
+
+      /// whether all the commands of the transaction may be signed by the key
+      bool allows(
+          const shared_model::interface::Transaction &transaction) const;
+
+      /**
+       * Check that the key may sign the transaction in the block following
+       * the given one
+       * @param top_height height of the last committed block
+       * @return the reason why the key may not sign the transaction
+       */
+      std::optional<std::string> checkSigning(
+          const shared_model::interface::Transaction &transaction,
+          shared_model::interface::types::HeightType top_height) const;
+
+      /// expires_height|permissions|allowed_asset_id, the RocksDB value
+      std::string encode() const;
+
+      static expected::Result<SessionKey, std::string> decode(
+          std::string_view value);
+    };
+
+  }  // namespace ametsuchi
+}  // namespace iroha
+
+#endif  // IROHA_SESSION_KEY_HPP
@@ -28,6 +28,7 @@ namespace iroha {
 
     extern const shared_model::interface::types::SettingKeyType
         kMaxDescriptionSizeKey;
+    extern const shared_model::interface::types::SettingKeyType kChainIdKey;
   }  // namespace ametsuchi
 }  // namespace iroha
 
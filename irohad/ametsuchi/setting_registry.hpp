@@ -0,0 +1,52 @@
+/**
+ * Copyright Soramitsu Co., Ltd. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+#ifndef IROHA_SETTING_REGISTRY_HPP
+#define IROHA_SETTING_REGISTRY_HPP
+
+#include <optional>
+#include <string>
+#include <vector>
+
+#include "interfaces/common_objects/types.hpp"
+#include "interfaces/query_responses/settings_response.hpp"
+
+namespace iroha {
+  namespace ametsuchi {
+
+    using SettingDescriptor =
+        shared_model::interface::SettingsResponse::SettingDescriptor;
+
+    /**
+     * Descriptors of all settings known to the ledger. Both executors check
+     * the values of SetSettingValue and ProposeSettingValue against them, and
+     * GetSettings returns them along with the values.
+     */
+    const std::vector<SettingDescriptor> &settingDescriptors();
+
+    /**
+     * Find the descriptor of the setting
+     * @param key - key of the setting, matched with the prefixes of the
+     * settings of an asset or a domain as well
+     * @return the descriptor or nullptr if the setting is unknown
+     */
+    const SettingDescriptor *findSettingDescriptor(
+        const shared_model::interface::types::SettingKeyType &key);
+
+    /**
+     * Check the value of a setting by its descriptor. Unknown settings are
+     * not checked, since clients may keep their own settings in the ledger.
+     * @param key - key of the setting
+     * @param value - value to check
+     * @return error message if the value is invalid
+     */
+    std::optional<std::string> checkSettingValue(
+        const shared_model::interface::types::SettingKeyType &key,
+        const shared_model::interface::types::SettingValueType &value);
+
+  }  // namespace ametsuchi
+}  // namespace iroha
+
+#endif  // IROHA_SETTING_REGISTRY_HPP
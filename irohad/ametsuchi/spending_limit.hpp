@@ -0,0 +1,65 @@
+/**
+ * Copyright Soramitsu Co., Ltd. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+#ifndef IROHA_SPENDING_LIMIT_HPP
+#define IROHA_SPENDING_LIMIT_HPP
+
+#include <string>
+#include <string_view>
+#include <vector>
+
+#include "common/result.hpp"
+#include "interfaces/common_objects/amount.hpp"
+#include "interfaces/common_objects/types.hpp"
+
+namespace iroha {
+  namespace ametsuchi {
+
+    /**
+     * Limit of an asset which an account may spend by TransferAsset and
+     * LockAsset within a rolling window of blocks, set by SetSpendingLimit.
+     * The spendings within the window are kept together with the limit, so
+     * changing the limit does not reset them.
+     */
+    struct SpendingLimit {
+      struct Spending {
+        shared_model::interface::types::HeightType height;
+        shared_model::interface::Amount amount;
+      };
+
+      /// maximal amount spent within the window
+      shared_model::interface::Amount amount;
+      /// number of the last blocks the spendings are counted in
+      shared_model::interface::types::HeightType window;
+      /// spendings by height, in ascending order of heights
+      std::vector<Spending> spendings;
+
+      /// amount spent within the window which ends at the height
+      shared_model::interface::Amount spent(
+          shared_model::interface::types::HeightType height) const;
+
+      /// whether the amount may be spent at the height within the limit
+      bool allows(shared_model::interface::types::HeightType height,
+                  shared_model::interface::Amount const &amount) const;
+
+      /// record the amount spent at the height and drop the spendings which
+      /// are out of the window
+      void spend(shared_model::interface::types::HeightType height,
+                 shared_model::interface::Amount const &amount);
+
+      /// encoded spendings, height:amount separated by commas
+      std::string encodeSpendings() const;
+
+      /// amount|window|spendings, the RocksDB value of the limit
+      std::string encode() const;
+
+      static expected::Result<SpendingLimit, std::string> decode(
+          std::string_view value);
+    };
+
+  }  // namespace ametsuchi
+}  // namespace iroha
+
+#endif  // IROHA_SPENDING_LIMIT_HPP
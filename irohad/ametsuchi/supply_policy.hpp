@@ -0,0 +1,59 @@
+/**
+ * Copyright Soramitsu Co., Ltd. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+#ifndef IROHA_SUPPLY_POLICY_HPP
+#define IROHA_SUPPLY_POLICY_HPP
+
+#include <cstdint>
+#include <optional>
+#include <string>
+#include <string_view>
+
+#include "common/result.hpp"
+#include "interfaces/common_objects/amount.hpp"
+#include "interfaces/common_objects/types.hpp"
+
+namespace iroha {
+  namespace ametsuchi {
+
+    /**
+     * Restrictions on the supply of an asset, set in the genesis block with
+     * SetSettingValue under supplyPolicyKey(asset_id). The value is JSON:
+     * {"max_supply": "1000000.00", "decreasing_only": false,
+     *  "burn_fee_basis_points": 25}
+     * All fields are optional.
+     */
+    struct SupplyPolicy {
+      /// cap on the total supply, including locked assets
+      std::optional<shared_model::interface::Amount> max_supply;
+      /// if set, AddAssetQuantity is not allowed after the genesis block
+      bool decreasing_only = false;
+      /// part of every TransferAsset amount which is burned instead of being
+      /// credited to the destination, in 1/100 of percent
+      uint32_t burn_fee_basis_points = 0;
+
+      static expected::Result<SupplyPolicy, std::string> parse(
+          std::string_view json);
+
+      /// fee burned on the transfer of the amount, rounded down
+      shared_model::interface::Amount burnFee(
+          const shared_model::interface::Amount &amount) const;
+
+      /// whether the supply after the increase is within the cap
+      bool allowsSupply(const shared_model::interface::Amount &supply) const;
+    };
+
+    /// setting key of the supply policy of the asset
+    shared_model::interface::types::SettingKeyType supplyPolicyKey(
+        const shared_model::interface::types::AssetIdType &asset_id);
+
+    /// whether the setting key belongs to a supply policy
+    bool isSupplyPolicyKey(
+        const shared_model::interface::types::SettingKeyType &key);
+
+  }  // namespace ametsuchi
+}  // namespace iroha
+
+#endif  // IROHA_SUPPLY_POLICY_HPP
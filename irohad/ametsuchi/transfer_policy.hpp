@@ -0,0 +1,64 @@
+/**
+ * Copyright Soramitsu Co., Ltd. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+#ifndef IROHA_TRANSFER_POLICY_HPP
+#define IROHA_TRANSFER_POLICY_HPP
+
+#include <optional>
+#include <string>
+#include <string_view>
+#include <vector>
+
+#include "common/result.hpp"
+#include "interfaces/common_objects/types.hpp"
+
+namespace iroha {
+  namespace ametsuchi {
+
+    /**
+     * Restrictions on transfers of an asset, set in the genesis block with
+     * SetSettingValue under transferPolicyKey(asset_id). The value is JSON:
+     * {"domains": ["bank"], "accounts": ["alice@other"],
+     *  "kyc": {"writer": "kyc@regulator", "key": "verified"}}
+     * All fields are optional.
+     */
+    struct TransferPolicy {
+      struct Kyc {
+        /// account which sets the detail
+        shared_model::interface::types::AccountIdType writer;
+        /// detail key, must be "true" for both parties
+        shared_model::interface::types::AccountDetailKeyType key;
+      };
+
+      /// if any of the lists is set, both parties must belong to one of the
+      /// domains or be one of the accounts
+      std::vector<shared_model::interface::types::DomainIdType> domains;
+      std::vector<shared_model::interface::types::AccountIdType> accounts;
+      std::optional<Kyc> kyc;
+
+      static expected::Result<TransferPolicy, std::string> parse(
+          std::string_view json);
+
+      /// whether the account may send or receive the asset by the lists
+      bool isWhitelisted(
+          const shared_model::interface::types::AccountIdType &account_id)
+          const;
+    };
+
+    /// setting key of the transfer policy of the asset
+    shared_model::interface::types::SettingKeyType transferPolicyKey(
+        const shared_model::interface::types::AssetIdType &asset_id);
+
+    /// whether the setting key belongs to a transfer policy
+    bool isTransferPolicyKey(
+        const shared_model::interface::types::SettingKeyType &key);
+
+    /// value of account detail marking a party as verified by the KYC writer
+    extern const char *kTransferPolicyKycVerified;
+
+  }  // namespace ametsuchi
+}  // namespace iroha
+
+#endif  // IROHA_TRANSFER_POLICY_HPP
@@ -0,0 +1,57 @@
+/**
+ * Copyright Soramitsu Co., Ltd. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+#ifndef IROHA_VESTING_SCHEDULE_HPP
+#define IROHA_VESTING_SCHEDULE_HPP
+
+#include <string>
+#include <string_view>
+
+#include "common/result.hpp"
+#include "interfaces/common_objects/amount.hpp"
+#include "interfaces/common_objects/types.hpp"
+
+namespace iroha {
+  namespace ametsuchi {
+
+    /**
+     * Schedule of the assets issued to an account by GrantVestedAsset. The
+     * whole amount is locked until the cliff, then it is released linearly
+     * until the end of the schedule. Heights are counted from the block with
+     * the grant. The account may not spend its balance below the locked
+     * amount.
+     */
+    struct VestingSchedule {
+      shared_model::interface::Amount amount;
+      /// height of the block with the grant
+      shared_model::interface::types::HeightType start_height;
+      /// blocks after the grant before the first release
+      shared_model::interface::types::HeightType cliff;
+      /// blocks after the grant until the whole amount is released
+      shared_model::interface::types::HeightType duration;
+
+      /// part of the amount which is not released at the height, the
+      /// released part is rounded down to the precision of the amount
+      shared_model::interface::Amount locked(
+          shared_model::interface::types::HeightType height) const;
+
+      /// part of the amount which is released at the height
+      shared_model::interface::Amount vested(
+          shared_model::interface::types::HeightType height) const;
+
+      /// whether the whole amount is released at the height
+      bool isVested(shared_model::interface::types::HeightType height) const;
+
+      /// amount|start height|cliff|duration, the RocksDB value of the schedule
+      std::string encode() const;
+
+      static expected::Result<VestingSchedule, std::string> decode(
+          std::string_view value);
+    };
+
+  }  // namespace ametsuchi
+}  // namespace iroha
+
+#endif  // IROHA_VESTING_SCHEDULE_HPP
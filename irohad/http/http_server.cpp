@@ -26,20 +26,21 @@ namespace iroha::network {
   std::optional<int> HttpRequestResponse::init() {
     if (0 == strcmp(request_info_->request_method, "GET")) {
       method_ = eMethodType::kGet;
+    } else if (0 == strcmp(request_info_->request_method, "POST")) {
+      method_ = eMethodType::kPost;
     }
 
     /**
-     * Uncomment for PUT, POST and DELETE processing.
+     * Uncomment for PUT and DELETE processing.
      */
     /* else if (0 == strcmp(request_info_->request_method, "PUT")) {
        method_ = eMethodType::kPut;
-     } else if (0 == strcmp(request_info_->request_method, "POST")) {
-       method_ = eMethodType::kPost;
      } else if (0 == strcmp(request_info_->request_method, "DELETE")) {
        method_ = eMethodType::kDelete;
      } */
     else {
-      mg_send_http_error(connection_, 405, "Only GET method supported");
+      mg_send_http_error(
+          connection_, 405, "Only GET and POST methods supported");
       return 405;
     }
     return std::nullopt;
@@ -55,11 +56,39 @@ namespace iroha::network {
     return true;
   }
 
+  bool HttpRequestResponse::setTextResponse(std::string_view data) {
+    if (!method_)
+      return false;
+
+    mg_send_http_ok(
+        connection_, "text/plain; charset=utf-8", (long long)data.size());
+    mg_write(connection_, data.data(), data.size());
+    return true;
+  }
+
   eMethodType HttpRequestResponse::getMethodType() const {
     assert(method_);
     return *method_;
   }
 
+  std::optional<std::string> HttpRequestResponse::getQueryParameter(
+      std::string const &name) const {
+    if (request_info_->query_string == nullptr) {
+      return std::nullopt;
+    }
+    std::string value(strlen(request_info_->query_string) + 1, '\0');
+    auto const size = mg_get_var(request_info_->query_string,
+                                 strlen(request_info_->query_string),
+                                 name.c_str(),
+                                 value.data(),
+                                 value.size());
+    if (size < 0) {
+      return std::nullopt;
+    }
+    value.resize(size);
+    return value;
+  }
+
   HttpServer::HttpServer(Options options, logger::LoggerPtr logger)
       : context_(nullptr),
         options_(std::move(options)),
@@ -9,6 +9,7 @@
 #include <functional>
 #include <list>
 #include <optional>
+#include <string>
 #include <string_view>
 #include <utility>
 #include <vector>
@@ -37,7 +38,12 @@ namespace iroha::network {
 
     bool setJsonResponse(std::string_view data);
 
+    bool setTextResponse(std::string_view data);
+
     eMethodType getMethodType() const;
+
+    /// Get a parameter of the request query string, if present.
+    std::optional<std::string> getQueryParameter(std::string const &name) const;
   };
 
   class HttpServer : utils::NoMove, utils::NoCopy {
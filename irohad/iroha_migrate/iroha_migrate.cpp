@@ -11,6 +11,8 @@
 #include <iostream>
 #include <set>
 
+#include "ametsuchi/impl/block_archive.hpp"
+#include "ametsuchi/impl/block_compression.hpp"
 #include "ametsuchi/impl/block_query_base.hpp"
 #include "ametsuchi/impl/flat_file/flat_file.hpp"
 #include "ametsuchi/impl/flat_file_block_storage.hpp"
@@ -71,6 +73,27 @@ DEFINE_bool(drop_state,
 DEFINE_string(export_to,
               "NOEXPORT",
               "Export block store to specified directory, default CWD");
+// NOLINTNEXTLINE
+DEFINE_uint32(compress_block_store,
+              0,
+              "Compress blocks under -block_store_path in place with the "
+              "specified zstd level and exit");
+// NOLINTNEXTLINE
+DEFINE_string(archive_export,
+              "",
+              "Export blocks under -block_store_path to the specified archive "
+              "file and exit");
+// NOLINTNEXTLINE
+DEFINE_string(archive_import,
+              "",
+              "Import blocks from the specified archive file to "
+              "-block_store_path and exit");
+// NOLINTNEXTLINE
+DEFINE_uint64(from_height, 1, "The first block to put in the archive");
+// NOLINTNEXTLINE
+DEFINE_uint64(to_height,
+              0,
+              "The last block to put in the archive, 0 means the top block");
 
 #define CHECK_RETURN(cond, ...)                                                \
   if (!(cond)) {                                                               \
@@ -232,7 +255,10 @@ makeStorage() {
                                initStorage(db_context_,
                                            pending_txs_storage_,
                                            query_response_factory_,
-                                           FLAGS_block_store_path,
+                                           // blocks are migrated into RocksDB
+                                           boost::none,
+                                           std::nullopt,
+                                           std::nullopt,
                                            vm_caller_ref,
                                            process_block,
                                            log_manager->getChild("Storage")));
@@ -281,6 +307,81 @@ expected::Result<void, std::string> restoreWsv() {
   return {};
 }
 
+int compress_blocks() {
+  CHECK_RETURN_FMT(
+      FLAGS_compress_block_store <= kMaxBlockCompressionLevel,
+      "-compress_block_store must be from 1 to {}",
+      kMaxBlockCompressionLevel);
+  CHECK_TRY_GET_VALUE(
+      flat_file,
+      FlatFile::create(FLAGS_block_store_path,
+                       log_manager->getChild("FlatFile")->getLogger()));
+  uint64_t count = 0;
+  for (auto block_id : flat_file->blockIdentifiers()) {
+    auto block = flat_file->get(block_id);
+    CHECK_RETURN_FMT(block, "Failed to read block {}", block_id);
+    if (isCompressedBlock(*block)) {
+      continue;
+    }
+    CHECK_TRY_GET_VALUE(compressed,
+                        compressBlock(*block, FLAGS_compress_block_store));
+    // make sure the block survives the round trip before overwriting it
+    CHECK_TRY_GET_VALUE(decompressed, decompressBlock(compressed));
+    CHECK_RETURN_FMT(decompressed == *block,
+                     "Compressed block {} does not match the original",
+                     block_id);
+    CHECK_RETURN_FMT(flat_file->replace(block_id, compressed),
+                     "Failed to write block {}",
+                     block_id);
+    ++count;
+  }
+  cout << "Compressed " << count << " blocks." << endl;
+  return 0;
+}
+
+int archive_blocks() {
+  fs::create_directories(FLAGS_block_store_path);
+  CHECK_TRY_GET_VALUE(
+      flat_file,
+      FlatFile::create(FLAGS_block_store_path,
+                       log_manager->getChild("FlatFile")->getLogger()));
+  auto converter =
+      std::make_shared<shared_model::proto::ProtoBlockJsonConverter>();
+  FlatFileBlockStorage block_storage(
+      std::move(flat_file),
+      converter,
+      log_manager->getChild("FlatFileBlockStorage")->getLogger());
+
+  if (not FLAGS_archive_export.empty()) {
+    auto to_height =
+        FLAGS_to_height == 0 ? block_storage.size() : FLAGS_to_height;
+    ofstream out(FLAGS_archive_export, std::ios::binary);
+    CHECK_RETURN_FMT(
+        out, "Failed to open archive file '{}'", FLAGS_archive_export);
+    auto result = exportBlockArchive(
+        block_storage, *converter, FLAGS_from_height, to_height, out);
+    CHECK_RETURN_FMT(iroha::expected::hasValue(result),
+                     "{}",
+                     result.assumeError());
+    fmt::print("Exported blocks {} to {} to '{}'.\n",
+               FLAGS_from_height,
+               to_height,
+               FLAGS_archive_export);
+    return 0;
+  }
+
+  ifstream in(FLAGS_archive_import, std::ios::binary);
+  CHECK_RETURN_FMT(
+      in, "Failed to open archive file '{}'", FLAGS_archive_import);
+  auto result = importBlockArchive(in, *converter, block_storage);
+  CHECK_RETURN_FMT(
+      iroha::expected::hasValue(result), "{}", result.assumeError());
+  fmt::print("Imported {} blocks. Run iroha_migrate without archive flags or "
+             "irohad with --drop_state to build WSV.\n",
+             result.assumeValue());
+  return 0;
+}
+
 std::shared_ptr<iroha::Subscription> subscription_manager;
 int main(int argc, char *argv[]) try {
   subscription_manager = iroha::getSubscription();
@@ -293,6 +394,18 @@ int main(int argc, char *argv[]) try {
   gflags::SetUsageMessage(
       "Migration tool builds WSV from block store to rocksdb.");
 
+  if (FLAGS_compress_block_store != 0) {
+    return compress_blocks();
+  }
+
+  if (not FLAGS_archive_export.empty() or not FLAGS_archive_import.empty()) {
+    CHECK_RETURN_FMT(
+        FLAGS_archive_export.empty() or FLAGS_archive_import.empty(),
+        "-archive_export and -archive_import cannot be used together",
+        "");
+    return archive_blocks();
+  }
+
   CHECK_RETURN_FMT(std::string(FLAGS_rocksdb_path).size() > 0,
                    "-rocksdb_path should be set.",
                    "");
@@ -0,0 +1,157 @@
+/**
+ * Copyright Soramitsu Co., Ltd. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+#include <chrono>
+#include <thread>
+
+#include <gflags/gflags.h>
+#include <soci/postgresql/soci-postgresql.h>
+#include <soci/soci.h>
+#include <boost/filesystem.hpp>
+#include "backend/protobuf/block.hpp"
+#include "backend/protobuf/queries/proto_query.hpp"
+#include "builders/protobuf/queries.hpp"
+#include "common/result.hpp"
+#include "crypto/keys_manager_impl.hpp"
+#include "datetime/time.hpp"
+#include "iroha_projection/projection.hpp"
+#include "logger/logger.hpp"
+#include "logger/logger_manager.hpp"
+#include "network/impl/channel_factory.hpp"
+#include "torii/query_client.hpp"
+
+// NOLINTNEXTLINE
+DEFINE_string(pg_opt,
+              "",
+              "Connection string of the projection database, e.g. "
+              "\"host=localhost port=5432 user=analyst password=secret "
+              "dbname=iroha_projection\"");
+// NOLINTNEXTLINE
+DEFINE_string(peer_ip, "127.0.0.1", "Address of the peer to read blocks from");
+// NOLINTNEXTLINE
+DEFINE_int32(torii_port, 50051, "Torii port of the peer");
+// NOLINTNEXTLINE
+DEFINE_string(account_name,
+              "",
+              "Account with can_get_blocks permission signing the queries");
+// NOLINTNEXTLINE
+DEFINE_string(key_path, ".", "Directory with the keypair of the account");
+// NOLINTNEXTLINE
+DEFINE_string(pass_phrase, "", "Pass phrase of the keypair");
+// NOLINTNEXTLINE
+DEFINE_uint32(poll_interval_ms,
+              1000,
+              "Delay before asking the peer for a block not committed yet");
+// NOLINTNEXTLINE
+DEFINE_bool(follow,
+            true,
+            "Keep applying new blocks, otherwise exit when the projection "
+            "reaches the top block");
+
+/// Error code of GetBlock for heights above the top block
+static constexpr uint32_t kHeightNotCommittedYet = 3;
+
+int main(int argc, char *argv[]) {
+  gflags::SetUsageMessage(
+      "Maintain a normalized SQL projection of the ledger in PostgreSQL");
+  gflags::ParseCommandLineFlags(&argc, &argv, true);
+  auto log_manager = std::make_shared<logger::LoggerManagerTree>(
+                         logger::LoggerConfig{logger::LogLevel::kInfo,
+                                              logger::getDefaultLogPatterns()})
+                         ->getChild("Projection");
+  auto log = log_manager->getChild("Main")->getLogger();
+
+  if (FLAGS_pg_opt.empty() or FLAGS_account_name.empty()) {
+    log->error("Specify the database with --pg_opt and the account with "
+               "--account_name");
+    return EXIT_FAILURE;
+  }
+
+  iroha::KeysManagerImpl keys_manager(
+      FLAGS_account_name,
+      boost::filesystem::path(FLAGS_key_path),
+      log_manager->getChild("KeysManager")->getLogger());
+  auto keypair = FLAGS_pass_phrase.empty()
+      ? keys_manager.loadKeys(boost::none)
+      : keys_manager.loadKeys(FLAGS_pass_phrase);
+  if (auto e = iroha::expected::resultToOptionalError(keypair)) {
+    log->error("Keypair error: {}", e.value());
+    return EXIT_FAILURE;
+  }
+
+  std::unique_ptr<soci::session> sql;
+  try {
+    sql = std::make_unique<soci::session>(*soci::factory_postgresql(),
+                                          FLAGS_pg_opt);
+  } catch (const std::exception &e) {
+    log->error("Failed to connect to the projection database: {}", e.what());
+    return EXIT_FAILURE;
+  }
+  iroha::projection::Projection projection(
+      *sql, log_manager->getChild("Projection")->getLogger());
+  if (auto e = iroha::expected::resultToOptionalError(projection.migrate())) {
+    log->error("{}", e.value());
+    return EXIT_FAILURE;
+  }
+
+  torii_utils::QuerySyncClient client(
+      iroha::network::createInsecureClient<
+          torii_utils::QuerySyncClient::Service>(
+          FLAGS_peer_ip, FLAGS_torii_port, std::nullopt));
+  uint64_t query_counter = 0;
+  auto const poll_interval =
+      std::chrono::milliseconds(FLAGS_poll_interval_ms);
+
+  while (true) {
+    auto height = projection.height();
+    if (auto e = iroha::expected::resultToOptionalError(height)) {
+      log->error("{}", e.value());
+      return EXIT_FAILURE;
+    }
+    auto const next_height = height.assumeValue() + 1;
+
+    auto query = shared_model::proto::QueryBuilder()
+                     .creatorAccountId(FLAGS_account_name)
+                     .createdTime(iroha::time::now())
+                     .queryCounter(++query_counter)
+                     .getBlock(next_height)
+                     .build()
+                     .signAndAddSignature(keypair.assumeValue())
+                     .finish();
+    iroha::protocol::QueryResponse response;
+    auto status = client.Find(query.getTransport(), response);
+    if (not status.ok()) {
+      log->warn("Failed to query {}:{}, retrying: {}",
+                FLAGS_peer_ip,
+                FLAGS_torii_port,
+                status.error_message());
+      std::this_thread::sleep_for(poll_interval);
+      continue;
+    }
+
+    if (response.has_block_response()) {
+      shared_model::proto::Block block(
+          response.block_response().block().block_v1());
+      if (auto e =
+              iroha::expected::resultToOptionalError(projection.apply(block))) {
+        log->error("{}", e.value());
+        return EXIT_FAILURE;
+      }
+      log->info("Applied block {}", next_height);
+      continue;
+    }
+
+    auto const &error = response.error_response();
+    if (error.error_code() != kHeightNotCommittedYet) {
+      log->error("Failed to get block {}: {}", next_height, error.message());
+      return EXIT_FAILURE;
+    }
+    if (not FLAGS_follow) {
+      log->info("Projection is at the top block {}", next_height - 1);
+      return EXIT_SUCCESS;
+    }
+    std::this_thread::sleep_for(poll_interval);
+  }
+}
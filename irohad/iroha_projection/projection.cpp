@@ -0,0 +1,540 @@
+/**
+ * Copyright Soramitsu Co., Ltd. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+#include "iroha_projection/projection.hpp"
+
+#include <stdexcept>
+#include <vector>
+
+#include <fmt/core.h>
+#include <soci/soci.h>
+#include "ametsuchi/asset_lock.hpp"
+#include "backend/protobuf/block.hpp"
+#include "backend/protobuf/transaction.hpp"
+#include "common/result.hpp"
+#include "common/to_lower.hpp"
+#include "cryptography/hash.hpp"
+#include "logger/logger.hpp"
+
+using iroha::projection::Projection;
+using shared_model::interface::types::HeightType;
+
+namespace {
+  /// Schema migrations, the version of a migration is its index plus one.
+  /// Applied migrations must never be changed, add a new one instead.
+  const std::vector<std::string> kMigrations{
+      R"(
+CREATE TABLE projection_cursor (
+    lock CHAR(1) DEFAULT 'X' NOT NULL PRIMARY KEY,
+    height bigint NOT NULL
+);
+INSERT INTO projection_cursor(height) VALUES (0);
+CREATE TABLE domains (
+    domain_id text PRIMARY KEY,
+    default_role text NOT NULL,
+    created_height bigint NOT NULL
+);
+CREATE TABLE accounts (
+    account_id text PRIMARY KEY,
+    domain_id text NOT NULL,
+    quorum int NOT NULL,
+    created_height bigint NOT NULL
+);
+CREATE TABLE account_signatories (
+    account_id text NOT NULL,
+    public_key text NOT NULL,
+    PRIMARY KEY (account_id, public_key)
+);
+CREATE TABLE assets (
+    asset_id text PRIMARY KEY,
+    domain_id text NOT NULL,
+    precision int NOT NULL,
+    created_height bigint NOT NULL
+);
+CREATE TABLE account_assets (
+    account_id text NOT NULL,
+    asset_id text NOT NULL,
+    balance numeric NOT NULL,
+    PRIMARY KEY (account_id, asset_id)
+);
+CREATE TABLE transactions (
+    hash text PRIMARY KEY,
+    height bigint NOT NULL,
+    creator_account_id text NOT NULL,
+    created_time bigint NOT NULL
+);
+CREATE INDEX transactions_creator_idx ON transactions(creator_account_id);
+CREATE TABLE transfers (
+    tx_hash text NOT NULL,
+    command_index int NOT NULL,
+    height bigint NOT NULL,
+    src_account_id text NOT NULL,
+    dest_account_id text NOT NULL,
+    asset_id text NOT NULL,
+    amount numeric NOT NULL,
+    description text NOT NULL,
+    PRIMARY KEY (tx_hash, command_index)
+);
+CREATE INDEX transfers_src_idx ON transfers(src_account_id);
+CREATE INDEX transfers_dest_idx ON transfers(dest_account_id);
+CREATE TABLE roles (
+    role_id text PRIMARY KEY
+);
+CREATE TABLE role_permissions (
+    role_id text NOT NULL,
+    permission text NOT NULL,
+    PRIMARY KEY (role_id, permission)
+);
+CREATE TABLE account_roles (
+    account_id text NOT NULL,
+    role_id text NOT NULL,
+    PRIMARY KEY (account_id, role_id)
+);
+CREATE TABLE grantable_permissions (
+    permittee_account_id text NOT NULL,
+    account_id text NOT NULL,
+    permission text NOT NULL,
+    PRIMARY KEY (permittee_account_id, account_id, permission)
+);
+)",
+      R"(
+ALTER TABLE accounts ADD COLUMN deactivated boolean NOT NULL DEFAULT false;
+)",
+      R"(
+CREATE TABLE asset_locks (
+    hash text PRIMARY KEY,
+    src_account_id text NOT NULL,
+    dest_account_id text NOT NULL,
+    asset_id text NOT NULL,
+    amount numeric NOT NULL,
+    timeout_height bigint NOT NULL
+);
+)",
+      R"(
+CREATE TABLE vesting_schedules (
+    account_id text NOT NULL,
+    asset_id text NOT NULL,
+    amount numeric NOT NULL,
+    start_height bigint NOT NULL,
+    cliff bigint NOT NULL,
+    duration bigint NOT NULL,
+    PRIMARY KEY (account_id, asset_id)
+);
+)",
+      R"(
+ALTER TABLE domains ADD COLUMN archived boolean NOT NULL DEFAULT false;
+)"};
+
+  /// Credit the account with the assets of the released lock
+  void releaseAssetLock(soci::session &sql,
+                        const std::string &hash,
+                        const char *account_column) {
+    sql << fmt::format(
+               "INSERT INTO account_assets(account_id, asset_id, balance) "
+               "SELECT {}, asset_id, amount FROM asset_locks "
+               "WHERE hash = :hash "
+               "ON CONFLICT (account_id, asset_id) DO UPDATE "
+               "SET balance = account_assets.balance + EXCLUDED.balance",
+               account_column),
+        soci::use(hash, "hash");
+    sql << "DELETE FROM asset_locks WHERE hash = :hash",
+        soci::use(hash, "hash");
+  }
+
+  /// Apply the effects of a committed command to the projection tables
+  void applyCommand(soci::session &sql,
+                    const iroha::protocol::Command &command,
+                    const std::string &creator,
+                    const std::string &tx_hash,
+                    int command_index,
+                    HeightType height) {
+    using iroha::protocol::Command;
+    switch (command.command_case()) {
+      case Command::kCreateDomain: {
+        auto const &cmd = command.create_domain();
+        sql << "INSERT INTO domains(domain_id, default_role, created_height) "
+               "VALUES (:domain_id, :default_role, :height)",
+            soci::use(cmd.domain_id(), "domain_id"),
+            soci::use(cmd.default_role(), "default_role"),
+            soci::use(height, "height");
+        break;
+      }
+      case Command::kArchiveDomain: {
+        auto const &cmd = command.archive_domain();
+        sql << "UPDATE domains SET archived = true "
+               "WHERE domain_id = :domain_id",
+            soci::use(cmd.domain_id(), "domain_id");
+        break;
+      }
+      case Command::kCreateAccount: {
+        auto const &cmd = command.create_account();
+        auto const account_id =
+            fmt::format("{}@{}", cmd.account_name(), cmd.domain_id());
+        sql << "INSERT INTO accounts(account_id, domain_id, quorum, "
+               "created_height) VALUES (:account_id, :domain_id, 1, :height)",
+            soci::use(account_id, "account_id"),
+            soci::use(cmd.domain_id(), "domain_id"),
+            soci::use(height, "height");
+        sql << "INSERT INTO account_signatories(account_id, public_key) "
+               "VALUES (:account_id, :public_key)",
+            soci::use(account_id, "account_id"),
+            soci::use(cmd.public_key(), "public_key");
+        // new accounts get the default role of the domain
+        sql << "INSERT INTO account_roles(account_id, role_id) "
+               "SELECT :account_id, default_role FROM domains "
+               "WHERE domain_id = :domain_id",
+            soci::use(account_id, "account_id"),
+            soci::use(cmd.domain_id(), "domain_id");
+        break;
+      }
+      case Command::kAddSignatory: {
+        auto const &cmd = command.add_signatory();
+        sql << "INSERT INTO account_signatories(account_id, public_key) "
+               "VALUES (:account_id, :public_key)",
+            soci::use(cmd.account_id(), "account_id"),
+            soci::use(cmd.public_key(), "public_key");
+        break;
+      }
+      case Command::kRemoveSignatory: {
+        auto const &cmd = command.remove_signatory();
+        sql << "DELETE FROM account_signatories "
+               "WHERE account_id = :account_id AND public_key = :public_key",
+            soci::use(cmd.account_id(), "account_id"),
+            soci::use(cmd.public_key(), "public_key");
+        break;
+      }
+      case Command::kRotateKey: {
+        auto const &cmd = command.rotate_key();
+        sql << "UPDATE account_signatories SET public_key = :new_public_key "
+               "WHERE account_id = :account_id "
+               "AND public_key = :old_public_key",
+            soci::use(cmd.new_public_key(), "new_public_key"),
+            soci::use(cmd.account_id(), "account_id"),
+            soci::use(cmd.old_public_key(), "old_public_key");
+        break;
+      }
+      case Command::kSetAccountQuorum: {
+        auto const &cmd = command.set_account_quorum();
+        int const quorum = cmd.quorum();
+        sql << "UPDATE accounts SET quorum = :quorum "
+               "WHERE account_id = :account_id",
+            soci::use(quorum, "quorum"),
+            soci::use(cmd.account_id(), "account_id");
+        break;
+      }
+      case Command::kDeactivateAccount: {
+        auto const &cmd = command.deactivate_account();
+        sql << "UPDATE accounts SET deactivated = true "
+               "WHERE account_id = :account_id",
+            soci::use(cmd.account_id(), "account_id");
+        break;
+      }
+      case Command::kRecoverAccount: {
+        auto const &cmd = command.recover_account();
+        sql << "DELETE FROM account_signatories "
+               "WHERE account_id = :account_id",
+            soci::use(cmd.account_id(), "account_id");
+        sql << "INSERT INTO account_signatories(account_id, public_key) "
+               "VALUES (:account_id, :public_key)",
+            soci::use(cmd.account_id(), "account_id"),
+            soci::use(cmd.public_key(), "public_key");
+        sql << "UPDATE accounts SET quorum = 1, deactivated = false "
+               "WHERE account_id = :account_id",
+            soci::use(cmd.account_id(), "account_id");
+        break;
+      }
+      case Command::kCreateAsset: {
+        auto const &cmd = command.create_asset();
+        auto const asset_id =
+            fmt::format("{}#{}", cmd.asset_name(), cmd.domain_id());
+        int const precision = cmd.precision();
+        sql << "INSERT INTO assets(asset_id, domain_id, precision, "
+               "created_height) VALUES (:asset_id, :domain_id, :precision, "
+               ":height)",
+            soci::use(asset_id, "asset_id"),
+            soci::use(cmd.domain_id(), "domain_id"),
+            soci::use(precision, "precision"),
+            soci::use(height, "height");
+        break;
+      }
+      case Command::kAddAssetQuantity: {
+        auto const &cmd = command.add_asset_quantity();
+        sql << "INSERT INTO account_assets(account_id, asset_id, balance) "
+               "VALUES (:account_id, :asset_id, CAST(:amount AS numeric)) "
+               "ON CONFLICT (account_id, asset_id) DO UPDATE "
+               "SET balance = account_assets.balance + EXCLUDED.balance",
+            soci::use(creator, "account_id"),
+            soci::use(cmd.asset_id(), "asset_id"),
+            soci::use(cmd.amount(), "amount");
+        break;
+      }
+      case Command::kGrantVestedAsset: {
+        auto const &cmd = command.grant_vested_asset();
+        HeightType const cliff = cmd.cliff();
+        HeightType const duration = cmd.duration();
+        sql << "INSERT INTO account_assets(account_id, asset_id, balance) "
+               "VALUES (:account_id, :asset_id, CAST(:amount AS numeric)) "
+               "ON CONFLICT (account_id, asset_id) DO UPDATE "
+               "SET balance = account_assets.balance + EXCLUDED.balance",
+            soci::use(cmd.dest_account_id(), "account_id"),
+            soci::use(cmd.asset_id(), "asset_id"),
+            soci::use(cmd.amount(), "amount");
+        sql << "INSERT INTO vesting_schedules(account_id, asset_id, amount, "
+               "start_height, cliff, duration) VALUES (:account_id, "
+               ":asset_id, CAST(:amount AS numeric), :height, :cliff, "
+               ":duration) "
+               "ON CONFLICT (account_id, asset_id) DO UPDATE "
+               "SET amount = EXCLUDED.amount, "
+               "start_height = EXCLUDED.start_height, "
+               "cliff = EXCLUDED.cliff, duration = EXCLUDED.duration",
+            soci::use(cmd.dest_account_id(), "account_id"),
+            soci::use(cmd.asset_id(), "asset_id"),
+            soci::use(cmd.amount(), "amount"),
+            soci::use(height, "height"), soci::use(cliff, "cliff"),
+            soci::use(duration, "duration");
+        break;
+      }
+      case Command::kSubtractAssetQuantity: {
+        auto const &cmd = command.subtract_asset_quantity();
+        sql << "UPDATE account_assets "
+               "SET balance = balance - CAST(:amount AS numeric) "
+               "WHERE account_id = :account_id AND asset_id = :asset_id",
+            soci::use(cmd.amount(), "amount"),
+            soci::use(creator, "account_id"),
+            soci::use(cmd.asset_id(), "asset_id");
+        break;
+      }
+      case Command::kTransferAsset: {
+        auto const &cmd = command.transfer_asset();
+        sql << "UPDATE account_assets "
+               "SET balance = balance - CAST(:amount AS numeric) "
+               "WHERE account_id = :account_id AND asset_id = :asset_id",
+            soci::use(cmd.amount(), "amount"),
+            soci::use(cmd.src_account_id(), "account_id"),
+            soci::use(cmd.asset_id(), "asset_id");
+        sql << "INSERT INTO account_assets(account_id, asset_id, balance) "
+               "VALUES (:account_id, :asset_id, CAST(:amount AS numeric)) "
+               "ON CONFLICT (account_id, asset_id) DO UPDATE "
+               "SET balance = account_assets.balance + EXCLUDED.balance",
+            soci::use(cmd.dest_account_id(), "account_id"),
+            soci::use(cmd.asset_id(), "asset_id"),
+            soci::use(cmd.amount(), "amount");
+        sql << "INSERT INTO transfers(tx_hash, command_index, height, "
+               "src_account_id, dest_account_id, asset_id, amount, "
+               "description) VALUES (:tx_hash, :command_index, :height, "
+               ":src_account_id, :dest_account_id, :asset_id, "
+               "CAST(:amount AS numeric), :description)",
+            soci::use(tx_hash, "tx_hash"),
+            soci::use(command_index, "command_index"),
+            soci::use(height, "height"),
+            soci::use(cmd.src_account_id(), "src_account_id"),
+            soci::use(cmd.dest_account_id(), "dest_account_id"),
+            soci::use(cmd.asset_id(), "asset_id"),
+            soci::use(cmd.amount(), "amount"),
+            soci::use(cmd.description(), "description");
+        break;
+      }
+      case Command::kLockAsset: {
+        auto const &cmd = command.lock_asset();
+        std::string hash;
+        iroha::toLowerAppend(cmd.hash(), hash);
+        HeightType const timeout_height = height + cmd.timeout();
+        sql << "UPDATE account_assets "
+               "SET balance = balance - CAST(:amount AS numeric) "
+               "WHERE account_id = :account_id AND asset_id = :asset_id",
+            soci::use(cmd.amount(), "amount"),
+            soci::use(cmd.src_account_id(), "account_id"),
+            soci::use(cmd.asset_id(), "asset_id");
+        sql << "INSERT INTO asset_locks(hash, src_account_id, "
+               "dest_account_id, asset_id, amount, timeout_height) "
+               "VALUES (:hash, :src_account_id, :dest_account_id, :asset_id, "
+               "CAST(:amount AS numeric), :timeout_height)",
+            soci::use(hash, "hash"),
+            soci::use(cmd.src_account_id(), "src_account_id"),
+            soci::use(cmd.dest_account_id(), "dest_account_id"),
+            soci::use(cmd.asset_id(), "asset_id"),
+            soci::use(cmd.amount(), "amount"),
+            soci::use(timeout_height, "timeout_height");
+        break;
+      }
+      case Command::kClaimWithPreimage: {
+        auto hash = iroha::ametsuchi::assetLockHash(
+            command.claim_with_preimage().preimage());
+        if (auto e = iroha::expected::resultToOptionalError(hash)) {
+          throw std::runtime_error(*e);
+        }
+        releaseAssetLock(sql, hash.assumeValue(), "dest_account_id");
+        break;
+      }
+      case Command::kRefundAfterTimeout: {
+        std::string hash;
+        iroha::toLowerAppend(command.refund_after_timeout().hash(), hash);
+        releaseAssetLock(sql, hash, "src_account_id");
+        break;
+      }
+      case Command::kCreateRole: {
+        auto const &cmd = command.create_role();
+        sql << "INSERT INTO roles(role_id) VALUES (:role_id)",
+            soci::use(cmd.role_name(), "role_id");
+        for (auto permission : cmd.permissions()) {
+          auto const name = iroha::protocol::RolePermission_Name(
+              static_cast<iroha::protocol::RolePermission>(permission));
+          sql << "INSERT INTO role_permissions(role_id, permission) "
+                 "VALUES (:role_id, :permission) ON CONFLICT DO NOTHING",
+              soci::use(cmd.role_name(), "role_id"),
+              soci::use(name, "permission");
+        }
+        break;
+      }
+      case Command::kAppendRole: {
+        auto const &cmd = command.append_role();
+        sql << "INSERT INTO account_roles(account_id, role_id) "
+               "VALUES (:account_id, :role_id) ON CONFLICT DO NOTHING",
+            soci::use(cmd.account_id(), "account_id"),
+            soci::use(cmd.role_name(), "role_id");
+        break;
+      }
+      case Command::kDetachRole: {
+        auto const &cmd = command.detach_role();
+        sql << "DELETE FROM account_roles "
+               "WHERE account_id = :account_id AND role_id = :role_id",
+            soci::use(cmd.account_id(), "account_id"),
+            soci::use(cmd.role_name(), "role_id");
+        break;
+      }
+      case Command::kGrantPermission: {
+        auto const &cmd = command.grant_permission();
+        auto const name =
+            iroha::protocol::GrantablePermission_Name(cmd.permission());
+        sql << "INSERT INTO grantable_permissions(permittee_account_id, "
+               "account_id, permission) VALUES (:permittee, :account_id, "
+               ":permission) ON CONFLICT DO NOTHING",
+            soci::use(cmd.account_id(), "permittee"),
+            soci::use(creator, "account_id"),
+            soci::use(name, "permission");
+        break;
+      }
+      case Command::kRevokePermission: {
+        auto const &cmd = command.revoke_permission();
+        auto const name =
+            iroha::protocol::GrantablePermission_Name(cmd.permission());
+        sql << "DELETE FROM grantable_permissions "
+               "WHERE permittee_account_id = :permittee "
+               "AND account_id = :account_id AND permission = :permission",
+            soci::use(cmd.account_id(), "permittee"),
+            soci::use(creator, "account_id"),
+            soci::use(name, "permission");
+        break;
+      }
+      default:
+        // peers, account details, settings and smart contracts are not
+        // projected
+        break;
+    }
+  }
+}  // namespace
+
+Projection::Projection(soci::session &sql, logger::LoggerPtr log)
+    : sql_(sql), log_(std::move(log)) {}
+
+size_t Projection::schemaVersion() {
+  return kMigrations.size();
+}
+
+iroha::expected::Result<void, std::string> Projection::migrate() {
+  try {
+    sql_ << "CREATE TABLE IF NOT EXISTS projection_migrations ("
+            "version int PRIMARY KEY, "
+            "applied_at timestamptz NOT NULL DEFAULT now())";
+    int version = 0;
+    soci::indicator ind;
+    sql_ << "SELECT max(version) FROM projection_migrations",
+        soci::into(version, ind);
+    if (ind == soci::i_null) {
+      version = 0;
+    }
+    if (static_cast<size_t>(version) > kMigrations.size()) {
+      return fmt::format(
+          "Projection schema version {} is newer than the supported {}",
+          version,
+          kMigrations.size());
+    }
+    for (auto next = static_cast<size_t>(version); next < kMigrations.size();
+         ++next) {
+      int const next_version = next + 1;
+      soci::transaction tx(sql_);
+      sql_ << kMigrations[next];
+      sql_ << "INSERT INTO projection_migrations(version) VALUES (:version)",
+          soci::use(next_version, "version");
+      tx.commit();
+      log_->info("Applied projection schema migration {}", next_version);
+    }
+  } catch (const std::exception &e) {
+    return fmt::format("Failed to migrate the projection schema: {}",
+                       e.what());
+  }
+  return {};
+}
+
+iroha::expected::Result<HeightType, std::string> Projection::height() {
+  try {
+    HeightType height = 0;
+    sql_ << "SELECT height FROM projection_cursor", soci::into(height);
+    return iroha::expected::makeValue(height);
+  } catch (const std::exception &e) {
+    return iroha::expected::makeError(
+        fmt::format("Failed to read the projection cursor: {}", e.what()));
+  }
+}
+
+iroha::expected::Result<void, std::string> Projection::apply(
+    const shared_model::interface::Block &block) {
+  auto const height = block.height();
+  try {
+    soci::transaction tx(sql_);
+    HeightType current = 0;
+    // the lock serializes concurrent projections of the same database
+    sql_ << "SELECT height FROM projection_cursor FOR UPDATE",
+        soci::into(current);
+    if (height <= current) {
+      log_->debug("Block {} is already applied", height);
+      return {};
+    }
+    if (height != current + 1) {
+      return fmt::format(
+          "Block {} does not follow the last applied block {}",
+          height,
+          current);
+    }
+    for (auto const &transaction : block.transactions()) {
+      auto const &transport =
+          static_cast<const shared_model::proto::Transaction &>(transaction)
+              .getTransport();
+      auto const &payload = transport.payload().reduced_payload();
+      auto const tx_hash = transaction.hash().hex();
+      auto const created_time = payload.created_time();
+      sql_ << "INSERT INTO transactions(hash, height, creator_account_id, "
+              "created_time) VALUES (:hash, :height, :creator, "
+              ":created_time)",
+          soci::use(tx_hash, "hash"), soci::use(height, "height"),
+          soci::use(payload.creator_account_id(), "creator"),
+          soci::use(created_time, "created_time");
+      for (int i = 0; i < payload.commands_size(); ++i) {
+        applyCommand(sql_,
+                     payload.commands(i),
+                     payload.creator_account_id(),
+                     tx_hash,
+                     i,
+                     height);
+      }
+    }
+    sql_ << "UPDATE projection_cursor SET height = :height",
+        soci::use(height, "height");
+    tx.commit();
+  } catch (const std::exception &e) {
+    return fmt::format("Failed to apply block {}: {}", height, e.what());
+  }
+  return {};
+}
@@ -0,0 +1,70 @@
+/**
+ * Copyright Soramitsu Co., Ltd. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+#ifndef IROHA_PROJECTION_PROJECTION_HPP
+#define IROHA_PROJECTION_PROJECTION_HPP
+
+#include <string>
+
+#include "common/result_fwd.hpp"
+#include "interfaces/common_objects/types.hpp"
+#include "logger/logger_fwd.hpp"
+
+namespace soci {
+  class session;
+}  // namespace soci
+
+namespace shared_model::interface {
+  class Block;
+}  // namespace shared_model::interface
+
+namespace iroha::projection {
+
+  /**
+   * Normalized SQL projection of the ledger for analysts: accounts,
+   * signatories, assets, balances, transfers, roles and permissions. The
+   * tables are maintained from committed blocks only, so they can live in a
+   * separate database and be queried without loading the peer.
+   *
+   * Each block is applied in one database transaction together with the
+   * projection cursor, so every block is applied exactly once even if the
+   * projection is interrupted.
+   */
+  class Projection {
+   public:
+    Projection(soci::session &sql, logger::LoggerPtr log);
+
+    /**
+     * Create the projection tables or upgrade them to the current schema
+     * version. Each migration is applied in its own transaction.
+     * @return error if a migration failed or the schema is newer than this
+     * version supports
+     */
+    iroha::expected::Result<void, std::string> migrate();
+
+    /// height of the last applied block, 0 if none
+    iroha::expected::Result<shared_model::interface::types::HeightType,
+                            std::string>
+    height();
+
+    /**
+     * Apply the block, ignored if it is already applied
+     * @return error if the block is not next to the last applied one or
+     * the database failed, in which case nothing is changed
+     */
+    iroha::expected::Result<void, std::string> apply(
+        const shared_model::interface::Block &block);
+
+    /// version of the projection schema created by migrate()
+    static size_t schemaVersion();
+
+   private:
+    soci::session &sql_;
+    logger::LoggerPtr log_;
+  };
+
+}  // namespace iroha::projection
+
+#endif  // IROHA_PROJECTION_PROJECTION_HPP
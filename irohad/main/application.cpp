@@ -10,6 +10,7 @@
 #include <rapidjson/stringbuffer.h>
 #include <rapidjson/writer.h>
 #include <boost/filesystem.hpp>
+#include <functional>
 #include <optional>
 
 #include "ametsuchi/impl/pool_wrapper.hpp"
@@ -27,33 +28,49 @@
 #include "backend/protobuf/proto_tx_status_factory.hpp"
 #include "common/bind.hpp"
 #include "common/files.hpp"
+#include "common/irohad_version.hpp"
 #include "common/result_try.hpp"
 #include "consensus/yac/consensus_outcome_type.hpp"
 #include "consensus/yac/consistency_model.hpp"
 #include "consensus/yac/supermajority_checker.hpp"
 #include "cryptography/crypto_provider/crypto_model_signer.hpp"
 #include "cryptography/default_hash_provider.hpp"
+#include "datetime/time.hpp"
 #include "generator/generator.hpp"
 #include "interfaces/common_objects/string_view_types.hpp"
 #include "interfaces/iroha_internal/transaction_batch_factory_impl.hpp"
 #include "interfaces/iroha_internal/transaction_batch_parser_impl.hpp"
 #include "logger/logger.hpp"
 #include "logger/logger_manager.hpp"
+#include "main/block_phase_stats.hpp"
 #include "main/impl/consensus_init.hpp"
 #include "main/impl/on_demand_ordering_init.hpp"
 #include "main/impl/pg_connection_init.hpp"
 #include "main/impl/rocksdb_connection_init.hpp"
 #include "main/impl/storage_init.hpp"
+#include "main/iroha_conf_literals.hpp"
 #include "main/iroha_status.hpp"
+#include "main/memory_budget.hpp"
+#include "main/reachability_check.hpp"
 #include "main/server_runner.hpp"
 #include "main/subscription.hpp"
+#include "maintenance/audit_log.hpp"
+#include "maintenance/chain_stats.hpp"
+#include "maintenance/event_sink.hpp"
+#include "maintenance/peer_identity.hpp"
+#include "maintenance/profiler.hpp"
 #include "network/impl/async_grpc_client.hpp"
+#include "network/impl/bandwidth_limiter.hpp"
 #include "network/impl/block_loader_impl.hpp"
 #include "network/impl/channel_factory.hpp"
+#include "network/impl/channel_factory_tls.hpp"
 #include "network/impl/channel_pool.hpp"
 #include "network/impl/client_factory_impl.hpp"
 #include "network/impl/generic_client_factory.hpp"
+#include "network/impl/in_process_network.hpp"
+#include "network/impl/peer_access_control.hpp"
 #include "network/impl/peer_communication_service_impl.hpp"
+#include "network/impl/peer_tls_auth_processor.hpp"
 #include "network/impl/peer_tls_certificates_provider_root.hpp"
 #include "network/impl/peer_tls_certificates_provider_wsv.hpp"
 #include "network/impl/tls_credentials.hpp"
@@ -99,6 +116,8 @@ static constexpr iroha::consensus::yac::ConsistencyModel
 
 static constexpr uint32_t kStaleStreamMaxRoundsDefault = 2;
 static constexpr uint32_t kMstExpirationTimeDefault = 1440;
+static constexpr std::chrono::seconds kEventSinkTimeout{10};
+static constexpr uint32_t kEventSinkMaxQueue = 1000;
 
 /**
  * Configuring iroha daemon
@@ -151,6 +170,8 @@ Irohad::Irohad(
 }
 
 Irohad::~Irohad() {
+  if (ledger_status_subscription_)
+    ledger_status_subscription_->unsubscribe();
   if (iroha_status_subscription_)
     iroha_status_subscription_->unsubscribe();
 
@@ -182,6 +203,10 @@ Irohad::RunResult Irohad::init() {
   // Recover WSV from the existing ledger to be sure it is consistent
   IROHA_EXPECTED_ERROR_CHECK(initWsvRestorer());
   IROHA_EXPECTED_ERROR_CHECK(restoreWsv());
+  IROHA_EXPECTED_ERROR_CHECK(initAuditLog());
+  IROHA_EXPECTED_ERROR_CHECK(initChainStats());
+  IROHA_EXPECTED_ERROR_CHECK(initMemoryBudget());
+  IROHA_EXPECTED_ERROR_CHECK(initEventSink());
   IROHA_EXPECTED_ERROR_CHECK(validateKeypair());
   IROHA_EXPECTED_ERROR_CHECK(initTlsCredentials());
   IROHA_EXPECTED_ERROR_CHECK(initPeerCertProvider());
@@ -204,6 +229,7 @@ Irohad::RunResult Irohad::init() {
   IROHA_EXPECTED_ERROR_CHECK(initQueryService());
   // HTTP
   IROHA_EXPECTED_ERROR_CHECK(initHttpServer());
+  IROHA_EXPECTED_ERROR_CHECK(initReachabilityCheck());
   return {};
 }
 
@@ -240,6 +266,8 @@ Irohad::RunResult Irohad::initNodeStatus() {
             stored_status.exclusiveAccess([&](IrohaStoredStatus &status) {
               if (new_status.is_healthy)
                 status.status.is_healthy = new_status.is_healthy;
+              if (new_status.is_reachable)
+                status.status.is_reachable = new_status.is_reachable;
               if (new_status.is_syncing)
                 status.status.is_syncing = new_status.is_syncing;
               if (new_status.memory_consumption)
@@ -269,10 +297,44 @@ Irohad::RunResult Irohad::initSettings() {
 
   IROHA_EXPECTED_TRY_GET_VALUE(settings, settingsQuery.get()->get());
   settings_ = std::move(settings);
+  updateLedgerStatus();
+
+  // the chain id and the retained blocks are changed by committed blocks,
+  // keep the status current
+  ledger_status_subscription_ = SubscriberCreator<
+      bool,
+      std::shared_ptr<shared_model::interface::Block const>>::
+      template create<EventTypes::kOnBlock>(
+          iroha::SubscriptionEngineHandlers::kMetrics,
+          [this](auto &, auto) { updateLedgerStatus(); });
   log_->info("[Init] => settings");
   return {};
 }
 
+void Irohad::updateLedgerStatus() {
+  std::optional<std::string> chain_id;
+  if (auto query = storage->createSettingQuery()) {
+    if (auto settings = query.get()->get(); expected::hasValue(settings)
+        and not settings.assumeValue()->chain_id.empty()) {
+      chain_id = settings.assumeValue()->chain_id;
+    }
+  }
+  std::optional<shared_model::interface::types::HeightType> first_retained;
+  if (auto block_query = storage->getBlockQuery()) {
+    first_retained = block_query->getFirstRetainedHeight();
+  }
+
+  iroha_status_subscription_->get().exclusiveAccess(
+      [&](IrohaStoredStatus &status) {
+        if (status.status.chain_id != chain_id
+            or status.status.first_retained_height != first_retained) {
+          status.status.chain_id = std::move(chain_id);
+          status.status.first_retained_height = first_retained;
+          status.serialized_status.Clear();
+        }
+      });
+}
+
 /**
  * Initializing validators' configs
  */
@@ -290,6 +352,26 @@ Irohad::RunResult Irohad::initValidatorsConfigs() {
   return {};
 }
 
+namespace {
+  /**
+   * Pass the stored blocks starting from the given height to the consumer
+   */
+  iroha::expected::Result<void, std::string> replayBlocks(
+      iroha::ametsuchi::BlockQuery &block_query,
+      shared_model::interface::types::HeightType from_height,
+      std::function<void(shared_model::interface::Block const &)> consumer) {
+    auto const top_height = block_query.getTopBlockHeight();
+    for (auto height = from_height; height <= top_height; ++height) {
+      auto block = block_query.getBlock(height);
+      if (auto e = iroha::expected::resultToOptionalError(block)) {
+        return fmt::format("Failed to get block {}: {}", height, e->message);
+      }
+      consumer(*block.assumeValue());
+    }
+    return {};
+  }
+}  // namespace
+
 /**
  * Initializing Http server.
  */
@@ -357,6 +439,23 @@ Irohad::RunResult Irohad::initHttpServer() {
                            status.status.is_healthy.has_value(),
                            *status.status.is_healthy);
 
+                writer.Key("is_reachable");
+                setOptBool(writer,
+                           status.status.is_reachable.has_value(),
+                           *status.status.is_reachable);
+
+                writer.Key("chain_id");
+                if (status.status.chain_id)
+                  writer.String(status.status.chain_id->c_str());
+                else
+                  writer.Null();
+
+                writer.Key("first_retained_height");
+                setOptUInt64(
+                    writer,
+                    status.status.first_retained_height.has_value(),
+                    status.status.first_retained_height.value_or(0));
+
                 writer.EndObject();
               }
               req_res.setJsonResponse(
@@ -364,6 +463,283 @@ Irohad::RunResult Irohad::initHttpServer() {
                                    status.serialized_status.GetLength()));
             });
       });
+
+  http_server_->registerHandler(
+      "/log_level",
+      [log_manager(log_manager_),
+       log(log_)](iroha::network::HttpRequestResponse &req_res) {
+        using namespace rapidjson;
+        auto level_name = [](logger::LogLevel level) {
+          auto it = std::find_if(
+              config_members::LogLevels.begin(),
+              config_members::LogLevels.end(),
+              [level](auto const &item) { return item.second == level; });
+          return it == config_members::LogLevels.end() ? std::string{}
+                                                       : it->first;
+        };
+        StringBuffer buffer;
+        Writer<StringBuffer> writer(buffer);
+        writer.StartObject();
+        if (req_res.getMethodType() == eMethodType::kPost) {
+          auto tag = req_res.getQueryParameter("logger");
+          auto level_str = req_res.getQueryParameter("level");
+          auto level = level_str
+              ? config_members::LogLevels.find(*level_str)
+              : config_members::LogLevels.end();
+          if (not tag or level == config_members::LogLevels.end()) {
+            writer.Key("error");
+            writer.String(
+                "Use POST with `logger' and `level' query parameters");
+          } else if (not log_manager->setLogLevel(*tag, level->second)) {
+            writer.Key("error");
+            writer.String(fmt::format("Unknown logger `{}'", *tag).c_str());
+          } else {
+            log->info("Log level of {} set to {}", *tag, level->first);
+          }
+        }
+        writer.Key("loggers");
+        writer.StartArray();
+        for (auto const &[tag, level] : log_manager->getLogLevels()) {
+          writer.StartObject();
+          writer.Key("logger");
+          writer.String(tag.c_str());
+          writer.Key("level");
+          writer.String(level_name(level).c_str());
+          writer.EndObject();
+        }
+        writer.EndArray();
+        writer.EndObject();
+        req_res.setJsonResponse(
+            std::string_view(buffer.GetString(), buffer.GetLength()));
+      });
+
+  http_server_->registerHandler(
+      "/identity",
+      [keypair(*keypair_),
+       status_sub(iroha_status_subscription_),
+       storage(storage)](iroha::network::HttpRequestResponse &req_res) {
+        iroha::PeerIdentity identity;
+        identity.public_key = keypair.publicKey();
+        status_sub->get().sharedAccess([&](IrohaStoredStatus const &status) {
+          identity.chain_id = status.status.chain_id.value_or(std::string{});
+        });
+        identity.version = iroha::kGitPrettyVersion;
+        if (auto block_query = storage->getBlockQuery();
+            block_query and block_query->getTopBlockHeight() > 0) {
+          if (auto genesis = block_query->getBlock(1);
+              expected::hasValue(genesis)) {
+            identity.genesis_hash = genesis.assumeValue()->hash().hex();
+          }
+        }
+        identity.issued_at = iroha::time::now();
+        req_res.setJsonResponse(iroha::signIdentity(identity, keypair));
+      });
+
+  if (config_.backup_path and db_context_) {
+    http_server_->registerHandler(
+        "/backup",
+        [db_context(db_context_),
+         backup_path(*config_.backup_path),
+         log(log_)](iroha::network::HttpRequestResponse &req_res) {
+          using namespace rapidjson;
+          StringBuffer buffer;
+          Writer<StringBuffer> writer(buffer);
+          writer.StartObject();
+          if (req_res.getMethodType() != eMethodType::kPost) {
+            writer.Key("error");
+            writer.String("Use POST method to create a backup");
+          } else {
+            auto const path = (boost::filesystem::path(backup_path)
+                               / std::to_string(iroha::time::now()))
+                                  .string();
+            RocksDbCommon common(db_context);
+            if (auto result = common.createCheckpoint(path);
+                expected::hasError(result)) {
+              log->error("Backup failed: {}",
+                         result.assumeError().description);
+              writer.Key("error");
+              writer.String(result.assumeError().description.c_str());
+            } else {
+              log->info("Backup created in {}", path);
+              writer.Key("path");
+              writer.String(path.c_str());
+            }
+          }
+          writer.EndObject();
+          req_res.setJsonResponse(
+              std::string_view(buffer.GetString(), buffer.GetLength()));
+        });
+  }
+
+  if (chain_stats_) {
+    http_server_->registerHandler(
+        "/explorer/stats",
+        [chain_stats(chain_stats_)](
+            iroha::network::HttpRequestResponse &req_res) {
+          size_t top = 10;
+          if (auto value = req_res.getQueryParameter("top")) {
+            top = std::strtoull(value->c_str(), nullptr, 10);
+          }
+          req_res.setJsonResponse(chain_stats->summaryJson(top));
+        });
+    http_server_->registerHandler(
+        "/explorer/blocks",
+        [chain_stats(chain_stats_)](
+            iroha::network::HttpRequestResponse &req_res) {
+          auto period = std::chrono::hours(1);
+          if (auto value = req_res.getQueryParameter("period");
+              value and *value == "day") {
+            period = std::chrono::hours(24);
+          }
+          size_t limit = 24;
+          if (auto value = req_res.getQueryParameter("limit")) {
+            limit = std::strtoull(value->c_str(), nullptr, 10);
+          }
+          req_res.setJsonResponse(chain_stats->periodsJson(period, limit));
+        });
+  }
+
+  if (config_.profiling.value_or(false)) {
+    http_server_->registerHandler(
+        "/debug/pprof/profile",
+        [profiler(std::make_shared<iroha::Profiler>()),
+         log(log_)](iroha::network::HttpRequestResponse &req_res) {
+          uint64_t seconds = 10;
+          if (auto value = req_res.getQueryParameter("seconds")) {
+            seconds = std::clamp<uint64_t>(
+                std::strtoull(value->c_str(), nullptr, 10), 1, 300);
+          }
+          uint32_t frequency = 99;
+          if (auto value = req_res.getQueryParameter("frequency")) {
+            frequency = std::strtoul(value->c_str(), nullptr, 10);
+          }
+          log->info("Collecting a CPU profile for {} s", seconds);
+          auto folded =
+              profiler->profile(std::chrono::seconds(seconds), frequency);
+          if (auto e = expected::resultToOptionalError(folded)) {
+            using namespace rapidjson;
+            StringBuffer buffer;
+            Writer<StringBuffer> writer(buffer);
+            writer.StartObject();
+            writer.Key("error");
+            writer.String(e->c_str());
+            writer.EndObject();
+            req_res.setJsonResponse(
+                std::string_view(buffer.GetString(), buffer.GetLength()));
+            return;
+          }
+          req_res.setTextResponse(folded.assumeValue());
+        });
+  }
+
+  if (audit_log_) {
+    http_server_->registerHandler(
+        "/audit_log",
+        [audit_log(audit_log_)](iroha::network::HttpRequestResponse &req_res) {
+          shared_model::interface::types::HeightType from_height = 0;
+          if (auto from = req_res.getQueryParameter("from")) {
+            from_height = std::strtoull(from->c_str(), nullptr, 10);
+          }
+          req_res.setJsonResponse(audit_log->entriesJson(from_height));
+        });
+  }
+  return {};
+}
+
+/**
+ * Initializing audit log and recording blocks committed while it was
+ * disabled or the peer was stopped
+ */
+Irohad::RunResult Irohad::initAuditLog() {
+  if (not config_.audit_log_path) {
+    return {};
+  }
+  IROHA_EXPECTED_TRY_GET_VALUE(
+      audit_log,
+      iroha::AuditLog::create(
+          *config_.audit_log_path,
+          log_manager_->getChild("AuditLog")->getLogger()));
+  audit_log_ = std::move(audit_log);
+
+  IROHA_EXPECTED_ERROR_CHECK(
+      replayBlocks(*storage->getBlockQuery(),
+                   audit_log_->lastHeight() + 1,
+                   [&](auto const &block) { audit_log_->append(block); }));
+  log_->info("[Init] => audit log");
+  return {};
+}
+
+/**
+ * Initializing chain statistics for block explorers from the stored blocks
+ */
+Irohad::RunResult Irohad::initChainStats() {
+  if (not config_.explorer_stats.value_or(false)) {
+    return {};
+  }
+  chain_stats_ = std::make_shared<iroha::ChainStats>();
+  IROHA_EXPECTED_ERROR_CHECK(
+      replayBlocks(*storage->getBlockQuery(), 1, [&](auto const &block) {
+        chain_stats_->append(block);
+      }));
+  log_->info("[Init] => chain stats");
+  return {};
+}
+
+/**
+ * Initializing memory limits of the transactions and events buffers
+ */
+Irohad::RunResult Irohad::initMemoryBudget() {
+  iroha::MemoryBudget::Limits limits;
+  if (config_.memory_limits) {
+    auto const kib = [](std::optional<uint64_t> value) {
+      return value ? std::optional<size_t>(*value * 1024) : std::nullopt;
+    };
+    limits.transactions = kib(config_.memory_limits->transactions_kib);
+    limits.events = kib(config_.memory_limits->events_kib);
+  }
+  memory_budget_ = std::make_shared<iroha::MemoryBudget>(limits);
+  log_->info("[Init] => memory budget");
+  return {};
+}
+
+/**
+ * Initializing event sink, which loads the blocks committed after its cursor
+ * from the block store
+ */
+Irohad::RunResult Irohad::initEventSink() {
+  if (not config_.event_sink) {
+    return {};
+  }
+  auto const &sink_config = *config_.event_sink;
+  IROHA_EXPECTED_TRY_GET_VALUE(
+      transport,
+      iroha::EventSink::makeTransport(sink_config.url, kEventSinkTimeout));
+  IROHA_EXPECTED_TRY_GET_VALUE(
+      event_sink,
+      iroha::EventSink::create(
+          std::move(transport),
+          [storage = storage, log = log_](
+              shared_model::interface::types::HeightType height)
+              -> std::shared_ptr<const shared_model::interface::Block> {
+            auto block = storage->getBlockQuery()->getBlock(height);
+            if (auto e = iroha::expected::resultToOptionalError(block)) {
+              if (e->code != iroha::ametsuchi::BlockQuery::GetBlockError::
+                                 Code::kNoBlock) {
+                log->error("Failed to get block {} for the event sink: {}",
+                           height,
+                           e->message);
+              }
+              return nullptr;
+            }
+            return std::move(block).assumeValue();
+          },
+          sink_config.cursor_path,
+          iroha::EventSink::Filter{sink_config.commands, sink_config.accounts},
+          sink_config.max_queue.value_or(kEventSinkMaxQueue),
+          log_manager_->getChild("EventSink")->getLogger(),
+          memory_budget_));
+  event_sink_ = std::move(event_sink);
+  log_->info("[Init] => event sink");
   return {};
 }
 
@@ -372,6 +748,14 @@ Irohad::RunResult Irohad::initHttpServer() {
  */
 Irohad::RunResult Irohad::initStorage(
     StartupWsvDataPolicy startup_wsv_data_policy, iroha::StorageType type) {
+  // the WSV is rebuilt from the block store after it is dropped, which is
+  // impossible once blocks have been pruned from it
+  if (startup_wsv_data_policy == StartupWsvDataPolicy::kDrop
+      and config_.block_store_path) {
+    IROHA_EXPECTED_ERROR_CHECK(iroha::checkBlockStoreReplayable(
+        *config_.block_store_path, log_manager_->getChild("Storage")));
+  }
+
   query_response_factory_ =
       std::make_shared<shared_model::proto::ProtoQueryResponseFactory>();
 
@@ -384,7 +768,21 @@ Irohad::RunResult Irohad::initStorage(
   auto storage_creator = [&]() -> RunResult {
     auto process_block =
         [this](std::shared_ptr<shared_model::interface::Block const> block) {
+          iroha::BlockPhaseTimer timer{iroha::BlockPhase::kEventEmission};
           iroha::getSubscription()->notify(EventTypes::kOnBlock, block);
+          if (audit_log_) {
+            audit_log_->append(*block);
+          }
+          if (chain_stats_) {
+            chain_stats_->append(*block);
+          }
+          if (event_sink_) {
+            event_sink_->append(*block);
+          }
+          if (inter_peer_auth_processor_) {
+            // the block may add or remove peers
+            inter_peer_auth_processor_->refreshLedgerPeers();
+          }
           if (ordering_init and tx_processor and pending_txs_storage_) {
             ordering_init->processCommittedBlock(block);
             tx_processor->processCommit(block);
@@ -404,6 +802,8 @@ Irohad::RunResult Irohad::initStorage(
                                pending_txs_storage_,
                                query_response_factory_,
                                config_.block_store_path,
+                               config_.getBlockStoreKeepBlocks(),
+                               config_.block_store_compression_level,
                                vm_caller_ref,
                                process_block,
                                log_manager_->getChild("Storage"))
@@ -412,6 +812,8 @@ Irohad::RunResult Irohad::initStorage(
                                    pending_txs_storage_,
                                    query_response_factory_,
                                    config_.block_store_path,
+                                   config_.getBlockStoreKeepBlocks(),
+                                   config_.block_store_compression_level,
                                    vm_caller_ref,
                                    process_block,
                                    log_manager_->getChild("Storage"))
@@ -488,8 +890,46 @@ Irohad::RunResult Irohad::validateKeypair() {
   };
   if (not peers) {
     log_->warn("There is no peer in the ledger with my public key!");
+  } else if (config_.external_address
+             and *config_.external_address != (*peers)->address()) {
+    log_->warn(
+        "My address in the ledger is {}, but the external address is {}. "
+        "Other peers connect to the address in the ledger.",
+        (*peers)->address(),
+        *config_.external_address);
   }
+  advertised_address_ = config_.external_address;
+  if (not advertised_address_ and peers) {
+    advertised_address_ = (*peers)->address();
+  }
+
+  return {};
+}
 
+/**
+ * Initializing periodic check that the advertised address is reachable
+ */
+Irohad::RunResult Irohad::initReachabilityCheck() {
+  if (not advertised_address_) {
+    return {};
+  }
+  getSubscription()->dispatcher()->repeat(
+      iroha::SubscriptionEngineHandlers::kMetrics,
+      std::chrono::minutes(1ull),
+      [address(*advertised_address_), log(log_)] {
+        iroha::IrohaStatus status;
+        status.is_reachable = true;
+        if (auto e = iroha::expected::resultToOptionalError(
+                iroha::main::checkReachable(address,
+                                            std::chrono::seconds(5)))) {
+          log->warn("My advertised address is unreachable: {}", *e);
+          status.is_reachable = false;
+        }
+        iroha::getSubscription()->notify(iroha::EventTypes::kOnIrohaStatus,
+                                         status);
+      },
+      [] { return true; });
+  log_->info("[Init] => reachability check of {}", *advertised_address_);
   return {};
 }
 
@@ -548,6 +988,7 @@ Irohad::RunResult Irohad::initPeerCertProvider() {
                    [&root, this](std::string &&root_cert) {
                      log_->debug("Loaded root TLS certificate from '{}'.",
                                  root.path);
+                     inter_peer_root_cert_ = root_cert;
                      return OptionalPeerCertProvider{
                          std::make_unique<PeerTlsCertificatesProviderRoot>(
                              root_cert)};
@@ -556,9 +997,15 @@ Irohad::RunResult Irohad::initPeerCertProvider() {
              [this](const IrohadConfig::InterPeerTls::FromWsv &)
                  -> PeerCertProviderResult {
                auto opt_peer_query = this->storage->createPeerQuery();
-               if (not opt_peer_query) {
+               auto opt_auth_peer_query = this->storage->createPeerQuery();
+               if (not opt_peer_query or not opt_auth_peer_query) {
                  return makeError(std::string{"Failed to get peer query."});
                }
+               // clients are pinned to the certificates of the ledger peers
+               inter_peer_auth_processor_ =
+                   std::make_shared<PeerTlsAuthProcessor>(
+                       std::move(opt_auth_peer_query).value(),
+                       log_manager_->getChild("InterPeerAuth")->getLogger());
                log_->debug("Prepared WSV peer certificate provider.");
                return boost::make_optional(
                    std::make_unique<PeerTlsCertificatesProviderWsv>(
@@ -575,6 +1022,10 @@ Irohad::RunResult Irohad::initPeerCertProvider() {
              | [this](OptionalPeerCertProvider &&opt_peer_cert_provider)
              -> RunResult {
     this->peer_tls_certificates_provider_ = std::move(opt_peer_cert_provider);
+    if (not inter_peer_auth_processor_) {
+      inter_peer_auth_processor_ = std::make_shared<PeerTlsAuthProcessor>(
+          std::nullopt, log_manager_->getChild("InterPeerAuth")->getLogger());
+    }
     return {};
   };
 }
@@ -583,8 +1034,28 @@ Irohad::RunResult Irohad::initPeerCertProvider() {
  * Initializing channel pool.
  */
 Irohad::RunResult Irohad::initClientFactory() {
-  auto channel_factory =
-      std::make_unique<ChannelFactory>(this->maybe_grpc_channel_params_);
+  if (in_process_network_) {
+    inter_peer_client_factory_ = std::make_unique<GenericClientFactory>(
+        std::make_unique<ChannelPool>(
+            std::make_unique<InProcessChannelProvider>(in_process_network_)));
+    log_->info("Inter peer communication is in-process.");
+    return {};
+  }
+  std::unique_ptr<ChannelFactory> channel_factory;
+  if (inter_peer_tls_config_) {
+    channel_factory = std::make_unique<ChannelFactoryTls>(
+        this->maybe_grpc_channel_params_,
+        peer_tls_certificates_provider_
+            ? std::make_optional(*peer_tls_certificates_provider_)
+            : std::nullopt,
+        my_inter_peer_tls_creds_
+            ? std::make_optional(*my_inter_peer_tls_creds_)
+            : std::nullopt);
+    log_->info("Inter peer communication is encrypted with TLS.");
+  } else {
+    channel_factory =
+        std::make_unique<ChannelFactory>(this->maybe_grpc_channel_params_);
+  }
   auto channel_pool = std::make_unique<ChannelPool>(std::move(channel_factory));
   inter_peer_client_factory_ =
       std::make_unique<GenericClientFactory>(std::move(channel_pool));
@@ -754,7 +1225,8 @@ Irohad::RunResult Irohad::initOrderingGate() {
       log_manager_->getChild("Ordering"),
       inter_peer_client_factory_,
       std::chrono::milliseconds(config_.getProposalCreationTimeout()),
-      config_.syncing_mode);
+      config_.syncing_mode,
+      memory_budget_);
   log_->info("[Init] => init ordering gate - [{}]",
              logger::boolRepr(bool(ordering_gate)));
   return {};
@@ -803,13 +1275,19 @@ Irohad::RunResult Irohad::initConsensusCache() {
  * Initializing block loader
  */
 Irohad::RunResult Irohad::initBlockLoader() {
+  std::shared_ptr<BandwidthLimiter> sync_limiter;
+  if (config_.block_sync_rate_kib) {
+    sync_limiter = std::make_shared<BandwidthLimiter>(
+        uint64_t{*config_.block_sync_rate_kib} * 1024);
+  }
   block_loader =
       loader_init.initBlockLoader(storage,
                                   storage,
                                   consensus_result_cache_,
                                   block_validators_config_,
                                   log_manager_->getChild("BlockLoader"),
-                                  inter_peer_client_factory_);
+                                  inter_peer_client_factory_,
+                                  std::move(sync_limiter));
 
   log_->info("[Init] => block loader");
   return {};
@@ -982,6 +1460,17 @@ Irohad::RunResult Irohad::initTransactionCommandService() {
             }
           });
 
+  expired_batches_ =
+      SubscriberCreator<bool, iroha::ordering::ExpiredBatchEvent>::
+          template create<EventTypes::kOnExpiredBatches>(
+              SubscriptionEngineHandlers::kNotifications,
+              [tx_processor(utils::make_weak(tx_processor))](
+                  auto &, iroha::ordering::ExpiredBatchEvent event) {
+                if (auto maybe_tx_processor = tx_processor.lock())
+                  maybe_tx_processor->processOutdatedBatch(event.batch,
+                                                           event.reason);
+              });
+
   command_service = std::make_shared<::torii::CommandServiceImpl>(
       tx_processor,
       status_bus_,
@@ -999,7 +1488,8 @@ Irohad::RunResult Irohad::initTransactionCommandService() {
           transaction_batch_factory_,
           config_.stale_stream_max_rounds.value_or(
               kStaleStreamMaxRoundsDefault),
-          command_service_log_manager->getChild("Transport")->getLogger());
+          command_service_log_manager->getChild("Transport")->getLogger(),
+          memory_budget_);
 
   log_->info("[Init] => command service");
   return {};
@@ -1023,7 +1513,31 @@ Irohad::RunResult Irohad::initQueryService() {
       query_factory,
       blocks_query_factory,
       query_service_log_manager->getLogger(),
-      iroha_status_subscription_);
+      iroha_status_subscription_,
+      config_.slow_query_threshold_ms
+          ? std::make_optional(
+              std::chrono::milliseconds(*config_.slow_query_threshold_ms))
+          : std::nullopt,
+      ::torii::QueryCostLimits{
+          config_.max_query_cost,
+          config_.query_cost_budget_per_minute,
+          config_.collection_query_cost.value_or(
+              ::torii::kDefaultCollectionQueryCost)},
+      storage,
+      config_.block_stream
+          ? ::torii::BlockStreamLimits{config_.block_stream->max_pending,
+                                       config_.block_stream->on_overflow
+                                           == "drop"}
+          : ::torii::BlockStreamLimits{},
+      ::torii::QueryTimeouts{
+          config_.query_timeout_ms
+              ? std::make_optional(
+                  std::chrono::milliseconds(*config_.query_timeout_ms))
+              : std::nullopt,
+          config_.max_query_timeout_ms
+              ? std::make_optional(
+                  std::chrono::milliseconds(*config_.max_query_timeout_ms))
+              : std::nullopt});
 
   log_->info("[Init] => query service");
   return {};
@@ -1139,7 +1653,22 @@ Irohad::RunResult Irohad::run() {
   internal_server = std::make_unique<ServerRunner>(
       listen_ip_ + ":" + std::to_string(config_.internal_port),
       log_manager_->getChild("InternalServerRunner")->getLogger(),
-      false);
+      false,
+      my_inter_peer_tls_creds_,
+      inter_peer_root_cert_,
+      inter_peer_auth_processor_);
+  if (in_process_network_) {
+    internal_server->inProcess(in_process_network_);
+  }
+  if (config_.peer_access) {
+    auto const &access = *config_.peer_access;
+    internal_server->intercept(makePeerAccessInterceptorFactory(
+        std::make_shared<PeerAccessControl>(PeerAccessLimits{
+            access.allow,
+            access.deny,
+            access.max_inbound_calls,
+            access.max_calls_per_ip})));
+  }
 
   // Run torii server
   IROHA_EXPECTED_TRY_GET_VALUE(torii_port,
@@ -1242,3 +1771,39 @@ Irohad::RunResult Irohad::run() {
 
   return {};
 }
+
+void Irohad::shutdown(std::chrono::system_clock::time_point deadline) {
+  log_->info("Shutting down");
+
+  // clients are told to reconnect to another peer before the streams end
+  subscription_engine_->notify(EventTypes::kOnShutdown, deadline);
+  if (torii_server) {
+    torii_server->shutdown(deadline);
+  }
+  if (torii_tls_server) {
+    torii_tls_server.value()->shutdown(deadline);
+  }
+  http_server_.reset();
+
+  // no new rounds are started, and the block being committed is finished
+  // when the workers are stopped
+  if (consensus_gate) {
+    consensus_gate->stop();
+  }
+  if (ordering_gate) {
+    ordering_gate->stop();
+  }
+  subscription_engine_->dispose();
+  if (internal_server) {
+    internal_server->shutdown(deadline);
+  }
+
+  if (db_context_) {
+    RocksDbCommon common(db_context_);
+    if (auto e = expected::resultToOptionalError(common.flush())) {
+      log_->error("Failed to flush the storage: {}", e->description);
+    } else {
+      log_->info("Storage is flushed");
+    }
+  }
+}
@@ -6,6 +6,7 @@
 #ifndef IROHA_APPLICATION_HPP
 #define IROHA_APPLICATION_HPP
 
+#include <chrono>
 #include <optional>
 
 #include "consensus/consensus_block_cache.hpp"
@@ -23,6 +24,7 @@
 #include "main/server_runner.hpp"
 #include "main/startup_params.hpp"
 #include "main/subscription_fwd.hpp"
+#include "ordering/impl/on_demand_common.hpp"
 #include "torii/tls_params.hpp"
 
 namespace google::protobuf {
@@ -34,6 +36,10 @@ namespace evpp::evpphttp {
 }
 
 namespace iroha {
+  class AuditLog;
+  class ChainStats;
+  class EventSink;
+  class MemoryBudget;
   class PendingTransactionStorage;
   class MstProcessor;
   class MstStorage;
@@ -61,9 +67,11 @@ namespace iroha {
     class BlockLoader;
     class ChannelPool;
     class GenericClientFactory;
+    class InProcessNetwork;
     class ConsensusGate;
     class OrderingGate;
     class PeerCommunicationService;
+    class PeerTlsAuthProcessor;
     class PeerTlsCertificatesProvider;
     struct GrpcChannelParams;
     struct TlsCredentials;
@@ -164,6 +172,11 @@ class Irohad {
    */
   RunResult validateKeypair();
 
+  /**
+   * Periodically check that the advertised address accepts connections
+   */
+  RunResult initReachabilityCheck();
+
   /**
    * Drop wsv and block store
    */
@@ -179,6 +192,14 @@ class Irohad {
    */
   RunResult run();
 
+  /**
+   * Stop the peer: Torii stops accepting calls and closes the client
+   * streams, the block being committed is finished, and the storage is
+   * flushed. The calls in progress are cancelled at the deadline.
+   * @param deadline - time the calls in progress may take to finish
+   */
+  void shutdown(std::chrono::system_clock::time_point deadline);
+
   virtual ~Irohad();
 
  protected:
@@ -231,12 +252,23 @@ class Irohad {
 
   virtual RunResult initSettings();
 
+  /// Show the chain id and the retained blocks in the status of the peer
+  void updateLedgerStatus();
+
   virtual RunResult initNodeStatus();
 
   virtual RunResult initValidatorsConfigs();
 
   virtual RunResult initHttpServer();
 
+  virtual RunResult initAuditLog();
+
+  virtual RunResult initChainStats();
+
+  virtual RunResult initMemoryBudget();
+
+  virtual RunResult initEventSink();
+
   /**
    * Initialize WSV restorer
    */
@@ -258,6 +290,13 @@ class Irohad {
   boost::optional<
       std::shared_ptr<const iroha::network::PeerTlsCertificatesProvider>>
       peer_tls_certificates_provider_;
+  /// root certificate inter peer clients must be signed with
+  boost::optional<std::string> inter_peer_root_cert_;
+  /// authenticates incoming inter peer calls when inter_peer_tls is set
+  std::shared_ptr<iroha::network::PeerTlsAuthProcessor>
+      inter_peer_auth_processor_;
+  /// address other peers should reach this peer at
+  std::optional<std::string> advertised_address_;
 
   // pending transactions storage
   std::shared_ptr<iroha::PendingTransactionStorage> pending_txs_storage_;
@@ -289,6 +328,9 @@ class Irohad {
   std::shared_ptr<iroha::network::GenericClientFactory>
       inter_peer_client_factory_;
 
+  /// when set, peers communicate through it instead of the sockets
+  std::shared_ptr<iroha::network::InProcessNetwork> in_process_network_;
+
   // Settings
   std::shared_ptr<const shared_model::validation::Settings> settings_;
 
@@ -383,11 +425,18 @@ class Irohad {
   std::shared_ptr<MstStateSubscriber> mst_state_update_;
   std::shared_ptr<MstStateSubscriber> mst_state_prepared_;
   std::shared_ptr<MstStateSubscriber> mst_state_expired_;
+  std::shared_ptr<
+      iroha::BaseSubscriber<bool, iroha::ordering::ExpiredBatchEvent>>
+      expired_batches_;
 
   std::shared_ptr<iroha::BaseSubscriber<
       iroha::utils::ReadWriteObject<iroha::IrohaStoredStatus, std::mutex>,
       iroha::IrohaStatus>>
       iroha_status_subscription_;
+  std::shared_ptr<iroha::BaseSubscriber<
+      bool,
+      std::shared_ptr<shared_model::interface::Block const>>>
+      ledger_status_subscription_;
 
   // query service
   std::shared_ptr<iroha::torii::QueryService> query_service;
@@ -395,6 +444,18 @@ class Irohad {
   // Http server
   std::unique_ptr<iroha::network::HttpServer> http_server_;
 
+  // audit log of privileged commands
+  std::shared_ptr<iroha::AuditLog> audit_log_;
+
+  // aggregated chain statistics for block explorers
+  std::shared_ptr<iroha::ChainStats> chain_stats_;
+
+  // memory of the buffers filled by the clients and the peers
+  std::shared_ptr<iroha::MemoryBudget> memory_budget_;
+
+  // forwarding of committed blocks to an external receiver
+  std::shared_ptr<iroha::EventSink> event_sink_;
+
   // consensus gate
   std::shared_ptr<iroha::network::ConsensusGate> consensus_gate;
 
@@ -0,0 +1,63 @@
+/**
+ * Copyright Soramitsu Co., Ltd. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+#ifndef IROHA_BLOCK_PHASE_STATS_HPP
+#define IROHA_BLOCK_PHASE_STATS_HPP
+
+#include <chrono>
+
+#include "main/subscription.hpp"
+
+namespace iroha {
+
+  /// Phases of block processing, timed separately
+  enum class BlockPhase {
+    /// stateless validation of a received proposal, including signatures of
+    /// the transactions
+    kStatelessValidation,
+    /// execution of the proposal transactions by the stateful validator
+    kExecution,
+    /// application of a committed block to the world state
+    kStateApplication,
+    /// commit of the world state and of the block store
+    kStorageCommit,
+    /// notification of the subscribers of committed blocks
+    kEventEmission
+  };
+
+  struct BlockPhaseStats {
+    BlockPhase phase;
+    std::chrono::microseconds duration;
+  };
+
+  /**
+   * Reports the time from its construction to its destruction as the
+   * duration of the phase
+   */
+  class BlockPhaseTimer {
+   public:
+    explicit BlockPhaseTimer(BlockPhase phase)
+        : phase_(phase), start_(std::chrono::steady_clock::now()) {}
+
+    BlockPhaseTimer(BlockPhaseTimer const &) = delete;
+    BlockPhaseTimer &operator=(BlockPhaseTimer const &) = delete;
+
+    ~BlockPhaseTimer() {
+      getSubscription()->notify(
+          EventTypes::kOnBlockPhase,
+          BlockPhaseStats{
+              phase_,
+              std::chrono::duration_cast<std::chrono::microseconds>(
+                  std::chrono::steady_clock::now() - start_)});
+    }
+
+   private:
+    BlockPhase const phase_;
+    std::chrono::steady_clock::time_point const start_;
+  };
+
+}  // namespace iroha
+
+#endif  // IROHA_BLOCK_PHASE_STATS_HPP
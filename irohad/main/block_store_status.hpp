@@ -0,0 +1,21 @@
+/**
+ * Copyright Soramitsu Co., Ltd. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+#ifndef IROHA_BLOCK_STORE_STATUS_HPP
+#define IROHA_BLOCK_STORE_STATUS_HPP
+
+#include <chrono>
+#include <cstdint>
+
+namespace iroha {
+
+  struct BlockStoreStatus {
+    uint64_t size;
+    std::chrono::microseconds fsync_duration;
+  };
+
+}  // namespace iroha
+
+#endif  // IROHA_BLOCK_STORE_STATUS_HPP
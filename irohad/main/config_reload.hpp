@@ -0,0 +1,44 @@
+/**
+ * Copyright Soramitsu Co., Ltd. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+#ifndef IROHA_CONFIG_RELOAD_HPP
+#define IROHA_CONFIG_RELOAD_HPP
+
+#include <string>
+#include <vector>
+
+#include "logger/logger_manager_fwd.hpp"
+#include "main/iroha_conf_loader.hpp"
+
+namespace iroha {
+  namespace main {
+
+    /// Outcome of applying an updated configuration to a running peer
+    struct ConfigReloadReport {
+      /// settings which took effect
+      std::vector<std::string> applied;
+      /// changed settings which were not applied, with the reasons
+      std::vector<std::string> rejected;
+    };
+
+    /**
+     * Apply the runtime-safe part of the updated configuration, which is
+     * currently the log levels. Other changed settings are only used at
+     * startup and are reported as rejected.
+     * @param current - configuration the peer was started with
+     * @param updated - newly loaded configuration
+     * @param log_manager - logger tree of the peer, nullptr if the log
+     * level is fixed with the command line
+     * @return applied and rejected settings
+     */
+    ConfigReloadReport reloadConfig(
+        const IrohadConfig &current,
+        const IrohadConfig &updated,
+        const logger::LoggerManagerTreePtr &log_manager);
+
+  }  // namespace main
+}  // namespace iroha
+
+#endif  // IROHA_CONFIG_RELOAD_HPP
@@ -0,0 +1,37 @@
+/**
+ * Copyright Soramitsu Co., Ltd. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+#ifndef IROHA_GENESIS_SCHEMA_HPP
+#define IROHA_GENESIS_SCHEMA_HPP
+
+#include <string>
+
+#include "common/result_fwd.hpp"
+
+namespace iroha {
+  namespace main {
+
+    /**
+     * Generate JSON Schema of the genesis block from the protobuf description
+     * of the block.
+     * @return JSON Schema (draft-07) document
+     */
+    std::string makeGenesisSchema();
+
+    /**
+     * Check that JSON matches the genesis block format. Unlike the protobuf
+     * JSON parser, all problems are reported at once, each one prefixed with
+     * the JSON path to the offending value, e.g.
+     * $.block_v1.payload.transactions[0].payload.reducedPayload.commands[1]
+     * @param data - JSON representation of the block
+     * @return error with one problem per line if the JSON does not match
+     */
+    iroha::expected::Result<void, std::string> validateGenesisJson(
+        const std::string &data);
+
+  }  // namespace main
+}  // namespace iroha
+
+#endif  // IROHA_GENESIS_SCHEMA_HPP
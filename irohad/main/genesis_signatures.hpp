@@ -0,0 +1,63 @@
+/**
+ * Copyright Soramitsu Co., Ltd. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+#ifndef IROHA_GENESIS_SIGNATURES_HPP
+#define IROHA_GENESIS_SIGNATURES_HPP
+
+#include <string>
+#include <vector>
+
+#include "common/result_fwd.hpp"
+
+namespace shared_model {
+  namespace crypto {
+    class Keypair;
+  }
+  namespace interface {
+    class Block;
+  }
+}  // namespace shared_model
+
+namespace iroha {
+  namespace main {
+
+    /**
+     * Sign the genesis block payload, keeping the signatures of other
+     * founders
+     * @param block - genesis block to sign
+     * @param keypair - keypair of the founder
+     * @return error if the block is already signed with this key
+     */
+    iroha::expected::Result<void, std::string> signGenesisBlock(
+        shared_model::interface::Block &block,
+        const shared_model::crypto::Keypair &keypair);
+
+    /**
+     * Copy signatures of the independently signed copy of the genesis block
+     * @param dest - genesis block to add the signatures to
+     * @param source - signed copy of the same genesis block
+     * @return error if the blocks have different payloads
+     */
+    iroha::expected::Result<void, std::string> mergeGenesisSignatures(
+        shared_model::interface::Block &dest,
+        const shared_model::interface::Block &source);
+
+    /**
+     * Check that the genesis block is signed by at least quorum founders.
+     * Every signature must be valid and belong to a founder.
+     * @param block - genesis block to check
+     * @param founders - hex public keys of the founders
+     * @param quorum - number of required founder signatures
+     * @return error describing the first problem found
+     */
+    iroha::expected::Result<void, std::string> verifyGenesisSignatures(
+        const shared_model::interface::Block &block,
+        const std::vector<std::string> &founders,
+        size_t quorum);
+
+  }  // namespace main
+}  // namespace iroha
+
+#endif  // IROHA_GENESIS_SIGNATURES_HPP
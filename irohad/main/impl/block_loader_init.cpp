@@ -21,16 +21,19 @@ namespace {
    * @param block_query_factory - factory to block query component
    * @param block_cache used to retrieve last block put by consensus
    * @param loader_log - the log of the loader subsystem
+   * @param sync_limiter - limits the rate of blocks streamed to syncing peers
    * @return initialized service
    */
   auto createService(
       std::shared_ptr<BlockQueryFactory> block_query_factory,
       std::shared_ptr<consensus::ConsensusResultCache> consensus_result_cache,
-      const logger::LoggerManagerTreePtr &loader_log_manager) {
+      const logger::LoggerManagerTreePtr &loader_log_manager,
+      std::shared_ptr<BandwidthLimiter> sync_limiter) {
     return std::make_shared<BlockLoaderService>(
         std::move(block_query_factory),
         std::move(consensus_result_cache),
-        loader_log_manager->getChild("Network")->getLogger());
+        loader_log_manager->getChild("Network")->getLogger(),
+        std::move(sync_limiter));
   }
 
   /**
@@ -69,10 +72,12 @@ std::shared_ptr<BlockLoader> BlockLoaderInit::initBlockLoader(
     std::shared_ptr<shared_model::validation::ValidatorsConfig>
         validators_config,
     const logger::LoggerManagerTreePtr &loader_log_manager,
-    std::shared_ptr<iroha::network::GenericClientFactory> client_factory) {
+    std::shared_ptr<iroha::network::GenericClientFactory> client_factory,
+    std::shared_ptr<BandwidthLimiter> sync_limiter) {
   service = createService(std::move(block_query_factory),
                           std::move(consensus_result_cache),
-                          loader_log_manager);
+                          loader_log_manager,
+                          std::move(sync_limiter));
   loader = createLoader(std::move(peer_query_factory),
                         std::move(validators_config),
                         loader_log_manager->getLogger(),
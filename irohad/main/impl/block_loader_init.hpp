@@ -31,6 +31,8 @@ namespace iroha {
        * @param validators_config - a config for underlying validators
        * @param loader_log - the log of the loader subsystem
        * @param client_factory - a factory of client stubs
+       * @param sync_limiter - limits the rate of blocks streamed to syncing
+       * peers, not limited if null
        * @return initialized service
        */
       std::shared_ptr<BlockLoader> initBlockLoader(
@@ -41,7 +43,8 @@ namespace iroha {
           std::shared_ptr<shared_model::validation::ValidatorsConfig>
               validators_config,
           const logger::LoggerManagerTreePtr &loader_log_manager,
-          std::shared_ptr<iroha::network::GenericClientFactory> client_factory);
+          std::shared_ptr<iroha::network::GenericClientFactory> client_factory,
+          std::shared_ptr<BandwidthLimiter> sync_limiter);
 
       std::shared_ptr<BlockLoaderImpl> loader;
       std::shared_ptr<BlockLoaderService> service;
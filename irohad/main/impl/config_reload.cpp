@@ -0,0 +1,223 @@
+/**
+ * Copyright Soramitsu Co., Ltd. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+#include "main/config_reload.hpp"
+
+#include <fmt/core.h>
+#include <fmt/format.h>
+#include "logger/logger_manager.hpp"
+#include "main/iroha_conf_literals.hpp"
+
+namespace {
+  std::string describeDbConfig(const IrohadConfig &config) {
+    if (not config.database_config) {
+      return {};
+    }
+    auto const &db = *config.database_config;
+    return fmt::format("{} {} {}:{} {}@{} {}",
+                       db.type,
+                       db.path,
+                       db.host,
+                       db.port,
+                       db.user,
+                       db.working_dbname,
+                       db.maintenance_dbname);
+  }
+
+  std::string describeEventSink(const IrohadConfig &config) {
+    if (not config.event_sink) {
+      return {};
+    }
+    auto const &sink = *config.event_sink;
+    return fmt::format("{} {} [{}] [{}] {}",
+                       sink.url,
+                       sink.cursor_path,
+                       fmt::join(sink.commands, ","),
+                       fmt::join(sink.accounts, ","),
+                       sink.max_queue.value_or(0));
+  }
+
+  std::string describeBlockStream(const IrohadConfig &config) {
+    if (not config.block_stream) {
+      return {};
+    }
+    return fmt::format("{} {}",
+                       config.block_stream->max_pending,
+                       config.block_stream->on_overflow);
+  }
+
+  std::string describePeerAccess(const IrohadConfig &config) {
+    if (not config.peer_access) {
+      return {};
+    }
+    auto const &access = *config.peer_access;
+    return fmt::format("[{}] [{}] {} {}",
+                       fmt::join(access.allow, ","),
+                       fmt::join(access.deny, ","),
+                       access.max_inbound_calls.value_or(0),
+                       access.max_calls_per_ip.value_or(0));
+  }
+
+  std::string describeMemoryLimits(const IrohadConfig &config) {
+    if (not config.memory_limits) {
+      return {};
+    }
+    return fmt::format("{} {}",
+                       config.memory_limits->transactions_kib.value_or(0),
+                       config.memory_limits->events_kib.value_or(0));
+  }
+
+  template <typename T>
+  void checkUnchanged(const char *name,
+                      const T &current,
+                      const T &updated,
+                      iroha::main::ConfigReloadReport &report) {
+    if (not(current == updated)) {
+      report.rejected.push_back(
+          fmt::format("{}: used at startup only, restart the peer to apply",
+                      name));
+    }
+  }
+}  // namespace
+
+namespace iroha {
+  namespace main {
+
+    ConfigReloadReport reloadConfig(
+        const IrohadConfig &current,
+        const IrohadConfig &updated,
+        const logger::LoggerManagerTreePtr &log_manager) {
+      using namespace config_members;
+      ConfigReloadReport report;
+
+      if (updated.logger_manager) {
+        if (log_manager) {
+          log_manager->updateLogLevels(**updated.logger_manager);
+          report.applied.push_back(LogSection);
+        } else {
+          report.rejected.push_back(fmt::format(
+              "{}: log level is set with the command line", LogSection));
+        }
+      }
+
+      checkUnchanged(BlockStorePath,
+                     current.block_store_path,
+                     updated.block_store_path,
+                     report);
+      checkUnchanged(BlockStorePruning,
+                     current.getBlockStoreKeepBlocks(),
+                     updated.getBlockStoreKeepBlocks(),
+                     report);
+      checkUnchanged(BlockStoreCompressionLevel,
+                     current.block_store_compression_level,
+                     updated.block_store_compression_level,
+                     report);
+      checkUnchanged(
+          ToriiPort, current.torii_port, updated.torii_port, report);
+      checkUnchanged(
+          InternalPort, current.internal_port, updated.internal_port, report);
+      checkUnchanged(DbConfig,
+                     describeDbConfig(current),
+                     describeDbConfig(updated),
+                     report);
+      checkUnchanged(PgOpt, current.pg_opt, updated.pg_opt, report);
+      checkUnchanged(MaxProposalSize,
+                     current.max_proposal_size,
+                     updated.max_proposal_size,
+                     report);
+      checkUnchanged(
+          VoteDelay, current.vote_delay, updated.vote_delay, report);
+      checkUnchanged(MstExpirationTime,
+                     current.mst_expiration_time,
+                     updated.mst_expiration_time,
+                     report);
+      checkUnchanged(MaxRoundsDelay,
+                     current.max_round_delay_ms,
+                     updated.max_round_delay_ms,
+                     report);
+      checkUnchanged(ProposalCreationTimeout,
+                     current.proposal_creation_timeout,
+                     updated.proposal_creation_timeout,
+                     report);
+      checkUnchanged(HealthcheckPort,
+                     current.healthcheck_port,
+                     updated.healthcheck_port,
+                     report);
+      checkUnchanged(
+          BackupPath, current.backup_path, updated.backup_path, report);
+      checkUnchanged(SlowQueryThreshold,
+                     current.slow_query_threshold_ms,
+                     updated.slow_query_threshold_ms,
+                     report);
+      checkUnchanged(
+          MaxQueryCost, current.max_query_cost, updated.max_query_cost, report);
+      checkUnchanged(QueryCostBudget,
+                     current.query_cost_budget_per_minute,
+                     updated.query_cost_budget_per_minute,
+                     report);
+      checkUnchanged(CollectionQueryCost,
+                     current.collection_query_cost,
+                     updated.collection_query_cost,
+                     report);
+      checkUnchanged(QueryTimeout,
+                     current.query_timeout_ms,
+                     updated.query_timeout_ms,
+                     report);
+      checkUnchanged(MaxQueryTimeout,
+                     current.max_query_timeout_ms,
+                     updated.max_query_timeout_ms,
+                     report);
+      checkUnchanged(BlockSyncRate,
+                     current.block_sync_rate_kib,
+                     updated.block_sync_rate_kib,
+                     report);
+      checkUnchanged(ExternalAddress,
+                     current.external_address,
+                     updated.external_address,
+                     report);
+      checkUnchanged(BlockStream,
+                     describeBlockStream(current),
+                     describeBlockStream(updated),
+                     report);
+      checkUnchanged(PeerAccess,
+                     describePeerAccess(current),
+                     describePeerAccess(updated),
+                     report);
+      checkUnchanged(
+          AuditLogPath, current.audit_log_path, updated.audit_log_path, report);
+      checkUnchanged(ExplorerStats,
+                     current.explorer_stats,
+                     updated.explorer_stats,
+                     report);
+      checkUnchanged(Profiling, current.profiling, updated.profiling, report);
+      checkUnchanged(MemoryLimits,
+                     describeMemoryLimits(current),
+                     describeMemoryLimits(updated),
+                     report);
+      checkUnchanged(ShutdownTimeout,
+                     current.shutdown_timeout_ms,
+                     updated.shutdown_timeout_ms,
+                     report);
+      checkUnchanged(EventSink,
+                     describeEventSink(current),
+                     describeEventSink(updated),
+                     report);
+      checkUnchanged(MaxProposalPack,
+                     current.max_proposal_pack,
+                     updated.max_proposal_pack,
+                     report);
+      checkUnchanged(StaleStreamMaxRounds,
+                     current.stale_stream_max_rounds,
+                     updated.stale_stream_max_rounds,
+                     report);
+      checkUnchanged(MaxPastCreatedHours,
+                     current.max_past_created_hours,
+                     updated.max_past_created_hours,
+                     report);
+      return report;
+    }
+
+  }  // namespace main
+}  // namespace iroha
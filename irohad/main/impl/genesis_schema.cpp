@@ -0,0 +1,339 @@
+/**
+ * Copyright Soramitsu Co., Ltd. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+#include "main/genesis_schema.hpp"
+
+#include <algorithm>
+#include <map>
+#include <vector>
+
+#include <fmt/core.h>
+#include <google/protobuf/descriptor.h>
+#include <rapidjson/document.h>
+#include <rapidjson/error/en.h>
+#include <rapidjson/prettywriter.h>
+#include <rapidjson/stringbuffer.h>
+#include "block.pb.h"
+#include "common/result.hpp"
+
+using google::protobuf::Descriptor;
+using google::protobuf::FieldDescriptor;
+using google::protobuf::OneofDescriptor;
+
+namespace {
+  using Writer = rapidjson::PrettyWriter<rapidjson::StringBuffer>;
+
+  const std::string kDefinitions = "#/definitions/";
+
+  /// Collect the message and all messages it refers to, depth first
+  void collectMessages(const Descriptor &message,
+                       std::vector<const Descriptor *> &messages) {
+    if (std::find(messages.begin(), messages.end(), &message)
+        != messages.end()) {
+      return;
+    }
+    messages.push_back(&message);
+    for (int i = 0; i < message.field_count(); ++i) {
+      if (auto nested = message.field(i)->message_type()) {
+        collectMessages(*nested, messages);
+      }
+    }
+  }
+
+  void writeValueSchema(const FieldDescriptor &field, Writer &writer) {
+    writer.StartObject();
+    switch (field.cpp_type()) {
+      case FieldDescriptor::CPPTYPE_INT32:
+      case FieldDescriptor::CPPTYPE_UINT32:
+        writer.Key("type");
+        writer.String("integer");
+        break;
+      case FieldDescriptor::CPPTYPE_INT64:
+      case FieldDescriptor::CPPTYPE_UINT64:
+        // proto3 JSON mapping allows 64-bit integers to be strings
+        writer.Key("type");
+        writer.StartArray();
+        writer.String("string");
+        writer.String("integer");
+        writer.EndArray();
+        writer.Key("pattern");
+        writer.String(field.cpp_type() == FieldDescriptor::CPPTYPE_INT64
+                          ? "^-?[0-9]+$"
+                          : "^[0-9]+$");
+        break;
+      case FieldDescriptor::CPPTYPE_DOUBLE:
+      case FieldDescriptor::CPPTYPE_FLOAT:
+        writer.Key("type");
+        writer.String("number");
+        break;
+      case FieldDescriptor::CPPTYPE_BOOL:
+        writer.Key("type");
+        writer.String("boolean");
+        break;
+      case FieldDescriptor::CPPTYPE_STRING:
+        writer.Key("type");
+        writer.String("string");
+        if (field.type() == FieldDescriptor::TYPE_BYTES) {
+          writer.Key("contentEncoding");
+          writer.String("base64");
+        }
+        break;
+      case FieldDescriptor::CPPTYPE_ENUM:
+        writer.Key("enum");
+        writer.StartArray();
+        for (int i = 0; i < field.enum_type()->value_count(); ++i) {
+          writer.String(field.enum_type()->value(i)->name().c_str());
+        }
+        writer.EndArray();
+        break;
+      case FieldDescriptor::CPPTYPE_MESSAGE:
+        writer.Key("$ref");
+        writer.String(
+            (kDefinitions + field.message_type()->full_name()).c_str());
+        break;
+    }
+    writer.EndObject();
+  }
+
+  void writeMessageSchema(const Descriptor &message, Writer &writer) {
+    writer.StartObject();
+    writer.Key("type");
+    writer.String("object");
+    writer.Key("additionalProperties");
+    writer.Bool(false);
+    writer.Key("properties");
+    writer.StartObject();
+    for (int i = 0; i < message.field_count(); ++i) {
+      auto const &field = *message.field(i);
+      writer.Key(field.json_name().c_str());
+      if (field.is_repeated()) {
+        writer.StartObject();
+        writer.Key("type");
+        writer.String("array");
+        writer.Key("items");
+        writeValueSchema(field, writer);
+        writer.EndObject();
+      } else {
+        writeValueSchema(field, writer);
+      }
+    }
+    writer.EndObject();
+    writer.EndObject();
+  }
+
+  /**
+   * Walks JSON alongside the protobuf descriptor and accumulates problems
+   * in the same way JsonStringToMessage would report them one at a time
+   */
+  class GenesisValidator {
+   public:
+    void validateMessage(const rapidjson::Value &value,
+                         const Descriptor &message,
+                         const std::string &path) {
+      if (not value.IsObject()) {
+        error(path, fmt::format("expected {} object", message.name()));
+        return;
+      }
+      std::map<const OneofDescriptor *, std::string> oneofs;
+      for (auto const &member : value.GetObject()) {
+        std::string name = member.name.GetString();
+        auto member_path = fmt::format("{}.{}", path, name);
+        auto field = findField(message, name);
+        if (not field) {
+          error(member_path,
+                fmt::format("unknown field of {}, expected one of: {}",
+                            message.name(),
+                            fieldNames(message)));
+          continue;
+        }
+        if (member.value.IsNull()) {
+          continue;
+        }
+        if (auto oneof = field->containing_oneof()) {
+          auto [it, inserted] = oneofs.emplace(oneof, name);
+          if (not inserted) {
+            error(member_path,
+                  fmt::format("`{}' is already set, only one field of `{}' "
+                              "is allowed",
+                              it->second,
+                              oneof->name()));
+            continue;
+          }
+        }
+        if (field->is_repeated()) {
+          if (not member.value.IsArray()) {
+            error(member_path, "expected array");
+            continue;
+          }
+          size_t index = 0;
+          for (auto const &item : member.value.GetArray()) {
+            validateValue(
+                item, *field, fmt::format("{}[{}]", member_path, index++));
+          }
+        } else {
+          validateValue(member.value, *field, member_path);
+        }
+      }
+    }
+
+    const std::vector<std::string> &errors() const {
+      return errors_;
+    }
+
+    void error(const std::string &path, const std::string &message) {
+      errors_.push_back(fmt::format("{}: {}", path, message));
+    }
+
+   private:
+    static const FieldDescriptor *findField(const Descriptor &message,
+                                            const std::string &name) {
+      // protobuf JSON parser accepts both original and lowerCamelCase names
+      if (auto field = message.FindFieldByName(name)) {
+        return field;
+      }
+      for (int i = 0; i < message.field_count(); ++i) {
+        if (message.field(i)->json_name() == name) {
+          return message.field(i);
+        }
+      }
+      return nullptr;
+    }
+
+    static std::string fieldNames(const Descriptor &message) {
+      std::string names;
+      for (int i = 0; i < message.field_count(); ++i) {
+        names += (i == 0 ? "" : ", ") + message.field(i)->json_name();
+      }
+      return names;
+    }
+
+    static bool isIntegerString(const rapidjson::Value &value,
+                                bool allow_negative) {
+      if (not value.IsString() or value.GetStringLength() == 0) {
+        return false;
+      }
+      std::string str = value.GetString();
+      auto start = allow_negative and str.front() == '-' ? 1u : 0u;
+      return str.size() > start
+          and std::all_of(str.begin() + start, str.end(), [](char c) {
+                return c >= '0' and c <= '9';
+              });
+    }
+
+    void validateValue(const rapidjson::Value &value,
+                       const FieldDescriptor &field,
+                       const std::string &path) {
+      bool valid = true;
+      switch (field.cpp_type()) {
+        case FieldDescriptor::CPPTYPE_INT32:
+          valid = value.IsInt() or isIntegerString(value, true);
+          break;
+        case FieldDescriptor::CPPTYPE_UINT32:
+          valid = value.IsUint() or isIntegerString(value, false);
+          break;
+        case FieldDescriptor::CPPTYPE_INT64:
+          valid = value.IsInt64() or isIntegerString(value, true);
+          break;
+        case FieldDescriptor::CPPTYPE_UINT64:
+          valid = value.IsUint64() or isIntegerString(value, false);
+          break;
+        case FieldDescriptor::CPPTYPE_DOUBLE:
+        case FieldDescriptor::CPPTYPE_FLOAT:
+          valid = value.IsNumber();
+          break;
+        case FieldDescriptor::CPPTYPE_BOOL:
+          valid = value.IsBool();
+          break;
+        case FieldDescriptor::CPPTYPE_STRING:
+          valid = value.IsString();
+          break;
+        case FieldDescriptor::CPPTYPE_ENUM:
+          if (value.IsString()) {
+            if (not field.enum_type()->FindValueByName(value.GetString())) {
+              error(path,
+                    fmt::format("unknown value `{}' of {}",
+                                value.GetString(),
+                                field.enum_type()->name()));
+            }
+            return;
+          }
+          valid = value.IsInt();
+          break;
+        case FieldDescriptor::CPPTYPE_MESSAGE:
+          validateMessage(value, *field.message_type(), path);
+          return;
+      }
+      if (not valid) {
+        error(path, fmt::format("expected {}", field.type_name()));
+      }
+    }
+
+    std::vector<std::string> errors_;
+  };
+}  // namespace
+
+namespace iroha {
+  namespace main {
+
+    std::string makeGenesisSchema() {
+      auto const &block = *iroha::protocol::Block::descriptor();
+      std::vector<const Descriptor *> messages;
+      collectMessages(block, messages);
+
+      rapidjson::StringBuffer buffer;
+      Writer writer(buffer);
+      writer.StartObject();
+      writer.Key("$schema");
+      writer.String("http://json-schema.org/draft-07/schema#");
+      writer.Key("title");
+      writer.String("Iroha genesis block");
+      writer.Key("$ref");
+      writer.String((kDefinitions + block.full_name()).c_str());
+      writer.Key("definitions");
+      writer.StartObject();
+      for (auto message : messages) {
+        writer.Key(message->full_name().c_str());
+        writeMessageSchema(*message, writer);
+      }
+      writer.EndObject();
+      writer.EndObject();
+      return buffer.GetString();
+    }
+
+    iroha::expected::Result<void, std::string> validateGenesisJson(
+        const std::string &data) {
+      rapidjson::Document document;
+      document.Parse(data.c_str());
+      if (document.HasParseError()) {
+        auto offset = document.GetErrorOffset();
+        auto line = std::count(data.begin(), data.begin() + offset, '\n') + 1;
+        return fmt::format(
+            "Genesis block is not valid JSON at line {}: {}",
+            line,
+            rapidjson::GetParseError_En(document.GetParseError()));
+      }
+
+      GenesisValidator validator;
+      auto const &block = *iroha::protocol::Block::descriptor();
+      validator.validateMessage(document, block, "$");
+      if (document.IsObject() and not document.HasMember("block_v1")
+          and not document.HasMember("blockV1")) {
+        validator.error("$", "missing `block_v1'");
+      }
+
+      auto const &errors = validator.errors();
+      if (errors.empty()) {
+        return {};
+      }
+      std::string message =
+          fmt::format("Genesis block has {} error(s):", errors.size());
+      for (auto const &error : errors) {
+        message += "\n  " + error;
+      }
+      return message;
+    }
+
+  }  // namespace main
+}  // namespace iroha
@@ -0,0 +1,98 @@
+/**
+ * Copyright Soramitsu Co., Ltd. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+#include "main/genesis_signatures.hpp"
+
+#include <algorithm>
+#include <set>
+
+#include <fmt/core.h>
+#include "common/result.hpp"
+#include "common/to_lower.hpp"
+#include "cryptography/crypto_provider/crypto_signer.hpp"
+#include "cryptography/crypto_provider/crypto_verifier.hpp"
+#include "cryptography/keypair.hpp"
+#include "interfaces/common_objects/signature.hpp"
+#include "interfaces/iroha_internal/block.hpp"
+
+using namespace shared_model::interface::types;
+
+namespace iroha {
+  namespace main {
+
+    iroha::expected::Result<void, std::string> signGenesisBlock(
+        shared_model::interface::Block &block,
+        const shared_model::crypto::Keypair &keypair) {
+      auto signature =
+          shared_model::crypto::CryptoSigner::sign(block.payload(), keypair);
+      if (not block.addSignature(
+              SignedHexStringView{signature},
+              PublicKeyHexStringView{keypair.publicKey()})) {
+        return fmt::format("Genesis block is already signed by {}",
+                           keypair.publicKey());
+      }
+      return {};
+    }
+
+    iroha::expected::Result<void, std::string> mergeGenesisSignatures(
+        shared_model::interface::Block &dest,
+        const shared_model::interface::Block &source) {
+      if (dest.hash() != source.hash()) {
+        return fmt::format(
+            "Genesis block {} cannot take signatures of a different block {}",
+            dest.hash().hex(),
+            source.hash().hex());
+      }
+      for (auto const &signature : source.signatures()) {
+        // signatures of the same founder are skipped
+        dest.addSignature(SignedHexStringView{signature.signedData()},
+                          PublicKeyHexStringView{signature.publicKey()});
+      }
+      return {};
+    }
+
+    iroha::expected::Result<void, std::string> verifyGenesisSignatures(
+        const shared_model::interface::Block &block,
+        const std::vector<std::string> &founders,
+        size_t quorum) {
+      std::set<std::string> founder_keys;
+      for (auto const &founder : founders) {
+        std::string key;
+        founder_keys.insert(iroha::toLowerAppend(founder, key));
+      }
+
+      std::set<std::string> signed_by;
+      for (auto const &signature : block.signatures()) {
+        std::string key;
+        iroha::toLowerAppend(signature.publicKey(), key);
+        if (founder_keys.count(key) == 0) {
+          return fmt::format("Genesis block is signed by {}, which is not a "
+                             "founder",
+                             signature.publicKey());
+        }
+        if (auto e = iroha::expected::resultToOptionalError(
+                shared_model::crypto::CryptoVerifier::verify(
+                    SignedHexStringView{signature.signedData()},
+                    block.payload(),
+                    PublicKeyHexStringView{signature.publicKey()}))) {
+          return fmt::format("Invalid genesis block signature of {}: {}",
+                             signature.publicKey(),
+                             e.value());
+        }
+        signed_by.insert(std::move(key));
+      }
+
+      if (signed_by.size() < quorum) {
+        return fmt::format(
+            "Genesis block is signed by {} of {} founders, {} required",
+            signed_by.size(),
+            founder_keys.size(),
+            quorum);
+      }
+      return {};
+    }
+
+  }  // namespace main
+}  // namespace iroha
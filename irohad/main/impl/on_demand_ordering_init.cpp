@@ -7,6 +7,7 @@
 
 #include "common/mem_operations.hpp"
 #include "common/permutation_generator.hpp"
+#include "datetime/time.hpp"
 #include "interfaces/iroha_internal/block.hpp"
 #include "logger/logger.hpp"
 #include "logger/logger_manager.hpp"
@@ -30,6 +31,9 @@ namespace {
 
   template <RoundType V>
   using RoundTypeConstant = std::integral_constant<RoundType, V>;
+
+  /// number of proposals stored by the ordering service
+  constexpr size_t kNumberOfProposals = 3;
 }  // namespace
 
 OnDemandOrderingInit::OnDemandOrderingInit(logger::LoggerPtr log)
@@ -50,7 +54,7 @@ auto createNotificationFactory(
   return std::make_shared<
       iroha::ordering::transport::OnDemandOsClientGrpcFactory>(
       std::move(proposal_transport_factory),
-      [] { return std::chrono::system_clock::now(); },
+      [] { return iroha::time::systemNow(); },
       delay,
       ordering_log_manager->getChild("NetworkClient")->getLogger(),
       std::make_unique<iroha::network::ClientFactoryImpl<
@@ -101,13 +105,16 @@ auto OnDemandOrderingInit::createService(
     std::shared_ptr<shared_model::interface::UnsafeProposalFactory>
         proposal_factory,
     std::shared_ptr<iroha::ametsuchi::TxPresenceCache> tx_cache,
-    const logger::LoggerManagerTreePtr &ordering_log_manager) {
+    const logger::LoggerManagerTreePtr &ordering_log_manager,
+    std::shared_ptr<MemoryBudget> memory_budget) {
   ordering_service_ = std::make_shared<OnDemandOrderingServiceImpl>(
       max_number_of_transactions,
       max_proposal_pack,
       std::move(proposal_factory),
       std::move(tx_cache),
-      ordering_log_manager->getChild("Service")->getLogger());
+      ordering_log_manager->getChild("Service")->getLogger(),
+      kNumberOfProposals,
+      std::move(memory_budget));
   return ordering_service_;
 }
 
@@ -129,14 +136,16 @@ OnDemandOrderingInit::initOrderingGate(
     logger::LoggerManagerTreePtr ordering_log_manager,
     std::shared_ptr<iroha::network::GenericClientFactory> client_factory,
     std::chrono::milliseconds proposal_creation_timeout,
-    bool syncing_mode) {
+    bool syncing_mode,
+    std::shared_ptr<MemoryBudget> memory_budget) {
   std::shared_ptr<OnDemandOrderingService> ordering_service;
   if (!syncing_mode) {
     ordering_service = createService(max_number_of_transactions,
                                      max_proposal_pack,
                                      proposal_factory,
                                      tx_cache,
-                                     ordering_log_manager);
+                                     ordering_log_manager,
+                                     std::move(memory_budget));
     service = std::make_shared<transport::OnDemandOsServerGrpc>(
         ordering_service,
         std::move(transaction_factory),
@@ -35,6 +35,8 @@ namespace shared_model {
 }  // namespace shared_model
 
 namespace iroha {
+  class MemoryBudget;
+
   namespace network {
     class GenericClientFactory;
     struct OrderingEvent;
@@ -108,7 +110,8 @@ namespace iroha::ordering {
         std::shared_ptr<shared_model::interface::UnsafeProposalFactory>
             proposal_factory,
         std::shared_ptr<ametsuchi::TxPresenceCache> tx_cache,
-        const logger::LoggerManagerTreePtr &ordering_log_manager);
+        const logger::LoggerManagerTreePtr &ordering_log_manager,
+        std::shared_ptr<MemoryBudget> memory_budget);
 
    public:
     /// Constructor.
@@ -130,6 +133,8 @@ namespace iroha::ordering {
      * @param proposal_factory factory required by ordering service to produce
      * proposals
      * @param client_factory - a factory of client stubs
+     * @param memory_budget - limits the memory of the batches waiting in the
+     * ordering service
      * @return initialized ordering gate
      */
     std::shared_ptr<network::OrderingGate> initOrderingGate(
@@ -150,7 +155,8 @@ namespace iroha::ordering {
         logger::LoggerManagerTreePtr ordering_log_manager,
         std::shared_ptr<iroha::network::GenericClientFactory> client_factory,
         std::chrono::milliseconds proposal_creation_timeout,
-        bool syncing_mode);
+        bool syncing_mode,
+        std::shared_ptr<MemoryBudget> memory_budget = nullptr);
 
     iroha::ordering::RoundSwitch processSynchronizationEvent(
         synchronizer::SynchronizationEvent event);
@@ -110,18 +110,13 @@ namespace {
 
   /**
    * Checks schema compatibility.
-   * @return value of true if the schema in the provided sql connection is
-   * compatibile with this code, false if not and an error message if the
-   * check could not be performed.
+   * @return the version of the schema in the provided sql connection or an
+   * error message if the check could not be performed.
    */
-  iroha::expected::Result<bool, std::string> isSchemaCompatible(
+  iroha::expected::Result<SchemaVersion, std::string> getSchemaVersion(
       const PostgresOptions &postgres_options) {
-    return getWorkingDbSession(postgres_options) | [](auto sql) {
-      return getDbSchemaVersion(*sql) |
-          [](const SchemaVersion &db_schema_version) {
-            return db_schema_version == iroha::getIrohadVersion();
-          };
-    };
+    return getWorkingDbSession(postgres_options) |
+        [](auto sql) { return getDbSchemaVersion(*sql); };
   }
 
   void processPqNotice(void *arg, const char *message) {
@@ -262,6 +257,7 @@ CREATE TABLE role (
 CREATE TABLE domain (
     domain_id character varying(255),
     default_role character varying(32) NOT NULL REFERENCES role(role_id),
+    archived boolean NOT NULL DEFAULT false,
     PRIMARY KEY (domain_id)
 );
 CREATE TABLE signatory (
@@ -273,6 +269,7 @@ CREATE TABLE account (
     domain_id character varying(255) NOT NULL REFERENCES domain,
     quorum int NOT NULL,
     data JSONB,
+    deactivated boolean NOT NULL DEFAULT false,
     PRIMARY KEY (account_id)
 );
 CREATE TABLE account_has_signatory (
@@ -280,6 +277,30 @@ CREATE TABLE account_has_signatory (
     public_key varchar NOT NULL REFERENCES signatory,
     PRIMARY KEY (account_id, public_key)
 );
+CREATE TABLE account_retired_signatory (
+    account_id character varying(288) NOT NULL REFERENCES account,
+    public_key varchar NOT NULL,
+    new_public_key varchar NOT NULL,
+    expires_height bigint NOT NULL,
+    PRIMARY KEY (account_id, public_key)
+);
+CREATE TABLE account_session_key (
+    account_id character varying(288) NOT NULL REFERENCES account,
+    public_key varchar NOT NULL,
+    permissions bit()"
+        + std::to_string(shared_model::interface::RolePermissionSet::size())
+        + R"() NOT NULL,
+    allowed_asset_id character varying(288) NOT NULL,
+    expires_height bigint NOT NULL,
+    PRIMARY KEY (account_id, public_key)
+);
+CREATE TABLE idempotency_key (
+    creator_id character varying(288) NOT NULL,
+    idempotency_key character varying(64) NOT NULL,
+    tx_hash character varying(64) NOT NULL,
+    created_time bigint NOT NULL,
+    PRIMARY KEY (creator_id, idempotency_key)
+);
 CREATE TABLE peer (
     public_key varchar NOT NULL,
     address character varying(261) NOT NULL UNIQUE,
@@ -304,6 +325,45 @@ CREATE TABLE account_has_asset (
     amount decimal NOT NULL,
     PRIMARY KEY (account_id, asset_id)
 );
+CREATE TABLE asset_lock (
+    hash varchar NOT NULL,
+    src_account_id character varying(288) NOT NULL REFERENCES account,
+    dest_account_id character varying(288) NOT NULL REFERENCES account,
+    asset_id character varying(288) NOT NULL REFERENCES asset,
+    amount decimal NOT NULL,
+    timeout_height bigint NOT NULL,
+    PRIMARY KEY (hash)
+);
+CREATE TABLE asset_lock_release (
+    hash varchar NOT NULL,
+    src_account_id character varying(288) NOT NULL REFERENCES account,
+    dest_account_id character varying(288) NOT NULL REFERENCES account,
+    asset_id character varying(288) NOT NULL REFERENCES asset,
+    PRIMARY KEY (hash)
+);
+CREATE TABLE vesting_schedule (
+    account_id character varying(288) NOT NULL REFERENCES account,
+    asset_id character varying(288) NOT NULL REFERENCES asset,
+    amount decimal NOT NULL,
+    start_height bigint NOT NULL,
+    cliff bigint NOT NULL,
+    duration bigint NOT NULL,
+    PRIMARY KEY (account_id, asset_id)
+);
+CREATE TABLE spending_limit (
+    account_id character varying(288) NOT NULL REFERENCES account,
+    asset_id character varying(288) NOT NULL REFERENCES asset,
+    amount decimal NOT NULL,
+    window_blocks bigint NOT NULL,
+    spendings text NOT NULL DEFAULT '',
+    PRIMARY KEY (account_id, asset_id)
+);
+CREATE TABLE asset_stats (
+    asset_id character varying(288) NOT NULL REFERENCES asset,
+    minted decimal NOT NULL DEFAULT 0,
+    burned decimal NOT NULL DEFAULT 0,
+    PRIMARY KEY (asset_id)
+);
 CREATE TABLE role_has_permissions (
     role_id character varying(32) NOT NULL REFERENCES role,
     permission bit()"
@@ -311,6 +371,11 @@ CREATE TABLE role_has_permissions (
         + R"() NOT NULL,
     PRIMARY KEY (role_id)
 );
+CREATE TABLE role_includes_role (
+    role_id character varying(32) NOT NULL REFERENCES role,
+    included_role_id character varying(32) NOT NULL REFERENCES role,
+    PRIMARY KEY (role_id, included_role_id)
+);
 CREATE TABLE account_has_roles (
     account_id character varying(288) NOT NULL REFERENCES account,
     role_id character varying(32) NOT NULL REFERENCES role,
@@ -356,6 +421,21 @@ CREATE TABLE IF NOT EXISTS setting(
     setting_value text,
     PRIMARY KEY (setting_key)
 );
+CREATE TABLE IF NOT EXISTS governance_proposal (
+    proposal_id character varying(32),
+    setting_key text NOT NULL,
+    setting_value text NOT NULL,
+    proposer_id character varying(288) NOT NULL REFERENCES account,
+    expires_height bigint NOT NULL,
+    enacted boolean NOT NULL DEFAULT false,
+    PRIMARY KEY (proposal_id)
+);
+CREATE TABLE IF NOT EXISTS governance_vote (
+    proposal_id character varying(32) NOT NULL REFERENCES governance_proposal,
+    voter_id character varying(288) NOT NULL REFERENCES account,
+    weight bigint NOT NULL,
+    PRIMARY KEY (proposal_id, voter_id)
+);
 CREATE TABLE IF NOT EXISTS engine_calls (
     call_id serial unique not null,
     tx_hash text,
@@ -365,6 +445,12 @@ CREATE TABLE IF NOT EXISTS engine_calls (
     created_address varchar(40),
     PRIMARY KEY (tx_hash, cmd_index)
 );
+CREATE TABLE IF NOT EXISTS transfer_fee (
+    tx_hash text,
+    cmd_index bigint,
+    fee decimal NOT NULL,
+    PRIMARY KEY (tx_hash, cmd_index)
+);
 CREATE TABLE IF NOT EXISTS burrow_account_data (
     address varchar(40),
     data text,
@@ -430,12 +516,18 @@ PgConnectionInit::prepareWorkingDatabase(
       return dropWorkingDatabase(options) |
           [&] { return createSchema(options); };
     } else {  // StartupWsvDataPolicy::kReuse
-      return isSchemaCompatible(options) | [&](bool is_compatible)
+      return getSchemaVersion(options) |
+                 [&](const SchemaVersion &db_schema_version)
                  -> iroha::expected::Result<void, std::string> {
-        if (not is_compatible && !skip_schema_check) {
-          return "The schema is not compatible. "
-                 "Either overwrite the ledger or use a compatible binary "
-                 "version.";
+        auto const version = iroha::getIrohadVersion();
+        if (not(db_schema_version == version) && !skip_schema_check) {
+          return fmt::format(
+              "The schema of version {} is not compatible with irohad {}. "
+              "Either restart with --drop_state to rebuild the state from "
+              "the block store, overwrite the ledger or use a compatible "
+              "binary version.",
+              db_schema_version.toString(),
+              version.toString());
         }
         return iroha::expected::Value<void>{};
       };
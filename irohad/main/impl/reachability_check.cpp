@@ -0,0 +1,31 @@
+/**
+ * Copyright Soramitsu Co., Ltd. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+#include "main/reachability_check.hpp"
+
+#include <fmt/core.h>
+#include <boost/asio/ip/tcp.hpp>
+#include "common/result.hpp"
+
+iroha::expected::Result<void, std::string> iroha::main::checkReachable(
+    const std::string &address, std::chrono::milliseconds timeout) {
+  auto const port_pos = address.rfind(':');
+  if (port_pos == std::string::npos) {
+    return fmt::format("Address {} has no port", address);
+  }
+  auto host = address.substr(0, port_pos);
+  if (host.size() > 1 and host.front() == '[' and host.back() == ']') {
+    host = host.substr(1, host.size() - 2);
+  }
+
+  boost::asio::ip::tcp::iostream stream;
+  stream.expires_after(timeout);
+  stream.connect(host, address.substr(port_pos + 1));
+  if (not stream) {
+    return fmt::format(
+        "Failed to connect to {}: {}", address, stream.error().message());
+  }
+  return {};
+}
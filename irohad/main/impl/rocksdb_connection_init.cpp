@@ -21,16 +21,26 @@ namespace {
 
   /**
    * Checks schema compatibility.
-   * @return value of true if the schema in the provided database is
-   * compatibile with this code, false if not and an error message if the
-   * check could not be performed.
+   * @return void value if the schema in the provided database is compatibile
+   * with this code, otherwise an error message describing the versions or
+   * why the check could not be performed.
    */
-  iroha::expected::Result<bool, std::string> isSchemaCompatible(
+  iroha::expected::Result<void, std::string> checkSchemaCompatible(
       RocksDbCommon &common, const RocksDbOptions &options) {
     RDB_TRY_GET_VALUE_OR_STR_ERR(
-        version,
+        db_version,
         forWSVVersion<kDbOperation::kGet, kDbEntry::kMustExist>(common));
-    return *version == iroha::getIrohadVersion();
+    auto const version = iroha::getIrohadVersion();
+    if (*db_version == version) {
+      return {};
+    }
+    return fmt::format(
+        "The schema of version {} is not compatible with irohad {}. "
+        "Either restart with --drop_state to rebuild the state from the "
+        "block store, overwrite the ledger or use a compatible binary "
+        "version.",
+        db_version->toString(),
+        version.toString());
   }
 
   iroha::expected::Result<void, std::string> createSchema(
@@ -105,16 +115,9 @@ RdbConnectionInit::prepareWorkingDatabase(
     return port;
   }
 
-  return isSchemaCompatible(common, options) | [port](bool is_compatible)
+  return checkSchemaCompatible(common, options) | [port]()
              -> iroha::expected::Result<std::shared_ptr<RocksDBPort>,
-                                        std::string> {
-    if (not is_compatible) {
-      return "The schema is not compatible. "
-             "Either overwrite the ledger or use a compatible binary "
-             "version.";
-    }
-    return port;
-  };
+                                        std::string> { return port; };
 }
 
 iroha::expected::Result<void, std::string>
@@ -0,0 +1,183 @@
+/**
+ * Copyright Soramitsu Co., Ltd. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+#include "main/secret_source.hpp"
+
+#include <algorithm>
+#include <cstdlib>
+#include <iterator>
+#include <string_view>
+#include <vector>
+
+#include <fmt/core.h>
+#include <rapidjson/document.h>
+#include <boost/algorithm/string/trim.hpp>
+#include <boost/asio/ip/tcp.hpp>
+#include <boost/process.hpp>
+#include "common/files.hpp"
+#include "common/result.hpp"
+
+using iroha::expected::makeError;
+using iroha::expected::makeValue;
+using iroha::expected::Result;
+
+namespace {
+  constexpr std::string_view kFilePrefix = "file:";
+  constexpr std::string_view kEnvPrefix = "env:";
+  constexpr std::string_view kExecPrefix = "exec:";
+  constexpr std::string_view kVaultPrefix = "vault:";
+  constexpr std::string_view kHttpScheme = "http://";
+
+#ifdef _WIN32
+  constexpr auto kShellCommandSwitch = "/c";
+#else
+  constexpr auto kShellCommandSwitch = "-c";
+#endif
+
+  bool startsWith(const std::string &str, std::string_view prefix) {
+    return str.compare(0, prefix.size(), prefix) == 0;
+  }
+
+  Result<std::string, std::string> fromEnv(const std::string &name) {
+    auto value = std::getenv(name.c_str());
+    if (not value) {
+      return makeError(
+          fmt::format("environment variable {} is not set", name));
+    }
+    return makeValue(std::string{value});
+  }
+
+  Result<std::string, std::string> fromCommand(const std::string &command) {
+    namespace bp = boost::process;
+    bp::ipstream out;
+    std::error_code ec;
+    bp::child child(bp::exe = bp::shell(),
+                    bp::args = std::vector<std::string>{kShellCommandSwitch,
+                                                        command},
+                    bp::std_out > out,
+                    ec);
+    if (ec) {
+      return makeError(
+          fmt::format("failed to run `{}': {}", command, ec.message()));
+    }
+    std::string output{std::istreambuf_iterator<char>(out), {}};
+    child.wait(ec);
+    if (ec or child.exit_code() != 0) {
+      return makeError(fmt::format("command `{}' failed with exit code {}",
+                                   command,
+                                   child.exit_code()));
+    }
+    return makeValue(std::move(output));
+  }
+
+  /// Read a field of the secret from Vault HTTP API, both KV v1 and KV v2
+  /// responses are supported
+  Result<std::string, std::string> fromVault(const std::string &reference) {
+    auto const field_pos = reference.rfind('#');
+    if (not startsWith(reference, kHttpScheme)
+        or field_pos == std::string::npos) {
+      return makeError(fmt::format(
+          "Vault secret must be referenced as {}<url>#<field>, where url "
+          "starts with {}; use a local Vault agent or {}vault kv get for "
+          "other setups",
+          kVaultPrefix,
+          kHttpScheme,
+          kExecPrefix));
+    }
+    auto const field = reference.substr(field_pos + 1);
+    auto const address = reference.substr(
+        kHttpScheme.size(), field_pos - kHttpScheme.size());
+    auto const path_pos = std::min(address.find('/'), address.size());
+    auto const authority = address.substr(0, path_pos);
+    auto const path =
+        path_pos == address.size() ? "/" : address.substr(path_pos);
+    auto const port_pos = authority.find(':');
+    auto const host = authority.substr(0, port_pos);
+    auto const port = port_pos == std::string::npos
+        ? std::string{"80"}
+        : authority.substr(port_pos + 1);
+
+    auto token = fromEnv("VAULT_TOKEN");
+    if (auto e = iroha::expected::resultToOptionalError(token)) {
+      return makeError(std::move(e).value());
+    }
+
+    boost::asio::ip::tcp::iostream stream(host, port);
+    if (not stream) {
+      return makeError(fmt::format("failed to connect to Vault at {}: {}",
+                                   authority,
+                                   stream.error().message()));
+    }
+    stream << "GET " << path << " HTTP/1.0\r\n"
+           << "Host: " << authority << "\r\n"
+           << "X-Vault-Token: " << token.assumeValue() << "\r\n"
+           << "Accept: application/json\r\n\r\n"
+           << std::flush;
+
+    std::string http_version;
+    unsigned int status = 0;
+    stream >> http_version >> status;
+    std::string line;
+    std::getline(stream, line);
+    // skip the headers up to the empty line
+    while (std::getline(stream, line) and line != "\r" and not line.empty()) {
+    }
+    std::string body{std::istreambuf_iterator<char>(stream), {}};
+    if (status != 200) {
+      return makeError(fmt::format(
+          "Vault responded with status {} for {}", status, path));
+    }
+
+    rapidjson::Document document;
+    document.Parse(body.c_str());
+    if (document.HasParseError() or not document.IsObject()
+        or not document.HasMember("data") or not document["data"].IsObject()) {
+      return makeError(fmt::format("unexpected Vault response for {}", path));
+    }
+    rapidjson::Value const *data = &document["data"];
+    if (data->HasMember("data") and (*data)["data"].IsObject()) {
+      data = &(*data)["data"];
+    }
+    auto value = data->FindMember(field.c_str());
+    if (value == data->MemberEnd() or not value->value.IsString()) {
+      return makeError(fmt::format(
+          "Vault secret {} has no string field `{}'", path, field));
+    }
+    return makeValue(std::string{value->value.GetString()});
+  }
+
+  Result<std::string, std::string> fromSource(const std::string &reference) {
+    if (startsWith(reference, kFilePrefix)) {
+      return iroha::readTextFile(reference.substr(kFilePrefix.size()));
+    }
+    if (startsWith(reference, kEnvPrefix)) {
+      return fromEnv(reference.substr(kEnvPrefix.size()));
+    }
+    if (startsWith(reference, kExecPrefix)) {
+      return fromCommand(reference.substr(kExecPrefix.size()));
+    }
+    if (startsWith(reference, kVaultPrefix)) {
+      return fromVault(reference.substr(kVaultPrefix.size()));
+    }
+    return makeValue(std::string{reference});
+  }
+}  // namespace
+
+namespace iroha {
+  namespace main {
+
+    iroha::expected::Result<std::string, std::string> resolveSecret(
+        const std::string &reference) {
+      auto secret = fromSource(reference);
+      if (auto e = expected::resultToOptionalError(secret)) {
+        return makeError(
+            fmt::format("Failed to resolve secret: {}", e.value()));
+      }
+      return makeValue(
+          boost::algorithm::trim_copy(std::move(secret).assumeValue()));
+    }
+
+  }  // namespace main
+}  // namespace iroha
@@ -8,12 +8,15 @@
 #include <stdexcept>
 
 #include <fmt/core.h>
+#include <boost/filesystem.hpp>
+#include "ametsuchi/impl/block_compression.hpp"
 #include "ametsuchi/impl/flat_file_block_storage.hpp"
 #include "ametsuchi/impl/in_memory_block_storage_factory.hpp"
 #include "ametsuchi/impl/pool_wrapper.hpp"
 #include "ametsuchi/impl/postgres_block_storage_factory.hpp"
 #include "ametsuchi/impl/rocksdb_block_storage.hpp"
 #include "ametsuchi/impl/rocksdb_block_storage_factory.hpp"
+#include "ametsuchi/impl/rocksdb_common.hpp"
 #include "ametsuchi/impl/rocksdb_storage_impl.hpp"
 #include "ametsuchi/impl/storage_base.hpp"
 #include "ametsuchi/impl/storage_impl.hpp"
@@ -39,16 +42,45 @@ class StorageInitException : public std::runtime_error {
 namespace {
   std::unique_ptr<ametsuchi::BlockStorage> makeFlatFileBlockStorage(
       std::string const &block_storage_dir,
+      std::optional<size_t> keep_blocks,
+      std::optional<uint32_t> compression_level,
       logger::LoggerManagerTreePtr log_manager) {
     auto flat_file = ametsuchi::FlatFile::create(
         block_storage_dir, log_manager->getChild("FlatFile")->getLogger());
     if (auto err = iroha::expected::resultToOptionalError(flat_file)) {
       throw StorageInitException{err.value()};
     }
-    return std::make_unique<ametsuchi::FlatFileBlockStorage>(
+    auto block_storage = std::make_unique<ametsuchi::FlatFileBlockStorage>(
         std::move(flat_file.assumeValue()),
         std::make_shared<shared_model::proto::ProtoBlockJsonConverter>(),
-        log_manager->getChild("FlatFileBlockStorage")->getLogger());
+        log_manager->getChild("FlatFileBlockStorage")->getLogger(),
+        keep_blocks,
+        compression_level);
+    block_storage->truncateCorruptedTail();
+    return block_storage;
+  }
+
+  void checkFlatFileOptions(
+      boost::optional<std::string> const &block_storage_dir,
+      std::optional<size_t> keep_blocks,
+      std::optional<uint32_t> compression_level) {
+    if (keep_blocks and not block_storage_dir) {
+      throw StorageInitException{
+          "block store pruning is supported only for flat file block "
+          "storage, please set block_store_path"};
+    }
+    if (compression_level and not block_storage_dir) {
+      throw StorageInitException{
+          "block store compression is supported only for flat file block "
+          "storage, please set block_store_path"};
+    }
+    if (compression_level
+        and (*compression_level == 0
+             or *compression_level > ametsuchi::kMaxBlockCompressionLevel)) {
+      throw StorageInitException{
+          fmt::format("block store compression level must be from 1 to {}",
+                      ametsuchi::kMaxBlockCompressionLevel)};
+    }
   }
 
   std::unique_ptr<ametsuchi::BlockStorage> makeRocksDbBlockStorage(
@@ -60,6 +92,55 @@ namespace {
         log_manager->getChild("RocksDbBlockStorage")->getLogger());
   }
 
+  /**
+   * Refuse to switch a RocksDB WSV to the flat file block store while the
+   * RocksDB instance still keeps the blocks, they would be silently ignored.
+   */
+  void checkNoRocksDbBlocks(
+      std::shared_ptr<ametsuchi::RocksDBContext> db_context,
+      logger::LoggerManagerTreePtr log_manager) {
+    auto const blocks_count =
+        makeRocksDbBlockStorage(std::move(db_context), log_manager)->size();
+    if (blocks_count > 0) {
+      throw StorageInitException{fmt::format(
+          "the RocksDB database keeps {} blocks, but block_store_path is "
+          "set. Remove block_store_path to keep using them, or start with "
+          "an empty database to sync the blocks into the flat file block "
+          "store",
+          blocks_count)};
+    }
+  }
+
+  /**
+   * Refuse to keep the blocks of a RocksDB WSV in RocksDB when the WSV has
+   * blocks applied, but RocksDB keeps none of them. It happens when
+   * block_store_path is removed from the config of a peer which kept its
+   * blocks in the flat file block store, they would be silently ignored.
+   */
+  void checkRocksDbBlocksPresent(
+      std::shared_ptr<ametsuchi::RocksDBContext> db_context,
+      logger::LoggerManagerTreePtr log_manager) {
+    ametsuchi::RocksDbCommon common(db_context);
+    auto wsv_height = ametsuchi::forTopBlockHeight(common);
+    if (auto err = iroha::expected::resultToOptionalError(wsv_height)) {
+      throw StorageInitException{fmt::format(
+          "failed to read the top block height: {}", err->description)};
+    }
+    if (wsv_height.assumeValue() == 0) {
+      return;
+    }
+    auto const blocks_count =
+        makeRocksDbBlockStorage(std::move(db_context), log_manager)->size();
+    if (blocks_count == 0) {
+      throw StorageInitException{fmt::format(
+          "the WSV is at height {}, but the RocksDB database keeps no "
+          "blocks, they are probably kept in a flat file block store. Set "
+          "block_store_path back to it, or start with an empty database to "
+          "sync the blocks into RocksDB",
+          wsv_height.assumeValue())};
+    }
+  }
+
   std::unique_ptr<ametsuchi::BlockStorage> makePostgresBlockStorage(
       std::shared_ptr<iroha::ametsuchi::PoolWrapper> pool_wrapper,
       std::shared_ptr<shared_model::proto::ProtoBlockFactory> block_factory,
@@ -94,37 +175,53 @@ iroha::initStorage(
     std::shared_ptr<shared_model::interface::QueryResponseFactory>
         query_response_factory,
     boost::optional<std::string> block_storage_dir,
+    std::optional<size_t> block_store_keep_blocks,
+    std::optional<uint32_t> block_store_compression_level,
     std::optional<std::reference_wrapper<const iroha::ametsuchi::VmCaller>>
         vm_caller_ref,
     std::function<void(std::shared_ptr<shared_model::interface::Block const>)>
         callback,
     logger::LoggerManagerTreePtr log_manager) {
-  auto perm_converter =
-      std::make_shared<shared_model::proto::ProtoPermissionToString>();
-
-  auto block_transport_factory =
-      std::make_shared<shared_model::proto::ProtoBlockFactory>(
-          std::make_unique<shared_model::validation::AlwaysValidValidator<
-              shared_model::interface::Block>>(),
-          std::make_unique<shared_model::validation::ProtoBlockValidator>());
-
-  std::unique_ptr<ametsuchi::BlockStorageFactory>
-      temporary_block_storage_factory =
-          std::make_unique<ametsuchi::InMemoryBlockStorageFactory>();
-
-  auto persistent_block_storage =
-      makeRocksDbBlockStorage(db_context, log_manager);
-
-  return ametsuchi::RocksDbStorageImpl::create(
-      std::move(db_context),
-      perm_converter,
-      std::move(pending_txs_storage),
-      std::move(query_response_factory),
-      std::move(temporary_block_storage_factory),
-      std::move(persistent_block_storage),
-      vm_caller_ref,
-      std::move(callback),
-      log_manager->getChild("Storage"));
+  try {
+    auto perm_converter =
+        std::make_shared<shared_model::proto::ProtoPermissionToString>();
+
+    std::unique_ptr<ametsuchi::BlockStorageFactory>
+        temporary_block_storage_factory =
+            std::make_unique<ametsuchi::InMemoryBlockStorageFactory>();
+
+    checkFlatFileOptions(block_storage_dir,
+                         block_store_keep_blocks,
+                         block_store_compression_level);
+
+    // blocks are kept in the same RocksDB instance as the WSV unless a flat
+    // file block store is requested explicitly
+    if (block_storage_dir) {
+      checkNoRocksDbBlocks(db_context, log_manager);
+    } else {
+      checkRocksDbBlocksPresent(db_context, log_manager);
+    }
+    auto persistent_block_storage = block_storage_dir
+        ? makeFlatFileBlockStorage(block_storage_dir.value(),
+                                   block_store_keep_blocks,
+                                   block_store_compression_level,
+                                   log_manager)
+        : makeRocksDbBlockStorage(db_context, log_manager);
+
+    return ametsuchi::RocksDbStorageImpl::create(
+        std::move(db_context),
+        perm_converter,
+        std::move(pending_txs_storage),
+        std::move(query_response_factory),
+        std::move(temporary_block_storage_factory),
+        std::move(persistent_block_storage),
+        vm_caller_ref,
+        std::move(callback),
+        log_manager->getChild("Storage"));
+  } catch (StorageInitException const &e) {
+    return iroha::expected::makeError(
+        fmt::format("Storage initialization failed: {}", e.what()));
+  }
 }
 
 iroha::expected::Result<std::shared_ptr<iroha::ametsuchi::Storage>, std::string>
@@ -135,6 +232,8 @@ iroha::initStorage(
     std::shared_ptr<shared_model::interface::QueryResponseFactory>
         query_response_factory,
     boost::optional<std::string> block_storage_dir,
+    std::optional<size_t> block_store_keep_blocks,
+    std::optional<uint32_t> block_store_compression_level,
     std::optional<std::reference_wrapper<const iroha::ametsuchi::VmCaller>>
         vm_caller_ref,
     std::function<void(std::shared_ptr<shared_model::interface::Block const>)>
@@ -160,8 +259,15 @@ iroha::initStorage(
                 []() { return generator::randomString(20); },
                 log_manager->getChild("TemporaryBlockStorage")->getLogger());
 
+    checkFlatFileOptions(block_storage_dir,
+                         block_store_keep_blocks,
+                         block_store_compression_level);
+
     auto persistent_block_storage = block_storage_dir
-        ? makeFlatFileBlockStorage(block_storage_dir.value(), log_manager)
+        ? makeFlatFileBlockStorage(block_storage_dir.value(),
+                                   block_store_keep_blocks,
+                                   block_store_compression_level,
+                                   log_manager)
         : makePostgresBlockStorage(
               pool_wrapper, block_transport_factory, log_manager);
     return ametsuchi::StorageImpl::create(
@@ -177,6 +283,33 @@ iroha::initStorage(
         log_manager->getChild("Storage"));
   } catch (StorageInitException const &e) {
     return iroha::expected::makeError(
-        fmt::format("Storage initialization failed: ", e.what()));
+        fmt::format("Storage initialization failed: {}", e.what()));
+  }
+}
+
+iroha::expected::Result<void, std::string> iroha::checkBlockStoreReplayable(
+    std::string const &block_storage_dir,
+    logger::LoggerManagerTreePtr log_manager) {
+  if (not boost::filesystem::exists(block_storage_dir)) {
+    return {};
+  }
+  try {
+    auto block_storage =
+        makeFlatFileBlockStorage(block_storage_dir,
+                                 std::nullopt,
+                                 std::nullopt,
+                                 log_manager->getChild("Storage"));
+    if (auto first_retained = block_storage->firstRetainedHeight()) {
+      return iroha::expected::makeError(fmt::format(
+          "Blocks from height 2 to {} are pruned from {}, the state can not "
+          "be rebuilt. Restart without --drop_state or copy the block store "
+          "of an archive peer.",
+          *first_retained - 1,
+          block_storage_dir));
+    }
+  } catch (StorageInitException const &e) {
+    return iroha::expected::makeError(
+        fmt::format("Storage initialization failed: {}", e.what()));
   }
+  return {};
 }
@@ -40,6 +40,8 @@ namespace iroha {
       std::shared_ptr<shared_model::interface::QueryResponseFactory>
           query_response_factory,
       boost::optional<std::string> block_storage_dir,
+      std::optional<size_t> block_store_keep_blocks,
+      std::optional<uint32_t> block_store_compression_level,
       std::optional<std::reference_wrapper<const iroha::ametsuchi::VmCaller>>
           vm_caller_ref,
       std::function<void(std::shared_ptr<shared_model::interface::Block const>)>
@@ -54,11 +56,24 @@ namespace iroha {
       std::shared_ptr<shared_model::interface::QueryResponseFactory>
           query_response_factory,
       boost::optional<std::string> block_storage_dir,
+      std::optional<size_t> block_store_keep_blocks,
+      std::optional<uint32_t> block_store_compression_level,
       std::optional<std::reference_wrapper<const iroha::ametsuchi::VmCaller>>
           vm_caller_ref,
       std::function<void(std::shared_ptr<shared_model::interface::Block const>)>
           callback,
       logger::LoggerManagerTreePtr log_manager);
+
+  /**
+   * Check that the WSV can be rebuilt from the flat file block store, i.e.
+   * that no blocks have been pruned from it.
+   * @param block_storage_dir - flat file block store directory
+   * @param log_manager - log manager of the storage
+   * @return error describing the first retained height if the store is pruned
+   */
+  expected::Result<void, std::string> checkBlockStoreReplayable(
+      std::string const &block_storage_dir,
+      logger::LoggerManagerTreePtr log_manager);
 }  // namespace iroha
 
 #endif
@@ -7,6 +7,10 @@
 
 namespace config_members {
   const char *BlockStorePath = "block_store_path";
+  const char *BlockStorePruning = "block_store_pruning";
+  const char *Archive = "archive";
+  const char *KeepBlocks = "keep_blocks";
+  const char *BlockStoreCompressionLevel = "block_store_compression_level";
   const char *ToriiPort = "torii_port";
   const char *ToriiTlsParams = "torii_tls_params";
   const char *InterPeerTls = "inter_peer_tls";
@@ -31,6 +35,36 @@ namespace config_members {
   const char *MaxProposalSize = "max_proposal_size";
   const char *ProposalCreationTimeout = "proposal_creation_timeout";
   const char *HealthcheckPort = "healthcheck_port";
+  const char *BackupPath = "backup_path";
+  const char *SlowQueryThreshold = "slow_query_threshold_ms";
+  const char *MaxQueryCost = "max_query_cost";
+  const char *QueryCostBudget = "query_cost_budget_per_minute";
+  const char *CollectionQueryCost = "collection_query_cost";
+  const char *QueryTimeout = "query_timeout_ms";
+  const char *MaxQueryTimeout = "max_query_timeout_ms";
+  const char *BlockSyncRate = "block_sync_rate_kib";
+  const char *ExternalAddress = "external_address";
+  const char *BlockStream = "block_stream";
+  const char *MaxPending = "max_pending";
+  const char *OnOverflow = "on_overflow";
+  const char *PeerAccess = "peer_access";
+  const char *Allow = "allow";
+  const char *Deny = "deny";
+  const char *MaxInboundCalls = "max_inbound_calls";
+  const char *MaxCallsPerIp = "max_calls_per_ip";
+  const char *AuditLogPath = "audit_log_path";
+  const char *ExplorerStats = "explorer_stats";
+  const char *Profiling = "profiling";
+  const char *MemoryLimits = "memory_limits";
+  const char *TransactionsKib = "transactions_kib";
+  const char *EventsKib = "events_kib";
+  const char *ShutdownTimeout = "shutdown_timeout_ms";
+  const char *EventSink = "event_sink";
+  const char *Url = "url";
+  const char *CursorPath = "cursor_path";
+  const char *Commands = "commands";
+  const char *Accounts = "accounts";
+  const char *MaxQueue = "max_queue";
   const char *MaxProposalPack = "max_proposal_pack";
   const char *VoteDelay = "vote_delay";
   const char *MstSupport = "mst_enable";
@@ -41,6 +75,9 @@ namespace config_members {
   const char *LogLevel = "level";
   const char *LogPatternsSection = "patterns";
   const char *LogChildrenSection = "children";
+  const char *LogFormat = "format";
+  const std::unordered_map<std::string, logger::LogFormat> LogFormats{
+      {"text", logger::LogFormat::kText}, {"json", logger::LogFormat::kJson}};
   const std::unordered_map<std::string, logger::LogLevel> LogLevels{
       {"trace", logger::LogLevel::kTrace},
       {"debug", logger::LogLevel::kDebug},
@@ -52,6 +89,9 @@ namespace config_members {
   const char *Address = "address";
   const char *PublicKey = "public_key";
   const char *InitialPeers = "initial_peers";
+  const char *GenesisFounders = "genesis_founders";
+  const char *PublicKeys = "public_keys";
+  const char *Quorum = "quorum";
   const char *TlsCertificatePath = "tls_certificate_path";
   const char *UtilityService = "utility_service";
   const char *kCrypto = "crypto";
@@ -13,6 +13,10 @@
 
 namespace config_members {
   extern const char *BlockStorePath;
+  extern const char *BlockStorePruning;
+  extern const char *Archive;
+  extern const char *KeepBlocks;
+  extern const char *BlockStoreCompressionLevel;
   extern const char *ToriiPort;
   extern const char *ToriiTlsParams;
   extern const char *InterPeerTls;
@@ -37,6 +41,36 @@ namespace config_members {
   extern const char *MaxProposalSize;
   extern const char *ProposalCreationTimeout;
   extern const char *HealthcheckPort;
+  extern const char *BackupPath;
+  extern const char *SlowQueryThreshold;
+  extern const char *MaxQueryCost;
+  extern const char *QueryCostBudget;
+  extern const char *CollectionQueryCost;
+  extern const char *QueryTimeout;
+  extern const char *MaxQueryTimeout;
+  extern const char *BlockSyncRate;
+  extern const char *ExternalAddress;
+  extern const char *BlockStream;
+  extern const char *MaxPending;
+  extern const char *OnOverflow;
+  extern const char *PeerAccess;
+  extern const char *Allow;
+  extern const char *Deny;
+  extern const char *MaxInboundCalls;
+  extern const char *MaxCallsPerIp;
+  extern const char *AuditLogPath;
+  extern const char *ExplorerStats;
+  extern const char *Profiling;
+  extern const char *MemoryLimits;
+  extern const char *TransactionsKib;
+  extern const char *EventsKib;
+  extern const char *ShutdownTimeout;
+  extern const char *EventSink;
+  extern const char *Url;
+  extern const char *CursorPath;
+  extern const char *Commands;
+  extern const char *Accounts;
+  extern const char *MaxQueue;
   extern const char *MaxProposalPack;
   extern const char *VoteDelay;
   extern const char *MstSupport;
@@ -47,9 +81,14 @@ namespace config_members {
   extern const char *LogLevel;
   extern const char *LogPatternsSection;
   extern const char *LogChildrenSection;
+  extern const char *LogFormat;
+  extern const std::unordered_map<std::string, logger::LogFormat> LogFormats;
   extern const char *MaxPastCreatedHours;
   extern const std::unordered_map<std::string, logger::LogLevel> LogLevels;
   extern const char *InitialPeers;
+  extern const char *GenesisFounders;
+  extern const char *PublicKeys;
+  extern const char *Quorum;
   extern const char *Address;
   extern const char *PublicKey;
   extern const char *TlsCertificatePath;
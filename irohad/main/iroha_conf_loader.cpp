@@ -12,8 +12,10 @@
 #include <functional>
 #include <iterator>
 #include <limits>
+#include <map>
 #include <optional>
 #include <ostream>
+#include <set>
 #include <sstream>
 #include <string>
 #include <string_view>
@@ -42,6 +44,13 @@ static constexpr size_t kBadJsonPrintOffsset = 5;
 
 static char const *kEnvVarPrefix = "IROHA";
 
+/// Shown instead of values which should not end up in logs
+static char const *kHiddenValue = "<hidden>";
+
+#ifndef _WIN32
+extern char **environ;
+#endif
+
 static_assert(kBadJsonPrintOffsset <= kBadJsonPrintLength,
               "The place of error is out of the printed string boundaries!");
 
@@ -114,6 +123,18 @@ std::string makeEnvDictChildKey(std::string_view base_path,
   return makeEnvDictChildKey(base_path, std::to_string(child_key));
 }
 
+/**
+ * Bookkeeping shared by all the nodes of a single configuration load.
+ */
+struct ConfigLoadState {
+  /// printable paths of all values the loader looked for, present or not
+  std::set<std::string> known_paths;
+  /// environment variables the loader looked up
+  std::set<std::string> env_keys;
+  /// loaded values by printable path
+  std::map<std::string, IrohadConfig::ValueOrigin> origins;
+};
+
 /**
  * A class for reading a structure from a JSON node.
  */
@@ -128,7 +149,8 @@ class JsonDeserializerImpl {
         env_path_(kEnvVarPrefix),
         json_(json),
         printable_path_(""),
-        log_(std::move(log)) {}
+        log_(std::move(log)),
+        state_(std::make_shared<ConfigLoadState>()) {}
 
   /**
    * Load the data from rapidjson::Value. Checks the JSON type and throws
@@ -145,6 +167,42 @@ class JsonDeserializerImpl {
     return dest;
   }
 
+  /// Loaded values by printable path, with the layer each one came from
+  const std::map<std::string, IrohadConfig::ValueOrigin> &origins() const {
+    return state_->origins;
+  }
+
+  /**
+   * Throws an exception listing the JSON keys the loader never looked for,
+   * which are most likely misspelled.
+   */
+  void checkUnknownKeys() const {
+    if (not json_) {
+      return;
+    }
+    std::vector<std::string> unknown;
+    collectUnknownKeys(json_->get(), printable_path_, unknown);
+    ::assert_fatal(unknown.empty(),
+                   printable_path_,
+                   fmt::format("unknown configuration keys: {}",
+                               fmt::join(unknown, ", ")));
+  }
+
+  /// Environment variables with the configuration prefix that were not used
+  std::vector<std::string> unusedEnvVars() const {
+    std::vector<std::string> unused;
+    auto const prefix = fmt::format("{}_", kEnvVarPrefix);
+    for (char **var = environ; var and *var; ++var) {
+      std::string_view entry{*var};
+      auto const key = entry.substr(0, entry.find('='));
+      if (key.substr(0, prefix.size()) == prefix
+          and state_->env_keys.count(std::string{key}) == 0) {
+        unused.emplace_back(key);
+      }
+    }
+    return unused;
+  }
+
  private:
   JsonDeserializerImpl(
       std::shared_ptr<shared_model::interface::CommonObjectsFactory>
@@ -152,12 +210,51 @@ class JsonDeserializerImpl {
       std::optional<std::string> env_path,
       std::optional<ConstJsonValRef> json,
       std::string printable_path,
-      std::optional<logger::LoggerPtr> log)
+      std::optional<logger::LoggerPtr> log,
+      std::shared_ptr<ConfigLoadState> state)
       : common_objects_factory_(std::move(common_objects_factory)),
         env_path_(std::move(env_path)),
         json_(json),
         printable_path_(std::move(printable_path)),
-        log_(std::move(log)) {}
+        log_(std::move(log)),
+        state_(std::move(state)) {
+    state_->known_paths.insert(printable_path_);
+  }
+
+  void collectUnknownKeys(const rapidjson::Value &json,
+                          const std::string &path,
+                          std::vector<std::string> &unknown) const {
+    if (json.IsObject()) {
+      for (auto const &member : json.GetObject()) {
+        auto child_path = fmt::format("{}/{}", path, member.name.GetString());
+        if (state_->known_paths.count(child_path) == 0) {
+          unknown.push_back(child_path);
+        } else {
+          collectUnknownKeys(member.value, child_path, unknown);
+        }
+      }
+    } else if (json.IsArray()) {
+      for (size_t i = 0; i < json.Size(); ++i) {
+        collectUnknownKeys(json[i], fmt::format("{}[{}]", path, i), unknown);
+      }
+    }
+  }
+
+  /// Remember the loaded value and the layer it came from
+  void recordOrigin(std::string value, bool from_env) {
+    static const std::vector<std::string_view> kSecretKeys{
+        config_members::PrivateKey,
+        config_members::Password,
+        config_members::PgOpt};
+    auto const key = printable_path_.substr(printable_path_.rfind('/') + 1);
+    if (std::find(kSecretKeys.begin(), kSecretKeys.end(), key)
+        != kSecretKeys.end()) {
+      value = kHiddenValue;
+    }
+    state_->origins[printable_path_] = IrohadConfig::ValueOrigin{
+        std::move(value),
+        from_env ? fmt::format("env {}", env_path_.value()) : "file"};
+  }
 
   JsonDeserializerImpl getDictChild(std::string const &key) {
     return JsonDeserializerImpl{
@@ -173,7 +270,8 @@ class JsonDeserializerImpl {
           return std::nullopt;
         },
         makePrintableDictChildKey(key),
-        log_};
+        log_,
+        state_};
   }
 
   template <typename T>
@@ -204,7 +302,8 @@ class JsonDeserializerImpl {
                                std::nullopt,
                                child_json.value,
                                makePrintableDictChildKey(key),
-                               log_});
+                               log_,
+                               state_});
       }
       return true;
     }
@@ -214,6 +313,7 @@ class JsonDeserializerImpl {
         auto array_el_env_val_prefix = makeEnvDictChildKey(i);
         auto array_el_env_key_key =
             fmt::format("{}_KEY", array_el_env_val_prefix);
+        state_->env_keys.insert(array_el_env_key_key);
         auto array_el_env_key_val = ::getOptEnvRaw(array_el_env_key_key, log_);
         if (not array_el_env_key_val) {
           break;
@@ -225,7 +325,8 @@ class JsonDeserializerImpl {
               array_el_env_val_prefix,
               std::nullopt,
               makePrintableDictChildKey(array_el_env_key_val.value()),
-              log_});
+              log_,
+              state_});
       }
       return have_dict;
     }
@@ -263,6 +364,7 @@ class JsonDeserializerImpl {
 
   std::optional<std::string> getOptEnvRaw() const {
     return env_path_ | [this](auto const &env_path) {
+      state_->env_keys.insert(env_path);
       return ::getOptEnvRaw(env_path.c_str(), log_) | [](std::string_view val) {
         return std::make_optional(std::string{val});
       };
@@ -273,13 +375,15 @@ class JsonDeserializerImpl {
   typename std::enable_if_t<IsInt64Like<TDest> and not std::is_signed_v<TDest>,
                             bool>
   loadInto(TDest &dest) {
+    if (auto from_env = getOptEnvRaw()) {
+      dest = std::strtoull(from_env->data(), nullptr, 10);
+      recordOrigin(std::to_string(dest), true);
+      return true;
+    }
     if (json_) {
       assert_fatal(json_->get().IsUint64(), "must be an unsigned integer");
       dest = json_->get().GetUint64();
-      return true;
-    }
-    if (auto from_env = getOptEnvRaw()) {
-      dest = std::strtoull(from_env->data(), nullptr, 10);
+      recordOrigin(std::to_string(dest), false);
       return true;
     }
     return false;
@@ -289,13 +393,15 @@ class JsonDeserializerImpl {
   typename std::enable_if_t<IsInt64Like<TDest> and std::is_signed_v<TDest>,
                             bool>
   loadInto(TDest &dest) {
+    if (auto from_env = getOptEnvRaw()) {
+      dest = std::strtoull(from_env->data(), nullptr, 10);
+      recordOrigin(std::to_string(dest), true);
+      return true;
+    }
     if (json_) {
       assert_fatal(json_->get().IsInt64(), "must be a signed integer");
       dest = json_->get().GetInt64();
-      return true;
-    }
-    if (auto from_env = getOptEnvRaw()) {
-      dest = std::strtoull(from_env->data(), nullptr, 10);
+      recordOrigin(std::to_string(dest), false);
       return true;
     }
     return false;
@@ -321,16 +427,18 @@ class JsonDeserializerImpl {
                       and fitsType<int64_t>(std::numeric_limits<TBase>::max()),
                   "destination type does not fit int64_t");
     int64_t val;
-    if (json_) {
+    auto from_env = getOptEnvRaw();
+    if (from_env) {
+      val = std::strtoull(from_env->data(), nullptr, 10);
+    } else if (json_) {
       assert_fatal(json_->get().IsInt64(), "must be an integer");
       val = json_->get().GetInt64();
-    } else if (auto from_env = getOptEnvRaw()) {
-      val = std::strtoull(from_env->data(), nullptr, 10);
     } else {
       return false;
     }
     assert_fatal(fitsType<TDest>(val), "integer value out of range");
     reinterpret_cast<TBase &>(dest) = val;
+    recordOrigin(std::to_string(val), from_env.has_value());
     return true;
   }
 
@@ -363,7 +471,8 @@ class JsonDeserializerImpl {
                                        std::nullopt,
                                        arr[i],
                                        makePrintableArrayElemPath(i),
-                                       log_});
+                                       log_,
+                                       state_});
       }
       return true;  // empty vector in JSON is loaded
     }
@@ -374,7 +483,8 @@ class JsonDeserializerImpl {
                                                array_el_env_key_prefix,
                                                std::nullopt,
                                                makePrintableArrayElemPath(i),
-                                               log_})) {
+                                               log_,
+                                               state_})) {
           break;
         }
       }
@@ -451,18 +561,21 @@ class JsonDeserializerImpl {
   std::optional<ConstJsonValRef> json_;
   std::string printable_path_;
   std::optional<logger::LoggerPtr> log_;
+  std::shared_ptr<ConfigLoadState> state_;
 };
 
 // ------------ loadInto(path, dst, src) specializations ------------
 
 template <>
 inline bool JsonDeserializerImpl::loadInto(std::string &dest) {
-  if (json_) {
+  if (auto from_env = getOptEnvRaw()) {
+    dest = std::move(from_env).value();
+    recordOrigin(dest, true);
+    return true;
+  } else if (json_) {
     assert_fatal(json_->get().IsString(), "must be a string");
     dest = json_->get().GetString();
-    return true;
-  } else if (auto from_env = getOptEnvRaw()) {
-    dest = std::move(from_env).value();
+    recordOrigin(dest, false);
     return true;
   }
   return false;
@@ -478,6 +591,23 @@ inline bool JsonDeserializerImpl::loadInto(logger::LogLevel &dest) {
   return true;
 }
 
+template <>
+inline bool JsonDeserializerImpl::loadInto(logger::LogFormat &dest) {
+  std::string format_str;
+  if (not loadInto(format_str)) {
+    return false;
+  }
+  const auto it = config_members::LogFormats.find(format_str);
+  assert_fatal(it != config_members::LogFormats.end(),
+               fmt::format("wrong log format `{}': must be one of `{}'",
+                           format_str,
+                           fmt::join(config_members::LogFormats
+                                         | boost::adaptors::map_keys,
+                                     "', `")));
+  dest = it->second;
+  return true;
+}
+
 template <>
 inline bool JsonDeserializerImpl::loadInto(logger::LogPatterns &dest) {
   return iterateDictChildren(
@@ -491,11 +621,7 @@ inline bool JsonDeserializerImpl::loadInto(logger::LogPatterns &dest) {
 
 template <>
 inline bool JsonDeserializerImpl::loadInto(bool &dest) {
-  if (json_) {
-    assert_fatal(json_->get().IsBool(), "must be a boolean");
-    dest = json_->get().GetBool();
-    return true;
-  } else if (auto from_env = getOptEnvRaw()) {
+  if (auto from_env = getOptEnvRaw()) {
     static std::vector<std::string_view> kTextFalse{"false", "f", "0"};
     static std::vector<std::string_view> kTextTrue{"true", "t", "1"};
     std::string from_env_lower;
@@ -509,13 +635,20 @@ inline bool JsonDeserializerImpl::loadInto(bool &dest) {
     };
     if (has_elem(kTextFalse, from_env_lower)) {
       dest = false;
+      recordOrigin("false", true);
       return true;
     }
     if (has_elem(kTextTrue, from_env_lower)) {
       dest = true;
+      recordOrigin("true", true);
       return true;
     }
-    return false;
+    assert_fatal(false, "must be a boolean");
+  } else if (json_) {
+    assert_fatal(json_->get().IsBool(), "must be a boolean");
+    dest = json_->get().GetBool();
+    recordOrigin(dest ? "true" : "false", false);
+    return true;
   }
   return false;
 }
@@ -635,6 +768,90 @@ inline bool JsonDeserializerImpl::loadInto(IrohadConfig::UtilityService &dest) {
       and getDictChild(config_members::Port).loadInto(dest.port);
 }
 
+template <>
+inline bool JsonDeserializerImpl::loadInto(
+    IrohadConfig::BlockStorePruning &dest) {
+  if (not getDictChild(config_members::Archive).loadInto(dest.archive)) {
+    return false;
+  }
+  if (dest.archive) {
+    dest.keep_blocks = 0;
+    return true;
+  }
+  return getDictChild(config_members::KeepBlocks).loadInto(dest.keep_blocks);
+}
+
+template <>
+inline bool JsonDeserializerImpl::loadInto(
+    IrohadConfig::GenesisFounders &dest) {
+  if (not getDictChild(config_members::PublicKeys).loadInto(dest.public_keys)
+      or not getDictChild(config_members::Quorum).loadInto(dest.quorum)) {
+    return false;
+  }
+  assert_fatal(dest.quorum > 0 and dest.quorum <= dest.public_keys.size(),
+               fmt::format("`{}' must be from 1 to the number of `{}'",
+                           config_members::Quorum,
+                           config_members::PublicKeys));
+  return true;
+}
+
+template <>
+inline bool JsonDeserializerImpl::loadInto(IrohadConfig::EventSink &dest) {
+  if (not getDictChild(config_members::Url).loadInto(dest.url)
+      or not getDictChild(config_members::CursorPath)
+                 .loadInto(dest.cursor_path)) {
+    return false;
+  }
+  // the filters are optional, all transactions are forwarded without them
+  getDictChild(config_members::Commands).loadInto(dest.commands);
+  getDictChild(config_members::Accounts).loadInto(dest.accounts);
+  if (not getDictChild(config_members::MaxQueue).loadInto(dest.max_queue)) {
+    return false;
+  }
+  assert_fatal(not dest.max_queue or *dest.max_queue > 0,
+               fmt::format("`{}' must be positive", config_members::MaxQueue));
+  return true;
+}
+
+template <>
+inline bool JsonDeserializerImpl::loadInto(IrohadConfig::BlockStream &dest) {
+  if (not getDictChild(config_members::MaxPending).loadInto(dest.max_pending)) {
+    return false;
+  }
+  assert_fatal(
+      dest.max_pending > 0,
+      fmt::format("`{}' must be positive", config_members::MaxPending));
+  if (not getDictChild(config_members::OnOverflow).loadInto(dest.on_overflow)) {
+    dest.on_overflow = "disconnect";
+  }
+  assert_fatal(dest.on_overflow == "disconnect" or dest.on_overflow == "drop",
+               fmt::format("`{}' must be `disconnect' or `drop'",
+                           config_members::OnOverflow));
+  return true;
+}
+
+template <>
+inline bool JsonDeserializerImpl::loadInto(IrohadConfig::PeerAccess &dest) {
+  // every field is optional, the section is loaded if any of them is set
+  bool const has_allow =
+      getDictChild(config_members::Allow).loadInto(dest.allow);
+  bool const has_deny = getDictChild(config_members::Deny).loadInto(dest.deny);
+  getDictChild(config_members::MaxInboundCalls)
+      .loadInto(dest.max_inbound_calls);
+  getDictChild(config_members::MaxCallsPerIp).loadInto(dest.max_calls_per_ip);
+  return has_allow or has_deny or dest.max_inbound_calls
+      or dest.max_calls_per_ip;
+}
+
+template <>
+inline bool JsonDeserializerImpl::loadInto(IrohadConfig::MemoryLimits &dest) {
+  // every field is optional, the section is loaded if any of them is set
+  getDictChild(config_members::TransactionsKib)
+      .loadInto(dest.transactions_kib);
+  getDictChild(config_members::EventsKib).loadInto(dest.events_kib);
+  return dest.transactions_kib or dest.events_kib;
+}
+
 template <>
 inline bool JsonDeserializerImpl::loadInto(iroha::multihash::Type &dest) {
   std::string type_str;
@@ -686,6 +903,9 @@ template <>
 inline bool JsonDeserializerImpl::loadInto(IrohadConfig &dest) {
   using namespace config_members;
   return getDictChild(BlockStorePath).loadInto(dest.block_store_path)
+      and getDictChild(BlockStorePruning).loadInto(dest.block_store_pruning)
+      and getDictChild(BlockStoreCompressionLevel)
+              .loadInto(dest.block_store_compression_level)
       and getDictChild(ToriiPort).loadInto(dest.torii_port)
       and getDictChild(ToriiTlsParams).loadInto(dest.torii_tls_params)
       and getDictChild(InterPeerTls).loadInto(dest.inter_peer_tls)
@@ -697,6 +917,26 @@ inline bool JsonDeserializerImpl::loadInto(IrohadConfig &dest) {
               .loadInto(dest.proposal_creation_timeout)
       and getDictChild(MaxProposalPack).loadInto(dest.max_proposal_pack)
       and getDictChild(HealthcheckPort).loadInto(dest.healthcheck_port)
+      and getDictChild(BackupPath).loadInto(dest.backup_path)
+      and getDictChild(SlowQueryThreshold)
+              .loadInto(dest.slow_query_threshold_ms)
+      and getDictChild(MaxQueryCost).loadInto(dest.max_query_cost)
+      and getDictChild(QueryCostBudget)
+              .loadInto(dest.query_cost_budget_per_minute)
+      and getDictChild(CollectionQueryCost)
+              .loadInto(dest.collection_query_cost)
+      and getDictChild(QueryTimeout).loadInto(dest.query_timeout_ms)
+      and getDictChild(MaxQueryTimeout).loadInto(dest.max_query_timeout_ms)
+      and getDictChild(BlockSyncRate).loadInto(dest.block_sync_rate_kib)
+      and getDictChild(ExternalAddress).loadInto(dest.external_address)
+      and getDictChild(BlockStream).loadInto(dest.block_stream)
+      and getDictChild(PeerAccess).loadInto(dest.peer_access)
+      and getDictChild(AuditLogPath).loadInto(dest.audit_log_path)
+      and getDictChild(ExplorerStats).loadInto(dest.explorer_stats)
+      and getDictChild(Profiling).loadInto(dest.profiling)
+      and getDictChild(MemoryLimits).loadInto(dest.memory_limits)
+      and getDictChild(ShutdownTimeout).loadInto(dest.shutdown_timeout_ms)
+      and getDictChild(EventSink).loadInto(dest.event_sink)
       and getDictChild(MaxPastCreatedHours).loadInto(dest.max_past_created_hours)
       and getDictChild(VoteDelay).loadInto(dest.vote_delay)
       and getDictChild(MstSupport).loadInto(dest.mst_support)
@@ -706,6 +946,7 @@ inline bool JsonDeserializerImpl::loadInto(IrohadConfig &dest) {
               .loadInto(dest.stale_stream_max_rounds)
       and getDictChild(LogSection).loadInto(dest.logger_manager)
       and getDictChild(InitialPeers).loadInto(dest.initial_peers)
+      and getDictChild(GenesisFounders).loadInto(dest.genesis_founders)
       and getDictChild(UtilityService).loadInto(dest.utility_service)
       and getDictChild(kCrypto).loadInto(dest.crypto)
       and (getDictChild("metrics").loadInto(dest.metrics_addr_port) or true);
@@ -742,6 +983,7 @@ bool JsonDeserializerImpl::addChildrenLoggerConfigs(
 void JsonDeserializerImpl::updateLoggerConfig(logger::LoggerConfig &cfg) {
   getDictChild(config_members::LogLevel).loadInto(cfg.log_level);
   getDictChild(config_members::LogPatternsSection).loadInto(cfg.patterns);
+  getDictChild(config_members::LogFormat).loadInto(cfg.format);
 }
 
 void reportJsonParsingError(const rapidjson::Document &doc,
@@ -759,6 +1001,27 @@ void reportJsonParsingError(const rapidjson::Document &doc,
   }
 }
 
+/**
+ * Check the block store pruning options against the rest of the config
+ * @return the error description if the options are invalid
+ */
+std::optional<std::string> checkBlockStorePruning(IrohadConfig const &config) {
+  using namespace config_members;
+  if (not config.getBlockStoreKeepBlocks()) {
+    return std::nullopt;
+  }
+  if (*config.getBlockStoreKeepBlocks() == 0) {
+    return fmt::format(
+        "/{}/{}: must be positive", BlockStorePruning, KeepBlocks);
+  }
+  if (not config.block_store_path) {
+    return fmt::format("/{}: pruning requires `{}' to be set",
+                       BlockStorePruning,
+                       BlockStorePath);
+  }
+  return std::nullopt;
+}
+
 // TODO mboldyrev 2019.05.06 IR-465 make config loader testable
 iroha::expected::Result<IrohadConfig, std::string> parse_iroha_config(
     const std::string &conf_path,
@@ -783,13 +1046,33 @@ iroha::expected::Result<IrohadConfig, std::string> parse_iroha_config(
       return std::make_optional(std::move(doc));
     }};
 
-    JsonDeserializerImpl parser(common_objects_factory, doc, std::move(log));
-    return parser.deserialize<IrohadConfig>();
+    JsonDeserializerImpl parser(common_objects_factory, doc, log);
+    auto config = parser.deserialize<IrohadConfig>();
+    parser.checkUnknownKeys();
+    if (auto error = checkBlockStorePruning(config)) {
+      return *std::move(error);
+    }
+    config.origins = parser.origins();
+    if (log) {
+      for (auto const &var : parser.unusedEnvVars()) {
+        log.value()->warn("Environment variable {} is not a configuration "
+                          "parameter and is ignored",
+                          var);
+      }
+    }
+    return config;
   } catch (ConfigParsingException const &e) {
     return e.what();
   };
 }
 
+void printConfigOrigins(IrohadConfig const &config, std::ostream &out) {
+  for (auto const &[path, origin] : config.origins) {
+    out << fmt::format("{} = {} ({})", path, origin.value, origin.source)
+        << std::endl;
+  }
+}
+
 uint32_t IrohadConfig::getProposalDelay() const {
   return getProposalCreationTimeout() * 2ul;
 }
@@ -797,3 +1080,10 @@ uint32_t IrohadConfig::getProposalDelay() const {
 uint32_t IrohadConfig::getProposalCreationTimeout() const {
   return proposal_creation_timeout.value_or(3000ul);
 }
+
+std::optional<size_t> IrohadConfig::getBlockStoreKeepBlocks() const {
+  if (block_store_pruning and not block_store_pruning->archive) {
+    return block_store_pruning->keep_blocks;
+  }
+  return std::nullopt;
+}
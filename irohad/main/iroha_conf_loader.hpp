@@ -6,8 +6,12 @@
 #ifndef IROHA_CONF_LOADER_HPP
 #define IROHA_CONF_LOADER_HPP
 
+#include <iosfwd>
+#include <map>
+#include <optional>
 #include <string>
 #include <unordered_map>
+#include <vector>
 
 #include "common/result_fwd.hpp"
 #include "interfaces/common_objects/common_objects_factory.hpp"
@@ -49,9 +53,73 @@ struct IrohadConfig {
     uint16_t port;
   };
 
+  struct BlockStorePruning {
+    /// keep every block, the default for a node without pruning config
+    bool archive;
+    /// number of the most recent blocks to keep when not in archive mode
+    uint64_t keep_blocks;
+  };
+
+  struct GenesisFounders {
+    /// hex public keys of the founders allowed to sign the genesis block
+    std::vector<std::string> public_keys;
+    /// number of founder signatures the genesis block must have
+    uint32_t quorum;
+  };
+
+  struct EventSink {
+    /// receiver of the events, http://host:port/path or
+    /// nats://host:port/subject
+    std::string url;
+    /// file with the height of the last delivered block
+    std::string cursor_path;
+    /// forward only transactions with these commands, all if empty
+    std::vector<std::string> commands;
+    /// forward only transactions created by these accounts, all if empty
+    std::vector<std::string> accounts;
+    /// maximal number of undelivered events kept in memory
+    std::optional<uint32_t> max_queue;
+  };
+
+  struct PeerAccess {
+    /// IP addresses of peers allowed to connect, all if empty
+    std::vector<std::string> allow;
+    /// IP addresses of peers never allowed to connect
+    std::vector<std::string> deny;
+    /// maximal number of inbound peer calls served at once
+    std::optional<uint32_t> max_inbound_calls;
+    /// maximal number of inbound calls from one IP address served at once
+    std::optional<uint32_t> max_calls_per_ip;
+  };
+
+  struct MemoryLimits {
+    /// payloads of the transactions waiting in the ordering service
+    std::optional<uint64_t> transactions_kib;
+    /// events waiting to be delivered by the event sink
+    std::optional<uint64_t> events_kib;
+  };
+
+  struct BlockStream {
+    /// maximal number of blocks waiting to be sent to a subscriber
+    uint32_t max_pending;
+    /// `disconnect' or `drop' the blocks over the limit
+    std::string on_overflow;
+  };
+
+  struct ValueOrigin {
+    /// loaded value as text, secrets are hidden
+    std::string value;
+    /// configuration layer the value was taken from, e.g. `file' or
+    /// `env IROHA_TORII_PORT'
+    std::string source;
+  };
+
   // TODO: block_store_path is now optional, change docs IR-576
   // luckychess 29.06.2019
   boost::optional<std::string> block_store_path;
+  boost::optional<BlockStorePruning> block_store_pruning;
+  /// zstd level for the flat file block store, uncompressed if unset
+  std::optional<uint32_t> block_store_compression_level;
   uint16_t torii_port;
   boost::optional<iroha::torii::TlsParams> torii_tls_params;
   boost::optional<InterPeerTls> inter_peer_tls;
@@ -68,16 +136,53 @@ struct IrohadConfig {
   boost::optional<uint32_t> max_round_delay_ms;
   boost::optional<uint32_t> proposal_creation_timeout;
   boost::optional<uint32_t> healthcheck_port;
+  /// directory for online database snapshots taken via the HTTP server
+  boost::optional<std::string> backup_path;
+  /// queries running at least this long are logged, not logged if unset
+  std::optional<uint32_t> slow_query_threshold_ms;
+  /// queries estimated to cost more are rejected, no limit if unset
+  std::optional<uint64_t> max_query_cost;
+  /// total query cost each account may spend per minute, no limit if unset
+  std::optional<uint64_t> query_cost_budget_per_minute;
+  /// cost of the queries returning collections which can not be paginated
+  std::optional<uint64_t> collection_query_cost;
+  /// queries running longer are aborted, unless the call sets a deadline
+  std::optional<uint32_t> query_timeout_ms;
+  /// longest execution time the deadline of a call may set for a query
+  std::optional<uint32_t> max_query_timeout_ms;
+  /// KiB per second of blocks streamed to syncing peers, no limit if unset
+  std::optional<uint32_t> block_sync_rate_kib;
+  /// host:port other peers reach this peer at, e.g. behind a NAT
+  std::optional<std::string> external_address;
+  /// buffering of blocks for slow FetchCommits subscribers
+  std::optional<BlockStream> block_stream;
+  /// restrictions of inbound connections of other peers
+  std::optional<PeerAccess> peer_access;
+  /// file of the hash-chained log of privileged commands
+  std::optional<std::string> audit_log_path;
+  /// whether chain statistics for block explorers are collected
+  std::optional<bool> explorer_stats;
+  /// whether CPU profiles can be collected through the HTTP server
+  std::optional<bool> profiling;
+  /// memory limits of the buffers filled by the clients and the peers
+  std::optional<MemoryLimits> memory_limits;
+  /// time the peer takes to stop before exiting forcibly
+  std::optional<uint32_t> shutdown_timeout_ms;
+  /// forwarding of committed blocks to an external receiver
+  boost::optional<EventSink> event_sink;
   boost::optional<uint32_t> max_proposal_pack;
   boost::optional<uint32_t> stale_stream_max_rounds;
   boost::optional<logger::LoggerManagerTreePtr> logger_manager;
   std::optional<shared_model::interface::types::PeerList> initial_peers;
+  boost::optional<GenesisFounders> genesis_founders;
   boost::optional<UtilityService> utility_service;
   std::optional<uint32_t> max_past_created_hours;
   // getters
   uint32_t getMaxpProposalPack() const;
   uint32_t getProposalDelay() const;
   uint32_t getProposalCreationTimeout() const;
+  /// number of blocks to retain in the block store, unset for archive mode
+  std::optional<size_t> getBlockStoreKeepBlocks() const;
 
   // This is a part of cryto providers feature:
   // https://github.com/MBoldyrev/iroha/tree/feature/hsm-utimaco.
@@ -101,10 +206,22 @@ struct IrohadConfig {
   boost::optional<Crypto> crypto;
 
   std::string metrics_addr_port;
+
+  /// origins of all loaded values by path, e.g. `/database/port'
+  std::map<std::string, ValueOrigin> origins;
 };
 
 /**
- * parse and assert trusted peers json in `iroha.conf`
+ * Print every loaded value of the configuration with its origin, one per line
+ * @param config - the loaded configuration
+ * @param out - stream to print to
+ */
+void printConfigOrigins(IrohadConfig const &config, std::ostream &out);
+
+/**
+ * parse and assert trusted peers json in `iroha.conf`. Environment variables
+ * take precedence over the values from the file. Keys of the file which the
+ * loader does not know are reported as an error.
  * @param conf_text is the contents of iroha's config file
  * @return a parsed equivalent of that file
  */
@@ -20,6 +20,12 @@ namespace iroha {
     std::optional<consensus::Round> last_round;
     std::optional<bool> is_syncing;
     std::optional<bool> is_healthy;
+    /// whether the advertised address of the peer accepts connections
+    std::optional<bool> is_reachable;
+    /// identifier of the network, set by the ChainId setting
+    std::optional<std::string> chain_id;
+    /// oldest block kept besides the genesis block if the blocks are pruned
+    std::optional<uint64_t> first_retained_height;
   };
 
   struct IrohaStoredStatus {
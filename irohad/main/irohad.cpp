@@ -8,10 +8,14 @@
 
 #include <chrono>
 #include <csignal>
+#include <cstdlib>
 #include <fstream>
 #include <future>
+#include <iostream>
 #include <thread>
 
+#include <boost/filesystem.hpp>
+
 #include "ametsuchi/storage.hpp"
 #include "backend/protobuf/common_objects/proto_common_objects_factory.hpp"
 #include "common/bind.hpp"
@@ -25,11 +29,15 @@
 #include "logger/logger.hpp"
 #include "logger/logger_manager.hpp"
 #include "main/application.hpp"
+#include "main/config_reload.hpp"
+#include "main/genesis_schema.hpp"
+#include "main/genesis_signatures.hpp"
 #include "main/impl/pg_connection_init.hpp"
 #include "main/impl/rocksdb_connection_init.hpp"
 #include "main/iroha_conf_literals.hpp"
 #include "main/iroha_conf_loader.hpp"
 #include "main/raw_block_loader.hpp"
+#include "main/secret_source.hpp"
 #include "maintenance/metrics.hpp"
 #include "network/impl/channel_factory.hpp"
 #include "util/status_notifier.hpp"
@@ -45,6 +53,7 @@ static const std::string kListenIp = "0.0.0.0";
 static const std::string kLogSettingsFromConfigFile = "config_file";
 static const std::string kDefaultWorkingDatabaseName{"iroha_default"};
 static const std::chrono::milliseconds kExitCheckPeriod{1000};
+static const std::chrono::milliseconds kDefaultShutdownTimeout{10000};
 
 /**
  * Creating input argument for the configuration file location.
@@ -121,7 +130,21 @@ DEFINE_bool(exit_after_init, false, "Use this flag to reindex WSV and exit");
 
 DEFINE_bool(syncing_node, false, "Use this flag to run iroha as syncing node");
 
+DEFINE_bool(genesis_schema,
+            false,
+            "Print JSON Schema of the genesis block and exit");
+
+DEFINE_bool(genesis_dry_run,
+            false,
+            "Apply the genesis block to a temporary state and exit");
+
+DEFINE_bool(print_config,
+            false,
+            "Print the resolved configuration with the origin of each value "
+            "and exit");
+
 std::sig_atomic_t caught_signal = 0;
+std::sig_atomic_t reload_requested = 0;
 std::promise<void> exit_requested;
 
 std::shared_ptr<iroha::utility_service::UtilityService> utility_service;
@@ -140,8 +163,13 @@ static shared_model::crypto::Keypair getKeypairFromConfig(
   }
   auto const &signer = provider_it->second;
 
+  auto const private_key_hex =
+      iroha::main::resolveSecret(signer.private_key.value());
+  if (auto e = iroha::expected::resultToOptionalError(private_key_hex)) {
+    throw std::runtime_error{e.value()};
+  }
   shared_model::crypto::PrivateKey private_key{
-      iroha::hexstringToBytestringResult(signer.private_key.value())
+      iroha::hexstringToBytestringResult(private_key_hex.assumeValue())
           .assumeValue()};
 
   switch (signer.type) {
@@ -211,6 +239,32 @@ getCommonObjectsFactory() {
       shared_model::validation::FieldValidator>>(validators_config);
 }
 
+/**
+ * Re-read the configuration file and apply the settings which can be changed
+ * without a restart
+ */
+void reloadConfig(const IrohadConfig &config,
+                  const logger::LoggerManagerTreePtr &log_manager,
+                  const logger::LoggerPtr &log) {
+  log->info("Reloading configuration from {}", FLAGS_config);
+  auto updated =
+      parse_iroha_config(FLAGS_config, getCommonObjectsFactory(), {log});
+  if (auto e = iroha::expected::resultToOptionalError(updated)) {
+    log->error("Failed reading the configuration: {}", e.value());
+    return;
+  }
+  auto report = iroha::main::reloadConfig(
+      config,
+      updated.assumeValue(),
+      FLAGS_verbosity == kLogSettingsFromConfigFile ? log_manager : nullptr);
+  for (auto const &setting : report.applied) {
+    log->info("Configuration reloaded: {}", setting);
+  }
+  for (auto const &setting : report.rejected) {
+    log->warn("Configuration change rejected: {}", setting);
+  }
+}
+
 int main(int argc, char *argv[]) {
   auto version = std::string(iroha::kGitPrettyVersion);
 #if defined(USE_BURROW)
@@ -224,6 +278,11 @@ int main(int argc, char *argv[]) {
   // Parsing command line arguments
   gflags::ParseCommandLineFlags(&argc, &argv, true);
 
+  if (FLAGS_genesis_schema) {
+    std::cout << iroha::main::makeGenesisSchema() << std::endl;
+    return EXIT_SUCCESS;
+  }
+
   logger::LoggerManagerTreePtr log_manager = getDefaultLogManager();
   logger::LoggerPtr log = log_manager->getChild("Init")->getLogger();
 
@@ -248,6 +307,23 @@ int main(int argc, char *argv[]) {
     auto config = std::move(config_result).assumeValue();
     config.syncing_mode = FLAGS_syncing_node;
 
+    // command line flags override both the file and the environment
+    if (FLAGS_verbosity != kLogSettingsFromConfigFile) {
+      config.origins[fmt::format("/{}/{}",
+                                 config_members::LogSection,
+                                 config_members::LogLevel)] =
+          IrohadConfig::ValueOrigin{FLAGS_verbosity, "flag --verbosity"};
+    }
+    if (FLAGS_metrics_port.size()) {
+      config.origins["/metrics"] = IrohadConfig::ValueOrigin{
+        FLAGS_metrics_addr + ":" + FLAGS_metrics_port,
+        "flag --metrics_addr, --metrics_port"};
+    }
+    if (FLAGS_print_config) {
+      printConfigOrigins(config, std::cout);
+      return EXIT_SUCCESS;
+    }
+
     if (FLAGS_verbosity == kLogSettingsFromConfigFile) {
       log_manager = config.logger_manager.value_or(getDefaultLogManager());
       log = log_manager->getChild("Init")->getLogger();
@@ -263,6 +339,28 @@ int main(int argc, char *argv[]) {
       return EXIT_FAILURE;
     }
 
+    // the temporary state of a dry run is removed whatever the outcome is
+    std::shared_ptr<void> dry_run_state;
+    if (FLAGS_genesis_dry_run) {
+      if (FLAGS_genesis_block.empty()) {
+        log->critical("--genesis_dry_run requires --genesis_block.");
+        return EXIT_FAILURE;
+      }
+      auto path = boost::filesystem::temp_directory_path()
+          / boost::filesystem::unique_path();
+      log->info("Genesis dry run uses temporary state at {}", path.string());
+      config.database_config = IrohadConfig::DbConfig{};
+      config.database_config->type = kDbTypeRocksdb;
+      config.database_config->path = path.string();
+      config.pg_opt = boost::none;
+      config.block_store_path = boost::none;
+      config.utility_service = boost::none;
+      dry_run_state = std::shared_ptr<void>(nullptr, [path](void *) {
+        boost::system::error_code ec;
+        boost::filesystem::remove_all(path, ec);
+      });
+    }
+
     if (config.utility_service) {
       initUtilityService(config.utility_service.value(),
                          [] {
@@ -374,7 +472,9 @@ int main(int argc, char *argv[]) {
       } else {
         auto block_result =
             iroha::readTextFile(FLAGS_genesis_block) | [](const auto &json) {
-              return iroha::main::BlockLoader::parseBlock(json);
+              return iroha::main::validateGenesisJson(json) | [&json] {
+                return iroha::main::BlockLoader::parseBlock(json);
+              };
             };
 
         if (auto e = iroha::expected::resultToOptionalError(block_result)) {
@@ -383,6 +483,17 @@ int main(int argc, char *argv[]) {
         }
         auto block = std::move(block_result).assumeValue();
 
+        if (config.genesis_founders) {
+          auto verified = iroha::main::verifyGenesisSignatures(
+              *block,
+              config.genesis_founders->public_keys,
+              config.genesis_founders->quorum);
+          if (auto e = iroha::expected::resultToOptionalError(verified)) {
+            log->error("Genesis block is rejected: {}", e.value());
+            return EXIT_FAILURE;
+          }
+        }
+
         if (not blockstore and overwrite) {
           log->warn(
               "Blockstore is empty - there is nothing to overwrite. Inserting "
@@ -408,6 +519,10 @@ int main(int argc, char *argv[]) {
         }
         log->info("Genesis block inserted, number of transactions: {}",
                   txs_num);
+        if (FLAGS_genesis_dry_run) {
+          log->info("Genesis block dry run succeeded.");
+          return EXIT_SUCCESS;
+        }
       }
     } else {  // genesis block file is not specified
       if (not blockstore) {
@@ -472,6 +587,9 @@ int main(int argc, char *argv[]) {
 #ifdef SIGQUIT
     std::signal(SIGQUIT, handler);
 #endif
+#ifdef SIGHUP
+    std::signal(SIGHUP, [](int) { reload_requested = 1; });
+#endif
 
     // start metrics
     std::shared_ptr<Metrics> metrics;  // Must be a pointer because 'this' is
@@ -514,15 +632,30 @@ int main(int argc, char *argv[]) {
         log->warn("Caught signal {}, exiting.", caught_signal);
         break;
       }
+      if (reload_requested != 0) {
+        reload_requested = 0;
+        reloadConfig(config, log_manager, log);
+      }
     }
     irohad->printDbStatus();
     daemon_status_notifier->notify(
         ::iroha::utility_service::Status::kTermination);
 
-    // We do not care about shutting down grpc servers
-    // They do all necessary work in their destructors
     log->info("shutting down...");
 
+    // the calls in progress may take half of the timeout, the rest is left
+    // for the block being committed and the storage flush
+    auto const shutdown_timeout = config.shutdown_timeout_ms
+        ? std::chrono::milliseconds(*config.shutdown_timeout_ms)
+        : kDefaultShutdownTimeout;
+    std::thread([shutdown_timeout, log] {
+      std::this_thread::sleep_for(shutdown_timeout);
+      log->critical("Shutdown did not finish in {} ms, exiting",
+                    shutdown_timeout.count());
+      std::_Exit(EXIT_FAILURE);
+    }).detach();
+    irohad->shutdown(std::chrono::system_clock::now() + shutdown_timeout / 2);
+
     irohad.reset();
     daemon_status_notifier->notify(::iroha::utility_service::Status::kStopped);
 
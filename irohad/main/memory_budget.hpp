@@ -0,0 +1,74 @@
+/**
+ * Copyright Soramitsu Co., Ltd. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+#ifndef IROHA_MEMORY_BUDGET_HPP
+#define IROHA_MEMORY_BUDGET_HPP
+
+#include <atomic>
+#include <cstddef>
+#include <optional>
+
+namespace iroha {
+
+  /**
+   * Memory used by the buffers which are filled at the pace of the clients
+   * and of the other peers. While a buffer is over its limit, the producers
+   * are pushed back instead of growing the buffer until the peer runs out of
+   * memory.
+   */
+  class MemoryBudget {
+   public:
+    enum class Buffer {
+      /// payloads of the transactions waiting in the ordering service
+      kTransactions,
+      /// events waiting to be delivered by the event sink
+      kEvents
+    };
+
+    /// Limits of the buffers in bytes, unlimited if unset
+    struct Limits {
+      std::optional<size_t> transactions;
+      std::optional<size_t> events;
+    };
+
+    explicit MemoryBudget(Limits limits = {}) : limits_(limits) {}
+
+    MemoryBudget(MemoryBudget const &) = delete;
+    MemoryBudget &operator=(MemoryBudget const &) = delete;
+
+    /// Set the number of bytes currently used by the buffer
+    void set(Buffer buffer, size_t bytes) {
+      usage(buffer).store(bytes, std::memory_order_relaxed);
+    }
+
+    size_t used(Buffer buffer) const {
+      return usage(buffer).load(std::memory_order_relaxed);
+    }
+
+    /// @return true if the buffer has reached its limit, so new data for it
+    /// has to be rejected or delayed
+    bool exhausted(Buffer buffer) const {
+      auto const &limit = buffer == Buffer::kTransactions ? limits_.transactions
+                                                          : limits_.events;
+      return limit and used(buffer) >= *limit;
+    }
+
+   private:
+    std::atomic<size_t> &usage(Buffer buffer) {
+      return buffer == Buffer::kTransactions ? transactions_ : events_;
+    }
+
+    std::atomic<size_t> const &usage(Buffer buffer) const {
+      return buffer == Buffer::kTransactions ? transactions_ : events_;
+    }
+
+    Limits const limits_;
+    std::atomic<size_t> transactions_{0};
+    std::atomic<size_t> events_{0};
+  };
+
+}  // namespace iroha
+
+#endif  // IROHA_MEMORY_BUDGET_HPP
@@ -0,0 +1,28 @@
+/**
+ * Copyright Soramitsu Co., Ltd. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+#ifndef IROHA_P2P_TRAFFIC_STATS_HPP
+#define IROHA_P2P_TRAFFIC_STATS_HPP
+
+#include <chrono>
+#include <cstddef>
+#include <string>
+
+namespace iroha {
+
+  struct P2pTrafficStats {
+    /// class of the sent message, e.g. block_sync
+    std::string message_class;
+    /// serialized message size in bytes
+    size_t bytes;
+    /// time the message waited for bandwidth
+    std::chrono::microseconds throttled;
+    /// number of senders of this class waiting for bandwidth
+    size_t waiting;
+  };
+
+}  // namespace iroha
+
+#endif  // IROHA_P2P_TRAFFIC_STATS_HPP
@@ -0,0 +1,27 @@
+/**
+ * Copyright Soramitsu Co., Ltd. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+#ifndef IROHA_QUERY_STATS_HPP
+#define IROHA_QUERY_STATS_HPP
+
+#include <chrono>
+#include <cstddef>
+#include <string>
+
+namespace iroha {
+
+  struct QueryStats {
+    /// query type, e.g. get_account_transactions
+    std::string type;
+    std::chrono::microseconds duration;
+    /// serialized response size in bytes
+    size_t response_size;
+    /// whether the response is an error response
+    bool failed;
+  };
+
+}  // namespace iroha
+
+#endif  // IROHA_QUERY_STATS_HPP
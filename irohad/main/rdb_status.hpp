@@ -16,6 +16,8 @@ namespace iroha {
     std::optional<uint64_t> all_mem_tables_sz;
     std::optional<uint64_t> num_snapshots;
     std::optional<uint64_t> sst_files_size;
+    std::optional<uint64_t> wsv_size;
+    std::optional<uint64_t> block_store_size;
   };
 
 }  // namespace iroha
@@ -0,0 +1,32 @@
+/**
+ * Copyright Soramitsu Co., Ltd. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+#ifndef IROHA_REACHABILITY_CHECK_HPP
+#define IROHA_REACHABILITY_CHECK_HPP
+
+#include <chrono>
+#include <string>
+
+#include "common/result_fwd.hpp"
+
+namespace iroha {
+  namespace main {
+
+    /**
+     * Check that a TCP connection to the address can be established. A peer
+     * checks its own advertised address this way, so a NAT without hairpin
+     * support reports the address as unreachable even when it is reachable
+     * from the outside.
+     * @param address - address in host:port form
+     * @param timeout - time to wait for the connection
+     * @return void value if connected, error message otherwise
+     */
+    iroha::expected::Result<void, std::string> checkReachable(
+        const std::string &address, std::chrono::milliseconds timeout);
+
+  }  // namespace main
+}  // namespace iroha
+
+#endif  // IROHA_REACHABILITY_CHECK_HPP
@@ -0,0 +1,36 @@
+/**
+ * Copyright Soramitsu Co., Ltd. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+#ifndef IROHA_SECRET_SOURCE_HPP
+#define IROHA_SECRET_SOURCE_HPP
+
+#include <string>
+
+#include "common/result_fwd.hpp"
+
+namespace iroha {
+  namespace main {
+
+    /**
+     * Resolve a secret, e.g. a private key, from the source the reference
+     * points to:
+     *  - `file:<path>' - contents of the file;
+     *  - `env:<variable>' - value of the environment variable;
+     *  - `exec:<command>' - standard output of the shell command;
+     *  - `vault:<url>#<field>' - field of a HashiCorp Vault secret read over
+     *    HTTP with the token from VAULT_TOKEN environment variable, e.g.
+     *    vault:http://127.0.0.1:8200/v1/secret/data/peer0#private_key
+     * Leading and trailing whitespace of the resolved secret is removed.
+     * A reference without a known prefix is the secret itself.
+     * @param reference - secret or reference to it
+     * @return the secret or error message
+     */
+    iroha::expected::Result<std::string, std::string> resolveSecret(
+        const std::string &reference);
+
+  }  // namespace main
+}  // namespace iroha
+
+#endif  // IROHA_SECRET_SOURCE_HPP
@@ -8,10 +8,12 @@
 #include <grpc/impl/codegen/grpc_types.h>
 
 #include <boost/format.hpp>
+#include <charconv>
 #include <chrono>
 
 #include "logger/logger.hpp"
 #include "network/channel_constants.hpp"
+#include "network/impl/in_process_network.hpp"
 #include "network/impl/tls_credentials.hpp"
 
 using namespace iroha::network;
@@ -20,30 +22,62 @@ namespace {
 
   std::shared_ptr<grpc::ServerCredentials> createCredentials(
       const boost::optional<std::shared_ptr<const TlsCredentials>>
-          &my_tls_creds) {
+          &my_tls_creds,
+      const boost::optional<std::string> &client_root_cert,
+      std::shared_ptr<grpc::AuthMetadataProcessor> auth_processor) {
     if (not my_tls_creds) {
       return grpc::InsecureServerCredentials();
     }
-    auto options = grpc::SslServerCredentialsOptions(
-        GRPC_SSL_DONT_REQUEST_CLIENT_CERTIFICATE);
+    auto client_certificate_request = GRPC_SSL_DONT_REQUEST_CLIENT_CERTIFICATE;
+    if (client_root_cert) {
+      client_certificate_request =
+          GRPC_SSL_REQUEST_AND_REQUIRE_CLIENT_CERTIFICATE_AND_VERIFY;
+    } else if (auth_processor) {
+      // the auth processor decides which certificates are accepted
+      client_certificate_request =
+          GRPC_SSL_REQUEST_CLIENT_CERTIFICATE_BUT_DONT_VERIFY;
+    }
+    auto options =
+        grpc::SslServerCredentialsOptions(client_certificate_request);
+    if (client_root_cert) {
+      options.pem_root_certs = *client_root_cert;
+    }
     grpc::SslServerCredentialsOptions::PemKeyCertPair keypair = {
         my_tls_creds.value()->private_key, my_tls_creds.value()->certificate};
     options.pem_key_cert_pairs.push_back(keypair);
     std::shared_ptr<grpc::ServerCredentials> credentials =
         grpc::SslServerCredentials(options);
+    if (auth_processor) {
+      credentials->SetAuthMetadataProcessor(std::move(auth_processor));
+    }
     return credentials;
   }
 
+  /// @return the port of address in host:port form, 0 if there is none
+  int getPort(const std::string &address) {
+    int port = 0;
+    auto separator = address.rfind(':');
+    if (separator != std::string::npos) {
+      std::from_chars(address.data() + separator + 1,
+                      address.data() + address.size(),
+                      port);
+    }
+    return port;
+  }
+
 }  // namespace
 
 ServerRunner::ServerRunner(
     const std::string &address,
     logger::LoggerPtr log,
     bool reuse,
-    const boost::optional<std::shared_ptr<const TlsCredentials>> &my_tls_creds)
+    const boost::optional<std::shared_ptr<const TlsCredentials>> &my_tls_creds,
+    const boost::optional<std::string> &client_root_cert,
+    std::shared_ptr<grpc::AuthMetadataProcessor> auth_processor)
     : log_(std::move(log)),
       server_address_(address),
-      credentials_(createCredentials(my_tls_creds)),
+      credentials_(createCredentials(
+          my_tls_creds, client_root_cert, std::move(auth_processor))),
       reuse_(reuse) {}
 
 ServerRunner::~ServerRunner() {
@@ -56,12 +90,28 @@ ServerRunner &ServerRunner::append(std::shared_ptr<grpc::Service> service) {
   return *this;
 }
 
+ServerRunner &ServerRunner::intercept(
+    std::unique_ptr<InterceptorFactory> factory) {
+  interceptors_.push_back(std::move(factory));
+  return *this;
+}
+
+ServerRunner &ServerRunner::inProcess(
+    std::shared_ptr<InProcessNetwork> network) {
+  in_process_network_ = std::move(network);
+  return *this;
+}
+
 iroha::expected::Result<int, std::string> ServerRunner::run() {
   grpc::ServerBuilder builder;
   int selected_port = 0;
 
-  builder.AddChannelArgument(GRPC_ARG_ALLOW_REUSEPORT, reuse_ ? 1 : 0);
-  builder.AddListeningPort(server_address_, credentials_, &selected_port);
+  if (in_process_network_) {
+    selected_port = getPort(server_address_);
+  } else {
+    builder.AddChannelArgument(GRPC_ARG_ALLOW_REUSEPORT, reuse_ ? 1 : 0);
+    builder.AddListeningPort(server_address_, credentials_, &selected_port);
+  }
   builder.SetMaxReceiveMessageSize(kMaxMessageSize);
   builder.SetMaxSendMessageSize(kMaxMessageSize);
 
@@ -72,9 +122,17 @@ iroha::expected::Result<int, std::string> ServerRunner::run() {
   // enable retry policy
   builder.AddChannelArgument(GRPC_ARG_ENABLE_RETRIES, 1);
 
+  if (not interceptors_.empty()) {
+    builder.experimental().SetInterceptorCreators(std::move(interceptors_));
+  }
+
   server_instance_ = builder.BuildAndStart();
   server_instance_cv_.notify_one();
 
+  if (in_process_network_ and server_instance_) {
+    in_process_network_->add(server_address_, *server_instance_);
+  }
+
   if (selected_port == 0) {
     return iroha::expected::makeError(
         fmt::format("Cannot bind server to address {}", server_address_));
@@ -91,6 +149,9 @@ void ServerRunner::waitForServersReady() {
 }
 
 void ServerRunner::shutdown() {
+  if (in_process_network_) {
+    in_process_network_->remove(server_address_);
+  }
   if (server_instance_) {
     server_instance_->Shutdown();
   } else {
@@ -100,6 +161,9 @@ void ServerRunner::shutdown() {
 
 void ServerRunner::shutdown(
     const std::chrono::system_clock::time_point &deadline) {
+  if (in_process_network_) {
+    in_process_network_->remove(server_address_);
+  }
   if (server_instance_) {
     server_instance_->Shutdown(deadline);
   } else {
@@ -10,31 +10,44 @@
 
 #include <grpc++/grpc++.h>
 #include <grpc++/impl/codegen/service_type.h>
+#include <grpc++/support/server_interceptor.h>
 #include "common/result.hpp"
 #include "logger/logger_fwd.hpp"
 
 namespace iroha {
   namespace network {
     struct TlsCredentials;
+    class InProcessNetwork;
 
     /**
      * Class runs Torii server for handling queries and commands.
      */
     class ServerRunner {
      public:
+      using InterceptorFactory =
+          grpc::experimental::ServerInterceptorFactoryInterface;
+
       /**
        * Constructor. Initialize a new instance of ServerRunner class.
        * @param address - the address the server will be bind to in URI form
        * @param log to print progress to
        * @param reuse - allow multiple sockets to bind to the same port
        * @param my_tls_creds - TLS credentials_ for this server, if required
+       * @param client_root_cert - when set together with my_tls_creds,
+       * clients must present a certificate signed by this root certificate
+       * @param auth_processor - when set together with my_tls_creds,
+       * authenticates every incoming call, and clients are asked for a
+       * certificate even without client_root_cert
        */
       explicit ServerRunner(
           const std::string &address,
           logger::LoggerPtr log,
           bool reuse = true,
           const boost::optional<std::shared_ptr<const TlsCredentials>>
-              &my_tls_creds = boost::none);
+              &my_tls_creds = boost::none,
+          const boost::optional<std::string> &client_root_cert = boost::none,
+          std::shared_ptr<grpc::AuthMetadataProcessor> auth_processor =
+              nullptr);
 
       ~ServerRunner();
 
@@ -45,9 +58,25 @@ namespace iroha {
        */
       ServerRunner &append(std::shared_ptr<grpc::Service> service);
 
+      /**
+       * Adds an interceptor of the calls to all services.
+       * @param factory - factory of the interceptors
+       * @return reference to this with interceptor added
+       */
+      ServerRunner &intercept(std::unique_ptr<InterceptorFactory> factory);
+
+      /**
+       * Makes the server reachable through the in-process network instead of
+       * binding it to the address.
+       * @param network - the network the server joins at its address
+       * @return reference to this
+       */
+      ServerRunner &inProcess(std::shared_ptr<InProcessNetwork> network);
+
       /**
        * Initialize the server and run main loop.
-       * @return Result with used port number or error message
+       * @return Result with used port number or error message, the port of
+       * the address for in-process servers
        */
       iroha::expected::Result<int, std::string> run();
 
@@ -77,6 +106,8 @@ namespace iroha {
       std::shared_ptr<grpc::ServerCredentials> credentials_;
       bool reuse_;
       std::vector<std::shared_ptr<grpc::Service>> services_;
+      std::vector<std::unique_ptr<InterceptorFactory>> interceptors_;
+      std::shared_ptr<InProcessNetwork> in_process_network_;
     };
 
   }  // namespace network
@@ -39,6 +39,7 @@ namespace iroha {
     kOnNeedProposal,
     kOnNewProposal,
     kOnTxsEnoughForProposal,
+    kOnExpiredBatches,
     kOnPackProposal,
     kOnProposalResponse,
     kOnProposalSingleEvent,
@@ -52,8 +53,23 @@ namespace iroha {
     // RDB
     kOnRdbStats,
 
+    // Flat file block store
+    kOnBlockStoreStats,
+
+    // Block processing phases
+    kOnBlockPhase,
+
+    // Queries
+    kOnQueryExecuted,
+    kOnBlockStreamOverflow,
+
+    // Peer to peer traffic
+    kOnP2pTraffic,
+    kOnPeerCallRejected,
+
     // Node status
     kOnIrohaStatus,
+    kOnShutdown,
 
     // MST
     kOnMstStateUpdate,
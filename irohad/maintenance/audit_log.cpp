@@ -0,0 +1,275 @@
+/**
+ * Copyright Soramitsu Co., Ltd. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+#include "maintenance/audit_log.hpp"
+
+#include <optional>
+#include <string_view>
+
+#include <fmt/core.h>
+#include <rapidjson/document.h>
+#include <rapidjson/stringbuffer.h>
+#include <rapidjson/writer.h>
+#include "common/result.hpp"
+#include "cryptography/default_hash_provider.hpp"
+#include "interfaces/commands/add_peer.hpp"
+#include "interfaces/commands/append_role.hpp"
+#include "interfaces/commands/archive_domain.hpp"
+#include "interfaces/commands/authorize_session_key.hpp"
+#include "interfaces/commands/command.hpp"
+#include "interfaces/commands/create_role.hpp"
+#include "interfaces/commands/deactivate_account.hpp"
+#include "interfaces/commands/detach_role.hpp"
+#include "interfaces/commands/grant_permission.hpp"
+#include "interfaces/commands/include_role.hpp"
+#include "interfaces/commands/propose_setting_value.hpp"
+#include "interfaces/commands/recover_account.hpp"
+#include "interfaces/commands/remove_peer.hpp"
+#include "interfaces/commands/revoke_permission.hpp"
+#include "interfaces/commands/rotate_key.hpp"
+#include "interfaces/commands/set_setting_value.hpp"
+#include "interfaces/commands/vote_for_proposal.hpp"
+#include "interfaces/iroha_internal/block.hpp"
+#include "interfaces/transaction.hpp"
+#include "logger/logger.hpp"
+
+using iroha::AuditLog;
+using shared_model::interface::types::HeightType;
+
+namespace {
+  using Writer = rapidjson::Writer<rapidjson::StringBuffer>;
+
+  struct Entry {
+    HeightType height;
+    uint64_t time;
+    std::string tx_hash;
+    std::string creator;
+    std::string command;
+    std::string details;
+    std::string prev_hash;
+  };
+
+  /// @return name of the audited command or nullopt for other commands
+  std::optional<std::string_view> auditedCommand(
+      const shared_model::interface::Command &command) {
+    using namespace shared_model::interface;
+    if (command.is<GrantPermission>()) {
+      return "grant_permission";
+    }
+    if (command.is<RevokePermission>()) {
+      return "revoke_permission";
+    }
+    if (command.is<CreateRole>()) {
+      return "create_role";
+    }
+    if (command.is<AppendRole>()) {
+      return "append_role";
+    }
+    if (command.is<DetachRole>()) {
+      return "detach_role";
+    }
+    if (command.is<IncludeRole>()) {
+      return "include_role";
+    }
+    if (command.is<SetSettingValue>()) {
+      return "set_setting_value";
+    }
+    if (command.is<ProposeSettingValue>()) {
+      return "propose_setting_value";
+    }
+    if (command.is<VoteForProposal>()) {
+      return "vote_for_proposal";
+    }
+    if (command.is<AddPeer>()) {
+      return "add_peer";
+    }
+    if (command.is<RemovePeer>()) {
+      return "remove_peer";
+    }
+    if (command.is<ArchiveDomain>()) {
+      return "archive_domain";
+    }
+    if (command.is<DeactivateAccount>()) {
+      return "deactivate_account";
+    }
+    if (command.is<RecoverAccount>()) {
+      return "recover_account";
+    }
+    if (command.is<RotateKey>()) {
+      return "rotate_key";
+    }
+    if (command.is<AuthorizeSessionKey>()) {
+      return "authorize_session_key";
+    }
+    return std::nullopt;
+  }
+
+  /// Serialize the entry without its hash, this is the hashed content
+  std::string serialize(const Entry &entry) {
+    rapidjson::StringBuffer buffer;
+    Writer writer(buffer);
+    writer.StartObject();
+    writer.Key("height");
+    writer.Uint64(entry.height);
+    writer.Key("time");
+    writer.Uint64(entry.time);
+    writer.Key("tx_hash");
+    writer.String(entry.tx_hash.c_str());
+    writer.Key("creator");
+    writer.String(entry.creator.c_str());
+    writer.Key("command");
+    writer.String(entry.command.c_str());
+    writer.Key("details");
+    writer.String(entry.details.c_str());
+    writer.Key("prev_hash");
+    writer.String(entry.prev_hash.c_str());
+    writer.EndObject();
+    return std::string(buffer.GetString(), buffer.GetLength());
+  }
+
+  std::string hashOf(const std::string &content) {
+    return shared_model::crypto::DefaultHashProvider::makeHash(
+               shared_model::crypto::Blob(content))
+        .hex();
+  }
+
+  /// Append the hash to the serialized entry
+  std::string withHash(const std::string &content, const std::string &hash) {
+    return fmt::format(
+        R"({},"hash":"{}"}})", content.substr(0, content.size() - 1), hash);
+  }
+
+  std::optional<Entry> parseEntry(const rapidjson::Document &document) {
+    auto is_string = [&](const char *name) {
+      return document.HasMember(name) and document[name].IsString();
+    };
+    auto is_uint64 = [&](const char *name) {
+      return document.HasMember(name) and document[name].IsUint64();
+    };
+    if (not document.IsObject() or not is_uint64("height")
+        or not is_uint64("time") or not is_string("tx_hash")
+        or not is_string("creator") or not is_string("command")
+        or not is_string("details") or not is_string("prev_hash")
+        or not is_string("hash")) {
+      return std::nullopt;
+    }
+    return Entry{document["height"].GetUint64(),
+                 document["time"].GetUint64(),
+                 document["tx_hash"].GetString(),
+                 document["creator"].GetString(),
+                 document["command"].GetString(),
+                 document["details"].GetString(),
+                 document["prev_hash"].GetString()};
+  }
+}  // namespace
+
+const std::string AuditLog::kInitialHash(64, '0');
+
+iroha::expected::Result<std::unique_ptr<AuditLog>, std::string>
+AuditLog::create(std::string path, logger::LoggerPtr log) {
+  std::string last_hash = kInitialHash;
+  HeightType last_height = 0;
+  size_t line_number = 0;
+
+  std::ifstream existing(path);
+  std::string line;
+  while (std::getline(existing, line)) {
+    ++line_number;
+    rapidjson::Document document;
+    document.Parse(line.c_str());
+    auto entry = parseEntry(document);
+    if (not entry) {
+      return fmt::format(
+          "Audit log {} has malformed entry at line {}", path, line_number);
+    }
+    auto const content = serialize(*entry);
+    if (entry->prev_hash != last_hash
+        or document["hash"].GetString() != hashOf(content)) {
+      return fmt::format(
+          "Audit log {} is tampered with at line {}", path, line_number);
+    }
+    last_hash = document["hash"].GetString();
+    last_height = entry->height;
+  }
+  existing.close();
+
+  std::unique_ptr<AuditLog> audit_log(new AuditLog(
+      std::move(path), std::move(last_hash), last_height, std::move(log)));
+  if (not audit_log->file_) {
+    return fmt::format("Failed to open audit log {}", audit_log->path_);
+  }
+  audit_log->log_->info("Audit log {} has {} entries, last block height {}",
+                        audit_log->path_,
+                        line_number,
+                        last_height);
+  return iroha::expected::makeValue(std::move(audit_log));
+}
+
+AuditLog::AuditLog(std::string path,
+                   std::string last_hash,
+                   HeightType last_height,
+                   logger::LoggerPtr log)
+    : path_(std::move(path)),
+      file_(path_, std::ios::app),
+      last_hash_(std::move(last_hash)),
+      last_height_(last_height),
+      log_(std::move(log)) {}
+
+void AuditLog::append(const shared_model::interface::Block &block) {
+  std::lock_guard<std::mutex> lock(mutex_);
+  if (block.height() <= last_height_) {
+    return;
+  }
+  for (auto const &tx : block.transactions()) {
+    for (auto const &command : tx.commands()) {
+      auto name = auditedCommand(command);
+      if (not name) {
+        continue;
+      }
+      auto const content = serialize(Entry{block.height(),
+                                           block.createdTime(),
+                                           tx.hash().hex(),
+                                           tx.creatorAccountId(),
+                                           std::string{*name},
+                                           command.toString(),
+                                           last_hash_});
+      last_hash_ = hashOf(content);
+      file_ << withHash(content, last_hash_) << '\n';
+    }
+  }
+  file_.flush();
+  if (not file_) {
+    log_->error("Failed to write audit log {}", path_);
+  }
+  last_height_ = block.height();
+}
+
+HeightType AuditLog::lastHeight() const {
+  std::lock_guard<std::mutex> lock(mutex_);
+  return last_height_;
+}
+
+std::string AuditLog::entriesJson(HeightType from_height) const {
+  std::lock_guard<std::mutex> lock(mutex_);
+  rapidjson::StringBuffer buffer;
+  Writer writer(buffer);
+  writer.StartObject();
+  writer.Key("entries");
+  writer.StartArray();
+  std::ifstream file(path_);
+  std::string line;
+  while (std::getline(file, line)) {
+    rapidjson::Document document;
+    document.Parse(line.c_str());
+    if (document.IsObject() and document.HasMember("height")
+        and document["height"].IsUint64()
+        and document["height"].GetUint64() >= from_height) {
+      document.Accept(writer);
+    }
+  }
+  writer.EndArray();
+  writer.EndObject();
+  return std::string(buffer.GetString(), buffer.GetLength());
+}
@@ -0,0 +1,80 @@
+/**
+ * Copyright Soramitsu Co., Ltd. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+#ifndef IROHA_MAINTENANCE_AUDIT_LOG_HPP
+#define IROHA_MAINTENANCE_AUDIT_LOG_HPP
+
+#include <fstream>
+#include <memory>
+#include <mutex>
+#include <string>
+
+#include "common/result_fwd.hpp"
+#include "interfaces/common_objects/types.hpp"
+#include "logger/logger_fwd.hpp"
+
+namespace shared_model::interface {
+  class Block;
+}  // namespace shared_model::interface
+
+namespace iroha {
+
+  /**
+   * Append-only log of privileged commands from committed blocks: permission
+   * grants and revocations, role changes, setting changes and peer
+   * membership changes. The log is a text file with one JSON entry per line,
+   * each entry contains the hash of the previous one, so any modification of
+   * the file breaks the chain and is detected on startup.
+   */
+  class AuditLog {
+   public:
+    /// hash of the entry preceding the first one
+    static const std::string kInitialHash;
+
+    /**
+     * Open the log file, creating it if it does not exist, and verify the
+     * hash chain of the existing entries
+     * @param path - path to the log file
+     * @param log - logger
+     * @return the audit log or error message if the file cannot be opened or
+     * the chain is broken
+     */
+    static iroha::expected::Result<std::unique_ptr<AuditLog>, std::string>
+    create(std::string path, logger::LoggerPtr log);
+
+    /**
+     * Append entries for privileged commands of the block. Blocks with height
+     * not greater than the last recorded one are ignored, so committed blocks
+     * can be replayed safely.
+     */
+    void append(const shared_model::interface::Block &block);
+
+    /// height of the last block processed by the log
+    shared_model::interface::types::HeightType lastHeight() const;
+
+    /**
+     * @param from_height - minimal block height of returned entries
+     * @return JSON object with the array of entries
+     */
+    std::string entriesJson(
+        shared_model::interface::types::HeightType from_height) const;
+
+   private:
+    AuditLog(std::string path,
+             std::string last_hash,
+             shared_model::interface::types::HeightType last_height,
+             logger::LoggerPtr log);
+
+    std::string path_;
+    std::ofstream file_;
+    std::string last_hash_;
+    shared_model::interface::types::HeightType last_height_;
+    logger::LoggerPtr log_;
+    mutable std::mutex mutex_;
+  };
+
+}  // namespace iroha
+
+#endif  // IROHA_MAINTENANCE_AUDIT_LOG_HPP
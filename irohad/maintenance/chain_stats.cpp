@@ -0,0 +1,231 @@
+/**
+ * Copyright Soramitsu Co., Ltd. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+#include "maintenance/chain_stats.hpp"
+
+#include <algorithm>
+#include <vector>
+
+#include <google/protobuf/descriptor.h>
+#include <rapidjson/stringbuffer.h>
+#include <rapidjson/writer.h>
+#include "backend/protobuf/block.hpp"
+
+using iroha::ChainStats;
+using shared_model::interface::types::TimestampType;
+
+namespace {
+  using Writer = rapidjson::Writer<rapidjson::StringBuffer>;
+
+  constexpr TimestampType kHourMs = 60 * 60 * 1000;
+  constexpr TimestampType kDayMs = 24 * kHourMs;
+}  // namespace
+
+ChainStats::ChainStats(size_t block_time_window)
+    : block_time_window_(std::max<size_t>(block_time_window, 2)) {}
+
+void ChainStats::append(const shared_model::interface::Block &block) {
+  auto const &payload =
+      static_cast<const shared_model::proto::Block &>(block)
+          .getTransport()
+          .payload();
+
+  std::lock_guard<std::mutex> lock(mutex_);
+  if (payload.height() <= height_) {
+    return;
+  }
+  height_ = payload.height();
+  last_block_time_ = payload.created_time();
+
+  block_times_.push_back(payload.created_time());
+  if (block_times_.size() > block_time_window_) {
+    block_times_.pop_front();
+  }
+
+  auto &hour = hours_[payload.created_time() / kHourMs];
+  ++hour.blocks;
+  hour.transactions += payload.transactions_size();
+  transactions_ += payload.transactions_size();
+  rejected_transactions_ += payload.rejected_transactions_hashes_size();
+
+  auto const *command_descriptor = iroha::protocol::Command::descriptor();
+  for (auto const &tx : payload.transactions()) {
+    auto const &reduced = tx.payload().reduced_payload();
+    auto &last_active = accounts_[reduced.creator_account_id()];
+    last_active = std::max(last_active, reduced.created_time());
+
+    for (auto const &command : reduced.commands()) {
+      if (auto field =
+              command_descriptor->FindFieldByNumber(command.command_case())) {
+        ++commands_[field->name()];
+      }
+      switch (command.command_case()) {
+        case iroha::protocol::Command::kCreateAsset: {
+          auto const &create = command.create_asset();
+          asset_precisions_[create.asset_name() + "#" + create.domain_id()] =
+              create.precision();
+          break;
+        }
+        case iroha::protocol::Command::kAddAssetQuantity:
+          changeBalance(command.add_asset_quantity().asset_id(),
+                        reduced.creator_account_id(),
+                        command.add_asset_quantity().amount(),
+                        true);
+          break;
+        case iroha::protocol::Command::kSubtractAssetQuantity:
+          changeBalance(command.subtract_asset_quantity().asset_id(),
+                        reduced.creator_account_id(),
+                        command.subtract_asset_quantity().amount(),
+                        false);
+          break;
+        case iroha::protocol::Command::kTransferAsset: {
+          auto const &transfer = command.transfer_asset();
+          changeBalance(transfer.asset_id(),
+                        transfer.src_account_id(),
+                        transfer.amount(),
+                        false);
+          changeBalance(transfer.asset_id(),
+                        transfer.dest_account_id(),
+                        transfer.amount(),
+                        true);
+          break;
+        }
+        default:
+          break;
+      }
+    }
+  }
+}
+
+void ChainStats::changeBalance(const std::string &asset_id,
+                               const std::string &account_id,
+                               const std::string &amount,
+                               bool add) {
+  auto precision = asset_precisions_.find(asset_id);
+  if (precision == asset_precisions_.end()) {
+    return;
+  }
+  auto balance =
+      balances_
+          .try_emplace(std::make_pair(asset_id, account_id),
+                       shared_model::interface::Amount(precision->second))
+          .first;
+  bool const was_holder = balance->second.sign() > 0;
+  if (add) {
+    balance->second += shared_model::interface::Amount(amount);
+  } else {
+    balance->second -= shared_model::interface::Amount(amount);
+  }
+  bool const is_holder = balance->second.sign() > 0;
+  if (is_holder and not was_holder) {
+    ++holders_[asset_id];
+  } else if (was_holder and not is_holder) {
+    --holders_[asset_id];
+  }
+}
+
+shared_model::interface::types::HeightType ChainStats::height() const {
+  std::lock_guard<std::mutex> lock(mutex_);
+  return height_;
+}
+
+std::string ChainStats::summaryJson(size_t top_assets) const {
+  std::lock_guard<std::mutex> lock(mutex_);
+  rapidjson::StringBuffer buffer;
+  Writer writer(buffer);
+  writer.StartObject();
+  writer.Key("height");
+  writer.Uint64(height_);
+  writer.Key("transactions");
+  writer.Uint64(transactions_);
+  writer.Key("rejected_transactions");
+  writer.Uint64(rejected_transactions_);
+
+  writer.Key("average_block_time_ms");
+  if (block_times_.size() < 2) {
+    writer.Null();
+  } else {
+    writer.Uint64((block_times_.back() - block_times_.front())
+                  / (block_times_.size() - 1));
+  }
+
+  writer.Key("accounts");
+  writer.StartObject();
+  writer.Key("active_last_day");
+  writer.Uint64(std::count_if(
+      accounts_.begin(), accounts_.end(), [this](auto const &account) {
+        return account.second + kDayMs >= last_block_time_;
+      }));
+  writer.Key("total");
+  writer.Uint64(accounts_.size());
+  writer.EndObject();
+
+  writer.Key("commands");
+  writer.StartObject();
+  for (auto const &[name, count] : commands_) {
+    writer.Key(name.c_str());
+    writer.Uint64(count);
+  }
+  writer.EndObject();
+
+  std::vector<std::pair<std::string, size_t>> assets(holders_.begin(),
+                                                     holders_.end());
+  auto const top_end =
+      assets.begin() + std::min(top_assets, assets.size());
+  std::partial_sort(
+      assets.begin(), top_end, assets.end(), [](auto const &a, auto const &b) {
+        return a.second > b.second or (a.second == b.second and a < b);
+      });
+  writer.Key("top_assets");
+  writer.StartArray();
+  for (auto it = assets.begin(); it != top_end; ++it) {
+    writer.StartObject();
+    writer.Key("asset_id");
+    writer.String(it->first.c_str());
+    writer.Key("holders");
+    writer.Uint64(it->second);
+    writer.EndObject();
+  }
+  writer.EndArray();
+
+  writer.EndObject();
+  return std::string(buffer.GetString(), buffer.GetLength());
+}
+
+std::string ChainStats::periodsJson(std::chrono::hours period,
+                                    size_t limit) const {
+  std::lock_guard<std::mutex> lock(mutex_);
+  auto const hours = std::max<uint64_t>(period.count(), 1);
+  rapidjson::StringBuffer buffer;
+  Writer writer(buffer);
+  writer.StartObject();
+  writer.Key("period_hours");
+  writer.Uint64(hours);
+  writer.Key("periods");
+  writer.StartArray();
+  if (height_ > 0) {
+    auto const last = last_block_time_ / kHourMs / hours;
+    auto const first = last + 1 > limit ? last + 1 - limit : 0;
+    for (auto index = first; index <= last; ++index) {
+      PeriodStats stats;
+      auto const end = hours_.lower_bound((index + 1) * hours);
+      for (auto it = hours_.lower_bound(index * hours); it != end; ++it) {
+        stats.blocks += it->second.blocks;
+        stats.transactions += it->second.transactions;
+      }
+      writer.StartObject();
+      writer.Key("start");
+      writer.Uint64(index * hours * kHourMs);
+      writer.Key("blocks");
+      writer.Uint64(stats.blocks);
+      writer.Key("transactions");
+      writer.Uint64(stats.transactions);
+      writer.EndObject();
+    }
+  }
+  writer.EndArray();
+  writer.EndObject();
+  return std::string(buffer.GetString(), buffer.GetLength());
+}
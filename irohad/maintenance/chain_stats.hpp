@@ -0,0 +1,97 @@
+/**
+ * Copyright Soramitsu Co., Ltd. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+#ifndef IROHA_MAINTENANCE_CHAIN_STATS_HPP
+#define IROHA_MAINTENANCE_CHAIN_STATS_HPP
+
+#include <chrono>
+#include <deque>
+#include <map>
+#include <mutex>
+#include <string>
+#include <unordered_map>
+
+#include "interfaces/common_objects/amount.hpp"
+#include "interfaces/common_objects/types.hpp"
+
+namespace shared_model::interface {
+  class Block;
+}  // namespace shared_model::interface
+
+namespace iroha {
+
+  /**
+   * Aggregated chain statistics for block explorers. The statistics are
+   * updated with every committed block, so serving them does not require
+   * scanning the chain.
+   */
+  class ChainStats {
+   public:
+    /**
+     * @param block_time_window - number of the latest blocks used to
+     * calculate the average block time
+     */
+    explicit ChainStats(size_t block_time_window = 100);
+
+    /**
+     * Account the block. Blocks with height not greater than the last
+     * accounted one are ignored.
+     */
+    void append(const shared_model::interface::Block &block);
+
+    /// height of the last accounted block
+    shared_model::interface::types::HeightType height() const;
+
+    /**
+     * @param top_assets - number of assets with most holders to report
+     * @return JSON object with totals, command counts by type, active
+     * accounts, top assets by holders and the average block time
+     */
+    std::string summaryJson(size_t top_assets) const;
+
+    /**
+     * @param period - length of a period, a multiple of an hour
+     * @param limit - number of the latest periods to report
+     * @return JSON object with numbers of blocks and transactions per period
+     */
+    std::string periodsJson(std::chrono::hours period, size_t limit) const;
+
+   private:
+    struct PeriodStats {
+      size_t blocks = 0;
+      size_t transactions = 0;
+    };
+
+    void changeBalance(const std::string &asset_id,
+                       const std::string &account_id,
+                       const std::string &amount,
+                       bool add);
+
+    const size_t block_time_window_;
+    shared_model::interface::types::HeightType height_ = 0;
+    shared_model::interface::types::TimestampType last_block_time_ = 0;
+    size_t transactions_ = 0;
+    size_t rejected_transactions_ = 0;
+    std::deque<shared_model::interface::types::TimestampType> block_times_;
+    /// blocks and transactions by the number of hours since epoch
+    std::map<uint64_t, PeriodStats> hours_;
+    std::map<std::string, size_t> commands_;
+    /// time of the last transaction created by the account
+    std::unordered_map<std::string,
+                       shared_model::interface::types::TimestampType>
+        accounts_;
+    std::unordered_map<std::string,
+                       shared_model::interface::types::PrecisionType>
+        asset_precisions_;
+    std::map<std::pair<std::string, std::string>,
+             shared_model::interface::Amount>
+        balances_;
+    std::unordered_map<std::string, size_t> holders_;
+    mutable std::mutex mutex_;
+  };
+
+}  // namespace iroha
+
+#endif  // IROHA_MAINTENANCE_CHAIN_STATS_HPP
@@ -0,0 +1,554 @@
+/**
+ * Copyright Soramitsu Co., Ltd. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+#include "maintenance/event_sink.hpp"
+
+#include <algorithm>
+#include <cstdio>
+#include <fstream>
+#include <iterator>
+#include <regex>
+
+#include <fmt/core.h>
+#include <google/protobuf/descriptor.h>
+#include <google/protobuf/util/json_util.h>
+#include <rapidjson/stringbuffer.h>
+#include <rapidjson/writer.h>
+#include <boost/asio/ip/tcp.hpp>
+#include "backend/protobuf/block.hpp"
+#include "common/result.hpp"
+#include "cryptography/hash.hpp"
+#include "logger/logger.hpp"
+#include "main/memory_budget.hpp"
+
+using iroha::EventSink;
+using shared_model::interface::types::HeightType;
+
+namespace {
+  using Writer = rapidjson::Writer<rapidjson::StringBuffer>;
+
+  constexpr std::chrono::seconds kMinRetryDelay{1};
+  constexpr std::chrono::seconds kMaxRetryDelay{30};
+
+  struct Url {
+    std::string scheme;
+    std::string host;
+    std::string port;
+    std::string path;
+  };
+
+  std::optional<Url> parseUrl(const std::string &url) {
+    static const std::regex kUrlRegex(R"(^(\w+)://([^/:]+):(\d+)(/.*)?$)");
+    std::smatch match;
+    if (not std::regex_match(url, match, kUrlRegex)) {
+      return std::nullopt;
+    }
+    return Url{match[1], match[2], match[3], match[4]};
+  }
+
+  /// Read a line without the trailing CR
+  bool readLine(std::istream &stream, std::string &line) {
+    if (not std::getline(stream, line)) {
+      return false;
+    }
+    if (not line.empty() and line.back() == '\r') {
+      line.pop_back();
+    }
+    return true;
+  }
+
+  iroha::expected::Result<void, std::string> postToWebhook(
+      const Url &url,
+      std::chrono::milliseconds timeout,
+      const std::string &event) {
+    boost::asio::ip::tcp::iostream stream;
+    stream.expires_after(timeout);
+    stream.connect(url.host, url.port);
+    if (not stream) {
+      return fmt::format("Failed to connect to {}:{}: {}",
+                         url.host,
+                         url.port,
+                         stream.error().message());
+    }
+    stream << "POST " << (url.path.empty() ? "/" : url.path) << " HTTP/1.1\r\n"
+           << "Host: " << url.host << "\r\n"
+           << "Content-Type: application/json\r\n"
+           << "Content-Length: " << event.size() << "\r\n"
+           << "Connection: close\r\n\r\n"
+           << event << std::flush;
+    std::string version;
+    unsigned status = 0;
+    if (not(stream >> version >> status)) {
+      return fmt::format("No response from {}:{}", url.host, url.port);
+    }
+    if (status < 200 or status >= 300) {
+      return fmt::format(
+          "{}:{} responded with status {}", url.host, url.port, status);
+    }
+    return {};
+  }
+
+  iroha::expected::Result<void, std::string> publishToNats(
+      const Url &url,
+      const std::string &subject,
+      std::chrono::milliseconds timeout,
+      const std::string &event) {
+    boost::asio::ip::tcp::iostream stream;
+    stream.expires_after(timeout);
+    stream.connect(url.host, url.port);
+    std::string line;
+    if (not stream or not readLine(stream, line)
+        or line.rfind("INFO", 0) != 0) {
+      return fmt::format(
+          "Failed to connect to NATS server {}:{}", url.host, url.port);
+    }
+    stream << "CONNECT {\"verbose\":false,\"pedantic\":false}\r\n"
+           << "PUB " << subject << " " << event.size() << "\r\n"
+           << event << "\r\n"
+           << "PING\r\n"
+           << std::flush;
+    // the server processes commands in order, so PONG confirms the publish
+    while (readLine(stream, line)) {
+      if (line == "PONG") {
+        return {};
+      }
+      if (line.rfind("-ERR", 0) == 0) {
+        return fmt::format("NATS server {}:{} rejected the event: {}",
+                           url.host,
+                           url.port,
+                           line);
+      }
+      if (line == "PING") {
+        stream << "PONG\r\n" << std::flush;
+      }
+    }
+    return fmt::format(
+        "NATS server {}:{} closed the connection", url.host, url.port);
+  }
+
+  template <typename Collection>
+  bool contains(const Collection &collection, const std::string &value) {
+    return std::find(collection.begin(), collection.end(), value)
+        != collection.end();
+  }
+
+  void appendKeyRotations(
+      HeightType height,
+      const iroha::protocol::Transaction::Payload::ReducedPayload &reduced,
+      std::vector<EventSink::KeyRotation> &rotations) {
+    for (auto const &command : reduced.commands()) {
+      if (command.has_rotate_key()) {
+        auto const &rotate_key = command.rotate_key();
+        rotations.push_back(
+            EventSink::KeyRotation{height,
+                                   height + rotate_key.grace_period(),
+                                   rotate_key.account_id(),
+                                   rotate_key.old_public_key(),
+                                   rotate_key.new_public_key()});
+      }
+    }
+  }
+
+  void writeKeyRotation(Writer &writer,
+                        const EventSink::KeyRotation &rotation,
+                        const char *phase) {
+    writer.StartObject();
+    writer.Key("phase");
+    writer.String(phase);
+    writer.Key("account_id");
+    writer.String(rotation.account_id.c_str());
+    writer.Key("old_public_key");
+    writer.String(rotation.old_public_key.c_str());
+    writer.Key("new_public_key");
+    writer.String(rotation.new_public_key.c_str());
+    writer.Key("height");
+    writer.Uint64(rotation.height);
+    writer.Key("expires_height");
+    writer.Uint64(rotation.expires_height);
+    writer.EndObject();
+  }
+}  // namespace
+
+iroha::expected::Result<EventSink::Transport, std::string>
+EventSink::makeTransport(const std::string &url_string,
+                         std::chrono::milliseconds timeout) {
+  auto url = parseUrl(url_string);
+  if (not url) {
+    return iroha::expected::makeError(
+        fmt::format("Malformed event sink URL {}", url_string));
+  }
+  if (url->scheme == "http") {
+    return Transport{[url = *url, timeout](const std::string &event) {
+      return postToWebhook(url, timeout, event);
+    }};
+  }
+  if (url->scheme == "nats") {
+    auto subject = url->path.empty() ? std::string{} : url->path.substr(1);
+    if (subject.empty()) {
+      return iroha::expected::makeError(fmt::format(
+          "NATS subject is not set in event sink URL {}", url_string));
+    }
+    return Transport{
+        [url = *url, subject, timeout](const std::string &event) {
+          return publishToNats(url, subject, timeout, event);
+        }};
+  }
+  return iroha::expected::makeError(
+      fmt::format("Unsupported event sink scheme {}, expected http or nats",
+                  url->scheme));
+}
+
+std::optional<std::string> EventSink::makeEvent(
+    const shared_model::interface::Block &block,
+    const Filter &filter,
+    const std::vector<KeyRotation> &expired) {
+  auto const &payload =
+      static_cast<const shared_model::proto::Block &>(block)
+          .getTransport()
+          .payload();
+  bool const filtered =
+      not filter.commands.empty() or not filter.accounts.empty();
+  auto const *command_descriptor = iroha::protocol::Command::descriptor();
+
+  rapidjson::StringBuffer buffer;
+  Writer writer(buffer);
+  writer.StartObject();
+  writer.Key("height");
+  writer.Uint64(payload.height());
+  writer.Key("hash");
+  writer.String(block.hash().hex().c_str());
+  writer.Key("created_time");
+  writer.Uint64(payload.created_time());
+
+  size_t forwarded = 0;
+  std::vector<KeyRotation> started;
+  writer.Key("transactions");
+  writer.StartArray();
+  for (auto const &tx : block.transactions()) {
+    auto const &transport =
+        static_cast<const shared_model::proto::Transaction &>(tx)
+            .getTransport();
+    auto const &reduced = transport.payload().reduced_payload();
+    std::vector<std::string> commands;
+    for (auto const &command : reduced.commands()) {
+      if (auto field =
+              command_descriptor->FindFieldByNumber(command.command_case())) {
+        commands.push_back(field->name());
+      }
+    }
+    if (not filter.accounts.empty()
+        and not contains(filter.accounts, reduced.creator_account_id())) {
+      continue;
+    }
+    if (not filter.commands.empty()
+        and std::none_of(commands.begin(),
+                         commands.end(),
+                         [&](auto const &name) {
+                           return contains(filter.commands, name);
+                         })) {
+      continue;
+    }
+    ++forwarded;
+    appendKeyRotations(payload.height(), reduced, started);
+    std::string transaction_json;
+    google::protobuf::util::MessageToJsonString(transport, &transaction_json);
+    writer.StartObject();
+    writer.Key("hash");
+    writer.String(tx.hash().hex().c_str());
+    writer.Key("creator");
+    writer.String(reduced.creator_account_id().c_str());
+    writer.Key("commands");
+    writer.StartArray();
+    for (auto const &name : commands) {
+      writer.String(name.c_str());
+    }
+    writer.EndArray();
+    writer.Key("transaction");
+    writer.RawValue(transaction_json.data(),
+                    transaction_json.size(),
+                    rapidjson::kObjectType);
+    writer.EndObject();
+  }
+  writer.EndArray();
+
+  writer.Key("rejected_transactions");
+  writer.StartArray();
+  if (not filtered) {
+    for (auto const &hash : payload.rejected_transactions_hashes()) {
+      writer.String(hash.c_str());
+    }
+  }
+  writer.EndArray();
+
+  size_t expired_forwarded = 0;
+  writer.Key("key_rotations");
+  writer.StartArray();
+  for (auto const &rotation : started) {
+    writeKeyRotation(writer, rotation, "started");
+  }
+  for (auto const &rotation : expired) {
+    if ((filter.accounts.empty()
+         or contains(filter.accounts, rotation.account_id))
+        and (filter.commands.empty()
+             or contains(filter.commands, "rotate_key"))) {
+      ++expired_forwarded;
+      writeKeyRotation(writer, rotation, "expired");
+    }
+  }
+  writer.EndArray();
+  writer.EndObject();
+
+  if (filtered and forwarded == 0 and expired_forwarded == 0) {
+    return std::nullopt;
+  }
+  return std::string(buffer.GetString(), buffer.GetLength());
+}
+
+std::vector<EventSink::KeyRotation> EventSink::keyRotations(
+    const shared_model::interface::Block &block) {
+  std::vector<KeyRotation> rotations;
+  for (auto const &tx : block.transactions()) {
+    appendKeyRotations(
+        block.height(),
+        static_cast<const shared_model::proto::Transaction &>(tx)
+            .getTransport()
+            .payload()
+            .reduced_payload(),
+        rotations);
+  }
+  return rotations;
+}
+
+iroha::expected::Result<std::unique_ptr<EventSink>, std::string>
+EventSink::create(Transport transport,
+                  BlockLoader block_loader,
+                  std::string cursor_path,
+                  Filter filter,
+                  size_t max_queue,
+                  logger::LoggerPtr log,
+                  std::shared_ptr<MemoryBudget> memory_budget) {
+  HeightType cursor = 0;
+  std::vector<KeyRotation> pending;
+  std::ifstream file(cursor_path);
+  if (file) {
+    if (not(file >> cursor)) {
+      return fmt::format("Event sink cursor {} is malformed", cursor_path);
+    }
+    KeyRotation rotation;
+    while (file >> rotation.height >> rotation.expires_height
+           >> rotation.account_id >> rotation.old_public_key
+           >> rotation.new_public_key) {
+      pending.push_back(rotation);
+    }
+    if (not file.eof()) {
+      return fmt::format("Event sink cursor {} is malformed", cursor_path);
+    }
+  }
+  log->info("Event sink delivers blocks after height {}", cursor);
+  return iroha::expected::makeValue(
+      std::unique_ptr<EventSink>(new EventSink(std::move(transport),
+                                               std::move(block_loader),
+                                               std::move(cursor_path),
+                                               cursor,
+                                               std::move(pending),
+                                               std::move(filter),
+                                               std::max<size_t>(max_queue, 1),
+                                               std::move(log),
+                                               std::move(memory_budget))));
+}
+
+EventSink::EventSink(Transport transport,
+                     BlockLoader block_loader,
+                     std::string cursor_path,
+                     HeightType cursor,
+                     std::vector<KeyRotation> pending,
+                     Filter filter,
+                     size_t max_queue,
+                     logger::LoggerPtr log,
+                     std::shared_ptr<MemoryBudget> memory_budget)
+    : transport_(std::move(transport)),
+      block_loader_(std::move(block_loader)),
+      cursor_path_(std::move(cursor_path)),
+      cursor_(cursor),
+      queued_height_(cursor),
+      filter_(std::move(filter)),
+      max_queue_(max_queue),
+      log_(std::move(log)),
+      memory_budget_(std::move(memory_budget)),
+      pending_(std::move(pending)),
+      worker_([this] { run(); }) {}
+
+EventSink::~EventSink() {
+  {
+    std::lock_guard<std::mutex> lock(mutex_);
+    stopped_ = true;
+  }
+  cv_.notify_all();
+  worker_.join();
+}
+
+void EventSink::append(const shared_model::interface::Block &block) {
+  auto rotations = keyRotations(block);
+  std::lock_guard<std::mutex> lock(mutex_);
+  if (block.height() <= queued_height_) {
+    return;
+  }
+  if (lagging_ or block.height() != queued_height_ + 1 or queueFull()) {
+    if (not lagging_) {
+      log_->warn(
+          "Event sink queue is full, blocks after {} will be loaded from the "
+          "block store",
+          queued_height_);
+    }
+    lagging_ = true;
+    skipped_height_ = std::max(skipped_height_, block.height());
+    cv_.notify_all();
+    return;
+  }
+  enqueue(block, rotations);
+}
+
+void EventSink::enqueue(const shared_model::interface::Block &block,
+                        const std::vector<KeyRotation> &rotations) {
+  pending_.insert(pending_.end(), rotations.begin(), rotations.end());
+  std::vector<KeyRotation> expired;
+  std::copy_if(pending_.begin(),
+               pending_.end(),
+               std::back_inserter(expired),
+               [&](auto const &rotation) {
+                 return rotation.expires_height > queued_height_
+                     and rotation.expires_height <= block.height();
+               });
+  queued_height_ = block.height();
+  queue_.push_back(
+      QueuedEvent{block.height(), makeEvent(block, filter_, expired)});
+  if (queue_.back().event) {
+    queued_bytes_ += queue_.back().event->size();
+    updateMemoryUsage();
+  }
+  cv_.notify_all();
+}
+
+void EventSink::loadNext(std::unique_lock<std::mutex> &lock) {
+  auto const height = queued_height_ + 1;
+  lock.unlock();
+  auto const block = block_loader_(height);
+  auto const rotations =
+      block ? keyRotations(*block) : std::vector<KeyRotation>{};
+  lock.lock();
+  if (block) {
+    enqueue(*block, rotations);
+    return;
+  }
+  if (skipped_height_ < height) {
+    // every appended block is stored, so the sink has caught up
+    lagging_ = false;
+    log_->info("Event sink caught up with the block store at height {}",
+               queued_height_);
+    return;
+  }
+  log_->warn("Failed to load block {} for the event sink, retrying in {} ms",
+             height,
+             std::chrono::milliseconds(kMinRetryDelay).count());
+  cv_.wait_for(lock, kMinRetryDelay, [this] { return stopped_; });
+}
+
+bool EventSink::queueFull() const {
+  return queue_.size() >= max_queue_
+      or (memory_budget_
+          and memory_budget_->exhausted(MemoryBudget::Buffer::kEvents));
+}
+
+void EventSink::updateMemoryUsage() {
+  if (memory_budget_) {
+    memory_budget_->set(MemoryBudget::Buffer::kEvents, queued_bytes_);
+  }
+}
+
+HeightType EventSink::cursor() const {
+  std::lock_guard<std::mutex> lock(mutex_);
+  return cursor_;
+}
+
+void EventSink::run() {
+  std::unique_lock<std::mutex> lock(mutex_);
+  while (true) {
+    cv_.wait(lock, [this] {
+      return stopped_ or not queue_.empty() or (lagging_ and not queueFull());
+    });
+    if (stopped_) {
+      return;
+    }
+    if (lagging_ and not queueFull()) {
+      loadNext(lock);
+      continue;
+    }
+    auto const &front = queue_.front();
+    if (front.event) {
+      auto const event = *front.event;
+      lock.unlock();
+      bool const delivered = deliver(event);
+      lock.lock();
+      if (not delivered) {
+        return;
+      }
+    }
+    cursor_ = queue_.front().height;
+    if (queue_.front().event) {
+      queued_bytes_ -= queue_.front().event->size();
+      updateMemoryUsage();
+    }
+    queue_.pop_front();
+    pending_.erase(std::remove_if(pending_.begin(),
+                                  pending_.end(),
+                                  [this](auto const &rotation) {
+                                    return rotation.expires_height <= cursor_;
+                                  }),
+                   pending_.end());
+    saveCursor();
+    cv_.notify_all();
+  }
+}
+
+bool EventSink::deliver(const std::string &event) {
+  std::chrono::milliseconds delay = kMinRetryDelay;
+  while (true) {
+    auto result = transport_(event);
+    if (iroha::expected::hasValue(result)) {
+      return true;
+    }
+    log_->warn("Failed to deliver event, retrying in {} ms: {}",
+               delay.count(),
+               result.assumeError());
+    std::unique_lock<std::mutex> lock(mutex_);
+    if (cv_.wait_for(lock, delay, [this] { return stopped_; })) {
+      return false;
+    }
+    delay = std::min<std::chrono::milliseconds>(delay * 2, kMaxRetryDelay);
+  }
+}
+
+void EventSink::saveCursor() {
+  auto const temporary_path = cursor_path_ + ".tmp";
+  {
+    std::ofstream file(temporary_path, std::ios::trunc);
+    file << cursor_ << '\n';
+    // rotations of the queued blocks are restored when they are appended
+    for (auto const &rotation : pending_) {
+      if (rotation.height <= cursor_) {
+        file << rotation.height << ' ' << rotation.expires_height << ' '
+             << rotation.account_id << ' ' << rotation.old_public_key << ' '
+             << rotation.new_public_key << '\n';
+      }
+    }
+    if (not file.flush()) {
+      log_->error("Failed to write event sink cursor {}", temporary_path);
+      return;
+    }
+  }
+  if (std::rename(temporary_path.c_str(), cursor_path_.c_str()) != 0) {
+    log_->error("Failed to write event sink cursor {}", cursor_path_);
+  }
+}
@@ -0,0 +1,199 @@
+/**
+ * Copyright Soramitsu Co., Ltd. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+#ifndef IROHA_MAINTENANCE_EVENT_SINK_HPP
+#define IROHA_MAINTENANCE_EVENT_SINK_HPP
+
+#include <chrono>
+#include <condition_variable>
+#include <deque>
+#include <functional>
+#include <memory>
+#include <mutex>
+#include <optional>
+#include <string>
+#include <thread>
+#include <vector>
+
+#include "common/result_fwd.hpp"
+#include "interfaces/common_objects/types.hpp"
+#include "logger/logger_fwd.hpp"
+
+namespace shared_model::interface {
+  class Block;
+}  // namespace shared_model::interface
+
+namespace iroha {
+  class MemoryBudget;
+
+  /**
+   * Forwards committed blocks to an external receiver, one JSON event per
+   * block. Delivery is at least once: the height of the last acknowledged
+   * event is stored in the cursor file, and blocks after it are delivered
+   * again after a restart. Failed deliveries are retried with a backoff
+   * without dropping events. Appending never waits for the receiver: when
+   * the queue of undelivered events is full, the appended blocks are not
+   * queued and are loaded from the block store once the queue drains, as
+   * the blocks after the cursor are on startup.
+   *
+   * Key rotations are reported twice: in the event of the block with the
+   * RotateKey command and in the event of the last block accepting the old
+   * key. Pending rotations are stored in the cursor file along with the
+   * height.
+   */
+  class EventSink {
+   public:
+    /// Deliver the event, error if the receiver did not acknowledge it
+    using Transport =
+        std::function<iroha::expected::Result<void, std::string>(
+            const std::string &event)>;
+
+    /// Load the stored block of the height, nullptr if it is not stored
+    using BlockLoader =
+        std::function<std::shared_ptr<const shared_model::interface::Block>(
+            shared_model::interface::types::HeightType height)>;
+
+    /// Transactions to forward, all of them if both lists are empty
+    struct Filter {
+      /// command names as in the protobuf schema, e.g. transfer_asset
+      std::vector<std::string> commands;
+      /// creators of the transactions
+      std::vector<std::string> accounts;
+    };
+
+    /// Key rotation with the grace period of the old key
+    struct KeyRotation {
+      /// height of the block with the rotation
+      shared_model::interface::types::HeightType height;
+      /// height of the last block accepting the old key
+      shared_model::interface::types::HeightType expires_height;
+      std::string account_id;
+      std::string old_public_key;
+      std::string new_public_key;
+    };
+
+    /**
+     * Create the transport for the receiver URL:
+     * http://host:port/path - POST the events to the webhook, any 2xx
+     * response is an acknowledgement;
+     * nats://host:port/subject - publish the events to the NATS subject,
+     * acknowledged when the server responds to a following PING.
+     * @return transport or error message if the URL is not supported
+     */
+    static iroha::expected::Result<Transport, std::string> makeTransport(
+        const std::string &url, std::chrono::milliseconds timeout);
+
+    /**
+     * @param expired - rotations whose grace period ends with the block
+     * @return JSON event of the block with the transactions passing the
+     * filter, nullopt if the filter is set and nothing passes it
+     */
+    static std::optional<std::string> makeEvent(
+        const shared_model::interface::Block &block,
+        const Filter &filter,
+        const std::vector<KeyRotation> &expired = {});
+
+    /// @return key rotations committed in the block
+    static std::vector<KeyRotation> keyRotations(
+        const shared_model::interface::Block &block);
+
+    /**
+     * Load the cursor and start delivering, beginning with the stored
+     * blocks after the cursor
+     * @param block_loader - loads the blocks which were not queued
+     * @param cursor_path - file with the height of the last delivered block
+     * @param max_queue - maximal number of undelivered events in memory
+     * @param memory_budget - accounts the memory of the undelivered events,
+     * appended blocks are not queued while it is exhausted
+     */
+    static iroha::expected::Result<std::unique_ptr<EventSink>, std::string>
+    create(Transport transport,
+           BlockLoader block_loader,
+           std::string cursor_path,
+           Filter filter,
+           size_t max_queue,
+           logger::LoggerPtr log,
+           std::shared_ptr<MemoryBudget> memory_budget = nullptr);
+
+    ~EventSink();
+
+    /**
+     * Queue the event of the block, which must be stored already. Blocks
+     * with height not greater than the last queued one are ignored, so
+     * committed blocks can be replayed safely. If the queue is full or the
+     * block does not follow the last queued one, it is left to be loaded
+     * from the block store.
+     */
+    void append(const shared_model::interface::Block &block);
+
+    /// height of the last delivered block
+    shared_model::interface::types::HeightType cursor() const;
+
+   private:
+    struct QueuedEvent {
+      shared_model::interface::types::HeightType height;
+      /// nullopt if the block has nothing to forward
+      std::optional<std::string> event;
+    };
+
+    EventSink(Transport transport,
+              BlockLoader block_loader,
+              std::string cursor_path,
+              shared_model::interface::types::HeightType cursor,
+              std::vector<KeyRotation> pending,
+              Filter filter,
+              size_t max_queue,
+              logger::LoggerPtr log,
+              std::shared_ptr<MemoryBudget> memory_budget);
+
+    void run();
+
+    /// Queue the event of the block following the last queued one, the
+    /// mutex must be held
+    void enqueue(const shared_model::interface::Block &block,
+                 const std::vector<KeyRotation> &rotations);
+
+    /// Load and queue the block following the last queued one
+    void loadNext(std::unique_lock<std::mutex> &lock);
+
+    /// Either the number or the memory of the queued events is at the limit
+    bool queueFull() const;
+
+    /// Account the memory of the queued events, the mutex must be held
+    void updateMemoryUsage();
+
+    /// Deliver the event until it is acknowledged, false if stopped
+    bool deliver(const std::string &event);
+
+    void saveCursor();
+
+    Transport transport_;
+    BlockLoader block_loader_;
+    const std::string cursor_path_;
+    shared_model::interface::types::HeightType cursor_;
+    shared_model::interface::types::HeightType queued_height_;
+    const Filter filter_;
+    const size_t max_queue_;
+    logger::LoggerPtr log_;
+    std::shared_ptr<MemoryBudget> memory_budget_;
+
+    std::deque<QueuedEvent> queue_;
+    /// bytes of the queued events
+    size_t queued_bytes_ = 0;
+    /// blocks after the last queued one are to be loaded from the store
+    bool lagging_ = true;
+    /// height of the last appended block which was not queued
+    shared_model::interface::types::HeightType skipped_height_ = 0;
+    /// rotations with the grace period not ended before the cursor
+    std::vector<KeyRotation> pending_;
+    bool stopped_ = false;
+    mutable std::mutex mutex_;
+    std::condition_variable cv_;
+    std::thread worker_;
+  };
+
+}  // namespace iroha
+
+#endif  // IROHA_MAINTENANCE_EVENT_SINK_HPP
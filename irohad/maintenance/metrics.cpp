@@ -7,6 +7,7 @@
 
 #include <prometheus/counter.h>
 #include <prometheus/exposer.h>
+#include <prometheus/histogram.h>
 #include <prometheus/registry.h>
 
 #include <memory>
@@ -25,6 +26,24 @@
 using namespace iroha;
 using namespace prometheus;
 
+namespace {
+  std::string blockPhaseName(BlockPhase phase) {
+    switch (phase) {
+      case BlockPhase::kStatelessValidation:
+        return "stateless_validation";
+      case BlockPhase::kExecution:
+        return "execution";
+      case BlockPhase::kStateApplication:
+        return "state_application";
+      case BlockPhase::kStorageCommit:
+        return "storage_commit";
+      case BlockPhase::kEventEmission:
+        return "event_emission";
+    }
+    return "unknown";
+  }
+}  // namespace
+
 Metrics::Metrics(std::string const &listen_addr,
                  std::shared_ptr<iroha::ametsuchi::Storage> storage,
                  logger::LoggerPtr const &logger)
@@ -151,6 +170,13 @@ Metrics::Metrics(std::string const &listen_addr,
                          .Register(*registry_)
                          .Add({});
 
+  auto &is_reachable =
+      BuildGauge()
+          .Name("is_reachable")
+          .Help("Advertised address of the peer accepts connections")
+          .Register(*registry_)
+          .Add({});
+
   iroha_status_subscription_ =
       SubscriberCreator<bool, iroha::IrohaStatus>::template create<
           EventTypes::kOnIrohaStatus>(
@@ -160,6 +186,9 @@ Metrics::Metrics(std::string const &listen_addr,
                 new_status.is_syncing && *new_status.is_syncing ? 1 : 0);
             is_healthy.Set(new_status.is_healthy && *new_status.is_healthy ? 1
                                                                            : 0);
+            if (new_status.is_reachable) {
+              is_reachable.Set(*new_status.is_reachable ? 1 : 0);
+            }
           });
 
   auto &number_of_pending_mst_batches =
@@ -218,6 +247,19 @@ Metrics::Metrics(std::string const &listen_addr,
                                    .Register(*registry_)
                                    .Add({});
 
+  auto &param_wsv_size = BuildGauge()
+                             .Name("rdb_wsv_size")
+                             .Help("RocksDB estimated size of WSV data")
+                             .Register(*registry_)
+                             .Add({});
+
+  auto &param_block_store_size =
+      BuildGauge()
+          .Name("rdb_block_store_size")
+          .Help("RocksDB estimated size of block store data")
+          .Register(*registry_)
+          .Add({});
+
   rdb_subscriber_ =
       SubscriberCreator<bool, iroha::RocksDbStatus>::template create<
           EventTypes::kOnRdbStats>(
@@ -237,6 +279,163 @@ Metrics::Metrics(std::string const &listen_addr,
 
             if (status.sst_files_size)
               param_sst_files_size.Set(*status.sst_files_size);
+
+            if (status.wsv_size)
+              param_wsv_size.Set(*status.wsv_size);
+
+            if (status.block_store_size)
+              param_block_store_size.Set(*status.block_store_size);
+          });
+
+  auto &flat_file_block_store_size =
+      BuildGauge()
+          .Name("flat_file_block_store_size")
+          .Help("Total size of flat file block store files in bytes")
+          .Register(*registry_)
+          .Add({});
+
+  auto &flat_file_fsync_duration =
+      BuildHistogram()
+          .Name("flat_file_fsync_duration_us")
+          .Help("Time to sync a written block file to disk in microseconds")
+          .Register(*registry_)
+          .Add({}, Histogram::BucketBoundaries{100, 1000, 10000, 100000});
+
+  block_store_subscriber_ =
+      SubscriberCreator<bool, iroha::BlockStoreStatus>::template create<
+          EventTypes::kOnBlockStoreStats>(
+          SubscriptionEngineHandlers::kMetrics,
+          [&](auto &, iroha::BlockStoreStatus status) {
+            flat_file_block_store_size.Set(status.size);
+            flat_file_fsync_duration.Observe(status.fsync_duration.count());
+          });
+
+  auto &block_phase_duration =
+      BuildHistogram()
+          .Name("block_phase_duration_us")
+          .Help("Time of a block processing phase in microseconds")
+          .Register(*registry_);
+
+  block_phase_subscriber_ =
+      SubscriberCreator<bool, iroha::BlockPhaseStats>::template create<
+          EventTypes::kOnBlockPhase>(
+          SubscriptionEngineHandlers::kMetrics,
+          [&](auto &, iroha::BlockPhaseStats stats) {
+            block_phase_duration
+                .Add({{"phase", blockPhaseName(stats.phase)}},
+                     Histogram::BucketBoundaries{
+                         100, 1000, 10000, 100000, 1000000})
+                .Observe(stats.duration.count());
+          });
+
+  // Queries are labelled by type only, creator accounts are reported by the
+  // slow query log to keep the number of series bounded
+  auto &queries_total = BuildCounter()
+                            .Name("queries_total")
+                            .Help("Total number of executed queries")
+                            .Register(*registry_);
+  auto &queries_failed = BuildCounter()
+                             .Name("queries_failed")
+                             .Help("Number of queries answered with an error")
+                             .Register(*registry_);
+  auto &query_duration = BuildHistogram()
+                             .Name("query_duration_us")
+                             .Help("Query execution time in microseconds")
+                             .Register(*registry_);
+  auto &query_response_size =
+      BuildHistogram()
+          .Name("query_response_size")
+          .Help("Size of serialized query responses in bytes")
+          .Register(*registry_);
+
+  query_subscriber_ =
+      SubscriberCreator<bool, iroha::QueryStats>::template create<
+          EventTypes::kOnQueryExecuted>(
+          SubscriptionEngineHandlers::kMetrics,
+          [&](auto &, iroha::QueryStats stats) {
+            prometheus::Labels const labels{{"type", stats.type}};
+            queries_total.Add(labels).Increment();
+            if (stats.failed) {
+              queries_failed.Add(labels).Increment();
+            }
+            query_duration
+                .Add(labels,
+                     Histogram::BucketBoundaries{
+                         100, 1000, 10000, 100000, 1000000})
+                .Observe(stats.duration.count());
+            query_response_size
+                .Add(labels,
+                     Histogram::BucketBoundaries{
+                         1000, 10000, 100000, 1000000, 10000000})
+                .Observe(stats.response_size);
+          });
+
+  auto &block_stream_dropped_blocks =
+      BuildCounter()
+          .Name("block_stream_dropped_blocks")
+          .Help("Blocks not sent to slow FetchCommits subscribers")
+          .Register(*registry_)
+          .Add({});
+  auto &block_stream_disconnects =
+      BuildCounter()
+          .Name("block_stream_disconnects")
+          .Help("FetchCommits streams closed because of slow subscribers")
+          .Register(*registry_)
+          .Add({});
+
+  block_stream_overflow_subscriber_ =
+      SubscriberCreator<bool, bool>::template create<
+          EventTypes::kOnBlockStreamOverflow>(
+          SubscriptionEngineHandlers::kMetrics, [&](auto &, bool dropped) {
+            if (dropped) {
+              block_stream_dropped_blocks.Increment();
+            } else {
+              block_stream_disconnects.Increment();
+            }
+          });
+
+  auto &p2p_sent_bytes = BuildCounter()
+                             .Name("p2p_sent_bytes")
+                             .Help("Bytes sent to other peers")
+                             .Register(*registry_);
+  auto &p2p_throttle_wait =
+      BuildHistogram()
+          .Name("p2p_throttle_wait_us")
+          .Help("Time messages waited for bandwidth in microseconds")
+          .Register(*registry_);
+  auto &p2p_throttled_senders =
+      BuildGauge()
+          .Name("p2p_throttled_senders")
+          .Help("Number of senders waiting for bandwidth")
+          .Register(*registry_);
+
+  p2p_traffic_subscriber_ =
+      SubscriberCreator<bool, iroha::P2pTrafficStats>::template create<
+          EventTypes::kOnP2pTraffic>(
+          SubscriptionEngineHandlers::kMetrics,
+          [&](auto &, iroha::P2pTrafficStats stats) {
+            prometheus::Labels const labels{{"class", stats.message_class}};
+            p2p_sent_bytes.Add(labels).Increment(stats.bytes);
+            p2p_throttle_wait
+                .Add(labels,
+                     Histogram::BucketBoundaries{
+                         1000, 10000, 100000, 1000000, 10000000})
+                .Observe(stats.throttled.count());
+            p2p_throttled_senders.Add(labels).Set(stats.waiting);
+          });
+
+  auto &p2p_rejected_calls =
+      BuildCounter()
+          .Name("p2p_rejected_calls")
+          .Help("Number of inbound peer calls rejected by peer_access")
+          .Register(*registry_);
+
+  peer_call_rejected_subscriber_ =
+      SubscriberCreator<bool, std::string>::template create<
+          EventTypes::kOnPeerCallRejected>(
+          SubscriptionEngineHandlers::kMetrics,
+          [&](auto &, std::string reason) {
+            p2p_rejected_calls.Add({{"reason", reason}}).Increment();
           });
   ///////////////////////////////
 
@@ -20,8 +20,12 @@
 #include "interfaces/common_objects/types.hpp"
 #include "interfaces/iroha_internal/block.hpp"
 #include "logger/logger_fwd.hpp"
+#include "main/block_phase_stats.hpp"
+#include "main/block_store_status.hpp"
 #include "main/rdb_status.hpp"
 #include "main/iroha_status.hpp"
+#include "main/p2p_traffic_stats.hpp"
+#include "main/query_stats.hpp"
 #include "main/subscription.hpp"
 #include "network/ordering_gate_common.hpp"
 
@@ -34,6 +38,15 @@ class Metrics : public std::enable_shared_from_this<Metrics> {
   using MstMetrics = std::tuple<size_t, size_t>;
   using MstSubscriber = iroha::BaseSubscriber<bool, MstMetrics>;
   using RdbSubscriber = iroha::BaseSubscriber<bool, iroha::RocksDbStatus>;
+  using BlockStoreSubscriber =
+      iroha::BaseSubscriber<bool, iroha::BlockStoreStatus>;
+  using QuerySubscriber = iroha::BaseSubscriber<bool, iroha::QueryStats>;
+  using BlockPhaseSubscriber =
+      iroha::BaseSubscriber<bool, iroha::BlockPhaseStats>;
+  using BlockStreamOverflowSubscriber = iroha::BaseSubscriber<bool, bool>;
+  using P2pTrafficSubscriber =
+      iroha::BaseSubscriber<bool, iroha::P2pTrafficStats>;
+  using PeerCallRejectedSubscriber = iroha::BaseSubscriber<bool, std::string>;
 
   std::string listen_addr_port_;
   std::shared_ptr<prometheus::Exposer> exposer_;
@@ -42,6 +55,13 @@ class Metrics : public std::enable_shared_from_this<Metrics> {
   std::shared_ptr<BlockSubscriber> block_subscriber_;
   std::shared_ptr<MstSubscriber> mst_subscriber_;
   std::shared_ptr<RdbSubscriber> rdb_subscriber_;
+  std::shared_ptr<BlockStoreSubscriber> block_store_subscriber_;
+  std::shared_ptr<QuerySubscriber> query_subscriber_;
+  std::shared_ptr<BlockPhaseSubscriber> block_phase_subscriber_;
+  std::shared_ptr<BlockStreamOverflowSubscriber>
+      block_stream_overflow_subscriber_;
+  std::shared_ptr<P2pTrafficSubscriber> p2p_traffic_subscriber_;
+  std::shared_ptr<PeerCallRejectedSubscriber> peer_call_rejected_subscriber_;
   logger::LoggerPtr logger_;
   std::chrono::steady_clock::time_point uptime_start_timepoint_;
   std::thread uptime_thread_;
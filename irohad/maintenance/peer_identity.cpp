@@ -0,0 +1,113 @@
+/**
+ * Copyright Soramitsu Co., Ltd. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+#include "maintenance/peer_identity.hpp"
+
+#include <fmt/core.h>
+#include <rapidjson/document.h>
+#include <rapidjson/stringbuffer.h>
+#include <rapidjson/writer.h>
+#include <boost/algorithm/string/predicate.hpp>
+#include "cryptography/blob.hpp"
+#include "cryptography/crypto_provider/crypto_signer.hpp"
+#include "cryptography/crypto_provider/crypto_verifier.hpp"
+#include "cryptography/keypair.hpp"
+
+namespace iroha {
+
+  std::string signIdentity(PeerIdentity const &identity,
+                           shared_model::crypto::Keypair const &keypair) {
+    using namespace rapidjson;
+    StringBuffer statement;
+    {
+      Writer<StringBuffer> writer(statement);
+      writer.StartObject();
+      writer.Key("public_key");
+      writer.String(identity.public_key.c_str());
+      writer.Key("chain_id");
+      writer.String(identity.chain_id.c_str());
+      writer.Key("version");
+      writer.String(identity.version.c_str());
+      writer.Key("genesis_hash");
+      if (identity.genesis_hash) {
+        writer.String(identity.genesis_hash->c_str());
+      } else {
+        writer.Null();
+      }
+      writer.Key("issued_at");
+      writer.Uint64(identity.issued_at);
+      writer.EndObject();
+    }
+    std::string_view bytes(statement.GetString(), statement.GetLength());
+    auto signature = shared_model::crypto::CryptoSigner::sign(
+        shared_model::crypto::Blob(bytes), keypair);
+
+    StringBuffer buffer;
+    Writer<StringBuffer> writer(buffer);
+    writer.StartObject();
+    writer.Key("statement");
+    writer.String(bytes.data(), bytes.size());
+    writer.Key("signature");
+    writer.String(signature.c_str());
+    writer.EndObject();
+    return std::string(buffer.GetString(), buffer.GetLength());
+  }
+
+  expected::Result<PeerIdentity, std::string> verifyIdentity(
+      std::string_view signed_identity,
+      shared_model::interface::types::PublicKeyHexStringView trusted_key) {
+    rapidjson::Document document;
+    document.Parse(signed_identity.data(), signed_identity.size());
+    if (document.HasParseError() or not document.IsObject()
+        or not document.HasMember("statement")
+        or not document["statement"].IsString()
+        or not document.HasMember("signature")
+        or not document["signature"].IsString()) {
+      return expected::makeError("Malformed signed identity");
+    }
+    std::string_view bytes(document["statement"].GetString(),
+                           document["statement"].GetStringLength());
+    std::string_view signature(document["signature"].GetString(),
+                               document["signature"].GetStringLength());
+    if (auto e = expected::resultToOptionalError(
+            shared_model::crypto::CryptoVerifier::verify(
+                shared_model::interface::types::SignedHexStringView{signature},
+                shared_model::crypto::Blob(bytes),
+                trusted_key))) {
+      return fmt::format("Invalid signature of the identity: {}", e.value());
+    }
+
+    rapidjson::Document statement;
+    statement.Parse(bytes.data(), bytes.size());
+    auto string_member = [&statement](const char *name) -> const char * {
+      auto it = statement.FindMember(name);
+      return it != statement.MemberEnd() and it->value.IsString()
+          ? it->value.GetString()
+          : nullptr;
+    };
+    if (statement.HasParseError() or not statement.IsObject()
+        or not string_member("public_key") or not string_member("chain_id")
+        or not string_member("version") or not statement.HasMember("issued_at")
+        or not statement["issued_at"].IsUint64()) {
+      return expected::makeError("Malformed identity statement");
+    }
+    PeerIdentity identity;
+    identity.public_key = string_member("public_key");
+    identity.chain_id = string_member("chain_id");
+    identity.version = string_member("version");
+    if (auto hash = string_member("genesis_hash")) {
+      identity.genesis_hash = hash;
+    }
+    identity.issued_at = statement["issued_at"].GetUint64();
+    if (not boost::iequals(
+            identity.public_key,
+            static_cast<std::string_view const &>(trusted_key))) {
+      return fmt::format("Identity is issued for another key {}",
+                         identity.public_key);
+    }
+    return identity;
+  }
+
+}  // namespace iroha
@@ -0,0 +1,57 @@
+/**
+ * Copyright Soramitsu Co., Ltd. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+#ifndef IROHA_MAINTENANCE_PEER_IDENTITY_HPP
+#define IROHA_MAINTENANCE_PEER_IDENTITY_HPP
+
+#include <optional>
+#include <string>
+#include <string_view>
+
+#include "common/result.hpp"
+#include "interfaces/common_objects/string_view_types.hpp"
+
+namespace shared_model::crypto {
+  class Keypair;
+}
+
+namespace iroha {
+
+  /**
+   * Identity of the peer and of its network. Tooling compares it with the
+   * values known out of band before sending anything to the peer.
+   */
+  struct PeerIdentity {
+    std::string public_key;
+    std::string chain_id;
+    std::string version;
+    /// hash of the first block, absent until the peer has it
+    std::optional<std::string> genesis_hash;
+    /// milliseconds since epoch when the statement was signed
+    uint64_t issued_at = 0;
+  };
+
+  /**
+   * Sign the identity with the key of the peer
+   * @return JSON object with the identity serialized to a string as
+   * `statement' and the hex signature of these bytes as `signature'
+   */
+  std::string signIdentity(PeerIdentity const &identity,
+                           shared_model::crypto::Keypair const &keypair);
+
+  /**
+   * Check the signed statement returned by signIdentity
+   * @param signed_identity - the statement with its signature
+   * @param trusted_key - key the peer is expected to have
+   * @return the identity, or an error if the statement is malformed, is not
+   * issued for trusted_key or its signature is invalid
+   */
+  expected::Result<PeerIdentity, std::string> verifyIdentity(
+      std::string_view signed_identity,
+      shared_model::interface::types::PublicKeyHexStringView trusted_key);
+
+}  // namespace iroha
+
+#endif  // IROHA_MAINTENANCE_PEER_IDENTITY_HPP
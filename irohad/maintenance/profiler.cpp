@@ -0,0 +1,160 @@
+/**
+ * Copyright Soramitsu Co., Ltd. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+#include "maintenance/profiler.hpp"
+
+#include <cxxabi.h>
+#include <dlfcn.h>
+#include <execinfo.h>
+#include <signal.h>
+#include <sys/time.h>
+#include <algorithm>
+#include <atomic>
+#include <cerrno>
+#include <cstdint>
+#include <cstdlib>
+#include <cstring>
+#include <iterator>
+#include <map>
+#include <memory>
+#include <mutex>
+#include <thread>
+#include <unordered_map>
+#include <vector>
+
+#include <fmt/core.h>
+
+using iroha::Profiler;
+
+namespace {
+  struct Sample {
+    /// set after the frames are written, 0 if the sample is not taken
+    std::atomic<int> depth{0};
+    void *frames[Profiler::kMaxDepth];
+  };
+
+  /// frames of the signal handler and of the signal trampoline
+  constexpr int kSkippedFrames = 2;
+
+  std::mutex profile_mutex;
+  /// written from the signal handler, so it is allocated once and never freed
+  Sample *samples_buffer = nullptr;
+  /// the buffer while a profile is collected, null otherwise
+  std::atomic<Sample *> active_samples{nullptr};
+  std::atomic<size_t> samples_count{0};
+
+  void onProfilingSignal(int) {
+    auto const saved_errno = errno;
+    if (auto samples = active_samples.load(std::memory_order_acquire)) {
+      auto const index = samples_count.fetch_add(1, std::memory_order_relaxed);
+      if (index < Profiler::kMaxSamples) {
+        auto &sample = samples[index];
+        sample.depth.store(
+            backtrace(sample.frames, static_cast<int>(Profiler::kMaxDepth)),
+            std::memory_order_release);
+      }
+    }
+    errno = saved_errno;
+  }
+
+  /// Name of the function, or the offset in the binary if it is not exported
+  std::string symbolize(void *address) {
+    Dl_info info;
+    if (dladdr(address, &info) == 0) {
+      return fmt::format("{}", address);
+    }
+    if (info.dli_sname != nullptr) {
+      int status = 0;
+      std::unique_ptr<char, decltype(&std::free)> demangled(
+          abi::__cxa_demangle(info.dli_sname, nullptr, nullptr, &status),
+          &std::free);
+      return status == 0 ? demangled.get() : info.dli_sname;
+    }
+    char const *module = info.dli_fname ? info.dli_fname : "?";
+    if (auto slash = std::strrchr(module, '/')) {
+      module = slash + 1;
+    }
+    return fmt::format("{}+{:#x}",
+                       module,
+                       reinterpret_cast<uintptr_t>(address)
+                           - reinterpret_cast<uintptr_t>(info.dli_fbase));
+  }
+}  // namespace
+
+iroha::expected::Result<std::string, std::string> Profiler::profile(
+    std::chrono::milliseconds duration, uint32_t frequency) {
+  std::unique_lock<std::mutex> lock(profile_mutex, std::try_to_lock);
+  if (not lock.owns_lock()) {
+    return expected::makeError("Another profile is being collected");
+  }
+
+  static std::once_flag init;
+  std::call_once(init, [] {
+    // the first call loads libgcc, which must not happen in a signal handler
+    void *frame;
+    backtrace(&frame, 1);
+    samples_buffer = new Sample[kMaxSamples];
+
+    // the handler is kept after profiling, so that a late signal does not
+    // terminate the process
+    struct sigaction action {};
+    action.sa_handler = onProfilingSignal;
+    action.sa_flags = SA_RESTART;
+    sigemptyset(&action.sa_mask);
+    sigaction(SIGPROF, &action, nullptr);
+  });
+
+  for (size_t i = 0; i < kMaxSamples; ++i) {
+    samples_buffer[i].depth.store(0, std::memory_order_relaxed);
+  }
+  samples_count.store(0);
+  active_samples.store(samples_buffer, std::memory_order_release);
+
+  auto const interval_us = 1000000 / std::clamp<uint32_t>(frequency, 1, 1000);
+  itimerval timer{};
+  timer.it_interval.tv_sec = interval_us / 1000000;
+  timer.it_interval.tv_usec = interval_us % 1000000;
+  timer.it_value = timer.it_interval;
+  if (setitimer(ITIMER_PROF, &timer, nullptr) != 0) {
+    active_samples.store(nullptr, std::memory_order_release);
+    return expected::makeError(fmt::format(
+        "Failed to set the profiling timer: {}", std::strerror(errno)));
+  }
+  std::this_thread::sleep_for(duration);
+  itimerval const stop{};
+  setitimer(ITIMER_PROF, &stop, nullptr);
+  active_samples.store(nullptr, std::memory_order_release);
+
+  // stacks with the outermost frame first
+  std::map<std::vector<void *>, size_t> stacks;
+  auto const count = std::min(samples_count.load(), kMaxSamples);
+  for (size_t i = 0; i < count; ++i) {
+    auto const &sample = samples_buffer[i];
+    auto const depth = sample.depth.load(std::memory_order_acquire);
+    if (depth <= kSkippedFrames) {
+      continue;
+    }
+    ++stacks[std::vector<void *>(
+        std::make_reverse_iterator(sample.frames + depth),
+        std::make_reverse_iterator(sample.frames + kSkippedFrames))];
+  }
+
+  std::unordered_map<void *, std::string> names;
+  std::string folded;
+  for (auto const &[frames, samples] : stacks) {
+    for (auto it = frames.begin(); it != frames.end(); ++it) {
+      auto name = names.find(*it);
+      if (name == names.end()) {
+        name = names.emplace(*it, symbolize(*it)).first;
+      }
+      if (it != frames.begin()) {
+        folded += ';';
+      }
+      folded += name->second;
+    }
+    fmt::format_to(std::back_inserter(folded), " {}\n", samples);
+  }
+  return folded;
+}
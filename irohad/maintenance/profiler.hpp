@@ -0,0 +1,43 @@
+/**
+ * Copyright Soramitsu Co., Ltd. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+#ifndef IROHA_MAINTENANCE_PROFILER_HPP
+#define IROHA_MAINTENANCE_PROFILER_HPP
+
+#include <chrono>
+#include <string>
+
+#include "common/result.hpp"
+
+namespace iroha {
+
+  /**
+   * Sampling CPU profiler of the whole process. Stacks of the threads
+   * consuming CPU are sampled on SIGPROF and aggregated in the folded format
+   * of flamegraph tools: one line per distinct stack with the frames from the
+   * outermost one separated by semicolons, followed by the number of samples.
+   */
+  class Profiler {
+   public:
+    /// samples above this number are dropped
+    static constexpr size_t kMaxSamples = 1 << 15;
+    /// frames of a stack above this number are dropped
+    static constexpr size_t kMaxDepth = 48;
+
+    /**
+     * Sample the process for the given duration. The calling thread sleeps
+     * meanwhile, and only one profile is collected at a time in the process.
+     * @param duration - time to sample for
+     * @param frequency - samples per second of CPU time
+     * @return folded stacks, or an error if another profile is being
+     * collected or the timer cannot be set
+     */
+    expected::Result<std::string, std::string> profile(
+        std::chrono::milliseconds duration, uint32_t frequency = 99);
+  };
+
+}  // namespace iroha
+
+#endif  // IROHA_MAINTENANCE_PROFILER_HPP
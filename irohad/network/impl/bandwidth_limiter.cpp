@@ -0,0 +1,42 @@
+/**
+ * Copyright Soramitsu Co., Ltd. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+#include "network/impl/bandwidth_limiter.hpp"
+
+#include <algorithm>
+#include <thread>
+
+using namespace iroha::network;
+
+static constexpr std::chrono::seconds kBurst{1};
+
+BandwidthLimiter::BandwidthLimiter(uint64_t bytes_per_second)
+    : bytes_per_second_(std::max<uint64_t>(bytes_per_second, 1)) {}
+
+std::chrono::microseconds BandwidthLimiter::reserve(size_t bytes,
+                                                    Clock::time_point now) {
+  std::chrono::microseconds const duration{bytes * 1000000ull
+                                           / bytes_per_second_};
+  std::lock_guard<std::mutex> lock(mutex_);
+  reserved_until_ = std::max(reserved_until_, now) + duration;
+  return std::max(
+      std::chrono::duration_cast<std::chrono::microseconds>(
+          reserved_until_ - now - kBurst),
+      std::chrono::microseconds::zero());
+}
+
+std::chrono::microseconds BandwidthLimiter::throttle(size_t bytes) {
+  auto const delay = reserve(bytes, Clock::now());
+  if (delay > std::chrono::microseconds::zero()) {
+    ++waiting_;
+    std::this_thread::sleep_for(delay);
+    --waiting_;
+  }
+  return delay;
+}
+
+size_t BandwidthLimiter::waiting() const {
+  return waiting_;
+}
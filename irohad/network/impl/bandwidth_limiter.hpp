@@ -0,0 +1,57 @@
+/**
+ * Copyright Soramitsu Co., Ltd. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+#ifndef IROHA_BANDWIDTH_LIMITER_HPP
+#define IROHA_BANDWIDTH_LIMITER_HPP
+
+#include <atomic>
+#include <chrono>
+#include <cstddef>
+#include <cstdint>
+#include <mutex>
+
+namespace iroha {
+  namespace network {
+
+    /**
+     * Limits the rate of outgoing traffic of one message class. Bursts of up
+     * to one second worth of traffic are sent without delay.
+     */
+    class BandwidthLimiter {
+     public:
+      using Clock = std::chrono::steady_clock;
+
+      explicit BandwidthLimiter(uint64_t bytes_per_second);
+
+      /**
+       * Reserve bandwidth for a message.
+       * @param bytes - size of the message
+       * @param now - current time
+       * @return time to wait before sending the message
+       */
+      std::chrono::microseconds reserve(size_t bytes, Clock::time_point now);
+
+      /**
+       * Block until a message may be sent.
+       * @param bytes - size of the message
+       * @return time spent waiting
+       */
+      std::chrono::microseconds throttle(size_t bytes);
+
+      /// number of senders currently waiting for bandwidth
+      size_t waiting() const;
+
+     private:
+      const uint64_t bytes_per_second_;
+      std::mutex mutex_;
+      /// time when all the reserved traffic is sent at the configured rate
+      Clock::time_point reserved_until_;
+      std::atomic<size_t> waiting_{0};
+    };
+
+  }  // namespace network
+}  // namespace iroha
+
+#endif  // IROHA_BANDWIDTH_LIMITER_HPP
@@ -8,6 +8,9 @@
 #include "backend/protobuf/block.hpp"
 #include "common/bind.hpp"
 #include "logger/logger.hpp"
+#include "main/p2p_traffic_stats.hpp"
+#include "main/subscription.hpp"
+#include "network/impl/bandwidth_limiter.hpp"
 
 using namespace iroha;
 using namespace iroha::ametsuchi;
@@ -20,6 +23,9 @@ static grpc::Status handleGetBlockError(const BlockQuery::GetBlockError &error,
       log->error("Could not retrieve a block from block storage: {}",
                  error.message);
       return grpc::Status(grpc::StatusCode::NOT_FOUND, "No such block.");
+    case BlockQuery::GetBlockError::Code::kPruned:
+      log->info("Requested block is pruned: {}", error.message);
+      return grpc::Status(grpc::StatusCode::OUT_OF_RANGE, error.message);
     default:
       log->error("Unexpected GetBlockError code!");
       assert(false);
@@ -36,10 +42,12 @@ BlockLoaderService::BlockLoaderService(
     std::shared_ptr<BlockQueryFactory> block_query_factory,
     std::shared_ptr<iroha::consensus::ConsensusResultCache>
         consensus_result_cache,
-    logger::LoggerPtr log)
+    logger::LoggerPtr log,
+    std::shared_ptr<BandwidthLimiter> sync_limiter)
     : block_query_factory_(std::move(block_query_factory)),
       consensus_result_cache_(std::move(consensus_result_cache)),
-      log_(std::move(log)) {}
+      log_(std::move(log)),
+      sync_limiter_(std::move(sync_limiter)) {}
 
 grpc::Status BlockLoaderService::retrieveBlocks(
     ::grpc::ServerContext *context,
@@ -69,6 +77,16 @@ grpc::Status BlockLoaderService::retrieveBlocks(
     *proto_block.mutable_block_v1() =
         static_cast<shared_model::proto::Block *>(block.get())->getTransport();
 
+    P2pTrafficStats stats{"block_sync",
+                          proto_block.ByteSizeLong(),
+                          std::chrono::microseconds::zero(),
+                          0};
+    if (sync_limiter_) {
+      stats.throttled = sync_limiter_->throttle(stats.bytes);
+      stats.waiting = sync_limiter_->waiting();
+    }
+    getSubscription()->notify(EventTypes::kOnP2pTraffic, stats);
+
     if (not writer->Write(proto_block)) {
       log_->error("Broken stream to {}", context->peer());
       break;
@@ -13,13 +13,21 @@
 
 namespace iroha {
   namespace network {
+    class BandwidthLimiter;
+
     class BlockLoaderService : public proto::Loader::Service {
      public:
+      /**
+       * @param sync_limiter - limits the rate of blocks streamed to syncing
+       * peers, not limited if null. Blocks retrieved one by one are requested
+       * by consensus and are never limited.
+       */
       BlockLoaderService(
           std::shared_ptr<ametsuchi::BlockQueryFactory> block_query_factory,
           std::shared_ptr<iroha::consensus::ConsensusResultCache>
               consensus_result_cache,
-          logger::LoggerPtr log);
+          logger::LoggerPtr log,
+          std::shared_ptr<BandwidthLimiter> sync_limiter = nullptr);
 
       grpc::Status retrieveBlocks(
           ::grpc::ServerContext *context,
@@ -35,6 +43,7 @@ namespace iroha {
       std::shared_ptr<iroha::consensus::ConsensusResultCache>
           consensus_result_cache_;
       logger::LoggerPtr log_;
+      std::shared_ptr<BandwidthLimiter> sync_limiter_;
     };
   }  // namespace network
 }  // namespace iroha
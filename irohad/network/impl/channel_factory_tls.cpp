@@ -7,6 +7,7 @@
 
 #include "interfaces/common_objects/peer.hpp"
 #include "network/impl/grpc_channel_params.hpp"
+#include "network/impl/peer_tls_auth_processor.hpp"
 #include "network/impl/tls_credentials.hpp"
 #include "network/peer_tls_certificates_provider.hpp"
 
@@ -40,5 +41,7 @@ ChannelFactoryTls::getChannelCredentials(
     options.pem_private_key = my_creds_.value()->private_key;
     options.pem_cert_chain = my_creds_.value()->certificate;
   }
-  return grpc::SslCredentials(options);
+  // peers negotiate the inter peer protocol version on every call
+  return grpc::CompositeChannelCredentials(grpc::SslCredentials(options),
+                                           makePeerProtocolCallCredentials());
 }
@@ -0,0 +1,47 @@
+/**
+ * Copyright Soramitsu Co., Ltd. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+#include "network/impl/in_process_network.hpp"
+
+#include <fmt/core.h>
+#include "interfaces/common_objects/peer.hpp"
+#include "network/channel_constants.hpp"
+
+using namespace iroha::expected;
+using namespace iroha::network;
+
+void InProcessNetwork::add(const std::string &address, grpc::Server &server) {
+  std::unique_lock<std::shared_timed_mutex> lock(mutex_);
+  servers_[address] = &server;
+}
+
+void InProcessNetwork::remove(const std::string &address) {
+  std::unique_lock<std::shared_timed_mutex> lock(mutex_);
+  servers_.erase(address);
+}
+
+Result<std::shared_ptr<grpc::Channel>, std::string>
+InProcessNetwork::getChannel(const std::string &address) const {
+  std::shared_lock<std::shared_timed_mutex> lock(mutex_);
+  auto it = servers_.find(address);
+  if (it == servers_.end()) {
+    return makeError(fmt::format("No in-process server at {}", address));
+  }
+  grpc::ChannelArguments args;
+  args.SetMaxReceiveMessageSize(kMaxMessageSize);
+  args.SetMaxSendMessageSize(kMaxMessageSize);
+  return it->second->InProcessChannel(args);
+}
+
+InProcessChannelProvider::InProcessChannelProvider(
+    std::shared_ptr<InProcessNetwork> network)
+    : network_(std::move(network)) {}
+
+Result<std::shared_ptr<grpc::Channel>, std::string>
+InProcessChannelProvider::getChannel(
+    const std::string &service_full_name,
+    const shared_model::interface::Peer &peer) {
+  return network_->getChannel(peer.address());
+}
@@ -0,0 +1,70 @@
+/**
+ * Copyright Soramitsu Co., Ltd. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+#ifndef IROHA_IN_PROCESS_NETWORK_HPP
+#define IROHA_IN_PROCESS_NETWORK_HPP
+
+#include "network/impl/channel_provider.hpp"
+
+#include <memory>
+#include <shared_mutex>
+#include <string>
+#include <unordered_map>
+
+#include <grpc++/grpc++.h>
+
+#include "common/result.hpp"
+
+namespace iroha {
+  namespace network {
+
+    /**
+     * Network of gRPC servers running in one process, which are reached by
+     * their addresses without sockets. It lets several peers communicate
+     * in-process, e.g. in integration tests.
+     */
+    class InProcessNetwork {
+     public:
+      /**
+       * Make the server reachable at the address
+       * @param address the server is reached at, e.g. 127.0.0.1:10001
+       * @param server which must outlive its registration
+       */
+      void add(const std::string &address, grpc::Server &server);
+
+      /// Make the server at the address unreachable
+      void remove(const std::string &address);
+
+      /**
+       * @param address of the server
+       * @return in-process channel to the server at the address, error if
+       * there is no such server
+       */
+      iroha::expected::Result<std::shared_ptr<grpc::Channel>, std::string>
+      getChannel(const std::string &address) const;
+
+     private:
+      mutable std::shared_timed_mutex mutex_;
+      std::unordered_map<std::string, grpc::Server *> servers_;
+    };
+
+    /// Provides in-process channels to the peers of the network
+    class InProcessChannelProvider : public ChannelProvider {
+     public:
+      explicit InProcessChannelProvider(
+          std::shared_ptr<InProcessNetwork> network);
+
+      iroha::expected::Result<std::shared_ptr<grpc::Channel>, std::string>
+      getChannel(const std::string &service_full_name,
+                 const shared_model::interface::Peer &peer) override;
+
+     private:
+      std::shared_ptr<InProcessNetwork> network_;
+    };
+
+  }  // namespace network
+}  // namespace iroha
+
+#endif  // IROHA_IN_PROCESS_NETWORK_HPP
@@ -0,0 +1,139 @@
+/**
+ * Copyright Soramitsu Co., Ltd. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+#include "network/impl/peer_access_control.hpp"
+
+#include <algorithm>
+
+#include "main/subscription.hpp"
+
+using namespace iroha::network;
+
+namespace {
+
+  bool contains(const std::vector<std::string> &list, const std::string &ip) {
+    return std::find(list.begin(), list.end(), ip) != list.end();
+  }
+
+  using ServerContextPtr =
+      decltype(std::declval<grpc::experimental::ServerRpcInfo &>()
+                   .server_context());
+
+  class PeerAccessInterceptor : public grpc::experimental::Interceptor {
+   public:
+    PeerAccessInterceptor(std::shared_ptr<PeerAccessControl> access_control,
+                          ServerContextPtr context)
+        : access_control_(std::move(access_control)), context_(context) {}
+
+    ~PeerAccessInterceptor() override {
+      if (admitted_) {
+        access_control_->release(peer_);
+      }
+    }
+
+    void Intercept(
+        grpc::experimental::InterceptorBatchMethods *methods) override {
+      if (methods->QueryInterceptionHookPoint(
+              grpc::experimental::InterceptionHookPoints::
+                  POST_RECV_INITIAL_METADATA)) {
+        peer_ = context_->peer();
+        if (auto reason = access_control_->admit(peer_)) {
+          iroha::getSubscription()->notify(
+              iroha::EventTypes::kOnPeerCallRejected, std::move(*reason));
+          context_->TryCancel();
+        } else {
+          admitted_ = true;
+        }
+      }
+      methods->Proceed();
+    }
+
+   private:
+    std::shared_ptr<PeerAccessControl> access_control_;
+    ServerContextPtr context_;
+    std::string peer_;
+    bool admitted_ = false;
+  };
+
+  class PeerAccessInterceptorFactory
+      : public grpc::experimental::ServerInterceptorFactoryInterface {
+   public:
+    explicit PeerAccessInterceptorFactory(
+        std::shared_ptr<PeerAccessControl> access_control)
+        : access_control_(std::move(access_control)) {}
+
+    grpc::experimental::Interceptor *CreateServerInterceptor(
+        grpc::experimental::ServerRpcInfo *info) override {
+      return new PeerAccessInterceptor(access_control_, info->server_context());
+    }
+
+   private:
+    std::shared_ptr<PeerAccessControl> access_control_;
+  };
+
+}  // namespace
+
+PeerAccessControl::PeerAccessControl(PeerAccessLimits limits)
+    : limits_(std::move(limits)) {}
+
+std::optional<std::string> PeerAccessControl::admit(const std::string &peer) {
+  auto const ip = hostOf(peer);
+  if (contains(limits_.deny, ip)) {
+    return "deny_list";
+  }
+  if (not limits_.allow.empty() and not contains(limits_.allow, ip)) {
+    return "not_allowed";
+  }
+
+  std::lock_guard<std::mutex> lock(mutex_);
+  if (limits_.max_inbound_calls
+      and inbound_calls_ >= *limits_.max_inbound_calls) {
+    return "max_inbound_calls";
+  }
+  auto &ip_calls = calls_per_ip_[ip];
+  if (limits_.max_calls_per_ip and ip_calls >= *limits_.max_calls_per_ip) {
+    if (ip_calls == 0) {
+      calls_per_ip_.erase(ip);
+    }
+    return "max_calls_per_ip";
+  }
+  ++ip_calls;
+  ++inbound_calls_;
+  return std::nullopt;
+}
+
+void PeerAccessControl::release(const std::string &peer) {
+  auto const ip = hostOf(peer);
+  std::lock_guard<std::mutex> lock(mutex_);
+  auto it = calls_per_ip_.find(ip);
+  if (it == calls_per_ip_.end()) {
+    return;
+  }
+  if (--it->second == 0) {
+    calls_per_ip_.erase(it);
+  }
+  --inbound_calls_;
+}
+
+std::string PeerAccessControl::hostOf(const std::string &peer) {
+  // ipv4:10.0.0.1:10001 or ipv6:[::1]:10001, brackets may be escaped
+  auto host = peer.substr(peer.find(':') + 1);
+  if (auto port = host.rfind(':'); port != std::string::npos) {
+    host.resize(port);
+  }
+  for (auto const &bracket : {"[", "]", "%5B", "%5D"}) {
+    if (auto pos = host.find(bracket); pos != std::string::npos) {
+      host.erase(pos, std::char_traits<char>::length(bracket));
+    }
+  }
+  return host;
+}
+
+std::unique_ptr<grpc::experimental::ServerInterceptorFactoryInterface>
+iroha::network::makePeerAccessInterceptorFactory(
+    std::shared_ptr<PeerAccessControl> access_control) {
+  return std::make_unique<PeerAccessInterceptorFactory>(
+      std::move(access_control));
+}
@@ -0,0 +1,73 @@
+/**
+ * Copyright Soramitsu Co., Ltd. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+#ifndef IROHA_PEER_ACCESS_CONTROL_HPP
+#define IROHA_PEER_ACCESS_CONTROL_HPP
+
+#include <cstddef>
+#include <memory>
+#include <mutex>
+#include <optional>
+#include <string>
+#include <unordered_map>
+#include <vector>
+
+#include <grpc++/support/server_interceptor.h>
+
+namespace iroha {
+  namespace network {
+
+    struct PeerAccessLimits {
+      /// IP addresses allowed to call this peer, all if empty
+      std::vector<std::string> allow;
+      /// IP addresses never allowed to call this peer
+      std::vector<std::string> deny;
+      /// maximal number of calls served at once
+      std::optional<size_t> max_inbound_calls;
+      /// maximal number of calls from one IP address served at once
+      std::optional<size_t> max_calls_per_ip;
+    };
+
+    /**
+     * Admits inbound calls of other peers according to the allow and deny
+     * lists and the limits of concurrent calls.
+     */
+    class PeerAccessControl {
+     public:
+      explicit PeerAccessControl(PeerAccessLimits limits);
+
+      /**
+       * Admit a call. An admitted call must be released when finished.
+       * @param peer - the caller address as reported by gRPC, e.g.
+       * ipv4:10.0.0.1:10001
+       * @return reason of the rejection, nullopt if the call is admitted
+       */
+      std::optional<std::string> admit(const std::string &peer);
+
+      /// Release an admitted call of the given peer.
+      void release(const std::string &peer);
+
+      /// Extract the IP address from the caller address reported by gRPC.
+      static std::string hostOf(const std::string &peer);
+
+     private:
+      const PeerAccessLimits limits_;
+      std::mutex mutex_;
+      size_t inbound_calls_{0};
+      std::unordered_map<std::string, size_t> calls_per_ip_;
+    };
+
+    /**
+     * Create a gRPC server interceptor factory, which cancels calls rejected
+     * by the given access control.
+     */
+    std::unique_ptr<grpc::experimental::ServerInterceptorFactoryInterface>
+    makePeerAccessInterceptorFactory(
+        std::shared_ptr<PeerAccessControl> access_control);
+
+  }  // namespace network
+}  // namespace iroha
+
+#endif  // IROHA_PEER_ACCESS_CONTROL_HPP
@@ -0,0 +1,174 @@
+/**
+ * Copyright Soramitsu Co., Ltd. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+#include "network/impl/peer_tls_auth_processor.hpp"
+
+#include <grpc/grpc_security_constants.h>
+
+#include <algorithm>
+#include <charconv>
+
+#include "ametsuchi/peer_query.hpp"
+#include "interfaces/common_objects/peer.hpp"
+#include "logger/logger.hpp"
+
+using namespace iroha::network;
+
+namespace {
+  /// PEM without the surrounding whitespace, which differs between encoders
+  std::string_view trimPem(std::string_view pem) {
+    auto constexpr kWhitespace = " \t\r\n";
+    auto const begin = pem.find_first_not_of(kWhitespace);
+    if (begin == std::string_view::npos) {
+      return {};
+    }
+    auto const end = pem.find_last_not_of(kWhitespace);
+    return pem.substr(begin, end - begin + 1);
+  }
+
+  class PeerProtocolVersionsPlugin : public grpc::MetadataCredentialsPlugin {
+   public:
+    bool IsBlocking() const override {
+      return false;
+    }
+
+    grpc::Status GetMetadata(
+        grpc::string_ref,
+        grpc::string_ref,
+        const grpc::AuthContext &,
+        std::multimap<grpc::string, grpc::string> *metadata) override {
+      std::string versions;
+      for (auto version = kPeerProtocolVersion;
+           version >= kMinPeerProtocolVersion;
+           --version) {
+        if (not versions.empty()) {
+          versions += ',';
+        }
+        versions += std::to_string(version);
+      }
+      metadata->emplace(kPeerProtocolVersionsKey, std::move(versions));
+      return grpc::Status::OK;
+    }
+  };
+}  // namespace
+
+std::optional<uint32_t> iroha::network::negotiatePeerProtocolVersion(
+    std::string_view client_versions) {
+  std::optional<uint32_t> selected;
+  while (not client_versions.empty()) {
+    auto const comma = client_versions.find(',');
+    auto const item = client_versions.substr(0, comma);
+    client_versions.remove_prefix(
+        comma == std::string_view::npos ? client_versions.size() : comma + 1);
+
+    uint32_t version;
+    auto [ptr, ec]{
+        std::from_chars(item.data(), item.data() + item.size(), version)};
+    if (ec != std::errc() or ptr != item.data() + item.size()) {
+      continue;
+    }
+    if (version >= kMinPeerProtocolVersion and version <= kPeerProtocolVersion
+        and (not selected or version > *selected)) {
+      selected = version;
+    }
+  }
+  return selected;
+}
+
+std::shared_ptr<grpc::CallCredentials>
+iroha::network::makePeerProtocolCallCredentials() {
+  return grpc::MetadataCredentialsFromPlugin(
+      std::make_unique<PeerProtocolVersionsPlugin>());
+}
+
+PeerTlsAuthProcessor::PeerTlsAuthProcessor(
+    std::optional<std::shared_ptr<ametsuchi::PeerQuery>> peer_query,
+    logger::LoggerPtr log)
+    : peer_query_(std::move(peer_query)), log_(std::move(log)) {}
+
+bool PeerTlsAuthProcessor::IsBlocking() const {
+  return true;
+}
+
+bool PeerTlsAuthProcessor::pinsClientCertificates() const {
+  return peer_query_.has_value();
+}
+
+grpc::Status PeerTlsAuthProcessor::Process(
+    const InputMetadata &auth_metadata,
+    grpc::AuthContext *context,
+    OutputMetadata *consumed_auth_metadata,
+    OutputMetadata *) {
+  auto const versions = auth_metadata.find(kPeerProtocolVersionsKey);
+  if (versions == auth_metadata.end()) {
+    log_->warn("Rejected a peer call without inter peer protocol versions.");
+    return grpc::Status(grpc::StatusCode::FAILED_PRECONDITION,
+                        "Inter peer protocol versions are not provided.");
+  }
+  auto const version = negotiatePeerProtocolVersion(
+      std::string_view{versions->second.data(), versions->second.size()});
+  if (not version) {
+    log_->warn("Rejected a peer call with inter peer protocol versions {}.",
+               std::string_view{versions->second.data(),
+                                versions->second.size()});
+    return grpc::Status(
+        grpc::StatusCode::FAILED_PRECONDITION,
+        "No common inter peer protocol version, supported versions are "
+            + std::to_string(kMinPeerProtocolVersion) + " to "
+            + std::to_string(kPeerProtocolVersion) + ".");
+  }
+  consumed_auth_metadata->emplace(kPeerProtocolVersionsKey,
+                                  std::string{versions->second.data(),
+                                              versions->second.size()});
+
+  if (pinsClientCertificates()) {
+    auto const certificates =
+        context->FindPropertyValues(GRPC_X509_PEM_CERT_PROPERTY_NAME);
+    if (certificates.empty()) {
+      log_->warn("Rejected a peer call without a client certificate.");
+      return grpc::Status(grpc::StatusCode::UNAUTHENTICATED,
+                          "Client certificate is required.");
+    }
+    if (not isLedgerPeerCertificate(std::string_view{
+            certificates.front().data(), certificates.front().size()})) {
+      log_->warn("Rejected a peer call with a certificate of unknown peer.");
+      return grpc::Status(grpc::StatusCode::UNAUTHENTICATED,
+                          "Client certificate is not of a ledger peer.");
+    }
+  }
+  return grpc::Status::OK;
+}
+
+void PeerTlsAuthProcessor::refreshLedgerPeers() {
+  std::lock_guard<std::mutex> lock(peer_query_mutex_);
+  ledger_certificates_.reset();
+}
+
+bool PeerTlsAuthProcessor::isLedgerPeerCertificate(
+    std::string_view certificate) {
+  certificate = trimPem(certificate);
+  std::lock_guard<std::mutex> lock(peer_query_mutex_);
+  if (not ledger_certificates_) {
+    std::vector<std::string> certificates;
+    // both validating and syncing peers talk to each other
+    for (bool syncing_peers : {false, true}) {
+      auto peers = peer_query_.value()->getLedgerPeers(syncing_peers);
+      if (not peers) {
+        log_->error("Failed to get ledger peers.");
+        return false;
+      }
+      for (auto const &peer : *peers) {
+        if (peer->tlsCertificate()) {
+          certificates.emplace_back(trimPem(*peer->tlsCertificate()));
+        }
+      }
+    }
+    ledger_certificates_ = std::move(certificates);
+  }
+  return std::find(ledger_certificates_->begin(),
+                   ledger_certificates_->end(),
+                   certificate)
+      != ledger_certificates_->end();
+}
@@ -0,0 +1,95 @@
+/**
+ * Copyright Soramitsu Co., Ltd. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+#ifndef IROHA_PEER_TLS_AUTH_PROCESSOR_HPP
+#define IROHA_PEER_TLS_AUTH_PROCESSOR_HPP
+
+#include <grpcpp/security/auth_metadata_processor.h>
+#include <grpcpp/security/credentials.h>
+
+#include <cstdint>
+#include <memory>
+#include <mutex>
+#include <optional>
+#include <string>
+#include <string_view>
+#include <vector>
+
+#include "logger/logger_fwd.hpp"
+
+namespace iroha {
+  namespace ametsuchi {
+    class PeerQuery;
+  }
+  namespace network {
+
+    /// Metadata key with the inter peer protocol versions a client speaks
+    constexpr char kPeerProtocolVersionsKey[] = "iroha-peer-protocol-versions";
+
+    /// Current version of the inter peer protocol
+    constexpr uint32_t kPeerProtocolVersion = 1;
+
+    /// Oldest version of the inter peer protocol this peer still speaks
+    constexpr uint32_t kMinPeerProtocolVersion = 1;
+
+    /**
+     * Select the newest inter peer protocol version both sides speak
+     * @param client_versions comma separated versions the client speaks
+     * @return the selected version, or nothing if there is no common one
+     */
+    std::optional<uint32_t> negotiatePeerProtocolVersion(
+        std::string_view client_versions);
+
+    /**
+     * Call credentials which send the inter peer protocol versions this peer
+     * speaks with every call, to be composed with the TLS channel credentials
+     */
+    std::shared_ptr<grpc::CallCredentials> makePeerProtocolCallCredentials();
+
+    /**
+     * Authenticates incoming inter peer calls: negotiates the protocol version
+     * and, when the peer query is given, accepts only the clients presenting
+     * the TLS certificate of a peer registered in the ledger. The certificates
+     * of the ledger peers are cached until refreshLedgerPeers is called.
+     */
+    class PeerTlsAuthProcessor : public grpc::AuthMetadataProcessor {
+     public:
+      /**
+       * @param peer_query to pin client certificates to the ledger peers, or
+       * nothing to accept any client the TLS layer accepted
+       * @param log to report rejected calls
+       */
+      PeerTlsAuthProcessor(
+          std::optional<std::shared_ptr<ametsuchi::PeerQuery>> peer_query,
+          logger::LoggerPtr log);
+
+      bool IsBlocking() const override;
+
+      grpc::Status Process(const InputMetadata &auth_metadata,
+                           grpc::AuthContext *context,
+                           OutputMetadata *consumed_auth_metadata,
+                           OutputMetadata *response_metadata) override;
+
+      /// Whether client certificates are pinned to the ledger peers
+      bool pinsClientCertificates() const;
+
+      /// Reload the certificates of the ledger peers on the next call, to be
+      /// called after a block is committed
+      void refreshLedgerPeers();
+
+     private:
+      bool isLedgerPeerCertificate(std::string_view certificate);
+
+      std::mutex peer_query_mutex_;
+      std::optional<std::shared_ptr<ametsuchi::PeerQuery>> peer_query_;
+      /// trimmed certificates of the ledger peers, nothing if not loaded
+      std::optional<std::vector<std::string>> ledger_certificates_;
+      logger::LoggerPtr log_;
+    };
+
+  }  // namespace network
+}  // namespace iroha
+
+#endif  // IROHA_PEER_TLS_AUTH_PROCESSOR_HPP
@@ -8,6 +8,7 @@
 #include <fmt/core.h>
 #include <mutex>
 
+#include "datetime/time.hpp"
 #include "interfaces/iroha_internal/transaction_batch.hpp"
 #include "interfaces/transaction.hpp"
 #include "main/subscription.hpp"
@@ -60,7 +61,7 @@ namespace {
 
 namespace iroha::ordering {
 
-  BatchesContext::BatchesContext() : tx_count_(0ull) {}
+  BatchesContext::BatchesContext() : tx_count_(0ull), bytes_(0ull) {}
 
   uint64_t BatchesContext::count(BatchesSetType const &src) {
     return std::accumulate(src.begin(),
@@ -71,10 +72,24 @@ namespace iroha::ordering {
                            });
   }
 
+  uint64_t BatchesContext::bytes(
+      std::shared_ptr<shared_model::interface::TransactionBatch> const &batch) {
+    return std::accumulate(batch->transactions().begin(),
+                           batch->transactions().end(),
+                           0ull,
+                           [](unsigned long long sum, auto const &tx) {
+                             return sum + tx->payload().size();
+                           });
+  }
+
   uint64_t BatchesContext::getTxsCount() const {
     return tx_count_;
   }
 
+  uint64_t BatchesContext::getBytes() const {
+    return bytes_;
+  }
+
   BatchesContext::BatchesSetType &BatchesContext::getBatchesSet() {
     return batches_;
   }
@@ -82,8 +97,10 @@ namespace iroha::ordering {
   bool BatchesContext::insert(
       std::shared_ptr<shared_model::interface::TransactionBatch> const &batch) {
     auto const inserted = batches_.insert(batch).second;
-    if (inserted)
+    if (inserted) {
       tx_count_ += batch->transactions().size();
+      bytes_ += bytes(batch);
+    }
 
     assert(count(batches_) == tx_count_);
     return inserted;
@@ -91,13 +108,16 @@ namespace iroha::ordering {
 
   bool BatchesContext::removeBatch(
       std::shared_ptr<shared_model::interface::TransactionBatch> const &batch) {
-    auto const was = batches_.size();
-    batches_.erase(batch);
-    if (batches_.size() != was)
-      tx_count_ -= batch->transactions().size();
+    auto const it = batches_.find(batch);
+    if (it == batches_.end())
+      return false;
+
+    tx_count_ -= (*it)->transactions().size();
+    bytes_ -= bytes(*it);
+    batches_.erase(it);
 
     assert(count(batches_) == tx_count_);
-    return (was != batches_.size());
+    return true;
   }
 
   void BatchesContext::merge(BatchesContext &from) {
@@ -105,10 +125,13 @@ namespace iroha::ordering {
     while (it != from.batches_.end())
       if (batches_.insert(*it).second) {
         auto const tx_count = (*it)->transactions().size();
+        auto const batch_bytes = bytes(*it);
         it = from.batches_.erase(it);
 
         tx_count_ += tx_count;
         from.tx_count_ -= tx_count;
+        bytes_ += batch_bytes;
+        from.bytes_ -= batch_bytes;
       } else
         ++it;
 
@@ -124,8 +147,7 @@ namespace iroha::ordering {
         std::chrono::seconds(10ull),  /// repeat task execution period
         [expiration_range, w_mst_state(utils::make_weak(mst_state_))]() {
           if (auto s_mst_state = w_mst_state.lock()) {
-            auto const now = std::chrono::system_clock::now().time_since_epoch()
-                / std::chrono::milliseconds(1);
+            auto const now = iroha::time::now();
 
             s_mst_state->exclusiveAccess(
                 [now, expiration_range](auto &mst_state) {
@@ -274,6 +296,11 @@ namespace iroha::ordering {
     return batches_cache_.getTxsCount();
   }
 
+  uint64_t BatchesCache::bytesCount() const {
+    std::shared_lock lock(batches_cache_cs_);
+    return batches_cache_.getBytes() + used_batches_cache_.getBytes();
+  }
+
   void BatchesCache::forCachedBatches(
       std::function<void(BatchesSetType &)> const &f) {
     std::unique_lock lock(batches_cache_cs_);
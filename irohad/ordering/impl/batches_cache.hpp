@@ -43,13 +43,23 @@ namespace iroha::ordering {
     /// Save this value in additional field to avoid batches iteration on
     /// request.
     uint64_t tx_count_;
+    /// Payload bytes of the transactions, counted the same way.
+    uint64_t bytes_;
     BatchesSetType batches_;
 
     static uint64_t count(BatchesSetType const &src);
 
    public:
+    /// Payload bytes of the transactions of the batch. Signatures are not
+    /// counted, since they are added to the stored batches.
+    static uint64_t bytes(
+        std::shared_ptr<shared_model::interface::TransactionBatch> const
+            &batch);
+
     uint64_t getTxsCount() const;
 
+    uint64_t getBytes() const;
+
     BatchesSetType &getBatchesSet();
 
     bool insert(std::shared_ptr<shared_model::interface::TransactionBatch> const
@@ -68,10 +78,13 @@ namespace iroha::ordering {
            process_iteration && it != batches_.end();)
         if (std::forward<_Predic>(pred)(*it, process_iteration)) {
           auto const erased_size = (*it)->transactions().size();
+          auto const erased_bytes = bytes(*it);
           it = batches_.erase(it);
 
           assert(tx_count_ >= erased_size);
           tx_count_ -= erased_size;
+          assert(bytes_ >= erased_bytes);
+          bytes_ -= erased_bytes;
         } else
           ++it;
 
@@ -180,16 +193,19 @@ namespace iroha::ordering {
     bool isEmpty();
     uint64_t txsCount() const;
     uint64_t availableTxsCount() const;
+    /// Payload bytes of the cached batches, both available and used
+    uint64_t bytesCount() const;
 
     void forCachedBatches(std::function<void(BatchesSetType &)> const &f);
 
-    template <typename IsProcessedFunc>
+    template <typename IsProcessedFunc, typename IsReadyFunc>
     void getTransactions(
         size_t requested_tx_amount,
         std::vector<std::shared_ptr<shared_model::interface::Transaction>>
             &collection,
         BloomFilter256 &bf,
-        IsProcessedFunc &&is_processed) {
+        IsProcessedFunc &&is_processed,
+        IsReadyFunc &&is_ready) {
       collection.clear();
       collection.reserve(requested_tx_amount);
       bf.clear();
@@ -200,6 +216,9 @@ namespace iroha::ordering {
         if (std::forward<IsProcessedFunc>(is_processed)(batch))
           return true;
 
+        if (not std::forward<IsReadyFunc>(is_ready)(batch))
+          return false;
+
         auto const txs_count = batch->transactions().size();
         if (collection.size() + txs_count > requested_tx_amount) {
           ++depth_counter;
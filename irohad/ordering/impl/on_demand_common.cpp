@@ -5,6 +5,13 @@
 
 #include "ordering/impl/on_demand_common.hpp"
 
+#include <algorithm>
+
+#include <fmt/core.h>
+#include "interfaces/iroha_internal/transaction_batch.hpp"
+#include "interfaces/transaction.hpp"
+#include "validators/field_validator.hpp"
+
 namespace iroha {
   namespace ordering {
 
@@ -18,5 +25,32 @@ namespace iroha {
       return {round.block_round, round.reject_round + 1};
     }
 
+    std::optional<std::string> getExpiryReason(
+        shared_model::interface::TransactionBatch const &batch,
+        shared_model::interface::types::TimestampType now) {
+      for (auto const &tx : batch.transactions()) {
+        auto const expires_at = tx->createdTime()
+            + shared_model::validation::FieldValidator::kDefaultMaxDelay;
+        if (now > expires_at) {
+          return fmt::format(
+              "transaction {} created at {} has expired at {}, now: {}",
+              tx->hash().hex(),
+              tx->createdTime(),
+              expires_at,
+              now);
+        }
+      }
+      return std::nullopt;
+    }
+
+    bool isBatchReady(shared_model::interface::TransactionBatch const &batch,
+                      shared_model::interface::types::TimestampType now) {
+      return std::all_of(batch.transactions().begin(),
+                         batch.transactions().end(),
+                         [now](auto const &tx) {
+                           return tx->notBefore() <= now;
+                         });
+    }
+
   }  // namespace ordering
 }  // namespace iroha
@@ -8,12 +8,15 @@
 
 #include <memory>
 #include <optional>
+#include <string>
 #include <vector>
 
 #include "consensus/round.hpp"
+#include "interfaces/common_objects/types.hpp"
 
 namespace shared_model::interface {
   class Proposal;
+  class TransactionBatch;
 }
 
 namespace iroha::ordering {
@@ -33,6 +36,31 @@ namespace iroha::ordering {
       std::tuple<consensus::Round,
                  std::shared_ptr<const shared_model::interface::Proposal>>;
 
+  /// Batch dropped from the queue, because it expired before being proposed
+  struct ExpiredBatchEvent {
+    std::shared_ptr<shared_model::interface::TransactionBatch> batch;
+    std::string reason;
+  };
+
+  /**
+   * Checks whether the batch has outlived any of its transactions
+   * @param batch to check
+   * @param now - current time
+   * @return the reason of expiry if some transaction has expired
+   */
+  std::optional<std::string> getExpiryReason(
+      shared_model::interface::TransactionBatch const &batch,
+      shared_model::interface::types::TimestampType now);
+
+  /**
+   * Checks that not before time of every transaction of the batch has come
+   * @param batch to check
+   * @param now - current time
+   * @return true if the batch may be proposed
+   */
+  bool isBatchReady(shared_model::interface::TransactionBatch const &batch,
+                    shared_model::interface::types::TimestampType now);
+
 }  // namespace iroha::ordering
 
 #endif  // IROHA_ON_DEMAND_COMMON_HPP
@@ -23,7 +23,6 @@
 #include "logger/logger.hpp"
 #include "main/subscription.hpp"
 #include "ordering/impl/on_demand_common.hpp"
-#include "validators/field_validator.hpp"
 
 using iroha::ordering::OnDemandOrderingGate;
 
@@ -191,14 +190,10 @@ void OnDemandOrderingGate::sendCachedTransactions() {
     auto const now = iroha::time::now();
 
     for (; end_iterator != batches.end();) {
-      if (std::any_of(
-              end_iterator->get()->transactions().begin(),
-              end_iterator->get()->transactions().end(),
-              [&](const auto &tx) {
-                return (uint64_t)now
-                    > shared_model::validation::FieldValidator::kDefaultMaxDelay
-                    + tx->createdTime();
-              })) {
+      if (auto reason = getExpiryReason(**end_iterator, now)) {
+        iroha::getSubscription()->notify(
+            iroha::EventTypes::kOnExpiredBatches,
+            ExpiredBatchEvent{*end_iterator, std::move(*reason)});
         end_iterator = batches.erase(end_iterator);
         continue;
       }
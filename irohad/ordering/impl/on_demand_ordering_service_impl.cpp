@@ -70,13 +70,15 @@ OnDemandOrderingServiceImpl::OnDemandOrderingServiceImpl(
         proposal_factory,
     std::shared_ptr<ametsuchi::TxPresenceCache> tx_cache,
     logger::LoggerPtr log,
-    size_t number_of_proposals)
+    size_t number_of_proposals,
+    std::shared_ptr<MemoryBudget> memory_budget)
     : transaction_limit_(transaction_limit),
       number_of_proposals_(number_of_proposals),
       max_proposal_pack_(max_proposal_pack),
       proposal_factory_(std::move(proposal_factory)),
       tx_cache_(std::move(tx_cache)),
-      log_(std::move(log)) {
+      log_(std::move(log)),
+      memory_budget_(std::move(memory_budget)) {
 #if USE_BLOOM_FILTER
   remote_proposal_observer_ =
       SubscriberCreator<bool, RemoteProposalDownloadedEvent>::template create<
@@ -154,8 +156,9 @@ void OnDemandOrderingServiceImpl::onCollaborationOutcome(
 }
 
 void OnDemandOrderingServiceImpl::onBatches(CollectionType batches) {
+  auto const now = iroha::time::now();
   for (auto &batch : batches)
-    if (not batchAlreadyProcessed(*batch))
+    if (not batchAlreadyProcessed(*batch) and not batchExpired(batch, now))
       if (!insertBatchToCache(batch))
         break;
 
@@ -165,7 +168,14 @@ void OnDemandOrderingServiceImpl::onBatches(CollectionType batches) {
 // ---------------------------------| Private |---------------------------------
 bool OnDemandOrderingServiceImpl::insertBatchToCache(
     std::shared_ptr<shared_model::interface::TransactionBatch> const &batch) {
+  if (memory_budget_
+      and memory_budget_->exhausted(MemoryBudget::Buffer::kTransactions)) {
+    log_->warn("Transactions memory limit is reached, dropping batch {}",
+               batch->reducedHash());
+    return false;
+  }
   auto const available_txs_count = batches_cache_.insert(batch);
+  updateMemoryUsage();
   if (available_txs_count >= transaction_limit_)
     getSubscription()->notify(EventTypes::kOnTxsEnoughForProposal,
                               std::shared_ptr(batch));
@@ -176,6 +186,14 @@ bool OnDemandOrderingServiceImpl::insertBatchToCache(
 void OnDemandOrderingServiceImpl::removeFromBatchesCache(
     const OnDemandOrderingService::HashesSetType &hashes) {
   batches_cache_.remove(hashes);
+  updateMemoryUsage();
+}
+
+void OnDemandOrderingServiceImpl::updateMemoryUsage() {
+  if (memory_budget_) {
+    memory_budget_->set(MemoryBudget::Buffer::kTransactions,
+                        batches_cache_.bytesCount());
+  }
 }
 
 bool OnDemandOrderingServiceImpl::isEmptyBatchesCache() {
@@ -304,14 +322,20 @@ OnDemandOrderingServiceImpl::packNextProposals(const consensus::Round &round) {
   PackedProposalContainer outcome;
   std::vector<std::shared_ptr<shared_model::interface::Transaction>> txs;
   BloomFilter256 bf;
+  auto const now = iroha::time::now();
 
   for (uint32_t ix = 0; ix < number_of_proposals; ++ix) {
     assert(!isEmptyBatchesCache());
     batches_cache_.getTransactions(
-        transaction_limit_, txs, bf, [&](auto const &batch) {
+        transaction_limit_,
+        txs,
+        bf,
+        [&](auto const &batch) {
           assert(batch);
-          return batchAlreadyProcessed(*batch);
-        });
+          return batchAlreadyProcessed(*batch) or batchExpired(batch, now);
+        },
+        [&](auto const &batch) { return isBatchReady(*batch, now); });
+    updateMemoryUsage();
 
     log_->debug(
         "Packed proposal {} contains: {} transactions.", ix, txs.size());
@@ -379,6 +403,19 @@ bool OnDemandOrderingServiceImpl::batchAlreadyProcessed(
       });
 }
 
+bool OnDemandOrderingServiceImpl::batchExpired(
+    std::shared_ptr<shared_model::interface::TransactionBatch> const &batch,
+    shared_model::interface::types::TimestampType now) {
+  auto reason = getExpiryReason(*batch, now);
+  if (not reason) {
+    return false;
+  }
+  log_->info("Batch {} is expired: {}", batch->reducedHash(), *reason);
+  getSubscription()->notify(EventTypes::kOnExpiredBatches,
+                            ExpiredBatchEvent{batch, std::move(*reason)});
+  return true;
+}
+
 bool OnDemandOrderingServiceImpl::hasProposal(consensus::Round round) const {
   std::lock_guard<std::mutex> lock(proposals_mutex_);
   return proposal_map_.find(round.block_round) != proposal_map_.end();
@@ -387,4 +424,5 @@ bool OnDemandOrderingServiceImpl::hasProposal(consensus::Round round) const {
 void OnDemandOrderingServiceImpl::processReceivedProposal(
     CollectionType batches) {
   batches_cache_.processReceivedProposal(std::move(batches));
+  updateMemoryUsage();
 }
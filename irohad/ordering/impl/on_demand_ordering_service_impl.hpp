@@ -14,6 +14,7 @@
 
 #include "interfaces/iroha_internal/unsafe_proposal_factory.hpp"
 #include "logger/logger_fwd.hpp"
+#include "main/memory_budget.hpp"
 #include "ordering/impl/batches_cache.hpp"
 // TODO 2019-03-15 andrei: IR-403 Separate BatchHashEquality and MstState
 #include "main/subscription.hpp"
@@ -40,6 +41,8 @@ namespace iroha {
        * @param log to print progress
        * @param number_of_proposals - number of stored proposals, older will be
        * removed. Default value is 3
+       * @param memory_budget - accounts the memory of the cached batches, new
+       * batches are dropped while it is exhausted. Unlimited if null
        */
       OnDemandOrderingServiceImpl(
           size_t transaction_limit,
@@ -48,7 +51,8 @@ namespace iroha {
               proposal_factory,
           std::shared_ptr<ametsuchi::TxPresenceCache> tx_cache,
           logger::LoggerPtr log,
-          size_t number_of_proposals = 3);
+          size_t number_of_proposals = 3,
+          std::shared_ptr<MemoryBudget> memory_budget = nullptr);
 
       ~OnDemandOrderingServiceImpl() override;
 
@@ -103,6 +107,14 @@ namespace iroha {
       bool batchAlreadyProcessed(
           const shared_model::interface::TransactionBatch &batch);
 
+      /**
+       * Check if batch has expired, notifying about it if so
+       */
+      bool batchExpired(
+          std::shared_ptr<shared_model::interface::TransactionBatch> const
+              &batch,
+          shared_model::interface::types::TimestampType now);
+
       bool insertBatchToCache(
           std::shared_ptr<shared_model::interface::TransactionBatch> const
               &batch);
@@ -110,6 +122,9 @@ namespace iroha {
       void removeFromBatchesCache(
           const OnDemandOrderingService::HashesSetType &hashes);
 
+      /// Report the memory of the cached batches to the memory budget
+      void updateMemoryUsage();
+
       bool isEmptyBatchesCache() override;
 
       uint32_t availableTxsCountBatchesCache() override;
@@ -161,6 +176,8 @@ namespace iroha {
        */
       logger::LoggerPtr log_;
 
+      std::shared_ptr<MemoryBudget> memory_budget_;
+
       /**
        * Current round
        */
@@ -9,6 +9,7 @@
 #include "backend/protobuf/transaction.hpp"
 #include "interfaces/common_objects/peer.hpp"
 #include "logger/logger.hpp"
+#include "main/block_phase_stats.hpp"
 #include "main/subscription.hpp"
 #include "network/impl/client_factory.hpp"
 #include "ordering/impl/os_executor_keepers.hpp"
@@ -99,8 +100,11 @@ namespace {
 
       /// parse request
       std::shared_ptr<shared_model::interface::Proposal const> remote_proposal;
-      if (auto proposal_result = proposal_factory->build(proposal);
-          iroha::expected::hasError(proposal_result)) {
+      auto proposal_result = [&] {
+        iroha::BlockPhaseTimer timer{iroha::BlockPhase::kStatelessValidation};
+        return proposal_factory->build(proposal);
+      }();
+      if (iroha::expected::hasError(proposal_result)) {
         log->warn("{}", proposal_result.assumeError().error);
         break;
       } else
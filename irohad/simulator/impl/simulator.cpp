@@ -10,6 +10,7 @@
 #include "interfaces/iroha_internal/block.hpp"
 #include "interfaces/iroha_internal/proposal.hpp"
 #include "logger/logger.hpp"
+#include "main/block_phase_stats.hpp"
 
 namespace iroha {
   namespace simulator {
@@ -39,8 +40,12 @@ namespace iroha {
             ametsuchi_factory_->createTemporaryWsv(command_executor_);
 
         std::shared_ptr<iroha::validation::VerifiedProposalAndErrors>
-            validated_proposal_and_errors =
-                validator_->validate(proposal, *storage);
+            validated_proposal_and_errors;
+        {
+          BlockPhaseTimer timer{BlockPhase::kExecution};
+          validated_proposal_and_errors =
+              validator_->validate(proposal, *storage);
+        }
         ametsuchi_factory_->prepareBlock(std::move(storage));
 
         return VerifiedProposalCreatorEvent{
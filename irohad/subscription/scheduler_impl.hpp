@@ -149,6 +149,12 @@ namespace iroha::subscription {
       event_.set();
     }
 
+    /// @return number of tasks waiting to be performed
+    size_t pendingTasks() const {
+      std::lock_guard lock(tasks_cs_);
+      return tasks_.size();
+    }
+
     bool isBusy() const override {
       std::lock_guard lock(tasks_cs_);
       return is_busy_;
@@ -16,6 +16,7 @@
 #include "interfaces/common_objects/string_view_types.hpp"
 #include "interfaces/iroha_internal/block.hpp"
 #include "logger/logger.hpp"
+#include "main/block_phase_stats.hpp"
 #include "main/iroha_status.hpp"
 #include "main/subscription.hpp"
 
@@ -142,8 +143,11 @@ iroha::ametsuchi::CommitResult SynchronizerImpl::downloadAndCommitMissingBlocks(
                 maybe_block = std::get_if<
                     std::shared_ptr<const shared_model::interface::Block>>(
                     &block_var)) {
-        if (not(peer_ok =
-                    validator_->validateAndApply(*maybe_block, *storage))) {
+        {
+          BlockPhaseTimer timer{BlockPhase::kStateApplication};
+          peer_ok = validator_->validateAndApply(*maybe_block, *storage);
+        }
+        if (not peer_ok) {
           break;
         }
 
@@ -155,6 +159,7 @@ iroha::ametsuchi::CommitResult SynchronizerImpl::downloadAndCommitMissingBlocks(
         log_->warn("failed to retrieve block: {}", *error);
       }
       if (my_height >= end_height) {
+        BlockPhaseTimer timer{BlockPhase::kStorageCommit};
         return mutable_factory_->commit(std::move(storage));
       }
       if (not peer_ok) {
@@ -179,7 +184,10 @@ std::optional<iroha::synchronizer::SynchronizationEvent>
 SynchronizerImpl::processNext(const consensus::PairValid &msg) {
   log_->info("at handleNext");
   if (mutable_factory_->preparedCommitEnabled()) {
-    auto result = mutable_factory_->commitPrepared(msg.block);
+    auto result = [&] {
+      BlockPhaseTimer timer{BlockPhase::kStorageCommit};
+      return mutable_factory_->commitPrepared(msg.block);
+    }();
     if (iroha::expected::hasValue(result)) {
       return SynchronizationEvent{SynchronizationOutcomeType::kCommit,
                                   msg.round,
@@ -194,13 +202,20 @@ SynchronizerImpl::processNext(const consensus::PairValid &msg) {
     return std::nullopt;
   }
 
-  if (not maybe_storage.assumeValue()->apply(msg.block)) {
+  bool applied;
+  {
+    BlockPhaseTimer timer{BlockPhase::kStateApplication};
+    applied = maybe_storage.assumeValue()->apply(msg.block);
+  }
+  if (not applied) {
     log_->error("Block failed to apply.");
     return std::nullopt;
   }
 
-  auto maybe_result =
-      mutable_factory_->commit(std::move(maybe_storage.assumeValue()));
+  auto maybe_result = [&] {
+    BlockPhaseTimer timer{BlockPhase::kStorageCommit};
+    return mutable_factory_->commit(std::move(maybe_storage.assumeValue()));
+  }();
 
   if (expected::hasError(maybe_result)) {
     log_->error("Failed to commit: {}", maybe_result.assumeError());
@@ -0,0 +1,52 @@
+/**
+ * Copyright Soramitsu Co., Ltd. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+#ifndef IROHA_ACCOUNT_SCOPE_HPP
+#define IROHA_ACCOUNT_SCOPE_HPP
+
+#include <optional>
+
+#include "block.pb.h"
+#include "interfaces/common_objects/types.hpp"
+
+namespace shared_model::interface {
+  class Block;
+  class Command;
+}  // namespace shared_model::interface
+
+namespace iroha::torii {
+
+  /**
+   * @return true if the command changes the balances, the permissions, the
+   * signatories, the roles or the details of the account, or mentions the
+   * account in the key or the value of an account detail or in the
+   * description of a transfer
+   */
+  bool affectsAccount(
+      shared_model::interface::Command const &command,
+      shared_model::interface::types::AccountIdType const &account_id);
+
+  /**
+   * Leave in the block only the transactions affecting the account. The
+   * transactions created by the account are kept whole. Of the transactions
+   * created by other accounts only the creator, the created time and the
+   * commands affecting the account are kept, so the stream does not disclose
+   * the rest of foreign transactions. The rejected transactions hashes and
+   * the signatures are removed, as they can not be attributed to the account
+   * and do not match the filtered payload.
+   * @param block - block to filter
+   * @param transport - protobuf representation of the block
+   * @param account_id - account to filter the transactions by
+   * @return the filtered block, or nullopt if no transaction affects the
+   * account
+   */
+  std::optional<iroha::protocol::Block_v1> scopeToAccount(
+      shared_model::interface::Block const &block,
+      iroha::protocol::Block_v1 const &transport,
+      shared_model::interface::types::AccountIdType const &account_id);
+
+}  // namespace iroha::torii
+
+#endif  // IROHA_ACCOUNT_SCOPE_HPP
@@ -0,0 +1,141 @@
+/**
+ * Copyright Soramitsu Co., Ltd. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+#include "torii/account_scope.hpp"
+
+#include <cctype>
+#include <string_view>
+
+#include <boost/range/adaptor/indexed.hpp>
+#include "common/visitor.hpp"
+#include "interfaces/commands/add_signatory.hpp"
+#include "interfaces/commands/append_role.hpp"
+#include "interfaces/commands/authorize_session_key.hpp"
+#include "interfaces/commands/command_variant.hpp"
+#include "interfaces/commands/compare_and_set_account_detail.hpp"
+#include "interfaces/commands/deactivate_account.hpp"
+#include "interfaces/commands/detach_role.hpp"
+#include "interfaces/commands/grant_permission.hpp"
+#include "interfaces/commands/grant_vested_asset.hpp"
+#include "interfaces/commands/lock_asset.hpp"
+#include "interfaces/commands/recover_account.hpp"
+#include "interfaces/commands/remove_signatory.hpp"
+#include "interfaces/commands/revoke_permission.hpp"
+#include "interfaces/commands/rotate_key.hpp"
+#include "interfaces/commands/set_account_detail.hpp"
+#include "interfaces/commands/set_account_detail_at_path.hpp"
+#include "interfaces/commands/set_quorum.hpp"
+#include "interfaces/commands/set_spending_limit.hpp"
+#include "interfaces/commands/transfer_asset.hpp"
+#include "interfaces/iroha_internal/block.hpp"
+#include "interfaces/transaction.hpp"
+
+using shared_model::interface::types::AccountIdType;
+
+namespace {
+  bool isIdCharacter(char c) {
+    return std::isalnum(static_cast<unsigned char>(c)) or c == '_'
+        or c == '-' or c == '.' or c == '@';
+  }
+
+  /// @return true if the text contains the account id as a whole word
+  bool mentions(std::string_view text, AccountIdType const &account_id) {
+    for (auto pos = text.find(account_id); pos != std::string_view::npos;
+         pos = text.find(account_id, pos + 1)) {
+      auto const end = pos + account_id.size();
+      if ((pos == 0 or not isIdCharacter(text[pos - 1]))
+          and (end == text.size() or not isIdCharacter(text[end]))) {
+        return true;
+      }
+    }
+    return false;
+  }
+}  // namespace
+
+namespace iroha::torii {
+
+  bool affectsAccount(shared_model::interface::Command const &command,
+                      AccountIdType const &account_id) {
+    using namespace shared_model::interface;
+    auto is_account = [&](auto const &c) {
+      return c.accountId() == account_id;
+    };
+    auto is_detail_of = [&](auto const &c) {
+      return is_account(c) or mentions(c.key(), account_id)
+          or mentions(c.value(), account_id);
+    };
+    auto is_party = [&](auto const &c) {
+      return c.srcAccountId() == account_id
+          or c.destAccountId() == account_id;
+    };
+    return iroha::visit_in_place(
+        command.get(),
+        [&](TransferAsset const &c) {
+          return is_party(c) or mentions(c.description(), account_id);
+        },
+        [&](LockAsset const &c) { return is_party(c); },
+        [&](GrantVestedAsset const &c) {
+          return c.destAccountId() == account_id;
+        },
+        [&](AddSignatory const &c) { return is_account(c); },
+        [&](RemoveSignatory const &c) { return is_account(c); },
+        [&](RotateKey const &c) { return is_account(c); },
+        [&](AuthorizeSessionKey const &c) { return is_account(c); },
+        [&](SetQuorum const &c) { return is_account(c); },
+        [&](SetSpendingLimit const &c) { return is_account(c); },
+        [&](AppendRole const &c) { return is_account(c); },
+        [&](DetachRole const &c) { return is_account(c); },
+        [&](GrantPermission const &c) { return is_account(c); },
+        [&](RevokePermission const &c) { return is_account(c); },
+        [&](SetAccountDetail const &c) { return is_detail_of(c); },
+        [&](SetAccountDetailAtPath const &c) { return is_detail_of(c); },
+        [&](CompareAndSetAccountDetail const &c) { return is_detail_of(c); },
+        [&](DeactivateAccount const &c) { return is_account(c); },
+        [&](RecoverAccount const &c) { return is_account(c); },
+        [](auto const &) { return false; });
+  }
+
+  std::optional<iroha::protocol::Block_v1> scopeToAccount(
+      shared_model::interface::Block const &block,
+      iroha::protocol::Block_v1 const &transport,
+      AccountIdType const &account_id) {
+    iroha::protocol::Block_v1 scoped;
+    auto &payload = *scoped.mutable_payload();
+    payload.set_height(transport.payload().height());
+    payload.set_prev_block_hash(transport.payload().prev_block_hash());
+    payload.set_created_time(transport.payload().created_time());
+
+    for (auto const &tx : block.transactions() | boost::adaptors::indexed(0)) {
+      auto const &tx_transport =
+          transport.payload().transactions(static_cast<int>(tx.index()));
+      if (tx.value().creatorAccountId() == account_id) {
+        *payload.add_transactions() = tx_transport;
+        continue;
+      }
+
+      iroha::protocol::Transaction foreign;
+      auto &reduced = *foreign.mutable_payload()->mutable_reduced_payload();
+      auto const &tx_reduced = tx_transport.payload().reduced_payload();
+      for (auto const &command :
+           tx.value().commands() | boost::adaptors::indexed(0)) {
+        if (affectsAccount(command.value(), account_id)) {
+          *reduced.add_commands() =
+              tx_reduced.commands(static_cast<int>(command.index()));
+        }
+      }
+      if (reduced.commands_size() != 0) {
+        reduced.set_creator_account_id(tx_reduced.creator_account_id());
+        reduced.set_created_time(tx_reduced.created_time());
+        *payload.add_transactions() = std::move(foreign);
+      }
+    }
+    if (payload.transactions_size() == 0) {
+      return std::nullopt;
+    }
+    payload.set_tx_number(payload.transactions_size());
+    return scoped;
+  }
+
+}  // namespace iroha::torii
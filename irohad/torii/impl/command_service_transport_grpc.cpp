@@ -16,6 +16,7 @@
 #include "interfaces/iroha_internal/tx_status_factory.hpp"
 #include "interfaces/transaction.hpp"
 #include "logger/logger.hpp"
+#include "main/memory_budget.hpp"
 #include "main/subscription.hpp"
 #include "subscription/scheduler_impl.hpp"
 #include "torii/impl/final_status_value.hpp"
@@ -33,7 +34,8 @@ CommandServiceTransportGrpc::CommandServiceTransportGrpc(
     std::shared_ptr<shared_model::interface::TransactionBatchFactory>
         transaction_batch_factory,
     int maximum_rounds_without_update,
-    logger::LoggerPtr log)
+    logger::LoggerPtr log,
+    std::shared_ptr<iroha::MemoryBudget> memory_budget)
     : command_service_(std::move(command_service)),
       status_bus_(std::move(status_bus)),
       status_factory_(std::move(status_factory)),
@@ -41,6 +43,7 @@ CommandServiceTransportGrpc::CommandServiceTransportGrpc(
       batch_parser_(std::move(batch_parser)),
       batch_factory_(std::move(transaction_batch_factory)),
       log_(std::move(log)),
+      memory_budget_(std::move(memory_budget)),
       maximum_rounds_without_update_(maximum_rounds_without_update) {}
 
 grpc::Status CommandServiceTransportGrpc::Torii(
@@ -56,6 +59,16 @@ grpc::Status CommandServiceTransportGrpc::ListTorii(
     grpc::ServerContext *context,
     const iroha::protocol::TxList *request,
     google::protobuf::Empty *response) {
+  if (memory_budget_
+      and memory_budget_->exhausted(
+          iroha::MemoryBudget::Buffer::kTransactions)) {
+    log_->warn(
+        "Transactions memory limit is reached, rejecting {} transactions",
+        request->transactions_size());
+    return grpc::Status(grpc::StatusCode::RESOURCE_EXHAUSTED,
+                        "Transactions queue is full, retry later");
+  }
+
   auto publish_stateless_fail = [&](auto &&message) {
     using HashProvider = shared_model::crypto::Sha3_256;
 
@@ -212,6 +225,16 @@ grpc::Status CommandServiceTransportGrpc::StatusStream(
             }
           });
 
+  bool shutting_down = false;
+  auto shutdown_subscription =
+      SubscriberCreator<bool, std::chrono::system_clock::time_point>::
+          template create<EventTypes::kOnShutdown>(
+              static_cast<iroha::SubscriptionEngineHandlers>(*tid),
+              [&](auto, auto) {
+                shutting_down = true;
+                scheduler->dispose();
+              });
+
   if (not is_final_status(initial_response)) {
     scheduler->process();
   }
@@ -221,5 +244,9 @@ grpc::Status CommandServiceTransportGrpc::StatusStream(
   log_->debug("stream done, {}", client_id);
   log_->debug("status stream done, {}", client_id);
 
+  if (shutting_down) {
+    return grpc::Status(grpc::StatusCode::UNAVAILABLE,
+                        "Server is shutting down");
+  }
   return grpc::Status::OK;
 }
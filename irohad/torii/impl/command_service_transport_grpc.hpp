@@ -14,6 +14,10 @@
 #include "interfaces/iroha_internal/abstract_transport_factory.hpp"
 #include "logger/logger_fwd.hpp"
 
+namespace iroha {
+  class MemoryBudget;
+}  // namespace iroha
+
 namespace iroha::torii {
   class StatusBus;
 }
@@ -46,6 +50,8 @@ namespace iroha::torii {
      * @param maximum_rounds_without_update - defines how long tx status
      * stream is kept alive when no new tx statuses appear
      * @param log to print progress
+     * @param memory_budget - transactions are rejected with RESOURCE_EXHAUSTED
+     * while the memory limit of the transactions queue is reached
      */
     CommandServiceTransportGrpc(
         std::shared_ptr<CommandService> command_service,
@@ -58,7 +64,8 @@ namespace iroha::torii {
         std::shared_ptr<shared_model::interface::TransactionBatchFactory>
             transaction_batch_factory,
         int maximum_rounds_without_update,
-        logger::LoggerPtr log);
+        logger::LoggerPtr log,
+        std::shared_ptr<iroha::MemoryBudget> memory_budget = nullptr);
 
     /**
      * Torii call via grpc
@@ -119,6 +126,7 @@ namespace iroha::torii {
     std::shared_ptr<shared_model::interface::TransactionBatchFactory>
         batch_factory_;
     logger::LoggerPtr log_;
+    std::shared_ptr<iroha::MemoryBudget> memory_budget_;
 
     const int maximum_rounds_without_update_;
   };
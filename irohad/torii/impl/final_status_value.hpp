@@ -32,6 +32,7 @@ constexpr bool FinalStatusValue =
     iroha::is_any<std::decay_t<T>,
                   shared_model::interface::StatelessFailedTxResponse,
                   shared_model::interface::CommittedTxResponse,
-                  shared_model::interface::RejectedTxResponse>::value;
+                  shared_model::interface::RejectedTxResponse,
+                  shared_model::interface::ExpiredTxResponse>::value;
 
 #endif  // IROHA_FINAL_STATUS_VALUE_HPP
@@ -28,6 +28,21 @@ namespace torii_utils {
     return stub_->Find(&context, query, &response);
   }
 
+  grpc::Status QuerySyncClient::FindStream(
+      const iroha::protocol::Query &query,
+      std::function<bool(const QueryResponse &)> const &handler) const {
+    grpc::ClientContext context;
+    auto reader = stub_->FindStream(&context, query);
+    QueryResponse resp;
+    while (reader->Read(&resp)) {
+      if (not handler(resp)) {
+        context.TryCancel();
+        break;
+      }
+    }
+    return reader->Finish();
+  }
+
   std::vector<iroha::protocol::BlockQueryResponse>
   QuerySyncClient::FetchCommits(
       const iroha::protocol::BlocksQuery &blocks_query) const {
@@ -0,0 +1,155 @@
+/**
+ * Copyright Soramitsu Co., Ltd. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+#include "torii/query_cost.hpp"
+
+#include <fmt/core.h>
+#include "common/visitor.hpp"
+#include "interfaces/queries/account_detail_pagination_meta.hpp"
+#include "interfaces/queries/asset_pagination_meta.hpp"
+#include "interfaces/queries/estimate_fee.hpp"
+#include "interfaces/queries/get_account_asset_transactions.hpp"
+#include "interfaces/queries/get_account_assets.hpp"
+#include "interfaces/queries/get_account_detail.hpp"
+#include "interfaces/queries/get_account_permissions.hpp"
+#include "interfaces/queries/get_account_transactions.hpp"
+#include "interfaces/queries/get_asset_stats.hpp"
+#include "interfaces/queries/get_engine_receipts.hpp"
+#include "interfaces/queries/get_peers.hpp"
+#include "interfaces/queries/get_pending_transactions.hpp"
+#include "interfaces/queries/get_roles.hpp"
+#include "interfaces/queries/get_settings.hpp"
+#include "interfaces/queries/get_signatories.hpp"
+#include "interfaces/queries/get_transactions.hpp"
+#include "interfaces/queries/get_vesting_schedules.hpp"
+#include "interfaces/queries/query.hpp"
+#include "interfaces/queries/tx_pagination_meta.hpp"
+
+namespace {
+  /// budgets are kept per client for this period
+  constexpr std::chrono::minutes kBudgetWindow{1};
+
+  /// number of clients after which the outdated budgets are dropped
+  constexpr size_t kBudgetsCleanupSize = 1024;
+
+  using CostResult = iroha::expected::Result<uint64_t, std::string>;
+
+  template <typename Meta>
+  CostResult pageCost(
+      std::optional<std::reference_wrapper<const Meta>> const &meta,
+      std::string_view query_name) {
+    if (not meta) {
+      return iroha::expected::makeError(fmt::format(
+          "{} without pagination_meta may return any number of rows, set the "
+          "page size in its pagination_meta",
+          query_name));
+    }
+    return iroha::expected::makeValue(uint64_t{meta->get().pageSize()});
+  }
+
+  CostResult cost(uint64_t value) {
+    return iroha::expected::makeValue(value);
+  }
+}  // namespace
+
+namespace iroha::torii {
+
+  iroha::expected::Result<uint64_t, std::string> estimateQueryCost(
+      shared_model::interface::Query const &query,
+      uint64_t collection_query_cost) {
+    using namespace shared_model::interface;
+    return iroha::visit_in_place(
+        query.get(),
+        [](GetAccountTransactions const &q) {
+          return cost(q.paginationMeta().pageSize());
+        },
+        [](GetAccountAssetTransactions const &q) {
+          return cost(q.paginationMeta().pageSize());
+        },
+        [](GetPendingTransactions const &q) {
+          return pageCost(q.paginationMeta(), "GetPendingTransactions");
+        },
+        [](GetAccountAssets const &q) {
+          return pageCost(q.paginationMeta(), "GetAccountAssets");
+        },
+        [](GetAccountDetail const &q) {
+          if (q.key() and q.writer()) {
+            return cost(1);
+          }
+          return pageCost(q.paginationMeta(), "GetAccountDetail");
+        },
+        [](GetTransactions const &q) {
+          return cost(q.transactionHashes().size());
+        },
+        // these return collections without pagination
+        [&](GetSignatories const &) { return cost(collection_query_cost); },
+        [&](GetRoles const &) { return cost(collection_query_cost); },
+        [&](GetPeers const &) { return cost(collection_query_cost); },
+        [&](GetEngineReceipts const &) { return cost(collection_query_cost); },
+        [&](GetSettings const &) { return cost(collection_query_cost); },
+        [&](GetAssetStats const &) { return cost(collection_query_cost); },
+        [&](GetAccountPermissions const &) {
+          return cost(collection_query_cost);
+        },
+        [&](EstimateFee const &) { return cost(collection_query_cost); },
+        [&](GetVestingSchedules const &) {
+          return cost(collection_query_cost);
+        },
+        [](auto const &) { return cost(1); });
+  }
+
+  QueryCostLimiter::QueryCostLimiter(QueryCostLimits limits)
+      : limits_(std::move(limits)) {}
+
+  std::optional<std::string> QueryCostLimiter::charge(
+      std::string const &client,
+      shared_model::interface::Query const &query,
+      Clock::time_point now) {
+    if (not limits_.max_cost and not limits_.budget_per_minute) {
+      return std::nullopt;
+    }
+    auto estimate = estimateQueryCost(query, limits_.collection_query_cost);
+    if (auto e = iroha::expected::resultToOptionalError(estimate)) {
+      return "query too expensive: " + *e;
+    }
+    auto const cost = estimate.assumeValue();
+    if (limits_.max_cost and cost > *limits_.max_cost) {
+      return fmt::format(
+          "query too expensive: cost {} exceeds the limit {}, add filters or "
+          "paginate",
+          cost,
+          *limits_.max_cost);
+    }
+    if (not limits_.budget_per_minute) {
+      return std::nullopt;
+    }
+
+    std::lock_guard<std::mutex> lock(budgets_mutex_);
+    if (budgets_.size() >= kBudgetsCleanupSize) {
+      for (auto it = budgets_.begin(); it != budgets_.end();) {
+        if (now - it->second.window_start >= kBudgetWindow) {
+          it = budgets_.erase(it);
+        } else {
+          ++it;
+        }
+      }
+    }
+    auto &budget = budgets_.try_emplace(client, Budget{now, 0}).first->second;
+    if (now - budget.window_start >= kBudgetWindow) {
+      budget = Budget{now, 0};
+    }
+    if (budget.spent + cost > *limits_.budget_per_minute) {
+      return fmt::format(
+          "query too expensive: cost {} exceeds the remaining budget {} of "
+          "{} per minute, add filters or paginate",
+          cost,
+          *limits_.budget_per_minute - budget.spent,
+          *limits_.budget_per_minute);
+    }
+    budget.spent += cost;
+    return std::nullopt;
+  }
+
+}  // namespace iroha::torii
@@ -5,6 +5,7 @@
 
 #include "torii/query_service.hpp"
 
+#include <google/protobuf/descriptor.h>
 #include "backend/protobuf/block.hpp"
 #include "backend/protobuf/query_responses/proto_block_query_response.hpp"
 #include "backend/protobuf/query_responses/proto_query_response.hpp"
@@ -12,12 +13,94 @@
 #include "cryptography/default_hash_provider.hpp"
 #include "interfaces/iroha_internal/abstract_transport_factory.hpp"
 #include "logger/logger.hpp"
+#include "main/query_stats.hpp"
 #include "main/subscription.hpp"
 #include "subscription/scheduler_impl.hpp"
+#include "torii/account_scope.hpp"
 #include "validators/default_validator.hpp"
 
 using iroha::torii::QueryService;
 
+namespace {
+  /**
+   * Make the response with the block, or with only the transactions affecting
+   * the creator of the query if it is scoped to the account
+   * @return the response, or nullopt if the block is skipped for the query
+   */
+  std::optional<iroha::protocol::BlockQueryResponse> makeBlockResponse(
+      shared_model::interface::Block const &block,
+      shared_model::interface::BlocksQuery const &query) {
+    auto const &transport =
+        static_cast<shared_model::proto::Block const &>(block).getTransport();
+    iroha::protocol::BlockQueryResponse response;
+    auto &block_v1 =
+        *response.mutable_block_response()->mutable_block()->mutable_block_v1();
+    if (not query.accountScoped()) {
+      block_v1 = transport;
+      return response;
+    }
+    auto scoped = iroha::torii::scopeToAccount(
+        block, transport, query.creatorAccountId());
+    if (not scoped) {
+      return std::nullopt;
+    }
+    block_v1 = std::move(*scoped);
+    return response;
+  }
+
+  /**
+   * Point the pagination meta of the query to the page following the response
+   * @return false if there are no more pages or the query is not paginated
+   */
+  bool nextPage(iroha::protocol::Query &query,
+                iroha::protocol::QueryResponse const &response) {
+    auto &payload = *query.mutable_payload();
+    switch (payload.query_case()) {
+      case iroha::protocol::Query::Payload::kGetAccountTransactions:
+        if (response.transactions_page_response().next_page_tag_case()
+            != iroha::protocol::TransactionsPageResponse::kNextTxHash) {
+          return false;
+        }
+        payload.mutable_get_account_transactions()
+            ->mutable_pagination_meta()
+            ->set_first_tx_hash(
+                response.transactions_page_response().next_tx_hash());
+        return true;
+      case iroha::protocol::Query::Payload::kGetAccountAssetTransactions:
+        if (response.transactions_page_response().next_page_tag_case()
+            != iroha::protocol::TransactionsPageResponse::kNextTxHash) {
+          return false;
+        }
+        payload.mutable_get_account_asset_transactions()
+            ->mutable_pagination_meta()
+            ->set_first_tx_hash(
+                response.transactions_page_response().next_tx_hash());
+        return true;
+      case iroha::protocol::Query::Payload::kGetAccountAssets:
+        if (response.account_assets_response().opt_next_asset_id_case()
+            != iroha::protocol::AccountAssetResponse::kNextAssetId) {
+          return false;
+        }
+        payload.mutable_get_account_assets()
+            ->mutable_pagination_meta()
+            ->set_first_asset_id(
+                response.account_assets_response().next_asset_id());
+        return true;
+      case iroha::protocol::Query::Payload::kGetAccountDetail:
+        if (not response.account_detail_response().has_next_record_id()) {
+          return false;
+        }
+        *payload.mutable_get_account_detail()
+             ->mutable_pagination_meta()
+             ->mutable_first_record_id() =
+            response.account_detail_response().next_record_id();
+        return true;
+      default:
+        return false;
+    }
+  }
+}  // namespace
+
 QueryService::QueryService(
     std::shared_ptr<iroha::torii::QueryProcessor> query_processor,
     std::shared_ptr<QueryFactoryType> query_factory,
@@ -25,15 +108,57 @@ QueryService::QueryService(
     logger::LoggerPtr log,
     std::shared_ptr<iroha::BaseSubscriber<
         iroha::utils::ReadWriteObject<iroha::IrohaStoredStatus, std::mutex>,
-        iroha::IrohaStatus>> iroha_status_subscription)
+        iroha::IrohaStatus>> iroha_status_subscription,
+    std::optional<std::chrono::milliseconds> slow_query_threshold,
+    QueryCostLimits cost_limits,
+    std::shared_ptr<iroha::ametsuchi::BlockQueryFactory> block_query_factory,
+    BlockStreamLimits block_stream_limits,
+    QueryTimeouts query_timeouts)
     : query_processor_{std::move(query_processor)},
       query_factory_{std::move(query_factory)},
       blocks_query_factory_{std::move(blocks_query_factory)},
       log_{std::move(log)},
-      iroha_status_subscription_(std::move(iroha_status_subscription)) {}
+      iroha_status_subscription_(std::move(iroha_status_subscription)),
+      slow_query_threshold_(std::move(slow_query_threshold)),
+      cost_limiter_(std::move(cost_limits)),
+      block_query_factory_(std::move(block_query_factory)),
+      block_stream_limits_(std::move(block_stream_limits)),
+      query_timeouts_(std::move(query_timeouts)) {}
 
 void QueryService::Find(iroha::protocol::Query const &request,
-                        iroha::protocol::QueryResponse &response) {
+                        iroha::protocol::QueryResponse &response,
+                        std::optional<std::chrono::milliseconds> timeout) {
+  auto const start = std::chrono::steady_clock::now();
+  processQuery(request, response, timeout);
+  auto const duration = std::chrono::duration_cast<std::chrono::microseconds>(
+      std::chrono::steady_clock::now() - start);
+
+  auto const field =
+      iroha::protocol::Query::Payload::descriptor()->FindFieldByNumber(
+          request.payload().query_case());
+  QueryStats stats{field ? field->name() : "unknown",
+                   duration,
+                   response.ByteSizeLong(),
+                   response.has_error_response()};
+
+  if (slow_query_threshold_ and duration >= *slow_query_threshold_) {
+    log_->warn(
+        "Slow query {} from {}: {} us, response {} bytes, predicate {}",
+        stats.type,
+        request.payload().meta().creator_account_id(),
+        stats.duration.count(),
+        stats.response_size,
+        request.payload().ShortDebugString());
+  }
+
+  iroha::getSubscription()->notify(EventTypes::kOnQueryExecuted,
+                                   std::move(stats));
+}
+
+void QueryService::processQuery(
+    iroha::protocol::Query const &request,
+    iroha::protocol::QueryResponse &response,
+    std::optional<std::chrono::milliseconds> timeout) {
   shared_model::crypto::Hash hash;
   auto blobPayload = shared_model::proto::makeBlob(request.payload());
   hash = shared_model::crypto::DefaultHashProvider::makeHash(blobPayload);
@@ -46,18 +171,12 @@ void QueryService::Find(iroha::protocol::Query const &request,
   }
 
   query_factory_->build(request).match(
-      [this, &hash, &response](const auto &query) {
-        query_processor_->queryHandle(*query.value) |
-            [&](auto &&iface_response) {
-              // Send query to iroha
-              response = static_cast<shared_model::proto::QueryResponse &>(
-                             *iface_response)
-                             .getTransport();
-              // TODO 18.02.2019 lebdron: IR-336 Replace cache
-              // 0 is used as a dummy value
-              cache_.addItem(hash, 0);
-              return iroha::expected::Value<void>{};
-            };
+      [this, &hash, &response, timeout](const auto &query) {
+        if (executeQuery(*query.value, hash, response, timeout)) {
+          // TODO 18.02.2019 lebdron: IR-336 Replace cache
+          // 0 is used as a dummy value
+          cache_.addItem(hash, 0);
+        }
       },
       [&hash, &response](auto &&error) {
         response.set_query_hash(hash.hex());
@@ -68,13 +187,113 @@ void QueryService::Find(iroha::protocol::Query const &request,
       });
 }
 
+bool QueryService::executeQuery(
+    shared_model::interface::Query const &query,
+    shared_model::crypto::Hash const &hash,
+    iroha::protocol::QueryResponse &response,
+    std::optional<std::chrono::milliseconds> timeout) {
+  if (auto error = cost_limiter_.charge(
+          query.creatorAccountId(), query, QueryCostLimiter::Clock::now())) {
+    response.set_query_hash(hash.hex());
+    response.mutable_error_response()->set_reason(
+        iroha::protocol::ErrorResponse::TOO_EXPENSIVE);
+    response.mutable_error_response()->set_message(std::move(*error));
+    return false;
+  }
+  bool executed = false;
+  query_processor_->queryHandle(query, timeout) | [&](auto &&iface_response) {
+    // Send query to iroha
+    response =
+        static_cast<shared_model::proto::QueryResponse &>(*iface_response)
+            .getTransport();
+    executed = true;
+    return iroha::expected::Value<void>{};
+  };
+  return executed;
+}
+
+grpc::Status QueryService::FindStream(
+    grpc::ServerContext *context,
+    const iroha::protocol::Query *request,
+    grpc::ServerWriter<iroha::protocol::QueryResponse> *writer) {
+  iroha::protocol::QueryResponse response;
+  Find(*request, response, queryTimeout(context));
+
+  // the following pages are not validated statelessly again, because the
+  // signature of the first one covers the whole scan
+  iroha::protocol::Query page = *request;
+  while (writer->Write(response) and not context->IsCancelled()
+         and nextPage(page, response)) {
+    response.Clear();
+    shared_model::proto::Query query{page};
+    executeQuery(query, query.hash(), response, queryTimeout(context));
+  }
+  return grpc::Status::OK;
+}
+
 grpc::Status QueryService::Find(grpc::ServerContext *context,
                                 const iroha::protocol::Query *request,
                                 iroha::protocol::QueryResponse *response) {
-  Find(*request, *response);
+  Find(*request, *response, queryTimeout(context));
   return grpc::Status::OK;
 }
 
+std::optional<std::chrono::milliseconds> QueryService::queryTimeout(
+    grpc::ServerContext const *context) const {
+  if (not context
+      or context->deadline() == std::chrono::system_clock::time_point::max()) {
+    return query_timeouts_.default_timeout;
+  }
+  // a query past its deadline still gets a minimal limit, since zero disables
+  // the limit in the storage
+  auto const requested = std::max(
+      std::chrono::duration_cast<std::chrono::milliseconds>(
+          context->deadline() - std::chrono::system_clock::now()),
+      std::chrono::milliseconds{1});
+  auto const &limit = query_timeouts_.max_timeout
+      ? query_timeouts_.max_timeout
+      : query_timeouts_.default_timeout;
+  return limit ? std::min(requested, *limit) : requested;
+}
+
+std::optional<std::string> QueryService::replayBlocks(
+    shared_model::interface::BlocksQuery const &query,
+    grpc::ServerWriter<::iroha::protocol::BlockQueryResponse> &writer,
+    shared_model::interface::types::HeightType &last_height) {
+  if (not block_query_factory_) {
+    return std::string{"Replay of stored blocks is not supported"};
+  }
+  auto block_query = block_query_factory_->createBlockQuery();
+  if (not block_query) {
+    return std::string{"Failed to create block query"};
+  }
+
+  // the stream must not skip blocks silently
+  if (auto first_retained = (*block_query)->getFirstRetainedHeight();
+      first_retained and query.fromHeight() < *first_retained) {
+    return fmt::format(
+        "Blocks from height 2 to {} are pruned, replay from height {} or "
+        "from an archive peer",
+        *first_retained - 1,
+        *first_retained);
+  }
+
+  auto const top_height = (*block_query)->getTopBlockHeight();
+  for (auto height = query.fromHeight(); height <= top_height; ++height) {
+    auto block = (*block_query)->getBlock(height);
+    if (auto e = iroha::expected::resultToOptionalError(block)) {
+      return fmt::format("Failed to get block {}: {}", height, e->message);
+    }
+
+    auto response = makeBlockResponse(*block.assumeValue(), query);
+    if (response and not writer.Write(*response)) {
+      return std::string{"Write to stream has failed"};
+    }
+    last_height = height;
+  }
+  return std::nullopt;
+}
+
 grpc::Status QueryService::Healthcheck(
     grpc::ServerContext *context,
     const google::protobuf::Empty *request,
@@ -86,6 +305,13 @@ grpc::Status QueryService::Healthcheck(
             response->set_is_syncing(*status.status.is_syncing);
           if (status.status.is_healthy)
             response->set_is_healthy(*status.status.is_healthy);
+          if (status.status.is_reachable)
+            response->set_is_reachable(*status.status.is_reachable);
+          if (status.status.chain_id)
+            response->set_chain_id(*status.status.chain_id);
+          if (status.status.first_retained_height)
+            response->set_first_retained_height(
+                *status.status.first_retained_height);
           if (status.status.memory_consumption)
             response->set_memory_consumption(*status.status.memory_consumption);
           if (status.status.last_round) {
@@ -125,11 +351,16 @@ grpc::Status QueryService::FetchCommits(
     return grpc::Status::OK;
   }
 
+  auto const &query = *maybe_query.assumeValue();
   std::string client_id = fmt::format("Peer: '{}'", context->peer());
 
   auto scheduler = std::make_shared<iroha::subscription::SchedulerBase>();
   auto tid = iroha::getSubscription()->dispatcher()->bind(scheduler);
 
+  // the blocks are subscribed to before the replay, so that the blocks
+  // committed during it are streamed afterwards, skipping the replayed ones
+  shared_model::interface::types::HeightType replayed_height = 0;
+
   auto batches_subscription =
       SubscriberCreator<bool,
                         std::shared_ptr<shared_model::interface::Block const>>::
@@ -142,19 +373,46 @@ grpc::Status QueryService::FetchCommits(
                   return;
                 }
 
+                if (block->height() <= replayed_height) {
+                  return;
+                }
+
+                auto response = makeBlockResponse(*block, query);
+                if (not response) {
+                  return;
+                }
+
+                // the block being written counts towards the limit
+                if (block_stream_limits_.max_pending
+                    and scheduler->pendingTasks() + 1
+                        > *block_stream_limits_.max_pending) {
+                  iroha::getSubscription()->notify(
+                      EventTypes::kOnBlockStreamOverflow,
+                      block_stream_limits_.drop_on_overflow);
+                  if (block_stream_limits_.drop_on_overflow) {
+                    log_->warn("{} is too slow, dropped block {}",
+                               client_id,
+                               block->height());
+                    return;
+                  }
+                  log_->warn("{} is too slow, closing the stream at block {}",
+                             client_id,
+                             block->height());
+                  iroha::protocol::BlockQueryResponse error;
+                  error.mutable_block_error_response()->set_message(
+                      fmt::format("Subscriber is too slow, resume with "
+                                  "from_height {}",
+                                  block->height()));
+                  writer->WriteLast(error, grpc::WriteOptions());
+                  scheduler->dispose();
+                  return;
+                }
+
                 log_->debug("{} receives {}",
                             request->meta().creator_account_id(),
                             *block);
 
-                iroha::protocol::BlockQueryResponse response;
-                *response.mutable_block_response()
-                     ->mutable_block()
-                     ->mutable_block_v1() =
-                    std::static_pointer_cast<shared_model::proto::Block const>(
-                        block)
-                        ->getTransport();
-
-                if (not writer->Write(response)) {
+                if (not writer->Write(*response)) {
                   log_->error("write to stream has failed to client {}",
                               client_id);
                   scheduler->dispose();
@@ -162,6 +420,34 @@ grpc::Status QueryService::FetchCommits(
                 }
               });
 
+  // the subscriber is told to resume from another peer
+  auto shutdown_subscription =
+      SubscriberCreator<bool, std::chrono::system_clock::time_point>::
+          template create<EventTypes::kOnShutdown>(
+              static_cast<iroha::SubscriptionEngineHandlers>(*tid),
+              [&](auto, auto) {
+                log_->debug("Closing block stream of {} on shutdown",
+                            client_id);
+                iroha::protocol::BlockQueryResponse response;
+                response.mutable_block_error_response()->set_message(
+                    "Server is shutting down");
+                writer->WriteLast(response, grpc::WriteOptions());
+                scheduler->dispose();
+              });
+
+  if (query.fromHeight() != 0) {
+    if (auto error = replayBlocks(query, *writer, replayed_height)) {
+      log_->debug("Replay to {} failed: {}", client_id, *error);
+      batches_subscription->unsubscribe();
+      shutdown_subscription->unsubscribe();
+      getSubscription()->dispatcher()->unbind(*tid);
+      iroha::protocol::BlockQueryResponse response;
+      response.mutable_block_error_response()->set_message(std::move(*error));
+      writer->WriteLast(response, grpc::WriteOptions());
+      return grpc::Status::OK;
+    }
+  }
+
   scheduler->process();
 
   getSubscription()->dispatcher()->unbind(*tid);
@@ -33,10 +33,15 @@ namespace iroha {
     iroha::expected::Result<
         std::unique_ptr<shared_model::interface::QueryResponse>,
         std::string>
-    QueryProcessorImpl::queryHandle(const shared_model::interface::Query &qry) {
+    QueryProcessorImpl::queryHandle(
+        const shared_model::interface::Query &qry,
+        std::optional<std::chrono::milliseconds> timeout) {
       return qry_exec_->createQueryExecutor(pending_transactions_,
                                             response_factory_)
           | [&](auto &&executor) {
+              if (timeout) {
+                executor->setTimeout(*timeout);
+              }
               return executor->validateAndExecute(qry, true);
             };
     }
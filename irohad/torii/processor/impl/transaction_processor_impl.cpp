@@ -9,6 +9,7 @@
 #include "interfaces/iroha_internal/proposal.hpp"
 #include "interfaces/iroha_internal/transaction_batch.hpp"
 #include "interfaces/iroha_internal/transaction_sequence.hpp"
+#include "logger/log_fields.hpp"
 #include "logger/logger.hpp"
 #include "simulator/verified_proposal_creator_common.hpp"
 #include "validation/stateful_validator_common.hpp"
@@ -74,6 +75,7 @@ namespace iroha {
       // notify about failed txs
       const auto &errors = proposal_and_errors->rejected_transactions;
       for (const auto &tx_error : errors) {
+        logger::LogFieldGuard tx_hash_field{"tx_hash", tx_error.tx_hash.hex()};
         log_->info("{}", composeErrorMessage(tx_error));
         publishStatus(
             TxStatusType::kStatefulFailed, tx_error.tx_hash, tx_error.error);
@@ -81,6 +83,8 @@ namespace iroha {
       // notify about success txs
       for (const auto &successful_tx :
            proposal_and_errors->verified_proposal->transactions()) {
+        logger::LogFieldGuard tx_hash_field{"tx_hash",
+                                            successful_tx.hash().hex()};
         log_->info("VerifiedProposalCreatorEvent StatefulValid: {}",
                    successful_tx.hash().hex());
         publishStatus(TxStatusType::kStatefulValid, successful_tx.hash());
@@ -89,13 +93,17 @@ namespace iroha {
 
     void TransactionProcessorImpl::processCommit(
         std::shared_ptr<const shared_model::interface::Block> const &block) {
+      logger::LogFieldGuard height_field{"height",
+                                         std::to_string(block->height())};
       for (const auto &tx : block->transactions()) {
         const auto &hash = tx.hash();
+        logger::LogFieldGuard tx_hash_field{"tx_hash", hash.hex()};
         log_->debug("Committed transaction: {}", hash.hex());
         publishStatus(TxStatusType::kCommitted, hash);
       }
       for (const auto &rejected_tx_hash :
            block->rejected_transactions_hashes()) {
+        logger::LogFieldGuard tx_hash_field{"tx_hash", rejected_tx_hash.hex()};
         log_->debug("Rejected transaction: {}", rejected_tx_hash.hex());
         publishStatus(TxStatusType::kRejected, rejected_tx_hash);
       }
@@ -129,6 +137,18 @@ namespace iroha {
       }
     }
 
+    void TransactionProcessorImpl::processOutdatedBatch(
+        std::shared_ptr<shared_model::interface::TransactionBatch> const
+            &batch,
+        std::string const &reason) {
+      log_->info("Batch {} is expired: {}", batch->reducedHash(), reason);
+      for (auto &&tx : batch->transactions()) {
+        publishStatus(TxStatusType::kExpired,
+                      tx->hash(),
+                      validation::CommandError{reason, 0, "", false});
+      }
+    }
+
     void TransactionProcessorImpl::publishStatus(
         TxStatusType tx_status,
         const shared_model::crypto::Hash &hash,
@@ -170,6 +190,10 @@ namespace iroha {
           status_bus_->publish(status_factory_->makeMstExpired(hash, tx_error));
           return;
         };
+        case TxStatusType::kExpired: {
+          status_bus_->publish(status_factory_->makeExpired(hash, tx_error));
+          return;
+        };
         case TxStatusType::kNotReceived: {
           status_bus_->publish(
               status_factory_->makeNotReceived(hash, tx_error));
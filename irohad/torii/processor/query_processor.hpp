@@ -6,7 +6,9 @@
 #ifndef IROHA_QUERY_PROCESSOR_HPP
 #define IROHA_QUERY_PROCESSOR_HPP
 
+#include <chrono>
 #include <memory>
+#include <optional>
 #include <string>
 
 #include "common/result_fwd.hpp"
@@ -30,12 +32,15 @@ namespace iroha {
       /**
        * Perform client query
        * @param qry - client intent
+       * @param timeout - execution time limit of the query, unlimited if unset
        * @return resulted response
        */
       virtual iroha::expected::Result<
           std::unique_ptr<shared_model::interface::QueryResponse>,
           std::string>
-      queryHandle(const shared_model::interface::Query &qry) = 0;
+      queryHandle(const shared_model::interface::Query &qry,
+                  std::optional<std::chrono::milliseconds> timeout =
+                      std::nullopt) = 0;
 
       /**
        * Register client blocks query
@@ -32,7 +32,9 @@ namespace iroha {
       iroha::expected::Result<
           std::unique_ptr<shared_model::interface::QueryResponse>,
           std::string>
-      queryHandle(const shared_model::interface::Query &qry) override;
+      queryHandle(const shared_model::interface::Query &qry,
+                  std::optional<std::chrono::milliseconds> timeout =
+                      std::nullopt) override;
 
       iroha::expected::Result<void, std::string> blocksQueryHandle(
           const shared_model::interface::BlocksQuery &qry) override;
@@ -7,6 +7,7 @@
 #define IROHA_TRANSACTION_PROCESSOR_HPP
 
 #include <memory>
+#include <string>
 
 #include "interfaces/common_objects/transaction_sequence_common.hpp"
 
@@ -56,6 +57,17 @@ namespace iroha {
           std::shared_ptr<shared_model::interface::TransactionBatch> const
               &batch) = 0;
 
+      /**
+       * Notify about transactions dropped from the queue, because they had
+       * expired before getting into a proposal
+       * @param batch - dropped batch
+       * @param reason - description of the expiry
+       */
+      virtual void processOutdatedBatch(
+          std::shared_ptr<shared_model::interface::TransactionBatch> const
+              &batch,
+          std::string const &reason) = 0;
+
       virtual ~TransactionProcessor() = default;
     };
   }  // namespace torii
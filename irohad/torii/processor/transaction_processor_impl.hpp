@@ -54,6 +54,10 @@ namespace iroha {
       void processExpiredBatch(
           std::shared_ptr<shared_model::interface::TransactionBatch> const
               &batch) override;
+      void processOutdatedBatch(
+          std::shared_ptr<shared_model::interface::TransactionBatch> const
+              &batch,
+          std::string const &reason) override;
 
      private:
       // connections
@@ -81,6 +85,7 @@ namespace iroha {
         kRejected,
         kCommitted,
         kMstExpired,
+        kExpired,
         kNotReceived,
         kMstPending,
         kEnoughSignaturesCollected
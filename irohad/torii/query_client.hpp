@@ -8,6 +8,7 @@
 
 #include <endpoint.grpc.pb.h>
 #include <endpoint.pb.h>
+#include <functional>
 #include <memory>
 #include <thread>
 
@@ -31,6 +32,19 @@ namespace torii_utils {
     grpc::Status Find(const iroha::protocol::Query &query,
                       iroha::protocol::QueryResponse &response) const;
 
+    /**
+     * requests paginated query to a torii server and passes the pages to the
+     * handler as soon as they arrive (blocking, sync)
+     * @param query - the query, its page size sets the size of the pages
+     * @param handler - called for every page, stops the stream if returns
+     * false
+     * @return grpc::Status
+     */
+    grpc::Status FindStream(
+        const iroha::protocol::Query &query,
+        std::function<bool(const iroha::protocol::QueryResponse &)> const
+            &handler) const;
+
     std::vector<iroha::protocol::BlockQueryResponse> FetchCommits(
         const iroha::protocol::BlocksQuery &blocks_query) const;
 
@@ -0,0 +1,84 @@
+/**
+ * Copyright Soramitsu Co., Ltd. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+#ifndef IROHA_QUERY_COST_HPP
+#define IROHA_QUERY_COST_HPP
+
+#include <chrono>
+#include <cstdint>
+#include <mutex>
+#include <optional>
+#include <string>
+#include <unordered_map>
+
+#include "common/result.hpp"
+
+namespace shared_model::interface {
+  class Query;
+}
+
+namespace iroha::torii {
+
+  /// default cost of the queries returning collections of any size
+  constexpr uint64_t kDefaultCollectionQueryCost = 100;
+
+  /**
+   * Estimates the cost of the query as the maximal number of rows it examines.
+   * The estimate depends only on the query, so it is the same on all peers.
+   * @param query to estimate
+   * @param collection_query_cost - cost of the queries returning collections
+   * which can not be paginated, such as roles, peers or permissions
+   * @return the cost, or the reason if the number of rows is not bounded
+   */
+  iroha::expected::Result<uint64_t, std::string> estimateQueryCost(
+      shared_model::interface::Query const &query,
+      uint64_t collection_query_cost = kDefaultCollectionQueryCost);
+
+  struct QueryCostLimits {
+    /// maximal cost of a single query
+    std::optional<uint64_t> max_cost;
+    /// maximal total cost of the queries of a client per minute
+    std::optional<uint64_t> budget_per_minute;
+    /// cost of the queries returning collections which can not be paginated
+    uint64_t collection_query_cost = kDefaultCollectionQueryCost;
+  };
+
+  /**
+   * Checks queries against the cost limits and keeps track of the budgets
+   * spent by the clients
+   */
+  class QueryCostLimiter {
+   public:
+    using Clock = std::chrono::steady_clock;
+
+    explicit QueryCostLimiter(QueryCostLimits limits);
+
+    /**
+     * Charge the client for the query
+     * @param client - creator of the query
+     * @param query - query to estimate the cost of
+     * @param now - current time
+     * @return the reason if the query exceeds the limits, the budget of the
+     * client is not charged then
+     */
+    std::optional<std::string> charge(
+        std::string const &client,
+        shared_model::interface::Query const &query,
+        Clock::time_point now);
+
+   private:
+    struct Budget {
+      Clock::time_point window_start;
+      uint64_t spent;
+    };
+
+    QueryCostLimits limits_;
+    std::mutex budgets_mutex_;
+    std::unordered_map<std::string, Budget> budgets_;
+  };
+
+}  // namespace iroha::torii
+
+#endif  // IROHA_QUERY_COST_HPP
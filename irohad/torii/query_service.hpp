@@ -6,6 +6,8 @@
 #ifndef TORII_QUERY_SERVICE_HPP
 #define TORII_QUERY_SERVICE_HPP
 
+#include <chrono>
+#include <optional>
 #include <unordered_map>
 #include "endpoint.grpc.pb.h"
 #include "endpoint.pb.h"
@@ -14,11 +16,13 @@
 #include "backend/protobuf/queries/proto_blocks_query.hpp"
 #include "backend/protobuf/queries/proto_query.hpp"
 #include "builders/protobuf/transport_builder.hpp"
+#include "ametsuchi/block_query_factory.hpp"
 #include "cache/cache.hpp"
 #include "logger/logger_fwd.hpp"
 #include "main/iroha_status.hpp"
 #include "main/subscription.hpp"
 #include "torii/processor/query_processor.hpp"
+#include "torii/query_cost.hpp"
 
 namespace shared_model::interface {
   template <typename Interface, typename Transport>
@@ -26,6 +30,28 @@ namespace shared_model::interface {
 }  // namespace shared_model::interface
 
 namespace iroha::torii {
+  /**
+   * Limits of the blocks buffered for a slow FetchCommits subscriber
+   */
+  struct BlockStreamLimits {
+    /// maximal number of blocks waiting to be written to a subscriber,
+    /// unlimited if unset
+    std::optional<size_t> max_pending;
+    /// skip the overflowing blocks instead of closing the stream
+    bool drop_on_overflow = false;
+  };
+
+  /**
+   * Execution time limits of queries
+   */
+  struct QueryTimeouts {
+    /// limit of queries without a deadline of the call, unlimited if unset
+    std::optional<std::chrono::milliseconds> default_timeout;
+    /// maximal limit the deadline of the call may set, the default limit is
+    /// the maximal one if unset
+    std::optional<std::chrono::milliseconds> max_timeout;
+  };
+
   /**
    * Actual implementation of async QueryService.
    * ToriiServiceHandler::(SomeMethod)Handler calls a corresponding method in
@@ -48,7 +74,14 @@ namespace iroha::torii {
         logger::LoggerPtr log,
         std::shared_ptr<iroha::BaseSubscriber<
             iroha::utils::ReadWriteObject<iroha::IrohaStoredStatus, std::mutex>,
-            iroha::IrohaStatus>> iroha_status_subscription);
+            iroha::IrohaStatus>> iroha_status_subscription,
+        std::optional<std::chrono::milliseconds> slow_query_threshold =
+            std::nullopt,
+        QueryCostLimits cost_limits = {},
+        std::shared_ptr<iroha::ametsuchi::BlockQueryFactory>
+            block_query_factory = nullptr,
+        BlockStreamLimits block_stream_limits = {},
+        QueryTimeouts query_timeouts = {});
 
     QueryService(const QueryService &) = delete;
     QueryService &operator=(const QueryService &) = delete;
@@ -57,14 +90,27 @@ namespace iroha::torii {
      * actual implementation of async Find in QueryService
      * @param request - Query
      * @param response - QueryResponse
+     * @param timeout - execution time limit of the query, unlimited if unset
      */
     void Find(iroha::protocol::Query const &request,
-              iroha::protocol::QueryResponse &response);
+              iroha::protocol::QueryResponse &response,
+              std::optional<std::chrono::milliseconds> timeout = std::nullopt);
 
     grpc::Status Find(grpc::ServerContext *context,
                       const iroha::protocol::Query *request,
                       iroha::protocol::QueryResponse *response) override;
 
+    /**
+     * Execute the paginated query page by page, writing every page to the
+     * stream as soon as it is ready, so that only one page is held in memory.
+     * The page size of the query sets the size of the chunks. Other queries
+     * are answered with a single response.
+     */
+    grpc::Status FindStream(
+        grpc::ServerContext *context,
+        const iroha::protocol::Query *request,
+        grpc::ServerWriter<iroha::protocol::QueryResponse> *writer) override;
+
     grpc::Status FetchCommits(
         grpc::ServerContext *context,
         const iroha::protocol::BlocksQuery *request,
@@ -77,6 +123,41 @@ namespace iroha::torii {
         iroha::protocol::HealthcheckData *response) override;
 
    private:
+    void processQuery(iroha::protocol::Query const &request,
+                      iroha::protocol::QueryResponse &response,
+                      std::optional<std::chrono::milliseconds> timeout);
+
+    /**
+     * Charge the cost of the query and execute it
+     * @return true if the query is executed, false if it is too expensive or
+     * failed
+     */
+    bool executeQuery(shared_model::interface::Query const &query,
+                      shared_model::crypto::Hash const &hash,
+                      iroha::protocol::QueryResponse &response,
+                      std::optional<std::chrono::milliseconds> timeout);
+
+    /**
+     * Execution time limit of a query of the call: the time left until the
+     * deadline of the call, limited by the maximal timeout, or the default
+     * timeout if the call has no deadline
+     */
+    std::optional<std::chrono::milliseconds> queryTimeout(
+        grpc::ServerContext const *context) const;
+
+    /**
+     * Write the stored blocks starting from the height requested by the query
+     * to the stream
+     * @param query - query requesting the blocks
+     * @param writer - the stream
+     * @param last_height - set to the height of the last written block
+     * @return error message if the blocks can not be replayed
+     */
+    std::optional<std::string> replayBlocks(
+        shared_model::interface::BlocksQuery const &query,
+        grpc::ServerWriter<::iroha::protocol::BlockQueryResponse> &writer,
+        shared_model::interface::types::HeightType &last_height);
+
     std::shared_ptr<iroha::torii::QueryProcessor> query_processor_;
     std::shared_ptr<QueryFactoryType> query_factory_;
     std::shared_ptr<BlocksQueryFactoryType> blocks_query_factory_;
@@ -92,6 +173,12 @@ namespace iroha::torii {
         iroha::utils::ReadWriteObject<iroha::IrohaStoredStatus, std::mutex>,
         iroha::IrohaStatus>>
         iroha_status_subscription_;
+    std::optional<std::chrono::milliseconds> slow_query_threshold_;
+    QueryCostLimiter cost_limiter_;
+    /// source of the stored blocks replayed by FetchCommits
+    std::shared_ptr<iroha::ametsuchi::BlockQueryFactory> block_query_factory_;
+    BlockStreamLimits block_stream_limits_;
+    QueryTimeouts query_timeouts_;
   };
 }  // namespace iroha::torii
 
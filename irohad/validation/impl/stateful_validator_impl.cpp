@@ -23,13 +23,30 @@ namespace iroha {
      * Complements initial transaction check with command-by-command check
      * @param temporary_wsv to apply commands on
      * @param transactions_errors_log to write errors to
+     * @param created_time - creation time of the proposal
      * @param tx to be checked
      * @return empty result, if check is successful, command error otherwise
      */
     static bool checkTransactions(
         ametsuchi::TemporaryWsv &temporary_wsv,
         validation::TransactionsErrors &transactions_errors_log,
+        shared_model::interface::types::TimestampType created_time,
         const shared_model::interface::Transaction &tx) {
+      // the ordering service holds such transactions back, but a proposal
+      // made by another peer may still carry them
+      if (tx.notBefore() > created_time) {
+        transactions_errors_log.emplace_back(validation::TransactionError{
+            tx.hash(),
+            validation::CommandError{
+                "NotBefore",
+                3,
+                (boost::format("Transaction is not valid before %d, the "
+                               "proposal is created at %d")
+                 % tx.notBefore() % created_time)
+                    .str(),
+                false}});
+        return false;
+      }
       return temporary_wsv.apply(tx).match(
           [](const auto &) { return true; },
           [&tx, &transactions_errors_log](auto &&error) {
@@ -45,6 +62,7 @@ namespace iroha {
      * @param txs to be validated
      * @param temporary_wsv to apply transactions on
      * @param transactions_errors_log to write errors to
+     * @param created_time - creation time of the proposal
      * @param batch_parser to parse batches from transaction range
      * @return range of transactions, which passed stateful validation
      */
@@ -52,13 +70,15 @@ namespace iroha {
         const shared_model::interface::types::TransactionsCollectionType &txs,
         ametsuchi::TemporaryWsv &temporary_wsv,
         validation::TransactionsErrors &transactions_errors_log,
+        shared_model::interface::types::TimestampType created_time,
         const shared_model::interface::TransactionBatchParser &batch_parser) {
       std::vector<bool> validation_results;
       validation_results.reserve(boost::size(txs));
 
       for (auto batch : batch_parser.parseBatches(txs)) {
         auto validation = [&](auto &tx) {
-          return checkTransactions(temporary_wsv, transactions_errors_log, tx);
+          return checkTransactions(
+              temporary_wsv, transactions_errors_log, created_time, tx);
         };
         if (batch.front().batchMeta()
             and batch.front().batchMeta()->get()->type()
@@ -131,6 +151,7 @@ namespace iroha {
           validateTransactions(proposal.transactions(),
                                temporaryWsv,
                                validation_result->rejected_transactions,
+                               proposal.createdTime(),
                                *batch_parser_);
 
       // Since proposal came from ordering gate it was already validated.
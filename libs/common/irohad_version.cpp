@@ -20,4 +20,9 @@ namespace iroha {
     return major == rhs.major and minor == rhs.minor and patch == rhs.patch;
   }
 
+  std::string IrohadVersion::toString() const {
+    return std::to_string(major) + "." + std::to_string(minor) + "."
+        + std::to_string(patch);
+  }
+
 }  // namespace iroha
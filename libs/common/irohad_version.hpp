@@ -15,6 +15,8 @@
 #undef minor
 #endif
 
+#include <string>
+
 namespace iroha {
 
   /// A string describing current git repository version in a human-readable way
@@ -26,6 +28,9 @@ namespace iroha {
     unsigned int patch;
 
     bool operator==(const IrohadVersion &) const;
+
+    /// @return the version formatted as major.minor.patch
+    std::string toString() const;
   };
 
   IrohadVersion getIrohadVersion();
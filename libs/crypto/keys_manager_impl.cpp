@@ -107,8 +107,12 @@ namespace iroha {
 
   bool KeysManagerImpl::createKeys(
       const boost::optional<std::string> &pass_phrase) {
-    Keypair keypair = DefaultCryptoAlgorithmType::generateKeypair();
+    return importKeys(DefaultCryptoAlgorithmType::generateKeypair(),
+                      pass_phrase);
+  }
 
+  bool KeysManagerImpl::importKeys(
+      const Keypair &keypair, const boost::optional<std::string> &pass_phrase) {
     auto pub = keypair.publicKey();
     auto &&priv = pass_phrase
         ? bytestringToHexstring(
@@ -37,6 +37,16 @@ namespace iroha {
 
     bool createKeys(const boost::optional<std::string> &pass_phrase) override;
 
+    /**
+     * Store an existing keypair on disk. If pass phrase is provided, the
+     * private key is encrypted.
+     * @param keypair - keypair to store
+     * @param pass_phrase (optional) used for private key encryption
+     * @return false if keys storing failed
+     */
+    bool importKeys(const shared_model::crypto::Keypair &keypair,
+                    const boost::optional<std::string> &pass_phrase);
+
     iroha::expected::Result<shared_model::crypto::Keypair, std::string>
     loadKeys(const boost::optional<std::string> &pass_phrase) override;
 
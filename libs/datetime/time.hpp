@@ -6,7 +6,10 @@
 #ifndef IROHA_TIME_H
 #define IROHA_TIME_H
 
+#include <atomic>
 #include <chrono>
+#include <functional>
+#include <memory>
 
 namespace iroha {
 
@@ -16,13 +19,37 @@ namespace iroha {
 
   namespace time {
 
+    /// Source of the current time
+    using TimeSource = std::function<std::chrono::system_clock::time_point()>;
+
+    namespace detail {
+      /// the source replacing the system clock, if set
+      inline std::shared_ptr<const TimeSource> time_source;
+    }  // namespace detail
+
+    /**
+     * Replace the system clock with the source, e.g. to control the time in
+     * tests. Affects the whole process.
+     * @param source of the time, nullptr restores the system clock
+     */
+    inline void setTimeSource(std::shared_ptr<const TimeSource> source) {
+      std::atomic_store(&detail::time_source, std::move(source));
+    }
+
+    /// Returns current time of the source set, system time by default.
+    inline std::chrono::system_clock::time_point systemNow() {
+      if (auto source = std::atomic_load(&detail::time_source)) {
+        return (*source)();
+      }
+      return std::chrono::system_clock::now();
+    }
+
     /**
      * Returns current UNIX timestamp.
      * Represents number of milliseconds since epoch.
      */
     inline auto now() {
-      return std::chrono::system_clock::now().time_since_epoch()
-          / std::chrono::milliseconds(1);
+      return systemNow().time_since_epoch() / std::chrono::milliseconds(1);
     }
 
     /**
@@ -40,7 +67,7 @@ namespace iroha {
      */
     template <typename T>
     inline auto now(const T &offset) {
-      return (std::chrono::system_clock::now().time_since_epoch() + offset)
+      return (systemNow().time_since_epoch() + offset)
           / std::chrono::milliseconds(1);
     }
 
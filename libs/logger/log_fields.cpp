@@ -0,0 +1,26 @@
+/**
+ * Copyright Soramitsu Co., Ltd. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+#include "logger/log_fields.hpp"
+
+namespace {
+  thread_local std::vector<logger::LogField> log_fields;
+}  // namespace
+
+namespace logger {
+
+  LogFieldGuard::LogFieldGuard(std::string key, std::string value) {
+    log_fields.emplace_back(std::move(key), std::move(value));
+  }
+
+  LogFieldGuard::~LogFieldGuard() {
+    log_fields.pop_back();
+  }
+
+  const std::vector<LogField> &getLogFields() {
+    return log_fields;
+  }
+
+}  // namespace logger
@@ -0,0 +1,37 @@
+/**
+ * Copyright Soramitsu Co., Ltd. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+#ifndef IROHA_LOGGER_LOG_FIELDS_HPP
+#define IROHA_LOGGER_LOG_FIELDS_HPP
+
+#include <string>
+#include <utility>
+#include <vector>
+
+namespace logger {
+
+  /// Named value attached to log records, e.g. block height
+  using LogField = std::pair<std::string, std::string>;
+
+  /**
+   * Attaches a field to every record logged by the current thread while the
+   * guard is alive, so that the records can be found by the field in a log
+   * storage. The fields are written in JSON log format only.
+   */
+  class LogFieldGuard {
+   public:
+    LogFieldGuard(std::string key, std::string value);
+    ~LogFieldGuard();
+
+    LogFieldGuard(const LogFieldGuard &) = delete;
+    LogFieldGuard &operator=(const LogFieldGuard &) = delete;
+  };
+
+  /// Fields attached by the guards alive in the current thread
+  const std::vector<LogField> &getLogFields();
+
+}  // namespace logger
+
+#endif  // IROHA_LOGGER_LOG_FIELDS_HPP
@@ -54,6 +54,12 @@ namespace logger {
     kCritical,
   };
 
+  /// Output format of log records
+  enum class LogFormat {
+    kText,  ///< lines formatted with the patterns
+    kJson,  ///< one JSON object per line with the message and log fields
+  };
+
   class Logger {
    public:
     using Level = LogLevel;
@@ -7,6 +7,7 @@
 
 #include <atomic>
 #include <ciso646>
+#include <iterator>
 
 static const std::string kTagHierarchySeparator = "/";
 
@@ -34,10 +35,12 @@ namespace logger {
       std::string tag,
       boost::optional<LogLevel> log_level,
       boost::optional<LogPatterns> patterns) {
+    auto config = std::atomic_load(&config_);
     LoggerConfig child_config{
-        log_level.value_or(config_->log_level),
-        patterns ? std::move(patterns)->inherit(config_->patterns)
-                 : config_->patterns};
+        log_level.value_or(config->log_level),
+        patterns ? std::move(patterns)->inherit(config->patterns)
+                 : config->patterns,
+        config->format};
     // Operator new is employed due to private visibility of used constructor.
     LoggerManagerTreePtr child(new LoggerManagerTree(
         joinTags(full_tag_, tag),
@@ -50,10 +53,11 @@ namespace logger {
   }
 
   LoggerPtr LoggerManagerTree::getLogger() {
-    LoggerPtr logger =
+    std::shared_ptr<LoggerSpdlog> logger =
         std::atomic_load_explicit(&logger_, std::memory_order_acquire);
     if (not logger) {
-      LoggerPtr new_logger = std::make_shared<LoggerSpdlog>(full_tag_, config_);
+      auto new_logger = std::make_shared<LoggerSpdlog>(
+          full_tag_, std::atomic_load(&config_));
       while (not logger) {
         if (std::atomic_compare_exchange_weak_explicit(
                 &logger_,
@@ -76,10 +80,85 @@ namespace logger {
     }
     // If a node for this child is not found in the tree config, create a
     // new standalone logger using this logger's settings.
-    LoggerManagerTreePtr new_child(
-        new LoggerManagerTree(joinTags(full_tag_, tag), tag, config_));
+    LoggerManagerTreePtr new_child(new LoggerManagerTree(
+        joinTags(full_tag_, tag), tag, std::atomic_load(&config_)));
     return children_.emplace(std::make_pair(tag, std::move(new_child)))
         .first->second;
   }
 
+  void LoggerManagerTree::setOwnLogLevel(LogLevel level) {
+    auto config = std::atomic_load(&config_);
+    if (config->log_level != level) {
+      std::atomic_store(
+          &config_,
+          std::make_shared<const LoggerConfig>(
+              LoggerConfig{level, config->patterns, config->format}));
+      if (auto logger = std::atomic_load(&logger_)) {
+        logger->setLevel(level);
+      }
+    }
+  }
+
+  void LoggerManagerTree::updateLogLevels(const LoggerManagerTree &source) {
+    setOwnLogLevel(std::atomic_load(&source.config_)->log_level);
+
+    std::lock_guard<std::mutex> lock(children_mutex_);
+    for (auto &child : children_) {
+      LoggerManagerTreePtr source_child;
+      {
+        std::lock_guard<std::mutex> source_lock(source.children_mutex_);
+        auto it = source.children_.find(child.first);
+        if (it != source.children_.end()) {
+          source_child = it->second;
+        }
+      }
+      child.second->updateLogLevels(source_child ? *source_child : source);
+    }
+  }
+
+  bool LoggerManagerTree::setLogLevel(const std::string &full_tag,
+                                      LogLevel level) {
+    if (full_tag == full_tag_) {
+      setOwnLogLevel(level);
+      std::lock_guard<std::mutex> lock(children_mutex_);
+      for (auto &child : children_) {
+        child.second->setLogLevel(child.second->full_tag_, level);
+      }
+      return true;
+    }
+
+    const auto prefix =
+        full_tag_.empty() ? full_tag_ : full_tag_ + kTagHierarchySeparator;
+    if (full_tag.compare(0, prefix.size(), prefix) != 0) {
+      return false;
+    }
+    const auto child_tag = full_tag.substr(
+        prefix.size(),
+        full_tag.find(kTagHierarchySeparator, prefix.size()) - prefix.size());
+    LoggerManagerTreePtr child;
+    {
+      std::lock_guard<std::mutex> lock(children_mutex_);
+      auto it = children_.find(child_tag);
+      if (it == children_.end()) {
+        return false;
+      }
+      child = it->second;
+    }
+    return child->setLogLevel(full_tag, level);
+  }
+
+  std::vector<std::pair<std::string, LogLevel>>
+  LoggerManagerTree::getLogLevels() const {
+    std::vector<std::pair<std::string, LogLevel>> levels{
+        {full_tag_, std::atomic_load(&config_)->log_level}};
+    std::lock_guard<std::mutex> lock(children_mutex_);
+    for (auto const &child : children_) {
+      auto child_levels = child.second->getLogLevels();
+      std::move(child_levels.begin(),
+                child_levels.end(),
+                std::back_inserter(levels));
+    }
+    return levels;
+  }
+
 }  // namespace logger
@@ -12,6 +12,8 @@
 #include <mutex>
 #include <string>
 #include <unordered_map>
+#include <utility>
+#include <vector>
 
 #include <boost/optional.hpp>
 #include "logger/logger_spdlog.hpp"
@@ -47,17 +49,44 @@ namespace logger {
     /// Get non-const child node by tag, if present. Thread safe.
     LoggerManagerTreePtr getChild(const std::string &tag);
 
+    /**
+     * Take log levels of this node and its children from another tree,
+     * e.g. one loaded from an updated configuration. Children missing in
+     * the other tree take the level of their nearest present parent.
+     * Loggers already created are updated too. Patterns are not changed.
+     * Thread safe.
+     *
+     * @param source - the tree to take the levels from
+     */
+    void updateLogLevels(const LoggerManagerTree &source);
+
+    /**
+     * Set the log level of a node and all its children. Loggers already
+     * created are updated too. Thread safe.
+     *
+     * @param full_tag - full tag of the node, e.g. "Irohad/Storage"
+     * @param level - the new log level
+     * @return false if this tree has no node with the given tag
+     */
+    bool setLogLevel(const std::string &full_tag, LogLevel level);
+
+    /// Full tags and log levels of this node and its children. Thread safe.
+    std::vector<std::pair<std::string, LogLevel>> getLogLevels() const;
+
    private:
     LoggerManagerTree(std::string full_tag,
                       std::string node_tag,
                       ConstLoggerConfigPtr config);
 
+    /// Set the log level of this node only
+    void setOwnLogLevel(LogLevel level);
+
     const std::string node_tag_;
     const std::string full_tag_;
-    const ConstLoggerConfigPtr config_;
-    std::shared_ptr<Logger> logger_;
+    ConstLoggerConfigPtr config_;
+    std::shared_ptr<LoggerSpdlog> logger_;
     std::unordered_map<std::string, LoggerManagerTreePtr> children_;
-    std::mutex children_mutex_;
+    mutable std::mutex children_mutex_;
   };
 
 }  // namespace logger
@@ -9,12 +9,63 @@
 #include <ciso646>
 #include <mutex>
 
+#include <fmt/format.h>
+
 #include <spdlog/sinks/stdout_color_sinks.h>
 #include <spdlog/spdlog.h>
 #include <boost/assert.hpp>
+#include "logger/log_fields.hpp"
 
 namespace {
 
+  /// The message with the log fields is substituted for %v
+  const std::string kJsonPattern =
+      R"({"time":"%Y-%m-%dT%H:%M:%S.%f%z","level":"%l","logger":"%n",)"
+      R"("thread":%t,%v})";
+
+  void appendJsonString(std::string &out, const std::string &str) {
+    out += '"';
+    for (char c : str) {
+      switch (c) {
+        case '"':
+          out += "\\\"";
+          break;
+        case '\\':
+          out += "\\\\";
+          break;
+        case '\n':
+          out += "\\n";
+          break;
+        case '\r':
+          out += "\\r";
+          break;
+        case '\t':
+          out += "\\t";
+          break;
+        default:
+          if (static_cast<unsigned char>(c) < 0x20) {
+            out += fmt::format("\\u{:04x}", static_cast<unsigned int>(c));
+          } else {
+            out += c;
+          }
+      }
+    }
+    out += '"';
+  }
+
+  /// Message and log fields as JSON object members
+  std::string makeJsonRecord(const std::string &message) {
+    std::string record = R"("message":)";
+    appendJsonString(record, message);
+    for (auto const &field : logger::getLogFields()) {
+      record += ',';
+      appendJsonString(record, field.first);
+      record += ':';
+      appendJsonString(record, field.second);
+    }
+    return record;
+  }
+
   spdlog::level::level_enum getSpdlogLogLevel(logger::LogLevel level) {
     switch (level) {
       case logger::LogLevel::kTrace:
@@ -89,20 +140,35 @@ namespace logger {
   }
 
   LoggerSpdlog::LoggerSpdlog(std::string tag, ConstLoggerConfigPtr config)
-      : tag_(tag), config_(std::move(config)), logger_(getOrCreateLogger(tag)) {
+      : tag_(tag),
+        config_(std::move(config)),
+        level_(config_->log_level),
+        logger_(getOrCreateLogger(tag)) {
+    setupLogger();
+  }
+
+  void LoggerSpdlog::setLevel(LogLevel level) {
+    level_ = level;
     setupLogger();
   }
 
   void LoggerSpdlog::setupLogger() {
-    logger_->set_level(getSpdlogLogLevel(config_->log_level));
-    logger_->set_pattern(config_->patterns.getPattern(config_->log_level));
+    const LogLevel level = level_;
+    logger_->set_level(getSpdlogLogLevel(level));
+    logger_->set_pattern(config_->format == LogFormat::kJson
+                             ? kJsonPattern
+                             : config_->patterns.getPattern(level));
   }
 
   void LoggerSpdlog::logInternal(Level level, const std::string &s) const {
-    logger_->log(getSpdlogLogLevel(level), s);
+    if (config_->format == LogFormat::kJson) {
+      logger_->log(getSpdlogLogLevel(level), makeJsonRecord(s));
+    } else {
+      logger_->log(getSpdlogLogLevel(level), s);
+    }
   }
 
   bool LoggerSpdlog::shouldLog(Level level) const {
-    return config_->log_level <= level;
+    return level_ <= level;
   }
 }  // namespace logger
@@ -8,6 +8,7 @@
 
 #include "logger/logger.hpp"
 
+#include <atomic>
 #include <map>
 #include <memory>
 #include <string>
@@ -49,6 +50,7 @@ namespace logger {
   struct LoggerConfig {
     LogLevel log_level;
     LogPatterns patterns;
+    LogFormat format = LogFormat::kText;
   };
 
   class LoggerSpdlog : public Logger {
@@ -59,6 +61,9 @@ namespace logger {
      */
     LoggerSpdlog(std::string tag, ConstLoggerConfigPtr config);
 
+    /// Change the logging level of a running logger. Thread safe.
+    void setLevel(LogLevel level);
+
    private:
     void logInternal(Level level, const std::string &s) const override;
 
@@ -71,6 +76,7 @@ namespace logger {
 
     const std::string tag_;
     const ConstLoggerConfigPtr config_;
+    std::atomic<LogLevel> level_;
     const std::shared_ptr<spdlog::logger> logger_;
   };
 
@@ -0,0 +1,19 @@
+/**
+ * Copyright Soramitsu Co., Ltd. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+#include "backend/protobuf/commands/proto_archive_domain.hpp"
+
+namespace shared_model {
+  namespace proto {
+
+    ArchiveDomain::ArchiveDomain(iroha::protocol::Command &command)
+        : archive_domain_{command.archive_domain()} {}
+
+    const interface::types::DomainIdType &ArchiveDomain::domainId() const {
+      return archive_domain_.domain_id();
+    }
+
+  }  // namespace proto
+}  // namespace shared_model
@@ -0,0 +1,60 @@
+/**
+ * Copyright Soramitsu Co., Ltd. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+#include "backend/protobuf/commands/proto_authorize_session_key.hpp"
+
+#include "backend/protobuf/permissions.hpp"
+
+namespace shared_model {
+  namespace proto {
+
+    AuthorizeSessionKey::AuthorizeSessionKey(iroha::protocol::Command &command)
+        : authorize_session_key_{command.authorize_session_key()},
+          permissions_{[&command] {
+            interface::RolePermissionSet perms_out;
+            for (const auto &perm :
+                 command.authorize_session_key().permissions()) {
+              perms_out.set(permissions::fromTransport(
+                  static_cast<iroha::protocol::RolePermission>(perm)));
+            }
+            return perms_out;
+          }()} {}
+
+    const interface::types::AccountIdType &AuthorizeSessionKey::accountId()
+        const {
+      return authorize_session_key_.account_id();
+    }
+
+    const std::string &AuthorizeSessionKey::pubkey() const {
+      return authorize_session_key_.public_key();
+    }
+
+    const interface::RolePermissionSet &AuthorizeSessionKey::permissions()
+        const {
+      return permissions_;
+    }
+
+    const interface::types::AssetIdType &AuthorizeSessionKey::allowedAssetId()
+        const {
+      return authorize_session_key_.allowed_asset_id();
+    }
+
+    interface::types::HeightType AuthorizeSessionKey::validity() const {
+      return authorize_session_key_.validity();
+    }
+
+    std::string AuthorizeSessionKey::toString() const {
+      return detail::PrettyStringBuilder()
+          .init("AuthorizeSessionKey")
+          .appendNamed("account_id", accountId())
+          .appendNamed("public_key", pubkey())
+          .append(permissions::toString(permissions()))
+          .appendNamed("allowed_asset_id", allowedAssetId())
+          .appendNamed("validity", validity())
+          .finalize();
+    }
+
+  }  // namespace proto
+}  // namespace shared_model
@@ -0,0 +1,19 @@
+/**
+ * Copyright Soramitsu Co., Ltd. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+#include "backend/protobuf/commands/proto_claim_with_preimage.hpp"
+
+namespace shared_model {
+  namespace proto {
+
+    ClaimWithPreimage::ClaimWithPreimage(iroha::protocol::Command &command)
+        : claim_with_preimage_{command.claim_with_preimage()} {}
+
+    const std::string &ClaimWithPreimage::preimage() const {
+      return claim_with_preimage_.preimage();
+    }
+
+  }  // namespace proto
+}  // namespace shared_model
@@ -9,22 +9,36 @@
 #include "backend/protobuf/commands/proto_add_peer.hpp"
 #include "backend/protobuf/commands/proto_add_signatory.hpp"
 #include "backend/protobuf/commands/proto_append_role.hpp"
+#include "backend/protobuf/commands/proto_archive_domain.hpp"
+#include "backend/protobuf/commands/proto_authorize_session_key.hpp"
 #include "backend/protobuf/commands/proto_call_engine.hpp"
+#include "backend/protobuf/commands/proto_claim_with_preimage.hpp"
 #include "backend/protobuf/commands/proto_compare_and_set_account_detail.hpp"
 #include "backend/protobuf/commands/proto_create_account.hpp"
 #include "backend/protobuf/commands/proto_create_asset.hpp"
 #include "backend/protobuf/commands/proto_create_domain.hpp"
 #include "backend/protobuf/commands/proto_create_role.hpp"
+#include "backend/protobuf/commands/proto_deactivate_account.hpp"
 #include "backend/protobuf/commands/proto_detach_role.hpp"
 #include "backend/protobuf/commands/proto_grant_permission.hpp"
+#include "backend/protobuf/commands/proto_grant_vested_asset.hpp"
+#include "backend/protobuf/commands/proto_include_role.hpp"
+#include "backend/protobuf/commands/proto_lock_asset.hpp"
+#include "backend/protobuf/commands/proto_propose_setting_value.hpp"
+#include "backend/protobuf/commands/proto_recover_account.hpp"
+#include "backend/protobuf/commands/proto_refund_after_timeout.hpp"
 #include "backend/protobuf/commands/proto_remove_peer.hpp"
 #include "backend/protobuf/commands/proto_remove_signatory.hpp"
 #include "backend/protobuf/commands/proto_revoke_permission.hpp"
+#include "backend/protobuf/commands/proto_rotate_key.hpp"
 #include "backend/protobuf/commands/proto_set_account_detail.hpp"
+#include "backend/protobuf/commands/proto_set_account_detail_at_path.hpp"
 #include "backend/protobuf/commands/proto_set_quorum.hpp"
 #include "backend/protobuf/commands/proto_set_setting_value.hpp"
+#include "backend/protobuf/commands/proto_set_spending_limit.hpp"
 #include "backend/protobuf/commands/proto_subtract_asset_quantity.hpp"
 #include "backend/protobuf/commands/proto_transfer_asset.hpp"
+#include "backend/protobuf/commands/proto_vote_for_proposal.hpp"
 #include "common/report_abort.h"
 
 namespace {
@@ -34,22 +48,36 @@ namespace {
                        shared_model::proto::AddPeer,
                        shared_model::proto::AddSignatory,
                        shared_model::proto::AppendRole,
+                       shared_model::proto::ArchiveDomain,
+                       shared_model::proto::AuthorizeSessionKey,
                        shared_model::proto::CallEngine,
+                       shared_model::proto::ClaimWithPreimage,
                        shared_model::proto::CompareAndSetAccountDetail,
                        shared_model::proto::CreateAccount,
                        shared_model::proto::CreateAsset,
                        shared_model::proto::CreateDomain,
                        shared_model::proto::CreateRole,
+                       shared_model::proto::DeactivateAccount,
                        shared_model::proto::DetachRole,
                        shared_model::proto::GrantPermission,
+                       shared_model::proto::GrantVestedAsset,
+                       shared_model::proto::IncludeRole,
+                       shared_model::proto::LockAsset,
+                       shared_model::proto::ProposeSettingValue,
+                       shared_model::proto::RecoverAccount,
+                       shared_model::proto::RefundAfterTimeout,
                        shared_model::proto::RemovePeer,
                        shared_model::proto::RemoveSignatory,
                        shared_model::proto::RevokePermission,
+                       shared_model::proto::RotateKey,
                        shared_model::proto::SetAccountDetail,
+                       shared_model::proto::SetAccountDetailAtPath,
                        shared_model::proto::SetQuorum,
                        shared_model::proto::SetSettingValue,
+                       shared_model::proto::SetSpendingLimit,
                        shared_model::proto::SubtractAssetQuantity,
-                       shared_model::proto::TransferAsset>;
+                       shared_model::proto::TransferAsset,
+                       shared_model::proto::VoteForProposal>;
 }  // namespace
 
 #ifdef IROHA_BIND_TYPE
@@ -91,6 +119,20 @@ namespace shared_model::proto {
             kCompareAndSetAccountDetail, CompareAndSetAccountDetail, ar);
         IROHA_BIND_TYPE(kSetSettingValue, SetSettingValue, ar);
         IROHA_BIND_TYPE(kCallEngine, CallEngine, ar);
+        IROHA_BIND_TYPE(kDeactivateAccount, DeactivateAccount, ar);
+        IROHA_BIND_TYPE(kRecoverAccount, RecoverAccount, ar);
+        IROHA_BIND_TYPE(kRotateKey, RotateKey, ar);
+        IROHA_BIND_TYPE(kLockAsset, LockAsset, ar);
+        IROHA_BIND_TYPE(kClaimWithPreimage, ClaimWithPreimage, ar);
+        IROHA_BIND_TYPE(kRefundAfterTimeout, RefundAfterTimeout, ar);
+        IROHA_BIND_TYPE(kSetAccountDetailAtPath, SetAccountDetailAtPath, ar);
+        IROHA_BIND_TYPE(kIncludeRole, IncludeRole, ar);
+        IROHA_BIND_TYPE(kProposeSettingValue, ProposeSettingValue, ar);
+        IROHA_BIND_TYPE(kVoteForProposal, VoteForProposal, ar);
+        IROHA_BIND_TYPE(kGrantVestedAsset, GrantVestedAsset, ar);
+        IROHA_BIND_TYPE(kSetSpendingLimit, SetSpendingLimit, ar);
+        IROHA_BIND_TYPE(kAuthorizeSessionKey, AuthorizeSessionKey, ar);
+        IROHA_BIND_TYPE(kArchiveDomain, ArchiveDomain, ar);
 
         default:
         case iroha::protocol::Command::CommandCase::COMMAND_NOT_SET:
@@ -0,0 +1,20 @@
+/**
+ * Copyright Soramitsu Co., Ltd. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+#include "backend/protobuf/commands/proto_deactivate_account.hpp"
+
+namespace shared_model {
+  namespace proto {
+
+    DeactivateAccount::DeactivateAccount(iroha::protocol::Command &command)
+        : deactivate_account_{command.deactivate_account()} {}
+
+    const interface::types::AccountIdType &DeactivateAccount::accountId()
+        const {
+      return deactivate_account_.account_id();
+    }
+
+  }  // namespace proto
+}  // namespace shared_model
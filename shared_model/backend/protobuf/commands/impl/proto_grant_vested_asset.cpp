@@ -0,0 +1,37 @@
+/**
+ * Copyright Soramitsu Co., Ltd. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+#include "backend/protobuf/commands/proto_grant_vested_asset.hpp"
+
+namespace shared_model {
+  namespace proto {
+
+    GrantVestedAsset::GrantVestedAsset(iroha::protocol::Command &command)
+        : grant_vested_asset_{command.grant_vested_asset()},
+          amount_{grant_vested_asset_.amount()} {}
+
+    const interface::types::AccountIdType &GrantVestedAsset::destAccountId()
+        const {
+      return grant_vested_asset_.dest_account_id();
+    }
+
+    const interface::types::AssetIdType &GrantVestedAsset::assetId() const {
+      return grant_vested_asset_.asset_id();
+    }
+
+    const interface::Amount &GrantVestedAsset::amount() const {
+      return amount_;
+    }
+
+    interface::types::HeightType GrantVestedAsset::cliff() const {
+      return grant_vested_asset_.cliff();
+    }
+
+    interface::types::HeightType GrantVestedAsset::duration() const {
+      return grant_vested_asset_.duration();
+    }
+
+  }  // namespace proto
+}  // namespace shared_model
@@ -0,0 +1,23 @@
+/**
+ * Copyright Soramitsu Co., Ltd. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+#include "backend/protobuf/commands/proto_include_role.hpp"
+
+namespace shared_model {
+  namespace proto {
+
+    IncludeRole::IncludeRole(iroha::protocol::Command &command)
+        : include_role_{command.include_role()} {}
+
+    const interface::types::RoleIdType &IncludeRole::roleName() const {
+      return include_role_.role_name();
+    }
+
+    const interface::types::RoleIdType &IncludeRole::includedRoleName() const {
+      return include_role_.included_role_name();
+    }
+
+  }  // namespace proto
+}  // namespace shared_model
@@ -0,0 +1,39 @@
+/**
+ * Copyright Soramitsu Co., Ltd. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+#include "backend/protobuf/commands/proto_lock_asset.hpp"
+
+namespace shared_model {
+  namespace proto {
+
+    LockAsset::LockAsset(iroha::protocol::Command &command)
+        : lock_asset_{command.lock_asset()}, amount_{lock_asset_.amount()} {}
+
+    const interface::types::AccountIdType &LockAsset::srcAccountId() const {
+      return lock_asset_.src_account_id();
+    }
+
+    const interface::types::AccountIdType &LockAsset::destAccountId() const {
+      return lock_asset_.dest_account_id();
+    }
+
+    const interface::types::AssetIdType &LockAsset::assetId() const {
+      return lock_asset_.asset_id();
+    }
+
+    const interface::Amount &LockAsset::amount() const {
+      return amount_;
+    }
+
+    const std::string &LockAsset::hash() const {
+      return lock_asset_.hash();
+    }
+
+    interface::types::HeightType LockAsset::timeout() const {
+      return lock_asset_.timeout();
+    }
+
+  }  // namespace proto
+}  // namespace shared_model
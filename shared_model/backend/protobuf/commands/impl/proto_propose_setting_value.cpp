@@ -0,0 +1,30 @@
+/**
+ * Copyright Soramitsu Co., Ltd. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+#include "backend/protobuf/commands/proto_propose_setting_value.hpp"
+
+namespace shared_model {
+  namespace proto {
+
+    ProposeSettingValue::ProposeSettingValue(iroha::protocol::Command &command)
+        : propose_setting_value_{command.propose_setting_value()} {}
+
+    const interface::types::ProposalIdType &ProposeSettingValue::proposalId()
+        const {
+      return propose_setting_value_.proposal_id();
+    }
+
+    const interface::types::SettingKeyType &ProposeSettingValue::settingKey()
+        const {
+      return propose_setting_value_.setting_key();
+    }
+
+    const interface::types::SettingValueType &
+    ProposeSettingValue::settingValue() const {
+      return propose_setting_value_.setting_value();
+    }
+
+  }  // namespace proto
+}  // namespace shared_model
@@ -0,0 +1,23 @@
+/**
+ * Copyright Soramitsu Co., Ltd. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+#include "backend/protobuf/commands/proto_recover_account.hpp"
+
+namespace shared_model {
+  namespace proto {
+
+    RecoverAccount::RecoverAccount(iroha::protocol::Command &command)
+        : recover_account_{command.recover_account()} {}
+
+    const interface::types::AccountIdType &RecoverAccount::accountId() const {
+      return recover_account_.account_id();
+    }
+
+    const std::string &RecoverAccount::pubkey() const {
+      return recover_account_.public_key();
+    }
+
+  }  // namespace proto
+}  // namespace shared_model
@@ -0,0 +1,19 @@
+/**
+ * Copyright Soramitsu Co., Ltd. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+#include "backend/protobuf/commands/proto_refund_after_timeout.hpp"
+
+namespace shared_model {
+  namespace proto {
+
+    RefundAfterTimeout::RefundAfterTimeout(iroha::protocol::Command &command)
+        : refund_after_timeout_{command.refund_after_timeout()} {}
+
+    const std::string &RefundAfterTimeout::hash() const {
+      return refund_after_timeout_.hash();
+    }
+
+  }  // namespace proto
+}  // namespace shared_model
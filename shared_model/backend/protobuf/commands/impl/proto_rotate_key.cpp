@@ -0,0 +1,31 @@
+/**
+ * Copyright Soramitsu Co., Ltd. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+#include "backend/protobuf/commands/proto_rotate_key.hpp"
+
+namespace shared_model {
+  namespace proto {
+
+    RotateKey::RotateKey(iroha::protocol::Command &command)
+        : rotate_key_{command.rotate_key()} {}
+
+    const interface::types::AccountIdType &RotateKey::accountId() const {
+      return rotate_key_.account_id();
+    }
+
+    const std::string &RotateKey::oldPubkey() const {
+      return rotate_key_.old_public_key();
+    }
+
+    const std::string &RotateKey::newPubkey() const {
+      return rotate_key_.new_public_key();
+    }
+
+    interface::types::HeightType RotateKey::gracePeriod() const {
+      return rotate_key_.grace_period();
+    }
+
+  }  // namespace proto
+}  // namespace shared_model
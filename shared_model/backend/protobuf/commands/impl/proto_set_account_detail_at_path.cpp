@@ -0,0 +1,44 @@
+/**
+ * Copyright Soramitsu Co., Ltd. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+#include "backend/protobuf/commands/proto_set_account_detail_at_path.hpp"
+
+namespace shared_model {
+  namespace proto {
+
+    SetAccountDetailAtPath::SetAccountDetailAtPath(
+        iroha::protocol::Command &command)
+        : set_account_detail_at_path_{command.set_account_detail_at_path()} {}
+
+    const interface::types::AccountIdType &SetAccountDetailAtPath::accountId()
+        const {
+      return set_account_detail_at_path_.account_id();
+    }
+
+    const interface::types::AccountDetailKeyType &SetAccountDetailAtPath::key()
+        const {
+      return set_account_detail_at_path_.key();
+    }
+
+    const std::string &SetAccountDetailAtPath::path() const {
+      return set_account_detail_at_path_.path();
+    }
+
+    const interface::types::AccountDetailValueType &
+    SetAccountDetailAtPath::value() const {
+      return set_account_detail_at_path_.value();
+    }
+
+    const std::optional<interface::types::AccountDetailValueType>
+    SetAccountDetailAtPath::oldValue() const {
+      if (set_account_detail_at_path_.opt_old_value_case()
+          == iroha::protocol::SetAccountDetailAtPath::OPT_OLD_VALUE_NOT_SET) {
+        return std::nullopt;
+      }
+      return set_account_detail_at_path_.old_value();
+    }
+
+  }  // namespace proto
+}  // namespace shared_model
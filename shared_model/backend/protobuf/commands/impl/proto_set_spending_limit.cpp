@@ -0,0 +1,32 @@
+/**
+ * Copyright Soramitsu Co., Ltd. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+#include "backend/protobuf/commands/proto_set_spending_limit.hpp"
+
+namespace shared_model {
+  namespace proto {
+
+    SetSpendingLimit::SetSpendingLimit(iroha::protocol::Command &command)
+        : set_spending_limit_{command.set_spending_limit()},
+          amount_{set_spending_limit_.amount()} {}
+
+    const interface::types::AccountIdType &SetSpendingLimit::accountId() const {
+      return set_spending_limit_.account_id();
+    }
+
+    const interface::types::AssetIdType &SetSpendingLimit::assetId() const {
+      return set_spending_limit_.asset_id();
+    }
+
+    const interface::Amount &SetSpendingLimit::amount() const {
+      return amount_;
+    }
+
+    interface::types::HeightType SetSpendingLimit::window() const {
+      return set_spending_limit_.window();
+    }
+
+  }  // namespace proto
+}  // namespace shared_model
@@ -0,0 +1,20 @@
+/**
+ * Copyright Soramitsu Co., Ltd. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+#include "backend/protobuf/commands/proto_vote_for_proposal.hpp"
+
+namespace shared_model {
+  namespace proto {
+
+    VoteForProposal::VoteForProposal(iroha::protocol::Command &command)
+        : vote_for_proposal_{command.vote_for_proposal()} {}
+
+    const interface::types::ProposalIdType &VoteForProposal::proposalId()
+        const {
+      return vote_for_proposal_.proposal_id();
+    }
+
+  }  // namespace proto
+}  // namespace shared_model
@@ -0,0 +1,29 @@
+/**
+ * Copyright Soramitsu Co., Ltd. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+#ifndef IROHA_PROTO_ARCHIVE_DOMAIN_HPP
+#define IROHA_PROTO_ARCHIVE_DOMAIN_HPP
+
+#include "interfaces/commands/archive_domain.hpp"
+
+#include "commands.pb.h"
+
+namespace shared_model {
+  namespace proto {
+
+    class ArchiveDomain final : public interface::ArchiveDomain {
+     public:
+      explicit ArchiveDomain(iroha::protocol::Command &command);
+
+      const interface::types::DomainIdType &domainId() const override;
+
+     private:
+      const iroha::protocol::ArchiveDomain &archive_domain_;
+    };
+
+  }  // namespace proto
+}  // namespace shared_model
+
+#endif  // IROHA_PROTO_ARCHIVE_DOMAIN_HPP
@@ -0,0 +1,42 @@
+/**
+ * Copyright Soramitsu Co., Ltd. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+#ifndef IROHA_PROTO_AUTHORIZE_SESSION_KEY_HPP
+#define IROHA_PROTO_AUTHORIZE_SESSION_KEY_HPP
+
+#include "interfaces/commands/authorize_session_key.hpp"
+
+#include "commands.pb.h"
+#include "interfaces/permissions.hpp"
+
+namespace shared_model {
+  namespace proto {
+
+    class AuthorizeSessionKey final : public interface::AuthorizeSessionKey {
+     public:
+      explicit AuthorizeSessionKey(iroha::protocol::Command &command);
+
+      const interface::types::AccountIdType &accountId() const override;
+
+      const std::string &pubkey() const override;
+
+      const interface::RolePermissionSet &permissions() const override;
+
+      const interface::types::AssetIdType &allowedAssetId() const override;
+
+      interface::types::HeightType validity() const override;
+
+      std::string toString() const override;
+
+     private:
+      const iroha::protocol::AuthorizeSessionKey &authorize_session_key_;
+
+      const interface::RolePermissionSet permissions_;
+    };
+
+  }  // namespace proto
+}  // namespace shared_model
+
+#endif  // IROHA_PROTO_AUTHORIZE_SESSION_KEY_HPP
@@ -0,0 +1,29 @@
+/**
+ * Copyright Soramitsu Co., Ltd. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+#ifndef IROHA_PROTO_CLAIM_WITH_PREIMAGE_HPP
+#define IROHA_PROTO_CLAIM_WITH_PREIMAGE_HPP
+
+#include "interfaces/commands/claim_with_preimage.hpp"
+
+#include "commands.pb.h"
+
+namespace shared_model {
+  namespace proto {
+
+    class ClaimWithPreimage final : public interface::ClaimWithPreimage {
+     public:
+      explicit ClaimWithPreimage(iroha::protocol::Command &command);
+
+      const std::string &preimage() const override;
+
+     private:
+      const iroha::protocol::ClaimWithPreimage &claim_with_preimage_;
+    };
+
+  }  // namespace proto
+}  // namespace shared_model
+
+#endif  // IROHA_PROTO_CLAIM_WITH_PREIMAGE_HPP
@@ -0,0 +1,29 @@
+/**
+ * Copyright Soramitsu Co., Ltd. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+#ifndef IROHA_PROTO_DEACTIVATE_ACCOUNT_HPP
+#define IROHA_PROTO_DEACTIVATE_ACCOUNT_HPP
+
+#include "interfaces/commands/deactivate_account.hpp"
+
+#include "commands.pb.h"
+
+namespace shared_model {
+  namespace proto {
+
+    class DeactivateAccount final : public interface::DeactivateAccount {
+     public:
+      explicit DeactivateAccount(iroha::protocol::Command &command);
+
+      const interface::types::AccountIdType &accountId() const override;
+
+     private:
+      const iroha::protocol::DeactivateAccount &deactivate_account_;
+    };
+
+  }  // namespace proto
+}  // namespace shared_model
+
+#endif  // IROHA_PROTO_DEACTIVATE_ACCOUNT_HPP
@@ -0,0 +1,40 @@
+/**
+ * Copyright Soramitsu Co., Ltd. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+#ifndef IROHA_PROTO_GRANT_VESTED_ASSET_HPP
+#define IROHA_PROTO_GRANT_VESTED_ASSET_HPP
+
+#include "interfaces/commands/grant_vested_asset.hpp"
+
+#include "commands.pb.h"
+#include "interfaces/common_objects/amount.hpp"
+
+namespace shared_model {
+  namespace proto {
+
+    class GrantVestedAsset final : public interface::GrantVestedAsset {
+     public:
+      explicit GrantVestedAsset(iroha::protocol::Command &command);
+
+      const interface::types::AccountIdType &destAccountId() const override;
+
+      const interface::types::AssetIdType &assetId() const override;
+
+      const interface::Amount &amount() const override;
+
+      interface::types::HeightType cliff() const override;
+
+      interface::types::HeightType duration() const override;
+
+     private:
+      const iroha::protocol::GrantVestedAsset &grant_vested_asset_;
+
+      const interface::Amount amount_;
+    };
+
+  }  // namespace proto
+}  // namespace shared_model
+
+#endif  // IROHA_PROTO_GRANT_VESTED_ASSET_HPP
@@ -0,0 +1,31 @@
+/**
+ * Copyright Soramitsu Co., Ltd. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+#ifndef IROHA_PROTO_INCLUDE_ROLE_HPP
+#define IROHA_PROTO_INCLUDE_ROLE_HPP
+
+#include "interfaces/commands/include_role.hpp"
+
+#include "commands.pb.h"
+
+namespace shared_model {
+  namespace proto {
+
+    class IncludeRole final : public interface::IncludeRole {
+     public:
+      explicit IncludeRole(iroha::protocol::Command &command);
+
+      const interface::types::RoleIdType &roleName() const override;
+
+      const interface::types::RoleIdType &includedRoleName() const override;
+
+     private:
+      const iroha::protocol::IncludeRole &include_role_;
+    };
+
+  }  // namespace proto
+}  // namespace shared_model
+
+#endif  // IROHA_PROTO_INCLUDE_ROLE_HPP
@@ -0,0 +1,42 @@
+/**
+ * Copyright Soramitsu Co., Ltd. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+#ifndef IROHA_PROTO_LOCK_ASSET_HPP
+#define IROHA_PROTO_LOCK_ASSET_HPP
+
+#include "interfaces/commands/lock_asset.hpp"
+
+#include "commands.pb.h"
+#include "interfaces/common_objects/amount.hpp"
+
+namespace shared_model {
+  namespace proto {
+
+    class LockAsset final : public interface::LockAsset {
+     public:
+      explicit LockAsset(iroha::protocol::Command &command);
+
+      const interface::types::AccountIdType &srcAccountId() const override;
+
+      const interface::types::AccountIdType &destAccountId() const override;
+
+      const interface::types::AssetIdType &assetId() const override;
+
+      const interface::Amount &amount() const override;
+
+      const std::string &hash() const override;
+
+      interface::types::HeightType timeout() const override;
+
+     private:
+      const iroha::protocol::LockAsset &lock_asset_;
+
+      const interface::Amount amount_;
+    };
+
+  }  // namespace proto
+}  // namespace shared_model
+
+#endif  // IROHA_PROTO_LOCK_ASSET_HPP
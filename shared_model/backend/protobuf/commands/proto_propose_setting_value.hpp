@@ -0,0 +1,33 @@
+/**
+ * Copyright Soramitsu Co., Ltd. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+#ifndef IROHA_PROTO_PROPOSE_SETTING_VALUE_HPP
+#define IROHA_PROTO_PROPOSE_SETTING_VALUE_HPP
+
+#include "interfaces/commands/propose_setting_value.hpp"
+
+#include "commands.pb.h"
+
+namespace shared_model {
+  namespace proto {
+
+    class ProposeSettingValue final : public interface::ProposeSettingValue {
+     public:
+      explicit ProposeSettingValue(iroha::protocol::Command &command);
+
+      const interface::types::ProposalIdType &proposalId() const override;
+
+      const interface::types::SettingKeyType &settingKey() const override;
+
+      const interface::types::SettingValueType &settingValue() const override;
+
+     private:
+      const iroha::protocol::ProposeSettingValue &propose_setting_value_;
+    };
+
+  }  // namespace proto
+}  // namespace shared_model
+
+#endif  // IROHA_PROTO_PROPOSE_SETTING_VALUE_HPP
@@ -0,0 +1,31 @@
+/**
+ * Copyright Soramitsu Co., Ltd. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+#ifndef IROHA_PROTO_RECOVER_ACCOUNT_HPP
+#define IROHA_PROTO_RECOVER_ACCOUNT_HPP
+
+#include "interfaces/commands/recover_account.hpp"
+
+#include "commands.pb.h"
+
+namespace shared_model {
+  namespace proto {
+
+    class RecoverAccount final : public interface::RecoverAccount {
+     public:
+      explicit RecoverAccount(iroha::protocol::Command &command);
+
+      const interface::types::AccountIdType &accountId() const override;
+
+      const std::string &pubkey() const override;
+
+     private:
+      const iroha::protocol::RecoverAccount &recover_account_;
+    };
+
+  }  // namespace proto
+}  // namespace shared_model
+
+#endif  // IROHA_PROTO_RECOVER_ACCOUNT_HPP
@@ -0,0 +1,29 @@
+/**
+ * Copyright Soramitsu Co., Ltd. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+#ifndef IROHA_PROTO_REFUND_AFTER_TIMEOUT_HPP
+#define IROHA_PROTO_REFUND_AFTER_TIMEOUT_HPP
+
+#include "interfaces/commands/refund_after_timeout.hpp"
+
+#include "commands.pb.h"
+
+namespace shared_model {
+  namespace proto {
+
+    class RefundAfterTimeout final : public interface::RefundAfterTimeout {
+     public:
+      explicit RefundAfterTimeout(iroha::protocol::Command &command);
+
+      const std::string &hash() const override;
+
+     private:
+      const iroha::protocol::RefundAfterTimeout &refund_after_timeout_;
+    };
+
+  }  // namespace proto
+}  // namespace shared_model
+
+#endif  // IROHA_PROTO_REFUND_AFTER_TIMEOUT_HPP
@@ -0,0 +1,35 @@
+/**
+ * Copyright Soramitsu Co., Ltd. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+#ifndef IROHA_PROTO_ROTATE_KEY_HPP
+#define IROHA_PROTO_ROTATE_KEY_HPP
+
+#include "interfaces/commands/rotate_key.hpp"
+
+#include "commands.pb.h"
+
+namespace shared_model {
+  namespace proto {
+
+    class RotateKey final : public interface::RotateKey {
+     public:
+      explicit RotateKey(iroha::protocol::Command &command);
+
+      const interface::types::AccountIdType &accountId() const override;
+
+      const std::string &oldPubkey() const override;
+
+      const std::string &newPubkey() const override;
+
+      interface::types::HeightType gracePeriod() const override;
+
+     private:
+      const iroha::protocol::RotateKey &rotate_key_;
+    };
+
+  }  // namespace proto
+}  // namespace shared_model
+
+#endif  // IROHA_PROTO_ROTATE_KEY_HPP
@@ -0,0 +1,39 @@
+/**
+ * Copyright Soramitsu Co., Ltd. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+#ifndef IROHA_PROTO_SET_ACCOUNT_DETAIL_AT_PATH_HPP
+#define IROHA_PROTO_SET_ACCOUNT_DETAIL_AT_PATH_HPP
+
+#include "interfaces/commands/set_account_detail_at_path.hpp"
+
+#include "commands.pb.h"
+
+namespace shared_model {
+  namespace proto {
+    class SetAccountDetailAtPath final
+        : public interface::SetAccountDetailAtPath {
+     public:
+      explicit SetAccountDetailAtPath(iroha::protocol::Command &command);
+
+      const interface::types::AccountIdType &accountId() const override;
+
+      const interface::types::AccountDetailKeyType &key() const override;
+
+      const std::string &path() const override;
+
+      const interface::types::AccountDetailValueType &value() const override;
+
+      const std::optional<interface::types::AccountDetailValueType> oldValue()
+          const override;
+
+     private:
+      const iroha::protocol::SetAccountDetailAtPath
+          &set_account_detail_at_path_;
+    };
+
+  }  // namespace proto
+}  // namespace shared_model
+
+#endif  // IROHA_PROTO_SET_ACCOUNT_DETAIL_AT_PATH_HPP
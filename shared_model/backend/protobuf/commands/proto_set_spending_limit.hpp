@@ -0,0 +1,38 @@
+/**
+ * Copyright Soramitsu Co., Ltd. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+#ifndef IROHA_PROTO_SET_SPENDING_LIMIT_HPP
+#define IROHA_PROTO_SET_SPENDING_LIMIT_HPP
+
+#include "interfaces/commands/set_spending_limit.hpp"
+
+#include "commands.pb.h"
+#include "interfaces/common_objects/amount.hpp"
+
+namespace shared_model {
+  namespace proto {
+
+    class SetSpendingLimit final : public interface::SetSpendingLimit {
+     public:
+      explicit SetSpendingLimit(iroha::protocol::Command &command);
+
+      const interface::types::AccountIdType &accountId() const override;
+
+      const interface::types::AssetIdType &assetId() const override;
+
+      const interface::Amount &amount() const override;
+
+      interface::types::HeightType window() const override;
+
+     private:
+      const iroha::protocol::SetSpendingLimit &set_spending_limit_;
+
+      const interface::Amount amount_;
+    };
+
+  }  // namespace proto
+}  // namespace shared_model
+
+#endif  // IROHA_PROTO_SET_SPENDING_LIMIT_HPP
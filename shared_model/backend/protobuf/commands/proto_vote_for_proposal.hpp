@@ -0,0 +1,29 @@
+/**
+ * Copyright Soramitsu Co., Ltd. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+#ifndef IROHA_PROTO_VOTE_FOR_PROPOSAL_HPP
+#define IROHA_PROTO_VOTE_FOR_PROPOSAL_HPP
+
+#include "interfaces/commands/vote_for_proposal.hpp"
+
+#include "commands.pb.h"
+
+namespace shared_model {
+  namespace proto {
+
+    class VoteForProposal final : public interface::VoteForProposal {
+     public:
+      explicit VoteForProposal(iroha::protocol::Command &command);
+
+      const interface::types::ProposalIdType &proposalId() const override;
+
+     private:
+      const iroha::protocol::VoteForProposal &vote_for_proposal_;
+    };
+
+  }  // namespace proto
+}  // namespace shared_model
+
+#endif  // IROHA_PROTO_VOTE_FOR_PROPOSAL_HPP
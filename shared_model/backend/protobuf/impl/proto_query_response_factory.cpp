@@ -191,6 +191,12 @@ shared_model::proto::ProtoQueryResponseFactory::createErrorQueryResponse(
           case ErrorQueryType::kNoRoles:
             reason = iroha::protocol::ErrorResponse_Reason_NO_ROLES;
             break;
+          case ErrorQueryType::kTooExpensive:
+            reason = iroha::protocol::ErrorResponse_Reason_TOO_EXPENSIVE;
+            break;
+          case ErrorQueryType::kTimeout:
+            reason = iroha::protocol::ErrorResponse_Reason_TIMEOUT;
+            break;
         }
         iroha::protocol::ErrorResponse *protocol_specific_response =
             protocol_query_response.mutable_error_response();
@@ -242,11 +248,14 @@ shared_model::proto::ProtoQueryResponseFactory::createTransactionsPageResponse(
         transactions,
     std::optional<std::reference_wrapper<const crypto::Hash>> next_tx_hash,
     interface::types::TransactionsNumberType all_transactions_size,
+    std::vector<interface::TransactionsPageResponse::TransferFee>
+        transfer_fees,
     const crypto::Hash &query_hash) const {
   return createQueryResponse(
       [transactions = std::move(transactions),
        &next_tx_hash,
-       &all_transactions_size](
+       &all_transactions_size,
+       &transfer_fees](
           iroha::protocol::QueryResponse &protocol_query_response) {
         auto *protocol_specific_response =
             protocol_query_response.mutable_transactions_page_response();
@@ -261,6 +270,12 @@ shared_model::proto::ProtoQueryResponseFactory::createTransactionsPageResponse(
         }
         protocol_specific_response->set_all_transactions_size(
             all_transactions_size);
+        for (const auto &fee : transfer_fees) {
+          auto *protocol_fee = protocol_specific_response->add_transfer_fees();
+          protocol_fee->set_tx_hash(fee.tx_hash.hex());
+          protocol_fee->set_command_index(fee.command_index);
+          protocol_fee->set_fee(fee.fee.toStringRepr());
+        }
       },
       query_hash);
 }
@@ -371,6 +386,120 @@ shared_model::proto::ProtoQueryResponseFactory::createPeersResponse(
       query_hash);
 }
 
+std::unique_ptr<shared_model::interface::QueryResponse>
+shared_model::proto::ProtoQueryResponseFactory::createSettingsResponse(
+    interface::SettingsMapType settings,
+    std::vector<interface::SettingsResponse::SettingDescriptor> descriptors,
+    const crypto::Hash &query_hash) const {
+  return createQueryResponse(
+      [settings = std::move(settings), descriptors = std::move(descriptors)](
+          iroha::protocol::QueryResponse &protocol_query_response) mutable {
+        auto *protocol_specific_response =
+            protocol_query_response.mutable_settings_response();
+        auto *protocol_settings =
+            protocol_specific_response->mutable_settings();
+        for (auto &&[key, value] : settings) {
+          (*protocol_settings)[key] = std::move(value);
+        }
+        for (const auto &descriptor : descriptors) {
+          auto *protocol_descriptor =
+              protocol_specific_response->add_descriptors();
+          protocol_descriptor->set_key(descriptor.key);
+          protocol_descriptor->set_type(
+              static_cast<iroha::protocol::SettingDescriptor::Type>(
+                  descriptor.type));
+          protocol_descriptor->set_default_value(descriptor.default_value);
+          if (descriptor.min) {
+            protocol_descriptor->set_min(*descriptor.min);
+          }
+          if (descriptor.max) {
+            protocol_descriptor->set_max(*descriptor.max);
+          }
+        }
+      },
+      query_hash);
+}
+
+std::unique_ptr<shared_model::interface::QueryResponse>
+shared_model::proto::ProtoQueryResponseFactory::createAssetStatsResponse(
+    interface::types::AssetIdType asset_id,
+    interface::Amount minted,
+    interface::Amount burned,
+    interface::Amount circulating,
+    uint64_t holders_count,
+    const crypto::Hash &query_hash) const {
+  return createQueryResponse(
+      [asset_id = std::move(asset_id),
+       minted = std::move(minted),
+       burned = std::move(burned),
+       circulating = std::move(circulating),
+       holders_count](iroha::protocol::QueryResponse &protocol_query_response) {
+        iroha::protocol::AssetStatsResponse *protocol_specific_response =
+            protocol_query_response.mutable_asset_stats_response();
+        protocol_specific_response->set_asset_id(std::move(asset_id));
+        protocol_specific_response->set_minted(minted.toStringRepr());
+        protocol_specific_response->set_burned(burned.toStringRepr());
+        protocol_specific_response->set_circulating(
+            circulating.toStringRepr());
+        protocol_specific_response->set_holders_count(holders_count);
+      },
+      query_hash);
+}
+
+std::unique_ptr<shared_model::interface::QueryResponse>
+shared_model::proto::ProtoQueryResponseFactory::createBlobVerificationResponse(
+    bool matches, const crypto::Hash &query_hash) const {
+  return createQueryResponse(
+      [matches](iroha::protocol::QueryResponse &protocol_query_response) {
+        protocol_query_response.mutable_blob_verification_response()
+            ->set_matches(matches);
+      },
+      query_hash);
+}
+
+std::unique_ptr<shared_model::interface::QueryResponse>
+shared_model::proto::ProtoQueryResponseFactory::createFeeEstimationResponse(
+    std::vector<interface::FeeEstimationResponse::CommandFee> fees,
+    const crypto::Hash &query_hash) const {
+  return createQueryResponse(
+      [fees = std::move(fees)](
+          iroha::protocol::QueryResponse &protocol_query_response) {
+        auto *protocol_specific_response =
+            protocol_query_response.mutable_fee_estimation_response();
+        for (const auto &fee : fees) {
+          auto *protocol_fee = protocol_specific_response->add_fees();
+          protocol_fee->set_command_index(fee.command_index);
+          protocol_fee->set_asset_id(fee.asset_id);
+          protocol_fee->set_fee(fee.fee.toStringRepr());
+        }
+      },
+      query_hash);
+}
+
+std::unique_ptr<shared_model::interface::QueryResponse>
+shared_model::proto::ProtoQueryResponseFactory::createVestingSchedulesResponse(
+    std::vector<interface::VestingSchedulesResponse::Schedule> schedules,
+    const crypto::Hash &query_hash) const {
+  return createQueryResponse(
+      [schedules = std::move(schedules)](
+          iroha::protocol::QueryResponse &protocol_query_response) {
+        auto *protocol_specific_response =
+            protocol_query_response.mutable_vesting_schedules_response();
+        for (const auto &schedule : schedules) {
+          auto *protocol_schedule =
+              protocol_specific_response->add_schedules();
+          protocol_schedule->set_asset_id(schedule.asset_id);
+          protocol_schedule->set_amount(schedule.amount.toStringRepr());
+          protocol_schedule->set_start_height(schedule.start_height);
+          protocol_schedule->set_cliff(schedule.cliff);
+          protocol_schedule->set_duration(schedule.duration);
+          protocol_schedule->set_vested(schedule.vested.toStringRepr());
+          protocol_schedule->set_locked(schedule.locked.toStringRepr());
+        }
+      },
+      query_hash);
+}
+
 std::unique_ptr<shared_model::interface::QueryResponse>
 shared_model::proto::ProtoQueryResponseFactory::createEngineReceiptsResponse(
     std::vector<std::unique_ptr<shared_model::interface::EngineReceipt>> const
@@ -87,6 +87,11 @@ ProtoTxStatusFactory::FactoryReturnType ProtoTxStatusFactory::makeMstExpired(
       fillCommon(hash, tx_error, iroha::protocol::TxStatus::MST_EXPIRED));
 }
 
+ProtoTxStatusFactory::FactoryReturnType ProtoTxStatusFactory::makeExpired(
+    TransactionHashType hash, TransactionError tx_error) {
+  return wrap(fillCommon(hash, tx_error, iroha::protocol::TxStatus::EXPIRED));
+}
+
 ProtoTxStatusFactory::FactoryReturnType ProtoTxStatusFactory::makeMstPending(
     TransactionHashType hash, TransactionError tx_error) {
   return wrap(
@@ -163,6 +163,18 @@ namespace shared_model {
       return impl_->reduced_payload_.quorum();
     }
 
+    interface::types::TimestampType Transaction::notBefore() const {
+      return impl_->reduced_payload_.not_before();
+    }
+
+    const std::string &Transaction::idempotencyKey() const {
+      return impl_->reduced_payload_.idempotency_key();
+    }
+
+    const std::string &Transaction::chainId() const {
+      return impl_->reduced_payload_.chain_id();
+    }
+
     std::optional<std::shared_ptr<interface::BatchMeta>>
     Transaction::batchMeta() const {
       return impl_->meta_;
@@ -63,6 +63,8 @@ namespace shared_model {
           std::optional<std::reference_wrapper<const crypto::Hash>>
               next_tx_hash,
           interface::types::TransactionsNumberType all_transactions_size,
+          std::vector<interface::TransactionsPageResponse::TransferFee>
+              transfer_fees,
           const crypto::Hash &query_hash) const override;
 
       std::unique_ptr<interface::QueryResponse>
@@ -92,6 +94,31 @@ namespace shared_model {
           interface::types::PeerList peers,
           const crypto::Hash &query_hash) const override;
 
+      std::unique_ptr<interface::QueryResponse> createSettingsResponse(
+          interface::SettingsMapType settings,
+          std::vector<interface::SettingsResponse::SettingDescriptor>
+              descriptors,
+          const crypto::Hash &query_hash) const override;
+
+      std::unique_ptr<interface::QueryResponse> createAssetStatsResponse(
+          interface::types::AssetIdType asset_id,
+          interface::Amount minted,
+          interface::Amount burned,
+          interface::Amount circulating,
+          uint64_t holders_count,
+          const crypto::Hash &query_hash) const override;
+
+      std::unique_ptr<interface::QueryResponse> createBlobVerificationResponse(
+          bool matches, const crypto::Hash &query_hash) const override;
+
+      std::unique_ptr<interface::QueryResponse> createFeeEstimationResponse(
+          std::vector<interface::FeeEstimationResponse::CommandFee> fees,
+          const crypto::Hash &query_hash) const override;
+
+      std::unique_ptr<interface::QueryResponse> createVestingSchedulesResponse(
+          std::vector<interface::VestingSchedulesResponse::Schedule> schedules,
+          const crypto::Hash &query_hash) const override;
+
       std::unique_ptr<interface::QueryResponse> createEngineReceiptsResponse(
           const std::vector<std::unique_ptr<interface::EngineReceipt>>
               &engine_response_records,
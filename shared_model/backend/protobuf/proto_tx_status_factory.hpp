@@ -43,6 +43,9 @@ namespace shared_model {
       FactoryReturnType makeMstExpired(TransactionHashType,
                                        TransactionError) override;
 
+      FactoryReturnType makeExpired(TransactionHashType,
+                                    TransactionError) override;
+
       FactoryReturnType makeMstPending(TransactionHashType,
                                        TransactionError) override;
 
@@ -7,6 +7,25 @@
 
 #include "backend/protobuf/util.hpp"
 
+namespace {
+  /**
+   * The signed part of the query: the meta, and the replay height and the
+   * account scope if set. Queries without them are signed as before they
+   * were added.
+   */
+  shared_model::interface::types::BlobType makePayload(
+      const iroha::protocol::BlocksQuery &query) {
+    if (query.from_height() == 0 and not query.account_scoped()) {
+      return shared_model::proto::makeBlob(query.meta());
+    }
+    iroha::protocol::BlocksQuery payload;
+    *payload.mutable_meta() = query.meta();
+    payload.set_from_height(query.from_height());
+    payload.set_account_scoped(query.account_scoped());
+    return shared_model::proto::makeBlob(payload);
+  }
+}  // namespace
+
 namespace shared_model {
   namespace proto {
 
@@ -16,7 +35,7 @@ namespace shared_model {
     BlocksQuery::BlocksQuery(TransportType &&query)
         : proto_{std::move(query)},
           blob_{makeBlob(proto_)},
-          payload_{makeBlob(proto_.meta())},
+          payload_{makePayload(proto_)},
           signatures_{[this] {
             SignatureSetType<proto::Signature> set;
             if (proto_.has_signature()) {
@@ -35,6 +54,14 @@ namespace shared_model {
       return proto_.meta().query_counter();
     }
 
+    interface::types::HeightType BlocksQuery::fromHeight() const {
+      return proto_.from_height();
+    }
+
+    bool BlocksQuery::accountScoped() const {
+      return proto_.account_scoped();
+    }
+
     const interface::types::BlobType &BlocksQuery::blob() const {
       return blob_;
     }
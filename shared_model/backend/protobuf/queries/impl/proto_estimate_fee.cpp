@@ -0,0 +1,23 @@
+/**
+ * Copyright Soramitsu Co., Ltd. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+#include "backend/protobuf/queries/proto_estimate_fee.hpp"
+
+namespace shared_model {
+  namespace proto {
+
+    EstimateFee::EstimateFee(iroha::protocol::Query &query) {
+      auto &commands =
+          *query.mutable_payload()->mutable_estimate_fee()->mutable_commands();
+      commands_ = std::make_shared<const std::vector<Command>>(
+          commands.begin(), commands.end());
+    }
+
+    EstimateFee::CommandsType EstimateFee::commands() const {
+      return *commands_;
+    }
+
+  }  // namespace proto
+}  // namespace shared_model
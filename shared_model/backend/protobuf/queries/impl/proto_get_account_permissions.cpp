@@ -0,0 +1,24 @@
+/**
+ * Copyright Soramitsu Co., Ltd. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+#include "backend/protobuf/queries/proto_get_account_permissions.hpp"
+
+namespace shared_model {
+  namespace proto {
+
+    GetAccountPermissions::GetAccountPermissions(iroha::protocol::Query &query)
+        : account_permissions_{query.payload().get_account_permissions()} {}
+
+    const interface::types::AccountIdType &GetAccountPermissions::accountId()
+        const {
+      return account_permissions_.account_id();
+    }
+
+    const std::string &GetAccountPermissions::nameFilter() const {
+      return account_permissions_.name_filter();
+    }
+
+  }  // namespace proto
+}  // namespace shared_model
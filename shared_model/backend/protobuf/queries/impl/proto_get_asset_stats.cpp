@@ -0,0 +1,19 @@
+/**
+ * Copyright Soramitsu Co., Ltd. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+#include "backend/protobuf/queries/proto_get_asset_stats.hpp"
+
+namespace shared_model {
+  namespace proto {
+
+    GetAssetStats::GetAssetStats(iroha::protocol::Query &query)
+        : asset_stats_{query.payload().get_asset_stats()} {}
+
+    const interface::types::AssetIdType &GetAssetStats::assetId() const {
+      return asset_stats_.asset_id();
+    }
+
+  }  // namespace proto
+}  // namespace shared_model
@@ -0,0 +1,14 @@
+/**
+ * Copyright Soramitsu Co., Ltd. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+#include "backend/protobuf/queries/proto_get_settings.hpp"
+
+namespace shared_model {
+  namespace proto {
+
+    GetSettings::GetSettings(iroha::protocol::Query &query) {}
+
+  }  // namespace proto
+}  // namespace shared_model
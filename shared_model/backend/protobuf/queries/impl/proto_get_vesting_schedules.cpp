@@ -0,0 +1,20 @@
+/**
+ * Copyright Soramitsu Co., Ltd. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+#include "backend/protobuf/queries/proto_get_vesting_schedules.hpp"
+
+namespace shared_model {
+  namespace proto {
+
+    GetVestingSchedules::GetVestingSchedules(iroha::protocol::Query &query)
+        : vesting_schedules_{query.payload().get_vesting_schedules()} {}
+
+    const interface::types::AccountIdType &GetVestingSchedules::accountId()
+        const {
+      return vesting_schedules_.account_id();
+    }
+
+  }  // namespace proto
+}  // namespace shared_model
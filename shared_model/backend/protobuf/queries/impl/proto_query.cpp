@@ -6,20 +6,26 @@
 #include "backend/protobuf/queries/proto_query.hpp"
 
 #include "backend/protobuf/common_objects/signature.hpp"
+#include "backend/protobuf/queries/proto_estimate_fee.hpp"
 #include "backend/protobuf/queries/proto_get_account.hpp"
 #include "backend/protobuf/queries/proto_get_account_asset_transactions.hpp"
 #include "backend/protobuf/queries/proto_get_account_assets.hpp"
 #include "backend/protobuf/queries/proto_get_account_detail.hpp"
+#include "backend/protobuf/queries/proto_get_account_permissions.hpp"
 #include "backend/protobuf/queries/proto_get_account_transactions.hpp"
 #include "backend/protobuf/queries/proto_get_asset_info.hpp"
+#include "backend/protobuf/queries/proto_get_asset_stats.hpp"
 #include "backend/protobuf/queries/proto_get_block.hpp"
 #include "backend/protobuf/queries/proto_get_engine_receipts.hpp"
 #include "backend/protobuf/queries/proto_get_peers.hpp"
 #include "backend/protobuf/queries/proto_get_pending_transactions.hpp"
 #include "backend/protobuf/queries/proto_get_role_permissions.hpp"
 #include "backend/protobuf/queries/proto_get_roles.hpp"
+#include "backend/protobuf/queries/proto_get_settings.hpp"
 #include "backend/protobuf/queries/proto_get_signatories.hpp"
 #include "backend/protobuf/queries/proto_get_transactions.hpp"
+#include "backend/protobuf/queries/proto_get_vesting_schedules.hpp"
+#include "backend/protobuf/queries/proto_verify_blob.hpp"
 #include "backend/protobuf/util.hpp"
 #include "common/report_abort.h"
 
@@ -39,7 +45,13 @@ namespace {
                      shared_model::proto::GetPendingTransactions,
                      shared_model::proto::GetBlock,
                      shared_model::proto::GetPeers,
-                     shared_model::proto::GetEngineReceipts>;
+                     shared_model::proto::GetEngineReceipts,
+                     shared_model::proto::GetSettings,
+                     shared_model::proto::GetAssetStats,
+                     shared_model::proto::GetAccountPermissions,
+                     shared_model::proto::VerifyBlob,
+                     shared_model::proto::EstimateFee,
+                     shared_model::proto::GetVestingSchedules>;
 }  // namespace
 
 #ifdef IROHA_BIND_TYPE
@@ -75,6 +87,12 @@ namespace shared_model::proto {
         IROHA_BIND_TYPE(kGetBlock, GetBlock, ar);
         IROHA_BIND_TYPE(kGetPeers, GetPeers, ar);
         IROHA_BIND_TYPE(kGetEngineReceipts, GetEngineReceipts, ar);
+        IROHA_BIND_TYPE(kGetSettings, GetSettings, ar);
+        IROHA_BIND_TYPE(kGetAssetStats, GetAssetStats, ar);
+        IROHA_BIND_TYPE(kGetAccountPermissions, GetAccountPermissions, ar);
+        IROHA_BIND_TYPE(kVerifyBlob, VerifyBlob, ar);
+        IROHA_BIND_TYPE(kEstimateFee, EstimateFee, ar);
+        IROHA_BIND_TYPE(kGetVestingSchedules, GetVestingSchedules, ar);
 
         default:
         case iroha::protocol::Query_Payload::QueryCase::QUERY_NOT_SET:
@@ -0,0 +1,35 @@
+/**
+ * Copyright Soramitsu Co., Ltd. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+#include "backend/protobuf/queries/proto_verify_blob.hpp"
+
+namespace shared_model {
+  namespace proto {
+
+    VerifyBlob::VerifyBlob(iroha::protocol::Query &query)
+        : verify_blob_{query.payload().verify_blob()} {}
+
+    const interface::types::AccountIdType &VerifyBlob::accountId() const {
+      return verify_blob_.account_id();
+    }
+
+    const interface::types::AccountIdType &VerifyBlob::writer() const {
+      return verify_blob_.writer();
+    }
+
+    const interface::types::AccountDetailKeyType &VerifyBlob::key() const {
+      return verify_blob_.key();
+    }
+
+    const std::string &VerifyBlob::blob() const {
+      return verify_blob_.blob();
+    }
+
+    const std::string &VerifyBlob::salt() const {
+      return verify_blob_.salt();
+    }
+
+  }  // namespace proto
+}  // namespace shared_model
@@ -24,6 +24,10 @@ namespace shared_model {
 
       interface::types::CounterType queryCounter() const override;
 
+      interface::types::HeightType fromHeight() const override;
+
+      bool accountScoped() const override;
+
       const interface::types::BlobType &blob() const override;
 
       const interface::types::BlobType &payload() const override;
@@ -0,0 +1,34 @@
+/**
+ * Copyright Soramitsu Co., Ltd. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+#ifndef IROHA_PROTO_ESTIMATE_FEE_HPP
+#define IROHA_PROTO_ESTIMATE_FEE_HPP
+
+#include "interfaces/queries/estimate_fee.hpp"
+
+#include <memory>
+#include <vector>
+
+#include "backend/protobuf/commands/proto_command.hpp"
+#include "queries.pb.h"
+
+namespace shared_model {
+  namespace proto {
+    class EstimateFee final : public interface::EstimateFee {
+     public:
+      explicit EstimateFee(iroha::protocol::Query &query);
+
+      CommandsType commands() const override;
+
+     private:
+      // ------------------------------| fields |-------------------------------
+      // shared, as the commands are not copyable
+      std::shared_ptr<const std::vector<Command>> commands_;
+    };
+
+  }  // namespace proto
+}  // namespace shared_model
+
+#endif  // IROHA_PROTO_ESTIMATE_FEE_HPP
@@ -0,0 +1,32 @@
+/**
+ * Copyright Soramitsu Co., Ltd. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+#ifndef IROHA_PROTO_GET_ACCOUNT_PERMISSIONS_H
+#define IROHA_PROTO_GET_ACCOUNT_PERMISSIONS_H
+
+#include "interfaces/queries/get_account_permissions.hpp"
+
+#include "queries.pb.h"
+
+namespace shared_model {
+  namespace proto {
+    class GetAccountPermissions final
+        : public interface::GetAccountPermissions {
+     public:
+      explicit GetAccountPermissions(iroha::protocol::Query &query);
+
+      const interface::types::AccountIdType &accountId() const override;
+
+      const std::string &nameFilter() const override;
+
+     private:
+      // ------------------------------| fields |-------------------------------
+      const iroha::protocol::GetAccountPermissions &account_permissions_;
+    };
+
+  }  // namespace proto
+}  // namespace shared_model
+
+#endif  // IROHA_PROTO_GET_ACCOUNT_PERMISSIONS_H
@@ -0,0 +1,29 @@
+/**
+ * Copyright Soramitsu Co., Ltd. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+#ifndef IROHA_PROTO_GET_ASSET_STATS_HPP
+#define IROHA_PROTO_GET_ASSET_STATS_HPP
+
+#include "interfaces/queries/get_asset_stats.hpp"
+
+#include "queries.pb.h"
+
+namespace shared_model {
+  namespace proto {
+    class GetAssetStats final : public interface::GetAssetStats {
+     public:
+      explicit GetAssetStats(iroha::protocol::Query &query);
+
+      const interface::types::AssetIdType &assetId() const override;
+
+     private:
+      // ------------------------------| fields |-------------------------------
+      const iroha::protocol::GetAssetStats &asset_stats_;
+    };
+
+  }  // namespace proto
+}  // namespace shared_model
+
+#endif  // IROHA_PROTO_GET_ASSET_STATS_HPP
@@ -0,0 +1,23 @@
+/**
+ * Copyright Soramitsu Co., Ltd. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+#ifndef IROHA_PROTO_GET_SETTINGS_HPP
+#define IROHA_PROTO_GET_SETTINGS_HPP
+
+#include "interfaces/queries/get_settings.hpp"
+
+#include "queries.pb.h"
+
+namespace shared_model {
+  namespace proto {
+    class GetSettings final : public interface::GetSettings {
+     public:
+      explicit GetSettings(iroha::protocol::Query &query);
+    };
+
+  }  // namespace proto
+}  // namespace shared_model
+
+#endif  // IROHA_PROTO_GET_SETTINGS_HPP
@@ -0,0 +1,29 @@
+/**
+ * Copyright Soramitsu Co., Ltd. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+#ifndef IROHA_PROTO_GET_VESTING_SCHEDULES_H
+#define IROHA_PROTO_GET_VESTING_SCHEDULES_H
+
+#include "interfaces/queries/get_vesting_schedules.hpp"
+
+#include "queries.pb.h"
+
+namespace shared_model {
+  namespace proto {
+    class GetVestingSchedules final : public interface::GetVestingSchedules {
+     public:
+      explicit GetVestingSchedules(iroha::protocol::Query &query);
+
+      const interface::types::AccountIdType &accountId() const override;
+
+     private:
+      // ------------------------------| fields |-------------------------------
+      const iroha::protocol::GetVestingSchedules &vesting_schedules_;
+    };
+
+  }  // namespace proto
+}  // namespace shared_model
+
+#endif  // IROHA_PROTO_GET_VESTING_SCHEDULES_H
@@ -0,0 +1,37 @@
+/**
+ * Copyright Soramitsu Co., Ltd. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+#ifndef IROHA_PROTO_VERIFY_BLOB_HPP
+#define IROHA_PROTO_VERIFY_BLOB_HPP
+
+#include "interfaces/queries/verify_blob.hpp"
+
+#include "queries.pb.h"
+
+namespace shared_model {
+  namespace proto {
+    class VerifyBlob final : public interface::VerifyBlob {
+     public:
+      explicit VerifyBlob(iroha::protocol::Query &query);
+
+      const interface::types::AccountIdType &accountId() const override;
+
+      const interface::types::AccountIdType &writer() const override;
+
+      const interface::types::AccountDetailKeyType &key() const override;
+
+      const std::string &blob() const override;
+
+      const std::string &salt() const override;
+
+     private:
+      // ------------------------------| fields |-------------------------------
+      const iroha::protocol::VerifyBlob &verify_blob_;
+    };
+
+  }  // namespace proto
+}  // namespace shared_model
+
+#endif  // IROHA_PROTO_VERIFY_BLOB_HPP
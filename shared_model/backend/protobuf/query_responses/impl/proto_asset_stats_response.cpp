@@ -0,0 +1,39 @@
+/**
+ * Copyright Soramitsu Co., Ltd. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+#include "backend/protobuf/query_responses/proto_asset_stats_response.hpp"
+
+namespace shared_model {
+  namespace proto {
+
+    AssetStatsResponse::AssetStatsResponse(
+        iroha::protocol::QueryResponse &query_response)
+        : asset_stats_response_{query_response.asset_stats_response()},
+          minted_{asset_stats_response_.minted()},
+          burned_{asset_stats_response_.burned()},
+          circulating_{asset_stats_response_.circulating()} {}
+
+    const interface::types::AssetIdType &AssetStatsResponse::assetId() const {
+      return asset_stats_response_.asset_id();
+    }
+
+    const interface::Amount &AssetStatsResponse::minted() const {
+      return minted_;
+    }
+
+    const interface::Amount &AssetStatsResponse::burned() const {
+      return burned_;
+    }
+
+    const interface::Amount &AssetStatsResponse::circulating() const {
+      return circulating_;
+    }
+
+    uint64_t AssetStatsResponse::holdersCount() const {
+      return asset_stats_response_.holders_count();
+    }
+
+  }  // namespace proto
+}  // namespace shared_model
@@ -0,0 +1,21 @@
+/**
+ * Copyright Soramitsu Co., Ltd. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+#include "backend/protobuf/query_responses/proto_blob_verification_response.hpp"
+
+namespace shared_model {
+  namespace proto {
+
+    BlobVerificationResponse::BlobVerificationResponse(
+        iroha::protocol::QueryResponse &query_response)
+        : blob_verification_response_{
+            query_response.blob_verification_response()} {}
+
+    bool BlobVerificationResponse::matches() const {
+      return blob_verification_response_.matches();
+    }
+
+  }  // namespace proto
+}  // namespace shared_model
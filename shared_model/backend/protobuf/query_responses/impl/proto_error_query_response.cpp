@@ -20,7 +20,9 @@ namespace {
                      shared_model::proto::NoSignatoriesErrorResponse,
                      shared_model::proto::NotSupportedErrorResponse,
                      shared_model::proto::NoAssetErrorResponse,
-                     shared_model::proto::NoRolesErrorResponse>;
+                     shared_model::proto::NoRolesErrorResponse,
+                     shared_model::proto::TooExpensiveErrorResponse,
+                     shared_model::proto::TimeoutErrorResponse>;
 }  // namespace
 
 #ifdef IROHA_BIND_TYPE
@@ -53,6 +55,8 @@ namespace shared_model::proto {
               IROHA_BIND_TYPE(NOT_SUPPORTED, NotSupportedErrorResponse, ar);
               IROHA_BIND_TYPE(NO_ASSET, NoAssetErrorResponse, ar);
               IROHA_BIND_TYPE(NO_ROLES, NoRolesErrorResponse, ar);
+              IROHA_BIND_TYPE(TOO_EXPENSIVE, TooExpensiveErrorResponse, ar);
+              IROHA_BIND_TYPE(TIMEOUT, TimeoutErrorResponse, ar);
 
               default:
                 report_abort("Unexpected query error response case.");
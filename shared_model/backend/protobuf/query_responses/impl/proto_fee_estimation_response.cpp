@@ -0,0 +1,28 @@
+/**
+ * Copyright Soramitsu Co., Ltd. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+#include "backend/protobuf/query_responses/proto_fee_estimation_response.hpp"
+
+namespace shared_model {
+  namespace proto {
+
+    FeeEstimationResponse::FeeEstimationResponse(
+        iroha::protocol::QueryResponse &query_response) {
+      for (const auto &fee : query_response.fee_estimation_response().fees()) {
+        fees_.push_back(CommandFee{
+            static_cast<interface::types::CommandIndexType>(
+                fee.command_index()),
+            fee.asset_id(),
+            interface::Amount{fee.fee()}});
+      }
+    }
+
+    const std::vector<FeeEstimationResponse::CommandFee> &
+    FeeEstimationResponse::fees() const {
+      return fees_;
+    }
+
+  }  // namespace proto
+}  // namespace shared_model
@@ -9,16 +9,21 @@
 #include "backend/protobuf/query_responses/proto_account_detail_response.hpp"
 #include "backend/protobuf/query_responses/proto_account_response.hpp"
 #include "backend/protobuf/query_responses/proto_asset_response.hpp"
+#include "backend/protobuf/query_responses/proto_asset_stats_response.hpp"
+#include "backend/protobuf/query_responses/proto_blob_verification_response.hpp"
 #include "backend/protobuf/query_responses/proto_engine_receipts_response.hpp"
 #include "backend/protobuf/query_responses/proto_error_query_response.hpp"
+#include "backend/protobuf/query_responses/proto_fee_estimation_response.hpp"
 #include "backend/protobuf/query_responses/proto_get_block_response.hpp"
 #include "backend/protobuf/query_responses/proto_peers_response.hpp"
 #include "backend/protobuf/query_responses/proto_pending_transactions_page_response.hpp"
 #include "backend/protobuf/query_responses/proto_role_permissions_response.hpp"
 #include "backend/protobuf/query_responses/proto_roles_response.hpp"
+#include "backend/protobuf/query_responses/proto_settings_response.hpp"
 #include "backend/protobuf/query_responses/proto_signatories_response.hpp"
 #include "backend/protobuf/query_responses/proto_transaction_response.hpp"
 #include "backend/protobuf/query_responses/proto_transactions_page_response.hpp"
+#include "backend/protobuf/query_responses/proto_vesting_schedules_response.hpp"
 #include "common/byteutils.hpp"
 #include "common/report_abort.h"
 
@@ -38,7 +43,12 @@ namespace {
                      shared_model::proto::PendingTransactionsPageResponse,
                      shared_model::proto::GetBlockResponse,
                      shared_model::proto::PeersResponse,
-                     shared_model::proto::EngineReceiptsResponse>;
+                     shared_model::proto::EngineReceiptsResponse,
+                     shared_model::proto::SettingsResponse,
+                     shared_model::proto::AssetStatsResponse,
+                     shared_model::proto::BlobVerificationResponse,
+                     shared_model::proto::FeeEstimationResponse,
+                     shared_model::proto::VestingSchedulesResponse>;
 }  // namespace
 
 namespace shared_model::proto {
@@ -67,6 +77,11 @@ namespace shared_model::proto {
             case QueryResponse::ResponseCase::kBlockResponse: return GetBlockResponse(proto_);
             case QueryResponse::ResponseCase::kPeersResponse: return PeersResponse(proto_);
             case QueryResponse::ResponseCase::kEngineReceiptsResponse: return EngineReceiptsResponse(proto_);
+            case QueryResponse::ResponseCase::kSettingsResponse: return SettingsResponse(proto_);
+            case QueryResponse::ResponseCase::kAssetStatsResponse: return AssetStatsResponse(proto_);
+            case QueryResponse::ResponseCase::kBlobVerificationResponse: return BlobVerificationResponse(proto_);
+            case QueryResponse::ResponseCase::kFeeEstimationResponse: return FeeEstimationResponse(proto_);
+            case QueryResponse::ResponseCase::kVestingSchedulesResponse: return VestingSchedulesResponse(proto_);
             // clang-format on
             default:
             case iroha::protocol::QueryResponse::ResponseCase::RESPONSE_NOT_SET:
@@ -0,0 +1,40 @@
+/**
+ * Copyright Soramitsu Co., Ltd. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+#include "backend/protobuf/query_responses/proto_settings_response.hpp"
+
+namespace shared_model {
+  namespace proto {
+
+    SettingsResponse::SettingsResponse(
+        iroha::protocol::QueryResponse &query_response)
+        : settings_{query_response.settings_response().settings().begin(),
+                    query_response.settings_response().settings().end()} {
+      for (const auto &descriptor :
+           query_response.settings_response().descriptors()) {
+        descriptors_.push_back(SettingDescriptor{
+            descriptor.key(),
+            static_cast<SettingDescriptor::Type>(descriptor.type()),
+            descriptor.default_value(),
+            descriptor.opt_min_case() == descriptor.kMin
+                ? std::optional<uint64_t>{descriptor.min()}
+                : std::nullopt,
+            descriptor.opt_max_case() == descriptor.kMax
+                ? std::optional<uint64_t>{descriptor.max()}
+                : std::nullopt});
+      }
+    }
+
+    const interface::SettingsMapType &SettingsResponse::settings() const {
+      return settings_;
+    }
+
+    const std::vector<SettingsResponse::SettingDescriptor> &
+    SettingsResponse::descriptors() const {
+      return descriptors_;
+    }
+
+  }  // namespace proto
+}  // namespace shared_model
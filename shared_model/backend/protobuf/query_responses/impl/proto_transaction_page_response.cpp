@@ -22,7 +22,15 @@ namespace shared_model {
               default:
                 return std::nullopt;
             }
-          }()} {}
+          }()} {
+      for (const auto &fee : transactionPageResponse_.transfer_fees()) {
+        transfer_fees_.push_back(
+            TransferFee{crypto::Hash::fromHexString(fee.tx_hash()),
+                        static_cast<interface::types::CommandIndexType>(
+                            fee.command_index()),
+                        interface::Amount{fee.fee()}});
+      }
+    }
 
     interface::types::TransactionsCollectionType
     TransactionsPageResponse::transactions() const {
@@ -39,5 +47,10 @@ namespace shared_model {
       return transactionPageResponse_.all_transactions_size();
     }
 
+    const std::vector<TransactionsPageResponse::TransferFee> &
+    TransactionsPageResponse::transferFees() const {
+      return transfer_fees_;
+    }
+
   }  // namespace proto
 }  // namespace shared_model
@@ -0,0 +1,31 @@
+/**
+ * Copyright Soramitsu Co., Ltd. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+#include "backend/protobuf/query_responses/proto_vesting_schedules_response.hpp"
+
+namespace shared_model {
+  namespace proto {
+
+    VestingSchedulesResponse::VestingSchedulesResponse(
+        iroha::protocol::QueryResponse &query_response) {
+      for (const auto &schedule :
+           query_response.vesting_schedules_response().schedules()) {
+        schedules_.push_back(Schedule{schedule.asset_id(),
+                                      interface::Amount{schedule.amount()},
+                                      schedule.start_height(),
+                                      schedule.cliff(),
+                                      schedule.duration(),
+                                      interface::Amount{schedule.vested()},
+                                      interface::Amount{schedule.locked()}});
+      }
+    }
+
+    const std::vector<VestingSchedulesResponse::Schedule> &
+    VestingSchedulesResponse::schedules() const {
+      return schedules_;
+    }
+
+  }  // namespace proto
+}  // namespace shared_model
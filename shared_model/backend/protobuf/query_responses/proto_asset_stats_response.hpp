@@ -0,0 +1,40 @@
+/**
+ * Copyright Soramitsu Co., Ltd. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+#ifndef IROHA_SHARED_MODEL_PROTO_ASSET_STATS_RESPONSE_HPP
+#define IROHA_SHARED_MODEL_PROTO_ASSET_STATS_RESPONSE_HPP
+
+#include "interfaces/query_responses/asset_stats_response.hpp"
+
+#include "qry_responses.pb.h"
+
+namespace shared_model {
+  namespace proto {
+    class AssetStatsResponse final : public interface::AssetStatsResponse {
+     public:
+      explicit AssetStatsResponse(
+          iroha::protocol::QueryResponse &query_response);
+
+      const interface::types::AssetIdType &assetId() const override;
+
+      const interface::Amount &minted() const override;
+
+      const interface::Amount &burned() const override;
+
+      const interface::Amount &circulating() const override;
+
+      uint64_t holdersCount() const override;
+
+     private:
+      const iroha::protocol::AssetStatsResponse &asset_stats_response_;
+
+      const interface::Amount minted_;
+      const interface::Amount burned_;
+      const interface::Amount circulating_;
+    };
+  }  // namespace proto
+}  // namespace shared_model
+
+#endif  // IROHA_SHARED_MODEL_PROTO_ASSET_STATS_RESPONSE_HPP
@@ -0,0 +1,30 @@
+/**
+ * Copyright Soramitsu Co., Ltd. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+#ifndef IROHA_SHARED_MODEL_PROTO_BLOB_VERIFICATION_RESPONSE_HPP
+#define IROHA_SHARED_MODEL_PROTO_BLOB_VERIFICATION_RESPONSE_HPP
+
+#include "interfaces/query_responses/blob_verification_response.hpp"
+
+#include "qry_responses.pb.h"
+
+namespace shared_model {
+  namespace proto {
+    class BlobVerificationResponse final
+        : public interface::BlobVerificationResponse {
+     public:
+      explicit BlobVerificationResponse(
+          iroha::protocol::QueryResponse &query_response);
+
+      bool matches() const override;
+
+     private:
+      const iroha::protocol::BlobVerificationResponse
+          &blob_verification_response_;
+    };
+  }  // namespace proto
+}  // namespace shared_model
+
+#endif  // IROHA_SHARED_MODEL_PROTO_BLOB_VERIFICATION_RESPONSE_HPP
@@ -16,6 +16,8 @@
 #include "interfaces/query_responses/error_responses/not_supported_error_response.hpp"
 #include "interfaces/query_responses/error_responses/stateful_failed_error_response.hpp"
 #include "interfaces/query_responses/error_responses/stateless_failed_error_response.hpp"
+#include "interfaces/query_responses/error_responses/timeout_error_response.hpp"
+#include "interfaces/query_responses/error_responses/too_expensive_error_response.hpp"
 #include "qry_responses.pb.h"
 
 namespace shared_model {
@@ -44,6 +46,11 @@ namespace shared_model {
                                           iroha::protocol::ErrorResponse>;
     using NoRolesErrorResponse = ProtoRef<interface::NoRolesErrorResponse,
                                           iroha::protocol::ErrorResponse>;
+    using TooExpensiveErrorResponse =
+        ProtoRef<interface::TooExpensiveErrorResponse,
+                 iroha::protocol::ErrorResponse>;
+    using TimeoutErrorResponse = ProtoRef<interface::TimeoutErrorResponse,
+                                          iroha::protocol::ErrorResponse>;
   }  // namespace proto
 }  // namespace shared_model
 
@@ -0,0 +1,29 @@
+/**
+ * Copyright Soramitsu Co., Ltd. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+#ifndef IROHA_SHARED_MODEL_PROTO_FEE_ESTIMATION_RESPONSE_HPP
+#define IROHA_SHARED_MODEL_PROTO_FEE_ESTIMATION_RESPONSE_HPP
+
+#include "interfaces/query_responses/fee_estimation_response.hpp"
+
+#include "qry_responses.pb.h"
+
+namespace shared_model {
+  namespace proto {
+    class FeeEstimationResponse final
+        : public interface::FeeEstimationResponse {
+     public:
+      explicit FeeEstimationResponse(
+          iroha::protocol::QueryResponse &query_response);
+
+      const std::vector<CommandFee> &fees() const override;
+
+     private:
+      std::vector<CommandFee> fees_;
+    };
+  }  // namespace proto
+}  // namespace shared_model
+
+#endif  // IROHA_SHARED_MODEL_PROTO_FEE_ESTIMATION_RESPONSE_HPP
@@ -0,0 +1,30 @@
+/**
+ * Copyright Soramitsu Co., Ltd. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+#ifndef IROHA_SHARED_MODEL_PROTO_SETTINGS_RESPONSE_HPP
+#define IROHA_SHARED_MODEL_PROTO_SETTINGS_RESPONSE_HPP
+
+#include "interfaces/query_responses/settings_response.hpp"
+
+#include "qry_responses.pb.h"
+
+namespace shared_model {
+  namespace proto {
+    class SettingsResponse final : public interface::SettingsResponse {
+     public:
+      explicit SettingsResponse(iroha::protocol::QueryResponse &query_response);
+
+      const interface::SettingsMapType &settings() const override;
+
+      const std::vector<SettingDescriptor> &descriptors() const override;
+
+     private:
+      interface::SettingsMapType settings_;
+      std::vector<SettingDescriptor> descriptors_;
+    };
+  }  // namespace proto
+}  // namespace shared_model
+
+#endif  // IROHA_SHARED_MODEL_PROTO_SETTINGS_RESPONSE_HPP
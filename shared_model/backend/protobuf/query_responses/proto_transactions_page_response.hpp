@@ -28,10 +28,13 @@ namespace shared_model {
       interface::types::TransactionsNumberType allTransactionsSize()
           const override;
 
+      const std::vector<TransferFee> &transferFees() const override;
+
      private:
       const iroha::protocol::TransactionsPageResponse &transactionPageResponse_;
       std::vector<proto::Transaction> transactions_;
       std::optional<interface::types::HashType> next_hash_;
+      std::vector<TransferFee> transfer_fees_;
     };
   }  // namespace proto
 }  // namespace shared_model
@@ -0,0 +1,29 @@
+/**
+ * Copyright Soramitsu Co., Ltd. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+#ifndef IROHA_SHARED_MODEL_PROTO_VESTING_SCHEDULES_RESPONSE_HPP
+#define IROHA_SHARED_MODEL_PROTO_VESTING_SCHEDULES_RESPONSE_HPP
+
+#include "interfaces/query_responses/vesting_schedules_response.hpp"
+
+#include "qry_responses.pb.h"
+
+namespace shared_model {
+  namespace proto {
+    class VestingSchedulesResponse final
+        : public interface::VestingSchedulesResponse {
+     public:
+      explicit VestingSchedulesResponse(
+          iroha::protocol::QueryResponse &query_response);
+
+      const std::vector<Schedule> &schedules() const override;
+
+     private:
+      std::vector<Schedule> schedules_;
+    };
+  }  // namespace proto
+}  // namespace shared_model
+
+#endif  // IROHA_SHARED_MODEL_PROTO_VESTING_SCHEDULES_RESPONSE_HPP
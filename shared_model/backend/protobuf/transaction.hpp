@@ -55,6 +55,12 @@ namespace shared_model {
 
       interface::types::QuorumType quorum() const override;
 
+      interface::types::TimestampType notBefore() const override;
+
+      const std::string &idempotencyKey() const override;
+
+      const std::string &chainId() const override;
+
       std::optional<std::shared_ptr<interface::BatchMeta>> batchMeta()
           const override;
 
@@ -25,7 +25,8 @@ namespace {
                      shared_model::proto::MstExpiredResponse,
                      shared_model::proto::NotReceivedTxResponse,
                      shared_model::proto::MstPendingResponse,
-                     shared_model::proto::EnoughSignaturesCollectedResponse>;
+                     shared_model::proto::EnoughSignaturesCollectedResponse,
+                     shared_model::proto::ExpiredTxResponse>;
 
   constexpr int kMaxPriority = std::numeric_limits<int>::max();
 }  // namespace
@@ -63,6 +64,8 @@ namespace shared_model::proto {
         case TxStatus::ENOUGH_SIGNATURES_COLLECTED:
           return ProtoResponseVariantType(
               EnoughSignaturesCollectedResponse(proto_));
+        case TxStatus::EXPIRED:
+          return ProtoResponseVariantType(ExpiredTxResponse(proto_));
         default:
           report_abort("Unexpected transaction response case.");
       }
@@ -127,6 +130,7 @@ namespace shared_model::proto {
         [](const StatelessFailedTxResponse &) { return 5; },
         [](const StatefulFailedTxResponse &) { return 5; },
         [](const MstExpiredResponse &) { return 5; },
+        [](const ExpiredTxResponse &) { return 5; },
         // following types are the final ones
         [](const CommittedTxResponse &) { return kMaxPriority; },
         [](const RejectedTxResponse &) { return kMaxPriority; });
@@ -7,6 +7,7 @@
 #include "endpoint.pb.h"
 #include "interfaces/transaction_responses/committed_tx_response.hpp"
 #include "interfaces/transaction_responses/enough_signatures_collected_response.hpp"
+#include "interfaces/transaction_responses/expired_tx_response.hpp"
 #include "interfaces/transaction_responses/mst_expired_response.hpp"
 #include "interfaces/transaction_responses/mst_pending_response.hpp"
 #include "interfaces/transaction_responses/not_received_tx_response.hpp"
@@ -46,6 +47,8 @@ namespace shared_model {
 
     using MstExpiredResponse =
         ProtoRef<interface::MstExpiredResponse, iroha::protocol::ToriiResponse>;
+    using ExpiredTxResponse =
+        ProtoRef<interface::ExpiredTxResponse, iroha::protocol::ToriiResponse>;
     using NotReceivedTxResponse = ProtoRef<interface::NotReceivedTxResponse,
                                            iroha::protocol::ToriiResponse>;
     using MstPendingResponse =
@@ -96,6 +96,16 @@ namespace shared_model {
         });
       }
 
+      auto fromHeight(interface::types::HeightType height) const {
+        return transform<0>(
+            [&](auto &qry) { qry.set_from_height(height); });
+      }
+
+      auto accountScoped(bool account_scoped) const {
+        return transform<0>(
+            [&](auto &qry) { qry.set_account_scoped(account_scoped); });
+      }
+
       auto build() const {
         static_assert(S == (1 << TOTAL) - 1, "Required fields are not set");
         auto result = BlocksQuery(iroha::protocol::BlocksQuery(query_));
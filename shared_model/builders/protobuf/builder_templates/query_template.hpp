@@ -398,6 +398,61 @@ namespace shared_model {
             [&](auto proto_query) { proto_query->mutable_get_peers(); });
       }
 
+      auto getSettings() const {
+        return queryField(
+            [&](auto proto_query) { proto_query->mutable_get_settings(); });
+      }
+
+      auto getAssetStats(const interface::types::AssetIdType &asset_id) const {
+        return queryField([&](auto proto_query) {
+          auto query = proto_query->mutable_get_asset_stats();
+          query->set_asset_id(asset_id);
+        });
+      }
+
+      auto getAccountPermissions(
+          const interface::types::AccountIdType &account_id,
+          const std::string &name_filter = "") const {
+        return queryField([&](auto proto_query) {
+          auto query = proto_query->mutable_get_account_permissions();
+          query->set_account_id(account_id);
+          query->set_name_filter(name_filter);
+        });
+      }
+
+      auto verifyBlob(const interface::types::AccountIdType &account_id,
+                      const interface::types::AccountIdType &writer,
+                      const interface::types::AccountDetailKeyType &key,
+                      const std::string &blob,
+                      const std::string &salt) const {
+        return queryField([&](auto proto_query) {
+          auto query = proto_query->mutable_verify_blob();
+          query->set_account_id(account_id);
+          query->set_writer(writer);
+          query->set_key(key);
+          query->set_blob(blob);
+          query->set_salt(salt);
+        });
+      }
+
+      auto estimateFee(
+          const std::vector<iroha::protocol::Command> &commands) const {
+        return queryField([&](auto proto_query) {
+          auto query = proto_query->mutable_estimate_fee();
+          for (const auto &command : commands) {
+            *query->add_commands() = command;
+          }
+        });
+      }
+
+      auto getVestingSchedules(
+          const interface::types::AccountIdType &account_id) const {
+        return queryField([&](auto proto_query) {
+          auto query = proto_query->mutable_get_vesting_schedules();
+          query->set_account_id(account_id);
+        });
+      }
+
       auto build() const {
         static_assert(S == (1 << TOTAL) - 1, "Required fields are not set");
         if (not query_.has_payload()) {
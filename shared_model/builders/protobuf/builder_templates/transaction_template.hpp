@@ -134,6 +134,27 @@ namespace shared_model {
         });
       }
 
+      auto notBefore(interface::types::TimestampType not_before) const {
+        return transform<0>([&](auto &tx) {
+          tx.mutable_payload()->mutable_reduced_payload()->set_not_before(
+              not_before);
+        });
+      }
+
+      auto idempotencyKey(const std::string &key) const {
+        return transform<0>([&](auto &tx) {
+          tx.mutable_payload()->mutable_reduced_payload()->set_idempotency_key(
+              key);
+        });
+      }
+
+      auto chainId(const std::string &chain_id) const {
+        return transform<0>([&](auto &tx) {
+          tx.mutable_payload()->mutable_reduced_payload()->set_chain_id(
+              chain_id);
+        });
+      }
+
       auto addAssetQuantity(const interface::types::AssetIdType &asset_id,
                             const std::string &amount) const {
         return addCommand([&](auto proto_command) {
@@ -285,6 +306,16 @@ namespace shared_model {
         });
       }
 
+      auto includeRole(const interface::types::RoleIdType &role_name,
+                       const interface::types::RoleIdType &included_role_name)
+          const {
+        return addCommand([&](auto proto_command) {
+          auto command = proto_command->mutable_include_role();
+          command->set_role_name(role_name);
+          command->set_included_role_name(included_role_name);
+        });
+      }
+
       auto revokePermission(const interface::types::AccountIdType &account_id,
                             interface::permissions::Grantable permission)
           const {
@@ -307,6 +338,25 @@ namespace shared_model {
         });
       }
 
+      auto setAccountDetailAtPath(
+          const interface::types::AccountIdType &account_id,
+          const interface::types::AccountDetailKeyType &key,
+          const std::string &path,
+          const interface::types::AccountDetailValueType &value,
+          const std::optional<interface::types::AccountDetailValueType>
+              &old_value = std::nullopt) const {
+        return addCommand([&](auto proto_command) {
+          auto command = proto_command->mutable_set_account_detail_at_path();
+          command->set_account_id(account_id);
+          command->set_key(key);
+          command->set_path(path);
+          command->set_value(value);
+          if (old_value) {
+            command->set_old_value(*old_value);
+          }
+        });
+      }
+
       auto setAccountQuorum(const interface::types::AddressType &account_id,
                             interface::types::QuorumType quorum) const {
         return addCommand([&](auto proto_command) {
@@ -350,6 +400,150 @@ namespace shared_model {
         });
       }
 
+      auto proposeSettingValue(
+          const interface::types::ProposalIdType &proposal_id,
+          const interface::types::SettingKeyType &setting_key,
+          const interface::types::SettingValueType &setting_value) const {
+        return addCommand([&](auto proto_command) {
+          auto command = proto_command->mutable_propose_setting_value();
+          command->set_proposal_id(proposal_id);
+          command->set_setting_key(setting_key);
+          command->set_setting_value(setting_value);
+        });
+      }
+
+      auto voteForProposal(
+          const interface::types::ProposalIdType &proposal_id) const {
+        return addCommand([&](auto proto_command) {
+          auto command = proto_command->mutable_vote_for_proposal();
+          command->set_proposal_id(proposal_id);
+        });
+      }
+
+      auto deactivateAccount(
+          const interface::types::AccountIdType &account_id) const {
+        return addCommand([&](auto proto_command) {
+          auto command = proto_command->mutable_deactivate_account();
+          command->set_account_id(account_id);
+        });
+      }
+
+      auto recoverAccount(const interface::types::AccountIdType &account_id,
+                          interface::types::PublicKeyHexStringView public_key)
+          const {
+        return addCommand([&](auto proto_command) {
+          auto command = proto_command->mutable_recover_account();
+          command->set_account_id(account_id);
+          const std::string_view public_key_sv = public_key;
+          command->set_public_key(public_key_sv.data(), public_key_sv.size());
+        });
+      }
+
+      auto rotateKey(const interface::types::AccountIdType &account_id,
+                     interface::types::PublicKeyHexStringView old_key,
+                     interface::types::PublicKeyHexStringView new_key,
+                     interface::types::HeightType grace_period) const {
+        return addCommand([&](auto proto_command) {
+          auto command = proto_command->mutable_rotate_key();
+          command->set_account_id(account_id);
+          const std::string_view old_key_sv = old_key;
+          command->set_old_public_key(old_key_sv.data(), old_key_sv.size());
+          const std::string_view new_key_sv = new_key;
+          command->set_new_public_key(new_key_sv.data(), new_key_sv.size());
+          command->set_grace_period(grace_period);
+        });
+      }
+
+      auto lockAsset(const interface::types::AccountIdType &src_account_id,
+                     const interface::types::AccountIdType &dest_account_id,
+                     const interface::types::AssetIdType &asset_id,
+                     const std::string &amount,
+                     const std::string &hash,
+                     interface::types::HeightType timeout) const {
+        return addCommand([&](auto proto_command) {
+          auto command = proto_command->mutable_lock_asset();
+          command->set_src_account_id(src_account_id);
+          command->set_dest_account_id(dest_account_id);
+          command->set_asset_id(asset_id);
+          command->set_amount(amount);
+          command->set_hash(hash);
+          command->set_timeout(timeout);
+        });
+      }
+
+      auto claimWithPreimage(const std::string &preimage) const {
+        return addCommand([&](auto proto_command) {
+          auto command = proto_command->mutable_claim_with_preimage();
+          command->set_preimage(preimage);
+        });
+      }
+
+      auto refundAfterTimeout(const std::string &hash) const {
+        return addCommand([&](auto proto_command) {
+          auto command = proto_command->mutable_refund_after_timeout();
+          command->set_hash(hash);
+        });
+      }
+
+      auto grantVestedAsset(
+          const interface::types::AccountIdType &dest_account_id,
+          const interface::types::AssetIdType &asset_id,
+          const std::string &amount,
+          interface::types::HeightType cliff,
+          interface::types::HeightType duration) const {
+        return addCommand([&](auto proto_command) {
+          auto command = proto_command->mutable_grant_vested_asset();
+          command->set_dest_account_id(dest_account_id);
+          command->set_asset_id(asset_id);
+          command->set_amount(amount);
+          command->set_cliff(cliff);
+          command->set_duration(duration);
+        });
+      }
+
+      auto setSpendingLimit(const interface::types::AccountIdType &account_id,
+                            const interface::types::AssetIdType &asset_id,
+                            const std::string &amount,
+                            interface::types::HeightType window) const {
+        return addCommand([&](auto proto_command) {
+          auto command = proto_command->mutable_set_spending_limit();
+          command->set_account_id(account_id);
+          command->set_asset_id(asset_id);
+          command->set_amount(amount);
+          command->set_window(window);
+        });
+      }
+
+      auto authorizeSessionKey(
+          const interface::types::AccountIdType &account_id,
+          interface::types::PublicKeyHexStringView public_key,
+          const interface::RolePermissionSet &permissions,
+          const interface::types::AssetIdType &allowed_asset_id,
+          interface::types::HeightType validity) const {
+        return addCommand([&](auto proto_command) {
+          auto command = proto_command->mutable_authorize_session_key();
+          command->set_account_id(account_id);
+          const std::string_view public_key_sv = public_key;
+          command->set_public_key(public_key_sv.data(), public_key_sv.size());
+          for (size_t i = 0; i < permissions.size(); ++i) {
+            auto perm = static_cast<interface::permissions::Role>(i);
+            if (permissions.isSet(perm)) {
+              command->add_permissions(permissions::toTransport(perm));
+            }
+          }
+          command->set_allowed_asset_id(allowed_asset_id);
+          command->set_validity(validity);
+        });
+      }
+
+      auto archiveDomain(
+          const interface::types::DomainIdType &domain_id) const {
+        return addCommand([&](auto proto_command) {
+          auto command = proto_command->mutable_archive_domain();
+          command->set_domain_id(domain_id);
+        });
+      }
+
       auto build() const {
         static_assert(S == (1 << TOTAL) - 1, "Required fields are not set");
         auto result = Transaction(iroha::protocol::Transaction(transaction_));
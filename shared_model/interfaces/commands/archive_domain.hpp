@@ -0,0 +1,32 @@
+/**
+ * Copyright Soramitsu Co., Ltd. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+#ifndef IROHA_SHARED_MODEL_ARCHIVE_DOMAIN_HPP
+#define IROHA_SHARED_MODEL_ARCHIVE_DOMAIN_HPP
+
+#include "interfaces/base/model_primitive.hpp"
+
+#include "interfaces/common_objects/types.hpp"
+
+namespace shared_model {
+  namespace interface {
+    /**
+     * Freeze the domain, keeping its accounts, assets and history readable
+     */
+    class ArchiveDomain : public ModelPrimitive<ArchiveDomain> {
+     public:
+      /**
+       * @return Id of the domain to archive
+       */
+      virtual const types::DomainIdType &domainId() const = 0;
+
+      std::string toString() const override;
+
+      bool operator==(const ModelType &rhs) const override;
+    };
+  }  // namespace interface
+}  // namespace shared_model
+
+#endif  // IROHA_SHARED_MODEL_ARCHIVE_DOMAIN_HPP
@@ -0,0 +1,51 @@
+/**
+ * Copyright Soramitsu Co., Ltd. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+#ifndef IROHA_SHARED_MODEL_AUTHORIZE_SESSION_KEY_HPP
+#define IROHA_SHARED_MODEL_AUTHORIZE_SESSION_KEY_HPP
+
+#include "interfaces/base/model_primitive.hpp"
+
+#include "interfaces/common_objects/types.hpp"
+#include "interfaces/permissions.hpp"
+
+namespace shared_model {
+  namespace interface {
+    /**
+     * Authorize a key which is not a signatory of the account to sign its
+     * transactions with a limited set of commands for a number of blocks
+     */
+    class AuthorizeSessionKey : public ModelPrimitive<AuthorizeSessionKey> {
+     public:
+      /**
+       * @return Id of the account the key signs for
+       */
+      virtual const types::AccountIdType &accountId() const = 0;
+      /**
+       * @return Session key
+       */
+      virtual const std::string &pubkey() const = 0;
+      /**
+       * @return Permissions of the commands the key may sign
+       */
+      virtual const RolePermissionSet &permissions() const = 0;
+      /**
+       * @return Id of the only asset the key may spend, empty for any asset
+       */
+      virtual const types::AssetIdType &allowedAssetId() const = 0;
+      /**
+       * @return Number of blocks after the authorization in which the key is
+       * accepted, zero revokes the key
+       */
+      virtual types::HeightType validity() const = 0;
+
+      std::string toString() const override = 0;
+
+      bool operator==(const ModelType &rhs) const override;
+    };
+  }  // namespace interface
+}  // namespace shared_model
+
+#endif  // IROHA_SHARED_MODEL_AUTHORIZE_SESSION_KEY_HPP
@@ -0,0 +1,33 @@
+/**
+ * Copyright Soramitsu Co., Ltd. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+#ifndef IROHA_SHARED_MODEL_CLAIM_WITH_PREIMAGE_HPP
+#define IROHA_SHARED_MODEL_CLAIM_WITH_PREIMAGE_HPP
+
+#include "interfaces/base/model_primitive.hpp"
+
+#include "interfaces/common_objects/types.hpp"
+
+namespace shared_model {
+  namespace interface {
+    /**
+     * Transfer assets of the lock with the hash of the preimage to its
+     * recipient
+     */
+    class ClaimWithPreimage : public ModelPrimitive<ClaimWithPreimage> {
+     public:
+      /**
+       * @return hex of the preimage of the lock hash
+       */
+      virtual const std::string &preimage() const = 0;
+
+      std::string toString() const override;
+
+      bool operator==(const ModelType &rhs) const override;
+    };
+  }  // namespace interface
+}  // namespace shared_model
+
+#endif  // IROHA_SHARED_MODEL_CLAIM_WITH_PREIMAGE_HPP
@@ -17,20 +17,34 @@ namespace shared_model {
     class AddPeer;
     class AddSignatory;
     class AppendRole;
+    class ArchiveDomain;
+    class AuthorizeSessionKey;
     class CallEngine;
+    class ClaimWithPreimage;
     class CreateAccount;
     class CreateAsset;
     class CreateDomain;
     class CreateRole;
+    class DeactivateAccount;
     class DetachRole;
     class GrantPermission;
+    class GrantVestedAsset;
+    class IncludeRole;
+    class LockAsset;
+    class ProposeSettingValue;
+    class RecoverAccount;
+    class RefundAfterTimeout;
     class RemovePeer;
     class RemoveSignatory;
     class RevokePermission;
+    class RotateKey;
     class SetAccountDetail;
+    class SetAccountDetailAtPath;
     class SetQuorum;
+    class SetSpendingLimit;
     class SubtractAssetQuantity;
     class TransferAsset;
+    class VoteForProposal;
     class CompareAndSetAccountDetail;
     class SetSettingValue;
 
@@ -50,22 +64,36 @@ namespace shared_model {
                                       AddPeer,
                                       AddSignatory,
                                       AppendRole,
+                                      ArchiveDomain,
+                                      AuthorizeSessionKey,
                                       CallEngine,
+                                      ClaimWithPreimage,
                                       CompareAndSetAccountDetail,
                                       CreateAccount,
                                       CreateAsset,
                                       CreateDomain,
                                       CreateRole,
+                                      DeactivateAccount,
                                       DetachRole,
                                       GrantPermission,
+                                      GrantVestedAsset,
+                                      IncludeRole,
+                                      LockAsset,
+                                      ProposeSettingValue,
+                                      RecoverAccount,
+                                      RefundAfterTimeout,
                                       RemovePeer,
                                       RemoveSignatory,
                                       RevokePermission,
+                                      RotateKey,
                                       SetAccountDetail,
+                                      SetAccountDetailAtPath,
                                       SetQuorum,
                                       SetSettingValue,
+                                      SetSpendingLimit,
                                       SubtractAssetQuantity,
-                                      TransferAsset>;
+                                      TransferAsset,
+                                      VoteForProposal>;
 
       /**
        * @return reference to const variant with concrete command
@@ -16,21 +16,35 @@ namespace boost {
       const shared_model::interface::AddPeer &,
       const shared_model::interface::AddSignatory &,
       const shared_model::interface::AppendRole &,
+      const shared_model::interface::ArchiveDomain &,
+      const shared_model::interface::AuthorizeSessionKey &,
       const shared_model::interface::CallEngine &,
+      const shared_model::interface::ClaimWithPreimage &,
       const shared_model::interface::CompareAndSetAccountDetail &,
       const shared_model::interface::CreateAccount &,
       const shared_model::interface::CreateAsset &,
       const shared_model::interface::CreateDomain &,
       const shared_model::interface::CreateRole &,
+      const shared_model::interface::DeactivateAccount &,
       const shared_model::interface::DetachRole &,
       const shared_model::interface::GrantPermission &,
+      const shared_model::interface::GrantVestedAsset &,
+      const shared_model::interface::IncludeRole &,
+      const shared_model::interface::LockAsset &,
+      const shared_model::interface::ProposeSettingValue &,
+      const shared_model::interface::RecoverAccount &,
+      const shared_model::interface::RefundAfterTimeout &,
       const shared_model::interface::RemoveSignatory &,
       const shared_model::interface::RevokePermission &,
+      const shared_model::interface::RotateKey &,
       const shared_model::interface::SetAccountDetail &,
+      const shared_model::interface::SetAccountDetailAtPath &,
       const shared_model::interface::SetQuorum &,
       const shared_model::interface::SetSettingValue &,
+      const shared_model::interface::SetSpendingLimit &,
       const shared_model::interface::SubtractAssetQuantity &,
-      const shared_model::interface::TransferAsset &>;
+      const shared_model::interface::TransferAsset &,
+      const shared_model::interface::VoteForProposal &>;
 }  // namespace boost
 
 #endif  // IROHA_SHARED_MODEL_COMMAND_VARIANT_HPP
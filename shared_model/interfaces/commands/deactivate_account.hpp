@@ -0,0 +1,32 @@
+/**
+ * Copyright Soramitsu Co., Ltd. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+#ifndef IROHA_SHARED_MODEL_DEACTIVATE_ACCOUNT_HPP
+#define IROHA_SHARED_MODEL_DEACTIVATE_ACCOUNT_HPP
+
+#include "interfaces/base/model_primitive.hpp"
+
+#include "interfaces/common_objects/types.hpp"
+
+namespace shared_model {
+  namespace interface {
+    /**
+     * Forbid the account to create transactions, keeping its assets
+     */
+    class DeactivateAccount : public ModelPrimitive<DeactivateAccount> {
+     public:
+      /**
+       * @return Id of the account to deactivate
+       */
+      virtual const types::AccountIdType &accountId() const = 0;
+
+      std::string toString() const override;
+
+      bool operator==(const ModelType &rhs) const override;
+    };
+  }  // namespace interface
+}  // namespace shared_model
+
+#endif  // IROHA_SHARED_MODEL_DEACTIVATE_ACCOUNT_HPP
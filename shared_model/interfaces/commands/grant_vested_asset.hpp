@@ -0,0 +1,52 @@
+/**
+ * Copyright Soramitsu Co., Ltd. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+#ifndef IROHA_SHARED_MODEL_GRANT_VESTED_ASSET_HPP
+#define IROHA_SHARED_MODEL_GRANT_VESTED_ASSET_HPP
+
+#include "interfaces/base/model_primitive.hpp"
+
+#include "interfaces/common_objects/amount.hpp"
+#include "interfaces/common_objects/types.hpp"
+
+namespace shared_model {
+  namespace interface {
+    /**
+     * Issue assets to the account under a vesting schedule: nothing can be
+     * spent before the cliff, then the amount is released linearly until the
+     * end of the schedule
+     */
+    class GrantVestedAsset : public ModelPrimitive<GrantVestedAsset> {
+     public:
+      /**
+       * @return Id of the account which receives the assets
+       */
+      virtual const types::AccountIdType &destAccountId() const = 0;
+      /**
+       * @return Id of the asset to issue
+       */
+      virtual const types::AssetIdType &assetId() const = 0;
+      /**
+       * @return asset amount to issue
+       */
+      virtual const Amount &amount() const = 0;
+      /**
+       * @return Number of blocks after the grant before the first release
+       */
+      virtual types::HeightType cliff() const = 0;
+      /**
+       * @return Number of blocks after the grant until the whole amount is
+       * released
+       */
+      virtual types::HeightType duration() const = 0;
+
+      std::string toString() const override;
+
+      bool operator==(const ModelType &rhs) const override;
+    };
+  }  // namespace interface
+}  // namespace shared_model
+
+#endif  // IROHA_SHARED_MODEL_GRANT_VESTED_ASSET_HPP
@@ -0,0 +1,23 @@
+/**
+ * Copyright Soramitsu Co., Ltd. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+#include "interfaces/commands/archive_domain.hpp"
+
+namespace shared_model {
+  namespace interface {
+
+    std::string ArchiveDomain::toString() const {
+      return detail::PrettyStringBuilder()
+          .init("ArchiveDomain")
+          .appendNamed("domain_id", domainId())
+          .finalize();
+    }
+
+    bool ArchiveDomain::operator==(const ModelType &rhs) const {
+      return domainId() == rhs.domainId();
+    }
+
+  }  // namespace interface
+}  // namespace shared_model
@@ -0,0 +1,19 @@
+/**
+ * Copyright Soramitsu Co., Ltd. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+#include "interfaces/commands/authorize_session_key.hpp"
+
+namespace shared_model {
+  namespace interface {
+
+    bool AuthorizeSessionKey::operator==(const ModelType &rhs) const {
+      return accountId() == rhs.accountId() and pubkey() == rhs.pubkey()
+          and permissions() == rhs.permissions()
+          and allowedAssetId() == rhs.allowedAssetId()
+          and validity() == rhs.validity();
+    }
+
+  }  // namespace interface
+}  // namespace shared_model
@@ -0,0 +1,23 @@
+/**
+ * Copyright Soramitsu Co., Ltd. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+#include "interfaces/commands/claim_with_preimage.hpp"
+
+namespace shared_model {
+  namespace interface {
+
+    std::string ClaimWithPreimage::toString() const {
+      return detail::PrettyStringBuilder()
+          .init("ClaimWithPreimage")
+          .appendNamed("preimage", preimage())
+          .finalize();
+    }
+
+    bool ClaimWithPreimage::operator==(const ModelType &rhs) const {
+      return preimage() == rhs.preimage();
+    }
+
+  }  // namespace interface
+}  // namespace shared_model
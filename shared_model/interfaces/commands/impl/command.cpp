@@ -9,22 +9,36 @@
 #include "interfaces/commands/add_peer.hpp"
 #include "interfaces/commands/add_signatory.hpp"
 #include "interfaces/commands/append_role.hpp"
+#include "interfaces/commands/archive_domain.hpp"
+#include "interfaces/commands/authorize_session_key.hpp"
 #include "interfaces/commands/call_engine.hpp"
+#include "interfaces/commands/claim_with_preimage.hpp"
 #include "interfaces/commands/compare_and_set_account_detail.hpp"
 #include "interfaces/commands/create_account.hpp"
 #include "interfaces/commands/create_asset.hpp"
 #include "interfaces/commands/create_domain.hpp"
 #include "interfaces/commands/create_role.hpp"
+#include "interfaces/commands/deactivate_account.hpp"
 #include "interfaces/commands/detach_role.hpp"
 #include "interfaces/commands/grant_permission.hpp"
+#include "interfaces/commands/grant_vested_asset.hpp"
+#include "interfaces/commands/include_role.hpp"
+#include "interfaces/commands/lock_asset.hpp"
+#include "interfaces/commands/propose_setting_value.hpp"
+#include "interfaces/commands/recover_account.hpp"
+#include "interfaces/commands/refund_after_timeout.hpp"
 #include "interfaces/commands/remove_peer.hpp"
 #include "interfaces/commands/remove_signatory.hpp"
 #include "interfaces/commands/revoke_permission.hpp"
+#include "interfaces/commands/rotate_key.hpp"
 #include "interfaces/commands/set_account_detail.hpp"
+#include "interfaces/commands/set_account_detail_at_path.hpp"
 #include "interfaces/commands/set_quorum.hpp"
 #include "interfaces/commands/set_setting_value.hpp"
+#include "interfaces/commands/set_spending_limit.hpp"
 #include "interfaces/commands/subtract_asset_quantity.hpp"
 #include "interfaces/commands/transfer_asset.hpp"
+#include "interfaces/commands/vote_for_proposal.hpp"
 #include "utils/visitor_apply_for_all.hpp"
 
 using Variant = shared_model::interface::Command::CommandVariantType;
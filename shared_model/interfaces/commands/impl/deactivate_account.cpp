@@ -0,0 +1,23 @@
+/**
+ * Copyright Soramitsu Co., Ltd. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+#include "interfaces/commands/deactivate_account.hpp"
+
+namespace shared_model {
+  namespace interface {
+
+    std::string DeactivateAccount::toString() const {
+      return detail::PrettyStringBuilder()
+          .init("DeactivateAccount")
+          .appendNamed("account_id", accountId())
+          .finalize();
+    }
+
+    bool DeactivateAccount::operator==(const ModelType &rhs) const {
+      return accountId() == rhs.accountId();
+    }
+
+  }  // namespace interface
+}  // namespace shared_model
@@ -0,0 +1,29 @@
+/**
+ * Copyright Soramitsu Co., Ltd. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+#include "interfaces/commands/grant_vested_asset.hpp"
+
+namespace shared_model {
+  namespace interface {
+
+    std::string GrantVestedAsset::toString() const {
+      return detail::PrettyStringBuilder()
+          .init("GrantVestedAsset")
+          .appendNamed("dest_account_id", destAccountId())
+          .appendNamed("asset_id", assetId())
+          .appendNamed("amount", amount())
+          .appendNamed("cliff", cliff())
+          .appendNamed("duration", duration())
+          .finalize();
+    }
+
+    bool GrantVestedAsset::operator==(const ModelType &rhs) const {
+      return destAccountId() == rhs.destAccountId()
+          and assetId() == rhs.assetId() and amount() == rhs.amount()
+          and cliff() == rhs.cliff() and duration() == rhs.duration();
+    }
+
+  }  // namespace interface
+}  // namespace shared_model
@@ -0,0 +1,25 @@
+/**
+ * Copyright Soramitsu Co., Ltd. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+#include "interfaces/commands/include_role.hpp"
+
+namespace shared_model {
+  namespace interface {
+
+    std::string IncludeRole::toString() const {
+      return detail::PrettyStringBuilder()
+          .init("IncludeRole")
+          .appendNamed("role_name", roleName())
+          .appendNamed("included_role_name", includedRoleName())
+          .finalize();
+    }
+
+    bool IncludeRole::operator==(const ModelType &rhs) const {
+      return roleName() == rhs.roleName()
+          and includedRoleName() == rhs.includedRoleName();
+    }
+
+  }  // namespace interface
+}  // namespace shared_model
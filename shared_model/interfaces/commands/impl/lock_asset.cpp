@@ -0,0 +1,31 @@
+/**
+ * Copyright Soramitsu Co., Ltd. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+#include "interfaces/commands/lock_asset.hpp"
+
+namespace shared_model {
+  namespace interface {
+
+    std::string LockAsset::toString() const {
+      return detail::PrettyStringBuilder()
+          .init("LockAsset")
+          .appendNamed("src_account_id", srcAccountId())
+          .appendNamed("dest_account_id", destAccountId())
+          .appendNamed("asset_id", assetId())
+          .appendNamed("amount", amount())
+          .appendNamed("hash", hash())
+          .appendNamed("timeout", timeout())
+          .finalize();
+    }
+
+    bool LockAsset::operator==(const ModelType &rhs) const {
+      return srcAccountId() == rhs.srcAccountId()
+          and destAccountId() == rhs.destAccountId()
+          and assetId() == rhs.assetId() and amount() == rhs.amount()
+          and hash() == rhs.hash() and timeout() == rhs.timeout();
+    }
+
+  }  // namespace interface
+}  // namespace shared_model
@@ -0,0 +1,27 @@
+/**
+ * Copyright Soramitsu Co., Ltd. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+#include "interfaces/commands/propose_setting_value.hpp"
+
+namespace shared_model {
+  namespace interface {
+
+    std::string ProposeSettingValue::toString() const {
+      return detail::PrettyStringBuilder()
+          .init("ProposeSettingValue")
+          .appendNamed("proposal_id", proposalId())
+          .appendNamed("setting_key", settingKey())
+          .appendNamed("setting_value", settingValue())
+          .finalize();
+    }
+
+    bool ProposeSettingValue::operator==(const ModelType &rhs) const {
+      return proposalId() == rhs.proposalId()
+          and settingKey() == rhs.settingKey()
+          and settingValue() == rhs.settingValue();
+    }
+
+  }  // namespace interface
+}  // namespace shared_model
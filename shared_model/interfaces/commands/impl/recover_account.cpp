@@ -0,0 +1,24 @@
+/**
+ * Copyright Soramitsu Co., Ltd. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+#include "interfaces/commands/recover_account.hpp"
+
+namespace shared_model {
+  namespace interface {
+
+    std::string RecoverAccount::toString() const {
+      return detail::PrettyStringBuilder()
+          .init("RecoverAccount")
+          .appendNamed("account_id", accountId())
+          .appendNamed("public_key", pubkey())
+          .finalize();
+    }
+
+    bool RecoverAccount::operator==(const ModelType &rhs) const {
+      return accountId() == rhs.accountId() and pubkey() == rhs.pubkey();
+    }
+
+  }  // namespace interface
+}  // namespace shared_model
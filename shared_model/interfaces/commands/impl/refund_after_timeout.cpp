@@ -0,0 +1,23 @@
+/**
+ * Copyright Soramitsu Co., Ltd. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+#include "interfaces/commands/refund_after_timeout.hpp"
+
+namespace shared_model {
+  namespace interface {
+
+    std::string RefundAfterTimeout::toString() const {
+      return detail::PrettyStringBuilder()
+          .init("RefundAfterTimeout")
+          .appendNamed("hash", hash())
+          .finalize();
+    }
+
+    bool RefundAfterTimeout::operator==(const ModelType &rhs) const {
+      return hash() == rhs.hash();
+    }
+
+  }  // namespace interface
+}  // namespace shared_model
@@ -0,0 +1,28 @@
+/**
+ * Copyright Soramitsu Co., Ltd. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+#include "interfaces/commands/rotate_key.hpp"
+
+namespace shared_model {
+  namespace interface {
+
+    std::string RotateKey::toString() const {
+      return detail::PrettyStringBuilder()
+          .init("RotateKey")
+          .appendNamed("account_id", accountId())
+          .appendNamed("old_public_key", oldPubkey())
+          .appendNamed("new_public_key", newPubkey())
+          .appendNamed("grace_period", gracePeriod())
+          .finalize();
+    }
+
+    bool RotateKey::operator==(const ModelType &rhs) const {
+      return accountId() == rhs.accountId() and oldPubkey() == rhs.oldPubkey()
+          and newPubkey() == rhs.newPubkey()
+          and gracePeriod() == rhs.gracePeriod();
+    }
+
+  }  // namespace interface
+}  // namespace shared_model
@@ -0,0 +1,29 @@
+/**
+ * Copyright Soramitsu Co., Ltd. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+#include "interfaces/commands/set_account_detail_at_path.hpp"
+
+namespace shared_model {
+  namespace interface {
+
+    std::string SetAccountDetailAtPath::toString() const {
+      return detail::PrettyStringBuilder()
+          .init("SetAccountDetailAtPath")
+          .appendNamed("account_id", accountId())
+          .appendNamed("key", key())
+          .appendNamed("path", path())
+          .appendNamed("value", value())
+          .appendNamed("old_value", oldValue())
+          .finalize();
+    }
+
+    bool SetAccountDetailAtPath::operator==(const ModelType &rhs) const {
+      return accountId() == rhs.accountId() and key() == rhs.key()
+          and path() == rhs.path() and value() == rhs.value()
+          and oldValue() == rhs.oldValue();
+    }
+
+  }  // namespace interface
+}  // namespace shared_model
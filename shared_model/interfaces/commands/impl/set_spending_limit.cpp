@@ -0,0 +1,27 @@
+/**
+ * Copyright Soramitsu Co., Ltd. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+#include "interfaces/commands/set_spending_limit.hpp"
+
+namespace shared_model {
+  namespace interface {
+
+    std::string SetSpendingLimit::toString() const {
+      return detail::PrettyStringBuilder()
+          .init("SetSpendingLimit")
+          .appendNamed("account_id", accountId())
+          .appendNamed("asset_id", assetId())
+          .appendNamed("amount", amount())
+          .appendNamed("window", window())
+          .finalize();
+    }
+
+    bool SetSpendingLimit::operator==(const ModelType &rhs) const {
+      return accountId() == rhs.accountId() and assetId() == rhs.assetId()
+          and amount() == rhs.amount() and window() == rhs.window();
+    }
+
+  }  // namespace interface
+}  // namespace shared_model
@@ -0,0 +1,23 @@
+/**
+ * Copyright Soramitsu Co., Ltd. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+#include "interfaces/commands/vote_for_proposal.hpp"
+
+namespace shared_model {
+  namespace interface {
+
+    std::string VoteForProposal::toString() const {
+      return detail::PrettyStringBuilder()
+          .init("VoteForProposal")
+          .appendNamed("proposal_id", proposalId())
+          .finalize();
+    }
+
+    bool VoteForProposal::operator==(const ModelType &rhs) const {
+      return proposalId() == rhs.proposalId();
+    }
+
+  }  // namespace interface
+}  // namespace shared_model
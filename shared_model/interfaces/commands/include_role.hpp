@@ -0,0 +1,37 @@
+/**
+ * Copyright Soramitsu Co., Ltd. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+#ifndef IROHA_SHARED_MODEL_INCLUDE_ROLE_HPP
+#define IROHA_SHARED_MODEL_INCLUDE_ROLE_HPP
+
+#include "interfaces/base/model_primitive.hpp"
+
+#include "interfaces/common_objects/types.hpp"
+
+namespace shared_model {
+  namespace interface {
+
+    /**
+     * Include permissions of one role into another role
+     */
+    class IncludeRole : public ModelPrimitive<IncludeRole> {
+     public:
+      /**
+       * @return Role which receives the permissions
+       */
+      virtual const types::RoleIdType &roleName() const = 0;
+      /**
+       * @return Role which permissions are included
+       */
+      virtual const types::RoleIdType &includedRoleName() const = 0;
+
+      std::string toString() const override;
+
+      bool operator==(const ModelType &rhs) const override;
+    };
+  }  // namespace interface
+}  // namespace shared_model
+
+#endif  // IROHA_SHARED_MODEL_INCLUDE_ROLE_HPP
@@ -0,0 +1,55 @@
+/**
+ * Copyright Soramitsu Co., Ltd. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+#ifndef IROHA_SHARED_MODEL_LOCK_ASSET_HPP
+#define IROHA_SHARED_MODEL_LOCK_ASSET_HPP
+
+#include "interfaces/base/model_primitive.hpp"
+
+#include "interfaces/common_objects/amount.hpp"
+#include "interfaces/common_objects/types.hpp"
+
+namespace shared_model {
+  namespace interface {
+    /**
+     * Lock assets of the account in a hashed timelock: they are transferred to
+     * the recipient by the preimage of the hash, or returned to the source
+     * account after the timeout
+     */
+    class LockAsset : public ModelPrimitive<LockAsset> {
+     public:
+      /**
+       * @return Id of the account from which assets are locked
+       */
+      virtual const types::AccountIdType &srcAccountId() const = 0;
+      /**
+       * @return Id of the account which can claim the locked assets
+       */
+      virtual const types::AccountIdType &destAccountId() const = 0;
+      /**
+       * @return Id of the asset to lock
+       */
+      virtual const types::AssetIdType &assetId() const = 0;
+      /**
+       * @return asset amount to lock
+       */
+      virtual const Amount &amount() const = 0;
+      /**
+       * @return hex of the SHA3-256 hash of the preimage, identifies the lock
+       */
+      virtual const std::string &hash() const = 0;
+      /**
+       * @return Number of blocks after the lock in which it can be claimed
+       */
+      virtual types::HeightType timeout() const = 0;
+
+      std::string toString() const override;
+
+      bool operator==(const ModelType &rhs) const override;
+    };
+  }  // namespace interface
+}  // namespace shared_model
+
+#endif  // IROHA_SHARED_MODEL_LOCK_ASSET_HPP
@@ -0,0 +1,42 @@
+/**
+ * Copyright Soramitsu Co., Ltd. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+#ifndef IROHA_SHARED_MODEL_PROPOSE_SETTING_VALUE_HPP
+#define IROHA_SHARED_MODEL_PROPOSE_SETTING_VALUE_HPP
+
+#include "interfaces/base/model_primitive.hpp"
+
+#include "interfaces/common_objects/types.hpp"
+
+namespace shared_model {
+  namespace interface {
+
+    /**
+     * Propose a change of a ledger setting to be voted on by the governance
+     * accounts
+     */
+    class ProposeSettingValue : public ModelPrimitive<ProposeSettingValue> {
+     public:
+      /**
+       * @return Id of the created proposal
+       */
+      virtual const types::ProposalIdType &proposalId() const = 0;
+      /**
+       * @return Key of the setting to change
+       */
+      virtual const types::SettingKeyType &settingKey() const = 0;
+      /**
+       * @return Value the setting gets when the proposal passes
+       */
+      virtual const types::SettingValueType &settingValue() const = 0;
+
+      std::string toString() const override;
+
+      bool operator==(const ModelType &rhs) const override;
+    };
+  }  // namespace interface
+}  // namespace shared_model
+
+#endif  // IROHA_SHARED_MODEL_PROPOSE_SETTING_VALUE_HPP
@@ -0,0 +1,36 @@
+/**
+ * Copyright Soramitsu Co., Ltd. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+#ifndef IROHA_SHARED_MODEL_RECOVER_ACCOUNT_HPP
+#define IROHA_SHARED_MODEL_RECOVER_ACCOUNT_HPP
+
+#include "interfaces/base/model_primitive.hpp"
+
+#include "interfaces/common_objects/types.hpp"
+
+namespace shared_model {
+  namespace interface {
+    /**
+     * Replace all signatories of the account with a single key and activate it
+     */
+    class RecoverAccount : public ModelPrimitive<RecoverAccount> {
+     public:
+      /**
+       * @return Id of the account to recover
+       */
+      virtual const types::AccountIdType &accountId() const = 0;
+      /**
+       * @return Public key to become the only signatory of the account
+       */
+      virtual const std::string &pubkey() const = 0;
+
+      std::string toString() const override;
+
+      bool operator==(const ModelType &rhs) const override;
+    };
+  }  // namespace interface
+}  // namespace shared_model
+
+#endif  // IROHA_SHARED_MODEL_RECOVER_ACCOUNT_HPP
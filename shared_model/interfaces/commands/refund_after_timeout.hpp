@@ -0,0 +1,32 @@
+/**
+ * Copyright Soramitsu Co., Ltd. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+#ifndef IROHA_SHARED_MODEL_REFUND_AFTER_TIMEOUT_HPP
+#define IROHA_SHARED_MODEL_REFUND_AFTER_TIMEOUT_HPP
+
+#include "interfaces/base/model_primitive.hpp"
+
+#include "interfaces/common_objects/types.hpp"
+
+namespace shared_model {
+  namespace interface {
+    /**
+     * Return assets of the timed out lock to its source account
+     */
+    class RefundAfterTimeout : public ModelPrimitive<RefundAfterTimeout> {
+     public:
+      /**
+       * @return hex of the hash identifying the lock
+       */
+      virtual const std::string &hash() const = 0;
+
+      std::string toString() const override;
+
+      bool operator==(const ModelType &rhs) const override;
+    };
+  }  // namespace interface
+}  // namespace shared_model
+
+#endif  // IROHA_SHARED_MODEL_REFUND_AFTER_TIMEOUT_HPP
@@ -0,0 +1,46 @@
+/**
+ * Copyright Soramitsu Co., Ltd. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+#ifndef IROHA_SHARED_MODEL_ROTATE_KEY_HPP
+#define IROHA_SHARED_MODEL_ROTATE_KEY_HPP
+
+#include "interfaces/base/model_primitive.hpp"
+
+#include "interfaces/common_objects/types.hpp"
+
+namespace shared_model {
+  namespace interface {
+    /**
+     * Replace a signatory of the account with another key, optionally
+     * accepting the old key for a number of blocks after the rotation
+     */
+    class RotateKey : public ModelPrimitive<RotateKey> {
+     public:
+      /**
+       * @return Id of the account to rotate the key of
+       */
+      virtual const types::AccountIdType &accountId() const = 0;
+      /**
+       * @return Signatory to be replaced
+       */
+      virtual const std::string &oldPubkey() const = 0;
+      /**
+       * @return Signatory to replace the old one with
+       */
+      virtual const std::string &newPubkey() const = 0;
+      /**
+       * @return Number of blocks after the rotation in which transactions
+       * signed with the old key are still accepted
+       */
+      virtual types::HeightType gracePeriod() const = 0;
+
+      std::string toString() const override;
+
+      bool operator==(const ModelType &rhs) const override;
+    };
+  }  // namespace interface
+}  // namespace shared_model
+
+#endif  // IROHA_SHARED_MODEL_ROTATE_KEY_HPP
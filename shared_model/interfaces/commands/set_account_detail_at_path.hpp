@@ -0,0 +1,58 @@
+/**
+ * Copyright Soramitsu Co., Ltd. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+#ifndef IROHA_SHARED_MODEL_SET_ACCOUNT_DETAIL_AT_PATH_HPP
+#define IROHA_SHARED_MODEL_SET_ACCOUNT_DETAIL_AT_PATH_HPP
+
+#include <optional>
+#include "interfaces/base/model_primitive.hpp"
+
+#include "interfaces/common_objects/types.hpp"
+
+namespace shared_model {
+  namespace interface {
+
+    /**
+     * Set a JSON value at the path inside of the account detail, which holds
+     * a JSON object, optionally if the current value at the path matches
+     * provided expectation
+     */
+    class SetAccountDetailAtPath
+        : public ModelPrimitive<SetAccountDetailAtPath> {
+     public:
+      /**
+       * @return Identity of user to set account detail to
+       */
+      virtual const types::AccountIdType &accountId() const = 0;
+
+      /**
+       * @return key of the detail holding the JSON object
+       */
+      virtual const types::AccountDetailKeyType &key() const = 0;
+
+      /**
+       * @return dot separated path inside of the detail
+       */
+      virtual const std::string &path() const = 0;
+
+      /**
+       * @return JSON value to store at the path
+       */
+      virtual const types::AccountDetailValueType &value() const = 0;
+
+      /**
+       * @return JSON value expected at the path before the change
+       */
+      virtual const std::optional<types::AccountDetailValueType> oldValue()
+          const = 0;
+
+      std::string toString() const override;
+
+      bool operator==(const ModelType &rhs) const override;
+    };
+  }  // namespace interface
+}  // namespace shared_model
+
+#endif  // IROHA_SHARED_MODEL_SET_ACCOUNT_DETAIL_AT_PATH_HPP
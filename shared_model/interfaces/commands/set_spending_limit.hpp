@@ -0,0 +1,47 @@
+/**
+ * Copyright Soramitsu Co., Ltd. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+#ifndef IROHA_SHARED_MODEL_SET_SPENDING_LIMIT_HPP
+#define IROHA_SHARED_MODEL_SET_SPENDING_LIMIT_HPP
+
+#include "interfaces/base/model_primitive.hpp"
+
+#include "interfaces/common_objects/amount.hpp"
+#include "interfaces/common_objects/types.hpp"
+
+namespace shared_model {
+  namespace interface {
+    /**
+     * Limit the amount of an asset which the account may spend within a
+     * rolling window of blocks
+     */
+    class SetSpendingLimit : public ModelPrimitive<SetSpendingLimit> {
+     public:
+      /**
+       * @return Id of the limited account
+       */
+      virtual const types::AccountIdType &accountId() const = 0;
+      /**
+       * @return Id of the limited asset
+       */
+      virtual const types::AssetIdType &assetId() const = 0;
+      /**
+       * @return maximal amount spent within the window
+       */
+      virtual const Amount &amount() const = 0;
+      /**
+       * @return Number of the last blocks the spendings are counted in, zero
+       * removes the limit
+       */
+      virtual types::HeightType window() const = 0;
+
+      std::string toString() const override;
+
+      bool operator==(const ModelType &rhs) const override;
+    };
+  }  // namespace interface
+}  // namespace shared_model
+
+#endif  // IROHA_SHARED_MODEL_SET_SPENDING_LIMIT_HPP
@@ -0,0 +1,34 @@
+/**
+ * Copyright Soramitsu Co., Ltd. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+#ifndef IROHA_SHARED_MODEL_VOTE_FOR_PROPOSAL_HPP
+#define IROHA_SHARED_MODEL_VOTE_FOR_PROPOSAL_HPP
+
+#include "interfaces/base/model_primitive.hpp"
+
+#include "interfaces/common_objects/types.hpp"
+
+namespace shared_model {
+  namespace interface {
+
+    /**
+     * Vote for a governance proposal with the weight of the creator, enacting
+     * the proposal once the threshold is reached
+     */
+    class VoteForProposal : public ModelPrimitive<VoteForProposal> {
+     public:
+      /**
+       * @return Id of the proposal to vote for
+       */
+      virtual const types::ProposalIdType &proposalId() const = 0;
+
+      std::string toString() const override;
+
+      bool operator==(const ModelType &rhs) const override;
+    };
+  }  // namespace interface
+}  // namespace shared_model
+
+#endif  // IROHA_SHARED_MODEL_VOTE_FOR_PROPOSAL_HPP
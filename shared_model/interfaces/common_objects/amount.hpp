@@ -8,6 +8,7 @@
 
 #include "interfaces/base/model_primitive.hpp"
 
+#include <optional>
 #include <string_view>
 
 #include "interfaces/common_objects/types.hpp"
@@ -52,10 +53,46 @@ namespace shared_model {
        */
       std::string const &toStringRepr() const;
 
+      /**
+       * @return true if the amount is not a number, which is the case for an
+       * invalid string representation or a failed arithmetic operation
+       */
+      bool isNaN() const;
+
+      /**
+       * Adding the amount of a greater precision, overflowing, or subtracting
+       * a greater amount makes this amount NaN.
+       */
       Amount &operator+=(Amount const &other);
 
       Amount &operator-=(Amount const &other);
 
+      /**
+       * @return the sum, or nullopt if any of the amounts is NaN, the
+       * precision of other is greater, or the sum does not fit in 256 bits
+       */
+      std::optional<Amount> checkedAdd(Amount const &other) const;
+
+      /**
+       * @return the difference, or nullopt if any of the amounts is NaN, the
+       * precision of other is greater, or other is greater
+       */
+      std::optional<Amount> checkedSub(Amount const &other) const;
+
+      /**
+       * @return the sum, or the maximal amount of this precision if it does
+       * not fit in 256 bits. NaN if any of the amounts is NaN, or the
+       * precision of other is greater.
+       */
+      Amount saturatingAdd(Amount const &other) const;
+
+      /**
+       * @return the difference, or zero of this precision if other is
+       * greater. NaN if any of the amounts is NaN, or the precision of other
+       * is greater.
+       */
+      Amount saturatingSub(Amount const &other) const;
+
       /**
        * Checks equality of objects inside
        * @param rhs - other wrapped value
@@ -5,6 +5,8 @@
 
 #include "interfaces/common_objects/amount.hpp"
 
+#include <limits>
+
 #include <boost/algorithm/string/classification.hpp>
 #include <boost/multiprecision/cpp_int.hpp>
 #include "utils/string_builder.hpp"
@@ -99,6 +101,15 @@ struct Amount::Impl {
   Impl(types::PrecisionType precision)
       : string_repr_("0"), precision_(precision), multiprecision_repr_(0) {}
 
+  void updateStringRepr() {
+    auto string_repr = multiprecision_repr_.str();
+    if (precision_ >= string_repr.size()) {
+      string_repr = std::string(precision_ - string_repr.size() + 1, '0')
+                        .append(string_repr);
+    }
+    string_repr_ = string_repr.insert(string_repr.size() - precision_, ".");
+  }
+
   std::string string_repr_;
   interface::types::PrecisionType precision_;
   boost::multiprecision::checked_uint256_t multiprecision_repr_;
@@ -139,6 +150,10 @@ std::string const &Amount::toStringRepr() const {
   return impl_->string_repr_;
 }
 
+bool Amount::isNaN() const {
+  return impl_->string_repr_ == "NaN";
+}
+
 Amount &Amount::operator+=(Amount const &other) {
   if (other.impl_->precision_ > impl_->precision_) {
     impl_ = std::make_unique<Impl>("");
@@ -156,13 +171,7 @@ Amount &Amount::operator+=(Amount const &other) {
     return *this;
   }
 
-  auto string_repr = impl_->multiprecision_repr_.str();
-  if (impl_->precision_ >= string_repr.size()) {
-    string_repr = std::string(impl_->precision_ - string_repr.size() + 1, '0')
-                      .append(string_repr);
-  }
-  impl_->string_repr_ =
-      string_repr.insert(string_repr.size() - impl_->precision_, ".");
+  impl_->updateStringRepr();
 
   return *this;
 }
@@ -182,19 +191,67 @@ Amount &Amount::operator-=(Amount const &other) {
   } catch (std::range_error const &) {
     impl_ = std::make_unique<Impl>("");
     return *this;
+  } catch (std::overflow_error const &) {
+    // scaling other to this precision overflows, so other is greater
+    impl_ = std::make_unique<Impl>("");
+    return *this;
   }
 
-  auto string_repr = impl_->multiprecision_repr_.str();
-  if (impl_->precision_ >= string_repr.size()) {
-    string_repr = std::string(impl_->precision_ - string_repr.size() + 1, '0')
-                      .append(string_repr);
-  }
-  impl_->string_repr_ =
-      string_repr.insert(string_repr.size() - impl_->precision_, ".");
+  impl_->updateStringRepr();
 
   return *this;
 }
 
+std::optional<Amount> Amount::checkedAdd(Amount const &other) const {
+  if (isNaN() or other.isNaN()) {
+    return std::nullopt;
+  }
+  Amount result(*this);
+  result += other;
+  if (result.isNaN()) {
+    return std::nullopt;
+  }
+  return result;
+}
+
+std::optional<Amount> Amount::checkedSub(Amount const &other) const {
+  if (isNaN() or other.isNaN()) {
+    return std::nullopt;
+  }
+  Amount result(*this);
+  result -= other;
+  if (result.isNaN()) {
+    return std::nullopt;
+  }
+  return result;
+}
+
+Amount Amount::saturatingAdd(Amount const &other) const {
+  if (auto result = checkedAdd(other)) {
+    return std::move(*result);
+  }
+  if (isNaN() or other.isNaN() or other.precision() > precision()) {
+    return Amount("");
+  }
+  Amount result(precision());
+  result.impl_->multiprecision_repr_ =
+      std::numeric_limits<boost::multiprecision::checked_uint256_t>::max();
+  result.impl_->updateStringRepr();
+  return result;
+}
+
+Amount Amount::saturatingSub(Amount const &other) const {
+  if (auto result = checkedSub(other)) {
+    return std::move(*result);
+  }
+  if (isNaN() or other.isNaN() or other.precision() > precision()) {
+    return Amount("");
+  }
+  Amount result(precision());
+  result.impl_->updateStringRepr();
+  return result;
+}
+
 bool Amount::operator==(const ModelType &rhs) const {
   auto lhs_precision = impl_->precision_;
   auto rhs_precision = rhs.impl_->precision_;
@@ -90,6 +90,8 @@ namespace shared_model {
       using SettingKeyType = std::string;
       /// Type of setting value
       using SettingValueType = std::string;
+      /// Type of governance proposal id
+      using ProposalIdType = std::string;
       /// Type of peers collection
       using PeerList =
           std::vector<std::shared_ptr<shared_model::interface::Peer>>;
@@ -25,6 +25,10 @@ namespace shared_model {
             return Role::kTransferMyAssets;
           case Grantable::kCallEngineOnMyBehalf:
             return Role::kGrantCallEngineOnMyBehalf;
+          case Grantable::kRecoverMyAccount:
+            return Role::kRecoverMyAccount;
+          case Grantable::kSetMySpendingLimit:
+            return Role::kSetMySpendingLimit;
           default:;
         }
         return Role::COUNT;
@@ -18,6 +18,9 @@ namespace shared_model {
           .appendNamed("hash", hash().hex())
           .appendNamed("creatorAccountId", creatorAccountId())
           .appendNamed("createdTime", createdTime())
+          .appendNamed("notBefore", notBefore())
+          .appendNamed("idempotencyKey", idempotencyKey())
+          .appendNamed("chainId", chainId())
           .appendNamed("quorum", quorum())
           .appendNamed("commands", commands())
           .appendNamed("batch_meta", batchMeta())
@@ -10,6 +10,7 @@
 
 #include <optional>
 #include "interfaces/common_objects/account.hpp"
+#include "interfaces/common_objects/amount.hpp"
 #include "interfaces/common_objects/asset.hpp"
 #include "interfaces/common_objects/types.hpp"
 #include "interfaces/permissions.hpp"
@@ -17,8 +18,12 @@
 #include "interfaces/query_responses/block_query_response.hpp"
 #include "interfaces/query_responses/engine_receipt.hpp"
 #include "interfaces/query_responses/error_query_response.hpp"
+#include "interfaces/query_responses/fee_estimation_response.hpp"
 #include "interfaces/query_responses/pending_transactions_page_response.hpp"
 #include "interfaces/query_responses/query_response.hpp"
+#include "interfaces/query_responses/settings_response.hpp"
+#include "interfaces/query_responses/transactions_page_response.hpp"
+#include "interfaces/query_responses/vesting_schedules_response.hpp"
 
 namespace shared_model {
   namespace crypto {
@@ -116,7 +121,9 @@ namespace shared_model {
         kNoSignatories,
         kNotSupported,
         kNoAsset,
-        kNoRoles
+        kNoRoles,
+        kTooExpensive,
+        kTimeout
       };
       /**
        * Create response for failed query
@@ -160,6 +167,7 @@ namespace shared_model {
        * @param next_tx_hash - hash of the transaction after
        * @param all_transactions_size - total number of transactions
        * for this query
+       * @param transfer_fees - burn fees charged by the transfers of the page
        * @param query_hash - hash of the query, for which response is created
        * @return transactions response
        */
@@ -169,6 +177,7 @@ namespace shared_model {
           std::optional<std::reference_wrapper<const crypto::Hash>>
               next_tx_hash,
           interface::types::TransactionsNumberType all_transactions_size,
+          std::vector<TransactionsPageResponse::TransferFee> transfer_fees,
           const crypto::Hash &query_hash) const = 0;
 
       /**
@@ -232,6 +241,67 @@ namespace shared_model {
       virtual std::unique_ptr<QueryResponse> createPeersResponse(
           types::PeerList peers, const crypto::Hash &query_hash) const = 0;
 
+      /**
+       * Create response for get settings query
+       * @param settings - values of the settings by their keys
+       * @param descriptors - descriptors of the settings known to the ledger
+       * @param query_hash - hash of the query, for which response is created
+       * @return get settings response
+       */
+      virtual std::unique_ptr<QueryResponse> createSettingsResponse(
+          SettingsMapType settings,
+          std::vector<SettingsResponse::SettingDescriptor> descriptors,
+          const crypto::Hash &query_hash) const = 0;
+
+      /**
+       * Create response for get asset stats query
+       * @param asset_id - id of the asset
+       * @param minted - total quantity added to accounts
+       * @param burned - total quantity subtracted from accounts or burned by
+       * transfer fees
+       * @param circulating - quantity on accounts and in asset locks
+       * @param holders_count - number of accounts with positive balance
+       * @param query_hash - hash of the query, for which response is created
+       * @return get asset stats response
+       */
+      virtual std::unique_ptr<QueryResponse> createAssetStatsResponse(
+          types::AssetIdType asset_id,
+          Amount minted,
+          Amount burned,
+          Amount circulating,
+          uint64_t holders_count,
+          const crypto::Hash &query_hash) const = 0;
+
+      /**
+       * Create response for verify blob query
+       * @param matches - whether the salted hash of the payload equals the
+       * stored one
+       * @param query_hash - hash of the query, for which response is created
+       * @return blob verification response
+       */
+      virtual std::unique_ptr<QueryResponse> createBlobVerificationResponse(
+          bool matches, const crypto::Hash &query_hash) const = 0;
+
+      /**
+       * Create response for estimate fee query
+       * @param fees - fees of all commands of the estimated transaction
+       * @param query_hash - hash of the query, for which response is created
+       * @return fee estimation response
+       */
+      virtual std::unique_ptr<QueryResponse> createFeeEstimationResponse(
+          std::vector<FeeEstimationResponse::CommandFee> fees,
+          const crypto::Hash &query_hash) const = 0;
+
+      /**
+       * Create response for vesting schedules query
+       * @param schedules - vesting schedules of the account
+       * @param query_hash - hash of the query, for which response is created
+       * @return vesting schedules response
+       */
+      virtual std::unique_ptr<QueryResponse> createVestingSchedulesResponse(
+          std::vector<VestingSchedulesResponse::Schedule> schedules,
+          const crypto::Hash &query_hash) const = 0;
+
       /**
        * Create response for EVM response
        * @param engine_response_records a vector of EVM responses for commands
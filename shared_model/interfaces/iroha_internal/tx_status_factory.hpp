@@ -96,6 +96,11 @@ namespace shared_model {
           TransactionHashType,
           TransactionError tx_error = TransactionError()) = 0;
 
+      /// Creates status of transaction dropped from the queue as outdated
+      virtual FactoryReturnType makeExpired(
+          TransactionHashType,
+          TransactionError tx_error = TransactionError()) = 0;
+
       /// Creates transaction pending status
       virtual FactoryReturnType makeMstPending(
           TransactionHashType,
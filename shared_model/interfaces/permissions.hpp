@@ -69,6 +69,10 @@ namespace shared_model {
         kGetMyEngineReceipts,
         kGetDomainEngineReceipts,
         kGetAllEngineReceipts,
+        kRecoverMyAccount,
+        kGetSettings,
+        kSetMySpendingLimit,
+        kArchiveDomain,
 
         COUNT
       };
@@ -80,6 +84,8 @@ namespace shared_model {
         kSetMyAccountDetail,
         kTransferMyAssets,
         kCallEngineOnMyBehalf,
+        kRecoverMyAccount,
+        kSetMySpendingLimit,
 
         COUNT
       };
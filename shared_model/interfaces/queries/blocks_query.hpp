@@ -31,6 +31,18 @@ namespace shared_model {
        */
       virtual types::CounterType queryCounter() const = 0;
 
+      /**
+       * @return height of the first stored block to replay before the new
+       * blocks, zero if only the new blocks are requested
+       */
+      virtual types::HeightType fromHeight() const = 0;
+
+      /**
+       * @return true if only the transactions affecting the creator account
+       * are requested
+       */
+      virtual bool accountScoped() const = 0;
+
       // ------------------------| Primitive override |-------------------------
 
       std::string toString() const override;
@@ -0,0 +1,38 @@
+/**
+ * Copyright Soramitsu Co., Ltd. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+#ifndef IROHA_SHARED_MODEL_ESTIMATE_FEE_HPP
+#define IROHA_SHARED_MODEL_ESTIMATE_FEE_HPP
+
+#include <boost/range/any_range.hpp>
+#include "interfaces/base/model_primitive.hpp"
+#include "interfaces/commands/command.hpp"
+
+namespace shared_model {
+  namespace interface {
+    /**
+     * Estimate the fees which would be charged for the commands of a
+     * transaction under the current ledger settings, before it is signed
+     */
+    class EstimateFee : public ModelPrimitive<EstimateFee> {
+     public:
+      /// Type of ordered collection of commands
+      using CommandsType = boost::any_range<Command,
+                                            boost::random_access_traversal_tag,
+                                            const Command &>;
+
+      /**
+       * @return commands of the estimated transaction
+       */
+      virtual CommandsType commands() const = 0;
+
+      std::string toString() const override;
+
+      bool operator==(const ModelType &rhs) const override;
+    };
+  }  // namespace interface
+}  // namespace shared_model
+
+#endif  // IROHA_SHARED_MODEL_ESTIMATE_FEE_HPP
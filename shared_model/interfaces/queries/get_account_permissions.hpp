@@ -0,0 +1,37 @@
+/**
+ * Copyright Soramitsu Co., Ltd. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+#ifndef IROHA_SHARED_MODEL_GET_ACCOUNT_PERMISSIONS_HPP
+#define IROHA_SHARED_MODEL_GET_ACCOUNT_PERMISSIONS_HPP
+
+#include "interfaces/base/model_primitive.hpp"
+#include "interfaces/common_objects/types.hpp"
+
+namespace shared_model {
+  namespace interface {
+    /**
+     * Query for the effective permissions of the account, that is the union
+     * of permissions of all its roles
+     */
+    class GetAccountPermissions : public ModelPrimitive<GetAccountPermissions> {
+     public:
+      /**
+       * @return Identity of user, for fetching data
+       */
+      virtual const types::AccountIdType &accountId() const = 0;
+
+      /**
+       * @return substring of the names of returned permissions, all of the
+       * permissions are returned if it is empty
+       */
+      virtual const std::string &nameFilter() const = 0;
+
+      std::string toString() const override;
+
+      bool operator==(const ModelType &rhs) const override;
+    };
+  }  // namespace interface
+}  // namespace shared_model
+#endif  // IROHA_SHARED_MODEL_GET_ACCOUNT_PERMISSIONS_HPP
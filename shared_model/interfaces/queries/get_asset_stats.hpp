@@ -0,0 +1,31 @@
+/**
+ * Copyright Soramitsu Co., Ltd. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+#ifndef IROHA_SHARED_MODEL_GET_ASSET_STATS_HPP
+#define IROHA_SHARED_MODEL_GET_ASSET_STATS_HPP
+
+#include "interfaces/base/model_primitive.hpp"
+#include "interfaces/common_objects/types.hpp"
+
+namespace shared_model {
+  namespace interface {
+    /**
+     * Get supply and holders count of asset
+     */
+    class GetAssetStats : public ModelPrimitive<GetAssetStats> {
+     public:
+      /**
+       * @return asset identifier to get asset's statistics
+       */
+      virtual const types::AssetIdType &assetId() const = 0;
+
+      std::string toString() const override;
+
+      bool operator==(const ModelType &rhs) const override;
+    };
+  }  // namespace interface
+}  // namespace shared_model
+
+#endif  // IROHA_SHARED_MODEL_GET_ASSET_STATS_HPP
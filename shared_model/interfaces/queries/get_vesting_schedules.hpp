@@ -0,0 +1,30 @@
+/**
+ * Copyright Soramitsu Co., Ltd. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+#ifndef IROHA_SHARED_MODEL_GET_VESTING_SCHEDULES_HPP
+#define IROHA_SHARED_MODEL_GET_VESTING_SCHEDULES_HPP
+
+#include "interfaces/base/model_primitive.hpp"
+#include "interfaces/common_objects/types.hpp"
+
+namespace shared_model {
+  namespace interface {
+    /**
+     * Query for the vesting schedules of the assets granted to the account
+     */
+    class GetVestingSchedules : public ModelPrimitive<GetVestingSchedules> {
+     public:
+      /**
+       * @return account which the assets are granted to
+       */
+      virtual const types::AccountIdType &accountId() const = 0;
+
+      std::string toString() const override;
+
+      bool operator==(const ModelType &rhs) const override;
+    };
+  }  // namespace interface
+}  // namespace shared_model
+#endif  // IROHA_SHARED_MODEL_GET_VESTING_SCHEDULES_HPP
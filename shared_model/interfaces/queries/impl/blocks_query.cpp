@@ -14,6 +14,8 @@ namespace shared_model {
           .init("BlocksQuery")
           .appendNamed("creatorId", creatorAccountId())
           .appendNamed("queryCounter", queryCounter())
+          .appendNamed("fromHeight", fromHeight())
+          .appendNamed("accountScoped", accountScoped())
           .append(Signable::toString())
           .finalize();
     }
@@ -21,6 +23,8 @@ namespace shared_model {
     bool BlocksQuery::operator==(const ModelType &rhs) const {
       return creatorAccountId() == rhs.creatorAccountId()
           and queryCounter() == rhs.queryCounter()
+          and fromHeight() == rhs.fromHeight()
+          and accountScoped() == rhs.accountScoped()
           and createdTime() == rhs.createdTime()
           and signatures() == rhs.signatures();
     }
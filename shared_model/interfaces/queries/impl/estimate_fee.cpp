@@ -0,0 +1,25 @@
+/**
+ * Copyright Soramitsu Co., Ltd. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+#include "interfaces/queries/estimate_fee.hpp"
+
+#include <boost/range/algorithm/equal.hpp>
+
+namespace shared_model {
+  namespace interface {
+
+    std::string EstimateFee::toString() const {
+      return detail::PrettyStringBuilder()
+          .init("EstimateFee")
+          .appendNamed("commands", commands())
+          .finalize();
+    }
+
+    bool EstimateFee::operator==(const ModelType &rhs) const {
+      return boost::equal(commands(), rhs.commands());
+    }
+
+  }  // namespace interface
+}  // namespace shared_model
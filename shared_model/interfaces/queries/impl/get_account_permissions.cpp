@@ -0,0 +1,25 @@
+/**
+ * Copyright Soramitsu Co., Ltd. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+#include "interfaces/queries/get_account_permissions.hpp"
+
+namespace shared_model {
+  namespace interface {
+
+    std::string GetAccountPermissions::toString() const {
+      return detail::PrettyStringBuilder()
+          .init("GetAccountPermissions")
+          .appendNamed("account_id", accountId())
+          .appendNamed("name_filter", nameFilter())
+          .finalize();
+    }
+
+    bool GetAccountPermissions::operator==(const ModelType &rhs) const {
+      return accountId() == rhs.accountId()
+          and nameFilter() == rhs.nameFilter();
+    }
+
+  }  // namespace interface
+}  // namespace shared_model
@@ -0,0 +1,23 @@
+/**
+ * Copyright Soramitsu Co., Ltd. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+#include "interfaces/queries/get_asset_stats.hpp"
+
+namespace shared_model {
+  namespace interface {
+
+    std::string GetAssetStats::toString() const {
+      return detail::PrettyStringBuilder()
+          .init("GetAssetStats")
+          .appendNamed("asset_id", assetId())
+          .finalize();
+    }
+
+    bool GetAssetStats::operator==(const ModelType &rhs) const {
+      return assetId() == rhs.assetId();
+    }
+
+  }  // namespace interface
+}  // namespace shared_model
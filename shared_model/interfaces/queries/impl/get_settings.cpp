@@ -0,0 +1,20 @@
+/**
+ * Copyright Soramitsu Co., Ltd. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+#include "interfaces/queries/get_settings.hpp"
+
+namespace shared_model {
+  namespace interface {
+
+    std::string GetSettings::toString() const {
+      return detail::PrettyStringBuilder().init("GetSettings").finalize();
+    }
+
+    bool GetSettings::operator==(const ModelType &rhs) const {
+      return true;
+    }
+
+  }  // namespace interface
+}  // namespace shared_model
@@ -0,0 +1,23 @@
+/**
+ * Copyright Soramitsu Co., Ltd. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+#include "interfaces/queries/get_vesting_schedules.hpp"
+
+namespace shared_model {
+  namespace interface {
+
+    std::string GetVestingSchedules::toString() const {
+      return detail::PrettyStringBuilder()
+          .init("GetVestingSchedules")
+          .appendNamed("account_id", accountId())
+          .finalize();
+    }
+
+    bool GetVestingSchedules::operator==(const ModelType &rhs) const {
+      return accountId() == rhs.accountId();
+    }
+
+  }  // namespace interface
+}  // namespace shared_model
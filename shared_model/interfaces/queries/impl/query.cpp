@@ -5,21 +5,27 @@
 
 #include "interfaces/queries/query_variant.hpp"
 
+#include "interfaces/queries/estimate_fee.hpp"
 #include "interfaces/queries/get_account.hpp"
 #include "interfaces/queries/get_account_asset_transactions.hpp"
 #include "interfaces/queries/get_account_assets.hpp"
 #include "interfaces/queries/get_account_detail.hpp"
+#include "interfaces/queries/get_account_permissions.hpp"
 #include "interfaces/queries/get_account_transactions.hpp"
 #include "interfaces/queries/get_asset_info.hpp"
+#include "interfaces/queries/get_asset_stats.hpp"
 #include "interfaces/queries/get_block.hpp"
 #include "interfaces/queries/get_engine_receipts.hpp"
 #include "interfaces/queries/get_peers.hpp"
 #include "interfaces/queries/get_pending_transactions.hpp"
 #include "interfaces/queries/get_role_permissions.hpp"
 #include "interfaces/queries/get_roles.hpp"
+#include "interfaces/queries/get_settings.hpp"
 #include "interfaces/queries/get_signatories.hpp"
 #include "interfaces/queries/get_transactions.hpp"
+#include "interfaces/queries/get_vesting_schedules.hpp"
 #include "interfaces/queries/query_payload_meta.hpp"
+#include "interfaces/queries/verify_blob.hpp"
 #include "utils/visitor_apply_for_all.hpp"
 
 using Variant = shared_model::interface::Query::QueryVariantType;
@@ -0,0 +1,28 @@
+/**
+ * Copyright Soramitsu Co., Ltd. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+#include "interfaces/queries/verify_blob.hpp"
+
+namespace shared_model {
+  namespace interface {
+
+    std::string VerifyBlob::toString() const {
+      return detail::PrettyStringBuilder()
+          .init("VerifyBlob")
+          .appendNamed("account_id", accountId())
+          .appendNamed("writer", writer())
+          .appendNamed("key", key())
+          .appendNamed("blob_size", blob().size())
+          .finalize();
+    }
+
+    bool VerifyBlob::operator==(const ModelType &rhs) const {
+      return accountId() == rhs.accountId() and writer() == rhs.writer()
+          and key() == rhs.key() and blob() == rhs.blob()
+          and salt() == rhs.salt();
+    }
+
+  }  // namespace interface
+}  // namespace shared_model
@@ -27,6 +27,12 @@ namespace shared_model {
     class GetPendingTransactions;
     class GetPeers;
     class GetEngineReceipts;
+    class GetSettings;
+    class GetAssetStats;
+    class GetAccountPermissions;
+    class VerifyBlob;
+    class EstimateFee;
+    class GetVestingSchedules;
 
     /**
      * Class Query provides container with one of concrete query available in
@@ -54,7 +60,13 @@ namespace shared_model {
                                     GetPendingTransactions,
                                     GetBlock,
                                     GetPeers,
-                                    GetEngineReceipts>;
+                                    GetEngineReceipts,
+                                    GetSettings,
+                                    GetAssetStats,
+                                    GetAccountPermissions,
+                                    VerifyBlob,
+                                    EstimateFee,
+                                    GetVestingSchedules>;
 
       /**
        * @return reference to const variant with concrete command
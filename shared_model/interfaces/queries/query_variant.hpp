@@ -24,7 +24,13 @@ namespace boost {
       const shared_model::interface::GetAssetInfo &,
       const shared_model::interface::GetPendingTransactions &,
       const shared_model::interface::GetPeers &,
-      const shared_model::interface::GetEngineReceipts &>;
+      const shared_model::interface::GetEngineReceipts &,
+      const shared_model::interface::GetSettings &,
+      const shared_model::interface::GetAssetStats &,
+      const shared_model::interface::GetAccountPermissions &,
+      const shared_model::interface::VerifyBlob &,
+      const shared_model::interface::EstimateFee &,
+      const shared_model::interface::GetVestingSchedules &>;
 }  // namespace boost
 
 #endif  // IROHA_SHARED_MODEL_QUERY_VARIANT_HPP
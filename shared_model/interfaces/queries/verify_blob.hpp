@@ -0,0 +1,53 @@
+/**
+ * Copyright Soramitsu Co., Ltd. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+#ifndef IROHA_SHARED_MODEL_VERIFY_BLOB_HPP
+#define IROHA_SHARED_MODEL_VERIFY_BLOB_HPP
+
+#include "interfaces/base/model_primitive.hpp"
+#include "interfaces/common_objects/types.hpp"
+
+namespace shared_model {
+  namespace interface {
+    /**
+     * Check a payload exchanged off-chain against the salted hash stored as
+     * an account detail
+     */
+    class VerifyBlob : public ModelPrimitive<VerifyBlob> {
+     public:
+      /**
+       * @return account which stores the hash
+       */
+      virtual const types::AccountIdType &accountId() const = 0;
+
+      /**
+       * @return account which set the detail with the hash
+       */
+      virtual const types::AccountIdType &writer() const = 0;
+
+      /**
+       * @return key of the detail with the hash
+       */
+      virtual const types::AccountDetailKeyType &key() const = 0;
+
+      /**
+       * @return presented payload
+       */
+      virtual const std::string &blob() const = 0;
+
+      /**
+       * @return salt the hash was made with
+       */
+      virtual const std::string &salt() const = 0;
+
+      /// the payload and the salt are not printed as they are private
+      std::string toString() const override;
+
+      bool operator==(const ModelType &rhs) const override;
+    };
+  }  // namespace interface
+}  // namespace shared_model
+
+#endif  // IROHA_SHARED_MODEL_VERIFY_BLOB_HPP
@@ -0,0 +1,53 @@
+/**
+ * Copyright Soramitsu Co., Ltd. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+#ifndef IROHA_SHARED_MODEL_ASSET_STATS_RESPONSE_HPP
+#define IROHA_SHARED_MODEL_ASSET_STATS_RESPONSE_HPP
+
+#include "interfaces/base/model_primitive.hpp"
+
+#include "interfaces/common_objects/amount.hpp"
+#include "interfaces/common_objects/types.hpp"
+
+namespace shared_model {
+  namespace interface {
+    /**
+     * Provide response with supply and holders count of asset
+     */
+    class AssetStatsResponse : public ModelPrimitive<AssetStatsResponse> {
+     public:
+      /**
+       * @return identifier of the asset
+       */
+      virtual const types::AssetIdType &assetId() const = 0;
+
+      /**
+       * @return total quantity added with AddAssetQuantity
+       */
+      virtual const Amount &minted() const = 0;
+
+      /**
+       * @return total quantity subtracted with SubtractAssetQuantity or burned
+       * by transfer fees
+       */
+      virtual const Amount &burned() const = 0;
+
+      /**
+       * @return quantity on all accounts and in asset locks
+       */
+      virtual const Amount &circulating() const = 0;
+
+      /**
+       * @return number of accounts with positive balance of the asset
+       */
+      virtual uint64_t holdersCount() const = 0;
+
+      std::string toString() const override;
+
+      bool operator==(const ModelType &rhs) const override;
+    };
+  }  // namespace interface
+}  // namespace shared_model
+#endif  // IROHA_SHARED_MODEL_ASSET_STATS_RESPONSE_HPP
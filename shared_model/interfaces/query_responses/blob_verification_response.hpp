@@ -0,0 +1,31 @@
+/**
+ * Copyright Soramitsu Co., Ltd. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+#ifndef IROHA_SHARED_MODEL_BLOB_VERIFICATION_RESPONSE_HPP
+#define IROHA_SHARED_MODEL_BLOB_VERIFICATION_RESPONSE_HPP
+
+#include "interfaces/base/model_primitive.hpp"
+
+namespace shared_model {
+  namespace interface {
+    /**
+     * Provide response with the result of the payload check against the
+     * stored hash
+     */
+    class BlobVerificationResponse
+        : public ModelPrimitive<BlobVerificationResponse> {
+     public:
+      /**
+       * @return whether the salted hash of the payload equals the stored one
+       */
+      virtual bool matches() const = 0;
+
+      std::string toString() const override;
+
+      bool operator==(const ModelType &rhs) const override;
+    };
+  }  // namespace interface
+}  // namespace shared_model
+#endif  // IROHA_SHARED_MODEL_BLOB_VERIFICATION_RESPONSE_HPP
@@ -22,6 +22,8 @@ namespace shared_model {
     class NotSupportedErrorResponse;
     class NoAssetErrorResponse;
     class NoRolesErrorResponse;
+    class TooExpensiveErrorResponse;
+    class TimeoutErrorResponse;
 
     /**
      * QueryErrorResponse interface container for all concrete error responses
@@ -43,7 +45,9 @@ namespace shared_model {
                                               NoSignatoriesErrorResponse,
                                               NotSupportedErrorResponse,
                                               NoAssetErrorResponse,
-                                              NoRolesErrorResponse>;
+                                              NoRolesErrorResponse,
+                                              TooExpensiveErrorResponse,
+                                              TimeoutErrorResponse>;
 
       /**
        * @return reference to const variant with concrete error response
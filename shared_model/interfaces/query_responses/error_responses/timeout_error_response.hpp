@@ -0,0 +1,26 @@
+/**
+ * Copyright Soramitsu Co., Ltd. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+#ifndef IROHA_SHARED_MODEL_TIMEOUT_ERROR_RESPONSE_HPP
+#define IROHA_SHARED_MODEL_TIMEOUT_ERROR_RESPONSE_HPP
+
+#include "interfaces/query_responses/error_responses/abstract_error_response.hpp"
+
+namespace shared_model {
+  namespace interface {
+    /**
+     * Error response of query, which is aborted because it runs longer than
+     * its deadline
+     */
+    class TimeoutErrorResponse
+        : public AbstractErrorResponse<TimeoutErrorResponse> {
+     private:
+      std::string reason() const override {
+        return "TimeoutErrorResponse";
+      }
+    };
+  }  // namespace interface
+}  // namespace shared_model
+#endif  // IROHA_SHARED_MODEL_TIMEOUT_ERROR_RESPONSE_HPP
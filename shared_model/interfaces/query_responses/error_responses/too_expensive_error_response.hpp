@@ -0,0 +1,25 @@
+/**
+ * Copyright Soramitsu Co., Ltd. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+#ifndef IROHA_SHARED_MODEL_TOO_EXPENSIVE_ERROR_RESPONSE_HPP
+#define IROHA_SHARED_MODEL_TOO_EXPENSIVE_ERROR_RESPONSE_HPP
+
+#include "interfaces/query_responses/error_responses/abstract_error_response.hpp"
+
+namespace shared_model {
+  namespace interface {
+    /**
+     * Error response of query, which exceeds the query cost limits of the peer
+     */
+    class TooExpensiveErrorResponse
+        : public AbstractErrorResponse<TooExpensiveErrorResponse> {
+     private:
+      std::string reason() const override {
+        return "TooExpensiveErrorResponse";
+      }
+    };
+  }  // namespace interface
+}  // namespace shared_model
+#endif  // IROHA_SHARED_MODEL_TOO_EXPENSIVE_ERROR_RESPONSE_HPP
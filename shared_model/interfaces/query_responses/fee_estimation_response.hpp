@@ -0,0 +1,48 @@
+/**
+ * Copyright Soramitsu Co., Ltd. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+#ifndef IROHA_SHARED_MODEL_FEE_ESTIMATION_RESPONSE_HPP
+#define IROHA_SHARED_MODEL_FEE_ESTIMATION_RESPONSE_HPP
+
+#include <vector>
+
+#include "interfaces/base/model_primitive.hpp"
+#include "interfaces/common_objects/amount.hpp"
+#include "interfaces/common_objects/types.hpp"
+
+namespace shared_model {
+  namespace interface {
+    /**
+     * Provide response with the fees of the commands of the estimated
+     * transaction
+     */
+    class FeeEstimationResponse : public ModelPrimitive<FeeEstimationResponse> {
+     public:
+      /// Fee which would be charged for one command
+      struct CommandFee {
+        /// position of the command in the transaction
+        types::CommandIndexType command_index;
+        /// asset the fee is charged in, empty if the command is free
+        types::AssetIdType asset_id;
+        Amount fee;
+
+        bool operator==(const CommandFee &rhs) const {
+          return command_index == rhs.command_index
+              and asset_id == rhs.asset_id and fee == rhs.fee;
+        }
+      };
+
+      /**
+       * @return fees of all commands in the order of the commands
+       */
+      virtual const std::vector<CommandFee> &fees() const = 0;
+
+      std::string toString() const override;
+
+      bool operator==(const ModelType &rhs) const override;
+    };
+  }  // namespace interface
+}  // namespace shared_model
+#endif  // IROHA_SHARED_MODEL_FEE_ESTIMATION_RESPONSE_HPP
@@ -0,0 +1,30 @@
+/**
+ * Copyright Soramitsu Co., Ltd. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+#include "interfaces/query_responses/asset_stats_response.hpp"
+#include "utils/string_builder.hpp"
+
+namespace shared_model {
+  namespace interface {
+
+    std::string AssetStatsResponse::toString() const {
+      return detail::PrettyStringBuilder()
+          .init("AssetStatsResponse")
+          .appendNamed("asset_id", assetId())
+          .appendNamed("minted", minted())
+          .appendNamed("burned", burned())
+          .appendNamed("circulating", circulating())
+          .appendNamed("holders_count", holdersCount())
+          .finalize();
+    }
+
+    bool AssetStatsResponse::operator==(const ModelType &rhs) const {
+      return assetId() == rhs.assetId() and minted() == rhs.minted()
+          and burned() == rhs.burned() and circulating() == rhs.circulating()
+          and holdersCount() == rhs.holdersCount();
+    }
+
+  }  // namespace interface
+}  // namespace shared_model
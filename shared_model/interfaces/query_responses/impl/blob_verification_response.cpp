@@ -0,0 +1,24 @@
+/**
+ * Copyright Soramitsu Co., Ltd. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+#include "interfaces/query_responses/blob_verification_response.hpp"
+#include "utils/string_builder.hpp"
+
+namespace shared_model {
+  namespace interface {
+
+    std::string BlobVerificationResponse::toString() const {
+      return detail::PrettyStringBuilder()
+          .init("BlobVerificationResponse")
+          .appendNamed("matches", matches())
+          .finalize();
+    }
+
+    bool BlobVerificationResponse::operator==(const ModelType &rhs) const {
+      return matches() == rhs.matches();
+    }
+
+  }  // namespace interface
+}  // namespace shared_model
@@ -16,6 +16,8 @@
 #include "interfaces/query_responses/error_responses/not_supported_error_response.hpp"
 #include "interfaces/query_responses/error_responses/stateful_failed_error_response.hpp"
 #include "interfaces/query_responses/error_responses/stateless_failed_error_response.hpp"
+#include "interfaces/query_responses/error_responses/timeout_error_response.hpp"
+#include "interfaces/query_responses/error_responses/too_expensive_error_response.hpp"
 #include "utils/visitor_apply_for_all.hpp"
 
 namespace shared_model {
@@ -0,0 +1,27 @@
+/**
+ * Copyright Soramitsu Co., Ltd. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+#include "interfaces/query_responses/fee_estimation_response.hpp"
+#include "utils/string_builder.hpp"
+
+namespace shared_model {
+  namespace interface {
+
+    std::string FeeEstimationResponse::toString() const {
+      detail::PrettyStringBuilder builder;
+      builder.init("FeeEstimationResponse");
+      for (const auto &fee : fees()) {
+        builder.appendNamed(std::to_string(fee.command_index),
+                            fee.asset_id + " " + fee.fee.toStringRepr());
+      }
+      return builder.finalize();
+    }
+
+    bool FeeEstimationResponse::operator==(const ModelType &rhs) const {
+      return fees() == rhs.fees();
+    }
+
+  }  // namespace interface
+}  // namespace shared_model
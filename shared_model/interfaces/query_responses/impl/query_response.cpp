@@ -9,16 +9,21 @@
 #include "interfaces/query_responses/account_detail_response.hpp"
 #include "interfaces/query_responses/account_response.hpp"
 #include "interfaces/query_responses/asset_response.hpp"
+#include "interfaces/query_responses/asset_stats_response.hpp"
+#include "interfaces/query_responses/blob_verification_response.hpp"
 #include "interfaces/query_responses/block_response.hpp"
 #include "interfaces/query_responses/engine_receipts_response.hpp"
 #include "interfaces/query_responses/error_query_response.hpp"
+#include "interfaces/query_responses/fee_estimation_response.hpp"
 #include "interfaces/query_responses/peers_response.hpp"
 #include "interfaces/query_responses/pending_transactions_page_response.hpp"
 #include "interfaces/query_responses/role_permissions.hpp"
 #include "interfaces/query_responses/roles_response.hpp"
+#include "interfaces/query_responses/settings_response.hpp"
 #include "interfaces/query_responses/signatories_response.hpp"
 #include "interfaces/query_responses/transactions_page_response.hpp"
 #include "interfaces/query_responses/transactions_response.hpp"
+#include "interfaces/query_responses/vesting_schedules_response.hpp"
 #include "utils/visitor_apply_for_all.hpp"
 
 using Variant =
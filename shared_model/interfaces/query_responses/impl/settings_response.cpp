@@ -0,0 +1,28 @@
+/**
+ * Copyright Soramitsu Co., Ltd. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+#include "interfaces/query_responses/settings_response.hpp"
+
+#include "utils/string_builder.hpp"
+
+namespace shared_model {
+  namespace interface {
+
+    std::string SettingsResponse::toString() const {
+      detail::PrettyStringBuilder builder;
+      builder.init("SettingsResponse");
+      for (auto const &[key, value] : settings()) {
+        builder.appendNamed(key, value);
+      }
+      return builder.finalize();
+    }
+
+    bool SettingsResponse::operator==(const ModelType &rhs) const {
+      return settings() == rhs.settings()
+          and descriptors() == rhs.descriptors();
+    }
+
+  }  // namespace interface
+}  // namespace shared_model
@@ -10,18 +10,25 @@ namespace shared_model {
   namespace interface {
 
     std::string TransactionsPageResponse::toString() const {
-      return detail::PrettyStringBuilder()
-          .init("TransactionsPageResponse")
+      detail::PrettyStringBuilder builder;
+      builder.init("TransactionsPageResponse")
           .appendNamed("transactions", transactions())
           .appendNamed("all transactions size", allTransactionsSize())
-          .appendNamed("next tx", nextTxHash())
-          .finalize();
+          .appendNamed("next tx", nextTxHash());
+      for (const auto &fee : transferFees()) {
+        builder.appendNamed(
+            "fee of " + fee.tx_hash.hex() + " "
+                + std::to_string(fee.command_index),
+            fee.fee.toStringRepr());
+      }
+      return builder.finalize();
     }
 
     bool TransactionsPageResponse::operator==(const ModelType &rhs) const {
       return transactions() == rhs.transactions()
           and nextTxHash() == rhs.nextTxHash()
-          and allTransactionsSize() == rhs.allTransactionsSize();
+          and allTransactionsSize() == rhs.allTransactionsSize()
+          and transferFees() == rhs.transferFees();
     }
 
   }  // namespace interface
@@ -0,0 +1,35 @@
+/**
+ * Copyright Soramitsu Co., Ltd. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+#include "interfaces/query_responses/vesting_schedules_response.hpp"
+
+#include <fmt/core.h>
+#include "utils/string_builder.hpp"
+
+namespace shared_model {
+  namespace interface {
+
+    std::string VestingSchedulesResponse::toString() const {
+      detail::PrettyStringBuilder builder;
+      builder.init("VestingSchedulesResponse");
+      for (const auto &schedule : schedules()) {
+        builder.appendNamed(
+            schedule.asset_id,
+            fmt::format("{} from {}, cliff {}, duration {}, locked {}",
+                        schedule.amount.toStringRepr(),
+                        schedule.start_height,
+                        schedule.cliff,
+                        schedule.duration,
+                        schedule.locked.toStringRepr()));
+      }
+      return builder.finalize();
+    }
+
+    bool VestingSchedulesResponse::operator==(const ModelType &rhs) const {
+      return schedules() == rhs.schedules();
+    }
+
+  }  // namespace interface
+}  // namespace shared_model
@@ -28,6 +28,11 @@ namespace shared_model {
     class TransactionsPageResponse;
     class PeersResponse;
     class EngineReceiptsResponse;
+    class SettingsResponse;
+    class AssetStatsResponse;
+    class BlobVerificationResponse;
+    class FeeEstimationResponse;
+    class VestingSchedulesResponse;
     /**
      * Class QueryResponse(qr) provides container with concrete query responses
      * available in the system.
@@ -54,7 +59,12 @@ namespace shared_model {
                                          PendingTransactionsPageResponse,
                                          BlockResponse,
                                          PeersResponse,
-                                         EngineReceiptsResponse>;
+                                         EngineReceiptsResponse,
+                                         SettingsResponse,
+                                         AssetStatsResponse,
+                                         BlobVerificationResponse,
+                                         FeeEstimationResponse,
+                                         VestingSchedulesResponse>;
 
       /**
        * @return reference to const variant with concrete qr
@@ -0,0 +1,70 @@
+/**
+ * Copyright Soramitsu Co., Ltd. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+#ifndef IROHA_SHARED_MODEL_SETTINGS_RESPONSE_HPP
+#define IROHA_SHARED_MODEL_SETTINGS_RESPONSE_HPP
+
+#include "interfaces/base/model_primitive.hpp"
+
+#include <map>
+#include <optional>
+#include <vector>
+#include "interfaces/common_objects/types.hpp"
+
+namespace shared_model {
+  namespace interface {
+
+    using SettingsMapType =
+        std::map<types::SettingKeyType, types::SettingValueType>;
+
+    /**
+     * Provide response with the ledger settings
+     */
+    class SettingsResponse : public ModelPrimitive<SettingsResponse> {
+     public:
+      /// Type, default value and bounds of a setting known to the ledger
+      struct SettingDescriptor {
+        enum class Type {
+          /// decimal unsigned integer within the bounds
+          kUnsigned,
+          /// string with the length within the bounds
+          kString,
+          /// policy with its own format, described in the documentation
+          kPolicy
+        };
+
+        /// key of the setting, keys ending with a colon are prefixes of the
+        /// settings of an asset or a domain
+        types::SettingKeyType key;
+        Type type;
+        /// value used when the setting is not set, empty if there is none
+        types::SettingValueType default_value;
+        std::optional<uint64_t> min;
+        std::optional<uint64_t> max;
+
+        bool operator==(const SettingDescriptor &rhs) const {
+          return key == rhs.key and type == rhs.type
+              and default_value == rhs.default_value and min == rhs.min
+              and max == rhs.max;
+        }
+      };
+
+      /**
+       * @return values of the settings set in the ledger by their keys
+       */
+      virtual const SettingsMapType &settings() const = 0;
+
+      /**
+       * @return descriptors of all settings known to the ledger
+       */
+      virtual const std::vector<SettingDescriptor> &descriptors() const = 0;
+
+      std::string toString() const override;
+
+      bool operator==(const ModelType &rhs) const override;
+    };
+  }  // namespace interface
+}  // namespace shared_model
+#endif  // IROHA_SHARED_MODEL_SETTINGS_RESPONSE_HPP
@@ -9,6 +9,8 @@
 #include "interfaces/base/model_primitive.hpp"
 
 #include <optional>
+#include <vector>
+#include "interfaces/common_objects/amount.hpp"
 #include "interfaces/common_objects/range_types.hpp"
 #include "interfaces/common_objects/types.hpp"
 
@@ -20,6 +22,19 @@ namespace shared_model {
     class TransactionsPageResponse
         : public ModelPrimitive<TransactionsPageResponse> {
      public:
+      /// Burn fee charged by a transfer of the asset
+      struct TransferFee {
+        types::HashType tx_hash;
+        /// position of the transfer in the transaction
+        types::CommandIndexType command_index;
+        Amount fee;
+
+        bool operator==(const TransferFee &rhs) const {
+          return tx_hash == rhs.tx_hash and command_index == rhs.command_index
+              and fee == rhs.fee;
+        }
+      };
+
       /**
        * @return transactions from this page
        */
@@ -36,6 +51,12 @@ namespace shared_model {
       virtual interface::types::TransactionsNumberType allTransactionsSize()
           const = 0;
 
+      /**
+       * @return burn fees charged by the transfers of the page, only for the
+       * transactions of an account asset
+       */
+      virtual const std::vector<TransferFee> &transferFees() const = 0;
+
       std::string toString() const override;
 
       bool operator==(const ModelType &rhs) const override;
@@ -0,0 +1,57 @@
+/**
+ * Copyright Soramitsu Co., Ltd. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+#ifndef IROHA_SHARED_MODEL_VESTING_SCHEDULES_RESPONSE_HPP
+#define IROHA_SHARED_MODEL_VESTING_SCHEDULES_RESPONSE_HPP
+
+#include <vector>
+
+#include "interfaces/base/model_primitive.hpp"
+#include "interfaces/common_objects/amount.hpp"
+#include "interfaces/common_objects/types.hpp"
+
+namespace shared_model {
+  namespace interface {
+    /**
+     * Provide response with the vesting schedules of the assets granted to the
+     * account
+     */
+    class VestingSchedulesResponse
+        : public ModelPrimitive<VestingSchedulesResponse> {
+     public:
+      /// Vesting schedule of one asset as of the next block
+      struct Schedule {
+        types::AssetIdType asset_id;
+        /// granted amount
+        Amount amount;
+        /// height of the block with the grant
+        types::HeightType start_height;
+        types::HeightType cliff;
+        types::HeightType duration;
+        /// part of the amount which is released
+        Amount vested;
+        /// part of the amount which the account may not spend
+        Amount locked;
+
+        bool operator==(const Schedule &rhs) const {
+          return asset_id == rhs.asset_id and amount == rhs.amount
+              and start_height == rhs.start_height and cliff == rhs.cliff
+              and duration == rhs.duration and vested == rhs.vested
+              and locked == rhs.locked;
+        }
+      };
+
+      /**
+       * @return schedules ordered by asset id
+       */
+      virtual const std::vector<Schedule> &schedules() const = 0;
+
+      std::string toString() const override;
+
+      bool operator==(const ModelType &rhs) const override;
+    };
+  }  // namespace interface
+}  // namespace shared_model
+#endif  // IROHA_SHARED_MODEL_VESTING_SCHEDULES_RESPONSE_HPP
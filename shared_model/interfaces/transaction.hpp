@@ -33,6 +33,24 @@ namespace shared_model {
        */
       virtual types::QuorumType quorum() const = 0;
 
+      /**
+       * @return time before which the transaction must not be applied, 0 if
+       * there is no such restriction
+       */
+      virtual types::TimestampType notBefore() const = 0;
+
+      /**
+       * @return key identifying retries of the same submission by the
+       * creator, empty if there is no key
+       */
+      virtual const std::string &idempotencyKey() const = 0;
+
+      /**
+       * @return identifier of the network the transaction is signed for,
+       * empty if the transaction is not bound to a network
+       */
+      virtual const std::string &chainId() const = 0;
+
       /// Type of ordered collection of commands
       using CommandsType = boost::any_range<Command,
                                             boost::random_access_traversal_tag,
@@ -0,0 +1,26 @@
+/**
+ * Copyright Soramitsu Co., Ltd. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+#ifndef IROHA_EXPIRED_TX_RESPONSE_HPP
+#define IROHA_EXPIRED_TX_RESPONSE_HPP
+
+#include "interfaces/transaction_responses/abstract_tx_response.hpp"
+
+namespace shared_model {
+  namespace interface {
+    /**
+     * Status shows that transaction was dropped from the queue, because it
+     * had expired before it got into a proposal
+     */
+    class ExpiredTxResponse : public AbstractTxResponse<ExpiredTxResponse> {
+     private:
+      std::string className() const override {
+        return "ExpiredTxResponse";
+      }
+    };
+
+  }  // namespace interface
+}  // namespace shared_model
+#endif  // IROHA_EXPIRED_TX_RESPONSE_HPP
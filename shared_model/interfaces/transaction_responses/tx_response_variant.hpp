@@ -21,7 +21,8 @@ namespace boost {
       const shared_model::interface::MstExpiredResponse &,
       const shared_model::interface::NotReceivedTxResponse &,
       const shared_model::interface::MstPendingResponse &,
-      const shared_model::interface::EnoughSignaturesCollectedResponse &>;
+      const shared_model::interface::EnoughSignaturesCollectedResponse &,
+      const shared_model::interface::ExpiredTxResponse &>;
 }
 
 #endif  // IROHA_SHARED_MODEL_TX_RESPONSE_VARIANT_HPP
@@ -101,7 +101,14 @@ namespace {
                                              + kDomainValidator.getPattern()};
   const RegexValidator kAccountDetailKeyValidator{"DetailKey",
                                                   R"([A-Za-z0-9_]{1,64})"};
+  const RegexValidator kAccountDetailPathValidator{
+      "DetailPath",
+      kAccountDetailKeyValidator.getPattern() + R"#((\.)#"
+          + kAccountDetailKeyValidator.getPattern() + ")*",
+      "Dot separated detail keys expected"};
   const RegexValidator kRoleIdValidator{"RoleId", R"#([a-z_0-9]{1,32})#"};
+  const RegexValidator kProposalIdValidator{"ProposalId",
+                                            R"#([a-z_0-9]{1,32})#"};
   const RegexValidator kHexValidator{
       "Hex", R"#(([0-9a-fA-F][0-9a-fA-F])*)#", "Hex encoded string expected"};
   const RegexValidator kPublicKeyHexValidator{
@@ -182,6 +189,11 @@ namespace shared_model {
       return kRoleIdValidator.validate(role_id);
     }
 
+    std::optional<ValidationError> FieldValidator::validateProposalId(
+        const interface::types::ProposalIdType &proposal_id) const {
+      return kProposalIdValidator.validate(proposal_id);
+    }
+
     std::optional<ValidationError> FieldValidator::validateAccountName(
         const interface::types::AccountNameType &account_name) const {
       return kAccountNameValidator.validate(account_name);
@@ -210,6 +222,11 @@ namespace shared_model {
       return kAccountDetailKeyValidator.validate(key);
     }
 
+    std::optional<ValidationError> FieldValidator::validateAccountDetailPath(
+        const std::string &path) const {
+      return kAccountDetailPathValidator.validate(path);
+    }
+
     std::optional<ValidationError> FieldValidator::validateAccountDetailValue(
         const interface::types::AccountDetailValueType &value) const {
       if (value.size() > value_size) {
@@ -300,6 +317,31 @@ namespace shared_model {
       return validateCreatedTime(timestamp, time_provider_());
     }
 
+    std::optional<ValidationError> FieldValidator::validateNotBefore(
+        interface::types::TimestampType not_before,
+        interface::types::TimestampType created_time) const {
+      if (not_before > max_delay_.count() + created_time) {
+        return ValidationError(
+            "NotBefore",
+            {fmt::format("expires before becoming valid, not_before: {}, "
+                         "created_time: {}",
+                         not_before,
+                         created_time)});
+      }
+      return std::nullopt;
+    }
+
+    std::optional<ValidationError> FieldValidator::validateIdempotencyKey(
+        const std::string &key) const {
+      if (key.size() > kMaxIdempotencyKeySize) {
+        return ValidationError(
+            "IdempotencyKey",
+            {fmt::format("Size should be less or equal '{}'.",
+                         kMaxIdempotencyKeySize)});
+      }
+      return std::nullopt;
+    }
+
     std::optional<ValidationError> FieldValidator::validateCounter(
         const interface::types::CounterType &counter) const {
       if (counter <= 0) {
@@ -79,6 +79,9 @@ namespace shared_model {
       std::optional<ValidationError> validateRoleId(
           const interface::types::RoleIdType &role_id) const;
 
+      std::optional<ValidationError> validateProposalId(
+          const interface::types::ProposalIdType &proposal_id) const;
+
       std::optional<ValidationError> validateAccountName(
           const interface::types::AccountNameType &account_name) const;
 
@@ -116,6 +119,9 @@ namespace shared_model {
       std::optional<ValidationError> validateAccountDetailKey(
           const interface::types::AccountDetailKeyType &key) const;
 
+      std::optional<ValidationError> validateAccountDetailPath(
+          const std::string &path) const;
+
       std::optional<ValidationError> validateAccountDetailValue(
           const interface::types::AccountDetailValueType &value) const;
 
@@ -154,6 +160,16 @@ namespace shared_model {
       std::optional<ValidationError> validateCreatedTime(
           interface::types::TimestampType timestamp) const;
 
+      /**
+       * Validate that the transaction becomes valid before it expires
+       */
+      std::optional<ValidationError> validateNotBefore(
+          interface::types::TimestampType not_before,
+          interface::types::TimestampType created_time) const;
+
+      std::optional<ValidationError> validateIdempotencyKey(
+          const std::string &key) const;
+
       std::optional<ValidationError> validateCounter(
           const interface::types::CounterType &counter) const;
 
@@ -220,6 +236,7 @@ namespace shared_model {
       /// limit for the set account detail size in bytes
       static constexpr size_t value_size = 4 * 1024 * 1024;
       static constexpr size_t kMaxDescriptionSize = 100 * 1024;  // 100K
+      static constexpr size_t kMaxIdempotencyKeySize = 64;
     };
 
     std::optional<ValidationError> validatePubkey(
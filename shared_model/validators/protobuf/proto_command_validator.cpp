@@ -23,6 +23,14 @@ namespace {
     }
     return std::nullopt;
   }
+
+  std::optional<ValidationError> validateHex(const char *name,
+                                             const std::string &value) {
+    if (not validateHexString(value)) {
+      return ValidationError{name, {"Not in hex format"}};
+    }
+    return std::nullopt;
+  }
 }  // namespace
 
 namespace shared_model {
@@ -74,6 +82,50 @@ namespace shared_model {
           return aggregateErrors(
               "RemoveSignatory", {}, {validatePublicKey(rs.public_key())});
         }
+        case iroha::protocol::Command::kRecoverAccount: {
+          const auto &ra = command.recover_account();
+          return aggregateErrors(
+              "RecoverAccount", {}, {validatePublicKey(ra.public_key())});
+        }
+        case iroha::protocol::Command::kRotateKey: {
+          const auto &rk = command.rotate_key();
+          return aggregateErrors("RotateKey",
+                                 {},
+                                 {validatePublicKey(rk.old_public_key()),
+                                  validatePublicKey(rk.new_public_key())});
+        }
+        case iroha::protocol::Command::kAuthorizeSessionKey: {
+          const auto &ask = command.authorize_session_key();
+          ValidationErrorCreator error_creator;
+          error_creator |= validatePublicKey(ask.public_key());
+          for (auto perm : ask.permissions() | boost::adaptors::indexed(1)) {
+            if (not iroha::protocol::RolePermission_IsValid(perm.value())) {
+              error_creator.addReason(
+                  fmt::format("Permission #{} is invalid.", perm.index()));
+            }
+          }
+          return std::move(error_creator)
+              .getValidationError("AuthorizeSessionKey");
+        }
+        case iroha::protocol::Command::kLockAsset: {
+          return aggregateErrors(
+              "LockAsset",
+              {},
+              {validateHex("Hash", command.lock_asset().hash())});
+        }
+        case iroha::protocol::Command::kClaimWithPreimage: {
+          return aggregateErrors(
+              "ClaimWithPreimage",
+              {},
+              {validateHex("Preimage",
+                           command.claim_with_preimage().preimage())});
+        }
+        case iroha::protocol::Command::kRefundAfterTimeout: {
+          return aggregateErrors(
+              "RefundAfterTimeout",
+              {},
+              {validateHex("Hash", command.refund_after_timeout().hash())});
+        }
         case iroha::protocol::Command::kAddPeer: {
           const auto &ap = command.add_peer();
           return aggregateErrors(
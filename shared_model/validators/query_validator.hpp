@@ -10,21 +10,28 @@
 #include <boost/variant/static_visitor.hpp>
 
 #include "common/bind.hpp"
+#include "interfaces/commands/transfer_asset.hpp"
+#include "interfaces/queries/estimate_fee.hpp"
 #include "interfaces/queries/get_account.hpp"
 #include "interfaces/queries/get_account_asset_transactions.hpp"
 #include "interfaces/queries/get_account_assets.hpp"
 #include "interfaces/queries/get_account_detail.hpp"
+#include "interfaces/queries/get_account_permissions.hpp"
 #include "interfaces/queries/get_account_transactions.hpp"
 #include "interfaces/queries/get_asset_info.hpp"
+#include "interfaces/queries/get_asset_stats.hpp"
 #include "interfaces/queries/get_block.hpp"
 #include "interfaces/queries/get_engine_receipts.hpp"
 #include "interfaces/queries/get_pending_transactions.hpp"
 #include "interfaces/queries/get_role_permissions.hpp"
 #include "interfaces/queries/get_roles.hpp"
+#include "interfaces/queries/get_settings.hpp"
 #include "interfaces/queries/get_signatories.hpp"
 #include "interfaces/queries/get_transactions.hpp"
+#include "interfaces/queries/get_vesting_schedules.hpp"
 #include "interfaces/queries/query.hpp"
 #include "interfaces/queries/tx_pagination_meta.hpp"
+#include "interfaces/queries/verify_blob.hpp"
 #include "validators/abstract_validator.hpp"
 #include "validators/validation_error_helpers.hpp"
 
@@ -192,6 +199,66 @@ namespace shared_model {
             crypto::Hash::fromHexString(qry.txHash()));
       }
 
+      std::optional<ValidationError> operator()(
+          const interface::GetSettings &get_settings) const {
+        return std::nullopt;
+      }
+
+      std::optional<ValidationError> operator()(
+          const interface::GetAssetStats &get_asset_stats) const {
+        return aggregateErrors(
+            "GetAssetStats",
+            {},
+            {validator_.validateAssetId(get_asset_stats.assetId())});
+      }
+
+      std::optional<ValidationError> operator()(
+          const interface::GetAccountPermissions &get_account_permissions)
+          const {
+        return aggregateErrors(
+            "GetAccountPermissions",
+            {},
+            {validator_.validateAccountId(
+                get_account_permissions.accountId())});
+      }
+
+      std::optional<ValidationError> operator()(
+          const interface::VerifyBlob &verify_blob) const {
+        return aggregateErrors(
+            "VerifyBlob",
+            {},
+            {validator_.validateAccountId(verify_blob.accountId()),
+             validator_.validateAccountId(verify_blob.writer()),
+             validator_.validateAccountDetailKey(verify_blob.key())});
+      }
+
+      std::optional<ValidationError> operator()(
+          const interface::EstimateFee &estimate_fee) const {
+        std::vector<std::optional<ValidationError>> errors;
+        for (const auto &command : estimate_fee.commands()) {
+          // only the fields the fees depend on are checked
+          if (auto transfer = boost::get<const interface::TransferAsset &>(
+                  &command.get())) {
+            errors.push_back(validator_.validateAssetId(transfer->assetId()));
+            errors.push_back(validator_.validateAmount(transfer->amount()));
+          }
+        }
+        return aggregateErrors(
+            "EstimateFee",
+            {estimate_fee.commands().empty()
+                 ? std::make_optional<ReasonType>("No commands to estimate")
+                 : std::nullopt},
+            std::move(errors));
+      }
+
+      std::optional<ValidationError> operator()(
+          const interface::GetVestingSchedules &get_vesting_schedules) const {
+        return aggregateErrors(
+            "GetVestingSchedules",
+            {},
+            {validator_.validateAccountId(get_vesting_schedules.accountId())});
+      }
+
      private:
       FieldValidator validator_;
     };
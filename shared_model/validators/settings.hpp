@@ -17,6 +17,9 @@ namespace shared_model {
      */
     struct Settings {
       size_t max_description_size;
+      /// identifier of the network, transactions signed for other networks
+      /// are rejected, empty if transactions are not bound to a network
+      std::string chain_id;
     };
 
     const size_t kDefaultDescriptionSize = 64;
@@ -6,6 +6,7 @@
 #ifndef IROHA_SHARED_MODEL_TRANSACTION_VALIDATOR_HPP
 #define IROHA_SHARED_MODEL_TRANSACTION_VALIDATOR_HPP
 
+#include <fmt/core.h>
 #include <boost/range/adaptor/indexed.hpp>
 #include <boost/variant.hpp>
 
@@ -14,23 +15,37 @@
 #include "interfaces/commands/add_peer.hpp"
 #include "interfaces/commands/add_signatory.hpp"
 #include "interfaces/commands/append_role.hpp"
+#include "interfaces/commands/archive_domain.hpp"
+#include "interfaces/commands/authorize_session_key.hpp"
 #include "interfaces/commands/call_engine.hpp"
+#include "interfaces/commands/claim_with_preimage.hpp"
 #include "interfaces/commands/command.hpp"
 #include "interfaces/commands/compare_and_set_account_detail.hpp"
 #include "interfaces/commands/create_account.hpp"
 #include "interfaces/commands/create_asset.hpp"
 #include "interfaces/commands/create_domain.hpp"
 #include "interfaces/commands/create_role.hpp"
+#include "interfaces/commands/deactivate_account.hpp"
 #include "interfaces/commands/detach_role.hpp"
 #include "interfaces/commands/grant_permission.hpp"
+#include "interfaces/commands/grant_vested_asset.hpp"
+#include "interfaces/commands/include_role.hpp"
+#include "interfaces/commands/lock_asset.hpp"
+#include "interfaces/commands/propose_setting_value.hpp"
+#include "interfaces/commands/recover_account.hpp"
+#include "interfaces/commands/refund_after_timeout.hpp"
 #include "interfaces/commands/remove_peer.hpp"
 #include "interfaces/commands/remove_signatory.hpp"
 #include "interfaces/commands/revoke_permission.hpp"
+#include "interfaces/commands/rotate_key.hpp"
 #include "interfaces/commands/set_account_detail.hpp"
+#include "interfaces/commands/set_account_detail_at_path.hpp"
 #include "interfaces/commands/set_quorum.hpp"
 #include "interfaces/commands/set_setting_value.hpp"
+#include "interfaces/commands/set_spending_limit.hpp"
 #include "interfaces/commands/subtract_asset_quantity.hpp"
 #include "interfaces/commands/transfer_asset.hpp"
+#include "interfaces/commands/vote_for_proposal.hpp"
 #include "interfaces/transaction.hpp"
 #include "validators/abstract_validator.hpp"
 #include "validators/validation_error_helpers.hpp"
@@ -103,6 +118,34 @@ namespace shared_model {
              validator_.validateRoleId(append_role.roleName())});
       }
 
+      std::optional<ValidationError> operator()(
+          const interface::ArchiveDomain &archive_domain) const {
+        return aggregateErrors(
+            "ArchiveDomain",
+            {},
+            {validator_.validateDomainId(archive_domain.domainId())});
+      }
+
+      std::optional<ValidationError> operator()(
+          const interface::AuthorizeSessionKey &authorize_session_key) const {
+        ValidationErrorCreator error_creator;
+        error_creator |=
+            validator_.validateAccountId(authorize_session_key.accountId());
+        error_creator |=
+            validator_.validatePubkey(authorize_session_key.pubkey());
+        // empty allowed asset id does not restrict the assets
+        if (not authorize_session_key.allowedAssetId().empty()) {
+          error_creator |= validator_.validateAssetId(
+              authorize_session_key.allowedAssetId());
+        }
+        authorize_session_key.permissions().iterate(
+            [&error_creator, this](auto i) {
+              error_creator |= validator_.validateRolePermission(i);
+            });
+        return std::move(error_creator)
+            .getValidationError("AuthorizeSessionKey");
+      }
+
       std::optional<ValidationError> operator()(
           const interface::CreateAccount &create_account) const {
         return aggregateErrors(
@@ -143,6 +186,14 @@ namespace shared_model {
         return std::move(error_creator).getValidationError("CreateRole");
       }
 
+      std::optional<ValidationError> operator()(
+          const interface::DeactivateAccount &deactivate_account) const {
+        return aggregateErrors(
+            "DeactivateAccount",
+            {},
+            {validator_.validateAccountId(deactivate_account.accountId())});
+      }
+
       std::optional<ValidationError> operator()(
           const interface::DetachRole &detach_role) const {
         return aggregateErrors(
@@ -162,6 +213,55 @@ namespace shared_model {
                  grant_permission.permissionName())});
       }
 
+      std::optional<ValidationError> operator()(
+          const interface::GrantVestedAsset &grant_vested_asset) const {
+        return aggregateErrors(
+            "GrantVestedAsset",
+            {[&]() -> std::optional<std::string> {
+               if (grant_vested_asset.duration() == 0) {
+                 return std::string{"Duration should be positive."};
+               }
+               return std::nullopt;
+             }(),
+             [&]() -> std::optional<std::string> {
+               if (grant_vested_asset.cliff()
+                   > grant_vested_asset.duration()) {
+                 return std::string{"Cliff should not exceed the duration."};
+               }
+               return std::nullopt;
+             }()},
+            {validator_.validateAccountId(grant_vested_asset.destAccountId()),
+             validator_.validateAssetId(grant_vested_asset.assetId()),
+             validator_.validateAmount(grant_vested_asset.amount())});
+      }
+
+      std::optional<ValidationError> operator()(
+          const interface::IncludeRole &include_role) const {
+        return aggregateErrors(
+            "IncludeRole",
+            {},
+            {validator_.validateRoleId(include_role.roleName()),
+             validator_.validateRoleId(include_role.includedRoleName())});
+      }
+
+      std::optional<ValidationError> operator()(
+          const interface::ProposeSettingValue &propose_setting_value) const {
+        return aggregateErrors(
+            "ProposeSettingValue",
+            {},
+            {validator_.validateProposalId(
+                propose_setting_value.proposalId())});
+      }
+
+      std::optional<ValidationError> operator()(
+          const interface::RecoverAccount &recover_account) const {
+        return aggregateErrors(
+            "RecoverAccount",
+            {},
+            {validator_.validateAccountId(recover_account.accountId()),
+             validator_.validatePubkey(recover_account.pubkey())});
+      }
+
       std::optional<ValidationError> operator()(
           const interface::RemovePeer &remove_peer) const {
         return aggregateErrors(
@@ -189,6 +289,61 @@ namespace shared_model {
                  revoke_permission.permissionName())});
       }
 
+      std::optional<ValidationError> operator()(
+          const interface::RotateKey &rotate_key) const {
+        return aggregateErrors(
+            "RotateKey",
+            {},
+            {validator_.validateAccountId(rotate_key.accountId()),
+             validator_.validatePubkey(rotate_key.oldPubkey()),
+             validator_.validatePubkey(rotate_key.newPubkey())});
+      }
+
+      std::optional<ValidationError> operator()(
+          const interface::LockAsset &lock_asset) const {
+        return aggregateErrors(
+            "LockAsset",
+            {[&]() -> std::optional<std::string> {
+               if (lock_asset.srcAccountId() == lock_asset.destAccountId()) {
+                 return std::string{
+                     "Source and destination accounts are the same."};
+               }
+               return std::nullopt;
+             }(),
+             [&]() -> std::optional<std::string> {
+               if (lock_asset.timeout() == 0) {
+                 return std::string{"Timeout should be positive."};
+               }
+               return std::nullopt;
+             }()},
+            {validator_.validateAccountId(lock_asset.srcAccountId()),
+             validator_.validateAccountId(lock_asset.destAccountId()),
+             validator_.validateAssetId(lock_asset.assetId()),
+             validator_.validateAmount(lock_asset.amount()),
+             validateLockHash(lock_asset.hash())});
+      }
+
+      std::optional<ValidationError> operator()(
+          const interface::ClaimWithPreimage &claim_with_preimage) const {
+        return aggregateErrors(
+            "ClaimWithPreimage",
+            {[&]() -> std::optional<std::string> {
+              if (claim_with_preimage.preimage().size() != kLockHashHexSize) {
+                return fmt::format("Preimage should be {} hex characters.",
+                                   kLockHashHexSize);
+              }
+              return std::nullopt;
+            }()},
+            {});
+      }
+
+      std::optional<ValidationError> operator()(
+          const interface::RefundAfterTimeout &refund_after_timeout) const {
+        return aggregateErrors("RefundAfterTimeout",
+                               {},
+                               {validateLockHash(refund_after_timeout.hash())});
+      }
+
       std::optional<ValidationError> operator()(
           const interface::SetAccountDetail &set_account_detail) const {
         return aggregateErrors(
@@ -209,6 +364,16 @@ namespace shared_model {
              validator_.validateQuorum(set_quorum.newQuorum())});
       }
 
+      std::optional<ValidationError> operator()(
+          const interface::SetSpendingLimit &set_spending_limit) const {
+        return aggregateErrors(
+            "SetSpendingLimit",
+            {},
+            {validator_.validateAccountId(set_spending_limit.accountId()),
+             validator_.validateAssetId(set_spending_limit.assetId()),
+             validator_.validateAmount(set_spending_limit.amount())});
+      }
+
       std::optional<ValidationError> operator()(
           const interface::SubtractAssetQuantity &subtract_asset_quantity)
           const {
@@ -258,12 +423,57 @@ namespace shared_model {
              }});
       }
 
+      std::optional<ValidationError> operator()(
+          const interface::SetAccountDetailAtPath &set_account_detail_at_path)
+          const {
+        using iroha::operator|;
+        return aggregateErrors(
+            "SetAccountDetailAtPath",
+            {},
+            {validator_.validateAccountId(
+                 set_account_detail_at_path.accountId()),
+             validator_.validateAccountDetailKey(
+                 set_account_detail_at_path.key()),
+             validator_.validateAccountDetailPath(
+                 set_account_detail_at_path.path()),
+             validator_.validateAccountDetailValue(
+                 set_account_detail_at_path.value()),
+             set_account_detail_at_path.oldValue() |
+                 [this](
+                     const auto &oldValue) -> std::optional<ValidationError> {
+               return this->validator_.validateOldAccountDetailValue(oldValue);
+             }});
+      }
+
       std::optional<ValidationError> operator()(
           const interface::SetSettingValue &set_setting_value) const {
         return std::nullopt;
       }
 
+      std::optional<ValidationError> operator()(
+          const interface::VoteForProposal &vote_for_proposal) const {
+        return aggregateErrors(
+            "VoteForProposal",
+            {},
+            {validator_.validateProposalId(vote_for_proposal.proposalId())});
+      }
+
      private:
+      /// Lock hashes and preimages are hex of 32 bytes
+      static constexpr size_t kLockHashHexSize = 64;
+
+      static std::optional<ValidationError> validateLockHash(
+          const std::string &hash) {
+        if (hash.size() != kLockHashHexSize) {
+          return ValidationError(
+              "Hash",
+              {fmt::format("Invalid size: {}, should be {}.",
+                           hash.size(),
+                           kLockHashHexSize)});
+        }
+        return std::nullopt;
+      }
+
       FieldValidator validator_;
     };
 
@@ -294,6 +504,10 @@ namespace shared_model {
         error_creator |=
             std::forward<CreatedTimeValidator>(validator)(tx.createdTime());
         error_creator |= field_validator_.validateQuorum(tx.quorum());
+        error_creator |= field_validator_.validateNotBefore(tx.notBefore(),
+                                                            tx.createdTime());
+        error_creator |=
+            field_validator_.validateIdempotencyKey(tx.idempotencyKey());
         error_creator |= tx.batchMeta() | [this](const auto &batch_meta) {
           return field_validator_.validateBatchMeta(*batch_meta);
         };
@@ -19,18 +19,23 @@
 #include "interfaces/query_responses/account_detail_response.hpp"
 #include "interfaces/query_responses/account_response.hpp"
 #include "interfaces/query_responses/asset_response.hpp"
+#include "interfaces/query_responses/asset_stats_response.hpp"
+#include "interfaces/query_responses/blob_verification_response.hpp"
 #include "interfaces/query_responses/block_error_response.hpp"
 #include "interfaces/query_responses/block_query_response.hpp"
 #include "interfaces/query_responses/block_response.hpp"
 #include "interfaces/query_responses/engine_receipts_response.hpp"
 #include "interfaces/query_responses/error_query_response.hpp"
+#include "interfaces/query_responses/fee_estimation_response.hpp"
 #include "interfaces/query_responses/pending_transactions_page_response.hpp"
 #include "interfaces/query_responses/query_response.hpp"
 #include "interfaces/query_responses/role_permissions.hpp"
 #include "interfaces/query_responses/roles_response.hpp"
+#include "interfaces/query_responses/settings_response.hpp"
 #include "interfaces/query_responses/signatories_response.hpp"
 #include "interfaces/query_responses/transactions_page_response.hpp"
 #include "interfaces/query_responses/transactions_response.hpp"
+#include "interfaces/query_responses/vesting_schedules_response.hpp"
 
 namespace iroha {
   namespace integration_framework {
@@ -72,7 +77,19 @@ namespace iroha {
           boost::mpl::pair<shared_model::interface::GetBlock,
                            shared_model::interface::BlockResponse>,
           boost::mpl::pair<shared_model::interface::GetEngineReceipts,
-                           shared_model::interface::EngineReceiptsResponse>>
+                           shared_model::interface::EngineReceiptsResponse>,
+          boost::mpl::pair<shared_model::interface::GetSettings,
+                           shared_model::interface::SettingsResponse>,
+          boost::mpl::pair<shared_model::interface::GetAssetStats,
+                           shared_model::interface::AssetStatsResponse>,
+          boost::mpl::pair<shared_model::interface::GetAccountPermissions,
+                           shared_model::interface::RolePermissionsResponse>,
+          boost::mpl::pair<shared_model::interface::VerifyBlob,
+                           shared_model::interface::BlobVerificationResponse>,
+          boost::mpl::pair<shared_model::interface::EstimateFee,
+                           shared_model::interface::FeeEstimationResponse>,
+          boost::mpl::pair<shared_model::interface::GetVestingSchedules,
+                           shared_model::interface::VestingSchedulesResponse>>
           SpecificQueryResponses;
 
       /// true for specific commands
@@ -0,0 +1,110 @@
+/**
+ * Copyright Soramitsu Co., Ltd. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+#include "framework/integration_framework/fake_peer/behaviour/faulty.hpp"
+
+#include <thread>
+
+#include <boost/range/adaptor/transformed.hpp>
+#include "backend/protobuf/block.hpp"
+#include "logger/logger.hpp"
+
+namespace integration_framework {
+  namespace fake_peer {
+
+    void FaultyBehaviour::pause() {
+      paused_ = true;
+    }
+
+    void FaultyBehaviour::resume() {
+      paused_ = false;
+    }
+
+    void FaultyBehaviour::setYacDropProbability(double probability) {
+      yac_drop_probability_ = probability;
+    }
+
+    void FaultyBehaviour::setYacDelay(std::chrono::milliseconds delay) {
+      yac_delay_ = delay;
+    }
+
+    void FaultyBehaviour::setCorruptBlocks(bool corrupt) {
+      corrupt_blocks_ = corrupt;
+    }
+
+    void FaultyBehaviour::processYacMessage(
+        std::shared_ptr<const YacMessage> message) {
+      if (paused_) {
+        getLogger()->debug("Paused, dropping a YAC message.");
+        return;
+      }
+      if (double probability = yac_drop_probability_; probability > 0) {
+        std::lock_guard<std::mutex> lock(random_mutex_);
+        if (std::bernoulli_distribution(probability)(random_engine_)) {
+          getLogger()->debug("Dropping a YAC message.");
+          return;
+        }
+      }
+      if (auto delay = yac_delay_.load(); delay.count() > 0) {
+        std::this_thread::sleep_for(delay);
+      }
+      HonestBehaviour::processYacMessage(std::move(message));
+    }
+
+    LoaderBlockRequestResult FaultyBehaviour::processLoaderBlockRequest(
+        LoaderBlockRequest request) {
+      if (paused_) {
+        return {};
+      }
+      auto block = HonestBehaviour::processLoaderBlockRequest(request);
+      if (block and corrupt_blocks_) {
+        return corrupt(*block);
+      }
+      return block;
+    }
+
+    LoaderBlocksRequestResult FaultyBehaviour::processLoaderBlocksRequest(
+        LoaderBlocksRequest request) {
+      if (paused_) {
+        return {};
+      }
+      auto blocks = HonestBehaviour::processLoaderBlocksRequest(request);
+      if (not corrupt_blocks_) {
+        return blocks;
+      }
+      return blocks | boost::adaptors::transformed(&FaultyBehaviour::corrupt);
+    }
+
+    OrderingProposalRequestResult
+    FaultyBehaviour::processOrderingProposalRequest(
+        const OrderingProposalRequest &request) {
+      if (paused_) {
+        return {};
+      }
+      return HonestBehaviour::processOrderingProposalRequest(request);
+    }
+
+    void FaultyBehaviour::processOrderingBatches(
+        const BatchesCollection &batches) {
+      if (paused_) {
+        return;
+      }
+      HonestBehaviour::processOrderingBatches(batches);
+    }
+
+    std::shared_ptr<const shared_model::proto::Block> FaultyBehaviour::corrupt(
+        std::shared_ptr<const shared_model::proto::Block> block) {
+      if (not block) {
+        return block;
+      }
+      auto transport = block->getTransport();
+      transport.mutable_payload()->set_created_time(
+          transport.payload().created_time() + 1);
+      return std::make_shared<const shared_model::proto::Block>(
+          std::move(transport));
+    }
+
+  }  // namespace fake_peer
+}  // namespace integration_framework
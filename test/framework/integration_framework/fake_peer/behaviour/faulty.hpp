@@ -0,0 +1,67 @@
+/**
+ * Copyright Soramitsu Co., Ltd. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+#ifndef INTEGRATION_FRAMEWORK_FAKE_PEER_BEHAVIOUR_FAULTY_HPP_
+#define INTEGRATION_FRAMEWORK_FAKE_PEER_BEHAVIOUR_FAULTY_HPP_
+
+#include "framework/integration_framework/fake_peer/behaviour/honest.hpp"
+
+#include <atomic>
+#include <chrono>
+#include <mutex>
+#include <random>
+
+namespace integration_framework {
+  namespace fake_peer {
+
+    /**
+     * Honest behaviour with injectable faults, so that tests can check how
+     * the network recovers from unreliable peers. The faults can be changed
+     * at any time while the behaviour is active.
+     */
+    class FaultyBehaviour : public HonestBehaviour {
+     public:
+      /// Ignore all messages and requests, as if the peer is stuck
+      void pause();
+
+      /// Stop ignoring messages and requests
+      void resume();
+
+      /// Drop each incoming YAC message with the given probability
+      void setYacDropProbability(double probability);
+
+      /// Delay the processing of each incoming YAC message
+      void setYacDelay(std::chrono::milliseconds delay);
+
+      /// Serve blocks with altered payload, so their signatures are invalid
+      void setCorruptBlocks(bool corrupt);
+
+      void processYacMessage(
+          std::shared_ptr<const YacMessage> message) override;
+      LoaderBlockRequestResult processLoaderBlockRequest(
+          LoaderBlockRequest request) override;
+      LoaderBlocksRequestResult processLoaderBlocksRequest(
+          LoaderBlocksRequest request) override;
+      OrderingProposalRequestResult processOrderingProposalRequest(
+          const OrderingProposalRequest &request) override;
+      void processOrderingBatches(const BatchesCollection &batches) override;
+
+     private:
+      static std::shared_ptr<const shared_model::proto::Block> corrupt(
+          std::shared_ptr<const shared_model::proto::Block> block);
+
+      std::atomic_bool paused_{false};
+      std::atomic<double> yac_drop_probability_{0};
+      std::atomic<std::chrono::milliseconds> yac_delay_{
+          std::chrono::milliseconds::zero()};
+      std::atomic_bool corrupt_blocks_{false};
+      std::mutex random_mutex_;
+      std::mt19937 random_engine_{std::random_device{}()};
+    };
+
+  }  // namespace fake_peer
+}  // namespace integration_framework
+
+#endif /* INTEGRATION_FRAMEWORK_FAKE_PEER_BEHAVIOUR_FAULTY_HPP_ */
@@ -80,7 +80,8 @@ FakePeer::FakePeer(
         iroha::ordering::transport::OnDemandOsClientGrpc::TransportFactoryType>
         proposal_factory,
     std::shared_ptr<iroha::ametsuchi::TxPresenceCache> tx_presence_cache,
-    logger::LoggerManagerTreePtr log_manager)
+    logger::LoggerManagerTreePtr log_manager,
+    std::shared_ptr<iroha::network::InProcessNetwork> in_process_network)
     : log_(log_manager->getLogger()),
       log_manager_(std::move(log_manager)),
       consensus_log_manager_(log_manager_->getChild("Consensus")),
@@ -101,8 +102,12 @@ FakePeer::FakePeer(
       real_peer_(std::move(real_peer)),
       async_call_(std::make_shared<AsyncCall>(
           log_manager_->getChild("AsyncNetworkClient")->getLogger())),
+      in_process_network_(std::move(in_process_network)),
       client_factory_(
-          iroha::network::getTestInsecureClientFactory(std::nullopt)),
+          in_process_network_
+              ? iroha::network::getTestInProcessClientFactory(
+                  in_process_network_)
+              : iroha::network::getTestInsecureClientFactory(std::nullopt)),
       yac_transport_client_(std::make_shared<YacTransportClient>(
           iroha::network::makeTransportClientFactory<YacTransportClient>(
               client_factory_),
@@ -201,6 +206,9 @@ std::unique_ptr<iroha::network::ServerRunner> FakePeer::run(bool reuse_port) {
       getAddress(),
       log_manager_->getChild("InternalServer")->getLogger(),
       reuse_port);
+  if (in_process_network_) {
+    internal_server->inProcess(in_process_network_);
+  }
   internal_server->append(yac_transport_server_)
       .append(od_os_transport_)
       .append(synchronizer_transport_)
@@ -23,6 +23,7 @@
 
 namespace iroha::network {
   class GenericClientFactory;
+  class InProcessNetwork;
   class ServerRunner;
 }  // namespace iroha::network
 
@@ -64,6 +65,8 @@ namespace integration_framework::fake_peer {
      * @param proposal_factory - proposal factory
      * @param tx_presence_cache - for MST transport
      * @param log_manager - log manager
+     * @param in_process_network - the network to communicate through instead
+     * of sockets, if set
      */
     FakePeer(
         HideCtor,
@@ -81,7 +84,9 @@ namespace integration_framework::fake_peer {
         std::shared_ptr<iroha::ordering::transport::OnDemandOsClientGrpc::
                             TransportFactoryType> proposal_factory,
         std::shared_ptr<iroha::ametsuchi::TxPresenceCache> tx_presence_cache,
-        logger::LoggerManagerTreePtr log_manager);
+        logger::LoggerManagerTreePtr log_manager,
+        std::shared_ptr<iroha::network::InProcessNetwork> in_process_network =
+            nullptr);
 
     ~FakePeer();
 
@@ -237,6 +242,7 @@ namespace integration_framework::fake_peer {
         real_peer_;  ///< the real instance
 
     std::shared_ptr<AsyncCall> async_call_;
+    std::shared_ptr<iroha::network::InProcessNetwork> in_process_network_;
     std::shared_ptr<iroha::network::GenericClientFactory> client_factory_;
 
     std::shared_ptr<YacTransportClient> yac_transport_client_;
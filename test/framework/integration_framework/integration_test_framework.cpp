@@ -49,6 +49,7 @@
 #include "module/shared_model/validators/always_valid_validators.hpp"
 #include "network/consensus_gate.hpp"
 #include "network/impl/channel_factory.hpp"
+#include "network/impl/in_process_network.hpp"
 #include "network/peer_communication_service.hpp"
 #include "ordering/impl/on_demand_os_client_grpc.hpp"
 #include "simulator/verified_proposal_creator_common.hpp"
@@ -359,6 +360,19 @@ IntegrationTestFramework::~IntegrationTestFramework() {
     subscription->dispose();
 }
 
+IntegrationTestFramework &IntegrationTestFramework::useInProcessNetwork() {
+  BOOST_ASSERT_MSG(not this_peer_, "Must be called before initPipeline!");
+  in_process_network_ = std::make_shared<iroha::network::InProcessNetwork>();
+  client_factory_ =
+      iroha::network::getTestInProcessClientFactory(in_process_network_);
+  yac_transport_ = std::make_shared<iroha::consensus::yac::NetworkImpl>(
+      makeTransportClientFactory<iroha::consensus::yac::NetworkImpl>(
+          client_factory_),
+      log_manager_->getChild("ConsensusTransport")->getLogger());
+  iroha_instance_->setInProcessNetwork(in_process_network_);
+  return *this;
+}
+
 std::shared_ptr<FakePeer> IntegrationTestFramework::addFakePeer(
     const boost::optional<Keypair> &key) {
   BOOST_ASSERT_MSG(this_peer_, "Need to set the ITF peer key first!");
@@ -375,7 +389,8 @@ std::shared_ptr<FakePeer> IntegrationTestFramework::addFakePeer(
                              proposal_factory_,
                              tx_presence_cache_,
                              log_manager_->getChild("FakePeer")
-                                 ->getChild(format_address(kLocalHost, port)));
+                                 ->getChild(format_address(kLocalHost, port)),
+                             in_process_network_);
   fake_peer->initialize();
   fake_peers_.emplace_back(fake_peer);
   log_->debug("Added a fake peer at {} with {}.",
@@ -69,6 +69,7 @@ namespace iroha {
   }  // namespace consensus
   namespace network {
     class GenericClientFactory;
+    class InProcessNetwork;
     struct OrderingEvent;
     class ServerRunner;
     template <typename Response>
@@ -155,6 +156,14 @@ namespace integration_framework {
 
     ~IntegrationTestFramework();
 
+    /**
+     * Make the peers communicate through an in-process network instead of
+     * sockets, which is faster and does not bind the internal ports. Must be
+     * called before initPipeline and adding fake peers.
+     * @return this
+     */
+    IntegrationTestFramework &useInProcessNetwork();
+
     /// Add a fake peer with given key.
     std::shared_ptr<fake_peer::FakePeer> addFakePeer(
         const boost::optional<shared_model::crypto::Keypair> &key);
@@ -515,6 +524,7 @@ namespace integration_framework {
         proposal_factory_;
     std::shared_ptr<iroha::ametsuchi::TxPresenceCache> tx_presence_cache_;
 
+    std::shared_ptr<iroha::network::InProcessNetwork> in_process_network_;
     std::shared_ptr<iroha::network::GenericClientFactory> client_factory_;
     std::shared_ptr<iroha::consensus::yac::YacNetwork> yac_transport_;
 
@@ -85,7 +85,13 @@ namespace integration_framework {
         key_pair,
         irohad_log_manager_,
         log_,
-        startup_wsv_data_policy_);
+        startup_wsv_data_policy_,
+        in_process_network_);
+  }
+
+  void IrohaInstance::setInProcessNetwork(
+      std::shared_ptr<iroha::network::InProcessNetwork> network) {
+    in_process_network_ = std::move(network);
   }
 
   void IrohaInstance::run() {
@@ -30,6 +30,10 @@ namespace shared_model {
   }  // namespace crypto
 }  // namespace shared_model
 
+namespace iroha::network {
+  class InProcessNetwork;
+}
+
 namespace integration_framework {
   class TestIrohad;
 
@@ -61,6 +65,11 @@ namespace integration_framework {
     void initPipeline(const shared_model::crypto::Keypair &key_pair,
                       size_t max_proposal_size = 10);
 
+    /// Communicate with other peers through the network instead of sockets,
+    /// must be set before initPipeline
+    void setInProcessNetwork(
+        std::shared_ptr<iroha::network::InProcessNetwork> network);
+
     void run();
 
     void printDbStatus();
@@ -78,6 +87,7 @@ namespace integration_framework {
 
    private:
     std::shared_ptr<TestIrohad> test_irohad_;
+    std::shared_ptr<iroha::network::InProcessNetwork> in_process_network_;
     logger::LoggerManagerTreePtr irohad_log_manager_;
 
     logger::LoggerPtr log_;
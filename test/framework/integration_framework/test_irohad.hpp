@@ -25,7 +25,9 @@ namespace integration_framework {
                const shared_model::crypto::Keypair &keypair,
                logger::LoggerManagerTreePtr irohad_log_manager,
                logger::LoggerPtr log,
-               iroha::StartupWsvDataPolicy startup_wsv_data_policy)
+               iroha::StartupWsvDataPolicy startup_wsv_data_policy,
+               std::shared_ptr<iroha::network::InProcessNetwork>
+                   in_process_network = nullptr)
         : Irohad(config,
                  std::move(pg_opt),
                  std::move(rdb_opt),
@@ -36,7 +38,9 @@ namespace integration_framework {
                  iroha::StartupWsvSynchronizationPolicy::kSyncUpAndGo,
                  std::nullopt,
                  boost::none),
-          log_(std::move(log)) {}
+          log_(std::move(log)) {
+      in_process_network_ = std::move(in_process_network);
+    }
 
     auto &getCommandService() {
       return command_service;
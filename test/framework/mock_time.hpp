@@ -0,0 +1,65 @@
+/**
+ * Copyright Soramitsu Co., Ltd. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+#ifndef IROHA_TEST_FRAMEWORK_MOCK_TIME_HPP
+#define IROHA_TEST_FRAMEWORK_MOCK_TIME_HPP
+
+#include <atomic>
+#include <chrono>
+#include <memory>
+
+#include "datetime/time.hpp"
+
+namespace framework {
+
+  /**
+   * Replaces the time of iroha::time::now in the whole process while alive,
+   * including the peers run by the integration test framework. The time
+   * starts at the current one and stands still until the test advances it,
+   * so that expirations of transactions do not depend on the speed of the
+   * machine.
+   */
+  class MockTime {
+   public:
+    using Clock = std::chrono::system_clock;
+
+    MockTime() : current_(std::make_shared<std::atomic<Clock::rep>>()) {
+      set(Clock::now());
+      iroha::time::setTimeSource(std::make_shared<iroha::time::TimeSource>(
+          [current = current_] {
+            return Clock::time_point(Clock::duration(current->load()));
+          }));
+    }
+
+    MockTime(const MockTime &) = delete;
+    MockTime &operator=(const MockTime &) = delete;
+
+    ~MockTime() {
+      iroha::time::setTimeSource(nullptr);
+    }
+
+    /// Move the time forward by the duration
+    template <typename Duration>
+    void advance(Duration duration) {
+      current_->fetch_add(
+          std::chrono::duration_cast<Clock::duration>(duration).count());
+    }
+
+    /// Set the time to the given point
+    void set(Clock::time_point time) {
+      current_->store(time.time_since_epoch().count());
+    }
+
+    Clock::time_point now() const {
+      return Clock::time_point(Clock::duration(current_->load()));
+    }
+
+   private:
+    std::shared_ptr<std::atomic<Clock::rep>> current_;
+  };
+
+}  // namespace framework
+
+#endif  // IROHA_TEST_FRAMEWORK_MOCK_TIME_HPP
@@ -10,6 +10,7 @@
 #include "logger/logger_manager.hpp"
 #include "network/impl/channel_factory.hpp"
 #include "network/impl/channel_pool.hpp"
+#include "network/impl/in_process_network.hpp"
 
 namespace iroha {
   namespace network {
@@ -22,6 +23,13 @@ namespace iroha {
           std::make_unique<ChannelPool>(std::move(channel_factory)));
     }
 
+    std::unique_ptr<GenericClientFactory> getTestInProcessClientFactory(
+        std::shared_ptr<InProcessNetwork> network) {
+      return std::make_unique<GenericClientFactory>(
+          std::make_unique<ChannelPool>(
+              std::make_unique<InProcessChannelProvider>(std::move(network))));
+    }
+
     std::shared_ptr<grpc::Channel> createSecureChannel(
         const shared_model::interface::types::AddressType &address,
         const std::string &service_full_name,
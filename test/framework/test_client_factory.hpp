@@ -19,6 +19,12 @@ namespace iroha {
     std::unique_ptr<GenericClientFactory> getTestInsecureClientFactory(
         std::optional<std::shared_ptr<const GrpcChannelParams>> maybe_params);
 
+    class InProcessNetwork;
+
+    /// Client factory connecting to the servers of the in-process network
+    std::unique_ptr<GenericClientFactory> getTestInProcessClientFactory(
+        std::shared_ptr<InProcessNetwork> network);
+
     template <typename Transport>
     auto makeTransportClientFactory(
         std::shared_ptr<iroha::network::GenericClientFactory> generic_factory) {
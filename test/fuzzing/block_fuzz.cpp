@@ -0,0 +1,33 @@
+/**
+ * Copyright Soramitsu Co., Ltd. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+#include <libfuzzer/libfuzzer_macro.h>
+
+#include "backend/protobuf/block.hpp"
+#include "module/irohad/common/validators_config.hpp"
+#include "validators/default_validator.hpp"
+#include "validators/protobuf/proto_block_validator.hpp"
+
+extern "C" int LLVMFuzzerTestOneInput(const uint8_t *data, std::size_t size) {
+  static const shared_model::validation::ProtoBlockValidator proto_validator;
+  static const shared_model::validation::DefaultSignedBlockValidator validator(
+      iroha::test::kTestsValidatorsConfig);
+
+  iroha::protocol::Block request;
+  if (not protobuf_mutator::libfuzzer::LoadProtoInput(
+          true, data, size, &request)) {
+    return 0;
+  }
+  if (proto_validator.validate(request)) {
+    return 0;
+  }
+
+  shared_model::proto::Block block(request.block_v1());
+  validator.validate(block);
+  block.hash();
+  block.toString();
+
+  return 0;
+}
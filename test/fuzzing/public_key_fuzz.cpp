@@ -0,0 +1,31 @@
+/**
+ * Copyright Soramitsu Co., Ltd. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+#include <string>
+#include <string_view>
+
+#include "common/hexutils.hpp"
+#include "cryptography/blob.hpp"
+#include "cryptography/crypto_provider/crypto_verifier.hpp"
+#include "multihash/multihash.hpp"
+
+extern "C" int LLVMFuzzerTestOneInput(const uint8_t *data, std::size_t size) {
+  using namespace shared_model::interface::types;
+
+  std::string_view input(reinterpret_cast<const char *>(data), size);
+  iroha::multihash::createFromBuffer(makeByteRange(input));
+
+  // the input is treated as a public key in multihash or plain form
+  static const std::string signature(
+      shared_model::crypto::CryptoVerifier::kMaxSignatureSize * 2, '0');
+  static const shared_model::crypto::Blob message("fuzz");
+  auto public_key = iroha::bytestringToHexstring(input);
+  shared_model::crypto::CryptoVerifier::verify(
+      SignedHexStringView{signature},
+      message,
+      PublicKeyHexStringView{public_key});
+
+  return 0;
+}
@@ -0,0 +1,34 @@
+/**
+ * Copyright Soramitsu Co., Ltd. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+#include <libfuzzer/libfuzzer_macro.h>
+
+#include "backend/protobuf/transaction.hpp"
+#include "module/irohad/common/validators_config.hpp"
+#include "validators/default_validator.hpp"
+#include "validators/protobuf/proto_transaction_validator.hpp"
+
+extern "C" int LLVMFuzzerTestOneInput(const uint8_t *data, std::size_t size) {
+  static const shared_model::validation::ProtoTransactionValidator
+      proto_validator;
+  static const shared_model::validation::DefaultSignedTransactionValidator
+      validator(iroha::test::kTestsValidatorsConfig);
+
+  iroha::protocol::Transaction request;
+  if (not protobuf_mutator::libfuzzer::LoadProtoInput(
+          true, data, size, &request)) {
+    return 0;
+  }
+  if (proto_validator.validate(request)) {
+    return 0;
+  }
+
+  shared_model::proto::Transaction transaction(request);
+  validator.validate(transaction);
+  transaction.hash();
+  transaction.toString();
+
+  return 0;
+}
@@ -178,6 +178,12 @@ AcceptanceFixture::checkQueryErrorResponse<
 template std::function<void(const shared_model::interface::QueryResponse &)>
 AcceptanceFixture::checkQueryErrorResponse<
     shared_model::interface::NoRolesErrorResponse>();
+template std::function<void(const shared_model::interface::QueryResponse &)>
+AcceptanceFixture::checkQueryErrorResponse<
+    shared_model::interface::TooExpensiveErrorResponse>();
+template std::function<void(const shared_model::interface::QueryResponse &)>
+AcceptanceFixture::checkQueryErrorResponse<
+    shared_model::interface::TimeoutErrorResponse>();
 
 iroha::time::time_t AcceptanceFixture::getUniqueTime() {
   return initial_time + nonce_counter++;
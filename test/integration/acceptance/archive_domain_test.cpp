@@ -0,0 +1,46 @@
+/**
+ * Copyright Soramitsu Co., Ltd. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+#include <gtest/gtest.h>
+#include "framework/integration_framework/integration_test_framework.hpp"
+#include "instantiate_test_suite.hpp"
+#include "integration/acceptance/acceptance_fixture.hpp"
+
+using namespace integration_framework;
+using namespace shared_model;
+using namespace common_constants;
+
+using iroha::StorageType;
+using shared_model::interface::permissions::Role;
+
+struct ArchiveDomainFixture : AcceptanceFixture,
+                              ::testing::WithParamInterface<StorageType> {
+  auto addAsset() {
+    return complete(baseTx().addAssetQuantity(kAssetId, "1.0"));
+  }
+
+  IntegrationTestFramework &prepareState(IntegrationTestFramework &itf) {
+    return itf.setInitialState(kAdminKeypair)
+        .sendTxAwait(makeUserWithPerms({Role::kArchiveDomain,
+                                        Role::kAddAssetQty}),
+                     CHECK_TXS_QUANTITY(1));
+  }
+};
+
+INSTANTIATE_TEST_SUITE_P_DifferentStorageTypes(ArchiveDomainFixture);
+
+/**
+ * @given a user with can_archive_domain permission
+ * @when the user archives its own domain
+ * @then transactions created by the user are rejected after it
+ */
+TEST_P(ArchiveDomainFixture, TransactionsAreRejected) {
+  IntegrationTestFramework itf(1, GetParam());
+  prepareState(itf)
+      .sendTxAwait(addAsset(), CHECK_TXS_QUANTITY(1))
+      .sendTxAwait(complete(baseTx().archiveDomain(kDomain)),
+                   CHECK_TXS_QUANTITY(1))
+      .sendTxAwait(addAsset(), CHECK_TXS_QUANTITY(0));
+}
@@ -32,6 +32,23 @@ static constexpr std::chrono::seconds kSynchronizerWaitingTime(20);
 struct FakePeerExampleTest : FakePeerFixture {};
 INSTANTIATE_TEST_SUITE_P_DifferentStorageTypes(FakePeerExampleTest);
 
+struct FakePeerInProcessTest : FakePeerFixture {
+  FakePeerInProcessTest() {
+    in_process_network_ = true;
+  }
+};
+INSTANTIATE_TEST_SUITE_P_DifferentStorageTypes(FakePeerInProcessTest);
+
+/**
+ * @given the real peer and honest fake peers in the in-process network
+ * @when transactions are sent
+ * @then they are committed, so the peers reach consensus without sockets
+ */
+TEST_P(FakePeerInProcessTest, CommitsBlocks) {
+  createFakePeers(3);
+  prepareState();
+}
+
 /**
  * Check that Irohad loads correct block version when having a malicious fork on
  * the network.
@@ -0,0 +1,68 @@
+/**
+ * Copyright Soramitsu Co., Ltd. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+#include "integration/acceptance/fake_peer_fixture.hpp"
+
+#include <thread>
+
+#include "framework/integration_framework/fake_peer/behaviour/faulty.hpp"
+
+using namespace common_constants;
+using namespace integration_framework;
+
+static constexpr std::chrono::seconds kStallTime(3);
+
+struct FakePeerFaultsTest : FakePeerFixture {
+  /// Create fake peers with faulty behaviours, which are honest initially
+  void createFaultyPeers(size_t num_fake_peers) {
+    createFakePeers(num_fake_peers);
+    for (auto &fake_peer : fake_peers_) {
+      behaviours_.push_back(std::make_shared<fake_peer::FaultyBehaviour>());
+      fake_peer->setBehaviour(behaviours_.back());
+    }
+  }
+
+  auto makeTransfer(const std::string &description) {
+    return complete(baseTx(kAdminId).transferAsset(
+                        kAdminId, kUserId, kAssetId, description, "1.0"),
+                    kAdminKeypair);
+  }
+
+  std::vector<std::shared_ptr<fake_peer::FaultyBehaviour>> behaviours_;
+};
+INSTANTIATE_TEST_SUITE_P_DifferentStorageTypes(FakePeerFaultsTest);
+
+/**
+ * @given a network of the real peer and 4 fake peers
+ * @when one fake peer is paused and another one delays its votes
+ * @then transactions are committed, since one faulty peer is tolerated
+ */
+TEST_P(FakePeerFaultsTest, ToleratesOneFaultyPeer) {
+  createFaultyPeers(4);
+  auto &itf = prepareState();
+
+  behaviours_[0]->pause();
+  behaviours_[1]->setYacDelay(std::chrono::milliseconds(100));
+  itf.sendTxAwait(makeTransfer("tx1"), checkBlockHasNTxs<1>);
+}
+
+/**
+ * @given a network of the real peer and 4 fake peers
+ * @when two fake peers drop all votes, so the consensus cannot be reached,
+ * and later one of them recovers
+ * @then the transaction sent during the outage is committed
+ */
+TEST_P(FakePeerFaultsTest, RecoversAfterPeerResumes) {
+  createFaultyPeers(4);
+  auto &itf = prepareState();
+
+  behaviours_[0]->pause();
+  behaviours_[1]->setYacDropProbability(1.);
+  itf.sendTx(makeTransfer("tx1"));
+  std::this_thread::sleep_for(kStallTime);
+
+  behaviours_[1]->setYacDropProbability(0.);
+  itf.checkBlock(checkBlockHasNTxs<1>);
+}
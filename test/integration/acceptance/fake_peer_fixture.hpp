@@ -70,6 +70,9 @@ struct FakePeerFixture : AcceptanceFixture,
         iroha::StartupWsvDataPolicy::kDrop,
         true,
         true);
+    if (in_process_network_) {
+      itf_->useInProcessNetwork();
+    }
     itf_->initPipeline(common_constants::kAdminKeypair);
   }
 
@@ -80,6 +83,8 @@ struct FakePeerFixture : AcceptanceFixture,
 
   std::vector<std::shared_ptr<FakePeer>> fake_peers_;
   std::shared_ptr<iroha::Subscription> subscription;
+  /// whether the peers communicate in-process instead of through sockets
+  bool in_process_network_ = false;
 };
 
 #endif  // IROHA_FAKE_PEER_FIXTURE_HPP
@@ -0,0 +1,131 @@
+/**
+ * Copyright Soramitsu Co., Ltd. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+#include <gtest/gtest.h>
+#include "framework/integration_framework/integration_test_framework.hpp"
+#include "instantiate_test_suite.hpp"
+#include "integration/acceptance/acceptance_fixture.hpp"
+
+using namespace integration_framework;
+using namespace shared_model;
+using namespace common_constants;
+
+using iroha::StorageType;
+using shared_model::interface::permissions::Role;
+using shared_model::interface::types::PublicKeyHexStringView;
+
+struct RotateKeyFixture : AcceptanceFixture,
+                          ::testing::WithParamInterface<StorageType> {
+  /// Rotate the key of the user to the key of the same domain user
+  auto rotateKey(interface::types::HeightType grace_period) {
+    return complete(baseTx().rotateKey(
+        kUserId,
+        PublicKeyHexStringView{kUserKeypair.publicKey()},
+        PublicKeyHexStringView{kSameDomainUserKeypair.publicKey()},
+        grace_period));
+  }
+
+  /// Transaction of the user signed with the given key
+  auto setDetail(const std::string &value,
+                 const crypto::Keypair &keypair = kUserKeypair) {
+    return complete(baseTx().setAccountDetail(kUserId, "key", value),
+                    keypair);
+  }
+
+  /// Sign the transaction with both given keys
+  template <typename TransactionBuilder>
+  auto completeWith(TransactionBuilder builder,
+                    const crypto::Keypair &first,
+                    const crypto::Keypair &second) {
+    return builder.build()
+        .signAndAddSignature(first)
+        .signAndAddSignature(second)
+        .finish();
+  }
+
+  IntegrationTestFramework &prepareState(IntegrationTestFramework &itf) {
+    return itf.setInitialState(kAdminKeypair)
+        .sendTxAwait(
+            makeUserWithPerms({Role::kAddSignatory, Role::kRemoveSignatory}),
+            CHECK_TXS_QUANTITY(1));
+  }
+};
+
+INSTANTIATE_TEST_SUITE_P_DifferentStorageTypes(RotateKeyFixture);
+
+/**
+ * @given a user with permissions to add and remove own signatories
+ * @when the user rotates the key without grace period
+ * @then transactions signed with the old key are rejected right away
+ * @and transactions signed with the new key are committed
+ */
+TEST_P(RotateKeyFixture, NoGracePeriod) {
+  IntegrationTestFramework itf(1, GetParam());
+  prepareState(itf)
+      .sendTxAwait(rotateKey(0), CHECK_TXS_QUANTITY(1))
+      .sendTxAwait(setDetail("old"), CHECK_TXS_QUANTITY(0))
+      .sendTxAwait(setDetail("new", kSameDomainUserKeypair),
+                   CHECK_TXS_QUANTITY(1));
+}
+
+/**
+ * @given a user with permissions to add and remove own signatories
+ * @when the user rotates the key with grace period of one block
+ * @then a transaction signed with the old key is committed in the next block
+ * @and transactions signed with the old key are rejected after it
+ */
+TEST_P(RotateKeyFixture, GracePeriod) {
+  IntegrationTestFramework itf(1, GetParam());
+  prepareState(itf)
+      .sendTxAwait(rotateKey(1), CHECK_TXS_QUANTITY(1))
+      .sendTxAwait(setDetail("in grace period"), CHECK_TXS_QUANTITY(1))
+      .sendTxAwait(setDetail("expired"), CHECK_TXS_QUANTITY(0))
+      .sendTxAwait(setDetail("new", kSameDomainUserKeypair),
+                   CHECK_TXS_QUANTITY(1));
+}
+
+/**
+ * @given a user with quorum 2 and two signatories
+ * @when the user rotates one of the keys with a grace period
+ * @then a transaction signed with the old and the new key of the rotation is
+ * rejected, as they count once towards the quorum
+ * @and a transaction signed with the new key and the other signatory is
+ * committed
+ */
+TEST_P(RotateKeyFixture, OldAndNewKeyCountOnce) {
+  IntegrationTestFramework itf(1, GetParam());
+  itf.setInitialState(kAdminKeypair)
+      .sendTxAwait(makeUserWithPerms({Role::kAddSignatory,
+                                      Role::kRemoveSignatory,
+                                      Role::kSetQuorum}),
+                   CHECK_TXS_QUANTITY(1))
+      .sendTxAwait(
+          complete(baseTx()
+                       .addSignatory(kUserId,
+                                     PublicKeyHexStringView{
+                                         kSecondDomainUserKeypair.publicKey()})
+                       .setAccountQuorum(kUserId, 2)),
+          CHECK_TXS_QUANTITY(1))
+      .sendTxAwait(
+          completeWith(baseTx().rotateKey(
+                           kUserId,
+                           PublicKeyHexStringView{kUserKeypair.publicKey()},
+                           PublicKeyHexStringView{
+                               kSameDomainUserKeypair.publicKey()},
+                           10),
+                       kUserKeypair,
+                       kSecondDomainUserKeypair),
+          CHECK_TXS_QUANTITY(1))
+      .sendTxAwait(
+          completeWith(baseTx().setAccountDetail(kUserId, "key", "rotated"),
+                       kUserKeypair,
+                       kSameDomainUserKeypair),
+          CHECK_TXS_QUANTITY(0))
+      .sendTxAwait(
+          completeWith(baseTx().setAccountDetail(kUserId, "key", "quorum"),
+                       kSameDomainUserKeypair,
+                       kSecondDomainUserKeypair),
+          CHECK_TXS_QUANTITY(1));
+}
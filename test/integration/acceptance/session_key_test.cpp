@@ -0,0 +1,129 @@
+/**
+ * Copyright Soramitsu Co., Ltd. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+#include <gtest/gtest.h>
+#include "framework/integration_framework/integration_test_framework.hpp"
+#include "instantiate_test_suite.hpp"
+#include "integration/acceptance/acceptance_fixture.hpp"
+
+using namespace integration_framework;
+using namespace shared_model;
+using namespace common_constants;
+
+using iroha::StorageType;
+using shared_model::interface::permissions::Role;
+using shared_model::interface::types::PublicKeyHexStringView;
+
+struct SessionKeyFixture : AcceptanceFixture,
+                           ::testing::WithParamInterface<StorageType> {
+  /// Authorize the key of the same domain user to add the asset for the user
+  auto authorize(interface::types::HeightType validity) {
+    return complete(baseTx().authorizeSessionKey(
+        kUserId,
+        PublicKeyHexStringView{kSameDomainUserKeypair.publicKey()},
+        {Role::kAddAssetQty},
+        kAssetId,
+        validity));
+  }
+
+  /// Sign the transaction with the key of the user and the session key
+  template <typename TransactionBuilder>
+  auto completeWithSessionKey(TransactionBuilder builder) {
+    return builder.build()
+        .signAndAddSignature(kUserKeypair)
+        .signAndAddSignature(kSameDomainUserKeypair)
+        .finish();
+  }
+
+  /// Transaction of the user within the scope of the session key
+  auto addAsset() {
+    return completeWithSessionKey(
+        baseTx().addAssetQuantity(kAssetId, "1.0"));
+  }
+
+  /// Transaction of the user out of the scope of the session key
+  auto setDetail() {
+    return completeWithSessionKey(
+        baseTx().setAccountDetail(kUserId, "key", "value"));
+  }
+
+  /// Transaction within the scope signed with the session key only
+  auto addAssetWithSessionKeyOnly() {
+    return complete(baseTx().addAssetQuantity(kAssetId, "1.0"),
+                    kSameDomainUserKeypair);
+  }
+
+  /// Transaction out of the scope signed with the session key only
+  auto setDetailWithSessionKeyOnly() {
+    return complete(baseTx().setAccountDetail(kUserId, "key", "value"),
+                    kSameDomainUserKeypair);
+  }
+
+  IntegrationTestFramework &prepareState(IntegrationTestFramework &itf) {
+    return itf.setInitialState(kAdminKeypair)
+        .sendTxAwait(makeUserWithPerms({Role::kAddSignatory,
+                                        Role::kAddAssetQty,
+                                        Role::kSetDetail}),
+                     CHECK_TXS_QUANTITY(1));
+  }
+};
+
+INSTANTIATE_TEST_SUITE_P_DifferentStorageTypes(SessionKeyFixture);
+
+/**
+ * @given a user with a session key allowed to add the asset
+ * @when transactions of the user are signed with the session key
+ * @then the transaction within the scope of the key is committed
+ * @and the transaction out of the scope is rejected
+ */
+TEST_P(SessionKeyFixture, Scope) {
+  IntegrationTestFramework itf(1, GetParam());
+  prepareState(itf)
+      .sendTxAwait(authorize(10), CHECK_TXS_QUANTITY(1))
+      .sendTxAwait(addAsset(), CHECK_TXS_QUANTITY(1))
+      .sendTxAwait(setDetail(), CHECK_TXS_QUANTITY(0));
+}
+
+/**
+ * @given a user with a session key allowed to add the asset
+ * @when transactions of the user are signed with the session key only
+ * @then the transaction within the scope of the key is committed
+ * @and the transaction out of the scope is rejected, the key does not count
+ * towards the quorum of the account
+ */
+TEST_P(SessionKeyFixture, SessionKeyOnly) {
+  IntegrationTestFramework itf(1, GetParam());
+  prepareState(itf)
+      .sendTxAwait(authorize(10), CHECK_TXS_QUANTITY(1))
+      .sendTxAwait(addAssetWithSessionKeyOnly(), CHECK_TXS_QUANTITY(1))
+      .sendTxAwait(setDetailWithSessionKeyOnly(), CHECK_TXS_QUANTITY(0));
+}
+
+/**
+ * @given a user with a session key valid for one block
+ * @when transactions of the user are signed with the session key
+ * @then a transaction is committed in the next block
+ * @and transactions are rejected after it
+ */
+TEST_P(SessionKeyFixture, Expiration) {
+  IntegrationTestFramework itf(1, GetParam());
+  prepareState(itf)
+      .sendTxAwait(authorize(1), CHECK_TXS_QUANTITY(1))
+      .sendTxAwait(addAsset(), CHECK_TXS_QUANTITY(1))
+      .sendTxAwait(addAsset(), CHECK_TXS_QUANTITY(0));
+}
+
+/**
+ * @given a user with a session key
+ * @when the key is revoked with zero validity
+ * @then transactions signed with the key are rejected
+ */
+TEST_P(SessionKeyFixture, Revocation) {
+  IntegrationTestFramework itf(1, GetParam());
+  prepareState(itf)
+      .sendTxAwait(authorize(10), CHECK_TXS_QUANTITY(1))
+      .sendTxAwait(authorize(0), CHECK_TXS_QUANTITY(1))
+      .sendTxAwait(addAsset(), CHECK_TXS_QUANTITY(0));
+}
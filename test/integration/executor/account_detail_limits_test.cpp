@@ -0,0 +1,142 @@
+/**
+ * Copyright Soramitsu Co., Ltd. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+#include "integration/executor/executor_fixture.hpp"
+
+#include <gtest/gtest.h>
+#include <algorithm>
+#include "ametsuchi/account_detail_limits.hpp"
+#include "framework/common_constants.hpp"
+#include "framework/result_gtest_checkers.hpp"
+#include "integration/executor/executor_fixture_param_provider.hpp"
+#include "integration/executor/query_permission_test.hpp"
+#include "interfaces/query_responses/settings_response.hpp"
+#include "module/shared_model/mock_objects_factories/mock_command_factory.hpp"
+#include "module/shared_model/mock_objects_factories/mock_query_factory.hpp"
+
+using namespace common_constants;
+using namespace executor_testing;
+using namespace shared_model::interface::types;
+
+using iroha::ametsuchi::kMaxAccountDetailKeysKey;
+using iroha::ametsuchi::kMaxAccountDetailValueSizeKey;
+using iroha::ametsuchi::QueryExecutorResult;
+using shared_model::interface::SettingsResponse;
+using shared_model::interface::permissions::Role;
+
+class AccountDetailLimitsTest : public ExecutorTestBase {
+ public:
+  iroha::ametsuchi::CommandResult setSetting(const SettingKeyType &key,
+                                             const SettingValueType &value) {
+    return getItf().executeMaintenanceCommand(
+        *getItf().getMockCommandFactory()->constructSetSettingValue(key,
+                                                                    value));
+  }
+
+  iroha::ametsuchi::CommandResult setDetail(
+      const AccountDetailKeyType &key, const AccountDetailValueType &value) {
+    return getItf().executeCommandAsAccount(
+        *getItf().getMockCommandFactory()->constructSetAccountDetail(
+            kAdminId, key, value),
+        kAdminId,
+        true);
+  }
+
+  iroha::ametsuchi::CommandResult compareAndSetDetail(
+      const AccountDetailKeyType &key, const AccountDetailValueType &value) {
+    return getItf().executeCommandAsAccount(
+        *getItf().getMockCommandFactory()->constructCompareAndSetAccountDetail(
+            kAdminId, key, value, std::nullopt, false),
+        kAdminId,
+        true);
+  }
+
+  /// Query the ledger settings.
+  QueryExecutorResult query(AccountIdType command_issuer = kAdminId) {
+    return getItf().executeQuery(
+        *getItf().getMockQueryFactory()->constructGetSettings(),
+        command_issuer);
+  }
+};
+
+using AccountDetailLimitsBasicTest = BasicExecutorTest<AccountDetailLimitsTest>;
+
+/**
+ * @given the limit of account detail value size
+ * @when details with values of the limit size and longer are set
+ * @then only the detail with the value of the limit size is set
+ */
+TEST_P(AccountDetailLimitsBasicTest, ValueSize) {
+  IROHA_ASSERT_RESULT_VALUE(setSetting(kMaxAccountDetailValueSizeKey, "4"));
+  IROHA_ASSERT_RESULT_VALUE(setDetail("key", "1234"));
+  checkCommandError(setDetail("key", "12345"), 5);
+  checkCommandError(compareAndSetDetail("other", "12345"), 5);
+}
+
+/**
+ * @given the limit of two account details
+ * @when details with two keys are set and a detail with the third key is set
+ * @then the existing details can be updated and the third one is rejected
+ */
+TEST_P(AccountDetailLimitsBasicTest, KeysCount) {
+  IROHA_ASSERT_RESULT_VALUE(setSetting(kMaxAccountDetailKeysKey, "2"));
+  IROHA_ASSERT_RESULT_VALUE(setDetail("first", "value"));
+  IROHA_ASSERT_RESULT_VALUE(setDetail("second", "value"));
+  IROHA_ASSERT_RESULT_VALUE(setDetail("first", "new value"));
+  checkCommandError(setDetail("third", "value"), 6);
+  checkCommandError(compareAndSetDetail("third", "value"), 6);
+}
+
+/**
+ * @given no account detail limits
+ * @when a limit is set to a value which is not an unsigned integer or is out
+ * of the bounds of the setting
+ * @then the command fails
+ */
+TEST_P(AccountDetailLimitsBasicTest, MalformedLimit) {
+  checkCommandError(setSetting(kMaxAccountDetailKeysKey, "-1"), 3);
+  checkCommandError(setSetting(kMaxAccountDetailValueSizeKey, "ten"), 3);
+  checkCommandError(setSetting(kMaxAccountDetailKeysKey, "0"), 3);
+}
+
+INSTANTIATE_TEST_SUITE_P(Base,
+                         AccountDetailLimitsBasicTest,
+                         executor_testing::getExecutorTestParams(),
+                         executor_testing::paramToString);
+
+using GetSettingsPermissionTest =
+    query_permission_test::QueryPermissionTest<AccountDetailLimitsTest>;
+
+/**
+ * @given the ledger with account detail limits set
+ * @when a spectator queries the settings
+ * @then the limits are returned along with the descriptors of the settings if
+ * the spectator has the permission
+ */
+TEST_P(GetSettingsPermissionTest, QueryPermissionTest) {
+  ASSERT_NO_FATAL_FAILURE(prepareState({}));
+  IROHA_ASSERT_RESULT_VALUE(setSetting(kMaxAccountDetailKeysKey, "16"));
+  IROHA_ASSERT_RESULT_VALUE(setSetting(kMaxAccountDetailValueSizeKey, "64"));
+  checkResponse<SettingsResponse>(
+      query(getSpectator()), [](const SettingsResponse &response) {
+        EXPECT_EQ(response.settings().at(kMaxAccountDetailKeysKey), "16");
+        EXPECT_EQ(response.settings().at(kMaxAccountDetailValueSizeKey), "64");
+        auto it = std::find_if(
+            response.descriptors().begin(),
+            response.descriptors().end(),
+            [](const auto &d) { return d.key == kMaxAccountDetailKeysKey; });
+        ASSERT_NE(it, response.descriptors().end());
+        EXPECT_EQ(it->type,
+                  SettingsResponse::SettingDescriptor::Type::kUnsigned);
+        EXPECT_EQ(it->min, 1);
+      });
+}
+
+INSTANTIATE_TEST_SUITE_P(Common,
+                         GetSettingsPermissionTest,
+                         query_permission_test::getParams({boost::none},
+                                                          {boost::none},
+                                                          {Role::kGetSettings}),
+                         query_permission_test::paramToString);
@@ -0,0 +1,110 @@
+/**
+ * Copyright Soramitsu Co., Ltd. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+#include "integration/executor/executor_fixture.hpp"
+
+#include <gtest/gtest.h>
+#include "ametsuchi/account_detail_limits.hpp"
+#include "framework/common_constants.hpp"
+#include "framework/result_gtest_checkers.hpp"
+#include "integration/executor/account_detail_checker.hpp"
+#include "integration/executor/executor_fixture_param_provider.hpp"
+#include "interfaces/query_responses/account_detail_response.hpp"
+#include "module/shared_model/mock_objects_factories/mock_command_factory.hpp"
+#include "module/shared_model/mock_objects_factories/mock_query_factory.hpp"
+
+using namespace common_constants;
+using namespace executor_testing;
+using namespace shared_model::interface::types;
+
+static const AccountDetailKeyType kKey{"config"};
+
+class AccountDetailPathTest : public ExecutorTestBase {
+ public:
+  iroha::ametsuchi::CommandResult setAtPath(
+      const std::string &path,
+      const AccountDetailValueType &value,
+      const std::optional<AccountDetailValueType> &old_value = std::nullopt) {
+    return getItf().executeCommandAsAccount(
+        *getItf().getMockCommandFactory()->constructSetAccountDetailAtPath(
+            kAdminId, kKey, path, value, old_value),
+        kAdminId,
+        true);
+  }
+
+  void checkDetail(const AccountDetailValueType &value) {
+    IROHA_ASSERT_RESULT_VALUE(
+        getItf()
+            .executeQueryAndConvertResult(
+                *getItf().getMockQueryFactory()->constructGetAccountDetail(
+                    kAdminId, kKey, kAdminId, std::nullopt))
+            .specific_response
+        | [&value](const auto &response) {
+            checkJsonData(response.detail(),
+                          DetailsByKeyByWriter{{{kAdminId, {{kKey, value}}}}});
+            return iroha::expected::Value<void>{};
+          });
+  }
+};
+
+using AccountDetailPathBasicTest = BasicExecutorTest<AccountDetailPathTest>;
+
+/**
+ * @given account without the detail
+ * @when values are set at nested paths of the detail
+ * @then the detail holds the object with the values and missing objects on
+ * the path are created
+ */
+TEST_P(AccountDetailPathBasicTest, SetNested) {
+  IROHA_ASSERT_RESULT_VALUE(setAtPath("limits.max", "10"));
+  IROHA_ASSERT_RESULT_VALUE(setAtPath("limits.currency", R"("usd")"));
+  IROHA_ASSERT_RESULT_VALUE(setAtPath("limits.max", "20"));
+  checkDetail(R"({"limits":{"max":20,"currency":"usd"}})");
+}
+
+/**
+ * @given account with the detail
+ * @when a value is set at the path with the old value
+ * @then the value is set only if the current value at the path matches
+ */
+TEST_P(AccountDetailPathBasicTest, OldValue) {
+  IROHA_ASSERT_RESULT_VALUE(setAtPath("limits", R"({"max": 10})"));
+  checkCommandError(setAtPath("limits.max", "20", std::string{"11"}), 4);
+  checkCommandError(setAtPath("limits.min", "1", std::string{"0"}), 4);
+  IROHA_ASSERT_RESULT_VALUE(
+      setAtPath("limits", R"({"max": 20})", std::string{R"({"max": 10})"}));
+  checkDetail(R"({"limits":{"max":20}})");
+}
+
+/**
+ * @given account with the detail holding a scalar at a path
+ * @when a value is set below the scalar or the value is not JSON
+ * @then the command fails
+ */
+TEST_P(AccountDetailPathBasicTest, NotAnObject) {
+  IROHA_ASSERT_RESULT_VALUE(setAtPath("limits", "10"));
+  checkCommandError(setAtPath("limits.max", "20"), 7);
+  checkCommandError(setAtPath("name", "not json"), 7);
+}
+
+/**
+ * @given the limit of the detail nesting depth
+ * @when values are set at paths of the limit depth and deeper
+ * @then only the value within the limit is set
+ */
+TEST_P(AccountDetailPathBasicTest, Depth) {
+  IROHA_ASSERT_RESULT_VALUE(getItf().executeMaintenanceCommand(
+      *getItf().getMockCommandFactory()->constructSetSettingValue(
+          iroha::ametsuchi::kMaxAccountDetailDepthKey, "2")));
+  IROHA_ASSERT_RESULT_VALUE(setAtPath("limits.max", "10"));
+  checkCommandError(setAtPath("limits.max", "[1]"), 7);
+  checkCommandError(setAtPath("limits.daily.max", "10"), 7);
+  checkDetail(R"({"limits":{"max":10}})");
+}
+
+INSTANTIATE_TEST_SUITE_P(Base,
+                         AccountDetailPathBasicTest,
+                         executor_testing::getExecutorTestParams(),
+                         executor_testing::paramToString);
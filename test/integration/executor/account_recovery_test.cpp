@@ -0,0 +1,161 @@
+/**
+ * Copyright Soramitsu Co., Ltd. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+#include "integration/executor/executor_fixture.hpp"
+
+#include <gtest/gtest.h>
+#include "framework/common_constants.hpp"
+#include "framework/result_gtest_checkers.hpp"
+#include "integration/executor/executor_fixture_param_provider.hpp"
+#include "interfaces/query_responses/account_response.hpp"
+#include "module/shared_model/mock_objects_factories/mock_command_factory.hpp"
+#include "module/shared_model/mock_objects_factories/mock_query_factory.hpp"
+
+using namespace std::literals;
+using namespace common_constants;
+using namespace executor_testing;
+
+using shared_model::interface::AccountResponse;
+using shared_model::interface::permissions::Grantable;
+using shared_model::interface::permissions::Role;
+using shared_model::interface::types::AccountIdType;
+using shared_model::interface::types::PublicKeyHexStringView;
+
+static const PublicKeyHexStringView kExtraSignatory{"extra_signatory"sv};
+static const PublicKeyHexStringView kRecoveryKey{"recovery_key"sv};
+
+class AccountRecoveryTest : public ExecutorTestBase {
+ public:
+  void SetUp() override {
+    ExecutorTestBase::SetUp();
+    IROHA_ASSERT_RESULT_VALUE(getItf().createUserWithPerms(
+        kUser,
+        kDomain,
+        PublicKeyHexStringView{kUserKeypair.publicKey()},
+        {Role::kRecoverMyAccount}));
+    IROHA_ASSERT_RESULT_VALUE(getItf().createUserWithPerms(
+        kSecondUser,
+        kDomain,
+        PublicKeyHexStringView{kSameDomainUserKeypair.publicKey()},
+        {}));
+  }
+
+  void grantRecovery() {
+    IROHA_ASSERT_RESULT_VALUE(getItf().executeCommandAsAccount(
+        *getItf().getMockCommandFactory()->constructGrantPermission(
+            kSameDomainUserId, Grantable::kRecoverMyAccount),
+        kUserId,
+        true));
+  }
+
+  iroha::ametsuchi::CommandResult deactivate(const AccountIdType &target,
+                                             const AccountIdType &issuer) {
+    return getItf().executeCommandAsAccount(
+        *getItf().getMockCommandFactory()->constructDeactivateAccount(target),
+        issuer,
+        true);
+  }
+
+  iroha::ametsuchi::CommandResult recover(const AccountIdType &target,
+                                          const AccountIdType &issuer) {
+    return getItf().executeCommandAsAccount(
+        *getItf().getMockCommandFactory()->constructRecoverAccount(
+            target, kRecoveryKey),
+        issuer,
+        true);
+  }
+
+  void checkQuorum(const AccountIdType &account_id,
+                   shared_model::interface::types::QuorumType quorum) {
+    checkSuccessfulResult<AccountResponse>(
+        getItf().executeQuery(
+            *getItf().getMockQueryFactory()->constructGetAccount(account_id)),
+        [quorum](const auto &response) {
+          EXPECT_EQ(response.account().quorum(), quorum);
+        });
+  }
+
+ protected:
+  PublicKeyHexStringView old_sig_{kUserKeypair.publicKey()};
+};
+
+using AccountRecoveryBasicTest = BasicExecutorTest<AccountRecoveryTest>;
+
+/**
+ * @given an active user
+ * @when the user deactivates the own account twice
+ * @then the first command succeeds and the second one fails
+ */
+TEST_P(AccountRecoveryBasicTest, DeactivateSelf) {
+  IROHA_ASSERT_RESULT_VALUE(deactivate(kUserId, kUserId));
+  checkCommandError(deactivate(kUserId, kUserId), 4);
+}
+
+/**
+ * @given two users without granted recovery permission
+ * @when the second user deactivates the account of the first one
+ * @then the command fails
+ */
+TEST_P(AccountRecoveryBasicTest, DeactivateNoPermission) {
+  checkCommandError(deactivate(kUserId, kSameDomainUserId), 2);
+}
+
+/**
+ * @given a user who granted recovery permission to the second user
+ * @when the second user deactivates the account of the first one
+ * @then the command succeeds
+ */
+TEST_P(AccountRecoveryBasicTest, DeactivateByGrantee) {
+  ASSERT_NO_FATAL_FAILURE(grantRecovery());
+  IROHA_ASSERT_RESULT_VALUE(deactivate(kUserId, kSameDomainUserId));
+}
+
+/**
+ * @given two users without granted recovery permission
+ * @when the second user recovers the account of the first one
+ * @then the command fails and the signatories are unchanged
+ */
+TEST_P(AccountRecoveryBasicTest, RecoverNoPermission) {
+  checkCommandError(recover(kUserId, kSameDomainUserId), 2);
+  checkSignatories(kUserId, {old_sig_});
+}
+
+/**
+ * @given a deactivated user with 2 signatories and quorum 2 who granted
+ * recovery permission to the second user
+ * @when the second user recovers the account of the first one
+ * @then the signatories are replaced with the recovery key and quorum is 1
+ * @and the account can be deactivated again
+ */
+TEST_P(AccountRecoveryBasicTest, RecoverByGrantee) {
+  ASSERT_NO_FATAL_FAILURE(grantRecovery());
+  IROHA_ASSERT_RESULT_VALUE(getItf().executeMaintenanceCommand(
+      *getItf().getMockCommandFactory()->constructAddSignatory(
+          kExtraSignatory, kUserId)));
+  IROHA_ASSERT_RESULT_VALUE(getItf().executeMaintenanceCommand(
+      *getItf().getMockCommandFactory()->constructSetQuorum(kUserId, 2)));
+  IROHA_ASSERT_RESULT_VALUE(deactivate(kUserId, kUserId));
+
+  IROHA_ASSERT_RESULT_VALUE(recover(kUserId, kSameDomainUserId));
+
+  checkSignatories(kUserId, {kRecoveryKey});
+  checkQuorum(kUserId, 1);
+  IROHA_ASSERT_RESULT_VALUE(deactivate(kUserId, kUserId));
+}
+
+/**
+ * @given an active user
+ * @when the user deactivates and recovers a nonexistent account
+ * @then both commands fail
+ */
+TEST_P(AccountRecoveryBasicTest, NonExistentAccount) {
+  checkCommandError(deactivate(kSecondDomainUserId, kUserId), 3);
+  checkCommandError(recover(kSecondDomainUserId, kUserId), 3);
+}
+
+INSTANTIATE_TEST_SUITE_P(Base,
+                         AccountRecoveryBasicTest,
+                         executor_testing::getExecutorTestParams(),
+                         executor_testing::paramToString);
@@ -0,0 +1,153 @@
+/**
+ * Copyright Soramitsu Co., Ltd. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+#include "integration/executor/executor_fixture.hpp"
+
+#include <gtest/gtest.h>
+#include "framework/common_constants.hpp"
+#include "framework/result_gtest_checkers.hpp"
+#include "integration/executor/executor_fixture_param_provider.hpp"
+#include "module/shared_model/mock_objects_factories/mock_command_factory.hpp"
+
+using namespace common_constants;
+using namespace executor_testing;
+
+using shared_model::interface::permissions::Role;
+using shared_model::interface::types::AccountIdType;
+using shared_model::interface::types::DomainIdType;
+using shared_model::interface::types::PublicKeyHexStringView;
+
+class ArchiveDomainTest : public ExecutorTestBase {
+ public:
+  void SetUp() override {
+    ExecutorTestBase::SetUp();
+    IROHA_ASSERT_RESULT_VALUE(getItf().createDomain(kSecondDomain));
+  }
+
+  iroha::ametsuchi::CommandResult archive(const DomainIdType &domain,
+                                          const AccountIdType &issuer) {
+    return getItf().executeCommandAsAccount(
+        *getItf().getMockCommandFactory()->constructArchiveDomain(domain),
+        issuer,
+        true);
+  }
+
+  iroha::ametsuchi::CommandResult createAccount(const DomainIdType &domain) {
+    return getItf().executeCommandAsAccount(
+        *getItf().getMockCommandFactory()->constructCreateAccount(
+            kSecondUser,
+            domain,
+            PublicKeyHexStringView{kSecondDomainUserKeypair.publicKey()}),
+        kAdminId,
+        true);
+  }
+
+  /// Execute the command as admin
+  template <typename Command>
+  iroha::ametsuchi::CommandResult execute(Command &&command) {
+    return getItf().executeCommandAsAccount(*command, kAdminId, true);
+  }
+
+  iroha::ametsuchi::CommandResult createAsset(const DomainIdType &domain) {
+    return getItf().executeCommandAsAccount(
+        *getItf().getMockCommandFactory()->constructCreateAsset(
+            kAssetName, domain, 1),
+        kAdminId,
+        true);
+  }
+};
+
+using ArchiveDomainBasicTest = BasicExecutorTest<ArchiveDomainTest>;
+
+/**
+ * @given an account with can_archive_domain permission
+ * @when the account archives a domain
+ * @then accounts and assets can not be created in the domain anymore
+ */
+TEST_P(ArchiveDomainBasicTest, Archive) {
+  IROHA_ASSERT_RESULT_VALUE(getItf().createUserWithPerms(
+      kUser,
+      kDomain,
+      PublicKeyHexStringView{kUserKeypair.publicKey()},
+      {Role::kArchiveDomain}));
+
+  IROHA_ASSERT_RESULT_VALUE(archive(kSecondDomain, kUserId));
+
+  checkCommandError(createAccount(kSecondDomain), 5);
+  checkCommandError(createAsset(kSecondDomain), 5);
+}
+
+/**
+ * @given an account without can_archive_domain permission
+ * @when the account archives a domain
+ * @then the command fails and the domain stays open
+ */
+TEST_P(ArchiveDomainBasicTest, NoPermission) {
+  IROHA_ASSERT_RESULT_VALUE(getItf().createUserWithPerms(
+      kUser, kDomain, PublicKeyHexStringView{kUserKeypair.publicKey()}, {}));
+
+  checkCommandError(archive(kSecondDomain, kUserId), 2);
+
+  IROHA_ASSERT_RESULT_VALUE(createAccount(kSecondDomain));
+  IROHA_ASSERT_RESULT_VALUE(createAsset(kSecondDomain));
+}
+
+/**
+ * @given a domain which does not exist
+ * @when the domain is archived
+ * @then the command fails
+ */
+TEST_P(ArchiveDomainBasicTest, NoDomain) {
+  checkCommandError(archive("nonexistent", kAdminId), 3);
+}
+
+/**
+ * @given an archived domain
+ * @when the domain is archived again
+ * @then the command fails
+ */
+TEST_P(ArchiveDomainBasicTest, AlreadyArchived) {
+  IROHA_ASSERT_RESULT_VALUE(archive(kSecondDomain, kAdminId));
+
+  checkCommandError(archive(kSecondDomain, kAdminId), 4);
+}
+
+/**
+ * @given an archived domain with an account and an asset
+ * @when commands write to the account or the asset
+ * @then the commands fail
+ */
+TEST_P(ArchiveDomainBasicTest, EntitiesAreReadOnly) {
+  IROHA_ASSERT_RESULT_VALUE(getItf().createUserWithPerms(
+      kSecondUser,
+      kSecondDomain,
+      PublicKeyHexStringView{kSecondDomainUserKeypair.publicKey()},
+      {}));
+  IROHA_ASSERT_RESULT_VALUE(createAsset(kSecondDomain));
+  IROHA_ASSERT_RESULT_VALUE(archive(kSecondDomain, kAdminId));
+
+  auto const &factory = getItf().getMockCommandFactory();
+  shared_model::interface::Amount const amount{"1.0"};
+  checkCommandError(
+      execute(factory->constructAddAssetQuantity(kSecondDomainAssetId, amount)),
+      11);
+  checkCommandError(execute(factory->constructSubtractAssetQuantity(
+                        kSecondDomainAssetId, amount)),
+                    11);
+  checkCommandError(
+      execute(factory->constructTransferAsset(
+          kAdminId, kSecondDomainUserId, kAssetId, "into archive", amount)),
+      11);
+  checkCommandError(execute(factory->constructSetAccountDetail(
+                        kSecondDomainUserId, "key", "value")),
+                    11);
+  checkCommandError(
+      execute(factory->constructSetQuorum(kSecondDomainUserId, 1)), 11);
+}
+
+INSTANTIATE_TEST_SUITE_P(Base,
+                         ArchiveDomainBasicTest,
+                         executor_testing::getExecutorTestParams(),
+                         executor_testing::paramToString);
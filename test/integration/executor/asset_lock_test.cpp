@@ -0,0 +1,147 @@
+/**
+ * Copyright Soramitsu Co., Ltd. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+#include "integration/executor/executor_fixture.hpp"
+
+#include <gtest/gtest.h>
+#include "ametsuchi/asset_lock.hpp"
+#include "framework/common_constants.hpp"
+#include "framework/result_gtest_checkers.hpp"
+#include "integration/executor/executor_fixture_param_provider.hpp"
+#include "interfaces/common_objects/amount.hpp"
+#include "module/shared_model/mock_objects_factories/mock_command_factory.hpp"
+
+using namespace common_constants;
+using namespace executor_testing;
+using namespace shared_model::interface::types;
+
+using shared_model::interface::Amount;
+using shared_model::interface::permissions::Role;
+
+static const Amount kAmount{std::string{"1.0"}};
+static const std::string kPreimage(64, 'a');
+static const std::string kWrongPreimage(64, 'b');
+
+class AssetLockTest : public ExecutorTestBase {
+ public:
+  void SetUp() override {
+    ExecutorTestBase::SetUp();
+    ASSERT_NO_FATAL_FAILURE(createAsset(kAssetName, kDomain, 1));
+    IROHA_ASSERT_RESULT_VALUE(getItf().createUserWithPerms(
+        kSecondUser,
+        kDomain,
+        PublicKeyHexStringView{kSameDomainUserKeypair.publicKey()},
+        {Role::kReceive}));
+    hash_ = iroha::ametsuchi::assetLockHash(kPreimage).assumeValue();
+  }
+
+  void addSourceUser(const shared_model::interface::RolePermissionSet &perms) {
+    IROHA_ASSERT_RESULT_VALUE(getItf().createUserWithPerms(
+        kUser,
+        kDomain,
+        PublicKeyHexStringView{kUserKeypair.publicKey()},
+        perms));
+    ASSERT_NO_FATAL_FAILURE(addAsset(kUserId, kAssetId, Amount{"10.0"}));
+  }
+
+  iroha::ametsuchi::CommandResult lock(const Amount &amount = kAmount) {
+    return getItf().executeCommandAsAccount(
+        *getItf().getMockCommandFactory()->constructLockAsset(
+            kUserId, kSameDomainUserId, kAssetId, amount, hash_, 10),
+        kUserId,
+        true);
+  }
+
+  iroha::ametsuchi::CommandResult claim(const std::string &preimage) {
+    return getItf().executeCommandAsAccount(
+        *getItf().getMockCommandFactory()->constructClaimWithPreimage(
+            preimage),
+        kSameDomainUserId,
+        true);
+  }
+
+  iroha::ametsuchi::CommandResult refund() {
+    return getItf().executeCommandAsAccount(
+        *getItf().getMockCommandFactory()->constructRefundAfterTimeout(hash_),
+        kUserId,
+        true);
+  }
+
+ protected:
+  std::string hash_;
+};
+
+using AssetLockBasicTest = BasicExecutorTest<AssetLockTest>;
+
+/**
+ * @given a user with permission to transfer assets
+ * @when the user locks assets for another user who claims them with the
+ * preimage
+ * @then the assets are withdrawn on the lock and credited on the claim
+ */
+TEST_P(AssetLockBasicTest, Claim) {
+  ASSERT_NO_FATAL_FAILURE(addSourceUser({Role::kTransfer}));
+
+  IROHA_ASSERT_RESULT_VALUE(lock());
+  checkAssetQuantities(kUserId, {AssetQuantity{kAssetId, Amount{"9.0"}}});
+  checkAssetQuantities(kSameDomainUserId, {});
+
+  IROHA_ASSERT_RESULT_VALUE(claim(kPreimage));
+  checkAssetQuantities(kSameDomainUserId, {AssetQuantity{kAssetId, kAmount}});
+
+  checkCommandError(claim(kPreimage), 3);
+}
+
+/**
+ * @given a lock of assets
+ * @when the lock is claimed with a wrong preimage or refunded before the
+ * timeout
+ * @then the commands fail and the assets stay locked
+ */
+TEST_P(AssetLockBasicTest, WrongPreimageAndEarlyRefund) {
+  ASSERT_NO_FATAL_FAILURE(addSourceUser({Role::kTransfer}));
+  IROHA_ASSERT_RESULT_VALUE(lock());
+
+  checkCommandError(claim(kWrongPreimage), 3);
+  checkCommandError(refund(), 4);
+
+  checkAssetQuantities(kUserId, {AssetQuantity{kAssetId, Amount{"9.0"}}});
+  checkAssetQuantities(kSameDomainUserId, {});
+}
+
+/**
+ * @given a lock of assets
+ * @when the assets are locked again with the same hash or the amount exceeds
+ * the balance
+ * @then the commands fail
+ */
+TEST_P(AssetLockBasicTest, InvalidLock) {
+  ASSERT_NO_FATAL_FAILURE(addSourceUser({Role::kTransfer}));
+  IROHA_ASSERT_RESULT_VALUE(lock());
+
+  checkCommandError(lock(), 5);
+  hash_ = iroha::ametsuchi::assetLockHash(kWrongPreimage).assumeValue();
+  checkCommandError(lock(Amount{"100.0"}), 6);
+
+  checkAssetQuantities(kUserId, {AssetQuantity{kAssetId, Amount{"9.0"}}});
+}
+
+/**
+ * @given a user without permission to transfer assets
+ * @when the user locks own assets
+ * @then the command fails and the balance is unchanged
+ */
+TEST_P(AssetLockBasicTest, NoPermission) {
+  ASSERT_NO_FATAL_FAILURE(addSourceUser({}));
+
+  checkCommandError(lock(), 2);
+
+  checkAssetQuantities(kUserId, {AssetQuantity{kAssetId, Amount{"10.0"}}});
+}
+
+INSTANTIATE_TEST_SUITE_P(Base,
+                         AssetLockBasicTest,
+                         executor_testing::getExecutorTestParams(),
+                         executor_testing::paramToString);
@@ -0,0 +1,169 @@
+/**
+ * Copyright Soramitsu Co., Ltd. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+#include "integration/executor/executor_fixture.hpp"
+
+#include <gtest/gtest.h>
+#include "ametsuchi/supply_policy.hpp"
+#include "framework/common_constants.hpp"
+#include "framework/result_gtest_checkers.hpp"
+#include "integration/executor/query_permission_test.hpp"
+#include "interfaces/common_objects/amount.hpp"
+#include "interfaces/query_responses/asset_stats_response.hpp"
+#include "module/shared_model/mock_objects_factories/mock_command_factory.hpp"
+#include "module/shared_model/mock_objects_factories/mock_query_factory.hpp"
+
+using namespace common_constants;
+using namespace executor_testing;
+using namespace shared_model::interface::types;
+
+using iroha::ametsuchi::QueryExecutorResult;
+using shared_model::interface::Amount;
+using shared_model::interface::AssetStatsResponse;
+using shared_model::interface::permissions::Role;
+
+struct AssetStatsTest : public ExecutorTestBase {
+  void prepareAsset() {
+    SCOPED_TRACE("AssetStatsTest::prepareAsset");
+    createAsset(kAssetName, kDomain, 2);
+  }
+
+  /// Query asset stats.
+  QueryExecutorResult query(AccountIdType command_issuer = kAdminId) {
+    return getItf().executeQuery(
+        *getItf().getMockQueryFactory()->constructGetAssetStats(kAssetId),
+        command_issuer);
+  }
+
+  /// Check the asset stats returned by the query.
+  void checkStats(const Amount &minted,
+                  const Amount &burned,
+                  const Amount &circulating,
+                  uint64_t holders_count) {
+    checkSuccessfulResult<AssetStatsResponse>(
+        query(), [&](const AssetStatsResponse &response) {
+          EXPECT_EQ(response.assetId(), kAssetId);
+          EXPECT_EQ(response.minted(), minted);
+          EXPECT_EQ(response.burned(), burned);
+          EXPECT_EQ(response.circulating(), circulating);
+          EXPECT_EQ(response.holdersCount(), holders_count);
+        });
+  }
+
+  iroha::ametsuchi::CommandResult issue(const Amount &amount) {
+    return getItf().executeMaintenanceCommand(
+        *getItf().getMockCommandFactory()->constructAddAssetQuantity(kAssetId,
+                                                                     amount));
+  }
+
+  iroha::ametsuchi::CommandResult burn(const Amount &amount) {
+    return getItf().executeMaintenanceCommand(
+        *getItf().getMockCommandFactory()->constructSubtractAssetQuantity(
+            kAssetId, amount));
+  }
+
+  iroha::ametsuchi::CommandResult transfer(const AccountIdType &source,
+                                           const AccountIdType &destination,
+                                           const Amount &amount) {
+    return getItf().executeMaintenanceCommand(
+        *getItf().getMockCommandFactory()->constructTransferAsset(
+            source, destination, kAssetId, "asset stats test", amount));
+  }
+};
+
+using AssetStatsBasicTest = BasicExecutorTest<AssetStatsTest>;
+
+/**
+ * @given an asset which was never issued
+ * @when GetAssetStats is queried
+ * @then all the stats are zero
+ */
+TEST_P(AssetStatsBasicTest, NotIssued) {
+  prepareAsset();
+  checkStats(Amount{"0.00"}, Amount{"0.00"}, Amount{"0.00"}, 0);
+}
+
+/**
+ * @given an issued asset
+ * @when the asset is transferred and partially burned
+ * @then minted, burned and circulating quantities are updated
+ * @and the holders count follows the number of accounts with positive balance
+ */
+TEST_P(AssetStatsBasicTest, IssueTransferBurn) {
+  prepareAsset();
+  IROHA_ASSERT_RESULT_VALUE(getItf().createUserWithPerms(
+      kUser,
+      kDomain,
+      PublicKeyHexStringView{kUserKeypair.publicKey()},
+      {Role::kReceive, Role::kTransfer}));
+
+  IROHA_ASSERT_RESULT_VALUE(issue(Amount{"10.00"}));
+  checkStats(Amount{"10.00"}, Amount{"0.00"}, Amount{"10.00"}, 1);
+
+  IROHA_ASSERT_RESULT_VALUE(transfer(kAdminId, kUserId, Amount{"4.00"}));
+  checkStats(Amount{"10.00"}, Amount{"0.00"}, Amount{"10.00"}, 2);
+
+  IROHA_ASSERT_RESULT_VALUE(burn(Amount{"6.00"}));
+  checkStats(Amount{"10.00"}, Amount{"6.00"}, Amount{"4.00"}, 1);
+
+  IROHA_ASSERT_RESULT_VALUE(transfer(kUserId, kAdminId, Amount{"4.00"}));
+  checkStats(Amount{"10.00"}, Amount{"6.00"}, Amount{"4.00"}, 1);
+}
+
+/**
+ * @given an issued asset with the burn fee
+ * @when the asset is transferred
+ * @then the fee is counted as burned
+ */
+TEST_P(AssetStatsBasicTest, BurnFee) {
+  prepareAsset();
+  IROHA_ASSERT_RESULT_VALUE(getItf().createUserWithPerms(
+      kUser,
+      kDomain,
+      PublicKeyHexStringView{kUserKeypair.publicKey()},
+      {Role::kReceive}));
+  IROHA_ASSERT_RESULT_VALUE(getItf().executeMaintenanceCommand(
+      *getItf().getMockCommandFactory()->constructSetSettingValue(
+          iroha::ametsuchi::supplyPolicyKey(kAssetId),
+          R"({"burn_fee_basis_points": 150})")));
+
+  IROHA_ASSERT_RESULT_VALUE(issue(Amount{"100.00"}));
+  IROHA_ASSERT_RESULT_VALUE(transfer(kAdminId, kUserId, Amount{"10.00"}));
+  checkStats(Amount{"100.00"}, Amount{"0.15"}, Amount{"99.85"}, 2);
+}
+
+/**
+ * @given a user with all related permissions
+ * @when GetAssetStats is queried on a nonexistent asset
+ * @then there is an error
+ */
+TEST_P(AssetStatsBasicTest, InvalidNoAsset) {
+  checkQueryError<shared_model::interface::NoAssetErrorResponse>(
+      query(), error_codes::kNoStatefulError);
+}
+
+INSTANTIATE_TEST_SUITE_P(Base,
+                         AssetStatsBasicTest,
+                         executor_testing::getExecutorTestParams(),
+                         executor_testing::paramToString);
+
+using AssetStatsPermissionTest =
+    query_permission_test::QueryPermissionTest<AssetStatsTest>;
+
+TEST_P(AssetStatsPermissionTest, QueryPermissionTest) {
+  ASSERT_NO_FATAL_FAILURE(prepareState({}));
+  prepareAsset();
+  checkResponse<AssetStatsResponse>(
+      query(getSpectator()), [](const AssetStatsResponse &response) {
+        EXPECT_EQ(response.assetId(), kAssetId);
+      });
+}
+
+INSTANTIATE_TEST_SUITE_P(Common,
+                         AssetStatsPermissionTest,
+                         query_permission_test::getParams({boost::none},
+                                                          {boost::none},
+                                                          {Role::kReadAssets}),
+                         query_permission_test::paramToString);
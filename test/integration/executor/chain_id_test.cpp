@@ -0,0 +1,88 @@
+/**
+ * Copyright Soramitsu Co., Ltd. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+#include "integration/executor/executor_fixture.hpp"
+
+#include <gtest/gtest.h>
+#include "ametsuchi/setting_query.hpp"
+#include "framework/common_constants.hpp"
+#include "framework/result_gtest_checkers.hpp"
+#include "integration/executor/executor_fixture_param_provider.hpp"
+#include "module/shared_model/builders/protobuf/test_transaction_builder.hpp"
+#include "module/shared_model/mock_objects_factories/mock_command_factory.hpp"
+
+using namespace common_constants;
+using namespace executor_testing;
+
+class ChainIdTest : public ExecutorTestBase {
+ public:
+  iroha::ametsuchi::CommandResult setChainId(const std::string &chain_id) {
+    return getItf().executeMaintenanceCommand(
+        *getItf().getMockCommandFactory()->constructSetSettingValue(
+            iroha::ametsuchi::kChainIdKey, chain_id));
+  }
+
+  iroha::expected::Result<void, iroha::ametsuchi::TxExecutionError> execute(
+      const std::string &chain_id) {
+    auto tx = TestTransactionBuilder{}
+                  .creatorAccountId(kAdminId)
+                  .createdTime(1000000)
+                  .quorum(1)
+                  .chainId(chain_id)
+                  .setAccountDetail(kAdminId, "key", "value")
+                  .build();
+    return getItf().executeTransaction(tx);
+  }
+
+  void checkRejected(const std::string &chain_id) {
+    auto error = iroha::expected::resultToOptionalError(execute(chain_id));
+    ASSERT_TRUE(error);
+    EXPECT_EQ(error->command_error.command_name, "ChainId");
+    EXPECT_EQ(error->command_error.error_code, 3);
+  }
+};
+
+using ChainIdBasicTest = BasicExecutorTest<ChainIdTest>;
+
+/**
+ * @given a ledger without the ChainId setting
+ * @when transactions with and without a chain id are applied
+ * @then only the transaction without a chain id is accepted
+ */
+TEST_P(ChainIdBasicTest, Unbound) {
+  IROHA_ASSERT_RESULT_VALUE(execute(""));
+  checkRejected("main");
+}
+
+/**
+ * @given a ledger with the ChainId setting
+ * @when transactions with the same, another and no chain id are applied
+ * @then only the transaction with the same chain id is accepted
+ */
+TEST_P(ChainIdBasicTest, Bound) {
+  IROHA_ASSERT_RESULT_VALUE(setChainId("main"));
+
+  IROHA_ASSERT_RESULT_VALUE(execute("main"));
+  checkRejected("test");
+  checkRejected("");
+}
+
+/**
+ * @given a ledger with the ChainId setting
+ * @when the setting is changed
+ * @then transactions are checked against the new value
+ */
+TEST_P(ChainIdBasicTest, Changed) {
+  IROHA_ASSERT_RESULT_VALUE(setChainId("main"));
+  IROHA_ASSERT_RESULT_VALUE(setChainId("next"));
+
+  IROHA_ASSERT_RESULT_VALUE(execute("next"));
+  checkRejected("main");
+}
+
+INSTANTIATE_TEST_SUITE_P(Base,
+                         ChainIdBasicTest,
+                         executor_testing::getExecutorTestParams(),
+                         executor_testing::paramToString);
@@ -0,0 +1,105 @@
+/**
+ * Copyright Soramitsu Co., Ltd. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+#include "integration/executor/executor_fixture.hpp"
+
+#include <fmt/core.h>
+#include <gtest/gtest.h>
+#include "ametsuchi/domain_policy.hpp"
+#include "framework/common_constants.hpp"
+#include "framework/result_gtest_checkers.hpp"
+#include "integration/executor/executor_fixture_param_provider.hpp"
+#include "module/shared_model/mock_objects_factories/mock_command_factory.hpp"
+
+using namespace common_constants;
+using namespace executor_testing;
+using namespace shared_model::interface::types;
+
+using iroha::ametsuchi::domainPolicyKey;
+
+class DomainPolicyTest : public ExecutorTestBase {
+ public:
+  void SetUp() override {
+    ExecutorTestBase::SetUp();
+    IROHA_ASSERT_RESULT_VALUE(getItf().createUserWithPerms(
+        kUser, kDomain, PublicKeyHexStringView{kUserKeypair.publicKey()}, {}));
+  }
+
+  iroha::ametsuchi::CommandResult setPolicy(const std::string &policy) {
+    return getItf().executeMaintenanceCommand(
+        *getItf().getMockCommandFactory()->constructSetSettingValue(
+            domainPolicyKey(kDomain), policy));
+  }
+
+  /// Create the second account of the domain.
+  iroha::ametsuchi::CommandResult createAccount() {
+    return getItf().executeMaintenanceCommand(
+        *getItf().getMockCommandFactory()->constructCreateAccount(
+            kSecondUser,
+            kDomain,
+            PublicKeyHexStringView{kSameDomainUserKeypair.publicKey()}));
+  }
+
+  /// Set a detail of the second account as the user.
+  iroha::ametsuchi::CommandResult setDetail() {
+    return getItf().executeCommandAsAccount(
+        *getItf().getMockCommandFactory()->constructSetAccountDetail(
+            kSameDomainUserId, "key", "value"),
+        kUserId,
+        true);
+  }
+};
+
+using DomainPolicyBasicTest = BasicExecutorTest<DomainPolicyTest>;
+
+/**
+ * @given domain policy granting the detail permission to the user
+ * @when an account is created in the domain
+ * @then the user can set details of the new account
+ */
+TEST_P(DomainPolicyBasicTest, GrantsApplied) {
+  IROHA_ASSERT_RESULT_VALUE(setPolicy(fmt::format(
+      R"({{"grants": {{"{}": ["can_set_my_account_detail"]}}}})", kUserId)));
+  IROHA_ASSERT_RESULT_VALUE(createAccount());
+  IROHA_ASSERT_RESULT_VALUE(setDetail());
+}
+
+/**
+ * @given no domain policy
+ * @when an account is created in the domain
+ * @then the user cannot set details of the new account
+ */
+TEST_P(DomainPolicyBasicTest, NoPolicy) {
+  IROHA_ASSERT_RESULT_VALUE(createAccount());
+  checkCommandError(setDetail(), 2);
+}
+
+/**
+ * @given domain policy granting a permission to an account which does not
+ * exist
+ * @when an account is created in the domain
+ * @then the account is created and the grant is skipped
+ */
+TEST_P(DomainPolicyBasicTest, NoPermittee) {
+  IROHA_ASSERT_RESULT_VALUE(setPolicy(
+      R"({"grants": {"nobody@domain": ["can_set_my_account_detail"]}})"));
+  IROHA_ASSERT_RESULT_VALUE(createAccount());
+  checkCommandError(setDetail(), 2);
+}
+
+/**
+ * @given no domain policy
+ * @when a policy granting an unknown permission is set
+ * @then the command fails
+ */
+TEST_P(DomainPolicyBasicTest, UnknownPermission) {
+  checkCommandError(
+      setPolicy(R"({"grants": {"nobody@domain": ["can_do_anything"]}})"), 3);
+}
+
+INSTANTIATE_TEST_SUITE_P(Base,
+                         DomainPolicyBasicTest,
+                         executor_testing::getExecutorTestParams(),
+                         executor_testing::paramToString);
@@ -0,0 +1,134 @@
+/**
+ * Copyright Soramitsu Co., Ltd. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+#include "integration/executor/executor_fixture.hpp"
+
+#include <gtest/gtest.h>
+#include "ametsuchi/supply_policy.hpp"
+#include "backend/protobuf/queries/proto_estimate_fee.hpp"
+#include "framework/common_constants.hpp"
+#include "framework/result_gtest_checkers.hpp"
+#include "interfaces/common_objects/amount.hpp"
+#include "interfaces/query_responses/fee_estimation_response.hpp"
+#include "module/shared_model/mock_objects_factories/mock_command_factory.hpp"
+
+using namespace common_constants;
+using namespace executor_testing;
+using namespace shared_model::interface::types;
+
+using iroha::ametsuchi::QueryExecutorResult;
+using shared_model::interface::Amount;
+using shared_model::interface::FeeEstimationResponse;
+using shared_model::interface::permissions::Role;
+
+struct EstimateFeeTest : public ExecutorTestBase {
+  void SetUp() override {
+    ExecutorTestBase::SetUp();
+    ASSERT_NO_FATAL_FAILURE(createAsset(kAssetName, kDomain, 2));
+  }
+
+  void setPolicy(const std::string &policy) {
+    SCOPED_TRACE("EstimateFeeTest::setPolicy");
+    IROHA_ASSERT_RESULT_VALUE(getItf().executeMaintenanceCommand(
+        *getItf().getMockCommandFactory()->constructSetSettingValue(
+            iroha::ametsuchi::supplyPolicyKey(kAssetId), policy)));
+  }
+
+  /// Add a TransferAsset command to the estimated transaction.
+  void addTransfer(const std::string &amount) {
+    auto transfer = query_.mutable_payload()
+                        ->mutable_estimate_fee()
+                        ->add_commands()
+                        ->mutable_transfer_asset();
+    transfer->set_src_account_id(kAdminId);
+    transfer->set_dest_account_id(kUserId);
+    transfer->set_asset_id(kAssetId);
+    transfer->set_amount(amount);
+  }
+
+  /// Add a command which is not charged to the estimated transaction.
+  void addFreeCommand() {
+    auto detail = query_.mutable_payload()
+                      ->mutable_estimate_fee()
+                      ->add_commands()
+                      ->mutable_set_account_detail();
+    detail->set_account_id(kAdminId);
+    detail->set_key("key");
+    detail->set_value("value");
+  }
+
+  QueryExecutorResult estimate(const AccountIdType &account_id) {
+    shared_model::proto::EstimateFee query{query_};
+    return getItf().executeQuery(query, account_id);
+  }
+
+  void checkFees(
+      const std::vector<FeeEstimationResponse::CommandFee> &expected) {
+    checkSuccessfulResult<FeeEstimationResponse>(
+        estimate(kAdminId), [&](const FeeEstimationResponse &response) {
+          EXPECT_EQ(response.fees(), expected);
+        });
+  }
+
+  iroha::protocol::Query query_;
+};
+
+using EstimateFeeBasicTest = BasicExecutorTest<EstimateFeeTest>;
+
+/**
+ * @given asset with the burn fee of 1.5%
+ * @when the fee of a transaction with transfers and other commands is
+ * estimated
+ * @then every transfer is charged the burn fee
+ * @and the other commands are free
+ */
+TEST_P(EstimateFeeBasicTest, BurnFee) {
+  setPolicy(R"({"burn_fee_basis_points": 150})");
+  addTransfer("10.00");
+  addFreeCommand();
+  addTransfer("1.00");
+  checkFees({{0, kAssetId, Amount{"0.15"}},
+             {1, "", Amount{"0"}},
+             {2, kAssetId, Amount{"0.01"}}});
+}
+
+/**
+ * @given asset without supply policy
+ * @when the fee of a transfer is estimated
+ * @then the transfer is free
+ */
+TEST_P(EstimateFeeBasicTest, NoPolicy) {
+  addTransfer("10.00");
+  checkFees({{0, kAssetId, Amount{"0.00"}}});
+}
+
+/**
+ * @given asset with the supply cap and no burn fee
+ * @when the fee of a transfer is estimated
+ * @then the transfer is free
+ */
+TEST_P(EstimateFeeBasicTest, PolicyWithoutFee) {
+  setPolicy(R"({"max_supply": "100.00"})");
+  addTransfer("10.00");
+  checkFees({{0, kAssetId, Amount{"0.00"}}});
+}
+
+/**
+ * @given an account without can_transfer permission
+ * @when it estimates the fee of a transfer
+ * @then there is an error
+ */
+TEST_P(EstimateFeeBasicTest, NoPermission) {
+  IROHA_ASSERT_RESULT_VALUE(getItf().createUserWithPerms(
+      kUser, kDomain, PublicKeyHexStringView{kUserKeypair.publicKey()}, {}));
+  addTransfer("10.00");
+  checkQueryError<shared_model::interface::StatefulFailedErrorResponse>(
+      estimate(kUserId), error_codes::kNoPermissions);
+}
+
+INSTANTIATE_TEST_SUITE_P(Base,
+                         EstimateFeeBasicTest,
+                         executor_testing::getExecutorTestParams(),
+                         executor_testing::paramToString);
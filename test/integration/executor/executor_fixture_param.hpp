@@ -8,6 +8,9 @@
 
 #include "framework/executor_itf/executor_itf_param.hpp"
 
+#include <optional>
+#include <string>
+
 #include <gtest/gtest.h>
 #include "interfaces/common_objects/types.hpp"
 #include "main/subscription.hpp"
@@ -49,6 +52,19 @@ namespace executor_testing {
     virtual std::shared_ptr<iroha::ametsuchi::BlockIndex> getBlockIndexer()
         const = 0;
 
+    /// Forget the supply counter of the asset, as in a state written before
+    /// the supply was counted. Backends without the counter ignore it.
+    virtual void dropAssetSupplyCounter(std::string const & /* asset_id */) {}
+
+    /// Get the burn fee recorded for the transfer command of the transaction.
+    virtual std::optional<std::string> getTransferFee(
+        std::string const &tx_hash,
+        shared_model::interface::types::CommandIndexType cmd_index) = 0;
+
+    /// Set the height of the top block, as if blocks up to it were committed.
+    virtual void setTopBlockHeight(
+        shared_model::interface::types::HeightType height) = 0;
+
     /// Implementations must define this to provide backend description.
     virtual std::string toString() const = 0;
 
@@ -12,6 +12,7 @@
 #include "ametsuchi/impl/postgres_indexer.hpp"
 #include "ametsuchi/impl/postgres_query_executor.hpp"
 #include "ametsuchi/impl/postgres_specific_query_executor.hpp"
+#include "ametsuchi/impl/soci_std_optional.hpp"
 #include "backend/protobuf/proto_permission_to_string.hpp"
 #include "backend/protobuf/proto_query_response_factory.hpp"
 #include "common/result.hpp"
@@ -88,6 +89,28 @@ PostgresExecutorTestParam::getBlockIndexer() const {
   return block_indexer_;
 }
 
+std::optional<std::string> PostgresExecutorTestParam::getTransferFee(
+    std::string const &tx_hash,
+    shared_model::interface::types::CommandIndexType cmd_index) {
+  auto session = db_manager_->getSession();
+  assert(session);
+  std::optional<std::string> fee;
+  *session << "SELECT fee::text FROM transfer_fee "
+              "WHERE tx_hash = :tx_hash AND cmd_index = :cmd_index",
+      soci::use(tx_hash, "tx_hash"), soci::use(cmd_index, "cmd_index"),
+      soci::into(fee);
+  return fee;
+}
+
+void PostgresExecutorTestParam::setTopBlockHeight(
+    shared_model::interface::types::HeightType height) {
+  auto session = db_manager_->getSession();
+  assert(session);
+  *session << "INSERT INTO top_block_info (height, hash) VALUES (:height, '') "
+              "ON CONFLICT (lock) DO UPDATE SET height = EXCLUDED.height",
+      soci::use(height, "height");
+}
+
 std::string PostgresExecutorTestParam::toString() const {
   return "PostgreSQL";
 }
@@ -51,6 +51,13 @@ namespace executor_testing {
     std::shared_ptr<iroha::ametsuchi::BlockIndex> getBlockIndexer()
         const override;
 
+    std::optional<std::string> getTransferFee(
+        std::string const &tx_hash,
+        shared_model::interface::types::CommandIndexType cmd_index) override;
+
+    void setTopBlockHeight(
+        shared_model::interface::types::HeightType height) override;
+
     std::string toString() const override;
 
    private:
@@ -97,6 +97,33 @@ RocksDBExecutorTestParam::getBlockIndexer() const {
   return block_indexer_;
 }
 
+void RocksDBExecutorTestParam::dropAssetSupplyCounter(
+    std::string const &asset_id) {
+  IROHA_ASSERT_RESULT_VALUE(
+      (forAssetSupply<kDbOperation::kDel, kDbEntry::kCanExist>(*common_,
+                                                                asset_id)));
+  common_->commit();
+}
+
+std::optional<std::string> RocksDBExecutorTestParam::getTransferFee(
+    std::string const &tx_hash,
+    shared_model::interface::types::CommandIndexType cmd_index) {
+  auto fee = forTransferFee<kDbOperation::kGet, kDbEntry::kCanExist>(
+      *common_, tx_hash, cmd_index);
+  if (hasError(fee) or not fee.assumeValue()) {
+    return std::nullopt;
+  }
+  return std::string{*fee.assumeValue()};
+}
+
+void RocksDBExecutorTestParam::setTopBlockHeight(
+    shared_model::interface::types::HeightType height) {
+  common_->valueBuffer() = std::to_string(height);
+  common_->valueBuffer() += '#';
+  IROHA_ASSERT_RESULT_VALUE(forTopBlockInfo<kDbOperation::kPut>(*common_));
+  common_->commit();
+}
+
 std::string RocksDBExecutorTestParam::toString() const {
   return "RocksDB";
 }
@@ -45,6 +45,15 @@ namespace executor_testing {
     std::shared_ptr<iroha::ametsuchi::BlockIndex> getBlockIndexer()
         const override;
 
+    void dropAssetSupplyCounter(std::string const &asset_id) override;
+
+    std::optional<std::string> getTransferFee(
+        std::string const &tx_hash,
+        shared_model::interface::types::CommandIndexType cmd_index) override;
+
+    void setTopBlockHeight(
+        shared_model::interface::types::HeightType height) override;
+
     std::string toString() const override;
 
    private:
@@ -0,0 +1,204 @@
+/**
+ * Copyright Soramitsu Co., Ltd. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+#include "integration/executor/executor_fixture.hpp"
+
+#include <fmt/core.h>
+#include <gtest/gtest.h>
+#include "ametsuchi/account_detail_limits.hpp"
+#include "ametsuchi/governance_policy.hpp"
+#include "framework/common_constants.hpp"
+#include "framework/result_gtest_checkers.hpp"
+#include "integration/executor/executor_fixture_param_provider.hpp"
+#include "interfaces/query_responses/settings_response.hpp"
+#include "module/shared_model/mock_objects_factories/mock_command_factory.hpp"
+#include "module/shared_model/mock_objects_factories/mock_query_factory.hpp"
+
+using namespace common_constants;
+using namespace executor_testing;
+using namespace shared_model::interface::types;
+
+using iroha::ametsuchi::kGovernancePolicyKey;
+using iroha::ametsuchi::kMaxAccountDetailKeysKey;
+using shared_model::interface::SettingsResponse;
+
+static const ProposalIdType kProposalId{"more_detail_keys"};
+
+class GovernanceTest : public ExecutorTestBase {
+ public:
+  void SetUp() override {
+    ExecutorTestBase::SetUp();
+    IROHA_ASSERT_RESULT_VALUE(getItf().createUserWithPerms(
+        kUser, kDomain, PublicKeyHexStringView{kUserKeypair.publicKey()}, {}));
+    IROHA_ASSERT_RESULT_VALUE(getItf().createUserWithPerms(
+        kSecondUser,
+        kDomain,
+        PublicKeyHexStringView{kSameDomainUserKeypair.publicKey()},
+        {}));
+  }
+
+  /// Make the admin and the user voters with equal weights.
+  iroha::ametsuchi::CommandResult setPolicy(uint64_t threshold) {
+    return getItf().executeMaintenanceCommand(
+        *getItf().getMockCommandFactory()->constructSetSettingValue(
+            kGovernancePolicyKey,
+            fmt::format(R"({{"voters": {{"{}": 1, "{}": 1}}, )"
+                        R"("threshold": {}, "voting_period": 100}})",
+                        kAdminId,
+                        kUserId,
+                        threshold)));
+  }
+
+  iroha::ametsuchi::CommandResult propose(
+      const AccountIdType &issuer,
+      const SettingValueType &value,
+      const ProposalIdType &proposal_id = kProposalId) {
+    return getItf().executeCommandAsAccount(
+        *getItf().getMockCommandFactory()->constructProposeSettingValue(
+            proposal_id, kMaxAccountDetailKeysKey, value),
+        issuer,
+        true);
+  }
+
+  iroha::ametsuchi::CommandResult vote(
+      const AccountIdType &issuer,
+      const ProposalIdType &proposal_id = kProposalId) {
+    return getItf().executeCommandAsAccount(
+        *getItf().getMockCommandFactory()->constructVoteForProposal(
+            proposal_id),
+        issuer,
+        true);
+  }
+
+  /// Check the value of the proposed setting, if any.
+  void checkSetting(std::optional<SettingValueType> expected) {
+    checkSuccessfulResult<SettingsResponse>(
+        getItf().executeQuery(
+            *getItf().getMockQueryFactory()->constructGetSettings(), kAdminId),
+        [&expected](const SettingsResponse &response) {
+          auto it = response.settings().find(kMaxAccountDetailKeysKey);
+          if (expected) {
+            ASSERT_NE(it, response.settings().end());
+            EXPECT_EQ(it->second, *expected);
+          } else {
+            EXPECT_EQ(it, response.settings().end());
+          }
+        });
+  }
+};
+
+using GovernanceBasicTest = BasicExecutorTest<GovernanceTest>;
+
+/**
+ * @given governance policy with two voters and the threshold of two
+ * @when a proposal is made and both voters vote for it
+ * @then the setting is changed only after the second vote
+ * @and the enacted proposal does not accept votes
+ */
+TEST_P(GovernanceBasicTest, Enacted) {
+  IROHA_ASSERT_RESULT_VALUE(setPolicy(2));
+  IROHA_ASSERT_RESULT_VALUE(propose(kAdminId, "16"));
+  checkSetting(std::nullopt);
+
+  IROHA_ASSERT_RESULT_VALUE(vote(kAdminId));
+  checkSetting(std::nullopt);
+
+  IROHA_ASSERT_RESULT_VALUE(vote(kUserId));
+  checkSetting(SettingValueType{"16"});
+
+  checkCommandError(vote(kAdminId), 4);
+}
+
+/**
+ * @given governance policy with the threshold of one
+ * @when a proposal is enacted
+ * @then a later vote of another voter fails as the proposal is closed
+ */
+TEST_P(GovernanceBasicTest, VoteForEnacted) {
+  IROHA_ASSERT_RESULT_VALUE(setPolicy(1));
+  IROHA_ASSERT_RESULT_VALUE(propose(kUserId, "16"));
+  IROHA_ASSERT_RESULT_VALUE(vote(kUserId));
+  checkSetting(SettingValueType{"16"});
+  checkCommandError(vote(kAdminId), 6);
+}
+
+/**
+ * @given governance policy with two voters, the threshold of two and the
+ * voting period of 100 blocks
+ * @when one voter votes in the last block of the period and the other one
+ * votes after it
+ * @then the late vote fails as the proposal is closed
+ * @and the proposal is not enacted
+ */
+TEST_P(GovernanceBasicTest, VoteAfterVotingPeriod) {
+  IROHA_ASSERT_RESULT_VALUE(setPolicy(2));
+  IROHA_ASSERT_RESULT_VALUE(propose(kAdminId, "16"));
+
+  getBackendParam().setTopBlockHeight(100);
+  IROHA_ASSERT_RESULT_VALUE(vote(kAdminId));
+
+  getBackendParam().setTopBlockHeight(101);
+  checkCommandError(vote(kUserId), 6);
+  checkSetting(std::nullopt);
+}
+
+/**
+ * @given governance policy
+ * @when an account which is not a voter proposes or votes
+ * @then the commands fail
+ */
+TEST_P(GovernanceBasicTest, NotVoter) {
+  IROHA_ASSERT_RESULT_VALUE(setPolicy(1));
+  checkCommandError(propose(kSameDomainUserId, "16"), 2);
+  IROHA_ASSERT_RESULT_VALUE(propose(kAdminId, "16"));
+  checkCommandError(vote(kSameDomainUserId), 2);
+  checkSetting(std::nullopt);
+}
+
+/**
+ * @given governance policy
+ * @when a proposal with an invalid setting value is made
+ * @then the command fails
+ */
+TEST_P(GovernanceBasicTest, InvalidValue) {
+  IROHA_ASSERT_RESULT_VALUE(setPolicy(1));
+  checkCommandError(propose(kAdminId, "many"), 3);
+}
+
+/**
+ * @given governance policy and a proposal
+ * @when a proposal with the same id is made
+ * @then the command fails
+ */
+TEST_P(GovernanceBasicTest, ProposalExists) {
+  IROHA_ASSERT_RESULT_VALUE(setPolicy(2));
+  IROHA_ASSERT_RESULT_VALUE(propose(kAdminId, "16"));
+  checkCommandError(propose(kUserId, "32"), 4);
+}
+
+/**
+ * @given governance policy
+ * @when a voter votes for a proposal which does not exist
+ * @then the command fails
+ */
+TEST_P(GovernanceBasicTest, NoProposal) {
+  IROHA_ASSERT_RESULT_VALUE(setPolicy(1));
+  checkCommandError(vote(kAdminId), 3);
+}
+
+/**
+ * @given no governance policy
+ * @when a proposal is made
+ * @then the command fails
+ */
+TEST_P(GovernanceBasicTest, NoPolicy) {
+  checkCommandError(propose(kAdminId, "16"), 5);
+  checkCommandError(vote(kAdminId), 5);
+}
+
+INSTANTIATE_TEST_SUITE_P(Base,
+                         GovernanceBasicTest,
+                         executor_testing::getExecutorTestParams(),
+                         executor_testing::paramToString);
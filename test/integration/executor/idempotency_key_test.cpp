@@ -0,0 +1,90 @@
+/**
+ * Copyright Soramitsu Co., Ltd. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+#include "integration/executor/executor_fixture.hpp"
+
+#include <gtest/gtest.h>
+#include "ametsuchi/idempotency_key.hpp"
+#include "framework/common_constants.hpp"
+#include "framework/result_gtest_checkers.hpp"
+#include "integration/executor/executor_fixture_param_provider.hpp"
+#include "module/shared_model/builders/protobuf/test_transaction_builder.hpp"
+#include "module/shared_model/mock_objects_factories/mock_command_factory.hpp"
+
+using namespace common_constants;
+using namespace executor_testing;
+using namespace shared_model::interface::types;
+
+static const std::string kKey{"payment-42"};
+static const TimestampType kTime{1000000};
+
+class IdempotencyKeyTest : public ExecutorTestBase {
+ public:
+  iroha::ametsuchi::CommandResult setWindow(uint64_t window) {
+    return getItf().executeMaintenanceCommand(
+        *getItf().getMockCommandFactory()->constructSetSettingValue(
+            iroha::ametsuchi::kIdempotencyWindowKey, std::to_string(window)));
+  }
+
+  iroha::expected::Result<void, iroha::ametsuchi::TxExecutionError> execute(
+      TimestampType created_time, const std::string &value) {
+    auto tx = TestTransactionBuilder{}
+                  .creatorAccountId(kAdminId)
+                  .createdTime(created_time)
+                  .quorum(1)
+                  .idempotencyKey(kKey)
+                  .setAccountDetail(kAdminId, "key", value)
+                  .build();
+    return getItf().executeTransaction(tx);
+  }
+};
+
+using IdempotencyKeyBasicTest = BasicExecutorTest<IdempotencyKeyTest>;
+
+/**
+ * @given a transaction with an idempotency key applied to the ledger
+ * @when another transaction of the same creator with the same key is applied
+ * within the idempotency window
+ * @then it is rejected with the hash of the first transaction
+ */
+TEST_P(IdempotencyKeyBasicTest, DuplicateWithinWindow) {
+  IROHA_ASSERT_RESULT_VALUE(setWindow(1000));
+  IROHA_ASSERT_RESULT_VALUE(execute(kTime, "first"));
+
+  auto result = execute(kTime + 999, "second");
+  auto error = iroha::expected::resultToOptionalError(result);
+  ASSERT_TRUE(error);
+  EXPECT_EQ(error->command_error.error_code, 3);
+  EXPECT_EQ(error->command_error.command_name, "IdempotencyKey");
+}
+
+/**
+ * @given a transaction with an idempotency key applied to the ledger
+ * @when another transaction with the same key is applied after the window
+ * @then it is accepted and starts a new window
+ */
+TEST_P(IdempotencyKeyBasicTest, ReuseAfterWindow) {
+  IROHA_ASSERT_RESULT_VALUE(setWindow(1000));
+  IROHA_ASSERT_RESULT_VALUE(execute(kTime, "first"));
+
+  IROHA_ASSERT_RESULT_VALUE(execute(kTime + 1000, "second"));
+  IROHA_ASSERT_RESULT_ERROR(execute(kTime + 1500, "third"));
+}
+
+/**
+ * @given the idempotency window set to 0
+ * @when two transactions with the same key are applied
+ * @then both are accepted
+ */
+TEST_P(IdempotencyKeyBasicTest, DisabledWindow) {
+  IROHA_ASSERT_RESULT_VALUE(setWindow(0));
+  IROHA_ASSERT_RESULT_VALUE(execute(kTime, "first"));
+  IROHA_ASSERT_RESULT_VALUE(execute(kTime + 1, "second"));
+}
+
+INSTANTIATE_TEST_SUITE_P(Base,
+                         IdempotencyKeyBasicTest,
+                         executor_testing::getExecutorTestParams(),
+                         executor_testing::paramToString);
@@ -0,0 +1,195 @@
+/**
+ * Copyright Soramitsu Co., Ltd. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+#include "integration/executor/executor_fixture.hpp"
+
+#include <map>
+#include <random>
+
+#include <fmt/core.h>
+#include <gtest/gtest.h>
+#include "framework/common_constants.hpp"
+#include "framework/result_gtest_checkers.hpp"
+#include "integration/executor/executor_fixture_param_provider.hpp"
+#include "interfaces/common_objects/amount.hpp"
+#include "module/shared_model/mock_objects_factories/mock_command_factory.hpp"
+#include "module/shared_model/mock_objects_factories/mock_query_factory.hpp"
+
+using namespace common_constants;
+using namespace executor_testing;
+using namespace shared_model::interface::types;
+
+using shared_model::interface::Amount;
+
+static constexpr PrecisionType kPrecision = 2;
+static constexpr size_t kSteps = 300;
+/// steps between comparisons of the whole state
+static constexpr size_t kCheckPeriod = 50;
+
+/**
+ * Applies random sequences of asset commands both to the executor and to a
+ * reference model of balances, and compares the results. The sequences are
+ * reproducible by the seed.
+ */
+class RandomCommandsTest : public ExecutorTestBase {
+ public:
+  void SetUp() override {
+    ExecutorTestBase::SetUp();
+    balances_.clear();
+    ASSERT_NO_FATAL_FAILURE(createAsset(kAssetName, kDomain, kPrecision));
+    for (auto const &[name, domain, keypair] :
+         {std::make_tuple(kUser, kDomain, &kUserKeypair),
+          std::make_tuple(kSecondUser, kDomain, &kSameDomainUserKeypair),
+          std::make_tuple(
+              kSecondUser, kSecondDomain, &kSecondDomainUserKeypair)}) {
+      IROHA_ASSERT_RESULT_VALUE(getItf().createUserWithPerms(
+          name, domain, PublicKeyHexStringView{keypair->publicKey()}, {}));
+    }
+    for (auto const &account :
+         {kAdminId, kUserId, kSameDomainUserId, kSecondDomainUserId}) {
+      balances_.emplace(account, Amount(kPrecision));
+    }
+  }
+
+  /// Apply a random command and check that its result matches the model
+  void step(std::mt19937 &random) {
+    auto pick_account = [&] {
+      auto it = balances_.begin();
+      std::advance(it,
+                   std::uniform_int_distribution<size_t>(
+                       0, balances_.size() - 1)(random));
+      return it->first;
+    };
+    // amounts from 0.01 to 10.00, large enough to exhaust balances
+    Amount const amount{fmt::format(
+        "{:.2f}", std::uniform_int_distribution<>(1, 1000)(random) / 100.)};
+    auto const account = pick_account();
+    auto &balance = balances_.at(account);
+    auto const &factory = *getItf().getMockCommandFactory();
+
+    switch (std::uniform_int_distribution<>(0, 2)(random)) {
+      case 0: {
+        SCOPED_TRACE(fmt::format("{} adds {}", account, amount.toStringRepr()));
+        IROHA_ASSERT_RESULT_VALUE(getItf().executeCommandAsAccount(
+            *factory.constructAddAssetQuantity(kAssetId, amount),
+            account,
+            false));
+        balance += amount;
+        break;
+      }
+      case 1: {
+        SCOPED_TRACE(
+            fmt::format("{} subtracts {}", account, amount.toStringRepr()));
+        auto result = getItf().executeCommandAsAccount(
+            *factory.constructSubtractAssetQuantity(kAssetId, amount),
+            account,
+            false);
+        applyDebit(balance, amount, result);
+        break;
+      }
+      default: {
+        auto dest = pick_account();
+        while (dest == account) {
+          dest = pick_account();
+        }
+        SCOPED_TRACE(fmt::format(
+            "{} transfers {} to {}", account, amount.toStringRepr(), dest));
+        auto result = getItf().executeCommandAsAccount(
+            *factory.constructTransferAsset(
+                account, dest, kAssetId, "random transfer", amount),
+            account,
+            false);
+        if (applyDebit(balance, amount, result)) {
+          balances_.at(dest) += amount;
+        }
+        break;
+      }
+    }
+  }
+
+  /// Compare balances of all accounts with the model
+  void checkBalances() {
+    for (auto const &[account, balance] : balances_) {
+      SCOPED_TRACE(account);
+      auto pagination_meta =
+          getItf().getMockQueryFactory()->constructAssetPaginationMeta(
+              1, std::nullopt);
+      getItf()
+          .executeQueryAndConvertResult(
+              *getItf().getMockQueryFactory()->constructGetAccountAssets(
+                  account, *pagination_meta))
+          .specific_response.match(
+              [&](const auto &response) {
+                auto const &assets = response.value.accountAssets();
+                // the asset may be absent or have zero balance when it is
+                // spent completely, depending on the storage
+                auto const actual = assets.empty()
+                    ? Amount(kPrecision).toStringRepr()
+                    : assets.front().balance().toStringRepr();
+                EXPECT_EQ(actual, balance.toStringRepr());
+              },
+              [](const auto &other_response) {
+                ADD_FAILURE() << "Unexpected query response: "
+                              << other_response.error->toString();
+              });
+    }
+  }
+
+ private:
+  /**
+   * Subtract the amount from the balance if the model allows it and check
+   * that the executor agrees
+   * @return whether the debit is applied
+   */
+  bool applyDebit(Amount &balance,
+                  const Amount &amount,
+                  const iroha::ametsuchi::CommandResult &result) {
+    auto remainder = balance;
+    remainder -= amount;
+    if (remainder.sign() < 0) {
+      EXPECT_TRUE(iroha::expected::hasError(result))
+          << "Debit exceeding the balance " << balance.toStringRepr()
+          << " succeeded";
+      return false;
+    }
+    EXPECT_TRUE(iroha::expected::hasValue(result))
+        << "Debit within the balance " << balance.toStringRepr() << " failed";
+    balance = std::move(remainder);
+    return true;
+  }
+
+  std::map<std::string, Amount> balances_;
+};
+
+using RandomCommandsBasicTest = BasicExecutorTest<RandomCommandsTest>;
+
+/**
+ * @given accounts without assets
+ * @when random add, subtract and transfer commands are executed
+ * @then each command succeeds if and only if the reference model allows it
+ * @and balances of the accounts match the reference model
+ */
+TEST_P(RandomCommandsBasicTest, MatchesReferenceModel) {
+  for (auto seed : {1u, 2u, 3u}) {
+    SCOPED_TRACE(fmt::format("seed {}", seed));
+    if (seed != 1u) {
+      // start every sequence from a clean state
+      SetUp();
+    }
+    std::mt19937 random(seed);
+    for (size_t i = 1; i <= kSteps; ++i) {
+      SCOPED_TRACE(fmt::format("step {}", i));
+      ASSERT_NO_FATAL_FAILURE(step(random));
+      if (i % kCheckPeriod == 0) {
+        ASSERT_NO_FATAL_FAILURE(checkBalances());
+      }
+    }
+  }
+}
+
+INSTANTIATE_TEST_SUITE_P(Base,
+                         RandomCommandsBasicTest,
+                         executor_testing::getExecutorTestParams(),
+                         executor_testing::paramToString);
@@ -0,0 +1,285 @@
+/**
+ * Copyright Soramitsu Co., Ltd. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+#include "integration/executor/executor_fixture.hpp"
+
+#include <gtest/gtest.h>
+#include "framework/common_constants.hpp"
+#include "integration/executor/query_permission_test.hpp"
+#include "interfaces/query_responses/role_permissions.hpp"
+#include "module/shared_model/mock_objects_factories/mock_command_factory.hpp"
+#include "module/shared_model/mock_objects_factories/mock_query_factory.hpp"
+
+using namespace common_constants;
+using namespace executor_testing;
+using namespace framework::expected;
+using namespace shared_model::interface::types;
+
+using iroha::ametsuchi::QueryExecutorResult;
+using shared_model::interface::RolePermissionSet;
+using shared_model::interface::RolePermissionsResponse;
+using shared_model::interface::permissions::Role;
+
+static const RoleIdType kRoleA("role_a");
+static const RoleIdType kRoleB("role_b");
+static const RoleIdType kRoleC("role_c");
+
+struct RoleHierarchyTest : public ExecutorTestBase {
+  /// Create the roles used in the tests and give the user all of them.
+  void prepareRoles() {
+    SCOPED_TRACE("RoleHierarchyTest::prepareRoles");
+    IROHA_ASSERT_RESULT_VALUE(getItf().createUserWithPerms(
+        kUser,
+        kDomain,
+        PublicKeyHexStringView{kUserKeypair.publicKey()},
+        {Role::kCreateRole,
+         Role::kReceive,
+         Role::kTransfer,
+         Role::kAddAssetQty}));
+    IROHA_ASSERT_RESULT_VALUE(
+        getItf().createRoleWithPerms(kRoleA, {Role::kReceive}));
+    IROHA_ASSERT_RESULT_VALUE(
+        getItf().createRoleWithPerms(kRoleB, {Role::kTransfer}));
+    IROHA_ASSERT_RESULT_VALUE(
+        getItf().createRoleWithPerms(kRoleC, {Role::kAddAssetQty}));
+  }
+
+  iroha::ametsuchi::CommandResult includeRole(
+      const RoleIdType &role,
+      const RoleIdType &included_role,
+      const AccountIdType &issuer = kUserId) {
+    return getItf().executeCommandAsAccount(
+        *getItf().getMockCommandFactory()->constructIncludeRole(role,
+                                                                included_role),
+        issuer,
+        true);
+  }
+
+  void checkRole(const RoleIdType &role,
+                 const RolePermissionSet &ref_permissions) {
+    getItf()
+        .executeQueryAndConvertResult(
+            *getItf().getMockQueryFactory()->constructGetRolePermissions(role))
+        .specific_response.match(
+            [&](const auto &test_permissions) {
+              EXPECT_EQ(test_permissions.value.rolePermissions(),
+                        ref_permissions)
+                  << "Wrong set of permissions for role " << role;
+            },
+            [](const auto &e) { ADD_FAILURE() << e.error->toString(); });
+  }
+
+  /// Query account permissions of the given account.
+  QueryExecutorResult queryAccountPermissions(
+      const AccountIdType &account_id = kUserId,
+      const AccountIdType &query_issuer = kAdminId,
+      const std::string &name_filter = "") {
+    return getItf().executeQuery(
+        *getItf().getMockQueryFactory()->constructGetAccountPermissions(
+            account_id, name_filter),
+        query_issuer);
+  }
+};
+
+using RoleHierarchyBasicTest = BasicExecutorTest<RoleHierarchyTest>;
+
+/**
+ * @given roles A, B and C
+ * @when C is included into B and then B is included into A
+ * @then A gets the permissions of both B and C, B gets the ones of C
+ */
+TEST_P(RoleHierarchyBasicTest, TransitiveInclusion) {
+  ASSERT_NO_FATAL_FAILURE(prepareRoles());
+  IROHA_ASSERT_RESULT_VALUE(includeRole(kRoleB, kRoleC));
+  IROHA_ASSERT_RESULT_VALUE(includeRole(kRoleA, kRoleB));
+  checkRole(kRoleA, {Role::kReceive, Role::kTransfer, Role::kAddAssetQty});
+  checkRole(kRoleB, {Role::kTransfer, Role::kAddAssetQty});
+  checkRole(kRoleC, {Role::kAddAssetQty});
+}
+
+/**
+ * @given role A including role B
+ * @when role C is included into B afterwards
+ * @then the permissions of C are propagated to A as well
+ */
+TEST_P(RoleHierarchyBasicTest, PropagationToIncludingRoles) {
+  ASSERT_NO_FATAL_FAILURE(prepareRoles());
+  IROHA_ASSERT_RESULT_VALUE(includeRole(kRoleA, kRoleB));
+  IROHA_ASSERT_RESULT_VALUE(includeRole(kRoleB, kRoleC));
+  checkRole(kRoleA, {Role::kReceive, Role::kTransfer, Role::kAddAssetQty});
+  checkRole(kRoleB, {Role::kTransfer, Role::kAddAssetQty});
+}
+
+/**
+ * @given roles A and B, A including B
+ * @when B is included into A once more
+ * @then the command fails with error code 4
+ */
+TEST_P(RoleHierarchyBasicTest, AlreadyIncluded) {
+  ASSERT_NO_FATAL_FAILURE(prepareRoles());
+  IROHA_ASSERT_RESULT_VALUE(includeRole(kRoleA, kRoleB));
+  checkCommandError(includeRole(kRoleA, kRoleB), 4);
+}
+
+/**
+ * @given role A including B and B including C
+ * @when A is included into C
+ * @then the command fails with error code 5 and C is not changed
+ */
+TEST_P(RoleHierarchyBasicTest, Cycle) {
+  ASSERT_NO_FATAL_FAILURE(prepareRoles());
+  IROHA_ASSERT_RESULT_VALUE(includeRole(kRoleA, kRoleB));
+  IROHA_ASSERT_RESULT_VALUE(includeRole(kRoleB, kRoleC));
+  checkCommandError(includeRole(kRoleC, kRoleA), 5);
+  checkRole(kRoleC, {Role::kAddAssetQty});
+}
+
+/**
+ * @given role A
+ * @when A is included into itself
+ * @then the command fails with error code 5
+ */
+TEST_P(RoleHierarchyBasicTest, SelfInclusion) {
+  ASSERT_NO_FATAL_FAILURE(prepareRoles());
+  checkCommandError(includeRole(kRoleA, kRoleA), 5);
+}
+
+/**
+ * @given role A
+ * @when a nonexistent role is included into A or A into a nonexistent role
+ * @then both commands fail with error code 3
+ */
+TEST_P(RoleHierarchyBasicTest, NonexistentRole) {
+  ASSERT_NO_FATAL_FAILURE(prepareRoles());
+  checkCommandError(includeRole(kRoleA, "no_such_role"), 3);
+  checkCommandError(includeRole("no_such_role", kRoleA), 3);
+}
+
+/**
+ * @given a user without kCreateRole permission
+ * @when the user includes one role into another
+ * @then the command fails with error code 2
+ */
+TEST_P(RoleHierarchyBasicTest, NoCreateRolePermission) {
+  IROHA_ASSERT_RESULT_VALUE(getItf().createUserWithPerms(
+      kUser,
+      kDomain,
+      PublicKeyHexStringView{kUserKeypair.publicKey()},
+      {Role::kReceive, Role::kTransfer}));
+  IROHA_ASSERT_RESULT_VALUE(
+      getItf().createRoleWithPerms(kRoleA, {Role::kReceive}));
+  IROHA_ASSERT_RESULT_VALUE(
+      getItf().createRoleWithPerms(kRoleB, {Role::kTransfer}));
+  checkCommandError(includeRole(kRoleA, kRoleB), 2);
+  checkRole(kRoleA, {Role::kReceive});
+}
+
+/**
+ * @given a user with kCreateRole permission lacking permissions of a role
+ * @when the user includes that role into another one
+ * @then the command fails with error code 2
+ */
+TEST_P(RoleHierarchyBasicTest, NoIncludedRolePermissions) {
+  IROHA_ASSERT_RESULT_VALUE(getItf().createUserWithPerms(
+      kUser,
+      kDomain,
+      PublicKeyHexStringView{kUserKeypair.publicKey()},
+      {Role::kCreateRole}));
+  IROHA_ASSERT_RESULT_VALUE(
+      getItf().createRoleWithPerms(kRoleA, {Role::kReceive}));
+  IROHA_ASSERT_RESULT_VALUE(
+      getItf().createRoleWithPerms(kRoleB, {Role::kTransfer}));
+  checkCommandError(includeRole(kRoleA, kRoleB), 2);
+  checkRole(kRoleA, {Role::kReceive});
+}
+
+/**
+ * @given an account without own permissions having role C which includes B
+ * @when GetAccountPermissions is queried for the account
+ * @then the response contains permissions of both C and B
+ */
+TEST_P(RoleHierarchyBasicTest, AccountPermissions) {
+  ASSERT_NO_FATAL_FAILURE(prepareRoles());
+  IROHA_ASSERT_RESULT_VALUE(getItf().createUserWithPerms(
+      kSecondUser,
+      kDomain,
+      PublicKeyHexStringView{kSameDomainUserKeypair.publicKey()},
+      {}));
+  IROHA_ASSERT_RESULT_VALUE(includeRole(kRoleC, kRoleB));
+  IROHA_ASSERT_RESULT_VALUE(getItf().executeMaintenanceCommand(
+      *getItf().getMockCommandFactory()->constructAppendRole(kSameDomainUserId,
+                                                             kRoleC)));
+  checkSuccessfulResult<RolePermissionsResponse>(
+      queryAccountPermissions(kSameDomainUserId), [](const auto &response) {
+        EXPECT_EQ(response.rolePermissions(),
+                  RolePermissionSet({Role::kTransfer, Role::kAddAssetQty}));
+      });
+}
+
+/**
+ * @given an account having role C which includes B, so that the account may
+ * transfer and add asset quantity
+ * @when GetAccountPermissions is queried with a name filter
+ * @then only the permissions with names containing the filter are returned
+ */
+TEST_P(RoleHierarchyBasicTest, FilteredAccountPermissions) {
+  ASSERT_NO_FATAL_FAILURE(prepareRoles());
+  IROHA_ASSERT_RESULT_VALUE(getItf().createUserWithPerms(
+      kSecondUser,
+      kDomain,
+      PublicKeyHexStringView{kSameDomainUserKeypair.publicKey()},
+      {}));
+  IROHA_ASSERT_RESULT_VALUE(includeRole(kRoleC, kRoleB));
+  IROHA_ASSERT_RESULT_VALUE(getItf().executeMaintenanceCommand(
+      *getItf().getMockCommandFactory()->constructAppendRole(kSameDomainUserId,
+                                                             kRoleC)));
+  auto check = [this](const std::string &name_filter,
+                      const RolePermissionSet &expected) {
+    checkSuccessfulResult<RolePermissionsResponse>(
+        queryAccountPermissions(kSameDomainUserId, kAdminId, name_filter),
+        [&](const auto &response) {
+          EXPECT_EQ(response.rolePermissions(), expected)
+              << "Wrong permissions for filter " << name_filter;
+        });
+  };
+  check("asset_qty", {Role::kAddAssetQty});
+  check("can_", {Role::kTransfer, Role::kAddAssetQty});
+  check("can_get", {});
+}
+
+/**
+ * @given a user with all related permissions
+ * @when GetAccountPermissions is queried on non existent user
+ * @then there is an NoAccountErrorResponse
+ */
+TEST_P(RoleHierarchyBasicTest, NonexistentAccount) {
+  checkQueryError<shared_model::interface::NoAccountErrorResponse>(
+      queryAccountPermissions(), 0);
+}
+
+INSTANTIATE_TEST_SUITE_P(Base,
+                         RoleHierarchyBasicTest,
+                         executor_testing::getExecutorTestParams(),
+                         executor_testing::paramToString);
+
+using GetAccountPermissionsPermissionTest =
+    query_permission_test::QueryPermissionTest<RoleHierarchyTest>;
+
+TEST_P(GetAccountPermissionsPermissionTest, QueryPermissionTest) {
+  ASSERT_NO_FATAL_FAILURE(prepareState({Role::kReceive}));
+  checkResponse<RolePermissionsResponse>(
+      queryAccountPermissions(kUserId, getSpectator()),
+      [](const auto &response) {
+        EXPECT_TRUE(response.rolePermissions().isSet(Role::kReceive));
+      });
+}
+
+INSTANTIATE_TEST_SUITE_P(
+    Common,
+    GetAccountPermissionsPermissionTest,
+    query_permission_test::getParams({Role::kGetMyAccount},
+                                     {Role::kGetDomainAccounts},
+                                     {Role::kGetAllAccounts}),
+    query_permission_test::paramToString);
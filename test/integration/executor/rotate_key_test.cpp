@@ -0,0 +1,127 @@
+/**
+ * Copyright Soramitsu Co., Ltd. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+#include "integration/executor/executor_fixture.hpp"
+
+#include <gtest/gtest.h>
+#include "framework/common_constants.hpp"
+#include "framework/result_gtest_checkers.hpp"
+#include "integration/executor/executor_fixture_param_provider.hpp"
+#include "module/shared_model/mock_objects_factories/mock_command_factory.hpp"
+
+using namespace std::literals;
+using namespace common_constants;
+using namespace executor_testing;
+
+using shared_model::interface::permissions::Grantable;
+using shared_model::interface::permissions::Role;
+using shared_model::interface::types::AccountIdType;
+using shared_model::interface::types::PublicKeyHexStringView;
+
+static const PublicKeyHexStringView kNewKey{"new_key"sv};
+static const PublicKeyHexStringView kUnknownKey{"unknown_key"sv};
+
+class RotateKeyTest : public ExecutorTestBase {
+ public:
+  void addTargetUser(const shared_model::interface::RolePermissionSet &perms) {
+    IROHA_ASSERT_RESULT_VALUE(getItf().createUserWithPerms(
+        kUser, kDomain, old_key_, perms));
+  }
+
+  iroha::ametsuchi::CommandResult rotate(
+      PublicKeyHexStringView old_key,
+      PublicKeyHexStringView new_key,
+      const AccountIdType &issuer = kUserId) {
+    return getItf().executeCommandAsAccount(
+        *getItf().getMockCommandFactory()->constructRotateKey(
+            kUserId, old_key, new_key, 10),
+        issuer,
+        true);
+  }
+
+ protected:
+  PublicKeyHexStringView old_key_{kUserKeypair.publicKey()};
+};
+
+using RotateKeyBasicTest = BasicExecutorTest<RotateKeyTest>;
+
+/**
+ * @given a user with permissions to add and remove own signatories
+ * @when the user rotates the own key
+ * @then the old key is replaced with the new one
+ */
+TEST_P(RotateKeyBasicTest, Valid) {
+  ASSERT_NO_FATAL_FAILURE(
+      addTargetUser({Role::kAddSignatory, Role::kRemoveSignatory}));
+
+  IROHA_ASSERT_RESULT_VALUE(rotate(old_key_, kNewKey));
+
+  checkSignatories(kUserId, {kNewKey});
+}
+
+/**
+ * @given a user with permission to add own signatories only
+ * @when the user rotates the own key
+ * @then the command fails and the signatories are unchanged
+ */
+TEST_P(RotateKeyBasicTest, NoPermission) {
+  ASSERT_NO_FATAL_FAILURE(addTargetUser({Role::kAddSignatory}));
+
+  checkCommandError(rotate(old_key_, kNewKey), 2);
+
+  checkSignatories(kUserId, {old_key_});
+}
+
+/**
+ * @given a user who granted permissions to add and remove signatories to
+ * another user
+ * @when the other user rotates the key of the first one
+ * @then the old key is replaced with the new one
+ */
+TEST_P(RotateKeyBasicTest, ValidGrantable) {
+  ASSERT_NO_FATAL_FAILURE(
+      addTargetUser({Role::kAddMySignatory, Role::kRemoveMySignatory}));
+  IROHA_ASSERT_RESULT_VALUE(getItf().createUserWithPerms(
+      kSecondUser,
+      kDomain,
+      PublicKeyHexStringView{kSameDomainUserKeypair.publicKey()},
+      {}));
+  for (auto permission :
+       {Grantable::kAddMySignatory, Grantable::kRemoveMySignatory}) {
+    IROHA_ASSERT_RESULT_VALUE(getItf().executeCommandAsAccount(
+        *getItf().getMockCommandFactory()->constructGrantPermission(
+            kSameDomainUserId, permission),
+        kUserId,
+        true));
+  }
+
+  IROHA_ASSERT_RESULT_VALUE(rotate(old_key_, kNewKey, kSameDomainUserId));
+
+  checkSignatories(kUserId, {kNewKey});
+}
+
+/**
+ * @given a user with permissions to add and remove own signatories
+ * @when the user rotates a key that is not a signatory or rotates a key to a
+ * key that already is a signatory
+ * @then the commands fail and the signatories are unchanged
+ */
+TEST_P(RotateKeyBasicTest, WrongSignatories) {
+  ASSERT_NO_FATAL_FAILURE(
+      addTargetUser({Role::kAddSignatory, Role::kRemoveSignatory}));
+  IROHA_ASSERT_RESULT_VALUE(getItf().executeMaintenanceCommand(
+      *getItf().getMockCommandFactory()->constructAddSignatory(kNewKey,
+                                                               kUserId)));
+
+  checkCommandError(rotate(kUnknownKey, kNewKey), 4);
+  checkCommandError(rotate(old_key_, kNewKey), 5);
+
+  checkSignatories(kUserId, {old_key_, kNewKey});
+}
+
+INSTANTIATE_TEST_SUITE_P(Base,
+                         RotateKeyBasicTest,
+                         executor_testing::getExecutorTestParams(),
+                         executor_testing::paramToString);
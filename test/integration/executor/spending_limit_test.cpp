@@ -0,0 +1,147 @@
+/**
+ * Copyright Soramitsu Co., Ltd. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+#include "integration/executor/executor_fixture.hpp"
+
+#include <gtest/gtest.h>
+#include "ametsuchi/asset_lock.hpp"
+#include "framework/common_constants.hpp"
+#include "framework/result_gtest_checkers.hpp"
+#include "integration/executor/executor_fixture_param_provider.hpp"
+#include "interfaces/common_objects/amount.hpp"
+#include "module/shared_model/mock_objects_factories/mock_command_factory.hpp"
+
+using namespace common_constants;
+using namespace executor_testing;
+using namespace shared_model::interface::types;
+
+using shared_model::interface::Amount;
+using shared_model::interface::permissions::Grantable;
+using shared_model::interface::permissions::Role;
+
+static const Amount kLimit{std::string{"5.0"}};
+static const std::string kPreimage(64, 'a');
+
+class SpendingLimitTest : public ExecutorTestBase {
+ public:
+  void SetUp() override {
+    ExecutorTestBase::SetUp();
+    ASSERT_NO_FATAL_FAILURE(createAsset(kAssetName, kDomain, 1));
+    IROHA_ASSERT_RESULT_VALUE(getItf().createUserWithPerms(
+        kUser,
+        kDomain,
+        PublicKeyHexStringView{kUserKeypair.publicKey()},
+        {Role::kReceive, Role::kTransfer, Role::kSetMySpendingLimit}));
+    IROHA_ASSERT_RESULT_VALUE(getItf().createUserWithPerms(
+        kSecondUser,
+        kDomain,
+        PublicKeyHexStringView{kSameDomainUserKeypair.publicKey()},
+        {Role::kReceive}));
+    ASSERT_NO_FATAL_FAILURE(addAsset(kUserId, kAssetId, Amount{"10.0"}));
+  }
+
+  void grantSpendingLimit() {
+    IROHA_ASSERT_RESULT_VALUE(getItf().executeCommandAsAccount(
+        *getItf().getMockCommandFactory()->constructGrantPermission(
+            kSameDomainUserId, Grantable::kSetMySpendingLimit),
+        kUserId,
+        true));
+  }
+
+  iroha::ametsuchi::CommandResult setLimit(const Amount &amount,
+                                           HeightType window) {
+    return getItf().executeCommandAsAccount(
+        *getItf().getMockCommandFactory()->constructSetSpendingLimit(
+            kUserId, kAssetId, amount, window),
+        kSameDomainUserId,
+        true);
+  }
+
+  iroha::ametsuchi::CommandResult transfer(const Amount &amount) {
+    return getItf().executeCommandAsAccount(
+        *getItf().getMockCommandFactory()->constructTransferAsset(
+            kUserId, kSameDomainUserId, kAssetId, "", amount),
+        kUserId,
+        true);
+  }
+
+  iroha::ametsuchi::CommandResult lock(const Amount &amount) {
+    return getItf().executeCommandAsAccount(
+        *getItf().getMockCommandFactory()->constructLockAsset(
+            kUserId,
+            kSameDomainUserId,
+            kAssetId,
+            amount,
+            iroha::ametsuchi::assetLockHash(kPreimage).assumeValue(),
+            10),
+        kUserId,
+        true);
+  }
+};
+
+using SpendingLimitBasicTest = BasicExecutorTest<SpendingLimitTest>;
+
+/**
+ * @given an account with a spending limit set by its guardian
+ * @when the account transfers its assets in the same window
+ * @then the transfers pass until the limit is reached
+ */
+TEST_P(SpendingLimitBasicTest, TransfersAreLimited) {
+  grantSpendingLimit();
+  IROHA_ASSERT_RESULT_VALUE(setLimit(kLimit, 100));
+
+  IROHA_ASSERT_RESULT_VALUE(transfer(Amount{"3.0"}));
+  checkCommandError(transfer(Amount{"3.0"}), 10);
+  IROHA_ASSERT_RESULT_VALUE(transfer(Amount{"2.0"}));
+  checkCommandError(transfer(Amount{"0.1"}), 10);
+
+  checkAssetQuantities(kUserId, {AssetQuantity{kAssetId, kLimit}});
+  checkAssetQuantities(kSameDomainUserId, {AssetQuantity{kAssetId, kLimit}});
+}
+
+/**
+ * @given an account with a spending limit
+ * @when the account locks more assets than the limit allows
+ * @then the command fails
+ */
+TEST_P(SpendingLimitBasicTest, LocksAreLimited) {
+  grantSpendingLimit();
+  IROHA_ASSERT_RESULT_VALUE(setLimit(kLimit, 100));
+
+  checkCommandError(lock(Amount{"6.0"}), 10);
+  IROHA_ASSERT_RESULT_VALUE(lock(Amount{"5.0"}));
+}
+
+/**
+ * @given an account which has not granted the permission to set its spending
+ * limit
+ * @when another account sets the limit
+ * @then the command fails and the transfers are not limited
+ */
+TEST_P(SpendingLimitBasicTest, NoPermission) {
+  checkCommandError(setLimit(kLimit, 100), 2);
+
+  IROHA_ASSERT_RESULT_VALUE(transfer(Amount{"6.0"}));
+}
+
+/**
+ * @given an account with a spending limit
+ * @when the limit is set with zero window
+ * @then the limit is removed and the transfers are not limited
+ */
+TEST_P(SpendingLimitBasicTest, RemoveLimit) {
+  grantSpendingLimit();
+  IROHA_ASSERT_RESULT_VALUE(setLimit(kLimit, 100));
+  checkCommandError(transfer(Amount{"6.0"}), 10);
+
+  IROHA_ASSERT_RESULT_VALUE(setLimit(kLimit, 0));
+
+  IROHA_ASSERT_RESULT_VALUE(transfer(Amount{"6.0"}));
+}
+
+INSTANTIATE_TEST_SUITE_P(Base,
+                         SpendingLimitBasicTest,
+                         executor_testing::getExecutorTestParams(),
+                         executor_testing::paramToString);
@@ -0,0 +1,176 @@
+/**
+ * Copyright Soramitsu Co., Ltd. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+#include "integration/executor/executor_fixture.hpp"
+
+#include <gtest/gtest.h>
+#include "ametsuchi/supply_policy.hpp"
+#include "framework/common_constants.hpp"
+#include "framework/result_gtest_checkers.hpp"
+#include "integration/executor/executor_fixture_param_provider.hpp"
+#include "interfaces/common_objects/amount.hpp"
+#include "module/shared_model/builders/protobuf/test_transaction_builder.hpp"
+#include "module/shared_model/mock_objects_factories/mock_command_factory.hpp"
+
+using namespace common_constants;
+using namespace executor_testing;
+using namespace shared_model::interface::types;
+
+using shared_model::interface::Amount;
+using shared_model::interface::permissions::Role;
+
+class SupplyPolicyTest : public ExecutorTestBase {
+ public:
+  void SetUp() override {
+    ExecutorTestBase::SetUp();
+    ASSERT_NO_FATAL_FAILURE(createAsset(kAssetName, kDomain, 2));
+    IROHA_ASSERT_RESULT_VALUE(getItf().createUserWithPerms(
+        kUser,
+        kDomain,
+        PublicKeyHexStringView{kUserKeypair.publicKey()},
+        {Role::kReceive}));
+  }
+
+  iroha::ametsuchi::CommandResult setPolicy(const std::string &policy) {
+    return getItf().executeMaintenanceCommand(
+        *getItf().getMockCommandFactory()->constructSetSettingValue(
+            iroha::ametsuchi::supplyPolicyKey(kAssetId), policy));
+  }
+
+  iroha::ametsuchi::CommandResult issue(const Amount &amount,
+                                        bool validation_enabled = true) {
+    return getItf().executeCommandAsAccount(
+        *getItf().getMockCommandFactory()->constructAddAssetQuantity(kAssetId,
+                                                                     amount),
+        kAdminId,
+        validation_enabled);
+  }
+
+  iroha::ametsuchi::CommandResult transfer(const Amount &amount) {
+    return getItf().executeMaintenanceCommand(
+        *getItf().getMockCommandFactory()->constructTransferAsset(
+            kAdminId, kUserId, kAssetId, "supply policy test", amount));
+  }
+
+  /// Execute a transaction transferring the amount as its second command
+  /// @return hash of the transaction
+  std::string transferInTransaction(const std::string &amount,
+                                    TimestampType created_time) {
+    auto tx = TestTransactionBuilder{}
+                  .creatorAccountId(kAdminId)
+                  .createdTime(created_time)
+                  .quorum(1)
+                  .setAccountDetail(kAdminId, "key", "value")
+                  .transferAsset(
+                      kAdminId, kUserId, kAssetId, "supply policy test", amount)
+                  .build();
+    EXPECT_TRUE(
+        iroha::expected::hasValue(getItf().executeTransaction(tx, false)));
+    return tx.hash().hex();
+  }
+};
+
+using SupplyPolicyBasicTest = BasicExecutorTest<SupplyPolicyTest>;
+
+/**
+ * @given asset with the supply cap
+ * @when the asset is issued up to the cap and over it
+ * @then the supply over the cap is not issued
+ * @and the quantity of all accounts is counted
+ */
+TEST_P(SupplyPolicyBasicTest, MaxSupply) {
+  IROHA_ASSERT_RESULT_VALUE(setPolicy(R"({"max_supply": "10.00"})"));
+  IROHA_ASSERT_RESULT_VALUE(issue(Amount{"6.00"}));
+  checkCommandError(issue(Amount{"5.00"}), 5);
+
+  IROHA_ASSERT_RESULT_VALUE(transfer(Amount{"6.00"}));
+  checkCommandError(issue(Amount{"4.01"}), 5);
+  IROHA_ASSERT_RESULT_VALUE(issue(Amount{"4.00"}));
+  checkAssetQuantities(kAdminId, {AssetQuantity{kAssetId, Amount{"4.00"}}});
+  checkAssetQuantities(kUserId, {AssetQuantity{kAssetId, Amount{"6.00"}}});
+}
+
+/**
+ * @given asset with decreasing only supply issued in genesis block
+ * @when the asset is issued later
+ * @then the command fails
+ */
+TEST_P(SupplyPolicyBasicTest, DecreasingOnly) {
+  IROHA_ASSERT_RESULT_VALUE(setPolicy(R"({"decreasing_only": true})"));
+  IROHA_ASSERT_RESULT_VALUE(issue(Amount{"1.00"}, false));
+  checkCommandError(issue(Amount{"1.00"}), 5);
+  checkAssetQuantities(kAdminId, {AssetQuantity{kAssetId, Amount{"1.00"}}});
+}
+
+/**
+ * @given asset with the burn fee of 1.5% and the supply cap
+ * @when the asset is transferred
+ * @then the destination account receives the amount minus the fee
+ * @and the burned fee can be issued again
+ */
+TEST_P(SupplyPolicyBasicTest, BurnFee) {
+  IROHA_ASSERT_RESULT_VALUE(setPolicy(
+      R"({"max_supply": "100.00", "burn_fee_basis_points": 150})"));
+  IROHA_ASSERT_RESULT_VALUE(issue(Amount{"100.00"}));
+  IROHA_ASSERT_RESULT_VALUE(transfer(Amount{"10.00"}));
+  checkAssetQuantities(kAdminId, {AssetQuantity{kAssetId, Amount{"90.00"}}});
+  checkAssetQuantities(kUserId, {AssetQuantity{kAssetId, Amount{"9.85"}}});
+
+  checkCommandError(issue(Amount{"0.16"}), 5);
+  IROHA_ASSERT_RESULT_VALUE(issue(Amount{"0.15"}));
+}
+
+/**
+ * @given asset with the burn fee which is changed between two transfers
+ * @when the transfers are executed
+ * @then the fee charged by each transfer is recorded by its command
+ */
+TEST_P(SupplyPolicyBasicTest, TransferFeeRecorded) {
+  IROHA_ASSERT_RESULT_VALUE(setPolicy(R"({"burn_fee_basis_points": 150})"));
+  IROHA_ASSERT_RESULT_VALUE(issue(Amount{"100.00"}));
+  auto const first_hash = transferInTransaction("10.00", 1000000);
+  IROHA_ASSERT_RESULT_VALUE(setPolicy(R"({"burn_fee_basis_points": 300})"));
+  auto const second_hash = transferInTransaction("10.00", 1000001);
+
+  EXPECT_EQ(getBackendParam().getTransferFee(first_hash, 1), "0.15");
+  EXPECT_EQ(getBackendParam().getTransferFee(second_hash, 1), "0.30");
+  EXPECT_EQ(getBackendParam().getTransferFee(second_hash, 0), std::nullopt);
+  checkAssetQuantities(kUserId, {AssetQuantity{kAssetId, Amount{"19.55"}}});
+}
+
+/**
+ * @given asset issued to two accounts before its supply was counted
+ * @when the supply cap is set and the asset is issued up to it and over it
+ * @then the pre-existing balances are counted in the supply on every backend
+ */
+TEST_P(SupplyPolicyBasicTest, PreexistingBalances) {
+  IROHA_ASSERT_RESULT_VALUE(issue(Amount{"6.00"}));
+  IROHA_ASSERT_RESULT_VALUE(transfer(Amount{"2.00"}));
+  getBackendParam().dropAssetSupplyCounter(kAssetId);
+
+  IROHA_ASSERT_RESULT_VALUE(setPolicy(R"({"max_supply": "10.00"})"));
+  checkCommandError(issue(Amount{"4.01"}), 5);
+  IROHA_ASSERT_RESULT_VALUE(issue(Amount{"4.00"}));
+  checkCommandError(issue(Amount{"0.01"}), 5);
+  checkAssetQuantities(kAdminId, {AssetQuantity{kAssetId, Amount{"8.00"}}});
+  checkAssetQuantities(kUserId, {AssetQuantity{kAssetId, Amount{"2.00"}}});
+}
+
+/**
+ * @given asset without supply policy
+ * @when a malformed policy is set
+ * @then the command fails
+ */
+TEST_P(SupplyPolicyBasicTest, MalformedPolicy) {
+  checkCommandError(setPolicy(R"({"max_supply": 10})"), 3);
+  checkCommandError(setPolicy(R"({"max_supply": "ten"})"), 3);
+  checkCommandError(setPolicy(R"({"burn_fee_basis_points": 10001})"), 3);
+  checkCommandError(setPolicy("["), 3);
+}
+
+INSTANTIATE_TEST_SUITE_P(Base,
+                         SupplyPolicyBasicTest,
+                         executor_testing::getExecutorTestParams(),
+                         executor_testing::paramToString);
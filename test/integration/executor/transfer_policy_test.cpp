@@ -0,0 +1,133 @@
+/**
+ * Copyright Soramitsu Co., Ltd. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+#include "integration/executor/executor_fixture.hpp"
+
+#include <fmt/core.h>
+#include <gtest/gtest.h>
+#include "ametsuchi/transfer_policy.hpp"
+#include "framework/common_constants.hpp"
+#include "framework/result_gtest_checkers.hpp"
+#include "integration/executor/executor_fixture_param_provider.hpp"
+#include "interfaces/common_objects/amount.hpp"
+#include "module/shared_model/mock_objects_factories/mock_command_factory.hpp"
+
+using namespace common_constants;
+using namespace executor_testing;
+using namespace shared_model::interface::types;
+
+using shared_model::interface::Amount;
+using shared_model::interface::permissions::Role;
+
+static const Amount kAmount{std::string{"1.0"}};
+static const std::string kKycKey{"kyc"};
+
+class TransferPolicyTest : public ExecutorTestBase {
+ public:
+  void SetUp() override {
+    ExecutorTestBase::SetUp();
+    ASSERT_NO_FATAL_FAILURE(createAsset(kAssetName, kDomain, 1));
+    for (auto const &[name, domain, keypair] :
+         {std::make_tuple(kUser, kDomain, &kUserKeypair),
+          std::make_tuple(kSecondUser, kDomain, &kSameDomainUserKeypair),
+          std::make_tuple(
+              kSecondUser, kSecondDomain, &kSecondDomainUserKeypair)}) {
+      IROHA_ASSERT_RESULT_VALUE(getItf().createUserWithPerms(
+          name,
+          domain,
+          PublicKeyHexStringView{keypair->publicKey()},
+          {Role::kTransfer, Role::kReceive}));
+    }
+    ASSERT_NO_FATAL_FAILURE(addAsset(kUserId, kAssetId, Amount{"10.0"}));
+  }
+
+  iroha::ametsuchi::CommandResult setPolicy(const std::string &policy) {
+    return getItf().executeMaintenanceCommand(
+        *getItf().getMockCommandFactory()->constructSetSettingValue(
+            iroha::ametsuchi::transferPolicyKey(kAssetId), policy));
+  }
+
+  iroha::ametsuchi::CommandResult transfer(const AccountIdType &dest) {
+    return getItf().executeCommandAsAccount(
+        *getItf().getMockCommandFactory()->constructTransferAsset(
+            kUserId, dest, kAssetId, "policy test", kAmount),
+        kUserId,
+        true);
+  }
+
+  void passKyc(const AccountIdType &account_id) {
+    IROHA_ASSERT_RESULT_VALUE(getItf().executeMaintenanceCommand(
+        *getItf().getMockCommandFactory()->constructSetAccountDetail(
+            account_id,
+            kKycKey,
+            iroha::ametsuchi::kTransferPolicyKycVerified)));
+  }
+};
+
+using TransferPolicyBasicTest = BasicExecutorTest<TransferPolicyTest>;
+
+/**
+ * @given asset without transfer policy
+ * @when the asset is transferred to another domain
+ * @then the transfer succeeds
+ */
+TEST_P(TransferPolicyBasicTest, NoPolicy) {
+  IROHA_ASSERT_RESULT_VALUE(transfer(kSecondDomainUserId));
+  checkAssetQuantities(kSecondDomainUserId, {AssetQuantity{kAssetId, kAmount}});
+}
+
+/**
+ * @given asset with the policy whitelisting its domain and an account of
+ * another domain
+ * @when the asset is transferred to accounts in and out of the whitelist
+ * @then only transfers to the whitelisted accounts succeed
+ */
+TEST_P(TransferPolicyBasicTest, Whitelist) {
+  IROHA_ASSERT_RESULT_VALUE(
+      setPolicy(fmt::format(R"({{"domains": ["{}"]}})", kDomain)));
+  IROHA_ASSERT_RESULT_VALUE(transfer(kSameDomainUserId));
+  checkCommandError(transfer(kSecondDomainUserId), 9);
+  checkAssetQuantities(kSecondDomainUserId, {});
+
+  IROHA_ASSERT_RESULT_VALUE(
+      setPolicy(fmt::format(R"({{"domains": ["{}"], "accounts": ["{}"]}})",
+                            kDomain,
+                            kSecondDomainUserId)));
+  IROHA_ASSERT_RESULT_VALUE(transfer(kSecondDomainUserId));
+  checkAssetQuantities(kSecondDomainUserId, {AssetQuantity{kAssetId, kAmount}});
+}
+
+/**
+ * @given asset with the policy requiring KYC by admin
+ * @when the asset is transferred before and after both parties pass KYC
+ * @then only the transfer after KYC succeeds
+ */
+TEST_P(TransferPolicyBasicTest, Kyc) {
+  IROHA_ASSERT_RESULT_VALUE(setPolicy(fmt::format(
+      R"({{"kyc": {{"writer": "{}", "key": "{}"}}}})", kAdminId, kKycKey)));
+  checkCommandError(transfer(kSameDomainUserId), 9);
+
+  ASSERT_NO_FATAL_FAILURE(passKyc(kSameDomainUserId));
+  checkCommandError(transfer(kSameDomainUserId), 9);
+
+  ASSERT_NO_FATAL_FAILURE(passKyc(kUserId));
+  IROHA_ASSERT_RESULT_VALUE(transfer(kSameDomainUserId));
+  checkAssetQuantities(kSameDomainUserId, {AssetQuantity{kAssetId, kAmount}});
+}
+
+/**
+ * @given asset without transfer policy
+ * @when a malformed policy is set
+ * @then the command fails
+ */
+TEST_P(TransferPolicyBasicTest, MalformedPolicy) {
+  checkCommandError(setPolicy(R"({"domains": "not a list"})"), 3);
+  checkCommandError(setPolicy("["), 3);
+}
+
+INSTANTIATE_TEST_SUITE_P(Base,
+                         TransferPolicyBasicTest,
+                         executor_testing::getExecutorTestParams(),
+                         executor_testing::paramToString);
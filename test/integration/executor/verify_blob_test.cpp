@@ -0,0 +1,105 @@
+/**
+ * Copyright Soramitsu Co., Ltd. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+#include "integration/executor/executor_fixture.hpp"
+
+#include <gtest/gtest.h>
+#include "cryptography/default_hash_provider.hpp"
+#include "framework/common_constants.hpp"
+#include "framework/result_gtest_checkers.hpp"
+#include "interfaces/query_responses/blob_verification_response.hpp"
+#include "module/shared_model/mock_objects_factories/mock_command_factory.hpp"
+#include "module/shared_model/mock_objects_factories/mock_query_factory.hpp"
+
+using namespace common_constants;
+using namespace executor_testing;
+using namespace shared_model::interface::types;
+
+using iroha::ametsuchi::QueryExecutorResult;
+using shared_model::interface::BlobVerificationResponse;
+
+static const std::string kKey{"contract_hash"};
+static const std::string kBlob{"the contract text"};
+static const std::string kSalt{"pepper"};
+
+struct VerifyBlobTest : public ExecutorTestBase {
+  /// Store the salted hash of the given payload as an account detail.
+  void storeHash(const std::string &blob, const std::string &salt) {
+    SCOPED_TRACE("VerifyBlobTest::storeHash");
+    const auto hash = shared_model::crypto::DefaultHashProvider::makeHash(
+                          shared_model::crypto::Blob(salt + blob))
+                          .hex();
+    IROHA_ASSERT_RESULT_VALUE(getItf().executeMaintenanceCommand(
+        *getItf().getMockCommandFactory()->constructSetAccountDetail(
+            kAdminId, kKey, hash)));
+  }
+
+  QueryExecutorResult verify(const AccountIdType &account_id,
+                             const std::string &blob,
+                             const std::string &salt) {
+    return getItf().executeQuery(
+        *getItf().getMockQueryFactory()->constructVerifyBlob(
+            account_id, kAdminId, kKey, blob, salt),
+        kAdminId);
+  }
+
+  void checkMatches(const std::string &blob,
+                    const std::string &salt,
+                    bool expected) {
+    checkSuccessfulResult<BlobVerificationResponse>(
+        verify(kAdminId, blob, salt),
+        [&](const BlobVerificationResponse &response) {
+          EXPECT_EQ(response.matches(), expected);
+        });
+  }
+};
+
+using VerifyBlobBasicTest = BasicExecutorTest<VerifyBlobTest>;
+
+/**
+ * @given an account detail holding the salted hash of a payload
+ * @when VerifyBlob is queried with the same payload and salt
+ * @then the payload matches
+ */
+TEST_P(VerifyBlobBasicTest, Matches) {
+  storeHash(kBlob, kSalt);
+  checkMatches(kBlob, kSalt, true);
+}
+
+/**
+ * @given an account detail holding the salted hash of a payload
+ * @when VerifyBlob is queried with a different payload or salt
+ * @then the payload does not match
+ */
+TEST_P(VerifyBlobBasicTest, Mismatches) {
+  storeHash(kBlob, kSalt);
+  checkMatches(kBlob + "!", kSalt, false);
+  checkMatches(kBlob, kSalt + "!", false);
+}
+
+/**
+ * @given an account without the requested detail
+ * @when VerifyBlob is queried
+ * @then there is an error
+ */
+TEST_P(VerifyBlobBasicTest, NoDetail) {
+  checkQueryError<shared_model::interface::NoAccountDetailErrorResponse>(
+      verify(kAdminId, kBlob, kSalt), error_codes::kNoStatefulError);
+}
+
+/**
+ * @given a nonexistent account
+ * @when VerifyBlob is queried
+ * @then there is an error
+ */
+TEST_P(VerifyBlobBasicTest, NoAccount) {
+  checkQueryError<shared_model::interface::NoAccountErrorResponse>(
+      verify(kUserId, kBlob, kSalt), error_codes::kNoStatefulError);
+}
+
+INSTANTIATE_TEST_SUITE_P(Base,
+                         VerifyBlobBasicTest,
+                         executor_testing::getExecutorTestParams(),
+                         executor_testing::paramToString);
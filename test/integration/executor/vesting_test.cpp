@@ -0,0 +1,145 @@
+/**
+ * Copyright Soramitsu Co., Ltd. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+#include "integration/executor/executor_fixture.hpp"
+
+#include <gtest/gtest.h>
+#include "framework/common_constants.hpp"
+#include "framework/result_gtest_checkers.hpp"
+#include "integration/executor/executor_fixture_param_provider.hpp"
+#include "interfaces/common_objects/amount.hpp"
+#include "interfaces/query_responses/vesting_schedules_response.hpp"
+#include "module/shared_model/mock_objects_factories/mock_command_factory.hpp"
+#include "module/shared_model/mock_objects_factories/mock_query_factory.hpp"
+
+using namespace common_constants;
+using namespace executor_testing;
+using namespace shared_model::interface::types;
+
+using shared_model::interface::Amount;
+using shared_model::interface::VestingSchedulesResponse;
+using shared_model::interface::permissions::Role;
+
+static const Amount kGranted{std::string{"10.0"}};
+
+class VestingTest : public ExecutorTestBase {
+ public:
+  void SetUp() override {
+    ExecutorTestBase::SetUp();
+    ASSERT_NO_FATAL_FAILURE(createAsset(kAssetName, kDomain, 1));
+    IROHA_ASSERT_RESULT_VALUE(getItf().createUserWithPerms(
+        kUser,
+        kDomain,
+        PublicKeyHexStringView{kUserKeypair.publicKey()},
+        {Role::kReceive, Role::kTransfer, Role::kSubtractAssetQty}));
+    IROHA_ASSERT_RESULT_VALUE(getItf().createUserWithPerms(
+        kSecondUser,
+        kDomain,
+        PublicKeyHexStringView{kSameDomainUserKeypair.publicKey()},
+        {Role::kReceive}));
+  }
+
+  iroha::ametsuchi::CommandResult grant(const AccountIdType &creator,
+                                        const Amount &amount = kGranted) {
+    return getItf().executeCommandAsAccount(
+        *getItf().getMockCommandFactory()->constructGrantVestedAsset(
+            kUserId, kAssetId, amount, 10, 100),
+        creator,
+        true);
+  }
+
+  iroha::ametsuchi::CommandResult transfer(const Amount &amount) {
+    return getItf().executeCommandAsAccount(
+        *getItf().getMockCommandFactory()->constructTransferAsset(
+            kUserId, kSameDomainUserId, kAssetId, "", amount),
+        kUserId,
+        true);
+  }
+
+  iroha::ametsuchi::CommandResult subtract(const Amount &amount) {
+    return getItf().executeCommandAsAccount(
+        *getItf().getMockCommandFactory()->constructSubtractAssetQuantity(
+            kAssetId, amount),
+        kUserId,
+        true);
+  }
+};
+
+using VestingBasicTest = BasicExecutorTest<VestingTest>;
+
+/**
+ * @given an account with a grant which is locked until the cliff and other
+ * assets of the same kind
+ * @when the account transfers and subtracts its assets
+ * @then only the assets which are not locked can be spent
+ */
+TEST_P(VestingBasicTest, LockedAssetsCannotBeSpent) {
+  IROHA_ASSERT_RESULT_VALUE(grant(kAdminId));
+  ASSERT_NO_FATAL_FAILURE(addAsset(kUserId, kAssetId, Amount{"5.0"}));
+  checkAssetQuantities(kUserId, {AssetQuantity{kAssetId, Amount{"15.0"}}});
+
+  checkCommandError(transfer(Amount{"6.0"}), 6);
+  IROHA_ASSERT_RESULT_VALUE(transfer(Amount{"4.0"}));
+  checkCommandError(subtract(Amount{"2.0"}), 4);
+  IROHA_ASSERT_RESULT_VALUE(subtract(Amount{"1.0"}));
+
+  checkAssetQuantities(kUserId, {AssetQuantity{kAssetId, kGranted}});
+  checkAssetQuantities(kSameDomainUserId,
+                       {AssetQuantity{kAssetId, Amount{"4.0"}}});
+}
+
+/**
+ * @given an account with a grant which is not vested yet
+ * @when the account is granted the same asset again
+ * @then the command fails and the balance is unchanged
+ */
+TEST_P(VestingBasicTest, ScheduleExists) {
+  IROHA_ASSERT_RESULT_VALUE(grant(kAdminId));
+
+  checkCommandError(grant(kAdminId), 6);
+
+  checkAssetQuantities(kUserId, {AssetQuantity{kAssetId, kGranted}});
+}
+
+/**
+ * @given an account without permission to add asset quantity
+ * @when it grants vested assets
+ * @then the command fails and nothing is issued
+ */
+TEST_P(VestingBasicTest, NoPermission) {
+  checkCommandError(grant(kSameDomainUserId), 2);
+
+  checkAssetQuantities(kUserId, {});
+}
+
+/**
+ * @given an account with a grant which is locked until the cliff
+ * @when its vesting schedules are queried
+ * @then the whole amount is reported as locked
+ */
+TEST_P(VestingBasicTest, QuerySchedules) {
+  IROHA_ASSERT_RESULT_VALUE(grant(kAdminId));
+
+  checkSuccessfulResult<VestingSchedulesResponse>(
+      getItf().executeQuery(
+          *getItf().getMockQueryFactory()->constructGetVestingSchedules(
+              kUserId)),
+      [](const VestingSchedulesResponse &response) {
+        ASSERT_EQ(response.schedules().size(), 1);
+        auto const &schedule = response.schedules().front();
+        EXPECT_EQ(schedule.asset_id, kAssetId);
+        EXPECT_EQ(schedule.amount, kGranted);
+        EXPECT_EQ(schedule.start_height, 1);
+        EXPECT_EQ(schedule.cliff, 10);
+        EXPECT_EQ(schedule.duration, 100);
+        EXPECT_EQ(schedule.vested, Amount{"0.0"});
+        EXPECT_EQ(schedule.locked, kGranted);
+      });
+}
+
+INSTANTIATE_TEST_SUITE_P(Base,
+                         VestingBasicTest,
+                         executor_testing::getExecutorTestParams(),
+                         executor_testing::paramToString);
@@ -0,0 +1,182 @@
+/**
+ * Copyright Soramitsu Co., Ltd. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+#include <gtest/gtest.h>
+
+#include "framework/result_gtest_checkers.hpp"
+#include "framework/test_logger.hpp"
+#include "main/server_runner.hpp"
+#include "module/irohad/ametsuchi/mock_peer_query.hpp"
+#include "module/shared_model/interface_mocks.hpp"
+#include "network/impl/channel_factory_tls.hpp"
+#include "network/impl/peer_tls_auth_processor.hpp"
+#include "network/impl/peer_tls_certificates_provider_root.hpp"
+#include "network/impl/tls_credentials.hpp"
+#include "yac.grpc.pb.h"
+
+using namespace iroha::network;
+using namespace std::literals;
+
+using shared_model::interface::types::PublicKeyHexStringView;
+using testing::Return;
+
+using PeerList = std::vector<std::shared_ptr<shared_model::interface::Peer>>;
+
+static const std::string kListenIp{"127.0.0.1"};
+static const std::string kTlsPath{PATHTESTDATA};
+
+class InterPeerTlsTest : public ::testing::Test {
+ public:
+  void SetUp() override {
+    auto peer_creds = TlsCredentials::load(kTlsPath + "/peer");
+    IROHA_ASSERT_RESULT_VALUE(peer_creds);
+    peer_creds_ = std::move(peer_creds).assumeValue();
+    auto unknown_peer_creds = TlsCredentials::load(kTlsPath + "/unknown_peer");
+    IROHA_ASSERT_RESULT_VALUE(unknown_peer_creds);
+    unknown_peer_creds_ = std::move(unknown_peer_creds).assumeValue();
+
+    // the ledger knows only the peer itself
+    peer_query_ = std::make_shared<iroha::ametsuchi::MockPeerQuery>();
+    PeerList ledger_peers{makePeer(kListenIp,
+                                   PublicKeyHexStringView{"peer"sv},
+                                   peer_creds_->certificate)};
+    EXPECT_CALL(*peer_query_, getLedgerPeers(false))
+        .WillRepeatedly(Return(ledger_peers));
+    EXPECT_CALL(*peer_query_, getLedgerPeers(true))
+        .WillRepeatedly(Return(PeerList{}));
+    auth_processor_ = std::make_shared<PeerTlsAuthProcessor>(
+        peer_query_, getTestLogger("InterPeerAuth"));
+
+    runner_ = std::make_unique<ServerRunner>(
+        kListenIp + ":0",
+        getTestLogger("ServerRunner"),
+        false,
+        std::shared_ptr<const TlsCredentials>(peer_creds_),
+        boost::none,
+        auth_processor_);
+    runner_->append(
+        std::make_shared<iroha::consensus::yac::proto::Yac::Service>());
+    auto port = runner_->run();
+    IROHA_ASSERT_RESULT_VALUE(port);
+    address_ = kListenIp + ":" + std::to_string(port.assumeValue());
+  }
+
+  /// Call the server as a peer with the given credentials
+  grpc::StatusCode callAs(
+      std::optional<std::shared_ptr<const TlsCredentials>> creds) {
+    ChannelFactoryTls factory(
+        std::nullopt,
+        std::make_shared<PeerTlsCertificatesProviderRoot>(
+            peer_creds_->certificate),
+        std::move(creds));
+    auto server = makePeer(address_, PublicKeyHexStringView{"peer"sv});
+    auto channel = factory.getChannel(
+        iroha::consensus::yac::proto::Yac::service_full_name(), *server);
+    EXPECT_TRUE(iroha::expected::hasValue(channel));
+    return call(channel.assumeValue());
+  }
+
+  grpc::StatusCode call(std::shared_ptr<grpc::Channel> channel) {
+    auto stub = iroha::consensus::yac::proto::Yac::NewStub(channel);
+    grpc::ClientContext context;
+    context.set_deadline(std::chrono::system_clock::now() + 5s);
+    iroha::consensus::yac::proto::State state;
+    google::protobuf::Empty response;
+    return stub->SendState(&context, state, &response).error_code();
+  }
+
+  std::shared_ptr<TlsCredentials> peer_creds_;
+  std::shared_ptr<TlsCredentials> unknown_peer_creds_;
+  std::shared_ptr<iroha::ametsuchi::MockPeerQuery> peer_query_;
+  std::shared_ptr<PeerTlsAuthProcessor> auth_processor_;
+  std::unique_ptr<ServerRunner> runner_;
+  std::string address_;
+};
+
+/**
+ * @given a peer server pinning clients to the certificates of ledger peers
+ * @when a ledger peer calls it
+ * @then the call reaches the service
+ */
+TEST_F(InterPeerTlsTest, LedgerPeerAccepted) {
+  // the default service does not implement the call, but it is reached
+  EXPECT_EQ(callAs(peer_creds_), grpc::StatusCode::UNIMPLEMENTED);
+}
+
+/**
+ * @given a peer server pinning clients to the certificates of ledger peers
+ * @when a peer with a certificate unknown to the ledger calls it
+ * @then the call is rejected as unauthenticated
+ */
+TEST_F(InterPeerTlsTest, UnknownPeerRejected) {
+  EXPECT_EQ(callAs(unknown_peer_creds_), grpc::StatusCode::UNAUTHENTICATED);
+}
+
+/**
+ * @given a peer server which has loaded the certificates of ledger peers
+ * @when a peer is added to the ledger and calls the server before and after
+ * the ledger peers are refreshed
+ * @then the first call is rejected as the certificates are cached
+ * @and the call after the refresh reaches the service
+ */
+TEST_F(InterPeerTlsTest, LedgerPeersCachedUntilRefresh) {
+  EXPECT_EQ(callAs(peer_creds_), grpc::StatusCode::UNIMPLEMENTED);
+
+  PeerList ledger_peers{makePeer(kListenIp,
+                                 PublicKeyHexStringView{"peer"sv},
+                                 peer_creds_->certificate),
+                        makePeer(kListenIp,
+                                 PublicKeyHexStringView{"new_peer"sv},
+                                 unknown_peer_creds_->certificate)};
+  EXPECT_CALL(*peer_query_, getLedgerPeers(false))
+      .WillRepeatedly(Return(ledger_peers));
+  EXPECT_EQ(callAs(unknown_peer_creds_), grpc::StatusCode::UNAUTHENTICATED);
+
+  auth_processor_->refreshLedgerPeers();
+  EXPECT_EQ(callAs(unknown_peer_creds_), grpc::StatusCode::UNIMPLEMENTED);
+}
+
+/**
+ * @given a peer server pinning clients to the certificates of ledger peers
+ * @when a client without a certificate calls it
+ * @then the call is rejected as unauthenticated
+ */
+TEST_F(InterPeerTlsTest, NoClientCertificateRejected) {
+  EXPECT_EQ(callAs(std::nullopt), grpc::StatusCode::UNAUTHENTICATED);
+}
+
+/**
+ * @given a peer server
+ * @when a ledger peer calls it without the inter peer protocol versions
+ * @then the call is rejected
+ */
+TEST_F(InterPeerTlsTest, NoProtocolVersionRejected) {
+  grpc::SslCredentialsOptions options;
+  options.pem_root_certs = peer_creds_->certificate;
+  options.pem_private_key = peer_creds_->private_key;
+  options.pem_cert_chain = peer_creds_->certificate;
+  EXPECT_EQ(call(grpc::CreateChannel(address_, grpc::SslCredentials(options))),
+            grpc::StatusCode::FAILED_PRECONDITION);
+}
+
+/**
+ * @given versions of the inter peer protocol a client speaks
+ * @when the version is negotiated
+ * @then the newest version both sides speak is selected
+ */
+TEST(PeerProtocolVersionTest, Negotiate) {
+  EXPECT_EQ(negotiatePeerProtocolVersion(std::to_string(kPeerProtocolVersion)),
+            kPeerProtocolVersion);
+  EXPECT_EQ(negotiatePeerProtocolVersion(
+                std::to_string(kPeerProtocolVersion + 1) + ","
+                + std::to_string(kPeerProtocolVersion)),
+            kPeerProtocolVersion);
+  EXPECT_EQ(negotiatePeerProtocolVersion(
+                std::to_string(kPeerProtocolVersion + 1)),
+            std::nullopt);
+  EXPECT_EQ(negotiatePeerProtocolVersion("0"), std::nullopt);
+  EXPECT_EQ(negotiatePeerProtocolVersion("garbage"), std::nullopt);
+  EXPECT_EQ(negotiatePeerProtocolVersion(""), std::nullopt);
+}
@@ -0,0 +1,247 @@
+#!/usr/bin/env python3
+#
+# Copyright Soramitsu Co., Ltd. All Rights Reserved.
+# SPDX-License-Identifier: Apache-2.0
+#
+
+"""
+Benchmark of an Iroha network with a synthetic workload.
+
+Workers send a weighted mix of transfers, mints, account detail writes and
+queries to the target peer for the given duration. Transactions are timed
+from submission until they appear in a committed block, queries until the
+response is received. The report with throughput and latency percentiles is
+printed and written in JSON format.
+"""
+
+import argparse
+import binascii
+import json
+import random
+import threading
+import time
+
+import grpc
+from iroha import Iroha, IrohaGrpc
+from iroha import IrohaCrypto as ic
+
+ADMIN_ACCOUNT = 'admin@test'
+ADMIN_PRIVATE_KEY = 'f101537e319568c765b2cc89698325604991dca57b9716b58016b253506cab70'
+DEST_ACCOUNT = 'test@test'
+ASSET = 'coin#test'
+
+OPERATIONS = ('transfer', 'mint', 'detail', 'query')
+
+
+def parse_mix(mix):
+    """
+    Parse the workload mix, e.g. 'transfer=70,mint=10,detail=10,query=10'
+    :return: dict of operation -> weight
+    """
+    weights = {}
+    for item in mix.split(','):
+        name, _, weight = item.partition('=')
+        if name not in OPERATIONS:
+            raise argparse.ArgumentTypeError(
+                'unknown operation {}, expected one of {}'.format(
+                    name, ', '.join(OPERATIONS)))
+        weights[name] = int(weight or 1)
+    return weights
+
+
+def percentiles(values):
+    """
+    :return: dict of latency percentiles in milliseconds
+    """
+    if not values:
+        return None
+    values = sorted(values)
+
+    def at(p):
+        return round(values[min(len(values) - 1, int(len(values) * p))], 3)
+
+    return {
+        'min': round(values[0], 3),
+        'p50': at(0.5),
+        'p90': at(0.9),
+        'p99': at(0.99),
+        'max': round(values[-1], 3),
+    }
+
+
+def tx_hash(tx):
+    return binascii.hexlify(ic.hash(tx)).decode('ascii')
+
+
+class Benchmark(object):
+
+    def __init__(self, args):
+        self.args = args
+        self.iroha = Iroha(ADMIN_ACCOUNT)
+        self.weights = parse_mix(args.mix)
+        self.lock = threading.Lock()
+        self.pending = dict()  # hash -> (operation, sent time)
+        self.latencies = {name: [] for name in OPERATIONS}
+        self.sent = {name: 0 for name in OPERATIONS}
+        self.failed = {name: 0 for name in OPERATIONS}
+        self.rejected = 0
+        self.blocks = 0
+        self.stopped = threading.Event()
+
+    def make_transaction(self, operation):
+        if operation == 'transfer':
+            command = self.iroha.command(
+                'TransferAsset', src_account_id=ADMIN_ACCOUNT,
+                dest_account_id=DEST_ACCOUNT, asset_id=ASSET,
+                amount='0.01', description=str(random.random()))
+        elif operation == 'mint':
+            command = self.iroha.command(
+                'AddAssetQuantity', asset_id=ASSET, amount='0.01')
+        else:
+            command = self.iroha.command(
+                'SetAccountDetail', account_id=ADMIN_ACCOUNT,
+                key='bench_{}'.format(random.randrange(self.args.detail_keys)),
+                value=str(random.random()))
+        tx = self.iroha.transaction([command])
+        ic.sign_transaction(tx, ADMIN_PRIVATE_KEY)
+        return tx
+
+    def send_query(self, net):
+        query = self.iroha.query('GetAccountAssets', account_id=ADMIN_ACCOUNT)
+        ic.sign_query(query, ADMIN_PRIVATE_KEY)
+        start = time.time()
+        response = net.send_query(query)
+        elapsed = (time.time() - start) * 1000
+        with self.lock:
+            self.sent['query'] += 1
+            if response.HasField('error_response'):
+                self.failed['query'] += 1
+            else:
+                self.latencies['query'].append(elapsed)
+
+    def worker(self, interval):
+        net = IrohaGrpc(self.args.target)
+        operations = list(self.weights.keys())
+        weights = list(self.weights.values())
+        next_time = time.time()
+        while not self.stopped.is_set():
+            operation = random.choices(operations, weights)[0]
+            try:
+                if operation == 'query':
+                    self.send_query(net)
+                else:
+                    # do not let the queue of the peer grow without bound
+                    while len(self.pending) > self.args.max_pending \
+                            and not self.stopped.is_set():
+                        time.sleep(0.01)
+                    tx = self.make_transaction(operation)
+                    with self.lock:
+                        self.sent[operation] += 1
+                        self.pending[tx_hash(tx)] = (operation, time.time())
+                    net.send_tx(tx)
+            except grpc.RpcError:
+                with self.lock:
+                    self.failed[operation] += 1
+            if interval:
+                next_time += interval
+                time.sleep(max(0, next_time - time.time()))
+
+    def block_listener(self):
+        net = IrohaGrpc(self.args.target)
+        query = self.iroha.blocks_query()
+        ic.sign_query(query, ADMIN_PRIVATE_KEY)
+        for response in net.send_blocks_stream_query(query):
+            payload = response.block_response.block.block_v1.payload
+            now = time.time()
+            with self.lock:
+                self.blocks += 1
+                for tx in payload.transactions:
+                    pending = self.pending.pop(tx_hash(tx), None)
+                    if pending:
+                        operation, sent = pending
+                        self.latencies[operation].append((now - sent) * 1000)
+                for hash in payload.rejected_transactions_hashes:
+                    pending = self.pending.pop(hash, None)
+                    if pending:
+                        self.rejected += 1
+                        self.failed[pending[0]] += 1
+            if self.stopped.is_set() and not self.pending:
+                break
+
+    def run(self):
+        listener = threading.Thread(target=self.block_listener, daemon=True)
+        listener.start()
+        interval = self.args.workers / self.args.rate if self.args.rate else 0
+        workers = [
+            threading.Thread(target=self.worker, args=(interval,), daemon=True)
+            for _ in range(self.args.workers)
+        ]
+        start = time.time()
+        for worker in workers:
+            worker.start()
+        time.sleep(self.args.duration)
+        self.stopped.set()
+        for worker in workers:
+            worker.join()
+        # wait for the sent transactions to be committed
+        listener.join(self.args.commit_timeout)
+        return self.report(time.time() - start)
+
+    def report(self, elapsed):
+        with self.lock:
+            operations = {}
+            for name in self.weights:
+                operations[name] = {
+                    'sent': self.sent[name],
+                    'completed': len(self.latencies[name]),
+                    'failed': self.failed[name],
+                    'latency_ms': percentiles(self.latencies[name]),
+                }
+            completed_txs = sum(len(self.latencies[name])
+                                for name in OPERATIONS if name != 'query')
+            return {
+                'target': self.args.target,
+                'mix': self.weights,
+                'workers': self.args.workers,
+                'duration_s': round(elapsed, 3),
+                'blocks': self.blocks,
+                'transactions_per_second': round(completed_txs / elapsed, 3),
+                'queries_per_second':
+                    round(len(self.latencies['query']) / elapsed, 3),
+                'rejected_transactions': self.rejected,
+                'not_committed_transactions': len(self.pending),
+                'operations': operations,
+            }
+
+
+def main():
+    parser = argparse.ArgumentParser(description=__doc__.strip().split('\n')[0])
+    parser.add_argument('--target', default='127.0.0.1:50051',
+                        help='Torii address of the peer')
+    parser.add_argument('--mix', default='transfer=70,mint=10,detail=10,query=10',
+                        help='Weights of the operations: ' + ', '.join(OPERATIONS))
+    parser.add_argument('--workers', type=int, default=4,
+                        help='Number of concurrent senders')
+    parser.add_argument('--rate', type=float, default=0,
+                        help='Total operations per second, unlimited if 0')
+    parser.add_argument('--duration', type=float, default=60,
+                        help='Duration of the load in seconds')
+    parser.add_argument('--max-pending', type=int, default=1000,
+                        help='Maximum number of not yet committed transactions')
+    parser.add_argument('--commit-timeout', type=float, default=30,
+                        help='Time to wait for the sent transactions in seconds')
+    parser.add_argument('--detail-keys', type=int, default=100,
+                        help='Number of distinct account detail keys written')
+    parser.add_argument('--report', default='report.json',
+                        help='Path of the JSON report')
+    args = parser.parse_args()
+
+    report = Benchmark(args).run()
+    text = json.dumps(report, indent=2)
+    print(text)
+    with open(args.report, 'w') as file:
+        file.write(text)
+
+
+if __name__ == '__main__':
+    main()
@@ -0,0 +1,177 @@
+/**
+ * Copyright Soramitsu Co., Ltd. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+#include "inspect.hpp"
+
+#include <gmock/gmock.h>
+#include <gtest/gtest.h>
+#include <rapidjson/document.h>
+
+using namespace iroha_cli::inspect;
+using ::testing::HasSubstr;
+
+class InspectTest : public ::testing::Test {
+ public:
+  void SetUp() override {
+    auto payload = transaction.mutable_payload()->mutable_reduced_payload();
+    payload->set_creator_account_id("admin@test");
+    payload->set_created_time(1000);
+    payload->set_quorum(1);
+    auto transfer = payload->add_commands()->mutable_transfer_asset();
+    transfer->set_src_account_id("admin@test");
+    transfer->set_dest_account_id("bob@test");
+    transfer->set_asset_id("coin#test");
+    transfer->set_description("rent");
+    transfer->set_amount("10");
+    auto signature = transaction.add_signatures();
+    signature->set_public_key("pubkey");
+    signature->set_signature("signature");
+  }
+
+  iroha::protocol::Transaction transaction;
+};
+
+/**
+ * @given a rejected transaction with an engine receipt
+ * @when it is described
+ * @then the description contains the creator, the command, the signature,
+ * the rejection reason and the receipt
+ */
+TEST_F(InspectTest, DescribeTransaction) {
+  TransactionDetails details{transaction, std::nullopt, {}};
+  details.status = iroha::protocol::ToriiResponse{};
+  details.status->set_tx_status(
+      iroha::protocol::TxStatus::STATEFUL_VALIDATION_FAILED);
+  details.status->set_err_or_cmd_name("TransferAsset");
+  details.status->set_failed_cmd_index(0);
+  details.status->set_error_code(6);
+  details.receipts.emplace_back();
+  details.receipts.back().set_caller("admin@test");
+  details.receipts.back().set_contract_address("contract");
+
+  auto const description = describe(details);
+  EXPECT_THAT(description, HasSubstr("creator: admin@test"));
+  EXPECT_THAT(description, HasSubstr("#0"));
+  EXPECT_THAT(description, HasSubstr("pubkey signature"));
+  EXPECT_THAT(description, HasSubstr("status: STATEFUL_VALIDATION_FAILED"));
+  EXPECT_THAT(description,
+              HasSubstr("rejected by command #0 TransferAsset, error code 6"));
+  EXPECT_THAT(description, HasSubstr("deployed contract: contract"));
+}
+
+/**
+ * @given a transaction without a known status
+ * @when it is printed in JSON format
+ * @then the JSON contains the hash, the transaction and a null status
+ */
+TEST_F(InspectTest, TransactionToJson) {
+  auto const json = toJson(TransactionDetails{transaction, std::nullopt, {}});
+  rapidjson::Document document;
+  document.Parse(json.c_str());
+  ASSERT_FALSE(document.HasParseError());
+  EXPECT_TRUE(document["hash"].IsString());
+  EXPECT_TRUE(document["transaction"].IsObject());
+  EXPECT_TRUE(document["status"].IsNull());
+  EXPECT_TRUE(document["receipts"].IsArray());
+}
+
+/**
+ * @given a block with one committed and one rejected transaction
+ * @when it is described and printed in JSON format
+ * @then the description contains the height, the committed transaction and
+ * the hash of the rejected one
+ * @and the JSON contains the hash and the block
+ */
+TEST_F(InspectTest, DescribeBlock) {
+  iroha::protocol::Block_v1 block;
+  auto payload = block.mutable_payload();
+  payload->set_height(7);
+  payload->set_prev_block_hash("prev");
+  *payload->add_transactions() = transaction;
+  payload->add_rejected_transactions_hashes("rejected");
+
+  auto const description = describe(block);
+  EXPECT_THAT(description, HasSubstr("at height 7"));
+  EXPECT_THAT(description, HasSubstr("previous block: prev"));
+  EXPECT_THAT(description, HasSubstr("transactions: 1"));
+  EXPECT_THAT(description, HasSubstr("creator: admin@test"));
+  EXPECT_THAT(description, HasSubstr("rejected transactions: 1"));
+  EXPECT_THAT(description, HasSubstr("rejected\n"));
+
+  auto const json = toJson(block);
+  rapidjson::Document document;
+  document.Parse(json.c_str());
+  ASSERT_FALSE(document.HasParseError());
+  EXPECT_TRUE(document["hash"].IsString());
+  EXPECT_TRUE(document["block"].IsObject());
+}
+
+/**
+ * @given a transaction with a transfer and an account detail
+ * @when it is weighed
+ * @then the number of commands, the size, the longest description and the
+ * longest detail value are measured
+ */
+TEST_F(InspectTest, Weigh) {
+  auto detail = transaction.mutable_payload()
+                    ->mutable_reduced_payload()
+                    ->add_commands()
+                    ->mutable_set_account_detail();
+  detail->set_account_id("admin@test");
+  detail->set_key("key");
+  detail->set_value("longer value");
+
+  auto const weight = weigh(transaction);
+  EXPECT_FALSE(weight.hash.empty());
+  EXPECT_EQ(weight.commands, 2);
+  EXPECT_EQ(weight.size, transaction.ByteSizeLong());
+  EXPECT_EQ(weight.max_description_size, 4);
+  EXPECT_EQ(weight.max_detail_value_size, 12);
+  EXPECT_TRUE(weight.exceeded.empty());
+}
+
+/**
+ * @given a transaction with the description of 4 bytes
+ * @when it is checked against the settings with the description limit of 3
+ * bytes, a malformed detail value limit and without other limits
+ * @then only the description limit is reported as exceeded
+ * @and the transaction fits the limit of 4 bytes
+ */
+TEST_F(InspectTest, CheckLimits) {
+  auto const weight = weigh(transaction);
+  google::protobuf::Map<std::string, std::string> settings;
+  settings["MaxDescriptionSize"] = "3";
+  settings["MaxAccountDetailValueSize"] = "many";
+
+  auto exceeded = checkLimits(weight, settings);
+  ASSERT_EQ(exceeded.size(), 1);
+  EXPECT_EQ(exceeded[0], "MaxDescriptionSize is 3, got 4");
+
+  settings["MaxDescriptionSize"] = "4";
+  EXPECT_TRUE(checkLimits(weight, settings).empty());
+}
+
+/**
+ * @given the weight of a transaction exceeding a limit
+ * @when it is described and printed in JSON format
+ * @then both list the exceeded limit
+ */
+TEST_F(InspectTest, DescribeWeight) {
+  auto weight = weigh(transaction);
+  weight.exceeded.push_back("MaxDescriptionSize is 3, got 4");
+
+  EXPECT_THAT(
+      describe(weight),
+      HasSubstr("exceeded limits:\n    MaxDescriptionSize is 3, got 4"));
+
+  auto const json = toJson(weight);
+  rapidjson::Document document;
+  document.Parse(json.c_str());
+  ASSERT_FALSE(document.HasParseError());
+  ASSERT_TRUE(document["exceeded"].IsArray());
+  ASSERT_EQ(document["exceeded"].Size(), 1);
+  EXPECT_STREQ(document["exceeded"][0].GetString(),
+               "MaxDescriptionSize is 3, got 4");
+}
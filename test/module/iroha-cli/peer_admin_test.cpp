@@ -0,0 +1,125 @@
+/**
+ * Copyright Soramitsu Co., Ltd. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+#include "peer_admin.hpp"
+
+#include <gmock/gmock.h>
+#include <gtest/gtest.h>
+#include <boost/asio/ip/tcp.hpp>
+#include <thread>
+#include "framework/result_gtest_checkers.hpp"
+
+using namespace iroha_cli::peer_admin;
+using ::testing::HasSubstr;
+
+/**
+ * @given log level changes in the <logger>=<level> format
+ * @when they are parsed
+ * @then the valid change is parsed into the logger and the level
+ * @and the ones without a separator, a logger or a known level are rejected
+ */
+TEST(PeerAdminTest, ParseLogLevelChange) {
+  auto change = parseLogLevelChange("Irohad/Storage=debug");
+  IROHA_ASSERT_RESULT_VALUE(change);
+  EXPECT_EQ(change.assumeValue().logger, "Irohad/Storage");
+  EXPECT_EQ(change.assumeValue().level, "debug");
+
+  IROHA_ASSERT_RESULT_ERROR(parseLogLevelChange("Irohad"));
+  IROHA_ASSERT_RESULT_ERROR(parseLogLevelChange("=debug"));
+  IROHA_ASSERT_RESULT_ERROR(parseLogLevelChange("Irohad&x=debug"));
+  IROHA_ASSERT_RESULT_ERROR(parseLogLevelChange("Irohad=verbose"));
+}
+
+/**
+ * Fake HTTP server of a peer, which answers one request with the given
+ * response and records the request line
+ */
+class FakePeerServer {
+ public:
+  explicit FakePeerServer(std::string response)
+      : acceptor_(io_context_, {boost::asio::ip::make_address("127.0.0.1"), 0}),
+        thread_([this, response = std::move(response)] {
+          boost::asio::ip::tcp::iostream stream;
+          acceptor_.accept(stream.socket());
+          std::getline(stream, request_line_);
+          std::string line;
+          while (std::getline(stream, line) and line != "\r")
+            ;
+          stream << response << std::flush;
+        }) {}
+
+  ~FakePeerServer() {
+    if (thread_.joinable()) {
+      thread_.join();
+    }
+  }
+
+  uint16_t port() const {
+    return acceptor_.local_endpoint().port();
+  }
+
+  /// Request line, waits until the request is answered
+  const std::string &requestLine() {
+    if (thread_.joinable()) {
+      thread_.join();
+    }
+    return request_line_;
+  }
+
+ private:
+  boost::asio::io_context io_context_;
+  boost::asio::ip::tcp::acceptor acceptor_;
+  std::string request_line_;
+  std::thread thread_;
+};
+
+/**
+ * @given a peer reporting the levels of two loggers
+ * @when the log levels are requested
+ * @then they are requested with GET
+ * @and both loggers are returned with their levels
+ */
+TEST(PeerAdminTest, RequestLogLevels) {
+  std::string body =
+      R"({"loggers":[{"logger":"Irohad","level":"info"},)"
+      R"({"logger":"Irohad/Storage","level":"debug"}]})";
+  FakePeerServer server("HTTP/1.1 200 OK\r\nContent-Length: "
+                        + std::to_string(body.size()) + "\r\n\r\n" + body);
+  auto levels = requestLogLevels("127.0.0.1", server.port(), std::nullopt);
+  EXPECT_THAT(server.requestLine(), HasSubstr("GET /log_level "));
+  IROHA_ASSERT_RESULT_VALUE(levels);
+  EXPECT_EQ(levels.assumeValue(),
+            (LogLevels{{"Irohad", "info"}, {"Irohad/Storage", "debug"}}));
+}
+
+/**
+ * @given a peer rejecting the change of a log level
+ * @when the level is changed
+ * @then it is requested with POST with the logger and the level
+ * @and the error of the peer is returned
+ */
+TEST(PeerAdminTest, ChangeRejected) {
+  std::string body = R"({"loggers":[],"error":"unknown logger"})";
+  FakePeerServer server("HTTP/1.1 200 OK\r\nContent-Length: "
+                        + std::to_string(body.size()) + "\r\n\r\n" + body);
+  auto levels = requestLogLevels(
+      "127.0.0.1", server.port(), LogLevelChange{"Unknown", "debug"});
+  EXPECT_THAT(server.requestLine(),
+              HasSubstr("POST /log_level?logger=Unknown&level=debug "));
+  IROHA_ASSERT_RESULT_ERROR(levels);
+  EXPECT_EQ(levels.assumeError(), "unknown logger");
+}
+
+/**
+ * @given a peer responding with an error status
+ * @when the log levels are requested
+ * @then the status is reported
+ */
+TEST(PeerAdminTest, ErrorStatus) {
+  FakePeerServer server("HTTP/1.1 404 Not Found\r\nContent-Length: 0\r\n\r\n");
+  auto levels = requestLogLevels("127.0.0.1", server.port(), std::nullopt);
+  IROHA_ASSERT_RESULT_ERROR(levels);
+  EXPECT_THAT(levels.assumeError(), HasSubstr("404"));
+}
@@ -0,0 +1,196 @@
+/**
+ * Copyright Soramitsu Co., Ltd. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+#include "statement.hpp"
+
+#include <functional>
+
+#include <gtest/gtest.h>
+#include "ametsuchi/asset_lock.hpp"
+#include "framework/result_gtest_checkers.hpp"
+
+using namespace iroha_cli::statement;
+
+class StatementTest : public ::testing::Test {
+ protected:
+  iroha::protocol::Transaction makeTransaction(
+      const std::string &creator,
+      std::function<void(iroha::protocol::Command &)> make_command) {
+    iroha::protocol::Transaction transaction;
+    auto &payload = *transaction.mutable_payload()->mutable_reduced_payload();
+    payload.set_creator_account_id(creator);
+    payload.set_created_time(++created_time_);
+    make_command(*payload.add_commands());
+    return transaction;
+  }
+
+  iroha::protocol::Transaction makeIssue(const std::string &amount) {
+    return makeTransaction(kAccount, [&](auto &command) {
+      auto &issue = *command.mutable_add_asset_quantity();
+      issue.set_asset_id(kAsset);
+      issue.set_amount(amount);
+    });
+  }
+
+  iroha::protocol::Transaction makeTransfer(const std::string &src,
+                                            const std::string &dest,
+                                            const std::string &amount) {
+    return makeTransaction(src, [&](auto &command) {
+      auto &transfer = *command.mutable_transfer_asset();
+      transfer.set_src_account_id(src);
+      transfer.set_dest_account_id(dest);
+      transfer.set_asset_id(kAsset);
+      transfer.set_description("rent");
+      transfer.set_amount(amount);
+    });
+  }
+
+  iroha::protocol::Transaction makeLock(const std::string &src,
+                                        const std::string &dest,
+                                        const std::string &amount) {
+    return makeTransaction(src, [&](auto &command) {
+      auto &lock = *command.mutable_lock_asset();
+      lock.set_src_account_id(src);
+      lock.set_dest_account_id(dest);
+      lock.set_asset_id(kAsset);
+      lock.set_amount(amount);
+      lock.set_hash(kLockHash);
+      lock.set_timeout(10);
+    });
+  }
+
+  iroha::protocol::Transaction makeClaim(const std::string &creator) {
+    return makeTransaction(creator, [&](auto &command) {
+      command.mutable_claim_with_preimage()->set_preimage(kPreimage);
+    });
+  }
+
+  iroha::protocol::Transaction makeRefund() {
+    return makeTransaction(kAccount, [&](auto &command) {
+      command.mutable_refund_after_timeout()->set_hash(kLockHash);
+    });
+  }
+
+  const std::string kAccount = "alice@test";
+  const std::string kOther = "bob@test";
+  const std::string kAsset = "coin#test";
+  const std::string kPreimage = "deadbeef";
+  const std::string kLockHash =
+      iroha::ametsuchi::assetLockHash(kPreimage).assumeValue();
+  uint64_t created_time_ = 0;
+  StatementBuilder builder_{kAccount, kAsset, 2};
+};
+
+/**
+ * @given transfers to the account charged with different burn fees, as the
+ * supply policy was changed between them
+ * @when the statement is built with the fees recorded by the peer
+ * @then each incoming transfer is reduced by the fee charged by it
+ */
+TEST_F(StatementTest, FeeChange) {
+  IROHA_ASSERT_RESULT_VALUE(
+      builder_.add(makeTransfer(kOther, kAccount, "10"), {{0, "0.10"}}));
+  IROHA_ASSERT_RESULT_VALUE(
+      builder_.add(makeTransfer(kOther, kAccount, "10"), {{0, "0.50"}}));
+  IROHA_ASSERT_RESULT_VALUE(builder_.add(makeTransfer(kAccount, kOther, "1")));
+
+  auto const statement = builder_.statement();
+  ASSERT_EQ(statement.entries.size(), 3);
+  EXPECT_EQ(statement.entries[0].type, "transfer_in");
+  EXPECT_EQ(statement.entries[0].fee, "0.10");
+  EXPECT_EQ(statement.entries[0].balance, "9.90");
+  EXPECT_EQ(statement.entries[1].fee, "0.50");
+  EXPECT_EQ(statement.entries[1].balance, "19.40");
+  EXPECT_EQ(statement.entries[2].type, "transfer_out");
+  EXPECT_EQ(statement.entries[2].amount, "-1.00");
+  EXPECT_EQ(statement.entries[2].fee, "");
+  EXPECT_EQ(statement.closing_balance, "18.40");
+}
+
+/**
+ * @given an asset lock made by the account before the period, which is
+ * refunded within it, and a lock which is claimed by the other account
+ * @when the statement is built
+ * @then the locked amount leaves the balance and is shown as locked
+ * @and the refund returns it to the balance, the claim only unlocks it
+ */
+TEST_F(StatementTest, LockedBalance) {
+  IROHA_ASSERT_RESULT_VALUE(builder_.addBefore(makeIssue("10")));
+  IROHA_ASSERT_RESULT_VALUE(
+      builder_.addBefore(makeLock(kAccount, kOther, "4")));
+  IROHA_ASSERT_RESULT_VALUE(builder_.add(makeRefund()));
+  IROHA_ASSERT_RESULT_VALUE(builder_.add(makeLock(kAccount, kOther, "3")));
+  IROHA_ASSERT_RESULT_VALUE(builder_.add(makeClaim(kOther)));
+
+  auto const statement = builder_.statement();
+  EXPECT_EQ(statement.opening_balance, "6.00");
+  EXPECT_EQ(statement.opening_locked, "4.00");
+  ASSERT_EQ(statement.entries.size(), 3);
+  EXPECT_EQ(statement.entries[0].type, "refund_in");
+  EXPECT_EQ(statement.entries[0].amount, "4.00");
+  EXPECT_EQ(statement.entries[0].balance, "10.00");
+  EXPECT_EQ(statement.entries[0].locked, "0.00");
+  EXPECT_EQ(statement.entries[1].type, "lock_out");
+  EXPECT_EQ(statement.entries[1].amount, "-3.00");
+  EXPECT_EQ(statement.entries[1].balance, "7.00");
+  EXPECT_EQ(statement.entries[1].locked, "3.00");
+  EXPECT_EQ(statement.entries[2].type, "lock_claimed");
+  EXPECT_EQ(statement.entries[2].counterparty, kOther);
+  EXPECT_EQ(statement.entries[2].amount, "0.00");
+  EXPECT_EQ(statement.entries[2].balance, "7.00");
+  EXPECT_EQ(statement.entries[2].locked, "0.00");
+  EXPECT_EQ(statement.closing_balance, "7.00");
+  EXPECT_EQ(statement.closing_locked, "0.00");
+}
+
+/**
+ * @given an asset lock made for the account by the other account
+ * @when the account claims it
+ * @then the lock is not in the statement and the claim credits the balance
+ */
+TEST_F(StatementTest, ClaimedLock) {
+  IROHA_ASSERT_RESULT_VALUE(builder_.add(makeLock(kOther, kAccount, "5")));
+  IROHA_ASSERT_RESULT_VALUE(builder_.add(makeClaim(kAccount)));
+
+  auto const statement = builder_.statement();
+  ASSERT_EQ(statement.entries.size(), 1);
+  EXPECT_EQ(statement.entries[0].type, "claim_in");
+  EXPECT_EQ(statement.entries[0].counterparty, kOther);
+  EXPECT_EQ(statement.entries[0].balance, "5.00");
+  EXPECT_EQ(statement.closing_locked, "0.00");
+}
+
+/**
+ * @given a vested grant to the account
+ * @when the statement is exported to CSV
+ * @then the grant credits the balance and its schedule is described
+ */
+TEST_F(StatementTest, GrantToCsv) {
+  auto grant = makeTransaction(kOther, [&](auto &command) {
+    auto &grant = *command.mutable_grant_vested_asset();
+    grant.set_dest_account_id(kAccount);
+    grant.set_asset_id(kAsset);
+    grant.set_amount("8");
+    grant.set_cliff(5);
+    grant.set_duration(20);
+  });
+  IROHA_ASSERT_RESULT_VALUE(builder_.add(grant));
+
+  auto const csv = toCsv(builder_.statement());
+  EXPECT_NE(csv.find(",grant,bob@test,vesting with cliff of 5 and duration "
+                     "of 20 blocks,8.00,,8.00,0.00\n"),
+            std::string::npos);
+  EXPECT_NE(csv.find(",,closing,,,,,8.00,0.00\n"), std::string::npos);
+}
+
+/**
+ * @given a transfer from the account which exceeds its balance
+ * @when it is added to the statement
+ * @then the history is reported as incomplete
+ */
+TEST_F(StatementTest, NegativeBalance) {
+  EXPECT_TRUE(iroha::expected::hasError(
+      builder_.add(makeTransfer(kAccount, kOther, "1"))));
+}
@@ -0,0 +1,157 @@
+/**
+ * Copyright Soramitsu Co., Ltd. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+#include "wallet.hpp"
+
+#include <fstream>
+
+#include <gtest/gtest.h>
+#include <boost/filesystem.hpp>
+#include <boost/range/size.hpp>
+#include "common/result.hpp"
+#include "cryptography/ed25519_sha3_impl/crypto_provider.hpp"
+#include "framework/result_gtest_checkers.hpp"
+
+using namespace iroha_cli::wallet;
+namespace fs = boost::filesystem;
+
+class WalletTest : public ::testing::Test {
+ protected:
+  void SetUp() override {
+    fs::create_directories(path_);
+  }
+
+  void TearDown() override {
+    fs::remove_all(path_);
+  }
+
+  void writeFile(const std::string &name, const std::string &content) {
+    std::ofstream((path_ / name).string()) << content;
+  }
+
+  shared_model::proto::Transaction makeTransfer(
+      const std::string &amount = "10.00") {
+    auto transaction = makeTransferTransaction(
+        "alice@test", "bob@test", "coin#test", amount, "rent", keypair_);
+    IROHA_ASSERT_RESULT_VALUE(transaction);
+    return std::move(transaction).assumeValue();
+  }
+
+  const fs::path path_ = fs::temp_directory_path() / fs::unique_path();
+  const shared_model::crypto::Keypair keypair_ =
+      shared_model::crypto::CryptoProviderEd25519Sha3::generateKeypair();
+};
+
+/**
+ * @given a private key
+ * @when the keypair is restored from it
+ * @then the public key is derived from the private key
+ * @and a private key which is not hex is rejected
+ */
+TEST_F(WalletTest, KeypairFromPrivateKey) {
+  auto keypair = keypairFromPrivateKey(keypair_.privateKey().hex());
+  IROHA_ASSERT_RESULT_VALUE(keypair);
+  EXPECT_EQ(keypair.assumeValue().publicKey(), keypair_.publicKey());
+
+  EXPECT_TRUE(iroha::expected::hasError(keypairFromPrivateKey("not a key")));
+}
+
+/**
+ * @given transfer parameters
+ * @when a transfer transaction is made
+ * @then it is signed by the creator and transfers the asset
+ * @and a transaction with a malformed amount is rejected
+ */
+TEST_F(WalletTest, MakeTransferTransaction) {
+  auto const transaction = makeTransfer();
+  EXPECT_EQ(transaction.creatorAccountId(), "alice@test");
+  ASSERT_EQ(boost::size(transaction.signatures()), 1);
+  EXPECT_EQ(transaction.signatures().front().publicKey(),
+            keypair_.publicKey());
+
+  auto const &commands =
+      transaction.getTransport().payload().reduced_payload().commands();
+  ASSERT_EQ(commands.size(), 1);
+  ASSERT_TRUE(commands[0].has_transfer_asset());
+  auto const &transfer = commands[0].transfer_asset();
+  EXPECT_EQ(transfer.src_account_id(), "alice@test");
+  EXPECT_EQ(transfer.dest_account_id(), "bob@test");
+  EXPECT_EQ(transfer.asset_id(), "coin#test");
+  EXPECT_EQ(transfer.description(), "rent");
+  EXPECT_EQ(transfer.amount(), "10.00");
+
+  EXPECT_TRUE(iroha::expected::hasError(
+      makeTransferTransaction("alice@test",
+                              "bob@test",
+                              "coin#test",
+                              "ten",
+                              "rent",
+                              keypair_)));
+}
+
+/**
+ * @given a transaction signed by its creator
+ * @when it is signed by another key and by the creator key again
+ * @then the other signature is added
+ * @and the repeated signature is rejected
+ */
+TEST_F(WalletTest, SignTransaction) {
+  auto transaction = makeTransfer();
+  auto const cosigner =
+      shared_model::crypto::CryptoProviderEd25519Sha3::generateKeypair();
+
+  IROHA_ASSERT_RESULT_VALUE(signTransaction(transaction, cosigner));
+  EXPECT_EQ(boost::size(transaction.signatures()), 2);
+
+  EXPECT_TRUE(
+      iroha::expected::hasError(signTransaction(transaction, keypair_)));
+  EXPECT_EQ(boost::size(transaction.signatures()), 2);
+}
+
+/**
+ * @given a signed transaction
+ * @when it is written to a file and read back
+ * @then the same transaction with its signature is read
+ * @and missing and malformed files are rejected
+ */
+TEST_F(WalletTest, WriteAndReadTransaction) {
+  auto const transaction = makeTransfer();
+  auto const file = (path_ / "transfer.json").string();
+  IROHA_ASSERT_RESULT_VALUE(writeTransaction(transaction, file));
+
+  auto read = readTransaction(file);
+  IROHA_ASSERT_RESULT_VALUE(read);
+  EXPECT_EQ(read.assumeValue().hash(), transaction.hash());
+  EXPECT_EQ(boost::size(read.assumeValue().signatures()), 1);
+
+  EXPECT_TRUE(iroha::expected::hasError(
+      readTransaction((path_ / "missing.json").string())));
+  writeFile("malformed.json", "{\"payload\": 1}");
+  EXPECT_TRUE(iroha::expected::hasError(
+      readTransaction((path_ / "malformed.json").string())));
+}
+
+/**
+ * @given key directory with complete keypairs of two accounts and only the
+ * public key of a third one
+ * @when the identities are listed
+ * @then the accounts with both keys are listed sorted by account id
+ */
+TEST_F(WalletTest, ListIdentities) {
+  writeFile("carol@test.pub", "carol_key");
+  writeFile("carol@test.priv", "carol_private_key");
+  writeFile("alice@test.pub", "alice_key");
+  writeFile("alice@test.priv", "alice_private_key");
+  writeFile("bob@test.pub", "bob_key");
+
+  auto const identities = listIdentities(path_);
+  ASSERT_EQ(identities.size(), 2);
+  EXPECT_EQ(identities[0].account_id, "alice@test");
+  EXPECT_EQ(identities[0].public_key, "alice_key");
+  EXPECT_EQ(identities[1].account_id, "carol@test");
+  EXPECT_EQ(identities[1].public_key, "carol_key");
+
+  EXPECT_TRUE(listIdentities(path_ / "missing").empty());
+}
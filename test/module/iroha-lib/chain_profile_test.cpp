@@ -0,0 +1,74 @@
+/**
+ * Copyright Soramitsu Co., Ltd. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+#include "model/ChainProfile.hpp"
+
+#include <gtest/gtest.h>
+
+using namespace iroha_lib;
+
+class ChainRegistryTest : public ::testing::Test {
+ public:
+  void SetUp() override {
+    registry.add(staging).add(production);
+  }
+
+  ChainProfile staging{
+      "staging", "staging-chain", {{"127.0.0.1", 50051}}, "admin@test"};
+  ChainProfile production{
+      "production", "production-chain", {{"127.0.0.2", 50051}}, "admin@test"};
+  ChainRegistry registry;
+};
+
+/**
+ * @given a registry with staging and production profiles
+ * @when profiles without a chain id, without peers or duplicating the name
+ * or the chain id of a registered one are added
+ * @then std::invalid_argument is thrown
+ */
+TEST_F(ChainRegistryTest, InvalidProfilesRejected) {
+  EXPECT_THROW(registry.add({"dev", "", {{"127.0.0.3", 50051}}, ""}),
+               std::invalid_argument);
+  EXPECT_THROW(registry.add({"dev", "dev-chain", {}, ""}),
+               std::invalid_argument);
+  EXPECT_THROW(
+      registry.add({"staging", "dev-chain", {{"127.0.0.3", 50051}}, ""}),
+      std::invalid_argument);
+  EXPECT_THROW(
+      registry.add({"dev", "staging-chain", {{"127.0.0.3", 50051}}, ""}),
+      std::invalid_argument);
+}
+
+/**
+ * @given a registry with staging and production profiles
+ * @when no profile is selected
+ * @then there is no current profile and no transaction can be made
+ * @and an unknown profile can not be selected
+ */
+TEST_F(ChainRegistryTest, NoCurrentProfileByDefault) {
+  EXPECT_THROW(registry.current(), std::runtime_error);
+  EXPECT_THROW(registry.tx(iroha::keypair_t{}), std::runtime_error);
+  EXPECT_THROW(registry.use("dev"), std::invalid_argument);
+}
+
+/**
+ * @given a registry with the staging profile selected
+ * @when a transaction is made
+ * @then it is created by the default account and bound to the staging chain
+ * @and it passes the check for the current profile
+ * @and fails it after switching to production
+ */
+TEST_F(ChainRegistryTest, TxBoundToCurrentChain) {
+  registry.use("staging");
+  EXPECT_EQ(registry.current().name, "staging");
+
+  auto tx = registry.tx(iroha::keypair_t{}).signAndAddSignature();
+  EXPECT_EQ(tx.payload().reduced_payload().creator_account_id(), "admin@test");
+  EXPECT_EQ(tx.payload().reduced_payload().chain_id(), "staging-chain");
+  EXPECT_NO_THROW(registry.check(tx));
+
+  registry.use("production");
+  EXPECT_THROW(registry.check(tx), std::invalid_argument);
+}
@@ -0,0 +1,68 @@
+/**
+ * Copyright Soramitsu Co., Ltd. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+#include "model/Chain.hpp"
+
+#include <gtest/gtest.h>
+
+#include "testing/MockClient.hpp"
+
+using namespace iroha_lib;
+
+class ChainTest : public ::testing::Test {
+ public:
+  void SetUp() override {
+    for (uint64_t height = 1; height <= 3; ++height) {
+      BlockQueryResponse response;
+      response.mutable_block_response()
+          ->mutable_block()
+          ->mutable_block_v1()
+          ->mutable_payload()
+          ->set_height(height);
+      client.addBlockResponse(response);
+    }
+  }
+
+  MockClient client;
+  Chain chain{client, "admin@test", iroha::keypair_t{}};
+};
+
+/**
+ * @given a peer which does not report the height of its ledger
+ * @when the chain status is requested
+ * @then nothing is returned
+ */
+TEST_F(ChainTest, NoStatusWithoutHeight) {
+  EXPECT_FALSE(chain.status());
+}
+
+/**
+ * @given a peer with the ledger of 3 blocks reporting the height of 3
+ * @when the chain status is requested
+ * @then the height of 3 is returned
+ * @and the latest and the finalized block hashes are the same
+ */
+TEST_F(ChainTest, StatusOfTopBlock) {
+  HealthcheckData healthcheck;
+  healthcheck.set_last_block_height(3);
+  client.setHealthcheck(healthcheck);
+
+  auto status = chain.status();
+  ASSERT_TRUE(status);
+  EXPECT_EQ(status->height, 3);
+  EXPECT_FALSE(status->latest_block_hash.empty());
+  EXPECT_EQ(status->latest_block_hash, status->finalized_block_hash);
+}
+
+/**
+ * @given a peer with the ledger of 3 blocks
+ * @when waiting for the heights of 2 and 4
+ * @then the height of 2 is reached
+ * @and the height of 4 is not
+ */
+TEST_F(ChainTest, WaitForHeight) {
+  EXPECT_TRUE(chain.waitForHeight(2, std::chrono::seconds(1)));
+  EXPECT_FALSE(chain.waitForHeight(4, std::chrono::seconds(1)));
+}
@@ -0,0 +1,131 @@
+/**
+ * Copyright Soramitsu Co., Ltd. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+#include "model/Intents.hpp"
+
+#include <gtest/gtest.h>
+
+#include "model/Tx.hpp"
+
+using namespace iroha_lib;
+
+class IntentsTest : public ::testing::Test {
+ public:
+  /// Commands of the transaction built so far
+  google::protobuf::RepeatedPtrField<iroha::protocol::Command> commands() {
+    return tx.signAndAddSignature().payload().reduced_payload().commands();
+  }
+
+  Tx tx{"admin@test", iroha::keypair_t{}};
+};
+
+/**
+ * @given a transfer intent with all values set
+ * @when it is added to the transaction
+ * @then the transaction contains the TransferAsset command with the values
+ */
+TEST_F(IntentsTest, Transfer) {
+  tx.transfer("coin#test")
+      .amount(10)
+      .from("alice@test")
+      .to("bob@test")
+      .description("rent")
+      .add();
+
+  auto const cmds = commands();
+  ASSERT_EQ(cmds.size(), 1);
+  ASSERT_TRUE(cmds[0].has_transfer_asset());
+  auto const &transfer = cmds[0].transfer_asset();
+  EXPECT_EQ(transfer.src_account_id(), "alice@test");
+  EXPECT_EQ(transfer.dest_account_id(), "bob@test");
+  EXPECT_EQ(transfer.asset_id(), "coin#test");
+  EXPECT_EQ(transfer.description(), "rent");
+  EXPECT_EQ(transfer.amount(), "10");
+}
+
+/**
+ * @given transfer intents with missing or malformed values
+ * @when they are added to the transaction
+ * @then std::invalid_argument is thrown
+ * @and no command is added
+ */
+TEST_F(IntentsTest, InvalidTransfer) {
+  EXPECT_THROW(tx.transfer("coin#test").amount(1).to("bob@test").add(),
+               std::invalid_argument);
+  EXPECT_THROW(tx.transfer("coin#test").from("alice@test").to("bob@test").add(),
+               std::invalid_argument);
+  EXPECT_THROW(
+      tx.transfer("coin").amount(1).from("alice@test").to("bob@test").add(),
+      std::invalid_argument);
+  EXPECT_THROW(tx.transfer("coin#test")
+                   .amount("0.0")
+                   .from("alice@test")
+                   .to("bob@test")
+                   .add(),
+               std::invalid_argument);
+  EXPECT_THROW(tx.transfer("coin#test")
+                   .amount(1)
+                   .from("alice@test")
+                   .to("alice@test")
+                   .add(),
+               std::invalid_argument);
+  EXPECT_TRUE(commands().empty());
+}
+
+/**
+ * @given mint and burn intents
+ * @when they are added to the transaction
+ * @then the transaction contains the AddAssetQuantity and
+ * SubtractAssetQuantity commands with the values
+ */
+TEST_F(IntentsTest, MintAndBurn) {
+  tx.mint("coin#test").amount("1.5").add();
+  tx.burn("coin#test").amount(2).add();
+
+  auto const cmds = commands();
+  ASSERT_EQ(cmds.size(), 2);
+  ASSERT_TRUE(cmds[0].has_add_asset_quantity());
+  EXPECT_EQ(cmds[0].add_asset_quantity().asset_id(), "coin#test");
+  EXPECT_EQ(cmds[0].add_asset_quantity().amount(), "1.5");
+  ASSERT_TRUE(cmds[1].has_subtract_asset_quantity());
+  EXPECT_EQ(cmds[1].subtract_asset_quantity().asset_id(), "coin#test");
+  EXPECT_EQ(cmds[1].subtract_asset_quantity().amount(), "2");
+}
+
+/**
+ * @given mint and burn intents without an amount or with a malformed one
+ * @when they are added to the transaction
+ * @then std::invalid_argument is thrown
+ */
+TEST_F(IntentsTest, InvalidMintAndBurn) {
+  EXPECT_THROW(tx.mint("coin#test").add(), std::invalid_argument);
+  EXPECT_THROW(tx.mint("coin#test").amount("-1").add(), std::invalid_argument);
+  EXPECT_THROW(tx.burn("coin#test").amount("1e3").add(),
+               std::invalid_argument);
+  EXPECT_THROW(tx.burn("Coin#test").amount(1).add(), std::invalid_argument);
+  EXPECT_TRUE(commands().empty());
+}
+
+/**
+ * @given a grant intent
+ * @when it is added to the transaction
+ * @then the transaction contains the GrantPermission command
+ * @and the intent without an account is rejected
+ */
+TEST_F(IntentsTest, Grant) {
+  tx.grant(iroha::protocol::GrantablePermission::can_set_my_quorum)
+      .to("bob@test")
+      .add();
+  EXPECT_THROW(
+      tx.grant(iroha::protocol::GrantablePermission::can_set_my_quorum).add(),
+      std::invalid_argument);
+
+  auto const cmds = commands();
+  ASSERT_EQ(cmds.size(), 1);
+  ASSERT_TRUE(cmds[0].has_grant_permission());
+  EXPECT_EQ(cmds[0].grant_permission().account_id(), "bob@test");
+  EXPECT_EQ(cmds[0].grant_permission().permission(),
+            iroha::protocol::GrantablePermission::can_set_my_quorum);
+}
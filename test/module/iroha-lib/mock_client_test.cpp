@@ -0,0 +1,147 @@
+/**
+ * Copyright Soramitsu Co., Ltd. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+#include "testing/MockClient.hpp"
+
+#include <gtest/gtest.h>
+
+using namespace iroha_lib;
+
+namespace {
+  BlockQueryResponse makeBlockResponse(uint64_t height) {
+    BlockQueryResponse response;
+    response.mutable_block_response()
+        ->mutable_block()
+        ->mutable_block_v1()
+        ->mutable_payload()
+        ->set_height(height);
+    return response;
+  }
+}  // namespace
+
+/**
+ * @given a mock client
+ * @when a transaction and a list of two transactions are sent
+ * @then all three transactions are captured in the order of sending
+ * @and they are forgotten after clear()
+ */
+TEST(MockClientTest, CapturesSentTransactions) {
+  MockClient client;
+  Transaction tx;
+  tx.mutable_payload()->mutable_reduced_payload()->set_creator_account_id(
+      "first@test");
+  EXPECT_TRUE(client.send(tx).ok());
+
+  TxList tx_list;
+  tx.mutable_payload()->mutable_reduced_payload()->set_creator_account_id(
+      "second@test");
+  *tx_list.add_transactions() = tx;
+  tx.mutable_payload()->mutable_reduced_payload()->set_creator_account_id(
+      "third@test");
+  *tx_list.add_transactions() = tx;
+  EXPECT_TRUE(client.send(tx_list).ok());
+
+  ASSERT_EQ(client.sentTransactions().size(), 3);
+  EXPECT_EQ(client.sentTransactions()[0]
+                .payload()
+                .reduced_payload()
+                .creator_account_id(),
+            "first@test");
+  EXPECT_EQ(client.sentTransactions()[2]
+                .payload()
+                .reduced_payload()
+                .creator_account_id(),
+            "third@test");
+
+  client.clear();
+  EXPECT_TRUE(client.sentTransactions().empty());
+}
+
+/**
+ * @given a mock client with the UNAVAILABLE send status
+ * @when a transaction is sent
+ * @then the status is returned
+ * @and the transaction is still captured
+ */
+TEST(MockClientTest, ReturnsSendStatus) {
+  MockClient client;
+  client.setSendStatus(grpc::Status(grpc::StatusCode::UNAVAILABLE, "down"));
+  EXPECT_EQ(client.send(Transaction()).error_code(),
+            grpc::StatusCode::UNAVAILABLE);
+  EXPECT_EQ(client.sentTransactions().size(), 1);
+}
+
+/**
+ * @given a mock client with one programmed query response and a handler
+ * @when three queries are sent
+ * @then the first one is answered with the programmed response
+ * @and the rest are answered by the handler
+ * @and all queries are captured
+ */
+TEST(MockClientTest, AnswersQueries) {
+  MockClient client;
+  QueryResponse programmed;
+  programmed.set_query_hash("programmed");
+  client.addQueryResponse(programmed).setQueryHandler([](auto const &query) {
+    QueryResponse response;
+    response.set_query_hash(query.payload().meta().creator_account_id());
+    return response;
+  });
+
+  iroha::protocol::Query query;
+  query.mutable_payload()->mutable_meta()->set_creator_account_id("user@test");
+  EXPECT_EQ(client.send(query).query_hash(), "programmed");
+  EXPECT_EQ(client.send(query).query_hash(), "user@test");
+  EXPECT_EQ(client.send(query).query_hash(), "user@test");
+  EXPECT_EQ(client.sentQueries().size(), 3);
+}
+
+/**
+ * @given a mock client with a status of one transaction
+ * @when statuses of it and of an unknown transaction are requested
+ * @then the programmed status is returned for the known transaction
+ * @and NOT_RECEIVED is returned for the unknown one
+ */
+TEST(MockClientTest, ReturnsTxStatuses) {
+  MockClient client;
+  client.setTxStatus("known", TxStatus::COMMITTED);
+
+  auto known = client.getTxStatus("known");
+  EXPECT_EQ(known.tx_hash(), "known");
+  EXPECT_EQ(known.tx_status(), TxStatus::COMMITTED);
+  EXPECT_EQ(client.getTxStatus("unknown").tx_status(),
+            TxStatus::NOT_RECEIVED);
+}
+
+/**
+ * @given a mock client with blocks of heights 1, 2 and 3
+ * @when commits are fetched from the height 2
+ * @then the blocks of heights 2 and 3 are streamed
+ * @and the stream is cancelled when the handler returns false
+ */
+TEST(MockClientTest, StreamsBlocksFromHeight) {
+  MockClient client;
+  client.addBlockResponse(makeBlockResponse(1))
+      .addBlockResponse(makeBlockResponse(2))
+      .addBlockResponse(makeBlockResponse(3));
+
+  BlocksQuery query;
+  query.set_from_height(2);
+  std::vector<uint64_t> heights;
+  auto status = client.fetchCommits(
+      query,
+      [&heights](auto const &response) {
+        heights.push_back(
+            response.block_response().block().block_v1().payload().height());
+        return true;
+      },
+      std::chrono::seconds(1));
+  EXPECT_TRUE(status.ok());
+  EXPECT_EQ(heights, (std::vector<uint64_t>{2, 3}));
+
+  status = client.fetchCommits(
+      query, [](auto const &) { return false; }, std::chrono::seconds(1));
+  EXPECT_EQ(status.error_code(), grpc::StatusCode::CANCELLED);
+}
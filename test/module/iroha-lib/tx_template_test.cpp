@@ -0,0 +1,100 @@
+/**
+ * Copyright Soramitsu Co., Ltd. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+#include "model/TxTemplate.hpp"
+
+#include <gtest/gtest.h>
+
+using namespace iroha_lib;
+
+class TxTemplateTest : public ::testing::Test {
+ public:
+  void SetUp() override {
+    Tx payroll{"admin@test", iroha::keypair_t{}};
+    payroll.transferAsset(
+        "admin@test", "{{employee}}", "coin#test", "salary", "{{amount}}");
+    tx_template = TxTemplate::fromTransaction(payroll.signAndAddSignature());
+  }
+
+  TxTemplate tx_template;
+};
+
+/**
+ * @given a template with the employee and amount parameters
+ * @when it is stored as JSON and loaded back
+ * @then the loaded template has the same parameters
+ */
+TEST_F(TxTemplateTest, JsonRoundTrip) {
+  auto const expected = std::set<std::string>{"amount", "employee"};
+  EXPECT_EQ(tx_template.parameters(), expected);
+  EXPECT_EQ(TxTemplate::fromJson(tx_template.toJson()).parameters(), expected);
+}
+
+/**
+ * @given a template with the employee and amount parameters
+ * @when it is instantiated with the values of both
+ * @then the transaction contains the command with the values in place of the
+ * parameters
+ */
+TEST_F(TxTemplateTest, Instantiate) {
+  Tx tx{"admin@test", iroha::keypair_t{}};
+  tx_template.instantiate(tx, {{"employee", "bob@test"}, {"amount", "10"}});
+
+  auto const cmds =
+      tx.signAndAddSignature().payload().reduced_payload().commands();
+  ASSERT_EQ(cmds.size(), 1);
+  ASSERT_TRUE(cmds[0].has_transfer_asset());
+  EXPECT_EQ(cmds[0].transfer_asset().src_account_id(), "admin@test");
+  EXPECT_EQ(cmds[0].transfer_asset().dest_account_id(), "bob@test");
+  EXPECT_EQ(cmds[0].transfer_asset().amount(), "10");
+}
+
+/**
+ * @given a template with the employee and amount parameters
+ * @when it is instantiated without a value of a parameter or with a value of
+ * an unknown one
+ * @then std::invalid_argument is thrown
+ * @and no command is added to the transaction
+ */
+TEST_F(TxTemplateTest, ParametersMustMatch) {
+  Tx tx{"admin@test", iroha::keypair_t{}};
+  EXPECT_THROW(tx_template.instantiate(tx, {{"employee", "bob@test"}}),
+               std::invalid_argument);
+  EXPECT_THROW(tx_template.instantiate(tx,
+                                       {{"employee", "bob@test"},
+                                        {"amount", "10"},
+                                        {"ammount", "10"}}),
+               std::invalid_argument);
+  EXPECT_TRUE(
+      tx.signAndAddSignature().payload().reduced_payload().commands().empty());
+}
+
+/**
+ * @given a template with the employee and amount parameters
+ * @when it is instantiated with a value containing quotes
+ * @then the value is kept as is instead of changing the command structure
+ */
+TEST_F(TxTemplateTest, ValuesAreEscaped) {
+  Tx tx{"admin@test", iroha::keypair_t{}};
+  tx_template.instantiate(
+      tx, {{"employee", R"(bob@test", "amount": "1000)"}, {"amount", "10"}});
+
+  auto const cmds =
+      tx.signAndAddSignature().payload().reduced_payload().commands();
+  ASSERT_EQ(cmds.size(), 1);
+  EXPECT_EQ(cmds[0].transfer_asset().dest_account_id(),
+            R"(bob@test", "amount": "1000)");
+  EXPECT_EQ(cmds[0].transfer_asset().amount(), "10");
+}
+
+/**
+ * @given JSON which is not a template or has no commands
+ * @when a template is loaded from it
+ * @then std::invalid_argument is thrown
+ */
+TEST(TxTemplateJsonTest, InvalidJsonRejected) {
+  EXPECT_THROW(TxTemplate::fromJson("not json"), std::invalid_argument);
+  EXPECT_THROW(TxTemplate::fromJson("{}"), std::invalid_argument);
+}
@@ -0,0 +1,102 @@
+/**
+ * Copyright Soramitsu Co., Ltd. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+#include "ametsuchi/impl/block_archive.hpp"
+
+#include <gtest/gtest.h>
+#include <sstream>
+#include "ametsuchi/impl/in_memory_block_storage.hpp"
+#include "backend/protobuf/block.hpp"
+#include "backend/protobuf/proto_block_json_converter.hpp"
+#include "framework/result_gtest_checkers.hpp"
+#include "module/shared_model/builders/protobuf/test_block_builder.hpp"
+
+using namespace iroha::ametsuchi;
+
+class BlockArchiveTest : public ::testing::Test {
+ protected:
+  void SetUp() override {
+    shared_model::crypto::Hash prev_hash{std::string(32, '0')};
+    for (shared_model::interface::types::HeightType height = 1;
+         height <= blocks_total_;
+         ++height) {
+      auto block = std::make_shared<shared_model::proto::Block>(
+          TestBlockBuilder().height(height).prevHash(prev_hash).build());
+      prev_hash = block->hash();
+      ASSERT_TRUE(source_.insert(block));
+    }
+  }
+
+  InMemoryBlockStorage source_;
+  InMemoryBlockStorage destination_;
+  shared_model::proto::ProtoBlockJsonConverter converter_;
+  const size_t blocks_total_ = 3;
+};
+
+/**
+ * @given block storage with several blocks
+ * @when the blocks are exported to an archive and imported to an empty
+ * storage
+ * @then the imported blocks are equal to the exported ones
+ */
+TEST_F(BlockArchiveTest, RoundTrip) {
+  std::stringstream archive;
+  IROHA_ASSERT_RESULT_VALUE(
+      exportBlockArchive(source_, converter_, 1, blocks_total_, archive));
+
+  auto result = importBlockArchive(archive, converter_, destination_);
+  IROHA_ASSERT_RESULT_VALUE(result);
+  EXPECT_EQ(result.assumeValue(), blocks_total_);
+  ASSERT_EQ(destination_.size(), blocks_total_);
+  for (size_t height = 1; height <= blocks_total_; ++height) {
+    EXPECT_EQ((*destination_.fetch(height))->hash(),
+              (*source_.fetch(height))->hash());
+  }
+}
+
+/**
+ * @given archive with blocks starting from height 2
+ * @when it is imported to an empty storage
+ * @then import fails and the storage stays empty
+ */
+TEST_F(BlockArchiveTest, ImportGap) {
+  std::stringstream archive;
+  IROHA_ASSERT_RESULT_VALUE(
+      exportBlockArchive(source_, converter_, 2, blocks_total_, archive));
+
+  IROHA_ASSERT_RESULT_ERROR(
+      importBlockArchive(archive, converter_, destination_));
+  EXPECT_EQ(destination_.size(), 0);
+}
+
+/**
+ * @given archive with a manifest hash that does not match the block
+ * @when it is imported
+ * @then import stops at the tampered block
+ */
+TEST_F(BlockArchiveTest, HashMismatch) {
+  std::stringstream archive;
+  IROHA_ASSERT_RESULT_VALUE(
+      exportBlockArchive(source_, converter_, 1, blocks_total_, archive));
+  auto text = archive.str();
+  auto hash = (*source_.fetch(2))->hash().hex();
+  text.replace(text.find(hash), hash.size(), std::string(hash.size(), 'a'));
+
+  std::stringstream tampered(text);
+  IROHA_ASSERT_RESULT_ERROR(
+      importBlockArchive(tampered, converter_, destination_));
+  EXPECT_EQ(destination_.size(), 1);
+}
+
+/**
+ * @given block storage with several blocks
+ * @when a range beyond the top block is exported
+ * @then export fails
+ */
+TEST_F(BlockArchiveTest, ExportMissingBlock) {
+  std::stringstream archive;
+  IROHA_ASSERT_RESULT_ERROR(
+      exportBlockArchive(source_, converter_, 1, blocks_total_ + 1, archive));
+}
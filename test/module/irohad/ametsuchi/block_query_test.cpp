@@ -311,3 +311,34 @@ TEST_F(BlockQueryTest, GetTopBlockFail) {
   ASSERT_EQ(top_block_error.value().code,
             BlockQuery::GetBlockError::Code::kNoBlock);
 }
+
+/**
+ * @given block store which keeps the genesis block and the blocks from 4
+ * @when a block between them is requested
+ * @then result must be a kPruned error naming the first retained height
+ * @and a missing block above the top is still a kNoBlock error
+ */
+TEST_F(BlockQueryTest, GetPrunedBlock) {
+  EXPECT_CALL(*mock_block_storage, fetch(testing::_))
+      .WillRepeatedly(testing::Invoke([](auto /* height */) {
+        return boost::optional<
+            std::unique_ptr<shared_model::interface::Block>>{};
+      }));
+  EXPECT_CALL(*mock_block_storage, firstRetainedHeight())
+      .WillRepeatedly(Return(4));
+
+  ASSERT_EQ(empty_blocks->getFirstRetainedHeight(), 4);
+
+  auto pruned_error =
+      iroha::expected::resultToOptionalError(empty_blocks->getBlock(2));
+  ASSERT_TRUE(pruned_error);
+  ASSERT_EQ(pruned_error.value().code,
+            BlockQuery::GetBlockError::Code::kPruned);
+  ASSERT_EQ(pruned_error.value().message, prunedBlockMessage(2, 4));
+
+  auto missing_error =
+      iroha::expected::resultToOptionalError(empty_blocks->getBlock(10));
+  ASSERT_TRUE(missing_error);
+  ASSERT_EQ(missing_error.value().code,
+            BlockQuery::GetBlockError::Code::kNoBlock);
+}
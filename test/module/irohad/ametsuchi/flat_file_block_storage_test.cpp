@@ -8,6 +8,8 @@
 
 #include <gtest/gtest.h>
 #include <boost/filesystem.hpp>
+#include "ametsuchi/impl/block_compression.hpp"
+#include "common/byteutils.hpp"
 #include "framework/result_gtest_checkers.hpp"
 #include "framework/test_logger.hpp"
 #include "module/shared_model/interface_mocks.hpp"
@@ -189,3 +191,166 @@ TEST_F(FlatFileBlockStorageTest, ForEach) {
 
   ASSERT_EQ(1, count);
 }
+
+/**
+ * @given block storage which keeps 2 latest blocks
+ * @when blocks with heights from 1 to 5 are inserted
+ * @then only the genesis block and 2 latest blocks are left, size is the top
+ * height
+ */
+TEST_F(FlatFileBlockStorageTest, PruneOldBlocks) {
+  auto flat_file =
+      FlatFile::create(block_store_path_,
+                       log_manager_->getChild("FlatFile")->getLogger())
+          .assumeValue();
+  auto flat_file_raw = flat_file.get();
+  FlatFileBlockStorage block_storage(
+      std::move(flat_file),
+      converter_,
+      log_manager_->getChild("FlatFileBlockStorage")->getLogger(),
+      2);
+
+  for (shared_model::interface::types::HeightType height = 1; height <= 5;
+       ++height) {
+    auto block = std::make_shared<NiceMock<MockBlock>>();
+    ON_CALL(*block, height()).WillByDefault(Return(height));
+    ASSERT_TRUE(block_storage.insert(block));
+  }
+
+  ASSERT_EQ(flat_file_raw->blockIdentifiers(),
+            (FlatFile::BlockIdCollectionType{1, 4, 5}));
+  ASSERT_EQ(5, block_storage.size());
+  ASSERT_EQ(4, block_storage.firstRetainedHeight());
+  ASSERT_TRUE(isPrunedBlock(block_storage.firstRetainedHeight(), 2));
+  ASSERT_FALSE(isPrunedBlock(block_storage.firstRetainedHeight(), 1));
+  ASSERT_FALSE(isPrunedBlock(block_storage.firstRetainedHeight(), 4));
+}
+
+/**
+ * @given block storage which keeps 2 latest blocks
+ * @when blocks with heights from 1 to 3 are inserted
+ * @then no blocks are pruned yet and the whole chain is retained
+ */
+TEST_F(FlatFileBlockStorageTest, NothingPrunedYet) {
+  FlatFileBlockStorage block_storage(
+      FlatFile::create(block_store_path_,
+                       log_manager_->getChild("FlatFile")->getLogger())
+          .assumeValue(),
+      converter_,
+      log_manager_->getChild("FlatFileBlockStorage")->getLogger(),
+      2);
+
+  for (shared_model::interface::types::HeightType height = 1; height <= 3;
+       ++height) {
+    auto block = std::make_shared<NiceMock<MockBlock>>();
+    ON_CALL(*block, height()).WillByDefault(Return(height));
+    ASSERT_TRUE(block_storage.insert(block));
+  }
+
+  ASSERT_EQ(std::nullopt, block_storage.firstRetainedHeight());
+  ASSERT_FALSE(isPrunedBlock(block_storage.firstRetainedHeight(), 2));
+}
+
+/**
+ * @given block storage with compression enabled
+ * @when a block is inserted and fetched back
+ * @then it is stored compressed and deserialized from the original json
+ */
+TEST_F(FlatFileBlockStorageTest, CompressedBlock) {
+  const std::string block_json = R"({"block_v1": {"payload": {}}})";
+  ON_CALL(*converter_, serialize(_))
+      .WillByDefault(
+          Return(iroha::expected::makeValue(std::string{block_json})));
+
+  auto flat_file =
+      FlatFile::create(block_store_path_,
+                       log_manager_->getChild("FlatFile")->getLogger())
+          .assumeValue();
+  auto flat_file_raw = flat_file.get();
+  FlatFileBlockStorage block_storage(
+      std::move(flat_file),
+      converter_,
+      log_manager_->getChild("FlatFileBlockStorage")->getLogger(),
+      std::nullopt,
+      3);
+  ASSERT_TRUE(block_storage.insert(block_));
+  ASSERT_TRUE(isCompressedBlock(*flat_file_raw->get(height_)));
+
+  EXPECT_CALL(*converter_, deserialize(block_json))
+      .WillOnce(Invoke([](const auto &) {
+        return iroha::expected::makeValue<
+            std::unique_ptr<shared_model::interface::Block>>(
+            std::make_unique<MockBlock>());
+      }));
+  ASSERT_TRUE(block_storage.fetch(height_));
+}
+
+/**
+ * @given block storage with compression enabled and an uncompressed block
+ * stored before compression was turned on
+ * @when the block is fetched
+ * @then it is deserialized as is
+ */
+TEST_F(FlatFileBlockStorageTest, UncompressedBlockWithCompressionEnabled) {
+  const std::string block_json = R"({"block_v1": {"payload": {}}})";
+  auto flat_file =
+      FlatFile::create(block_store_path_,
+                       log_manager_->getChild("FlatFile")->getLogger())
+          .assumeValue();
+  ASSERT_TRUE(flat_file->add(height_, iroha::stringToBytes(block_json)));
+  FlatFileBlockStorage block_storage(
+      std::move(flat_file),
+      converter_,
+      log_manager_->getChild("FlatFileBlockStorage")->getLogger(),
+      std::nullopt,
+      3);
+
+  EXPECT_CALL(*converter_, deserialize(block_json))
+      .WillOnce(Invoke([](const auto &) {
+        return iroha::expected::makeValue<
+            std::unique_ptr<shared_model::interface::Block>>(
+            std::make_unique<MockBlock>());
+      }));
+  ASSERT_TRUE(block_storage.fetch(height_));
+}
+
+/**
+ * @given block storage with 3 blocks, the last 2 of them cannot be read
+ * @when the corrupted tail is truncated
+ * @then the 2 unreadable blocks are removed and the first one is kept
+ */
+TEST_F(FlatFileBlockStorageTest, TruncateCorruptedTail) {
+  const std::string valid_json = "valid";
+  auto flat_file =
+      FlatFile::create(block_store_path_,
+                       log_manager_->getChild("FlatFile")->getLogger())
+          .assumeValue();
+  ASSERT_TRUE(flat_file->add(1, iroha::stringToBytes(valid_json)));
+  ASSERT_TRUE(flat_file->add(2, iroha::stringToBytes("torn")));
+  ASSERT_TRUE(flat_file->add(3, iroha::stringToBytes("")));
+  auto flat_file_raw = flat_file.get();
+  FlatFileBlockStorage block_storage(
+      std::move(flat_file),
+      converter_,
+      log_manager_->getChild("FlatFileBlockStorage")->getLogger());
+
+  ON_CALL(*converter_, deserialize(_))
+      .WillByDefault(Invoke([&](const auto &json)
+                                -> iroha::expected::Result<
+                                    std::unique_ptr<
+                                        shared_model::interface::Block>,
+                                    std::string> {
+        if (json != valid_json) {
+          return std::string{"corrupted block"};
+        }
+        auto block = std::make_unique<NiceMock<MockBlock>>();
+        ON_CALL(*block, height()).WillByDefault(Return(1));
+        return iroha::expected::makeValue<
+            std::unique_ptr<shared_model::interface::Block>>(std::move(block));
+      }));
+
+  ASSERT_EQ(2, block_storage.truncateCorruptedTail());
+  ASSERT_EQ(flat_file_raw->blockIdentifiers(),
+            (FlatFile::BlockIdCollectionType{1}));
+  ASSERT_EQ(1, block_storage.size());
+}
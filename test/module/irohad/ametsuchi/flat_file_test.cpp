@@ -260,3 +260,91 @@ TEST_F(BlStore_Test, RemoveAndCreateNew) {
   ASSERT_TRUE(bl_store->get(7));
   ASSERT_FALSE(bl_store->get(1));
 }
+
+/**
+ * @given initialized FlatFile storage with 3 blocks
+ * @when the second block is removed
+ * @then it is neither available nor listed, the other blocks are intact
+ */
+TEST_F(BlStore_Test, RemoveBlock) {
+  auto store = FlatFile::create(block_store_path, flat_file_log_);
+  IROHA_ASSERT_RESULT_VALUE(store);
+  auto bl_store = std::move(store).assumeValue();
+
+  bl_store->add(1, block);
+  bl_store->add(2, block);
+  bl_store->add(3, block);
+
+  ASSERT_TRUE(bl_store->remove(2));
+
+  ASSERT_FALSE(bl_store->get(2));
+  ASSERT_TRUE(bl_store->get(1));
+  ASSERT_TRUE(bl_store->get(3));
+  ASSERT_EQ(bl_store->blockIdentifiers(),
+            (FlatFile::BlockIdCollectionType{1, 3}));
+  ASSERT_EQ(bl_store->last_id(), 3);
+}
+
+/**
+ * @given initialized FlatFile storage with a block
+ * @when the block is replaced, and a missing block is replaced
+ * @then the first replacement changes the contents, the second one fails
+ */
+TEST_F(BlStore_Test, ReplaceBlock) {
+  auto store = FlatFile::create(block_store_path, flat_file_log_);
+  IROHA_ASSERT_RESULT_VALUE(store);
+  auto bl_store = std::move(store).assumeValue();
+
+  bl_store->add(1, block);
+  const std::vector<uint8_t> new_block{9, 8, 7};
+
+  ASSERT_TRUE(bl_store->replace(1, new_block));
+  ASSERT_EQ(*bl_store->get(1), new_block);
+  ASSERT_FALSE(bl_store->replace(2, new_block));
+  ASSERT_FALSE(bl_store->get(2));
+}
+
+/**
+ * @given block store directory with a temporary file left by an interrupted
+ * write
+ * @when FlatFile storage is created
+ * @then the temporary file is removed and the block can be written again
+ */
+TEST_F(BlStore_Test, RemoveIncompleteWrite) {
+  auto tmp_file = fs::path(block_store_path)
+      / (FlatFile::id_to_name(1) + FlatFile::kTempFileExtension);
+  fs::ofstream(tmp_file) << "torn";
+
+  auto store = FlatFile::create(block_store_path, flat_file_log_);
+  IROHA_ASSERT_RESULT_VALUE(store);
+  auto bl_store = std::move(store).assumeValue();
+
+  ASSERT_FALSE(fs::exists(tmp_file));
+  ASSERT_TRUE(bl_store->add(1, block));
+}
+
+/**
+ * @given initialized FlatFile storage
+ * @when blocks are added, replaced and removed
+ * @then total size follows the size of stored files, also after reload
+ */
+TEST_F(BlStore_Test, TotalSize) {
+  auto store = FlatFile::create(block_store_path, flat_file_log_);
+  IROHA_ASSERT_RESULT_VALUE(store);
+  auto bl_store = std::move(store).assumeValue();
+  ASSERT_EQ(bl_store->totalSize(), 0);
+
+  bl_store->add(1, block);
+  bl_store->add(2, block);
+  ASSERT_EQ(bl_store->totalSize(), 2 * block.size());
+
+  const std::vector<uint8_t> small_block{1, 2, 3};
+  ASSERT_TRUE(bl_store->replace(2, small_block));
+  ASSERT_EQ(bl_store->totalSize(), block.size() + small_block.size());
+
+  ASSERT_TRUE(bl_store->remove(1));
+  ASSERT_EQ(bl_store->totalSize(), small_block.size());
+
+  bl_store->reload();
+  ASSERT_EQ(bl_store->totalSize(), small_block.size());
+}
@@ -21,6 +21,9 @@ namespace iroha {
           boost::optional<std::unique_ptr<shared_model::interface::Block>>(
               shared_model::interface::types::HeightType));
       MOCK_CONST_METHOD0(size, size_t());
+      MOCK_CONST_METHOD0(
+          firstRetainedHeight,
+          std::optional<shared_model::interface::types::HeightType>());
       MOCK_METHOD0(reload, void());
       MOCK_METHOD0(clear, void());
       MOCK_CONST_METHOD1(forEach,
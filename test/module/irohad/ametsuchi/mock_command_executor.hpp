@@ -22,6 +22,13 @@ namespace iroha {
                         shared_model::interface::types::CommandIndexType,
                         bool));
 
+      MOCK_METHOD2(
+          recordIdempotencyKey,
+          CommandResult(const shared_model::interface::Transaction &, bool));
+
+      MOCK_METHOD1(checkChainId,
+                   CommandResult(const shared_model::interface::Transaction &));
+
       MOCK_METHOD0(skipChanges, void());
       MOCK_METHOD0(dbSession, DatabaseTransaction &());
     };
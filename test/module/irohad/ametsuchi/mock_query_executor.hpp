@@ -26,6 +26,7 @@ namespace iroha {
       MOCK_METHOD2(validate,
                    bool(const shared_model::interface::BlocksQuery &,
                         const bool validate_signatories));
+      MOCK_METHOD1(setTimeout, void(std::chrono::milliseconds));
     };
 
   }  // namespace ametsuchi
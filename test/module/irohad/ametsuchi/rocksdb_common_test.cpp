@@ -1053,3 +1053,88 @@ TEST_F(RocksDBTest, Signatories) {
   ASSERT_TRUE(status.ok());
   ASSERT_EQ(counter, 3);
 }
+
+/**
+ * @given database with committed values
+ * @when checkpoint is created while the database is open
+ * @then the checkpoint can be opened as a separate database with the same data
+ */
+TEST_F(RocksDBTest, Checkpoint) {
+  auto const checkpoint_path =
+      (fs::temp_directory_path() / fs::unique_path()).string();
+  {
+    RocksDbCommon common(tx_context_);
+    ASSERT_FALSE(
+        iroha::expected::hasError(common.createCheckpoint(checkpoint_path)));
+  }
+  insertDb(key1_, value2_);
+
+  {
+    auto checkpoint_port = std::make_shared<RocksDBPort>();
+    ASSERT_FALSE(iroha::expected::hasError(
+        checkpoint_port->initialize(checkpoint_path)));
+    auto checkpoint_context =
+        std::make_shared<RocksDBContext>(checkpoint_port);
+
+    RocksDbCommon common(checkpoint_context);
+    ASSERT_TRUE(common.get(RocksDBPort::ColumnFamilyType::kWsv, key1_).ok());
+    ASSERT_EQ(common.valueBuffer(), value1_);
+    ASSERT_TRUE(common.get(RocksDBPort::ColumnFamilyType::kWsv, key5_).ok());
+    ASSERT_EQ(common.valueBuffer(), value5_);
+  }
+  ASSERT_EQ(readDb(key1_), value2_);
+
+  RocksDbCommon common(tx_context_);
+  ASSERT_TRUE(iroha::expected::hasError(
+      common.createCheckpoint(checkpoint_path)));
+  fs::remove_all(checkpoint_path);
+}
+
+/**
+ * @given database with committed values
+ * @when a read view is made and the values are changed afterwards
+ * @then the read view returns the values committed before it was made
+ * @and a new read view returns the committed changes only
+ */
+TEST_F(RocksDBTest, ReadView) {
+  auto read_view = tx_context_->makeReadView();
+  insertDb(key1_, value2_);
+  {
+    RocksDbCommon common(tx_context_);
+    common.valueBuffer() = value3_;
+    ASSERT_TRUE(common.put(RocksDBPort::ColumnFamilyType::kWsv, key5_).ok());
+  }
+
+  {
+    RocksDbCommon common(read_view);
+    ASSERT_TRUE(common.get(RocksDBPort::ColumnFamilyType::kWsv, key1_).ok());
+    ASSERT_EQ(common.valueBuffer(), value1_);
+  }
+
+  RocksDbCommon common(tx_context_->makeReadView());
+  ASSERT_TRUE(common.get(RocksDBPort::ColumnFamilyType::kWsv, key1_).ok());
+  ASSERT_EQ(common.valueBuffer(), value2_);
+  ASSERT_TRUE(common.get(RocksDBPort::ColumnFamilyType::kWsv, key5_).ok());
+  ASSERT_EQ(common.valueBuffer(), value5_);
+}
+
+/**
+ * @given database with committed values in the memtable
+ * @when the database is flushed
+ * @then the memtable is empty
+ * @and the values are read from the table files
+ */
+TEST_F(RocksDBTest, Flush) {
+  auto const entries_in_memtable = [&] {
+    return db_port_->getPropUInt64(RocksDBPort::ColumnFamilyType::kWsv,
+                                   "rocksdb.num-entries-active-mem-table");
+  };
+  ASSERT_NE(entries_in_memtable(), 0);
+
+  RocksDbCommon common(tx_context_);
+  ASSERT_TRUE(iroha::expected::hasValue(common.flush()));
+
+  ASSERT_EQ(entries_in_memtable(), 0);
+  ASSERT_TRUE(common.get(RocksDBPort::ColumnFamilyType::kWsv, key1_).ok());
+  ASSERT_EQ(common.valueBuffer(), value1_);
+}
@@ -0,0 +1,91 @@
+/**
+ * Copyright Soramitsu Co., Ltd. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+#include "ametsuchi/session_key.hpp"
+
+#include <gmock/gmock.h>
+#include <gtest/gtest.h>
+#include "backend/protobuf/commands/proto_command.hpp"
+#include "backend/protobuf/transaction.hpp"
+#include "framework/result_gtest_checkers.hpp"
+
+using namespace iroha::ametsuchi;
+using shared_model::interface::RolePermissionSet;
+using shared_model::interface::permissions::Role;
+using ::testing::HasSubstr;
+using ::testing::Optional;
+
+/**
+ * @given a session key allowed to transfer a single asset
+ * @when it is checked against several commands
+ * @then only the transfers of that asset are allowed
+ */
+TEST(SessionKeyTest, Allows) {
+  SessionKey key{RolePermissionSet{Role::kTransfer}, "coin#test", 10};
+
+  iroha::protocol::Command transfer;
+  transfer.mutable_transfer_asset()->set_asset_id("coin#test");
+  EXPECT_TRUE(key.allows(shared_model::proto::Command{transfer}));
+
+  transfer.mutable_transfer_asset()->set_asset_id("gold#test");
+  EXPECT_FALSE(key.allows(shared_model::proto::Command{transfer}));
+
+  iroha::protocol::Command add;
+  add.mutable_add_asset_quantity()->set_asset_id("coin#test");
+  EXPECT_FALSE(key.allows(shared_model::proto::Command{add}));
+
+  iroha::protocol::Command add_signatory;
+  add_signatory.mutable_add_signatory();
+  EXPECT_FALSE(key.allows(shared_model::proto::Command{add_signatory}));
+
+  key.allowed_asset_id.clear();
+  EXPECT_TRUE(key.allows(shared_model::proto::Command{transfer}));
+}
+
+/**
+ * @given a session key allowed to transfer until height 10
+ * @when transactions are checked against it at several heights
+ * @then the reason names the expiration height or the first command out of
+ * the scope of the key
+ */
+TEST(SessionKeyTest, CheckSigning) {
+  SessionKey key{RolePermissionSet{Role::kTransfer}, "", 10};
+
+  iroha::protocol::Transaction proto_tx;
+  auto payload = proto_tx.mutable_payload()->mutable_reduced_payload();
+  payload->add_commands()->mutable_transfer_asset()->set_asset_id("coin#test");
+  EXPECT_EQ(key.checkSigning(shared_model::proto::Transaction{proto_tx}, 9),
+            std::nullopt);
+  EXPECT_THAT(key.checkSigning(shared_model::proto::Transaction{proto_tx}, 10),
+              Optional(HasSubstr("expired at height 10")));
+
+  payload->add_commands()->mutable_add_signatory();
+  EXPECT_THAT(key.checkSigning(shared_model::proto::Transaction{proto_tx}, 9),
+              Optional(HasSubstr("command 1")));
+}
+
+/**
+ * @given a session key
+ * @when it is encoded and decoded
+ * @then the same key is returned
+ * @and malformed values are not decoded
+ */
+TEST(SessionKeyTest, Encoding) {
+  SessionKey key{
+      RolePermissionSet{Role::kTransfer, Role::kSetDetail}, "coin#test", 10};
+  auto encoded = key.encode();
+  auto decoded = SessionKey::decode(encoded);
+  IROHA_ASSERT_RESULT_VALUE(decoded);
+  EXPECT_EQ(decoded.assumeValue().permissions, key.permissions);
+  EXPECT_EQ(decoded.assumeValue().allowed_asset_id, key.allowed_asset_id);
+  EXPECT_EQ(decoded.assumeValue().expires_height, key.expires_height);
+
+  key.allowed_asset_id.clear();
+  IROHA_ASSERT_RESULT_VALUE(SessionKey::decode(key.encode()));
+
+  IROHA_ASSERT_RESULT_ERROR(SessionKey::decode("10"));
+  IROHA_ASSERT_RESULT_ERROR(SessionKey::decode("ten|0101|"));
+  IROHA_ASSERT_RESULT_ERROR(SessionKey::decode("10|0101|"));
+}
@@ -0,0 +1,60 @@
+/**
+ * Copyright Soramitsu Co., Ltd. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+#include "ametsuchi/setting_registry.hpp"
+
+#include <gtest/gtest.h>
+#include "ametsuchi/account_detail_limits.hpp"
+#include "ametsuchi/domain_policy.hpp"
+#include "ametsuchi/setting_query.hpp"
+#include "validators/field_validator.hpp"
+
+using namespace iroha::ametsuchi;
+
+/**
+ * @given the settings registry
+ * @when descriptors of known settings are looked up
+ * @then they are found, including the settings keyed by a prefix
+ */
+TEST(SettingRegistryTest, FindDescriptor) {
+  auto const *descriptor = findSettingDescriptor(kMaxDescriptionSizeKey);
+  ASSERT_NE(descriptor, nullptr);
+  EXPECT_EQ(descriptor->type, SettingDescriptor::Type::kUnsigned);
+
+  descriptor = findSettingDescriptor(domainPolicyKey("domain"));
+  ASSERT_NE(descriptor, nullptr);
+  EXPECT_EQ(descriptor->type, SettingDescriptor::Type::kPolicy);
+
+  EXPECT_EQ(findSettingDescriptor("UnknownSetting"), nullptr);
+}
+
+/**
+ * @given the settings registry
+ * @when values of an unsigned setting are checked
+ * @then only the numbers within its bounds are accepted
+ */
+TEST(SettingRegistryTest, UnsignedBounds) {
+  auto max = shared_model::validation::FieldValidator::kMaxDescriptionSize;
+  EXPECT_FALSE(checkSettingValue(kMaxDescriptionSizeKey, "0"));
+  EXPECT_FALSE(checkSettingValue(kMaxDescriptionSizeKey, std::to_string(max)));
+  EXPECT_TRUE(
+      checkSettingValue(kMaxDescriptionSizeKey, std::to_string(max + 1)));
+  EXPECT_TRUE(checkSettingValue(kMaxDescriptionSizeKey, "two"));
+  EXPECT_TRUE(checkSettingValue(kMaxDescriptionSizeKey, "-1"));
+  EXPECT_TRUE(checkSettingValue(kMaxDescriptionSizeKey, ""));
+
+  EXPECT_TRUE(checkSettingValue(kMaxAccountDetailKeysKey, "0"));
+  EXPECT_FALSE(checkSettingValue(kMaxAccountDetailKeysKey, "1"));
+}
+
+/**
+ * @given the settings registry
+ * @when values of a policy setting and of an unknown setting are checked
+ * @then malformed policies are rejected and unknown settings are accepted
+ */
+TEST(SettingRegistryTest, PoliciesAndUnknownSettings) {
+  EXPECT_TRUE(checkSettingValue(domainPolicyKey("domain"), "not a policy"));
+  EXPECT_FALSE(checkSettingValue("UnknownSetting", "anything"));
+}
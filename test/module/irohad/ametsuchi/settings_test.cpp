@@ -14,6 +14,7 @@
 #include "module/irohad/ametsuchi/ametsuchi_fixture.hpp"
 #include "module/shared_model/interface_mocks.hpp"
 #include "module/shared_model/mock_objects_factories/mock_command_factory.hpp"
+#include "validators/field_validator.hpp"
 
 namespace iroha {
   namespace ametsuchi {
@@ -98,16 +99,38 @@ namespace iroha {
 
     /**
      * @given settings
-     * @when trying to get setting with the key that has invalid value
-     * @then settings return default value
+     * @when trying to set a value which is not a number
+     * @then the command fails and settings return default value
      */
     TEST_F(SettingsTest, InvalidSettingValue) {
       std::string value = "two";
-      execute(*mock_command_factory->constructSetSettingValue(
-                  iroha::ametsuchi::kMaxDescriptionSizeKey, value),
-              true);
+      auto result =
+          execute(*mock_command_factory->constructSetSettingValue(
+                      iroha::ametsuchi::kMaxDescriptionSizeKey, value),
+                  true);
+      IROHA_ASSERT_RESULT_ERROR(result);
+      ASSERT_EQ(err(result)->error.error_code, 3);
+
+      auto settings_result = setting_query->get();
+      IROHA_ASSERT_RESULT_VALUE(settings_result);
+      ASSERT_EQ(val(settings_result).value().value->max_description_size,
+                shared_model::validation::kDefaultDescriptionSize);
+    }
 
-      ASSERT_TRUE(expected::hasError(setting_query->get()));
+    /**
+     * @given settings
+     * @when trying to set a value out of the bounds of the setting
+     * @then the command fails
+     */
+    TEST_F(SettingsTest, OutOfBoundsSettingValue) {
+      auto value = std::to_string(
+          shared_model::validation::FieldValidator::kMaxDescriptionSize + 1);
+      auto result =
+          execute(*mock_command_factory->constructSetSettingValue(
+                      iroha::ametsuchi::kMaxDescriptionSizeKey, value),
+                  true);
+      IROHA_ASSERT_RESULT_ERROR(result);
+      ASSERT_EQ(err(result)->error.error_code, 3);
     }
 
     /**
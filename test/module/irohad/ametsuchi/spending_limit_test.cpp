@@ -0,0 +1,77 @@
+/**
+ * Copyright Soramitsu Co., Ltd. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+#include "ametsuchi/spending_limit.hpp"
+
+#include <gtest/gtest.h>
+#include "framework/result_gtest_checkers.hpp"
+
+using namespace iroha::ametsuchi;
+using shared_model::interface::Amount;
+
+/**
+ * @given a limit of 10 per 5 blocks
+ * @when amounts are spent at several heights
+ * @then only the spendings within the last 5 blocks are counted
+ */
+TEST(SpendingLimitTest, RollingWindow) {
+  SpendingLimit limit{Amount{"10.00"}, 5, {}};
+  limit.spend(1, Amount{"4.00"});
+  limit.spend(3, Amount{"3.00"});
+  limit.spend(3, Amount{"1.00"});
+  EXPECT_EQ(limit.spent(5).toStringRepr(), "8.00");
+  EXPECT_EQ(limit.spent(6).toStringRepr(), "4.00");
+  EXPECT_EQ(limit.spent(8).toStringRepr(), "0.00");
+  EXPECT_EQ(limit.spendings.size(), 2);
+}
+
+/**
+ * @given a limit with some amount spent within the window
+ * @when it is checked whether more can be spent
+ * @then the amounts up to the rest of the limit are allowed
+ */
+TEST(SpendingLimitTest, Allows) {
+  SpendingLimit limit{Amount{"10.00"}, 5, {}};
+  limit.spend(1, Amount{"4.00"});
+  EXPECT_TRUE(limit.allows(2, Amount{"6.00"}));
+  EXPECT_FALSE(limit.allows(2, Amount{"6.01"}));
+  EXPECT_TRUE(limit.allows(6, Amount{"10.00"}));
+}
+
+/**
+ * @given a limit with spendings out of the window
+ * @when an amount is spent
+ * @then the spendings out of the window are dropped
+ */
+TEST(SpendingLimitTest, DropsOldSpendings) {
+  SpendingLimit limit{Amount{"10.00"}, 5, {}};
+  limit.spend(1, Amount{"4.00"});
+  limit.spend(4, Amount{"1.00"});
+  limit.spend(6, Amount{"2.00"});
+  EXPECT_EQ(limit.encodeSpendings(), "4:1.00,6:2.00");
+}
+
+/**
+ * @given a limit with spendings
+ * @when it is encoded and decoded
+ * @then the same limit is returned
+ * @and malformed values are not decoded
+ */
+TEST(SpendingLimitTest, Encoding) {
+  SpendingLimit limit{Amount{"10.00"}, 5, {}};
+  EXPECT_EQ(limit.encode(), "10.00|5|");
+  limit.spend(1, Amount{"4.00"});
+  limit.spend(3, Amount{"3.00"});
+  auto encoded = limit.encode();
+  EXPECT_EQ(encoded, "10.00|5|1:4.00,3:3.00");
+  auto decoded = SpendingLimit::decode(encoded);
+  IROHA_ASSERT_RESULT_VALUE(decoded);
+  EXPECT_EQ(decoded.assumeValue().encode(), encoded);
+
+  IROHA_ASSERT_RESULT_ERROR(SpendingLimit::decode("10.00"));
+  IROHA_ASSERT_RESULT_ERROR(SpendingLimit::decode("ten|5|"));
+  IROHA_ASSERT_RESULT_ERROR(SpendingLimit::decode("10.00|5|1:4.00,3"));
+  IROHA_ASSERT_RESULT_ERROR(SpendingLimit::decode("10.00|5|x:4.00"));
+}
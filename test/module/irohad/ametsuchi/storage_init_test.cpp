@@ -16,8 +16,10 @@
 #include "ametsuchi/impl/k_times_reconnection_strategy.hpp"
 #include "backend/protobuf/proto_permission_to_string.hpp"
 #include "backend/protobuf/proto_query_response_factory.hpp"
+#include "common/irohad_version.hpp"
 #include "common/result.hpp"
 #include "framework/config_helper.hpp"
+#include "framework/result_gtest_checkers.hpp"
 #include "framework/test_logger.hpp"
 #include "logger/logger_manager.hpp"
 #include "main/impl/pg_connection_init.hpp"
@@ -136,6 +138,37 @@ TEST_F(StorageInitTest, CreateStorageWithDatabase) {
   PgConnectionInit::dropWorkingDatabase(*options);
 }
 
+/**
+ * @given a working database created by an older irohad
+ * @when the database is reused
+ * @then the preparation fails with both schema versions in the error
+ * @and the database can be recreated with the drop policy
+ */
+TEST_F(StorageInitTest, ReuseOldSchema) {
+  PostgresOptions options(pgopt_,
+                          integration_framework::kDefaultWorkingDatabaseName,
+                          storage_log_manager_->getLogger());
+
+  IROHA_ASSERT_RESULT_VALUE(PgConnectionInit::prepareWorkingDatabase(
+      iroha::StartupWsvDataPolicy::kDrop, options));
+  {
+    soci::session sql(*soci::factory_postgresql(),
+                      options.workingConnectionString());
+    sql << "UPDATE schema_version SET iroha_minor = iroha_minor + 1";
+  }
+
+  auto result = PgConnectionInit::prepareWorkingDatabase(
+      iroha::StartupWsvDataPolicy::kReuse, options);
+  IROHA_ASSERT_RESULT_ERROR(result);
+  EXPECT_NE(result.assumeError().find(iroha::getIrohadVersion().toString()),
+            std::string::npos);
+  EXPECT_NE(result.assumeError().find("--drop_state"), std::string::npos);
+
+  IROHA_ASSERT_RESULT_VALUE(PgConnectionInit::prepareWorkingDatabase(
+      iroha::StartupWsvDataPolicy::kDrop, options));
+  PgConnectionInit::dropWorkingDatabase(options);
+}
+
 /**
  * @given Bad Postgres options string with nonexisting user in it
  * @when Create storage using that options string
@@ -15,8 +15,17 @@ namespace iroha {
           R"(
         TRUNCATE TABLE top_block_info;
         TRUNCATE TABLE account_has_signatory RESTART IDENTITY CASCADE;
+        TRUNCATE TABLE account_retired_signatory RESTART IDENTITY CASCADE;
+        TRUNCATE TABLE account_session_key RESTART IDENTITY CASCADE;
+        TRUNCATE TABLE idempotency_key RESTART IDENTITY CASCADE;
         TRUNCATE TABLE account_has_asset RESTART IDENTITY CASCADE;
+        TRUNCATE TABLE asset_lock RESTART IDENTITY CASCADE;
+        TRUNCATE TABLE asset_lock_release RESTART IDENTITY CASCADE;
+        TRUNCATE TABLE vesting_schedule RESTART IDENTITY CASCADE;
+        TRUNCATE TABLE spending_limit RESTART IDENTITY CASCADE;
+        TRUNCATE TABLE asset_stats RESTART IDENTITY CASCADE;
         TRUNCATE TABLE role_has_permissions RESTART IDENTITY CASCADE;
+        TRUNCATE TABLE role_includes_role RESTART IDENTITY CASCADE;
         TRUNCATE TABLE account_has_roles RESTART IDENTITY CASCADE;
         TRUNCATE TABLE account_has_grantable_permissions RESTART IDENTITY CASCADE;
         TRUNCATE TABLE account RESTART IDENTITY CASCADE;
@@ -27,7 +36,10 @@ namespace iroha {
         TRUNCATE TABLE role RESTART IDENTITY CASCADE;
         TRUNCATE TABLE tx_status_by_hash RESTART IDENTITY CASCADE;
         TRUNCATE TABLE setting RESTART IDENTITY CASCADE;
+        TRUNCATE TABLE governance_vote RESTART IDENTITY CASCADE;
+        TRUNCATE TABLE governance_proposal RESTART IDENTITY CASCADE;
         TRUNCATE TABLE engine_calls RESTART IDENTITY CASCADE;
+        TRUNCATE TABLE transfer_fee RESTART IDENTITY CASCADE;
         TRUNCATE TABLE burrow_account_data;
         TRUNCATE TABLE burrow_account_key_value;
         TRUNCATE TABLE burrow_tx_logs RESTART IDENTITY CASCADE;
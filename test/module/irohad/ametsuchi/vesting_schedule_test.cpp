@@ -0,0 +1,77 @@
+/**
+ * Copyright Soramitsu Co., Ltd. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+#include "ametsuchi/vesting_schedule.hpp"
+
+#include <gtest/gtest.h>
+#include "framework/result_gtest_checkers.hpp"
+
+using namespace iroha::ametsuchi;
+using shared_model::interface::Amount;
+
+static const VestingSchedule kSchedule{Amount{"10.00"}, 5, 10, 100};
+
+/**
+ * @given a schedule with a cliff
+ * @when the locked amount is computed before the cliff
+ * @then the whole amount is locked
+ */
+TEST(VestingScheduleTest, BeforeCliff) {
+  EXPECT_EQ(kSchedule.locked(4).toStringRepr(), "10.00");
+  EXPECT_EQ(kSchedule.locked(14).toStringRepr(), "10.00");
+  EXPECT_EQ(kSchedule.vested(14).toStringRepr(), "0.00");
+}
+
+/**
+ * @given a schedule with a cliff
+ * @when the locked amount is computed after the cliff
+ * @then the amount is released in proportion to the blocks since the grant
+ */
+TEST(VestingScheduleTest, LinearRelease) {
+  EXPECT_EQ(kSchedule.locked(15).toStringRepr(), "9.00");
+  EXPECT_EQ(kSchedule.locked(38).toStringRepr(), "6.70");
+  EXPECT_EQ(kSchedule.vested(38).toStringRepr(), "3.30");
+  EXPECT_FALSE(kSchedule.isVested(104));
+}
+
+/**
+ * @given a schedule of an amount which is not divisible by the duration
+ * @when the locked amount is computed
+ * @then the released part is rounded down
+ */
+TEST(VestingScheduleTest, Rounding) {
+  VestingSchedule schedule{Amount{"1.00"}, 1, 0, 3};
+  EXPECT_EQ(schedule.locked(2).toStringRepr(), "0.67");
+  EXPECT_EQ(schedule.vested(2).toStringRepr(), "0.33");
+}
+
+/**
+ * @given a schedule
+ * @when the locked amount is computed at the end of the schedule or later
+ * @then nothing is locked
+ */
+TEST(VestingScheduleTest, FullyVested) {
+  EXPECT_EQ(kSchedule.locked(105).toStringRepr(), "0.00");
+  EXPECT_EQ(kSchedule.vested(1000).toStringRepr(), "10.00");
+  EXPECT_TRUE(kSchedule.isVested(105));
+}
+
+/**
+ * @given a schedule
+ * @when it is encoded and decoded
+ * @then the same schedule is returned
+ * @and malformed values are not decoded
+ */
+TEST(VestingScheduleTest, Encoding) {
+  auto encoded = kSchedule.encode();
+  EXPECT_EQ(encoded, "10.00|5|10|100");
+  auto decoded = VestingSchedule::decode(encoded);
+  IROHA_ASSERT_RESULT_VALUE(decoded);
+  EXPECT_EQ(decoded.assumeValue().encode(), encoded);
+
+  IROHA_ASSERT_RESULT_ERROR(VestingSchedule::decode("10.00|5|10"));
+  IROHA_ASSERT_RESULT_ERROR(VestingSchedule::decode("ten|5|10|100"));
+  IROHA_ASSERT_RESULT_ERROR(VestingSchedule::decode("10.00|5|10|1x"));
+}
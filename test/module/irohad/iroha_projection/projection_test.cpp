@@ -0,0 +1,186 @@
+/**
+ * Copyright Soramitsu Co., Ltd. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+#include "iroha_projection/projection.hpp"
+
+#include <gtest/gtest.h>
+#include <soci/soci.h>
+#include "framework/result_gtest_checkers.hpp"
+#include "framework/test_db_manager.hpp"
+#include "framework/test_logger.hpp"
+#include "interfaces/permissions.hpp"
+#include "module/shared_model/builders/protobuf/test_block_builder.hpp"
+#include "module/shared_model/builders/protobuf/test_transaction_builder.hpp"
+
+using iroha::integration_framework::TestDbManager;
+using iroha::projection::Projection;
+using shared_model::interface::permissions::Grantable;
+using shared_model::interface::permissions::Role;
+
+static const std::string kAliceKeyHex(64, 'a');
+static const std::string kBobKeyHex(64, 'b');
+
+class ProjectionTest : public ::testing::Test {
+ protected:
+  void SetUp() override {
+    IROHA_ASSERT_RESULT_VALUE(projection_.migrate());
+  }
+
+  /// Block creating the domain, the accounts and the asset
+  shared_model::proto::Block makeGenesisBlock() {
+    using shared_model::interface::types::PublicKeyHexStringView;
+    auto tx = TestTransactionBuilder()
+                  .createdTime(1)
+                  .creatorAccountId("admin@test")
+                  .createRole("user",
+                              shared_model::interface::RolePermissionSet{
+                                  Role::kTransfer, Role::kReceive})
+                  .createDomain("test", "user")
+                  .createAccount(
+                      "alice", "test", PublicKeyHexStringView{kAliceKeyHex})
+                  .createAccount(
+                      "bob", "test", PublicKeyHexStringView{kBobKeyHex})
+                  .createAsset("coin", "test", 2)
+                  .build();
+    return makeBlock(1, tx);
+  }
+
+  /// Block where alice mints coins and pays bob
+  shared_model::proto::Block makeTransferBlock() {
+    auto tx = TestTransactionBuilder()
+                  .createdTime(2)
+                  .creatorAccountId("alice@test")
+                  .addAssetQuantity("coin#test", "10.00")
+                  .transferAsset(
+                      "alice@test", "bob@test", "coin#test", "rent", "3.50")
+                  .grantPermission("bob@test", Grantable::kSetMyQuorum)
+                  .build();
+    return makeBlock(2, tx);
+  }
+
+  shared_model::proto::Block makeBlock(
+      shared_model::interface::types::HeightType height,
+      const shared_model::proto::Transaction &tx) {
+    return TestBlockBuilder()
+        .height(height)
+        .transactions(std::vector<shared_model::proto::Transaction>{tx})
+        .build();
+  }
+
+  std::string balance(const std::string &account_id) {
+    std::string balance;
+    *sql_ << "SELECT balance FROM account_assets "
+             "WHERE account_id = :account_id AND asset_id = 'coin#test'",
+        soci::use(account_id, "account_id"), soci::into(balance);
+    return balance;
+  }
+
+  size_t count(const std::string &table) {
+    size_t count = 0;
+    *sql_ << "SELECT count(*) FROM " + table, soci::into(count);
+    return count;
+  }
+
+  std::unique_ptr<TestDbManager> test_db_manager_{
+      TestDbManager::createWithRandomDbName(
+          1, getTestLoggerManager()->getChild("TestDbManager"))
+          .assumeValue()};
+  std::unique_ptr<soci::session> sql_{test_db_manager_->getSession()};
+  Projection projection_{*sql_, getTestLogger("Projection")};
+};
+
+/**
+ * @given migrated projection
+ * @when the schema is migrated again
+ * @then nothing is changed and the applied version is recorded once
+ */
+TEST_F(ProjectionTest, MigratesOnce) {
+  IROHA_ASSERT_RESULT_VALUE(projection_.migrate());
+  EXPECT_EQ(count("projection_migrations"), Projection::schemaVersion());
+  IROHA_ASSERT_RESULT_VALUE(projection_.height());
+  EXPECT_EQ(projection_.height().assumeValue(), 0);
+}
+
+/**
+ * @given empty projection
+ * @when blocks with account, asset and permission commands are applied
+ * @then the tables reflect the state of the ledger
+ */
+TEST_F(ProjectionTest, ProjectsCommands) {
+  IROHA_ASSERT_RESULT_VALUE(projection_.apply(makeGenesisBlock()));
+  IROHA_ASSERT_RESULT_VALUE(projection_.apply(makeTransferBlock()));
+
+  EXPECT_EQ(projection_.height().assumeValue(), 2);
+  EXPECT_EQ(count("accounts"), 2);
+  EXPECT_EQ(count("account_signatories"), 2);
+  EXPECT_EQ(count("transactions"), 2);
+  EXPECT_EQ(balance("alice@test"), "6.50");
+  EXPECT_EQ(balance("bob@test"), "3.50");
+
+  std::string description;
+  *sql_ << "SELECT description FROM transfers "
+           "WHERE src_account_id = 'alice@test' "
+           "AND dest_account_id = 'bob@test'",
+      soci::into(description);
+  EXPECT_EQ(description, "rent");
+
+  size_t default_roles = 0;
+  *sql_ << "SELECT count(*) FROM account_roles WHERE role_id = 'user'",
+      soci::into(default_roles);
+  EXPECT_EQ(default_roles, 2);
+  EXPECT_EQ(count("role_permissions"), 2);
+
+  std::string permittee;
+  *sql_ << "SELECT permittee_account_id FROM grantable_permissions "
+           "WHERE account_id = 'alice@test' "
+           "AND permission = 'can_set_my_quorum'",
+      soci::into(permittee);
+  EXPECT_EQ(permittee, "bob@test");
+}
+
+/**
+ * @given projection with applied blocks
+ * @when an applied block is applied again or a block is skipped
+ * @then the applied block is ignored and the gap is an error
+ * @and the projection is not changed
+ */
+TEST_F(ProjectionTest, AppliesBlocksExactlyOnce) {
+  IROHA_ASSERT_RESULT_VALUE(projection_.apply(makeGenesisBlock()));
+  IROHA_ASSERT_RESULT_VALUE(projection_.apply(makeTransferBlock()));
+  IROHA_ASSERT_RESULT_VALUE(projection_.apply(makeTransferBlock()));
+
+  auto tx = TestTransactionBuilder()
+                .createdTime(4)
+                .creatorAccountId("alice@test")
+                .addAssetQuantity("coin#test", "1.00")
+                .build();
+  IROHA_ASSERT_RESULT_ERROR(projection_.apply(makeBlock(4, tx)));
+
+  EXPECT_EQ(projection_.height().assumeValue(), 2);
+  EXPECT_EQ(balance("alice@test"), "6.50");
+  EXPECT_EQ(count("transfers"), 1);
+}
+
+/**
+ * @given projection with applied blocks
+ * @when a block fails to apply in the middle
+ * @then none of its changes are kept
+ */
+TEST_F(ProjectionTest, RollsBackFailedBlock) {
+  IROHA_ASSERT_RESULT_VALUE(projection_.apply(makeGenesisBlock()));
+
+  // the domain already exists, so the block fails after the mint
+  auto tx = TestTransactionBuilder()
+                .createdTime(2)
+                .creatorAccountId("alice@test")
+                .addAssetQuantity("coin#test", "10.00")
+                .createDomain("test", "user")
+                .build();
+  IROHA_ASSERT_RESULT_ERROR(projection_.apply(makeBlock(2, tx)));
+
+  EXPECT_EQ(projection_.height().assumeValue(), 1);
+  EXPECT_EQ(count("account_assets"), 0);
+  EXPECT_EQ(count("transactions"), 1);
+}
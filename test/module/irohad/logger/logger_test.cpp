@@ -3,9 +3,15 @@
  * SPDX-License-Identifier: Apache-2.0
  */
 
+#include <gmock/gmock.h>
 #include <gtest/gtest.h>
+#include <spdlog/spdlog.h>
+#include "logger/log_fields.hpp"
 #include "logger/logger_manager.hpp"
 
+using ::testing::HasSubstr;
+using ::testing::UnorderedElementsAre;
+
 TEST(LoggerTest, basicStandaloneLoggerTest) {
   logger::LoggerConfig config;
   config.log_level = logger::LogLevel::kInfo;
@@ -17,6 +23,82 @@ TEST(LoggerTest, basicStandaloneLoggerTest) {
   a_logger->error("testing a standalone logger: error");
 }
 
+/**
+ * @given logger tree with a configured child and a standalone child, both
+ * with created loggers
+ * @when log levels are updated from another tree which configures only the
+ * first child
+ * @then the configured child takes its own level and the standalone child
+ * takes the level of the root
+ */
+TEST(LoggerTest, updateLogLevelsTest) {
+  logger::LoggerManagerTree manager(logger::LoggerConfig{
+      logger::LogLevel::kInfo, logger::getDefaultLogPatterns()});
+  manager.registerChild("configured", logger::LogLevel::kWarn, boost::none)
+      ->getLogger();
+  manager.getChild("standalone")->getLogger();
+
+  logger::LoggerManagerTree updated(logger::LoggerConfig{
+      logger::LogLevel::kDebug, logger::getDefaultLogPatterns()});
+  updated.registerChild("configured", logger::LogLevel::kError, boost::none);
+  manager.updateLogLevels(updated);
+
+  EXPECT_EQ(spdlog::get("configured")->level(), spdlog::level::err);
+  EXPECT_EQ(spdlog::get("standalone")->level(), spdlog::level::debug);
+}
+
+/**
+ * @given logger tree with nested children
+ * @when log level of a child is set by its full tag
+ * @then the child and its own children take the level, other nodes keep
+ * theirs
+ */
+TEST(LoggerTest, setLogLevelTest) {
+  logger::LoggerManagerTree manager(logger::LoggerConfig{
+      logger::LogLevel::kInfo, logger::getDefaultLogPatterns()});
+  manager.getChild("parent")->getChild("nested")->getLogger();
+  manager.getChild("sibling");
+
+  EXPECT_TRUE(manager.setLogLevel("parent", logger::LogLevel::kTrace));
+  EXPECT_FALSE(manager.setLogLevel("parent/unknown", logger::LogLevel::kTrace));
+
+  EXPECT_EQ(spdlog::get("parent/nested")->level(), spdlog::level::trace);
+  EXPECT_THAT(manager.getLogLevels(),
+              UnorderedElementsAre(
+                  std::make_pair(std::string{}, logger::LogLevel::kInfo),
+                  std::make_pair(std::string{"parent"},
+                                 logger::LogLevel::kTrace),
+                  std::make_pair(std::string{"parent/nested"},
+                                 logger::LogLevel::kTrace),
+                  std::make_pair(std::string{"sibling"},
+                                 logger::LogLevel::kInfo)));
+}
+
+/**
+ * @given logger with JSON format
+ * @when a message with special characters is logged with a log field
+ * @then the record is a JSON object with the escaped message and the field
+ */
+TEST(LoggerTest, jsonFormatTest) {
+  logger::LoggerManagerTree manager(
+      logger::LoggerConfig{logger::LogLevel::kInfo,
+                           logger::getDefaultLogPatterns(),
+                           logger::LogFormat::kJson});
+  auto log = manager.getChild("json")->getLogger();
+
+  testing::internal::CaptureStdout();
+  {
+    logger::LogFieldGuard height{"height", "5"};
+    log->info("a \"quoted\"\nline");
+  }
+  spdlog::get("json")->flush();
+  auto output = testing::internal::GetCapturedStdout();
+
+  EXPECT_THAT(output, HasSubstr(R"("level":"info","logger":"json")"));
+  EXPECT_THAT(output,
+              HasSubstr(R"("message":"a \"quoted\"\nline","height":"5"})"));
+}
+
 TEST(LoggerTest, boolReprTest) {
   ASSERT_EQ("true", logger::boolRepr(true));
   ASSERT_EQ("false", logger::boolRepr(false));
@@ -0,0 +1,74 @@
+/**
+ * Copyright Soramitsu Co., Ltd. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+#include "main/config_reload.hpp"
+
+#include <gmock/gmock.h>
+#include <gtest/gtest.h>
+#include "logger/logger_manager.hpp"
+
+using namespace iroha::main;
+using ::testing::ElementsAre;
+using ::testing::HasSubstr;
+using ::testing::IsEmpty;
+
+class ConfigReloadTest : public ::testing::Test {
+ protected:
+  static logger::LoggerManagerTreePtr makeLogManager(logger::LogLevel level) {
+    return std::make_shared<logger::LoggerManagerTree>(
+        logger::LoggerConfig{level, logger::getDefaultLogPatterns()});
+  }
+
+  IrohadConfig current_{};
+  IrohadConfig updated_{};
+};
+
+/**
+ * @given configuration with changed log level
+ * @when it is reloaded
+ * @then the log level is applied and nothing is rejected
+ */
+TEST_F(ConfigReloadTest, LogLevelApplied) {
+  auto log_manager = makeLogManager(logger::LogLevel::kInfo);
+  updated_.logger_manager = makeLogManager(logger::LogLevel::kDebug);
+
+  auto report = reloadConfig(current_, updated_, log_manager);
+
+  EXPECT_THAT(report.applied, ElementsAre("log"));
+  EXPECT_THAT(report.rejected, IsEmpty());
+}
+
+/**
+ * @given configuration with changed ports
+ * @when it is reloaded
+ * @then the changes are rejected with a reason
+ */
+TEST_F(ConfigReloadTest, StartupSettingsRejected) {
+  updated_.torii_port = current_.torii_port + 1;
+  updated_.max_proposal_size = current_.max_proposal_size + 1;
+
+  auto report = reloadConfig(current_, updated_, nullptr);
+
+  EXPECT_THAT(report.applied, IsEmpty());
+  EXPECT_THAT(report.rejected,
+              ElementsAre(HasSubstr("torii_port"),
+                          HasSubstr("max_proposal_size")));
+  EXPECT_THAT(report.rejected.front(), HasSubstr("restart"));
+}
+
+/**
+ * @given log level set with the command line and changed in the
+ * configuration
+ * @when the configuration is reloaded
+ * @then the log level change is rejected
+ */
+TEST_F(ConfigReloadTest, LogLevelFromCommandLine) {
+  updated_.logger_manager = makeLogManager(logger::LogLevel::kDebug);
+
+  auto report = reloadConfig(current_, updated_, nullptr);
+
+  EXPECT_THAT(report.applied, IsEmpty());
+  EXPECT_THAT(report.rejected, ElementsAre(HasSubstr("command line")));
+}
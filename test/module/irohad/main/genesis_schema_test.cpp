@@ -0,0 +1,96 @@
+/**
+ * Copyright Soramitsu Co., Ltd. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+#include "main/genesis_schema.hpp"
+
+#include <gtest/gtest.h>
+#include <rapidjson/document.h>
+#include "common/result.hpp"
+#include "framework/result_gtest_checkers.hpp"
+
+using namespace iroha::main;
+
+namespace {
+  std::string makeGenesis(const std::string &command) {
+    return R"({"block_v1": {"payload": {"transactions": [{"payload": {
+             "reducedPayload": {"commands": [)"
+        + command + R"(], "creatorAccountId": "", "createdTime": "0",
+             "quorum": 1}}}], "txNumber": 1, "height": "1",
+             "prevBlockHash": "00"}}})";
+  }
+
+  std::string errorOf(const std::string &json) {
+    auto result = validateGenesisJson(json);
+    EXPECT_TRUE(iroha::expected::hasError(result));
+    return iroha::expected::hasError(result) ? result.assumeError() : "";
+  }
+}  // namespace
+
+/**
+ * @given JSON Schema of the genesis block
+ * @when it is parsed
+ * @then it is a valid JSON document referring to the block definition
+ */
+TEST(GenesisSchemaTest, SchemaIsValidJson) {
+  rapidjson::Document schema;
+  schema.Parse(makeGenesisSchema());
+  ASSERT_FALSE(schema.HasParseError());
+  ASSERT_TRUE(schema.HasMember("definitions"));
+  EXPECT_TRUE(schema["definitions"].HasMember("iroha.protocol.Block"));
+  EXPECT_TRUE(schema["definitions"].HasMember("iroha.protocol.Command"));
+}
+
+/**
+ * @given genesis block with a valid command
+ * @when it is validated
+ * @then validation succeeds
+ */
+TEST(GenesisSchemaTest, ValidGenesis) {
+  IROHA_ASSERT_RESULT_VALUE(validateGenesisJson(makeGenesis(
+      R"({"createDomain": {"domainId": "test", "defaultRole": "user"}})")));
+}
+
+/**
+ * @given genesis block with a misspelled command field
+ * @when it is validated
+ * @then the error contains the path to the field
+ */
+TEST(GenesisSchemaTest, UnknownField) {
+  auto error = errorOf(makeGenesis(
+      R"({"createDomain": {"domain": "test", "defaultRole": "user"}})"));
+  EXPECT_NE(error.find("$.block_v1.payload.transactions[0].payload."
+                       "reducedPayload.commands[0].createDomain.domain: "
+                       "unknown field"),
+            std::string::npos)
+      << error;
+}
+
+/**
+ * @given genesis block with a wrongly typed value and two commands in one
+ * Command object
+ * @when it is validated
+ * @then both problems are reported
+ */
+TEST(GenesisSchemaTest, AllErrorsReported) {
+  auto error = errorOf(makeGenesis(
+      R"({"createDomain": {"domainId": 1, "defaultRole": "user"},
+          "createRole": {"roleName": "user"}})"));
+  EXPECT_NE(error.find("commands[0].createDomain.domainId: expected string"),
+            std::string::npos)
+      << error;
+  EXPECT_NE(error.find("commands[0].createRole: `createDomain' is already set"),
+            std::string::npos)
+      << error;
+}
+
+/**
+ * @given malformed JSON
+ * @when it is validated
+ * @then the error points to the line of the problem
+ */
+TEST(GenesisSchemaTest, MalformedJson) {
+  auto error = errorOf("{\n\"block_v1\": }");
+  EXPECT_NE(error.find("line 2"), std::string::npos) << error;
+}
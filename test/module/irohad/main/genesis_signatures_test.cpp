@@ -0,0 +1,92 @@
+/**
+ * Copyright Soramitsu Co., Ltd. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+#include "main/genesis_signatures.hpp"
+
+#include <gtest/gtest.h>
+#include "backend/protobuf/block.hpp"
+#include "framework/result_gtest_checkers.hpp"
+#include "module/shared_model/builders/protobuf/test_block_builder.hpp"
+#include "module/shared_model/cryptography/crypto_defaults.hpp"
+
+using namespace iroha::main;
+using shared_model::crypto::DefaultCryptoAlgorithmType;
+
+class GenesisSignaturesTest : public ::testing::Test {
+ protected:
+  shared_model::proto::Block makeGenesis() {
+    return TestBlockBuilder().height(1).createdTime(created_time_).build();
+  }
+
+  std::vector<std::string> founderKeys() const {
+    std::vector<std::string> keys;
+    for (auto const &keypair : founders_) {
+      keys.push_back(keypair.publicKey());
+    }
+    return keys;
+  }
+
+  const shared_model::interface::types::TimestampType created_time_ = 42;
+  const std::vector<shared_model::crypto::Keypair> founders_{
+      DefaultCryptoAlgorithmType::generateKeypair(),
+      DefaultCryptoAlgorithmType::generateKeypair(),
+      DefaultCryptoAlgorithmType::generateKeypair()};
+};
+
+/**
+ * @given genesis block signed independently by two of three founders
+ * @when the signatures are merged
+ * @then the block satisfies quorum 2, but not quorum 3
+ */
+TEST_F(GenesisSignaturesTest, MergedSignaturesReachQuorum) {
+  auto first = makeGenesis();
+  auto second = makeGenesis();
+  IROHA_ASSERT_RESULT_VALUE(signGenesisBlock(first, founders_[0]));
+  IROHA_ASSERT_RESULT_VALUE(signGenesisBlock(second, founders_[1]));
+
+  IROHA_ASSERT_RESULT_ERROR(verifyGenesisSignatures(first, founderKeys(), 2));
+  IROHA_ASSERT_RESULT_VALUE(mergeGenesisSignatures(first, second));
+  IROHA_ASSERT_RESULT_VALUE(verifyGenesisSignatures(first, founderKeys(), 2));
+  IROHA_ASSERT_RESULT_ERROR(verifyGenesisSignatures(first, founderKeys(), 3));
+}
+
+/**
+ * @given genesis block signed by a key which is not a founder
+ * @when the signatures are verified
+ * @then verification fails
+ */
+TEST_F(GenesisSignaturesTest, UnknownSigner) {
+  auto block = makeGenesis();
+  IROHA_ASSERT_RESULT_VALUE(signGenesisBlock(block, founders_[0]));
+  IROHA_ASSERT_RESULT_VALUE(signGenesisBlock(
+      block, DefaultCryptoAlgorithmType::generateKeypair()));
+
+  IROHA_ASSERT_RESULT_ERROR(verifyGenesisSignatures(block, founderKeys(), 1));
+}
+
+/**
+ * @given two genesis blocks with different payloads
+ * @when their signatures are merged
+ * @then merge fails
+ */
+TEST_F(GenesisSignaturesTest, MergeDifferentBlocks) {
+  auto first = makeGenesis();
+  auto second =
+      TestBlockBuilder().height(1).createdTime(created_time_ + 1).build();
+  IROHA_ASSERT_RESULT_VALUE(signGenesisBlock(second, founders_[1]));
+
+  IROHA_ASSERT_RESULT_ERROR(mergeGenesisSignatures(first, second));
+}
+
+/**
+ * @given genesis block signed by a founder
+ * @when the same founder signs it again
+ * @then signing fails
+ */
+TEST_F(GenesisSignaturesTest, DuplicateSignature) {
+  auto block = makeGenesis();
+  IROHA_ASSERT_RESULT_VALUE(signGenesisBlock(block, founders_[0]));
+  IROHA_ASSERT_RESULT_ERROR(signGenesisBlock(block, founders_[0]));
+}
@@ -0,0 +1,155 @@
+/**
+ * Copyright Soramitsu Co., Ltd. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+#include "main/iroha_conf_loader.hpp"
+
+#include <cstdlib>
+#include <fstream>
+
+#include <gmock/gmock.h>
+#include <gtest/gtest.h>
+#include <boost/filesystem.hpp>
+#include "common/result.hpp"
+
+using ::testing::HasSubstr;
+
+class IrohaConfLoaderTest : public ::testing::Test {
+ protected:
+  void TearDown() override {
+    unsetenv("IROHA_TORII_PORT");
+    boost::filesystem::remove(path_);
+  }
+
+  iroha::expected::Result<IrohadConfig, std::string> load(
+      std::string const &extra_keys = "") {
+    std::ofstream(path_) << R"({
+      "torii_port": 50051,
+      "internal_port": 10001,
+      "database": {
+        "type": "postgres",
+        "host": "localhost",
+        "port": 5432,
+        "user": "postgres",
+        "password": "mysecretpassword",
+        "working database": "iroha_data",
+        "maintenance database": "postgres"
+      },)" << extra_keys
+                         << R"(
+      "max_proposal_size": 10,
+      "vote_delay": 5000,
+      "mst_enable": false
+    })";
+    return parse_iroha_config(path_, nullptr, std::nullopt);
+  }
+
+  const std::string path_ = (boost::filesystem::temp_directory_path()
+                             / boost::filesystem::unique_path())
+                                .string();
+};
+
+/**
+ * @given config file and an environment variable for one of its values
+ * @when the config is loaded
+ * @then the environment variable takes precedence and origins of the values
+ * are reported
+ */
+TEST_F(IrohaConfLoaderTest, EnvOverridesFile) {
+  setenv("IROHA_TORII_PORT", "50052", 1);
+
+  auto result = load();
+
+  ASSERT_TRUE(iroha::expected::hasValue(result)) << result.assumeError();
+  auto const &config = result.assumeValue();
+  EXPECT_EQ(config.torii_port, 50052);
+  EXPECT_EQ(config.origins.at("/torii_port").value, "50052");
+  EXPECT_EQ(config.origins.at("/torii_port").source, "env IROHA_TORII_PORT");
+  EXPECT_EQ(config.origins.at("/internal_port").value, "10001");
+  EXPECT_EQ(config.origins.at("/internal_port").source, "file");
+}
+
+/**
+ * @given config file with a password
+ * @when the config is loaded
+ * @then the password is hidden in the reported origins
+ */
+TEST_F(IrohaConfLoaderTest, SecretsHidden) {
+  auto result = load();
+
+  ASSERT_TRUE(iroha::expected::hasValue(result)) << result.assumeError();
+  auto const &config = result.assumeValue();
+  ASSERT_TRUE(config.database_config);
+  EXPECT_EQ(config.database_config->password, "mysecretpassword");
+  EXPECT_EQ(config.origins.at("/database/password").value, "<hidden>");
+}
+
+/**
+ * @given config file with a misspelled key
+ * @when the config is loaded
+ * @then loading fails and the key is reported
+ */
+TEST_F(IrohaConfLoaderTest, UnknownKeyFails) {
+  auto result = load(R"("vote_dealy": 1000, "database_x": {"a": 1},)");
+
+  ASSERT_TRUE(iroha::expected::hasError(result));
+  EXPECT_THAT(result.assumeError(), HasSubstr("/vote_dealy"));
+  EXPECT_THAT(result.assumeError(), HasSubstr("/database_x"));
+}
+
+/**
+ * @given config file with block store pruning keeping 0 blocks
+ * @when the config is loaded
+ * @then loading fails and the option is reported
+ */
+TEST_F(IrohaConfLoaderTest, ZeroKeepBlocksFails) {
+  auto result = load(R"("block_store_path": "/tmp/blocks",
+      "block_store_pruning": {"archive": false, "keep_blocks": 0},)");
+
+  ASSERT_TRUE(iroha::expected::hasError(result));
+  EXPECT_THAT(result.assumeError(),
+              HasSubstr("/block_store_pruning/keep_blocks"));
+}
+
+/**
+ * @given config file with block store pruning without block_store_path
+ * @when the config is loaded
+ * @then loading fails
+ */
+TEST_F(IrohaConfLoaderTest, PruningWithoutBlockStorePathFails) {
+  auto result = load(
+      R"("block_store_pruning": {"archive": false, "keep_blocks": 10},)");
+
+  ASSERT_TRUE(iroha::expected::hasError(result));
+  EXPECT_THAT(result.assumeError(), HasSubstr("block_store_path"));
+}
+
+/**
+ * @given config file with block store pruning keeping 10 blocks
+ * @when the config is loaded
+ * @then the number of kept blocks is loaded
+ */
+TEST_F(IrohaConfLoaderTest, KeepBlocks) {
+  auto result = load(R"("block_store_path": "/tmp/blocks",
+      "block_store_pruning": {"archive": false, "keep_blocks": 10},)");
+
+  ASSERT_TRUE(iroha::expected::hasValue(result)) << result.assumeError();
+  EXPECT_EQ(result.assumeValue().getBlockStoreKeepBlocks(), 10);
+}
+
+/**
+ * @given config file with the external address of the peer
+ * @when the config is loaded
+ * @then the external address is loaded
+ * @and it is unset when absent from the file
+ */
+TEST_F(IrohaConfLoaderTest, ExternalAddress) {
+  auto result = load(R"("external_address": "203.0.113.7:10001",)");
+
+  ASSERT_TRUE(iroha::expected::hasValue(result)) << result.assumeError();
+  EXPECT_EQ(result.assumeValue().external_address, "203.0.113.7:10001");
+
+  result = load();
+  ASSERT_TRUE(iroha::expected::hasValue(result)) << result.assumeError();
+  EXPECT_FALSE(result.assumeValue().external_address);
+}
@@ -0,0 +1,56 @@
+/**
+ * Copyright Soramitsu Co., Ltd. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+#include "main/reachability_check.hpp"
+
+#include <gtest/gtest.h>
+#include <boost/asio/ip/tcp.hpp>
+#include "framework/result_gtest_checkers.hpp"
+
+using namespace std::chrono_literals;
+
+class ReachabilityCheckTest : public ::testing::Test {
+ protected:
+  /// Address of a port which is listened on while the acceptor is open
+  std::string address() const {
+    return "127.0.0.1:" + std::to_string(acceptor_.local_endpoint().port());
+  }
+
+  boost::asio::io_context io_context_;
+  boost::asio::ip::tcp::acceptor acceptor_{
+      io_context_, {boost::asio::ip::make_address("127.0.0.1"), 0}};
+};
+
+/**
+ * @given a listened port
+ * @when its address is checked
+ * @then it is reachable
+ */
+TEST_F(ReachabilityCheckTest, ListenedAddressReachable) {
+  IROHA_ASSERT_RESULT_VALUE(iroha::main::checkReachable(address(), 1s));
+}
+
+/**
+ * @given a port nobody listens on
+ * @when its address is checked
+ * @then it is unreachable and the address is reported
+ */
+TEST_F(ReachabilityCheckTest, ClosedAddressUnreachable) {
+  auto const closed = address();
+  acceptor_.close();
+
+  auto result = iroha::main::checkReachable(closed, 1s);
+  IROHA_ASSERT_RESULT_ERROR(result);
+  EXPECT_NE(result.assumeError().find(closed), std::string::npos);
+}
+
+/**
+ * @given an address without a port
+ * @when it is checked
+ * @then it is reported as malformed
+ */
+TEST_F(ReachabilityCheckTest, AddressWithoutPort) {
+  IROHA_ASSERT_RESULT_ERROR(iroha::main::checkReachable("127.0.0.1", 1s));
+}
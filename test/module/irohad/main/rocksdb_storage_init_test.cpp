@@ -0,0 +1,146 @@
+/**
+ * Copyright Soramitsu Co., Ltd. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+#include "main/impl/storage_init.hpp"
+
+#include <gmock/gmock.h>
+#include <gtest/gtest.h>
+#include <boost/filesystem.hpp>
+#include "ametsuchi/impl/rocksdb_common.hpp"
+#include "ametsuchi/impl/rocksdb_options.hpp"
+#include "ametsuchi/storage.hpp"
+#include "backend/protobuf/proto_query_response_factory.hpp"
+#include "common/result.hpp"
+#include "framework/result_gtest_checkers.hpp"
+#include "framework/test_logger.hpp"
+#include "main/impl/rocksdb_connection_init.hpp"
+#include "module/irohad/pending_txs_storage/pending_txs_storage_mock.hpp"
+
+namespace fs = boost::filesystem;
+using namespace iroha::ametsuchi;
+
+class RocksDbStorageInitTest : public ::testing::Test {
+ protected:
+  void SetUp() override {
+    auto db_port =
+        RdbConnectionInit::init(iroha::StartupWsvDataPolicy::kDrop,
+                                RocksDbOptions{db_path_},
+                                log_manager_);
+    IROHA_ASSERT_RESULT_VALUE(db_port);
+    db_context_ =
+        std::make_shared<RocksDBContext>(std::move(db_port).assumeValue());
+  }
+
+  void TearDown() override {
+    db_context_.reset();
+    fs::remove_all(db_path_);
+    fs::remove_all(block_store_path_);
+  }
+
+  auto initStorage(boost::optional<std::string> block_storage_dir) {
+    return iroha::initStorage(
+        db_context_,
+        std::make_shared<iroha::MockPendingTransactionStorage>(),
+        std::make_shared<shared_model::proto::ProtoQueryResponseFactory>(),
+        std::move(block_storage_dir),
+        std::nullopt,
+        std::nullopt,
+        std::nullopt,
+        [](auto) {},
+        log_manager_);
+  }
+
+  /// Store the blocks count the way the RocksDB block storage does
+  void putRocksDbBlocksCount(uint64_t count) {
+    RocksDbCommon common(db_context_);
+    common.encode(count);
+    IROHA_ASSERT_RESULT_VALUE(
+        (forBlocksTotalCount<kDbOperation::kPut, kDbEntry::kMustExist>(
+            common)));
+    common.commit();
+  }
+
+  /// Store the top block of the WSV the way the WSV command does
+  void putWsvTopBlockHeight(uint64_t height) {
+    RocksDbCommon common(db_context_);
+    common.valueBuffer() = std::to_string(height) + "#hash";
+    IROHA_ASSERT_RESULT_VALUE(
+        (forTopBlockInfo<kDbOperation::kPut, kDbEntry::kMustExist>(common)));
+    common.commit();
+  }
+
+  const std::string db_path_ =
+      (fs::temp_directory_path() / fs::unique_path()).string();
+  const std::string block_store_path_ =
+      (fs::temp_directory_path() / fs::unique_path()).string();
+  logger::LoggerManagerTreePtr log_manager_ = getTestLoggerManager();
+  std::shared_ptr<RocksDBContext> db_context_;
+};
+
+/**
+ * @given RocksDB database without blocks
+ * @when storage is initialized without block_store_path
+ * @then the blocks are kept in RocksDB @and no flat file store is created
+ */
+TEST_F(RocksDbStorageInitTest, BlocksInRocksDb) {
+  IROHA_ASSERT_RESULT_VALUE(initStorage(boost::none));
+  EXPECT_FALSE(fs::exists(block_store_path_));
+}
+
+/**
+ * @given RocksDB database without blocks
+ * @when storage is initialized with block_store_path
+ * @then the flat file block store is created in block_store_path
+ */
+TEST_F(RocksDbStorageInitTest, BlocksInFlatFile) {
+  IROHA_ASSERT_RESULT_VALUE(initStorage(block_store_path_));
+  EXPECT_TRUE(fs::exists(block_store_path_));
+}
+
+/**
+ * @given RocksDB database which keeps blocks
+ * @when storage is initialized with block_store_path
+ * @then initialization fails telling that the RocksDB blocks would be ignored
+ * @and it succeeds without block_store_path
+ */
+TEST_F(RocksDbStorageInitTest, BlocksInRocksDbWithBlockStorePath) {
+  putRocksDbBlocksCount(3);
+
+  auto result = initStorage(block_store_path_);
+  IROHA_ASSERT_RESULT_ERROR(result);
+  EXPECT_THAT(result.assumeError(),
+              ::testing::HasSubstr("the RocksDB database keeps 3 blocks"));
+  EXPECT_FALSE(fs::exists(block_store_path_));
+
+  IROHA_ASSERT_RESULT_VALUE(initStorage(boost::none));
+}
+
+/**
+ * @given RocksDB database with the WSV at height 3 which keeps no blocks,
+ * because they were kept in a flat file block store
+ * @when storage is initialized without block_store_path
+ * @then initialization fails telling that the blocks are missing
+ */
+TEST_F(RocksDbStorageInitTest, BlocksInFlatFileWithoutBlockStorePath) {
+  putWsvTopBlockHeight(3);
+
+  auto result = initStorage(boost::none);
+  IROHA_ASSERT_RESULT_ERROR(result);
+  EXPECT_THAT(result.assumeError(),
+              ::testing::HasSubstr("the WSV is at height 3, but the RocksDB "
+                                   "database keeps no blocks"));
+}
+
+/**
+ * @given RocksDB database with the WSV at height 3 which keeps the blocks
+ * @when storage is initialized without block_store_path
+ * @then initialization succeeds
+ */
+TEST_F(RocksDbStorageInitTest, WsvWithBlocksInRocksDb) {
+  putWsvTopBlockHeight(3);
+  putRocksDbBlocksCount(3);
+
+  IROHA_ASSERT_RESULT_VALUE(initStorage(boost::none));
+}
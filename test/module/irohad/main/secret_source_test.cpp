@@ -0,0 +1,108 @@
+/**
+ * Copyright Soramitsu Co., Ltd. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+#include "main/secret_source.hpp"
+
+#include <cstdlib>
+#include <fstream>
+
+#include <gmock/gmock.h>
+#include <gtest/gtest.h>
+#include <boost/filesystem.hpp>
+#include "common/result.hpp"
+
+using iroha::main::resolveSecret;
+using ::testing::HasSubstr;
+
+static const std::string kSecret = "cc5013e43918bd0e5c4d800416c88bed";
+
+/**
+ * @given secret without a source prefix
+ * @when it is resolved
+ * @then it is returned as is
+ */
+TEST(SecretSourceTest, Literal) {
+  auto secret = resolveSecret(kSecret);
+  ASSERT_TRUE(iroha::expected::hasValue(secret)) << secret.assumeError();
+  EXPECT_EQ(secret.assumeValue(), kSecret);
+}
+
+/**
+ * @given secret stored in a file with a trailing newline
+ * @when it is resolved by the file reference
+ * @then the file contents without whitespace are returned
+ */
+TEST(SecretSourceTest, File) {
+  auto path = (boost::filesystem::temp_directory_path()
+               / boost::filesystem::unique_path())
+                  .string();
+  std::ofstream(path) << kSecret << std::endl;
+
+  auto secret = resolveSecret("file:" + path);
+  boost::filesystem::remove(path);
+
+  ASSERT_TRUE(iroha::expected::hasValue(secret)) << secret.assumeError();
+  EXPECT_EQ(secret.assumeValue(), kSecret);
+}
+
+/**
+ * @given secret stored in an environment variable
+ * @when it is resolved by the env reference
+ * @then the value of the variable is returned
+ */
+TEST(SecretSourceTest, Env) {
+  setenv("IROHA_TEST_SECRET", kSecret.c_str(), 1);
+
+  auto secret = resolveSecret("env:IROHA_TEST_SECRET");
+  unsetenv("IROHA_TEST_SECRET");
+
+  ASSERT_TRUE(iroha::expected::hasValue(secret)) << secret.assumeError();
+  EXPECT_EQ(secret.assumeValue(), kSecret);
+}
+
+/**
+ * @given reference to an unset environment variable
+ * @when it is resolved
+ * @then an error naming the variable is returned
+ */
+TEST(SecretSourceTest, EnvMissing) {
+  auto secret = resolveSecret("env:IROHA_TEST_NO_SUCH_SECRET");
+
+  ASSERT_TRUE(iroha::expected::hasError(secret));
+  EXPECT_THAT(secret.assumeError(), HasSubstr("IROHA_TEST_NO_SUCH_SECRET"));
+}
+
+/**
+ * @given command printing the secret
+ * @when it is resolved by the exec reference
+ * @then the output of the command is returned
+ */
+TEST(SecretSourceTest, Exec) {
+  auto secret = resolveSecret("exec:echo " + kSecret);
+
+  ASSERT_TRUE(iroha::expected::hasValue(secret)) << secret.assumeError();
+  EXPECT_EQ(secret.assumeValue(), kSecret);
+}
+
+/**
+ * @given failing command
+ * @when it is resolved by the exec reference
+ * @then an error is returned
+ */
+TEST(SecretSourceTest, ExecFails) {
+  EXPECT_TRUE(iroha::expected::hasError(resolveSecret("exec:exit 3")));
+}
+
+/**
+ * @given Vault reference without the field of the secret
+ * @when it is resolved
+ * @then an error describing the reference format is returned
+ */
+TEST(SecretSourceTest, VaultMalformed) {
+  auto secret = resolveSecret("vault:http://127.0.0.1:8200/v1/secret/peer");
+
+  ASSERT_TRUE(iroha::expected::hasError(secret));
+  EXPECT_THAT(secret.assumeError(), HasSubstr("#<field>"));
+}
@@ -0,0 +1,156 @@
+/**
+ * Copyright Soramitsu Co., Ltd. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+#include "maintenance/audit_log.hpp"
+
+#include <fstream>
+#include <iterator>
+
+#include <gmock/gmock.h>
+#include <gtest/gtest.h>
+#include <rapidjson/document.h>
+#include <boost/filesystem.hpp>
+#include "framework/result_gtest_checkers.hpp"
+#include "framework/test_logger.hpp"
+#include "interfaces/common_objects/string_view_types.hpp"
+#include "module/shared_model/builders/protobuf/test_block_builder.hpp"
+#include "module/shared_model/builders/protobuf/test_transaction_builder.hpp"
+
+using iroha::AuditLog;
+using ::testing::HasSubstr;
+
+static const std::string kPeerKeyHex(64, '0');
+static const shared_model::interface::types::PublicKeyHexStringView kPeerKey{
+    kPeerKeyHex};
+static const std::string kNewKeyHex(64, '1');
+static const shared_model::interface::types::PublicKeyHexStringView kNewKey{
+    kNewKeyHex};
+
+class AuditLogTest : public ::testing::Test {
+ protected:
+  void TearDown() override {
+    boost::filesystem::remove(path_);
+  }
+
+  std::unique_ptr<AuditLog> open() {
+    return AuditLog::create(path_, getTestLogger("AuditLog")).assumeValue();
+  }
+
+  shared_model::proto::Block makeBlock(
+      shared_model::interface::types::HeightType height) {
+    auto tx = TestTransactionBuilder()
+                  .createdTime(height)
+                  .creatorAccountId("admin@test")
+                  .appendRole("user@test", "auditor")
+                  .setAccountQuorum("user@test", 2)
+                  .addPeer("127.0.0.1:10001", kPeerKey)
+                  .build();
+    return TestBlockBuilder()
+        .height(height)
+        .transactions(std::vector<shared_model::proto::Transaction>{tx})
+        .build();
+  }
+
+  /// Names of the commands of the transaction recorded in the audit log
+  std::vector<std::string> recordedCommands(
+      const shared_model::proto::Transaction &tx) {
+    auto audit_log = open();
+    audit_log->append(
+        TestBlockBuilder()
+            .height(1)
+            .transactions(std::vector<shared_model::proto::Transaction>{tx})
+            .build());
+    rapidjson::Document document;
+    document.Parse(audit_log->entriesJson(1).c_str());
+    std::vector<std::string> commands;
+    for (auto const &entry : document["entries"].GetArray()) {
+      commands.emplace_back(entry["command"].GetString());
+    }
+    return commands;
+  }
+
+  std::string readFile() {
+    std::ifstream file(path_);
+    return std::string{std::istreambuf_iterator<char>(file), {}};
+  }
+
+  const std::string path_ = (boost::filesystem::temp_directory_path()
+                             / boost::filesystem::unique_path())
+                                .string();
+};
+
+/**
+ * @given empty audit log
+ * @when blocks with privileged and regular commands are appended and the log
+ * is reopened
+ * @then only privileged commands are recorded, the chain is verified and
+ * already recorded blocks are ignored
+ */
+TEST_F(AuditLogTest, RecordsPrivilegedCommands) {
+  {
+    auto audit_log = open();
+    audit_log->append(makeBlock(1));
+    audit_log->append(makeBlock(2));
+  }
+
+  auto audit_log = open();
+  EXPECT_EQ(audit_log->lastHeight(), 2);
+  audit_log->append(makeBlock(2));
+
+  rapidjson::Document document;
+  document.Parse(audit_log->entriesJson(2).c_str());
+  ASSERT_TRUE(document.IsObject());
+  auto const &entries = document["entries"];
+  ASSERT_EQ(entries.Size(), 2);
+  EXPECT_STREQ(entries[0]["command"].GetString(), "append_role");
+  EXPECT_STREQ(entries[0]["creator"].GetString(), "admin@test");
+  EXPECT_STREQ(entries[1]["command"].GetString(), "add_peer");
+  EXPECT_STREQ(entries[1]["prev_hash"].GetString(),
+               entries[0]["hash"].GetString());
+}
+
+/**
+ * @given audit log with recorded entries
+ * @when the file is modified and the log is opened
+ * @then opening fails pointing to the modified line
+ */
+TEST_F(AuditLogTest, TamperingDetected) {
+  open()->append(makeBlock(1));
+
+  auto contents = readFile();
+  auto pos = contents.find("user@test");
+  ASSERT_NE(pos, std::string::npos);
+  contents.replace(pos, 4, "evil");
+  std::ofstream(path_) << contents;
+
+  auto audit_log = AuditLog::create(path_, getTestLogger("AuditLog"));
+  IROHA_ASSERT_RESULT_ERROR(audit_log);
+  EXPECT_THAT(audit_log.assumeError(), HasSubstr("line 1"));
+}
+
+/**
+ * @given empty audit log
+ * @when a block with commands changing the lifecycle of domains and
+ * accounts is appended
+ * @then all of them are recorded
+ */
+TEST_F(AuditLogTest, RecordsLifecycleCommands) {
+  auto tx = TestTransactionBuilder()
+                .createdTime(1)
+                .creatorAccountId("admin@test")
+                .archiveDomain("archived")
+                .deactivateAccount("user@test")
+                .recoverAccount("user@test", kPeerKey)
+                .rotateKey("user@test", kPeerKey, kNewKey, 10)
+                .authorizeSessionKey("user@test", kNewKey, {}, "", 10)
+                .build();
+  EXPECT_EQ(recordedCommands(tx),
+            (std::vector<std::string>{
+                "archive_domain",
+                "deactivate_account",
+                "recover_account",
+                "rotate_key",
+                "authorize_session_key"}));
+}
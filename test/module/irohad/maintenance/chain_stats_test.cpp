@@ -0,0 +1,100 @@
+/**
+ * Copyright Soramitsu Co., Ltd. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+#include "maintenance/chain_stats.hpp"
+
+#include <gtest/gtest.h>
+#include <rapidjson/document.h>
+#include "module/shared_model/builders/protobuf/test_block_builder.hpp"
+#include "module/shared_model/builders/protobuf/test_transaction_builder.hpp"
+
+using iroha::ChainStats;
+
+namespace {
+  constexpr uint64_t kHourMs = 60 * 60 * 1000;
+  constexpr uint64_t kStartTime = 1000 * kHourMs;
+
+  shared_model::proto::Block makeBlock(
+      shared_model::interface::types::HeightType height,
+      uint64_t time,
+      std::vector<shared_model::proto::Transaction> txs) {
+    return TestBlockBuilder()
+        .height(height)
+        .createdTime(time)
+        .transactions(txs)
+        .build();
+  }
+
+  rapidjson::Document parse(const std::string &json) {
+    rapidjson::Document document;
+    document.Parse(json.c_str());
+    return document;
+  }
+}  // namespace
+
+/**
+ * @given chain stats
+ * @when blocks creating, issuing and transferring an asset are appended
+ * @then totals, command counts, holders and block time are reported
+ */
+TEST(ChainStatsTest, Summary) {
+  ChainStats stats;
+  stats.append(makeBlock(1,
+                         kStartTime,
+                         {TestTransactionBuilder()
+                              .createdTime(kStartTime)
+                              .creatorAccountId("admin@test")
+                              .createAsset("coin", "test", 2)
+                              .addAssetQuantity("coin#test", "10.00")
+                              .build()}));
+  stats.append(makeBlock(2,
+                         kStartTime + 3000,
+                         {TestTransactionBuilder()
+                              .createdTime(kStartTime + 3000)
+                              .creatorAccountId("admin@test")
+                              .transferAsset("admin@test",
+                                             "user@test",
+                                             "coin#test",
+                                             "payment",
+                                             "10.00")
+                              .build()}));
+  // already accounted block is ignored
+  stats.append(makeBlock(2, kStartTime + 3000, {}));
+
+  auto summary = parse(stats.summaryJson(10));
+  ASSERT_TRUE(summary.IsObject());
+  EXPECT_EQ(summary["height"].GetUint64(), 2);
+  EXPECT_EQ(summary["transactions"].GetUint64(), 2);
+  EXPECT_EQ(summary["average_block_time_ms"].GetUint64(), 3000);
+  EXPECT_EQ(summary["accounts"]["total"].GetUint64(), 1);
+  EXPECT_EQ(summary["commands"]["transfer_asset"].GetUint64(), 1);
+  EXPECT_EQ(summary["commands"]["create_asset"].GetUint64(), 1);
+  auto const &top_assets = summary["top_assets"];
+  ASSERT_EQ(top_assets.Size(), 1);
+  EXPECT_STREQ(top_assets[0]["asset_id"].GetString(), "coin#test");
+  EXPECT_EQ(top_assets[0]["holders"].GetUint64(), 1);
+}
+
+/**
+ * @given chain stats with blocks in two different hours
+ * @when blocks per hour are requested
+ * @then each hour up to the last block is reported, including empty ones
+ */
+TEST(ChainStatsTest, Periods) {
+  ChainStats stats;
+  stats.append(makeBlock(1, kStartTime, {}));
+  stats.append(makeBlock(2, kStartTime + 1, {}));
+  stats.append(makeBlock(3, kStartTime + 2 * kHourMs, {}));
+
+  auto periods = parse(stats.periodsJson(std::chrono::hours(1), 24));
+  ASSERT_TRUE(periods.IsObject());
+  auto const &items = periods["periods"];
+  ASSERT_EQ(items.Size(), 24);
+  EXPECT_EQ(items[23]["start"].GetUint64(), kStartTime + 2 * kHourMs);
+  EXPECT_EQ(items[23]["blocks"].GetUint64(), 1);
+  EXPECT_EQ(items[22]["blocks"].GetUint64(), 0);
+  EXPECT_EQ(items[21]["start"].GetUint64(), kStartTime);
+  EXPECT_EQ(items[21]["blocks"].GetUint64(), 2);
+}
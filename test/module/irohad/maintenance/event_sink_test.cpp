@@ -0,0 +1,348 @@
+/**
+ * Copyright Soramitsu Co., Ltd. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+#include "maintenance/event_sink.hpp"
+
+#include <map>
+#include <mutex>
+#include <thread>
+
+#include <gtest/gtest.h>
+#include <rapidjson/document.h>
+#include <boost/filesystem.hpp>
+#include "common/result.hpp"
+#include "framework/test_logger.hpp"
+#include "module/shared_model/builders/protobuf/test_block_builder.hpp"
+#include "module/shared_model/builders/protobuf/test_transaction_builder.hpp"
+
+using iroha::EventSink;
+
+static const std::string kOldKeyHex(64, 'a');
+static const std::string kNewKeyHex(64, 'b');
+
+class EventSinkTest : public ::testing::Test {
+ protected:
+  void TearDown() override {
+    boost::filesystem::remove(path_);
+  }
+
+  /// Transport collecting the events, failing while fail_ is set
+  EventSink::Transport makeTransport() {
+    return [this](const std::string &event)
+               -> iroha::expected::Result<void, std::string> {
+      std::lock_guard<std::mutex> lock(mutex_);
+      if (fail_) {
+        return iroha::expected::makeError("receiver is unavailable");
+      }
+      events_.push_back(event);
+      return {};
+    };
+  }
+
+  /// Loader of the blocks stored by store()
+  EventSink::BlockLoader makeBlockLoader() {
+    return [this](shared_model::interface::types::HeightType height)
+               -> std::shared_ptr<const shared_model::interface::Block> {
+      std::lock_guard<std::mutex> lock(mutex_);
+      auto it = blocks_.find(height);
+      return it == blocks_.end() ? nullptr : it->second;
+    };
+  }
+
+  std::unique_ptr<EventSink> open(EventSink::Filter filter = {},
+                                  size_t max_queue = 10) {
+    return EventSink::create(makeTransport(),
+                             makeBlockLoader(),
+                             path_,
+                             std::move(filter),
+                             max_queue,
+                             getTestLogger("EventSink"))
+        .assumeValue();
+  }
+
+  /// Put the block to the block store
+  std::shared_ptr<const shared_model::interface::Block> store(
+      shared_model::proto::Block block) {
+    auto stored =
+        std::make_shared<shared_model::proto::Block>(std::move(block));
+    std::lock_guard<std::mutex> lock(mutex_);
+    blocks_[stored->height()] = stored;
+    return stored;
+  }
+
+  /// Store the block and append it to the sink, as the peer commits it
+  void commit(EventSink &sink, shared_model::proto::Block block) {
+    sink.append(*store(std::move(block)));
+  }
+
+  void setFail(bool fail) {
+    std::lock_guard<std::mutex> lock(mutex_);
+    fail_ = fail;
+  }
+
+  std::vector<std::string> events() {
+    std::lock_guard<std::mutex> lock(mutex_);
+    return events_;
+  }
+
+  /// Wait until the sink delivers the block of the given height
+  static void waitForCursor(const EventSink &sink,
+                            shared_model::interface::types::HeightType height) {
+    for (int i = 0; i < 100 and sink.cursor() < height; ++i) {
+      std::this_thread::sleep_for(std::chrono::milliseconds(50));
+    }
+    ASSERT_EQ(sink.cursor(), height);
+  }
+
+  static uint64_t eventHeight(const std::string &event) {
+    rapidjson::Document document;
+    document.Parse(event.c_str());
+    return document["height"].GetUint64();
+  }
+
+  static std::vector<std::string> rotationPhases(const std::string &event) {
+    rapidjson::Document document;
+    document.Parse(event.c_str());
+    std::vector<std::string> phases;
+    for (auto const &rotation : document["key_rotations"].GetArray()) {
+      phases.emplace_back(rotation["phase"].GetString());
+    }
+    return phases;
+  }
+
+  shared_model::proto::Block makeBlock(
+      shared_model::interface::types::HeightType height) {
+    auto tx = TestTransactionBuilder()
+                  .createdTime(height)
+                  .creatorAccountId("admin@test")
+                  .appendRole("user@test", "auditor")
+                  .build();
+    return makeBlock(height, tx);
+  }
+
+  shared_model::proto::Block makeBlock(
+      shared_model::interface::types::HeightType height,
+      const shared_model::proto::Transaction &tx) {
+    return TestBlockBuilder()
+        .height(height)
+        .transactions(std::vector<shared_model::proto::Transaction>{tx})
+        .build();
+  }
+
+  /// Block rotating the key of user@test
+  shared_model::proto::Block makeRotationBlock(
+      shared_model::interface::types::HeightType height,
+      shared_model::interface::types::HeightType grace_period) {
+    using shared_model::interface::types::PublicKeyHexStringView;
+    auto tx = TestTransactionBuilder()
+                  .createdTime(height)
+                  .creatorAccountId("user@test")
+                  .rotateKey("user@test",
+                             PublicKeyHexStringView{kOldKeyHex},
+                             PublicKeyHexStringView{kNewKeyHex},
+                             grace_period)
+                  .build();
+    return makeBlock(height, tx);
+  }
+
+  const std::string path_ = (boost::filesystem::temp_directory_path()
+                             / boost::filesystem::unique_path())
+                                .string();
+  std::mutex mutex_;
+  bool fail_ = false;
+  std::vector<std::string> events_;
+  std::map<shared_model::interface::types::HeightType,
+           std::shared_ptr<const shared_model::interface::Block>>
+      blocks_;
+};
+
+/**
+ * @given event sink with an unavailable receiver
+ * @when blocks are appended and the sink is restarted with the receiver
+ * available
+ * @then no event is lost, the blocks after the cursor are delivered in order
+ * @and already delivered blocks are not delivered again
+ */
+TEST_F(EventSinkTest, DeliversAfterRestart) {
+  setFail(true);
+  {
+    auto sink = open();
+    commit(*sink, makeBlock(1));
+    commit(*sink, makeBlock(2));
+    EXPECT_EQ(sink->cursor(), 0);
+  }
+
+  setFail(false);
+  {
+    auto sink = open();
+    ASSERT_EQ(sink->cursor(), 0);
+    sink->append(makeBlock(1));
+    sink->append(makeBlock(2));
+    ASSERT_NO_FATAL_FAILURE(waitForCursor(*sink, 2));
+  }
+
+  auto sink = open();
+  EXPECT_EQ(sink->cursor(), 2);
+  commit(*sink, makeBlock(2));
+  commit(*sink, makeBlock(3));
+  ASSERT_NO_FATAL_FAILURE(waitForCursor(*sink, 3));
+
+  auto const delivered = events();
+  ASSERT_EQ(delivered.size(), 3);
+  for (size_t i = 0; i < delivered.size(); ++i) {
+    EXPECT_EQ(eventHeight(delivered[i]), i + 1);
+  }
+}
+
+/**
+ * @given event sink with an unavailable receiver and a queue of two events
+ * @when more blocks are committed than fit into the queue
+ * @then appending does not wait for the receiver
+ * @and once the receiver is available, the blocks which were not queued are
+ * loaded from the block store and every block is delivered in order
+ */
+TEST_F(EventSinkTest, DoesNotWaitForReceiver) {
+  constexpr shared_model::interface::types::HeightType kBlocks = 10;
+  setFail(true);
+  auto sink = open({}, 2);
+  for (shared_model::interface::types::HeightType height = 1;
+       height <= kBlocks;
+       ++height) {
+    commit(*sink, makeBlock(height));
+  }
+  EXPECT_EQ(sink->cursor(), 0);
+
+  setFail(false);
+  ASSERT_NO_FATAL_FAILURE(waitForCursor(*sink, kBlocks));
+  auto const delivered = events();
+  ASSERT_EQ(delivered.size(), kBlocks);
+  for (size_t i = 0; i < delivered.size(); ++i) {
+    EXPECT_EQ(eventHeight(delivered[i]), i + 1);
+  }
+}
+
+/**
+ * @given blocks stored before the event sink is started
+ * @when the sink is started without appending them
+ * @then the stored blocks after the cursor are loaded and delivered
+ */
+TEST_F(EventSinkTest, LoadsStoredBlocksOnStartup) {
+  store(makeBlock(1));
+  store(makeBlock(2));
+  auto sink = open();
+  ASSERT_NO_FATAL_FAILURE(waitForCursor(*sink, 2));
+  EXPECT_EQ(events().size(), 2);
+}
+
+/**
+ * @given event sink with a receiver failing at first
+ * @when a block is appended and the receiver recovers
+ * @then the event is retried until it is delivered
+ */
+TEST_F(EventSinkTest, RetriesDelivery) {
+  setFail(true);
+  auto sink = open();
+  commit(*sink, makeBlock(1));
+  std::this_thread::sleep_for(std::chrono::milliseconds(100));
+  EXPECT_EQ(sink->cursor(), 0);
+
+  setFail(false);
+  ASSERT_NO_FATAL_FAILURE(waitForCursor(*sink, 1));
+  EXPECT_EQ(events().size(), 1);
+}
+
+/**
+ * @given block with a transaction of admin@test appending a role
+ * @when events are made with different filters
+ * @then the transaction is forwarded only if it passes the filter
+ */
+TEST_F(EventSinkTest, FiltersTransactions) {
+  auto block = makeBlock(1);
+
+  auto event = EventSink::makeEvent(block, {});
+  ASSERT_TRUE(event);
+  rapidjson::Document document;
+  document.Parse(event->c_str());
+  ASSERT_FALSE(document.HasParseError());
+  EXPECT_EQ(document["height"].GetUint64(), 1);
+  EXPECT_EQ(document["hash"].GetString(), block.hash().hex());
+  auto const &transactions = document["transactions"];
+  ASSERT_EQ(transactions.Size(), 1);
+  EXPECT_STREQ(transactions[0]["creator"].GetString(), "admin@test");
+  ASSERT_EQ(transactions[0]["commands"].Size(), 1);
+  EXPECT_STREQ(transactions[0]["commands"][0].GetString(), "append_role");
+
+  EXPECT_TRUE(EventSink::makeEvent(block, {{"append_role"}, {}}));
+  EXPECT_TRUE(EventSink::makeEvent(block, {{}, {"admin@test"}}));
+  EXPECT_FALSE(EventSink::makeEvent(block, {{"transfer_asset"}, {}}));
+  EXPECT_FALSE(
+      EventSink::makeEvent(block, {{"append_role"}, {"user@test"}}));
+}
+
+/**
+ * @given event sink with a filter no transaction passes
+ * @when a block is appended
+ * @then nothing is delivered and the cursor advances
+ */
+TEST_F(EventSinkTest, AdvancesCursorOverFilteredBlocks) {
+  auto sink = open({{"transfer_asset"}, {}});
+  commit(*sink, makeBlock(1));
+  ASSERT_NO_FATAL_FAILURE(waitForCursor(*sink, 1));
+  EXPECT_TRUE(events().empty());
+}
+
+/**
+ * @given block rotating a key with grace period of two blocks
+ * @when it is delivered, the sink is restarted and the following blocks are
+ * appended
+ * @then the rotation is reported as started in its block and as expired in
+ * the last block of the grace period
+ */
+TEST_F(EventSinkTest, ReportsKeyRotations) {
+  {
+    auto sink = open();
+    commit(*sink, makeRotationBlock(1, 2));
+    ASSERT_NO_FATAL_FAILURE(waitForCursor(*sink, 1));
+  }
+
+  auto sink = open();
+  commit(*sink, makeBlock(2));
+  commit(*sink, makeBlock(3));
+  commit(*sink, makeBlock(4));
+  ASSERT_NO_FATAL_FAILURE(waitForCursor(*sink, 4));
+
+  auto const delivered = events();
+  ASSERT_EQ(delivered.size(), 4);
+  EXPECT_EQ(rotationPhases(delivered[0]), std::vector<std::string>{"started"});
+  EXPECT_TRUE(rotationPhases(delivered[1]).empty());
+  EXPECT_EQ(rotationPhases(delivered[2]), std::vector<std::string>{"expired"});
+  EXPECT_TRUE(rotationPhases(delivered[3]).empty());
+
+  auto const expired =
+      EventSink::makeEvent(makeBlock(3),
+                           {{"rotate_key"}, {}},
+                           EventSink::keyRotations(makeRotationBlock(1, 2)));
+  ASSERT_TRUE(expired);
+  EXPECT_EQ(rotationPhases(*expired), std::vector<std::string>{"expired"});
+}
+
+/**
+ * @given URLs with supported and unsupported schemes
+ * @when transports are made
+ * @then only http and nats URLs with a subject are accepted
+ */
+TEST_F(EventSinkTest, MakesTransports) {
+  auto const timeout = std::chrono::milliseconds(100);
+  EXPECT_TRUE(iroha::expected::hasValue(
+      EventSink::makeTransport("http://localhost:8080/events", timeout)));
+  EXPECT_TRUE(iroha::expected::hasValue(
+      EventSink::makeTransport("nats://localhost:4222/iroha.blocks", timeout)));
+  EXPECT_TRUE(iroha::expected::hasError(
+      EventSink::makeTransport("nats://localhost:4222", timeout)));
+  EXPECT_TRUE(iroha::expected::hasError(
+      EventSink::makeTransport("kafka://localhost:9092/blocks", timeout)));
+  EXPECT_TRUE(iroha::expected::hasError(
+      EventSink::makeTransport("localhost:8080", timeout)));
+}
@@ -0,0 +1,109 @@
+/**
+ * Copyright Soramitsu Co., Ltd. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+#include "maintenance/peer_identity.hpp"
+
+#include <gtest/gtest.h>
+#include <rapidjson/document.h>
+#include <rapidjson/stringbuffer.h>
+#include <rapidjson/writer.h>
+#include "framework/result_gtest_checkers.hpp"
+#include "module/shared_model/cryptography/crypto_defaults.hpp"
+
+using iroha::PeerIdentity;
+using shared_model::interface::types::PublicKeyHexStringView;
+
+class PeerIdentityTest : public ::testing::Test {
+ public:
+  void SetUp() override {
+    identity.public_key = keypair.publicKey();
+    identity.chain_id = "test-chain";
+    identity.version = "1.0.0";
+    identity.genesis_hash = std::string(64, 'a');
+    identity.issued_at = 1600000000000;
+  }
+
+  shared_model::crypto::Keypair keypair =
+      shared_model::crypto::DefaultCryptoAlgorithmType::generateKeypair();
+  PeerIdentity identity;
+};
+
+/**
+ * @given an identity signed by the key of the peer
+ * @when it is verified against this key
+ * @then the original identity is returned
+ */
+TEST_F(PeerIdentityTest, SignedIdentityVerified) {
+  auto verified = iroha::verifyIdentity(
+      iroha::signIdentity(identity, keypair),
+      PublicKeyHexStringView{keypair.publicKey()});
+  IROHA_ASSERT_RESULT_VALUE(verified);
+  auto const &result = verified.assumeValue();
+  EXPECT_EQ(result.public_key, identity.public_key);
+  EXPECT_EQ(result.chain_id, identity.chain_id);
+  EXPECT_EQ(result.version, identity.version);
+  EXPECT_EQ(result.genesis_hash, identity.genesis_hash);
+  EXPECT_EQ(result.issued_at, identity.issued_at);
+}
+
+/**
+ * @given an identity of a peer without the genesis block
+ * @when it is signed and verified
+ * @then the genesis hash stays absent
+ */
+TEST_F(PeerIdentityTest, NoGenesisHash) {
+  identity.genesis_hash.reset();
+  auto verified = iroha::verifyIdentity(
+      iroha::signIdentity(identity, keypair),
+      PublicKeyHexStringView{keypair.publicKey()});
+  IROHA_ASSERT_RESULT_VALUE(verified);
+  EXPECT_FALSE(verified.assumeValue().genesis_hash);
+}
+
+/**
+ * @given an identity signed by the key of the peer
+ * @when it is verified against another trusted key
+ * @then there is an error
+ */
+TEST_F(PeerIdentityTest, UntrustedKey) {
+  auto other =
+      shared_model::crypto::DefaultCryptoAlgorithmType::generateKeypair();
+  IROHA_ASSERT_RESULT_ERROR(
+      iroha::verifyIdentity(iroha::signIdentity(identity, keypair),
+                            PublicKeyHexStringView{other.publicKey()}));
+}
+
+/**
+ * @given a signed identity with the chain ID changed afterwards
+ * @when it is verified
+ * @then there is an error
+ */
+TEST_F(PeerIdentityTest, TamperedStatement) {
+  rapidjson::Document document;
+  document.Parse(iroha::signIdentity(identity, keypair).c_str());
+  std::string statement = document["statement"].GetString();
+  auto pos = statement.find("test-chain");
+  ASSERT_NE(pos, std::string::npos);
+  statement.replace(pos, 10, "main-chain");
+  document["statement"].SetString(
+      statement.c_str(), statement.size(), document.GetAllocator());
+
+  rapidjson::StringBuffer buffer;
+  rapidjson::Writer<rapidjson::StringBuffer> writer(buffer);
+  document.Accept(writer);
+  IROHA_ASSERT_RESULT_ERROR(
+      iroha::verifyIdentity(buffer.GetString(),
+                            PublicKeyHexStringView{keypair.publicKey()}));
+}
+
+/**
+ * @given a response which is not a signed identity
+ * @when it is verified
+ * @then there is an error
+ */
+TEST_F(PeerIdentityTest, Malformed) {
+  IROHA_ASSERT_RESULT_ERROR(iroha::verifyIdentity(
+      R"({"status":"ok"})", PublicKeyHexStringView{keypair.publicKey()}));
+}
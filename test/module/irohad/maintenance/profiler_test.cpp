@@ -0,0 +1,60 @@
+/**
+ * Copyright Soramitsu Co., Ltd. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+#include "maintenance/profiler.hpp"
+
+#include <atomic>
+#include <regex>
+#include <sstream>
+#include <thread>
+
+#include <gtest/gtest.h>
+#include "framework/result_gtest_checkers.hpp"
+
+using iroha::Profiler;
+
+/**
+ * @given a thread consuming CPU
+ * @when the process is profiled
+ * @then the profile contains folded stacks with sample counts
+ */
+TEST(ProfilerTest, FoldedStacks) {
+  std::atomic_bool stop{false};
+  std::thread busy([&stop] {
+    volatile uint64_t counter = 0;
+    while (not stop) {
+      counter = counter + 1;
+    }
+  });
+
+  Profiler profiler;
+  auto folded = profiler.profile(std::chrono::milliseconds(500), 1000);
+  stop = true;
+  busy.join();
+
+  IROHA_ASSERT_RESULT_VALUE(folded);
+  ASSERT_FALSE(folded.assumeValue().empty());
+  std::istringstream lines(folded.assumeValue());
+  std::string line;
+  while (std::getline(lines, line)) {
+    EXPECT_TRUE(std::regex_match(line, std::regex(R"(.+ [1-9][0-9]*)")))
+        << line;
+  }
+}
+
+/**
+ * @given a profile being collected
+ * @when another profile is requested
+ * @then there is an error
+ */
+TEST(ProfilerTest, OneProfileAtATime) {
+  Profiler profiler;
+  std::thread first(
+      [&profiler] { profiler.profile(std::chrono::milliseconds(500)); });
+  std::this_thread::sleep_for(std::chrono::milliseconds(100));
+
+  IROHA_ASSERT_RESULT_ERROR(Profiler{}.profile(std::chrono::milliseconds(1)));
+  first.join();
+}
@@ -0,0 +1,48 @@
+/**
+ * Copyright Soramitsu Co., Ltd. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+#include "network/impl/bandwidth_limiter.hpp"
+
+#include <gtest/gtest.h>
+
+using namespace iroha::network;
+using namespace std::chrono_literals;
+
+/**
+ * @given a limiter of 1000 bytes per second
+ * @when one second worth of traffic is reserved at once
+ * @then it is not delayed
+ */
+TEST(BandwidthLimiterTest, BurstNotDelayed) {
+  BandwidthLimiter limiter(1000);
+  auto const now = BandwidthLimiter::Clock::now();
+  EXPECT_EQ(limiter.reserve(600, now), 0us);
+  EXPECT_EQ(limiter.reserve(400, now), 0us);
+}
+
+/**
+ * @given a limiter of 1000 bytes per second
+ * @when more than one second worth of traffic is reserved at once
+ * @then the excess is delayed until it fits the rate
+ */
+TEST(BandwidthLimiterTest, ExcessDelayed) {
+  BandwidthLimiter limiter(1000);
+  auto const now = BandwidthLimiter::Clock::now();
+  EXPECT_EQ(limiter.reserve(1000, now), 0us);
+  EXPECT_EQ(limiter.reserve(500, now), 500ms);
+  EXPECT_EQ(limiter.reserve(500, now), 1s);
+}
+
+/**
+ * @given a limiter of 1000 bytes per second with a spent burst
+ * @when traffic is reserved after the reserved traffic is sent
+ * @then it is not delayed
+ */
+TEST(BandwidthLimiterTest, RecoversAfterIdle) {
+  BandwidthLimiter limiter(1000);
+  auto const now = BandwidthLimiter::Clock::now();
+  EXPECT_EQ(limiter.reserve(2000, now), 1s);
+  EXPECT_EQ(limiter.reserve(1000, now + 2s), 0us);
+}
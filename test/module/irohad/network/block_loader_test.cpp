@@ -8,6 +8,7 @@
 #include <grpc++/server_builder.h>
 #include <gtest/gtest.h>
 
+#include "ametsuchi/block_storage.hpp"
 #include "builders/protobuf/builder_templates/transaction_template.hpp"
 #include "consensus/consensus_block_cache.hpp"
 #include "cryptography/hash.hpp"
@@ -337,3 +338,23 @@ TEST_F(BlockLoaderTest, NoBlocksInStorage) {
 
   IROHA_ASSERT_RESULT_ERROR(loader->retrieveBlock(peer_key, 1));
 }
+
+/**
+ * @given block loader @and empty consensus cache @and a block store which
+ * pruned the requested block
+ * @when retrieveBlock is called with the pruned block's height
+ * @then block loader returns an error telling that the block is pruned
+ */
+TEST_F(BlockLoaderTest, PrunedBlockInStorage) {
+  setPeerQuery();
+  EXPECT_CALL(*storage, getBlock(2))
+      .WillOnce(
+          Return(ByMove(iroha::expected::makeError(BlockQuery::GetBlockError{
+              BlockQuery::GetBlockError::Code::kPruned,
+              prunedBlockMessage(2, 4)}))));
+
+  auto result = loader->retrieveBlock(peer_key, 2);
+  IROHA_ASSERT_RESULT_ERROR(result);
+  EXPECT_THAT(result.assumeError(),
+              testing::HasSubstr(prunedBlockMessage(2, 4)));
+}
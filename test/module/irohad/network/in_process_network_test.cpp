@@ -0,0 +1,65 @@
+/**
+ * Copyright Soramitsu Co., Ltd. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+#include "network/impl/in_process_network.hpp"
+
+#include <gtest/gtest.h>
+#include "framework/result_gtest_checkers.hpp"
+#include "framework/test_logger.hpp"
+#include "main/server_runner.hpp"
+#include "yac.grpc.pb.h"
+
+using namespace iroha::network;
+
+static const std::string kAddress{"127.0.0.1:10001"};
+
+class InProcessNetworkTest : public ::testing::Test {
+ public:
+  void SetUp() override {
+    network = std::make_shared<InProcessNetwork>();
+    runner = std::make_unique<ServerRunner>(kAddress,
+                                            getTestLogger("ServerRunner"));
+    runner->inProcess(network).append(
+        std::make_shared<iroha::consensus::yac::proto::Yac::Service>());
+  }
+
+  std::shared_ptr<InProcessNetwork> network;
+  std::unique_ptr<ServerRunner> runner;
+};
+
+/**
+ * @given an in-process server
+ * @when it is run
+ * @then it reports the port of its address and its services are called
+ * through the channel of the network
+ */
+TEST_F(InProcessNetworkTest, CallsServer) {
+  IROHA_ASSERT_RESULT_ERROR(network->getChannel(kAddress));
+  auto port = runner->run();
+  IROHA_ASSERT_RESULT_VALUE(port);
+  EXPECT_EQ(port.assumeValue(), 10001);
+
+  auto channel = network->getChannel(kAddress);
+  IROHA_ASSERT_RESULT_VALUE(channel);
+  auto stub = iroha::consensus::yac::proto::Yac::NewStub(channel.assumeValue());
+  grpc::ClientContext context;
+  iroha::consensus::yac::proto::State state;
+  google::protobuf::Empty response;
+  // the default service does not implement the call, but it is reached
+  EXPECT_EQ(stub->SendState(&context, state, &response).error_code(),
+            grpc::StatusCode::UNIMPLEMENTED);
+}
+
+/**
+ * @given an in-process server
+ * @when it is shut down
+ * @then it leaves the network
+ */
+TEST_F(InProcessNetworkTest, ShutdownLeaves) {
+  IROHA_ASSERT_RESULT_VALUE(runner->run());
+  IROHA_ASSERT_RESULT_VALUE(network->getChannel(kAddress));
+  runner->shutdown();
+  IROHA_ASSERT_RESULT_ERROR(network->getChannel(kAddress));
+}
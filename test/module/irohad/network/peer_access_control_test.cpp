@@ -0,0 +1,55 @@
+/**
+ * Copyright Soramitsu Co., Ltd. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+#include "network/impl/peer_access_control.hpp"
+
+#include <gtest/gtest.h>
+
+using namespace iroha::network;
+
+static const std::string kPeer{"ipv4:10.0.0.1:10001"};
+static const std::string kOtherPeer{"ipv4:10.0.0.2:10001"};
+
+/**
+ * @given caller addresses reported by gRPC
+ * @when the IP address is extracted
+ * @then the port and the brackets are removed
+ */
+TEST(PeerAccessControlTest, HostOf) {
+  EXPECT_EQ(PeerAccessControl::hostOf(kPeer), "10.0.0.1");
+  EXPECT_EQ(PeerAccessControl::hostOf("ipv6:[::1]:10001"), "::1");
+  EXPECT_EQ(PeerAccessControl::hostOf("ipv6:%5B::1%5D:10001"), "::1");
+}
+
+/**
+ * @given access control with allow and deny lists
+ * @when peers call
+ * @then only allowed peers, which are not denied, are admitted
+ */
+TEST(PeerAccessControlTest, AllowDenyLists) {
+  PeerAccessControl access(
+      {{"10.0.0.1", "10.0.0.3"}, {"10.0.0.3"}, std::nullopt, std::nullopt});
+  EXPECT_EQ(access.admit(kPeer), std::nullopt);
+  EXPECT_EQ(access.admit(kOtherPeer), "not_allowed");
+  EXPECT_EQ(access.admit("ipv4:10.0.0.3:10001"), "deny_list");
+}
+
+/**
+ * @given access control with a limit of 2 calls and 1 call per IP address
+ * @when peers call
+ * @then the calls over the limits are rejected until admitted calls are
+ * released
+ */
+TEST(PeerAccessControlTest, CallLimits) {
+  PeerAccessControl access({{}, {}, 2, 1});
+  EXPECT_EQ(access.admit(kPeer), std::nullopt);
+  EXPECT_EQ(access.admit(kPeer), "max_calls_per_ip");
+  EXPECT_EQ(access.admit(kOtherPeer), std::nullopt);
+  EXPECT_EQ(access.admit("ipv4:10.0.0.3:10001"), "max_inbound_calls");
+
+  access.release(kPeer);
+  EXPECT_EQ(access.admit("ipv4:10.0.0.3:10001"), std::nullopt);
+  EXPECT_EQ(access.admit(kPeer), "max_inbound_calls");
+}
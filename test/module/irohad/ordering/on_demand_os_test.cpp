@@ -19,6 +19,7 @@
 #include "module/shared_model/interface_mocks.hpp"
 #include "module/shared_model/validators/validators.hpp"
 #include "ordering/impl/on_demand_common.hpp"
+#include "validators/field_validator.hpp"
 
 using namespace iroha;
 using namespace iroha::ordering;
@@ -90,7 +91,8 @@ class OnDemandOsTest : public ::testing::Test {
 
   OnDemandOrderingService::CollectionType generateTransactions(
       std::pair<uint64_t, uint64_t> range,
-      shared_model::interface::types::TimestampType now = iroha::time::now()) {
+      shared_model::interface::types::TimestampType now = iroha::time::now(),
+      shared_model::interface::types::TimestampType not_before = 0) {
     OnDemandOrderingService::CollectionType collection;
 
     for (auto i = range.first; i < range.second; ++i) {
@@ -100,6 +102,7 @@ class OnDemandOsTest : public ::testing::Test {
                   std::make_unique<shared_model::proto::Transaction>(
                       shared_model::proto::TransactionBuilder()
                           .createdTime(now + i)
+                          .notBefore(not_before)
                           .creatorAccountId("foo@bar")
                           .createAsset("asset", "domain", 1)
                           .quorum(1)
@@ -415,3 +418,80 @@ TEST_F(OnDemandOsTest, FailOnCreationStrategy) {
 
   ASSERT_TRUE(os->onRequestProposal(target_round));
 }
+
+/**
+ * @given initialized on-demand OS
+ * @when a batch which has already expired arrives
+ * @then it is not used for the next proposal
+ */
+TEST_F(OnDemandOsTest, ExpiredBatchDropped) {
+  auto expired = iroha::time::now()
+      - shared_model::validation::FieldValidator::kDefaultMaxDelay - 10;
+  os->onBatches(generateTransactions({1, 2}, expired));
+
+  os->onCollaborationOutcome(commit_round);
+
+  ASSERT_FALSE(os->onRequestProposal(target_round));
+}
+
+/**
+ * @given initialized on-demand OS with a batch which is valid only in future
+ * and a batch valid now
+ * @when next proposal is requested
+ * @then only the batch valid now is used for the proposal
+ */
+TEST_F(OnDemandOsTest, NotBeforeBatchDeferred) {
+  auto now = iroha::time::now();
+  os->onBatches(generateTransactions({1, 2}, now, now + 60 * 60 * 1000));
+  os->onBatches(generateTransactions({2, 3}, now));
+
+  os->onCollaborationOutcome(commit_round);
+  auto proposal = os->onRequestProposal(target_round);
+
+  ASSERT_TRUE(proposal);
+  ASSERT_EQ(1, proposal->size());
+  auto txs = proposal->operator[](0).first->transactions();
+  ASSERT_EQ(1, boost::size(txs));
+  EXPECT_EQ(0, txs.front().notBefore());
+}
+
+/**
+ * @given on-demand OS with the transactions memory limit of one byte
+ * @when three batches arrive
+ * AND the transactions of the first one are committed
+ * @then only the first batch is cached and accounted in the budget
+ * @then the budget is released after the commit
+ */
+TEST_F(OnDemandOsTest, MemoryLimitReached) {
+  auto budget = std::make_shared<MemoryBudget>(MemoryBudget::Limits{1, {}});
+  auto factory = std::make_unique<
+      shared_model::proto::ProtoProposalFactory<MockProposalValidator>>(
+      iroha::test::kTestsValidatorsConfig);
+  auto tx_cache =
+      std::make_unique<NiceMock<iroha::ametsuchi::MockTxPresenceCache>>();
+  ON_CALL(*tx_cache,
+          check(testing::Matcher<
+                const shared_model::interface::TransactionBatch &>(_)))
+      .WillByDefault(Return(std::vector<iroha::ametsuchi::TxCacheStatusType>{
+          iroha::ametsuchi::tx_cache_status_responses::Missing()}));
+  os = std::make_shared<OnDemandOrderingServiceImpl>(
+      transaction_limit,
+      max_proposal_pack,
+      std::move(factory),
+      std::move(tx_cache),
+      getTestLogger("OdOrderingService"),
+      proposal_limit,
+      budget);
+
+  auto batches = generateTransactions({1, 4});
+  auto const hash = batches.front()->transactions().front()->hash();
+  os->onBatches(batches);
+
+  EXPECT_EQ(1, os->availableTxsCountBatchesCache());
+  EXPECT_TRUE(budget->exhausted(MemoryBudget::Buffer::kTransactions));
+
+  os->onTxsCommitted({hash});
+
+  EXPECT_EQ(0, budget->used(MemoryBudget::Buffer::kTransactions));
+  EXPECT_FALSE(budget->exhausted(MemoryBudget::Buffer::kTransactions));
+}
@@ -6,6 +6,7 @@
 #include "interfaces/common_objects/string_view_types.hpp"
 #include "synchronizer/impl/synchronizer_impl.hpp"
 
+#include <future>
 #include <string_view>
 
 #include <gmock/gmock-generated-matchers.h>
@@ -13,6 +14,7 @@
 #include <boost/range/adaptor/transformed.hpp>
 #include "backend/protobuf/block.hpp"
 #include "framework/test_logger.hpp"
+#include "main/block_phase_stats.hpp"
 #include "module/irohad/ametsuchi/mock_block_query.hpp"
 #include "module/irohad/ametsuchi/mock_block_query_factory.hpp"
 #include "module/irohad/ametsuchi/mock_command_executor.hpp"
@@ -234,6 +236,36 @@ TEST_F(SynchronizerTest, ValidWhenSingleCommitSynchronized) {
   ASSERT_EQ(commit_event->sync_outcome, SynchronizationOutcomeType::kCommit);
 }
 
+/**
+ * @given A commit from consensus and initialized components
+ * @when the block is committed
+ * @then the duration of the storage commit phase is published
+ */
+TEST_F(SynchronizerTest, StorageCommitPhasePublished) {
+  EXPECT_CALL(*mutable_factory, preparedCommitEnabled())
+      .WillRepeatedly(Return(false));
+  mutableStorageExpectChain(*mutable_factory, {commit_message});
+
+  std::promise<void> published;
+  auto subscriber =
+      SubscriberCreator<bool, BlockPhaseStats>::template create<
+          EventTypes::kOnBlockPhase>(
+          SubscriptionEngineHandlers::kNotifications,
+          [&](auto &, BlockPhaseStats stats) {
+            if (stats.phase == BlockPhase::kStorageCommit) {
+              published.set_value();
+            }
+          });
+
+  auto commit_event = synchronizer->processOutcome(consensus::PairValid(
+      consensus::Round{kHeight, 1}, ledger_state, commit_message));
+  ASSERT_TRUE(commit_event);
+
+  ASSERT_EQ(published.get_future().wait_for(std::chrono::seconds(5)),
+            std::future_status::ready);
+  subscriber->unsubscribe();
+}
+
 /**
  * @given A commit from consensus and initialized components
  * @when gate have voted for other block
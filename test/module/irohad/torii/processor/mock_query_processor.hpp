@@ -15,10 +15,11 @@ namespace iroha {
 
     class MockQueryProcessor : public QueryProcessor {
      public:
-      MOCK_METHOD1(queryHandle,
+      MOCK_METHOD2(queryHandle,
                    iroha::expected::Result<
                        std::unique_ptr<shared_model::interface::QueryResponse>,
-                       std::string>(const shared_model::interface::Query &));
+                       std::string>(const shared_model::interface::Query &,
+                                    std::optional<std::chrono::milliseconds>));
       MOCK_METHOD((iroha::expected::Result<void, std::string>),
                   blocksQueryHandle,
                   (shared_model::interface::BlocksQuery const &),
@@ -101,6 +101,36 @@ TEST_F(QueryProcessorTest,
   EXPECT_THAT(response.assumeError(), ::testing::HasSubstr(error_text));
 }
 
+/**
+ * @given QueryProcessorImpl and GetAccountDetail query
+ * @when queryHandle is called with a timeout
+ * @then the timeout is set to the query executor before the execution
+ */
+TEST_F(QueryProcessorTest, QueryProcessorSetsTimeout) {
+  auto qry = TestUnsignedQueryBuilder()
+                 .creatorAccountId(kAccountId)
+                 .getAccountDetail(kMaxPageSize, kAccountId)
+                 .build()
+                 .signAndAddSignature(keypair)
+                 .finish();
+  auto *qry_resp =
+      query_response_factory
+          ->createAccountDetailResponse("", 1, std::nullopt, qry.hash())
+          .release();
+
+  ::testing::Sequence seq;
+  EXPECT_CALL(*qry_exec, setTimeout(std::chrono::milliseconds{100}))
+      .InSequence(seq);
+  EXPECT_CALL(*qry_exec, validateAndExecute_(_))
+      .InSequence(seq)
+      .WillOnce(Return(qry_resp));
+  EXPECT_CALL(*storage, createQueryExecutor(_, _))
+      .WillOnce(Return(ByMove(std::move(qry_exec))));
+
+  IROHA_ASSERT_RESULT_VALUE(
+      qpi->queryHandle(qry, std::chrono::milliseconds{100}));
+}
+
 /**
  * @given QueryProcessorImpl and GetAccountDetail query
  * @when queryHandle called at normal flow
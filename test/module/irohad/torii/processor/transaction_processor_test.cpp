@@ -15,6 +15,7 @@
 #include "framework/test_logger.hpp"
 #include "interfaces/iroha_internal/transaction_batch.hpp"
 #include "interfaces/iroha_internal/transaction_sequence_factory.hpp"
+#include "interfaces/transaction_responses/expired_tx_response.hpp"
 #include "module/irohad/common/validators_config.hpp"
 #include "module/irohad/network/network_mocks.hpp"
 #include "module/irohad/torii/torii_mocks.hpp"
@@ -418,3 +419,22 @@ TEST_F(TransactionProcessorTest, MultisigExpired) {
   tp->processExpiredBatch(
       framework::batch::createBatchFromSingleTransaction(tx));
 }
+
+/**
+ * @given valid tx
+ * @when the ordering service drops it as expired
+ * @then it will have EXPIRED status with the reason of expiry
+ */
+TEST_F(TransactionProcessorTest, TransactionOutdated) {
+  std::shared_ptr<shared_model::interface::Transaction> tx =
+      clone(base_tx().build().signAndAddSignature(makeKey()).finish());
+  EXPECT_CALL(*status_bus, publish(_))
+      .WillOnce(testing::Invoke([](auto response) {
+        ASSERT_NO_THROW(
+            boost::get<const shared_model::interface::ExpiredTxResponse &>(
+                response->get()));
+        EXPECT_EQ(response->statelessErrorOrCommandName(), "too old");
+      }));
+  tp->processOutdatedBatch(
+      framework::batch::createBatchFromSingleTransaction(tx), "too old");
+}
@@ -4,11 +4,16 @@
  */
 
 #include "torii/query_service.hpp"
+
+#include <future>
+
 #include "backend/protobuf/proto_query_response_factory.hpp"
 #include "backend/protobuf/proto_transport_factory.hpp"
 #include "backend/protobuf/query_responses/proto_query_response.hpp"
 #include "builders/protobuf/queries.hpp"
 #include "framework/test_logger.hpp"
+#include "main/query_stats.hpp"
+#include "main/subscription.hpp"
 #include "module/irohad/common/validators_config.hpp"
 #include "module/irohad/torii/processor/mock_query_processor.hpp"
 #include "module/shared_model/cryptography/crypto_defaults.hpp"
@@ -70,13 +75,55 @@ class QueryServiceTest : public ::testing::Test {
             std::move(proto_blocks_query_validator));
   }
 
-  void init() {
+  void init(QueryCostLimits cost_limits = {}, QueryTimeouts timeouts = {}) {
     query_service =
         std::make_shared<QueryService>(query_processor,
                                        query_factory,
                                        blocks_query_factory,
                                        getTestLogger("QueryService"),
-                                       nullptr);
+                                       nullptr,
+                                       std::nullopt,
+                                       std::move(cost_limits),
+                                       nullptr,
+                                       BlockStreamLimits{},
+                                       std::move(timeouts));
+  }
+
+  /// Build a GetAccountAssets query with the given page size.
+  shared_model::proto::Query makeAssetsQuery(size_t page_size,
+                                             uint64_t counter) {
+    return shared_model::proto::QueryBuilder()
+        .creatorAccountId("user@domain")
+        .createdTime(iroha::time::now())
+        .queryCounter(counter)
+        .getAccountAssets("user@domain", page_size, std::nullopt)
+        .build()
+        .signAndAddSignature(shared_model::crypto::DefaultCryptoAlgorithmType::
+                                 generateKeypair())
+        .finish();
+  }
+
+  /// Build a GetRoles query, which returns all roles without pagination.
+  shared_model::proto::Query makeRolesQuery() {
+    return shared_model::proto::QueryBuilder()
+        .creatorAccountId("user@domain")
+        .createdTime(iroha::time::now())
+        .queryCounter(1)
+        .getRoles()
+        .build()
+        .signAndAddSignature(shared_model::crypto::DefaultCryptoAlgorithmType::
+                                 generateKeypair())
+        .finish();
+  }
+
+  /// Check that the response is a TooExpensive error.
+  void checkTooExpensive(const protocol::QueryResponse &response) {
+    ASSERT_TRUE(response.has_error_response());
+    shared_model::proto::QueryResponse resp{protocol::QueryResponse{response}};
+    ASSERT_TRUE(boost::apply_visitor(
+        shared_model::interface::QueryErrorResponseChecker<
+            shared_model::interface::TooExpensiveErrorResponse>(),
+        resp.get()));
   }
 
   std::unique_ptr<shared_model::interface::QueryResponse> getResponse() {
@@ -103,8 +150,9 @@ TEST_F(QueryServiceTest, ValidWhenUniqueHash) {
                   // match by shared_ptr's content
                   Truly([this](const shared_model::interface::Query &rhs) {
                     return rhs == *query;
-                  })))
-      .WillOnce(Invoke([this](auto &) { return this->getResponse(); }));
+                  }),
+                  _))
+      .WillOnce(Invoke([this](auto &, auto) { return this->getResponse(); }));
   init();
 
   protocol::QueryResponse response;
@@ -121,9 +169,8 @@ TEST_F(QueryServiceTest, ValidWhenUniqueHash) {
  */
 TEST_F(QueryServiceTest, InvalidWhenDuplicateHash) {
   // two same queries => only first query handled by query processor
-  EXPECT_CALL(*query_processor, queryHandle(_)).WillOnce(Invoke([this](auto &) {
-    return this->getResponse();
-  }));
+  EXPECT_CALL(*query_processor, queryHandle(_, _))
+      .WillOnce(Invoke([this](auto &, auto) { return this->getResponse(); }));
 
   init();
 
@@ -139,3 +186,140 @@ TEST_F(QueryServiceTest, InvalidWhenDuplicateHash) {
           shared_model::interface::StatelessFailedErrorResponse>(),
       resp.get()));
 }
+
+/**
+ * @given query service
+ * @when a query is processed
+ * @then execution statistics with the type of the query are published
+ */
+TEST_F(QueryServiceTest, StatsPublished) {
+  EXPECT_CALL(*query_processor, queryHandle(_, _))
+      .WillOnce(Invoke([this](auto &, auto) { return this->getResponse(); }));
+  init();
+
+  std::promise<QueryStats> published;
+  auto subscriber =
+      SubscriberCreator<bool, QueryStats>::template create<
+          EventTypes::kOnQueryExecuted>(
+          SubscriptionEngineHandlers::kNotifications,
+          [&](auto &, QueryStats stats) { published.set_value(stats); });
+
+  protocol::QueryResponse response;
+  query_service->Find(query->getTransport(), response);
+
+  auto stats = published.get_future();
+  ASSERT_EQ(stats.wait_for(std::chrono::seconds(5)),
+            std::future_status::ready);
+  auto const value = stats.get();
+  EXPECT_EQ(value.type, "get_account");
+  EXPECT_EQ(value.response_size, response.ByteSizeLong());
+  EXPECT_FALSE(value.failed);
+  subscriber->unsubscribe();
+}
+
+/**
+ * @given query service with the maximum query cost of 10
+ * @when a query with the page size of 100 is sent
+ * @then it is rejected with TooExpensive error without being executed
+ */
+TEST_F(QueryServiceTest, RejectedWhenTooExpensive) {
+  EXPECT_CALL(*query_processor, queryHandle(_, _)).Times(0);
+  init(QueryCostLimits{10, std::nullopt});
+
+  protocol::QueryResponse response;
+  query_service->Find(makeAssetsQuery(100, 1).getTransport(), response);
+  checkTooExpensive(response);
+}
+
+/**
+ * @given query service with the cost budget of 15 per minute
+ * @when two queries with the page size of 10 are sent by the same account
+ * @then the first one is executed
+ * @and the second one is rejected with TooExpensive error
+ */
+TEST_F(QueryServiceTest, RejectedWhenBudgetExhausted) {
+  EXPECT_CALL(*query_processor, queryHandle(_, _))
+      .WillOnce(Invoke([this](auto &, auto) { return this->getResponse(); }));
+  init(QueryCostLimits{std::nullopt, 15});
+
+  protocol::QueryResponse response;
+  query_service->Find(makeAssetsQuery(10, 1).getTransport(), response);
+  ASSERT_FALSE(response.has_error_response());
+
+  query_service->Find(makeAssetsQuery(10, 2).getTransport(), response);
+  checkTooExpensive(response);
+}
+
+/**
+ * @given query service with the maximum query cost of 10
+ * @and the cost of collection queries of 50
+ * @when a GetRoles query is sent
+ * @then it is rejected with TooExpensive error without being executed
+ */
+TEST_F(QueryServiceTest, CollectionQueryRejectedWhenTooExpensive) {
+  EXPECT_CALL(*query_processor, queryHandle(_, _)).Times(0);
+  init(QueryCostLimits{10, std::nullopt, 50});
+
+  protocol::QueryResponse response;
+  query_service->Find(makeRolesQuery().getTransport(), response);
+  checkTooExpensive(response);
+}
+
+/**
+ * @given query service with the maximum query cost of 100
+ * @and the cost of collection queries of 50
+ * @when a GetRoles query is sent
+ * @then it is executed
+ */
+TEST_F(QueryServiceTest, CollectionQueryExecutedWithinCost) {
+  EXPECT_CALL(*query_processor, queryHandle(_, _))
+      .WillOnce(Invoke([this](auto &, auto) { return this->getResponse(); }));
+  init(QueryCostLimits{100, std::nullopt, 50});
+
+  protocol::QueryResponse response;
+  query_service->Find(makeRolesQuery().getTransport(), response);
+  ASSERT_FALSE(response.has_error_response());
+}
+
+/**
+ * @given query service with the maximum query cost of 100
+ * @when a GetPendingTransactions query without pagination meta is sent
+ * @then it is rejected with TooExpensive error without being executed
+ * @and the error asks to set the page size
+ */
+TEST_F(QueryServiceTest, UnpaginatedQueryRejectedWithReason) {
+  EXPECT_CALL(*query_processor, queryHandle(_, _)).Times(0);
+  init(QueryCostLimits{100, std::nullopt});
+
+  auto pending_query =
+      shared_model::proto::QueryBuilder()
+          .creatorAccountId("user@domain")
+          .createdTime(iroha::time::now())
+          .queryCounter(1)
+          .getPendingTransactions()
+          .build()
+          .signAndAddSignature(shared_model::crypto::DefaultCryptoAlgorithmType::
+                                   generateKeypair())
+          .finish();
+  protocol::QueryResponse response;
+  query_service->Find(pending_query.getTransport(), response);
+  checkTooExpensive(response);
+  EXPECT_THAT(response.error_response().message(),
+              ::testing::HasSubstr("set the page size"));
+}
+
+/**
+ * @given query service with the default query timeout of 100 ms
+ * @when a query is sent without a deadline
+ * @then it is executed with the default timeout
+ */
+TEST_F(QueryServiceTest, DefaultTimeout) {
+  EXPECT_CALL(*query_processor,
+              queryHandle(_, ::testing::Eq(std::chrono::milliseconds{100})))
+      .WillOnce(Invoke([this](auto &, auto) { return this->getResponse(); }));
+  init({}, QueryTimeouts{std::chrono::milliseconds{100}, std::nullopt});
+
+  protocol::QueryResponse response;
+  query_service->Find(nullptr, &query->getTransport(), &response);
+  ASSERT_FALSE(response.has_error_response());
+}
@@ -73,6 +73,12 @@ namespace iroha {
           processExpiredBatch,
           (std::shared_ptr<shared_model::interface::TransactionBatch> const &),
           (override));
+      MOCK_METHOD(
+          void,
+          processOutdatedBatch,
+          (std::shared_ptr<shared_model::interface::TransactionBatch> const &,
+           std::string const &),
+          (override));
     };
 
   }  // namespace torii
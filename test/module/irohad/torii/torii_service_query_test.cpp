@@ -15,6 +15,8 @@
 #include "framework/test_logger.hpp"
 #include "main/server_runner.hpp"
 #include "main/subscription.hpp"
+#include "module/irohad/ametsuchi/mock_block_query.hpp"
+#include "module/irohad/ametsuchi/mock_block_query_factory.hpp"
 #include "module/irohad/common/validators_config.hpp"
 #include "module/irohad/torii/processor/mock_query_processor.hpp"
 #include "module/shared_model/builders/protobuf/test_query_builder.hpp"
@@ -25,6 +27,7 @@
 #include "validators/protobuf/proto_query_validator.hpp"
 
 using ::testing::_;
+using ::testing::ByMove;
 using ::testing::Return;
 using ::testing::Truly;
 
@@ -122,7 +125,12 @@ class ToriiQueryServiceTest : public ::testing::Test {
             query_processor,
             query_factory,
             blocks_query_factory,
-            getTestLogger("QueryService"), nullptr))
+            getTestLogger("QueryService"),
+            nullptr,
+            std::nullopt,
+            iroha::torii::QueryCostLimits{},
+            block_query_factory,
+            block_stream_limits))
         .run()
         .match([this](auto port) { this->port = port.value; },
                [](const auto &err) { FAIL() << err.error; });
@@ -167,6 +175,9 @@ class ToriiQueryServiceTest : public ::testing::Test {
   std::shared_ptr<iroha::torii::QueryService::QueryFactoryType> query_factory;
   std::shared_ptr<iroha::torii::QueryService::BlocksQueryFactoryType>
       blocks_query_factory;
+  std::shared_ptr<iroha::ametsuchi::MockBlockQueryFactory> block_query_factory =
+      std::make_shared<iroha::ametsuchi::MockBlockQueryFactory>();
+  iroha::torii::BlockStreamLimits block_stream_limits;
   std::shared_ptr<torii_utils::QuerySyncClient::Service::StubInterface> stub_;
 
   iroha::protocol::Block block;
@@ -245,3 +256,336 @@ TEST_F(ToriiQueryServiceTest, FetchBlocksWhenInvalidQuery) {
   auto response = responses.at(0);
   ASSERT_TRUE(response.has_block_error_response());
 }
+
+/**
+ * @given valid blocks query with the replay height of 2 and 3 stored blocks
+ * @when blocks query is executed
+ * @then the stored blocks starting from the height of 2 are received
+ * @and then the new block is received
+ */
+TEST_F(ToriiQueryServiceTest, FetchBlocksWithReplay) {
+  auto blocks_query = shared_model::proto::BlocksQueryBuilder()
+                          .creatorAccountId("user@domain")
+                          .createdTime(iroha::time::now())
+                          .queryCounter(1)
+                          .fromHeight(2)
+                          .build()
+                          .signAndAddSignature(keypair)
+                          .finish();
+
+  auto makeBlock = [](shared_model::interface::types::HeightType height) {
+    iroha::protocol::Block_v1 block;
+    block.mutable_payload()->set_height(height);
+    return std::unique_ptr<shared_model::interface::Block>(
+        std::make_unique<shared_model::proto::Block>(block));
+  };
+  auto block_query = std::make_shared<iroha::ametsuchi::MockBlockQuery>();
+  EXPECT_CALL(*query_processor, blocksQueryHandle(_))
+      .WillOnce(Return(iroha::expected::makeValue()));
+  EXPECT_CALL(*block_query_factory, createBlockQuery())
+      .WillOnce(Return(boost::make_optional<
+                       std::shared_ptr<iroha::ametsuchi::BlockQuery>>(
+          block_query)));
+  EXPECT_CALL(*block_query, getTopBlockHeight()).WillOnce(Return(3));
+  EXPECT_CALL(*block_query, getBlock(2))
+      .WillOnce(Return(ByMove(iroha::expected::makeValue(makeBlock(2)))));
+  EXPECT_CALL(*block_query, getBlock(3))
+      .WillOnce(Return(ByMove(iroha::expected::makeValue(makeBlock(3)))));
+
+  auto client = torii_utils::QuerySyncClient(stub_);
+  auto responses = client.FetchCommits(blocks_query.getTransport());
+
+  std::vector<shared_model::interface::types::HeightType> heights;
+  for (auto const &response : responses) {
+    ASSERT_TRUE(response.has_block_response());
+    heights.push_back(
+        response.block_response().block().block_v1().payload().height());
+  }
+  EXPECT_THAT(heights, ::testing::ElementsAre(2, 3, 123));
+}
+
+/**
+ * @given valid account scoped blocks query replaying 2 stored blocks
+ * @and the first block with transactions created by the account, by another
+ * account, and transferring to the account
+ * @and the second block with the transaction of another account
+ * @when blocks query is executed
+ * @then only the first block is received without the signatures
+ * @and it contains only the transactions affecting the account
+ */
+TEST_F(ToriiQueryServiceTest, FetchBlocksAccountScoped) {
+  auto blocks_query = shared_model::proto::BlocksQueryBuilder()
+                          .creatorAccountId("user@domain")
+                          .createdTime(iroha::time::now())
+                          .queryCounter(1)
+                          .fromHeight(1)
+                          .accountScoped(true)
+                          .build()
+                          .signAndAddSignature(keypair)
+                          .finish();
+
+  auto makeTx = [](std::string const &creator, std::string const &dest) {
+    iroha::protocol::Transaction tx;
+    auto &payload = *tx.mutable_payload()->mutable_reduced_payload();
+    payload.set_creator_account_id(creator);
+    auto &transfer = *payload.add_commands()->mutable_transfer_asset();
+    transfer.set_src_account_id(creator);
+    transfer.set_dest_account_id(dest);
+    transfer.set_amount("1.0");
+    return tx;
+  };
+  auto makeBlock = [](shared_model::interface::types::HeightType height,
+                      std::vector<iroha::protocol::Transaction> txs) {
+    iroha::protocol::Block_v1 block;
+    block.mutable_payload()->set_height(height);
+    block.mutable_payload()->set_tx_number(txs.size());
+    for (auto &tx : txs) {
+      *block.mutable_payload()->add_transactions() = std::move(tx);
+    }
+    block.add_signatures()->set_public_key("key");
+    return std::unique_ptr<shared_model::interface::Block>(
+        std::make_unique<shared_model::proto::Block>(block));
+  };
+  auto block_query = std::make_shared<iroha::ametsuchi::MockBlockQuery>();
+  EXPECT_CALL(*query_processor, blocksQueryHandle(_))
+      .WillOnce(Return(iroha::expected::makeValue()));
+  EXPECT_CALL(*block_query_factory, createBlockQuery())
+      .WillOnce(Return(boost::make_optional<
+                       std::shared_ptr<iroha::ametsuchi::BlockQuery>>(
+          block_query)));
+  EXPECT_CALL(*block_query, getTopBlockHeight()).WillOnce(Return(2));
+  EXPECT_CALL(*block_query, getBlock(1))
+      .WillOnce(Return(ByMove(iroha::expected::makeValue(
+          makeBlock(1,
+                    {makeTx("user@domain", "other@domain"),
+                     makeTx("other@domain", "another@domain"),
+                     makeTx("other@domain", "user@domain")})))));
+  EXPECT_CALL(*block_query, getBlock(2))
+      .WillOnce(Return(ByMove(iroha::expected::makeValue(
+          makeBlock(2, {makeTx("other@domain", "another@domain")})))));
+
+  auto client = torii_utils::QuerySyncClient(stub_);
+  auto responses = client.FetchCommits(blocks_query.getTransport());
+
+  ASSERT_EQ(responses.size(), 1);
+  ASSERT_TRUE(responses.at(0).has_block_response());
+  auto const &block = responses.at(0).block_response().block().block_v1();
+  EXPECT_EQ(block.payload().height(), 1);
+  EXPECT_EQ(block.signatures_size(), 0);
+  ASSERT_EQ(block.payload().transactions_size(), 2);
+  EXPECT_EQ(block.payload().tx_number(), 2);
+  EXPECT_EQ(block.payload()
+                .transactions(0)
+                .payload()
+                .reduced_payload()
+                .creator_account_id(),
+            "user@domain");
+  EXPECT_EQ(block.payload()
+                .transactions(1)
+                .payload()
+                .reduced_payload()
+                .commands(0)
+                .transfer_asset()
+                .dest_account_id(),
+            "user@domain");
+}
+
+/**
+ * @given valid account scoped blocks query replaying a stored block
+ * @and the block with a transaction of another account transferring to the
+ * account and between other accounts, setting a detail mentioning the account
+ * and a detail mentioning another account with the same prefix
+ * @when blocks query is executed
+ * @then only the transfer to the account and the mentioning detail are
+ * received together with the creator of the transaction
+ */
+TEST_F(ToriiQueryServiceTest, FetchBlocksAccountScopedForeignCommands) {
+  auto blocks_query = shared_model::proto::BlocksQueryBuilder()
+                          .creatorAccountId("user@domain")
+                          .createdTime(iroha::time::now())
+                          .queryCounter(1)
+                          .fromHeight(1)
+                          .accountScoped(true)
+                          .build()
+                          .signAndAddSignature(keypair)
+                          .finish();
+
+  iroha::protocol::Transaction tx;
+  auto &payload = *tx.mutable_payload()->mutable_reduced_payload();
+  payload.set_creator_account_id("other@domain");
+  payload.set_quorum(2);
+  auto addTransfer = [&](std::string const &dest) {
+    auto &transfer = *payload.add_commands()->mutable_transfer_asset();
+    transfer.set_src_account_id("other@domain");
+    transfer.set_dest_account_id(dest);
+    transfer.set_amount("1.0");
+  };
+  auto addDetail = [&](std::string const &value) {
+    auto &detail = *payload.add_commands()->mutable_set_account_detail();
+    detail.set_account_id("other@domain");
+    detail.set_key("invoice");
+    detail.set_value(value);
+  };
+  addTransfer("another@domain");
+  addTransfer("user@domain");
+  addDetail("{\"payer\": \"user@domain\"}");
+  addDetail("{\"payer\": \"superuser@domain\"}");
+
+  iroha::protocol::Block_v1 block;
+  block.mutable_payload()->set_height(1);
+  block.mutable_payload()->set_tx_number(1);
+  *block.mutable_payload()->add_transactions() = tx;
+
+  auto block_query = std::make_shared<iroha::ametsuchi::MockBlockQuery>();
+  EXPECT_CALL(*query_processor, blocksQueryHandle(_))
+      .WillOnce(Return(iroha::expected::makeValue()));
+  EXPECT_CALL(*block_query_factory, createBlockQuery())
+      .WillOnce(Return(boost::make_optional<
+                       std::shared_ptr<iroha::ametsuchi::BlockQuery>>(
+          block_query)));
+  EXPECT_CALL(*block_query, getTopBlockHeight()).WillOnce(Return(1));
+  EXPECT_CALL(*block_query, getBlock(1))
+      .WillOnce(Return(ByMove(iroha::expected::makeValue(
+          std::unique_ptr<shared_model::interface::Block>(
+              std::make_unique<shared_model::proto::Block>(block))))));
+
+  auto client = torii_utils::QuerySyncClient(stub_);
+  auto responses = client.FetchCommits(blocks_query.getTransport());
+
+  ASSERT_EQ(responses.size(), 1);
+  auto const &scoped = responses.at(0).block_response().block().block_v1();
+  ASSERT_EQ(scoped.payload().transactions_size(), 1);
+  auto const &reduced =
+      scoped.payload().transactions(0).payload().reduced_payload();
+  EXPECT_EQ(reduced.creator_account_id(), "other@domain");
+  EXPECT_EQ(reduced.quorum(), 0);
+  ASSERT_EQ(reduced.commands_size(), 2);
+  EXPECT_EQ(reduced.commands(0).transfer_asset().dest_account_id(),
+            "user@domain");
+  EXPECT_EQ(reduced.commands(1).set_account_detail().value(),
+            "{\"payer\": \"user@domain\"}");
+}
+
+/**
+ * @given paginated query over two pages
+ * @when the query is streamed
+ * @then both pages are received one by one, the second one is requested
+ * starting from the next asset of the first page
+ */
+TEST_F(ToriiQueryServiceTest, FindStreamPages) {
+  auto query = shared_model::proto::QueryBuilder()
+                   .creatorAccountId("user@domain")
+                   .queryCounter(1)
+                   .createdTime(iroha::time::now())
+                   .getAccountAssets("user@domain", 1, std::nullopt)
+                   .build()
+                   .signAndAddSignature(keypair)
+                   .finish();
+
+  shared_model::proto::ProtoQueryResponseFactory response_factory;
+  using PageResult = iroha::expected::
+      Result<std::unique_ptr<shared_model::interface::QueryResponse>,
+             std::string>;
+  auto page = [&](std::string asset_id,
+                  std::optional<std::string> next) -> PageResult {
+    return response_factory.createAccountAssetResponse(
+        {{"user@domain", asset_id, shared_model::interface::Amount{"1"}}},
+        2,
+        next,
+        query.hash());
+  };
+  auto first_asset_id = [](auto const &handled) {
+    return static_cast<shared_model::proto::Query const &>(handled)
+        .getTransport()
+        .payload()
+        .get_account_assets()
+        .pagination_meta()
+        .first_asset_id();
+  };
+
+  ::testing::InSequence seq;
+  EXPECT_CALL(*query_processor,
+              queryHandle(Truly([&](auto const &handled) {
+                            return first_asset_id(handled).empty();
+                          }),
+                          _))
+      .WillOnce(Return(ByMove(page("a#domain", "b#domain"))));
+  EXPECT_CALL(*query_processor,
+              queryHandle(Truly([&](auto const &handled) {
+                            return first_asset_id(handled) == "b#domain";
+                          }),
+                          _))
+      .WillOnce(Return(ByMove(page("b#domain", std::nullopt))));
+
+  std::vector<std::string> assets;
+  auto status = torii_utils::QuerySyncClient(stub_).FindStream(
+      query.getTransport(), [&](auto const &response) {
+        for (auto const &asset :
+             response.account_assets_response().account_assets()) {
+          assets.push_back(asset.asset_id());
+        }
+        return true;
+      });
+
+  ASSERT_TRUE(status.ok());
+  EXPECT_THAT(assets, ::testing::ElementsAre("a#domain", "b#domain"));
+}
+
+/**
+ * Torii query service, which has no room for blocks of slow subscribers
+ */
+class ToriiQueryServiceOverflowTest : public ToriiQueryServiceTest {
+ public:
+  void SetUp() override {
+    block_stream_limits.max_pending = 0;
+    ToriiQueryServiceTest::SetUp();
+  }
+
+  std::vector<iroha::protocol::BlockQueryResponse> fetchCommits() {
+    auto blocks_query = shared_model::proto::BlocksQueryBuilder()
+                            .creatorAccountId("user@domain")
+                            .createdTime(iroha::time::now())
+                            .queryCounter(1)
+                            .build()
+                            .signAndAddSignature(keypair)
+                            .finish();
+    EXPECT_CALL(*query_processor, blocksQueryHandle(_))
+        .WillOnce(Return(iroha::expected::makeValue()));
+    return torii_utils::QuerySyncClient(stub_).FetchCommits(
+        blocks_query.getTransport());
+  }
+};
+
+/**
+ * @given query service closing the streams of slow subscribers
+ * @when a block does not fit into the buffer of the subscriber
+ * @then the stream is closed with an error telling the height to resume from
+ */
+TEST_F(ToriiQueryServiceOverflowTest, Disconnect) {
+  auto responses = fetchCommits();
+
+  ASSERT_EQ(responses.size(), 1);
+  ASSERT_TRUE(responses.at(0).has_block_error_response());
+  EXPECT_THAT(responses.at(0).block_error_response().message(),
+              ::testing::HasSubstr("from_height 123"));
+}
+
+/**
+ * Torii query service, which skips the blocks of slow subscribers
+ */
+class ToriiQueryServiceDropTest : public ToriiQueryServiceOverflowTest {
+ public:
+  void SetUp() override {
+    block_stream_limits.drop_on_overflow = true;
+    ToriiQueryServiceOverflowTest::SetUp();
+  }
+};
+
+/**
+ * @given query service skipping the blocks of slow subscribers
+ * @when a block does not fit into the buffer of the subscriber
+ * @then the block is not sent
+ */
+TEST_F(ToriiQueryServiceDropTest, Drop) {
+  EXPECT_TRUE(fetchCommits().empty());
+}
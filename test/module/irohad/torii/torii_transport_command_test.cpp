@@ -20,6 +20,7 @@
 #include "interfaces/iroha_internal/transaction_batch.hpp"
 #include "interfaces/iroha_internal/transaction_batch_factory_impl.hpp"
 #include "interfaces/iroha_internal/transaction_batch_parser_impl.hpp"
+#include "main/memory_budget.hpp"
 #include "main/subscription.hpp"
 #include "module/irohad/network/network_mocks.hpp"
 #include "module/irohad/torii/torii_mocks.hpp"
@@ -279,6 +280,40 @@ TEST_F(CommandServiceTransportGrpcTest, ListToriiInvalid) {
   transport_grpc->ListTorii(&context, &request, &response);
 }
 
+/**
+ * @given torii service with the transactions memory limit reached
+ * @when calling ListTorii
+ * @then the call fails with RESOURCE_EXHAUSTED
+ *       and the transactions are neither validated nor handled
+ */
+TEST_F(CommandServiceTransportGrpcTest, ListToriiMemoryLimitReached) {
+  auto budget = std::make_shared<iroha::MemoryBudget>(
+      iroha::MemoryBudget::Limits{1024, {}});
+  budget->set(iroha::MemoryBudget::Buffer::kTransactions, 1024);
+  transport_grpc = std::make_shared<CommandServiceTransportGrpc>(
+      command_service,
+      status_bus,
+      status_factory,
+      transaction_factory,
+      batch_parser,
+      batch_factory,
+      gate_objects.size(),
+      getTestLogger("CommandServiceTransportGrpc"),
+      budget);
+
+  grpc::ServerContext context;
+  google::protobuf::Empty response;
+  iroha::protocol::TxList request;
+  request.add_transactions();
+
+  EXPECT_CALL(*proto_tx_validator, validate(_)).Times(0);
+  EXPECT_CALL(*command_service, handleTransactionBatch(_)).Times(0);
+  EXPECT_CALL(*status_bus, publish(_)).Times(0);
+
+  auto status = transport_grpc->ListTorii(&context, &request, &response);
+  EXPECT_EQ(status.error_code(), grpc::StatusCode::RESOURCE_EXHAUSTED);
+}
+
 /**
  * @given torii service
  *        and some number of valid transactions
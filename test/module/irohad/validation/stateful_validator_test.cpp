@@ -259,6 +259,53 @@ TEST_F(Validator, SomeTxsFail) {
             sample_error_extra);
 }
 
+/**
+ * @given proposal with a transaction valid only after the proposal creation
+ * time and a transaction valid since it
+ * @when statefully validating the proposal
+ * @then the early transaction is rejected without being applied @and the
+ * other one appears in verified proposal
+ */
+TEST_F(Validator, RejectsTxsBeforeNotBefore) {
+  auto const created_time = iroha::time::now();
+  std::vector<shared_model::proto::Transaction> txs;
+  txs.push_back(TestTransactionBuilder()
+                    .creatorAccountId("doge@master")
+                    .createdTime(created_time)
+                    .notBefore(created_time + 1)
+                    .quorum(1)
+                    .createAsset("doge", "coin", 1)
+                    .build());
+  txs.push_back(TestTransactionBuilder()
+                    .creatorAccountId("doge@master")
+                    .createdTime(created_time)
+                    .notBefore(created_time)
+                    .quorum(1)
+                    .createAsset("cate", "coin", 1)
+                    .build());
+  auto proposal = TestProposalBuilder()
+                      .createdTime(created_time)
+                      .height(3)
+                      .transactions(txs)
+                      .build();
+
+  EXPECT_CALL(*temp_wsv_mock, apply(Eq(ByRef(txs.at(0))))).Times(0);
+  EXPECT_CALL(*temp_wsv_mock, apply(Eq(ByRef(txs.at(1)))))
+      .WillOnce(Return(iroha::expected::Value<void>({})));
+
+  auto verified_proposal_and_errors = sfv->validate(proposal, *temp_wsv_mock);
+  auto const &verified_txs =
+      verified_proposal_and_errors->verified_proposal->transactions();
+  ASSERT_EQ(verified_txs.size(), 1);
+  EXPECT_EQ(verified_txs.begin()->hash(), txs.at(1).hash());
+  ASSERT_EQ(verified_proposal_and_errors->rejected_transactions.size(), 1);
+  auto const &rejected =
+      verified_proposal_and_errors->rejected_transactions.front();
+  EXPECT_EQ(rejected.tx_hash, txs.at(0).hash());
+  EXPECT_EQ(rejected.error.name, "NotBefore");
+  EXPECT_EQ(rejected.error.error_code, 3);
+}
+
 /**
  * @given two atomic batches @and one ordered @and several single transactions
  * @when failing one of the atomic batched @and transaction from ordered batch
@@ -127,6 +127,14 @@ TYPED_TEST(KeyManager, CreateAndLoadEncryptedInvalidKey) {
   IROHA_ASSERT_RESULT_ERROR(this->manager.loadKeys(this->passphrase + "123"));
 }
 
+TYPED_TEST(KeyManager, ImportAndLoadEncrypted) {
+  ASSERT_TRUE(this->manager.importKeys(this->keypair, this->passphrase));
+  auto keypair = this->manager.loadKeys(this->passphrase);
+  IROHA_ASSERT_RESULT_VALUE(keypair);
+  EXPECT_EQ(keypair.assumeValue().publicKey(), this->pubkey);
+  EXPECT_EQ(keypair.assumeValue().privateKey().hex(), this->prikey);
+}
+
 TYPED_TEST(KeyManager, LoadInaccessiblePubkey) {
   create_file(this->pub_key_path, this->pubkey);
   create_file(this->pri_key_path, this->prikey);
@@ -5,6 +5,7 @@
 
 #include <gtest/gtest.h>
 #include <datetime/time.hpp>
+#include "framework/mock_time.hpp"
 
 TEST(Time, Now) {
   auto time = iroha::time::now();
@@ -13,3 +14,33 @@ TEST(Time, Now) {
   ASSERT_GT(time, 1497447645000);  // 06/14/2017 @ 1:40pm (UTC)
   ASSERT_EQ(sizeof(time), 8);
 }
+
+/**
+ * @given mock time
+ * @when it is advanced
+ * @then the current time moves by the duration only
+ */
+TEST(Time, MockTime) {
+  using namespace std::chrono_literals;
+  framework::MockTime mock_time;
+  auto start = iroha::time::now();
+  EXPECT_EQ(iroha::time::now(), start);
+  mock_time.advance(10min);
+  EXPECT_EQ(iroha::time::now(), start + 600000);
+  EXPECT_EQ(iroha::time::now(1s), start + 601000);
+}
+
+/**
+ * @given mock time
+ * @when it is destroyed
+ * @then the system clock is used again
+ */
+TEST(Time, MockTimeRestoresSystemClock) {
+  using namespace std::chrono_literals;
+  {
+    framework::MockTime mock_time;
+    mock_time.set(std::chrono::system_clock::time_point(1h));
+    EXPECT_EQ(iroha::time::now(), 3600000);
+  }
+  ASSERT_GT(iroha::time::now(), 1497447645000);
+}
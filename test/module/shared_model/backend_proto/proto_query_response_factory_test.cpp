@@ -316,7 +316,8 @@ TEST_F(ProtoQueryResponseFactoryTest, CreateTransactionsResponse) {
 
 /**
  * Checks createTransactionsPageResponse method of QueryResponseFactory
- * @given collection of transactions, next tx hash and transactions number
+ * @given collection of transactions, next tx hash, transactions number and
+ * the fee of a transfer
  * @when creating transactions page response via factory
  * @then that response is created @and is well-formed
  */
@@ -336,8 +337,16 @@ TEST_F(ProtoQueryResponseFactoryTest, CreateTransactionsPageResponse) {
     transactions.push_back(std::move(tx));
     transactions_test_copy.push_back(std::move(tx_copy));
   }
+  const shared_model::interface::TransactionsPageResponse::TransferFee kFee{
+      transactions_test_copy[1]->hash(),
+      2,
+      shared_model::interface::Amount{"0.25"}};
   auto query_response = response_factory->createTransactionsPageResponse(
-      std::move(transactions), kNextTxHash, kTransactionsNumber, kQueryHash);
+      std::move(transactions),
+      kNextTxHash,
+      kTransactionsNumber,
+      {kFee},
+      kQueryHash);
 
   ASSERT_TRUE(query_response);
   EXPECT_EQ(query_response->queryHash(), kQueryHash);
@@ -353,6 +362,8 @@ TEST_F(ProtoQueryResponseFactoryTest, CreateTransactionsPageResponse) {
     }
     ASSERT_TRUE(response.nextTxHash());
     EXPECT_EQ(response.nextTxHash().value(), kNextTxHash);
+    ASSERT_EQ(response.transferFees().size(), 1);
+    EXPECT_EQ(response.transferFees()[0], kFee);
   });
 }
 
@@ -379,7 +390,11 @@ TEST_F(ProtoQueryResponseFactoryTest,
     transactions_test_copy.push_back(std::move(tx_copy));
   }
   auto query_response = response_factory->createTransactionsPageResponse(
-      std::move(transactions), std::nullopt, kTransactionsNumber, kQueryHash);
+      std::move(transactions),
+      std::nullopt,
+      kTransactionsNumber,
+      {},
+      kQueryHash);
 
   ASSERT_TRUE(query_response);
   EXPECT_EQ(query_response->queryHash(), kQueryHash);
@@ -394,6 +409,7 @@ TEST_F(ProtoQueryResponseFactoryTest,
                 transactions_test_copy[i]->creatorAccountId());
     }
     EXPECT_FALSE(response.nextTxHash());
+    EXPECT_TRUE(response.transferFees().empty());
   });
 }
 
@@ -13,22 +13,36 @@
 #include "interfaces/commands/add_peer.hpp"
 #include "interfaces/commands/add_signatory.hpp"
 #include "interfaces/commands/append_role.hpp"
+#include "interfaces/commands/archive_domain.hpp"
+#include "interfaces/commands/authorize_session_key.hpp"
+#include "interfaces/commands/claim_with_preimage.hpp"
 #include "interfaces/commands/command.hpp"
 #include "interfaces/commands/compare_and_set_account_detail.hpp"
 #include "interfaces/commands/create_account.hpp"
 #include "interfaces/commands/create_asset.hpp"
 #include "interfaces/commands/create_domain.hpp"
 #include "interfaces/commands/create_role.hpp"
+#include "interfaces/commands/deactivate_account.hpp"
 #include "interfaces/commands/detach_role.hpp"
 #include "interfaces/commands/grant_permission.hpp"
+#include "interfaces/commands/grant_vested_asset.hpp"
+#include "interfaces/commands/include_role.hpp"
+#include "interfaces/commands/lock_asset.hpp"
+#include "interfaces/commands/propose_setting_value.hpp"
+#include "interfaces/commands/recover_account.hpp"
+#include "interfaces/commands/refund_after_timeout.hpp"
 #include "interfaces/commands/remove_peer.hpp"
 #include "interfaces/commands/remove_signatory.hpp"
 #include "interfaces/commands/revoke_permission.hpp"
+#include "interfaces/commands/rotate_key.hpp"
 #include "interfaces/commands/set_account_detail.hpp"
+#include "interfaces/commands/set_account_detail_at_path.hpp"
 #include "interfaces/commands/set_quorum.hpp"
 #include "interfaces/commands/set_setting_value.hpp"
+#include "interfaces/commands/set_spending_limit.hpp"
 #include "interfaces/commands/subtract_asset_quantity.hpp"
 #include "interfaces/commands/transfer_asset.hpp"
+#include "interfaces/commands/vote_for_proposal.hpp"
 
 using testing::Return;
 
@@ -62,6 +76,30 @@ namespace shared_model {
       MOCK_CONST_METHOD0(roleName, const types::RoleIdType &());
     };
 
+    struct MockArchiveDomain : public shared_model::interface::ArchiveDomain {
+      MOCK_CONST_METHOD0(domainId, const types::DomainIdType &());
+    };
+
+    struct MockAuthorizeSessionKey
+        : public shared_model::interface::AuthorizeSessionKey {
+      MockAuthorizeSessionKey() {
+        ON_CALL(*this, toString())
+            .WillByDefault(Return("MockAuthorizeSessionKey"));
+      }
+
+      MOCK_CONST_METHOD0(accountId, const types::AccountIdType &());
+      MOCK_CONST_METHOD0(pubkey, const std::string &());
+      MOCK_CONST_METHOD0(permissions, const RolePermissionSet &());
+      MOCK_CONST_METHOD0(allowedAssetId, const types::AssetIdType &());
+      MOCK_CONST_METHOD0(validity, types::HeightType());
+      MOCK_CONST_METHOD0(toString, std::string());
+    };
+
+    struct MockClaimWithPreimage
+        : public shared_model::interface::ClaimWithPreimage {
+      MOCK_CONST_METHOD0(preimage, const std::string &());
+    };
+
     struct MockCreateAccount : public shared_model::interface::CreateAccount {
       MOCK_CONST_METHOD0(accountName, const types::AccountNameType &());
       MOCK_CONST_METHOD0(domainId, const types::DomainIdType &());
@@ -89,6 +127,11 @@ namespace shared_model {
       MOCK_CONST_METHOD0(toString, std::string());
     };
 
+    struct MockDeactivateAccount
+        : public shared_model::interface::DeactivateAccount {
+      MOCK_CONST_METHOD0(accountId, const types::AccountIdType &());
+    };
+
     struct MockDetachRole : public shared_model::interface::DetachRole {
       MOCK_CONST_METHOD0(accountId, const types::AccountIdType &());
       MOCK_CONST_METHOD0(roleName, const types::RoleIdType &());
@@ -106,6 +149,40 @@ namespace shared_model {
       MOCK_CONST_METHOD0(toString, std::string());
     };
 
+    struct MockGrantVestedAsset
+        : public shared_model::interface::GrantVestedAsset {
+      MOCK_CONST_METHOD0(destAccountId, const types::AccountIdType &());
+      MOCK_CONST_METHOD0(assetId, const types::AssetIdType &());
+      MOCK_CONST_METHOD0(amount, const Amount &());
+      MOCK_CONST_METHOD0(cliff, types::HeightType());
+      MOCK_CONST_METHOD0(duration, types::HeightType());
+    };
+
+    struct MockIncludeRole : public shared_model::interface::IncludeRole {
+      MOCK_CONST_METHOD0(roleName, const types::RoleIdType &());
+      MOCK_CONST_METHOD0(includedRoleName, const types::RoleIdType &());
+    };
+
+    struct MockLockAsset : public shared_model::interface::LockAsset {
+      MOCK_CONST_METHOD0(srcAccountId, const types::AccountIdType &());
+      MOCK_CONST_METHOD0(destAccountId, const types::AccountIdType &());
+      MOCK_CONST_METHOD0(assetId, const types::AssetIdType &());
+      MOCK_CONST_METHOD0(amount, const Amount &());
+      MOCK_CONST_METHOD0(hash, const std::string &());
+      MOCK_CONST_METHOD0(timeout, types::HeightType());
+    };
+
+    struct MockRecoverAccount
+        : public shared_model::interface::RecoverAccount {
+      MOCK_CONST_METHOD0(accountId, const types::AccountIdType &());
+      MOCK_CONST_METHOD0(pubkey, const std::string &());
+    };
+
+    struct MockRefundAfterTimeout
+        : public shared_model::interface::RefundAfterTimeout {
+      MOCK_CONST_METHOD0(hash, const std::string &());
+    };
+
     struct MockRemoveSignatory
         : public shared_model::interface::RemoveSignatory {
       MOCK_CONST_METHOD0(accountId, const types::AccountIdType &());
@@ -124,6 +201,13 @@ namespace shared_model {
       MOCK_CONST_METHOD0(toString, std::string());
     };
 
+    struct MockRotateKey : public shared_model::interface::RotateKey {
+      MOCK_CONST_METHOD0(accountId, const types::AccountIdType &());
+      MOCK_CONST_METHOD0(oldPubkey, const std::string &());
+      MOCK_CONST_METHOD0(newPubkey, const std::string &());
+      MOCK_CONST_METHOD0(gracePeriod, types::HeightType());
+    };
+
     struct MockSetAccountDetail
         : public shared_model::interface::SetAccountDetail {
       MOCK_CONST_METHOD0(accountId, const types::AccountIdType &());
@@ -131,11 +215,29 @@ namespace shared_model {
       MOCK_CONST_METHOD0(value, const types::AccountDetailValueType &());
     };
 
+    struct MockSetAccountDetailAtPath
+        : public shared_model::interface::SetAccountDetailAtPath {
+      MOCK_CONST_METHOD0(accountId, const types::AccountIdType &());
+      MOCK_CONST_METHOD0(key, const types::AccountDetailKeyType &());
+      MOCK_CONST_METHOD0(path, const std::string &());
+      MOCK_CONST_METHOD0(value, const types::AccountDetailValueType &());
+      MOCK_CONST_METHOD0(oldValue,
+                         const std::optional<types::AccountDetailValueType>());
+    };
+
     struct MockSetQuorum : public shared_model::interface::SetQuorum {
       MOCK_CONST_METHOD0(accountId, const types::AccountIdType &());
       MOCK_CONST_METHOD0(newQuorum, types::QuorumType());
     };
 
+    struct MockSetSpendingLimit
+        : public shared_model::interface::SetSpendingLimit {
+      MOCK_CONST_METHOD0(accountId, const types::AccountIdType &());
+      MOCK_CONST_METHOD0(assetId, const types::AssetIdType &());
+      MOCK_CONST_METHOD0(amount, const Amount &());
+      MOCK_CONST_METHOD0(window, types::HeightType());
+    };
+
     struct MockSubtractAssetQuantity
         : public shared_model::interface::SubtractAssetQuantity {
       MOCK_CONST_METHOD0(assetId, const types::AssetIdType &());
@@ -165,6 +267,18 @@ namespace shared_model {
       MOCK_CONST_METHOD0(key, const types::SettingKeyType &());
       MOCK_CONST_METHOD0(value, const types::SettingValueType &());
     };
+
+    struct MockProposeSettingValue
+        : public shared_model::interface::ProposeSettingValue {
+      MOCK_CONST_METHOD0(proposalId, const types::ProposalIdType &());
+      MOCK_CONST_METHOD0(settingKey, const types::SettingKeyType &());
+      MOCK_CONST_METHOD0(settingValue, const types::SettingValueType &());
+    };
+
+    struct MockVoteForProposal
+        : public shared_model::interface::VoteForProposal {
+      MOCK_CONST_METHOD0(proposalId, const types::ProposalIdType &());
+    };
   }  // namespace interface
 }  // namespace shared_model
 
@@ -60,3 +60,46 @@ TEST_F(AmountTest, Invalid) {
   checkInvalid(Amount{"."});
   checkInvalid(Amount{""});
 }
+
+TEST_F(AmountTest, CheckedAdd) {
+  auto sum = Amount{"1.5"}.checkedAdd(Amount{"2.25"});
+  EXPECT_FALSE(sum);
+  sum = Amount{"2.25"}.checkedAdd(Amount{"1.5"});
+  ASSERT_TRUE(sum);
+  checkValid(*sum, 1, 2, "3.75");
+
+  auto const half = std::string(77, '9');
+  EXPECT_FALSE(Amount{half}.checkedAdd(Amount{half}));
+  EXPECT_FALSE(Amount{"1"}.checkedAdd(Amount{""}));
+  EXPECT_FALSE(Amount{""}.checkedAdd(Amount{"1"}));
+}
+
+TEST_F(AmountTest, CheckedSub) {
+  auto difference = Amount{"2.25"}.checkedSub(Amount{"1.5"});
+  ASSERT_TRUE(difference);
+  checkValid(*difference, 1, 2, "0.75");
+
+  EXPECT_FALSE(Amount{"1.5"}.checkedSub(Amount{"2.25"}));
+  EXPECT_FALSE(Amount{"1.50"}.checkedSub(Amount{"2.25"}));
+  EXPECT_FALSE(Amount{"1"}.checkedSub(Amount{""}));
+}
+
+TEST_F(AmountTest, SaturatingAdd) {
+  checkValid(Amount{"2.25"}.saturatingAdd(Amount{"1.5"}), 1, 2, "3.75");
+
+  auto const half = std::string(77, '9');
+  auto const max = Amount{half}.saturatingAdd(Amount{half});
+  EXPECT_FALSE(max.isNaN());
+  EXPECT_EQ(max.precision(), 0);
+  EXPECT_FALSE(max.checkedAdd(Amount{"1"}));
+
+  checkInvalid(Amount{"1.5"}.saturatingAdd(Amount{"2.25"}));
+}
+
+TEST_F(AmountTest, SaturatingSub) {
+  checkValid(Amount{"2.25"}.saturatingSub(Amount{"1.5"}), 1, 2, "0.75");
+  checkValid(Amount{"1.50"}.saturatingSub(Amount{"2.25"}), 0, 2, "0.00");
+
+  checkInvalid(Amount{"1.5"}.saturatingSub(Amount{"2.25"}));
+  checkInvalid(Amount{""}.saturatingSub(Amount{"1"}));
+}
@@ -50,6 +50,10 @@ struct MockTransaction : public shared_model::interface::Transaction {
   MOCK_CONST_METHOD0(creatorAccountId,
                      const shared_model::interface::types::AccountIdType &());
   MOCK_CONST_METHOD0(quorum, shared_model::interface::types::QuorumType());
+  MOCK_CONST_METHOD0(notBefore,
+                     shared_model::interface::types::TimestampType());
+  MOCK_CONST_METHOD0(idempotencyKey, const std::string &());
+  MOCK_CONST_METHOD0(chainId, const std::string &());
   MOCK_CONST_METHOD0(commands, CommandsType());
   MOCK_CONST_METHOD0(reducedHash,
                      const shared_model::interface::types::HashType &());
@@ -87,6 +87,52 @@ namespace shared_model {
           });
     }
 
+    MockCommandFactory::FactoryResult<MockArchiveDomain>
+    MockCommandFactory::constructArchiveDomain(
+        const types::DomainIdType &domain_id) const {
+      return createFactoryResult<MockArchiveDomain>(
+          [&domain_id](FactoryResult<MockArchiveDomain> specific_cmd_mock) {
+            EXPECT_CALL(*specific_cmd_mock, domainId())
+                .WillRepeatedly(ReturnRefOfCopy(domain_id));
+            return specific_cmd_mock;
+          });
+    }
+
+    MockCommandFactory::FactoryResult<MockAuthorizeSessionKey>
+    MockCommandFactory::constructAuthorizeSessionKey(
+        const types::AccountIdType &account_id,
+        types::PublicKeyHexStringView pubkey,
+        const RolePermissionSet &permissions,
+        const types::AssetIdType &allowed_asset_id,
+        types::HeightType validity) const {
+      return createFactoryResult<MockAuthorizeSessionKey>(
+          [&account_id, &pubkey, &permissions, &allowed_asset_id, validity](
+              FactoryResult<MockAuthorizeSessionKey> specific_cmd_mock) {
+            EXPECT_CALL(*specific_cmd_mock, accountId())
+                .WillRepeatedly(ReturnRefOfCopy(account_id));
+            EXPECT_CALL(*specific_cmd_mock, pubkey())
+                .WillRepeatedly(ReturnRefOfCopy(std::string{pubkey}));
+            EXPECT_CALL(*specific_cmd_mock, permissions())
+                .WillRepeatedly(ReturnRefOfCopy(permissions));
+            EXPECT_CALL(*specific_cmd_mock, allowedAssetId())
+                .WillRepeatedly(ReturnRefOfCopy(allowed_asset_id));
+            EXPECT_CALL(*specific_cmd_mock, validity())
+                .WillRepeatedly(Return(validity));
+            return specific_cmd_mock;
+          });
+    }
+
+    MockCommandFactory::FactoryResult<MockClaimWithPreimage>
+    MockCommandFactory::constructClaimWithPreimage(
+        const std::string &preimage) const {
+      return createFactoryResult<MockClaimWithPreimage>(
+          [&preimage](FactoryResult<MockClaimWithPreimage> specific_cmd_mock) {
+            EXPECT_CALL(*specific_cmd_mock, preimage())
+                .WillRepeatedly(ReturnRefOfCopy(preimage));
+            return specific_cmd_mock;
+          });
+    }
+
     MockCommandFactory::FactoryResult<MockCreateAccount>
     MockCommandFactory::constructCreateAccount(
         const types::AccountNameType &account_name,
@@ -161,6 +207,18 @@ namespace shared_model {
           });
     }
 
+    MockCommandFactory::FactoryResult<MockDeactivateAccount>
+    MockCommandFactory::constructDeactivateAccount(
+        const types::AccountIdType &account_id) const {
+      return createFactoryResult<MockDeactivateAccount>(
+          [&account_id](
+              FactoryResult<MockDeactivateAccount> specific_cmd_mock) {
+            EXPECT_CALL(*specific_cmd_mock, accountId())
+                .WillRepeatedly(ReturnRefOfCopy(account_id));
+            return specific_cmd_mock;
+          });
+    }
+
     MockCommandFactory::FactoryResult<MockDetachRole>
     MockCommandFactory::constructDetachRole(
         const types::AccountIdType &account_id,
@@ -200,6 +258,102 @@ namespace shared_model {
           });
     }
 
+    MockCommandFactory::FactoryResult<MockGrantVestedAsset>
+    MockCommandFactory::constructGrantVestedAsset(
+        const types::AccountIdType &dest_account_id,
+        const types::AssetIdType &asset_id,
+        const Amount &amount,
+        types::HeightType cliff,
+        types::HeightType duration) const {
+      return createFactoryResult<MockGrantVestedAsset>(
+          [&dest_account_id, &asset_id, &amount, cliff, duration](
+              FactoryResult<MockGrantVestedAsset> specific_cmd_mock) {
+            EXPECT_CALL(*specific_cmd_mock, destAccountId())
+                .WillRepeatedly(ReturnRefOfCopy(dest_account_id));
+            EXPECT_CALL(*specific_cmd_mock, assetId())
+                .WillRepeatedly(ReturnRefOfCopy(asset_id));
+            EXPECT_CALL(*specific_cmd_mock, amount())
+                .WillRepeatedly(ReturnRefOfCopy(amount));
+            EXPECT_CALL(*specific_cmd_mock, cliff())
+                .WillRepeatedly(Return(cliff));
+            EXPECT_CALL(*specific_cmd_mock, duration())
+                .WillRepeatedly(Return(duration));
+            return specific_cmd_mock;
+          });
+    }
+
+    MockCommandFactory::FactoryResult<MockIncludeRole>
+    MockCommandFactory::constructIncludeRole(
+        const types::RoleIdType &role_name,
+        const types::RoleIdType &included_role_name) const {
+      return createFactoryResult<MockIncludeRole>(
+          [&role_name, &included_role_name](
+              FactoryResult<MockIncludeRole> specific_cmd_mock) {
+            EXPECT_CALL(*specific_cmd_mock, roleName())
+                .WillRepeatedly(ReturnRefOfCopy(role_name));
+            EXPECT_CALL(*specific_cmd_mock, includedRoleName())
+                .WillRepeatedly(ReturnRefOfCopy(included_role_name));
+            return specific_cmd_mock;
+          });
+    }
+
+    MockCommandFactory::FactoryResult<MockLockAsset>
+    MockCommandFactory::constructLockAsset(
+        const types::AccountIdType &src_account_id,
+        const types::AccountIdType &dest_account_id,
+        const types::AssetIdType &asset_id,
+        const Amount &amount,
+        const std::string &hash,
+        types::HeightType timeout) const {
+      return createFactoryResult<MockLockAsset>(
+          [&src_account_id,
+           &dest_account_id,
+           &asset_id,
+           &amount,
+           &hash,
+           timeout](FactoryResult<MockLockAsset> specific_cmd_mock) {
+            EXPECT_CALL(*specific_cmd_mock, srcAccountId())
+                .WillRepeatedly(ReturnRefOfCopy(src_account_id));
+            EXPECT_CALL(*specific_cmd_mock, destAccountId())
+                .WillRepeatedly(ReturnRefOfCopy(dest_account_id));
+            EXPECT_CALL(*specific_cmd_mock, assetId())
+                .WillRepeatedly(ReturnRefOfCopy(asset_id));
+            EXPECT_CALL(*specific_cmd_mock, amount())
+                .WillRepeatedly(ReturnRefOfCopy(amount));
+            EXPECT_CALL(*specific_cmd_mock, hash())
+                .WillRepeatedly(ReturnRefOfCopy(hash));
+            EXPECT_CALL(*specific_cmd_mock, timeout())
+                .WillRepeatedly(Return(timeout));
+            return specific_cmd_mock;
+          });
+    }
+
+    MockCommandFactory::FactoryResult<MockRecoverAccount>
+    MockCommandFactory::constructRecoverAccount(
+        const types::AccountIdType &account_id,
+        types::PublicKeyHexStringView pubkey) const {
+      return createFactoryResult<MockRecoverAccount>(
+          [&account_id,
+           &pubkey](FactoryResult<MockRecoverAccount> specific_cmd_mock) {
+            EXPECT_CALL(*specific_cmd_mock, accountId())
+                .WillRepeatedly(ReturnRefOfCopy(account_id));
+            EXPECT_CALL(*specific_cmd_mock, pubkey())
+                .WillRepeatedly(ReturnRefOfCopy(std::string{pubkey}));
+            return specific_cmd_mock;
+          });
+    }
+
+    MockCommandFactory::FactoryResult<MockRefundAfterTimeout>
+    MockCommandFactory::constructRefundAfterTimeout(
+        const std::string &hash) const {
+      return createFactoryResult<MockRefundAfterTimeout>(
+          [&hash](FactoryResult<MockRefundAfterTimeout> specific_cmd_mock) {
+            EXPECT_CALL(*specific_cmd_mock, hash())
+                .WillRepeatedly(ReturnRefOfCopy(hash));
+            return specific_cmd_mock;
+          });
+    }
+
     MockCommandFactory::FactoryResult<MockRemoveSignatory>
     MockCommandFactory::constructRemoveSignatory(
         const types::AccountIdType &account_id,
@@ -239,6 +393,27 @@ namespace shared_model {
           });
     }
 
+    MockCommandFactory::FactoryResult<MockRotateKey>
+    MockCommandFactory::constructRotateKey(
+        const types::AccountIdType &account_id,
+        types::PublicKeyHexStringView old_pubkey,
+        types::PublicKeyHexStringView new_pubkey,
+        types::HeightType grace_period) const {
+      return createFactoryResult<MockRotateKey>(
+          [&account_id, &old_pubkey, &new_pubkey, grace_period](
+              FactoryResult<MockRotateKey> specific_cmd_mock) {
+            EXPECT_CALL(*specific_cmd_mock, accountId())
+                .WillRepeatedly(ReturnRefOfCopy(account_id));
+            EXPECT_CALL(*specific_cmd_mock, oldPubkey())
+                .WillRepeatedly(ReturnRefOfCopy(std::string{old_pubkey}));
+            EXPECT_CALL(*specific_cmd_mock, newPubkey())
+                .WillRepeatedly(ReturnRefOfCopy(std::string{new_pubkey}));
+            EXPECT_CALL(*specific_cmd_mock, gracePeriod())
+                .WillRepeatedly(Return(grace_period));
+            return specific_cmd_mock;
+          });
+    }
+
     MockCommandFactory::FactoryResult<MockSetAccountDetail>
     MockCommandFactory::constructSetAccountDetail(
         const types::AccountIdType &account_id,
@@ -257,6 +432,30 @@ namespace shared_model {
           });
     }
 
+    MockCommandFactory::FactoryResult<MockSetAccountDetailAtPath>
+    MockCommandFactory::constructSetAccountDetailAtPath(
+        const types::AccountIdType &account_id,
+        const types::AccountDetailKeyType &cmd_key,
+        const std::string &cmd_path,
+        const types::AccountDetailValueType &cmd_value,
+        const std::optional<types::AccountDetailValueType> &cmd_old_value)
+        const {
+      return createFactoryResult<MockSetAccountDetailAtPath>(
+          [&](FactoryResult<MockSetAccountDetailAtPath> specific_cmd_mock) {
+            EXPECT_CALL(*specific_cmd_mock, accountId())
+                .WillRepeatedly(ReturnRefOfCopy(account_id));
+            EXPECT_CALL(*specific_cmd_mock, key())
+                .WillRepeatedly(ReturnRefOfCopy(cmd_key));
+            EXPECT_CALL(*specific_cmd_mock, path())
+                .WillRepeatedly(ReturnRefOfCopy(cmd_path));
+            EXPECT_CALL(*specific_cmd_mock, value())
+                .WillRepeatedly(ReturnRefOfCopy(cmd_value));
+            EXPECT_CALL(*specific_cmd_mock, oldValue())
+                .WillRepeatedly(Return(cmd_old_value));
+            return specific_cmd_mock;
+          });
+    }
+
     MockCommandFactory::FactoryResult<MockSetQuorum>
     MockCommandFactory::constructSetQuorum(
         const types::AccountIdType &account_id,
@@ -272,6 +471,27 @@ namespace shared_model {
           });
     }
 
+    MockCommandFactory::FactoryResult<MockSetSpendingLimit>
+    MockCommandFactory::constructSetSpendingLimit(
+        const types::AccountIdType &account_id,
+        const types::AssetIdType &asset_id,
+        const Amount &amount,
+        types::HeightType window) const {
+      return createFactoryResult<MockSetSpendingLimit>(
+          [&account_id, &asset_id, &amount, window](
+              FactoryResult<MockSetSpendingLimit> specific_cmd_mock) {
+            EXPECT_CALL(*specific_cmd_mock, accountId())
+                .WillRepeatedly(ReturnRefOfCopy(account_id));
+            EXPECT_CALL(*specific_cmd_mock, assetId())
+                .WillRepeatedly(ReturnRefOfCopy(asset_id));
+            EXPECT_CALL(*specific_cmd_mock, amount())
+                .WillRepeatedly(ReturnRefOfCopy(amount));
+            EXPECT_CALL(*specific_cmd_mock, window())
+                .WillRepeatedly(Return(window));
+            return specific_cmd_mock;
+          });
+    }
+
     MockCommandFactory::FactoryResult<MockSubtractAssetQuantity>
     MockCommandFactory::constructSubtractAssetQuantity(
         const types::AssetIdType &asset_id, const Amount &cmd_amount) const {
@@ -352,5 +572,34 @@ namespace shared_model {
             return specific_cmd_mock;
           });
     };
+
+    MockCommandFactory::FactoryResult<MockProposeSettingValue>
+    MockCommandFactory::constructProposeSettingValue(
+        const types::ProposalIdType &proposal_id,
+        const types::SettingKeyType &setting_key,
+        const types::SettingValueType &setting_value) const {
+      return createFactoryResult<MockProposeSettingValue>(
+          [&proposal_id, &setting_key, &setting_value](
+              FactoryResult<MockProposeSettingValue> specific_cmd_mock) {
+            EXPECT_CALL(*specific_cmd_mock, proposalId())
+                .WillRepeatedly(ReturnRefOfCopy(proposal_id));
+            EXPECT_CALL(*specific_cmd_mock, settingKey())
+                .WillRepeatedly(ReturnRefOfCopy(setting_key));
+            EXPECT_CALL(*specific_cmd_mock, settingValue())
+                .WillRepeatedly(ReturnRefOfCopy(setting_value));
+            return specific_cmd_mock;
+          });
+    }
+
+    MockCommandFactory::FactoryResult<MockVoteForProposal>
+    MockCommandFactory::constructVoteForProposal(
+        const types::ProposalIdType &proposal_id) const {
+      return createFactoryResult<MockVoteForProposal>(
+          [&proposal_id](FactoryResult<MockVoteForProposal> specific_cmd_mock) {
+            EXPECT_CALL(*specific_cmd_mock, proposalId())
+                .WillRepeatedly(ReturnRefOfCopy(proposal_id));
+            return specific_cmd_mock;
+          });
+    }
   }  // namespace interface
 }  // namespace shared_model
@@ -60,6 +60,38 @@ namespace shared_model {
           const types::AccountIdType &account_id,
           const types::RoleIdType &role_name) const;
 
+      /**
+       * Construct a mocked ArchiveDomain
+       * @param domain_id to be in that command
+       * @return pointer to the created command
+       */
+      FactoryResult<MockArchiveDomain> constructArchiveDomain(
+          const types::DomainIdType &domain_id) const;
+
+      /**
+       * Construct a mocked AuthorizeSessionKey
+       * @param account_id to be in that command
+       * @param pubkey to be in that command
+       * @param permissions to be in that command
+       * @param allowed_asset_id to be in that command
+       * @param validity to be in that command
+       * @return pointer to the created command
+       */
+      FactoryResult<MockAuthorizeSessionKey> constructAuthorizeSessionKey(
+          const types::AccountIdType &account_id,
+          types::PublicKeyHexStringView pubkey,
+          const RolePermissionSet &permissions,
+          const types::AssetIdType &allowed_asset_id,
+          types::HeightType validity) const;
+
+      /**
+       * Construct a mocked ClaimWithPreimage
+       * @param preimage to be in that command
+       * @return pointer to the created command
+       */
+      FactoryResult<MockClaimWithPreimage> constructClaimWithPreimage(
+          const std::string &preimage) const;
+
       /**
        * Construct a mocked CreateAccount
        * @param account_name to be in that command
@@ -104,6 +136,14 @@ namespace shared_model {
           const types::RoleIdType &role_id,
           const RolePermissionSet &role_permissions) const;
 
+      /**
+       * Construct a mocked DeactivateAccount
+       * @param account_id to be in that command
+       * @return pointer to the created command
+       */
+      FactoryResult<MockDeactivateAccount> constructDeactivateAccount(
+          const types::AccountIdType &account_id) const;
+
       /**
        * Construct a mocked DetachRole
        * @param account_id to be in that command
@@ -124,6 +164,68 @@ namespace shared_model {
           const types::AccountIdType &account_id,
           permissions::Grantable permission) const;
 
+      /**
+       * Construct a mocked GrantVestedAsset
+       * @param dest_account_id to be in that command
+       * @param asset_id to be in that command
+       * @param amount to be in that command
+       * @param cliff to be in that command
+       * @param duration to be in that command
+       * @return pointer to the created command
+       */
+      FactoryResult<MockGrantVestedAsset> constructGrantVestedAsset(
+          const types::AccountIdType &dest_account_id,
+          const types::AssetIdType &asset_id,
+          const Amount &amount,
+          types::HeightType cliff,
+          types::HeightType duration) const;
+
+      /**
+       * Construct a mocked IncludeRole
+       * @param role_name to be in that command
+       * @param included_role_name to be in that command
+       * @return pointer to the created command
+       */
+      FactoryResult<MockIncludeRole> constructIncludeRole(
+          const types::RoleIdType &role_name,
+          const types::RoleIdType &included_role_name) const;
+
+      /**
+       * Construct a mocked LockAsset
+       * @param src_account_id to be in that command
+       * @param dest_account_id to be in that command
+       * @param asset_id to be in that command
+       * @param amount to be in that command
+       * @param hash to be in that command
+       * @param timeout to be in that command
+       * @return pointer to the created command
+       */
+      FactoryResult<MockLockAsset> constructLockAsset(
+          const types::AccountIdType &src_account_id,
+          const types::AccountIdType &dest_account_id,
+          const types::AssetIdType &asset_id,
+          const Amount &amount,
+          const std::string &hash,
+          types::HeightType timeout) const;
+
+      /**
+       * Construct a mocked RecoverAccount
+       * @param account_id to be in that command
+       * @param pubkey to be in that command
+       * @return pointer to the created command
+       */
+      FactoryResult<MockRecoverAccount> constructRecoverAccount(
+          const types::AccountIdType &account_id,
+          types::PublicKeyHexStringView pubkey) const;
+
+      /**
+       * Construct a mocked RefundAfterTimeout
+       * @param hash to be in that command
+       * @return pointer to the created command
+       */
+      FactoryResult<MockRefundAfterTimeout> constructRefundAfterTimeout(
+          const std::string &hash) const;
+
       /**
        * Construct a mocked RemoveSignatory
        * @param account_id to be in that command
@@ -144,6 +246,20 @@ namespace shared_model {
           const types::AccountIdType &account_id,
           permissions::Grantable permission) const;
 
+      /**
+       * Construct a mocked RotateKey
+       * @param account_id to be in that command
+       * @param old_pubkey to be in that command
+       * @param new_pubkey to be in that command
+       * @param grace_period to be in that command
+       * @return pointer to the created command
+       */
+      FactoryResult<MockRotateKey> constructRotateKey(
+          const types::AccountIdType &account_id,
+          types::PublicKeyHexStringView old_pubkey,
+          types::PublicKeyHexStringView new_pubkey,
+          types::HeightType grace_period) const;
+
       /**
        * Construct a mocked SetAccountDetail
        * @param account_id to be in that command
@@ -156,6 +272,23 @@ namespace shared_model {
           const types::AccountDetailKeyType &key,
           const types::AccountDetailValueType &value) const;
 
+      /**
+       * Construct a mocked SetAccountDetailAtPath
+       * @param account_id to be in that command
+       * @param key to be in that command
+       * @param path to be in that command
+       * @param value to be in that command
+       * @param old_value to be in that command
+       * @return pointer to the created command
+       */
+      FactoryResult<MockSetAccountDetailAtPath> constructSetAccountDetailAtPath(
+          const types::AccountIdType &account_id,
+          const types::AccountDetailKeyType &key,
+          const std::string &path,
+          const types::AccountDetailValueType &value,
+          const std::optional<types::AccountDetailValueType> &old_value =
+              std::nullopt) const;
+
       /**
        * Construct a mocked SetQuorum
        * @param account_id to be in that command
@@ -166,6 +299,20 @@ namespace shared_model {
           const types::AccountIdType &account_id,
           types::QuorumType quorum) const;
 
+      /**
+       * Construct a mocked SetSpendingLimit
+       * @param account_id to be in that command
+       * @param asset_id to be in that command
+       * @param amount to be in that command
+       * @param window to be in that command
+       * @return pointer to the created command
+       */
+      FactoryResult<MockSetSpendingLimit> constructSetSpendingLimit(
+          const types::AccountIdType &account_id,
+          const types::AssetIdType &asset_id,
+          const Amount &amount,
+          types::HeightType window) const;
+
       /**
        * Construct a mocked SubtractAssetQuantity
        * @param asset_id to be in that command
@@ -218,6 +365,26 @@ namespace shared_model {
           const types::SettingKeyType &key,
           const types::SettingValueType &value) const;
 
+      /**
+       * Construct a mocked ProposeSettingValue
+       * @param proposal_id to be in that command
+       * @param setting_key to be in that command
+       * @param setting_value to be in that command
+       * @return pointer to the created command
+       */
+      FactoryResult<MockProposeSettingValue> constructProposeSettingValue(
+          const types::ProposalIdType &proposal_id,
+          const types::SettingKeyType &setting_key,
+          const types::SettingValueType &setting_value) const;
+
+      /**
+       * Construct a mocked VoteForProposal
+       * @param proposal_id to be in that command
+       * @return pointer to the created command
+       */
+      FactoryResult<MockVoteForProposal> constructVoteForProposal(
+          const types::ProposalIdType &proposal_id) const;
+
      private:
       /**
        * Actually create a pointer to the mocked command
@@ -162,6 +162,57 @@ MockQueryFactory::constructGetPeers() const {
   return createFactoryResult<MockGetPeers>([](MockGetPeers &) {});
 };
 
+MockQueryFactory::FactoryResult<MockGetSettings>
+MockQueryFactory::constructGetSettings() const {
+  return createFactoryResult<MockGetSettings>([](MockGetSettings &) {});
+}
+
+MockQueryFactory::FactoryResult<MockGetAssetStats>
+MockQueryFactory::constructGetAssetStats(
+    const types::AssetIdType &asset_id) const {
+  return createFactoryResult<MockGetAssetStats>(
+      [&asset_id](MockGetAssetStats &mock) {
+        EXPECT_CALL(mock, assetId()).WillRepeatedly(ReturnRef(asset_id));
+      });
+}
+
+MockQueryFactory::FactoryResult<MockGetAccountPermissions>
+MockQueryFactory::constructGetAccountPermissions(
+    const types::AccountIdType &account_id,
+    const std::string &name_filter) const {
+  return createFactoryResult<MockGetAccountPermissions>(
+      [&account_id, &name_filter](MockGetAccountPermissions &mock) {
+        EXPECT_CALL(mock, accountId()).WillRepeatedly(ReturnRef(account_id));
+        EXPECT_CALL(mock, nameFilter())
+            .WillRepeatedly(ReturnRefOfCopy(name_filter));
+      });
+}
+
+MockQueryFactory::FactoryResult<MockVerifyBlob>
+MockQueryFactory::constructVerifyBlob(const types::AccountIdType &account_id,
+                                      const types::AccountIdType &writer,
+                                      const types::AccountDetailKeyType &key,
+                                      const std::string &blob,
+                                      const std::string &salt) const {
+  return createFactoryResult<MockVerifyBlob>(
+      [&account_id, &writer, &key, &blob, &salt](MockVerifyBlob &mock) {
+        EXPECT_CALL(mock, accountId()).WillRepeatedly(ReturnRef(account_id));
+        EXPECT_CALL(mock, writer()).WillRepeatedly(ReturnRef(writer));
+        EXPECT_CALL(mock, key()).WillRepeatedly(ReturnRef(key));
+        EXPECT_CALL(mock, blob()).WillRepeatedly(ReturnRef(blob));
+        EXPECT_CALL(mock, salt()).WillRepeatedly(ReturnRef(salt));
+      });
+}
+
+MockQueryFactory::FactoryResult<MockGetVestingSchedules>
+MockQueryFactory::constructGetVestingSchedules(
+    const types::AccountIdType &account_id) const {
+  return createFactoryResult<MockGetVestingSchedules>(
+      [&account_id](MockGetVestingSchedules &mock) {
+        EXPECT_CALL(mock, accountId()).WillRepeatedly(ReturnRef(account_id));
+      });
+}
+
 MockQueryFactory::FactoryResult<MockTxPaginationMeta>
 MockQueryFactory::constructTxPaginationMeta(
     types::TransactionsNumberType page_size,
@@ -70,6 +70,25 @@ namespace shared_model {
 
       FactoryResult<MockGetPeers> constructGetPeers() const;
 
+      FactoryResult<MockGetSettings> constructGetSettings() const;
+
+      FactoryResult<MockGetAssetStats> constructGetAssetStats(
+          const types::AssetIdType &asset_id) const;
+
+      FactoryResult<MockGetAccountPermissions> constructGetAccountPermissions(
+          const types::AccountIdType &account_id,
+          const std::string &name_filter = "") const;
+
+      FactoryResult<MockVerifyBlob> constructVerifyBlob(
+          const types::AccountIdType &account_id,
+          const types::AccountIdType &writer,
+          const types::AccountDetailKeyType &key,
+          const std::string &blob,
+          const std::string &salt) const;
+
+      FactoryResult<MockGetVestingSchedules> constructGetVestingSchedules(
+          const types::AccountIdType &account_id) const;
+
       FactoryResult<MockTxPaginationMeta> constructTxPaginationMeta(
           types::TransactionsNumberType page_size,
           std::optional<types::HashType> first_tx_hash,
@@ -10,23 +10,29 @@
 
 #include "interfaces/queries/asset_pagination_meta.hpp"
 #include "interfaces/queries/blocks_query.hpp"
+#include "interfaces/queries/estimate_fee.hpp"
 #include "interfaces/queries/get_account.hpp"
 #include "interfaces/queries/get_account_asset_transactions.hpp"
 #include "interfaces/queries/get_account_assets.hpp"
 #include "interfaces/queries/get_account_detail.hpp"
+#include "interfaces/queries/get_account_permissions.hpp"
 #include "interfaces/queries/get_account_transactions.hpp"
 #include "interfaces/queries/get_asset_info.hpp"
+#include "interfaces/queries/get_asset_stats.hpp"
 #include "interfaces/queries/get_block.hpp"
 #include "interfaces/queries/get_engine_receipts.hpp"
 #include "interfaces/queries/get_peers.hpp"
 #include "interfaces/queries/get_role_permissions.hpp"
 #include "interfaces/queries/get_roles.hpp"
+#include "interfaces/queries/get_settings.hpp"
 #include "interfaces/queries/get_signatories.hpp"
 #include "interfaces/queries/get_transactions.hpp"
+#include "interfaces/queries/get_vesting_schedules.hpp"
 #include "interfaces/queries/query.hpp"
 #include "interfaces/queries/query_payload_meta.hpp"
 #include "interfaces/queries/query_variant.hpp"
 #include "interfaces/queries/tx_pagination_meta.hpp"
+#include "interfaces/queries/verify_blob.hpp"
 
 namespace shared_model {
   namespace interface {
@@ -138,6 +144,42 @@ namespace shared_model {
       MOCK_CONST_METHOD0(clone, GetPeers *());
     };
 
+    struct MockGetSettings : public SpecificMockQuery<GetSettings> {
+      MOCK_CONST_METHOD0(clone, GetSettings *());
+    };
+
+    struct MockGetAssetStats : public SpecificMockQuery<GetAssetStats> {
+      MOCK_CONST_METHOD0(assetId, const types::AssetIdType &());
+      MOCK_CONST_METHOD0(clone, GetAssetStats *());
+    };
+
+    struct MockGetAccountPermissions
+        : public SpecificMockQuery<GetAccountPermissions> {
+      MOCK_CONST_METHOD0(accountId, const types::AccountIdType &());
+      MOCK_CONST_METHOD0(nameFilter, const std::string &());
+      MOCK_CONST_METHOD0(clone, GetAccountPermissions *());
+    };
+
+    struct MockVerifyBlob : public SpecificMockQuery<VerifyBlob> {
+      MOCK_CONST_METHOD0(accountId, const types::AccountIdType &());
+      MOCK_CONST_METHOD0(writer, const types::AccountIdType &());
+      MOCK_CONST_METHOD0(key, const types::AccountDetailKeyType &());
+      MOCK_CONST_METHOD0(blob, const std::string &());
+      MOCK_CONST_METHOD0(salt, const std::string &());
+      MOCK_CONST_METHOD0(clone, VerifyBlob *());
+    };
+
+    struct MockEstimateFee : public SpecificMockQuery<EstimateFee> {
+      MOCK_CONST_METHOD0(commands, CommandsType());
+      MOCK_CONST_METHOD0(clone, EstimateFee *());
+    };
+
+    struct MockGetVestingSchedules
+        : public SpecificMockQuery<GetVestingSchedules> {
+      MOCK_CONST_METHOD0(accountId, const types::AccountIdType &());
+      MOCK_CONST_METHOD0(clone, GetVestingSchedules *());
+    };
+
     struct MockTxPaginationMeta : public TxPaginationMeta {
       MOCK_CONST_METHOD0(pageSize, types::TransactionsNumberType());
       MOCK_CONST_METHOD0(firstTxHash, std::optional<types::HashType>());
@@ -70,17 +70,27 @@ class FieldValidatorTest : public ValidatorsTest {
 
  public:
   FieldValidatorTest() : field_validator(iroha::test::kTestsValidatorsConfig) {
-    field_validators.insert(makeValidator("public_key",
-                                          &FieldValidator::validatePubkey,
-                                          &FieldValidatorTest::public_key,
-                                          public_key_test_cases));
+    for (const auto &field :
+         {"public_key", "old_public_key", "new_public_key"}) {
+      field_validators.insert(makeValidator(field,
+                                            &FieldValidator::validatePubkey,
+                                            &FieldValidatorTest::public_key,
+                                            public_key_test_cases));
+    }
 
-    for (const auto &field : {"role_name", "default_role", "role_id"}) {
+    for (const auto &field :
+         {"role_name", "included_role_name", "default_role", "role_id"}) {
       field_validators.insert(makeValidator(field,
                                             &FieldValidator::validateRoleId,
                                             &FieldValidatorTest::role_name,
                                             role_name_test_cases));
     }
+
+    field_validators.insert(makeValidator("proposal_id",
+                                          &FieldValidator::validateProposalId,
+                                          &FieldValidatorTest::role_name,
+                                          proposal_id_test_cases));
+
     for (const auto &field : {"account_id",
                               "src_account_id",
                               "dest_account_id",
@@ -97,6 +107,12 @@ class FieldValidatorTest : public ValidatorsTest {
                       &FieldValidatorTest::detail_key,
                       detail_key_test_cases));
 
+    field_validators.insert(
+        makeValidator("path",
+                      &FieldValidator::validateAccountDetailPath,
+                      &FieldValidatorTest::detail_path,
+                      detail_path_test_cases));
+
     field_validators.insert(
         makeValidator("value",
                       &FieldValidator::validateAccountDetailValue,
@@ -150,7 +166,37 @@ class FieldValidatorTest : public ValidatorsTest {
                               "tx_hash",
                               // permissions are always valid
                               "permissions",
-                              "check_empty"}) {
+                              "check_empty",
+                              "grace_period",
+                              // any setting may be proposed, the value is
+                              // checked on execution
+                              "setting_key",
+                              "setting_value",
+                              // lock hashes are checked by the transaction
+                              // validator
+                              "hash",
+                              "preimage",
+                              "timeout",
+                              // the payload is private and is only hashed
+                              "blob",
+                              "salt",
+                              // checked against created_time by the
+                              // transaction validator
+                              "not_before",
+                              // any part of permission names may be searched
+                              "name_filter",
+                              // vesting schedules are checked by the
+                              // transaction validator
+                              "cliff",
+                              "duration",
+                              // any window is valid, zero removes the
+                              // spending limit
+                              "window",
+                              // empty allows any asset, checked by the
+                              // transaction validator
+                              "allowed_asset_id",
+                              // zero revokes the session key
+                              "validity"}) {
       field_validators.insert(makeNullValidator(field));
     }
   }
@@ -490,6 +536,9 @@ class FieldValidatorTest : public ValidatorsTest {
   std::vector<FieldTestCase> role_name_test_cases =
       nameTestCases("role_id", &FieldValidatorTest::role_name);
 
+  std::vector<FieldTestCase> proposal_id_test_cases =
+      nameTestCases("proposal_id", &FieldValidatorTest::role_name);
+
   std::vector<FieldTestCase> account_name_test_cases =
       nameTestCases("account_name", &FieldValidatorTest::account_name);
 
@@ -569,6 +618,18 @@ class FieldValidatorTest : public ValidatorsTest {
       makeInvalidCase(
           "illegal_char", "key", &FieldValidatorTest::detail_key, "hi-there")};
 
+  std::vector<FieldTestCase> detail_path_test_cases{
+      makeValidCase(&FieldValidatorTest::detail_path, "key"),
+      makeValidCase(&FieldValidatorTest::detail_path, "config.limits.max"),
+      makeInvalidCase(
+          "empty_string", "path", &FieldValidatorTest::detail_path, ""),
+      makeInvalidCase(
+          "empty_segment", "path", &FieldValidatorTest::detail_path, "a..b"),
+      makeInvalidCase(
+          "trailing_dot", "path", &FieldValidatorTest::detail_path, "a.b."),
+      makeInvalidCase(
+          "illegal_char", "path", &FieldValidatorTest::detail_path, "a.b-c")};
+
   std::vector<FieldTestCase> detail_value_test_cases{
       makeValidCase(&FieldValidatorTest::detail_value, "valid value"),
       makeValidCase(&FieldValidatorTest::detail_value, std::string(4096, '0')),
@@ -604,6 +665,17 @@ class FieldValidatorTest : public ValidatorsTest {
           &FieldValidatorTest::description,
           std::string(FieldValidator::kMaxDescriptionSize + 1, '0'))};
 
+  std::vector<FieldTestCase> idempotency_key_test_cases{
+      makeValidCase(&FieldValidatorTest::idempotency_key, "transfer-42"),
+      makeValidCase(&FieldValidatorTest::idempotency_key, ""),
+      makeValidCase(&FieldValidatorTest::idempotency_key,
+                    std::string(FieldValidator::kMaxIdempotencyKeySize, 'k')),
+      makeInvalidCase(
+          "long_idempotency_key",
+          "idempotency_key",
+          &FieldValidatorTest::idempotency_key,
+          std::string(FieldValidator::kMaxIdempotencyKeySize + 1, 'k'))};
+
   std::vector<FieldTestCase> quorum_test_cases{
       makeValidCase(&FieldValidatorTest::quorum, 1),
       makeValidCase(&FieldValidatorTest::quorum, 128),
@@ -791,6 +863,10 @@ class FieldValidatorTest : public ValidatorsTest {
                     &FieldValidator::validateDescription,
                     &FieldValidatorTest::description,
                     description_test_cases),
+      makeValidator("idempotency_key",
+                    &FieldValidator::validateIdempotencyKey,
+                    &FieldValidatorTest::idempotency_key,
+                    idempotency_key_test_cases),
       makeTransformValidator(
           "batch",
           &FieldValidator::validateBatchMeta,
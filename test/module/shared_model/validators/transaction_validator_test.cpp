@@ -174,3 +174,33 @@ TEST_F(TransactionValidatorTest, BatchValidTest) {
   ASSERT_EQ(tx.payload().batch().type(),
             static_cast<int>(interface::types::BatchType::ATOMIC));
 }
+
+/**
+ * @given transaction which becomes valid only after it expires
+ * @when transaction validation is invoked
+ * @then answer has an error about the not before time
+ */
+TEST_F(TransactionValidatorTest, NotBeforeAfterExpiry) {
+  auto make_tx = [this](interface::types::TimestampType not_before) {
+    return TestTransactionBuilder()
+        .creatorAccountId("admin@test")
+        .createdTime(created_time)
+        .notBefore(not_before)
+        .quorum(1)
+        .createDomain("test", "test")
+        .build()
+        .getTransport();
+  };
+  auto const max_delay =
+      shared_model::validation::FieldValidator::kDefaultMaxDelay;
+
+  EXPECT_EQ(transaction_validator.validate(
+                proto::Transaction(make_tx(created_time + max_delay))),
+            std::nullopt);
+
+  auto error = transaction_validator.validate(
+      proto::Transaction(make_tx(created_time + max_delay + 1)));
+  ASSERT_TRUE(error);
+  EXPECT_EQ(error->child_errors.size(), 1);
+  EXPECT_EQ(error->child_errors.front().name, "NotBefore");
+}
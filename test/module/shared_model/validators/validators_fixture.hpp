@@ -63,6 +63,9 @@ class ValidatorsTest : public ::testing::Test {
          setString(account_id)},
         {"iroha.protocol.GetAccountAssets.account_id", setString(account_id)},
         {"iroha.protocol.GetAccountDetail.account_id", setString(account_id)},
+        {"iroha.protocol.GetAccountPermissions.account_id",
+         setString(account_id)},
+        {"iroha.protocol.VerifyBlob.account_id", setString(account_id)},
         {"iroha.protocol.TransferAsset.src_account_id", setString(account_id)},
         {"iroha.protocol.AddSignatory.account_id", setString(account_id)},
         {"iroha.protocol.AppendRole.account_id", setString(account_id)},
@@ -72,6 +75,15 @@ class ValidatorsTest : public ::testing::Test {
         {"iroha.protocol.RevokePermission.account_id", setString(account_id)},
         {"iroha.protocol.SetAccountDetail.account_id", setString(account_id)},
         {"iroha.protocol.SetAccountQuorum.account_id", setString(account_id)},
+        {"iroha.protocol.DeactivateAccount.account_id", setString(account_id)},
+        {"iroha.protocol.RecoverAccount.account_id", setString(account_id)},
+        {"iroha.protocol.RotateKey.account_id", setString(account_id)},
+        {"iroha.protocol.LockAsset.src_account_id", setString(account_id)},
+        {"iroha.protocol.SetSpendingLimit.account_id", setString(account_id)},
+        {"iroha.protocol.AuthorizeSessionKey.account_id",
+         setString(account_id)},
+        {"iroha.protocol.SetAccountDetailAtPath.account_id",
+         setString(account_id)},
         {"iroha.protocol.CompareAndSetAccountDetail.account_id",
          setString(account_id)},
         {"iroha.protocol.CompareAndSetAccountDetail.check_empty",
@@ -79,26 +91,48 @@ class ValidatorsTest : public ::testing::Test {
         {"iroha.protocol.AppendRole.role_name", setString(role_name)},
         {"iroha.protocol.DetachRole.role_name", setString(role_name)},
         {"iroha.protocol.CreateRole.role_name", setString(role_name)},
+        {"iroha.protocol.IncludeRole.role_name", setString(role_name)},
+        {"iroha.protocol.IncludeRole.included_role_name", setString(role_name)},
+        {"iroha.protocol.ProposeSettingValue.proposal_id",
+         setString(role_name)},
+        {"iroha.protocol.VoteForProposal.proposal_id", setString(role_name)},
         {"iroha.protocol.CreateDomain.default_role", setString(role_name)},
         {"iroha.protocol.GetRolePermissions.role_id", setString(role_name)},
         {"iroha.protocol.AddSignatory.public_key", setString(public_key)},
         {"iroha.protocol.CreateAccount.public_key", setString(public_key)},
         {"iroha.protocol.RemovePeer.public_key", setString(public_key)},
         {"iroha.protocol.RemoveSignatory.public_key", setString(public_key)},
+        {"iroha.protocol.RecoverAccount.public_key", setString(public_key)},
+        {"iroha.protocol.RotateKey.old_public_key", setString(public_key)},
+        {"iroha.protocol.RotateKey.new_public_key", setString(public_key)},
+        {"iroha.protocol.AuthorizeSessionKey.public_key",
+         setString(public_key)},
         {"iroha.protocol.TransferAsset.dest_account_id", setString(dest_id)},
+        {"iroha.protocol.LockAsset.dest_account_id", setString(dest_id)},
+        {"iroha.protocol.GrantVestedAsset.dest_account_id",
+         setString(dest_id)},
         {"iroha.protocol.AddAssetQuantity.asset_id", setString(asset_id)},
         {"iroha.protocol.TransferAsset.asset_id", setString(asset_id)},
         {"iroha.protocol.SubtractAssetQuantity.asset_id", setString(asset_id)},
+        {"iroha.protocol.LockAsset.asset_id", setString(asset_id)},
+        {"iroha.protocol.GrantVestedAsset.asset_id", setString(asset_id)},
+        {"iroha.protocol.SetSpendingLimit.asset_id", setString(asset_id)},
+        {"iroha.protocol.AuthorizeSessionKey.allowed_asset_id",
+         setString(asset_id)},
         {"iroha.protocol.GetAccountAssetTransactions.asset_id",
          setString(asset_id)},
         {"iroha.protocol.GetAssetInfo.asset_id", setString(asset_id)},
+        {"iroha.protocol.GetAssetStats.asset_id", setString(asset_id)},
         {"iroha.protocol.CreateAccount.account_name", setString(account_name)},
         {"iroha.protocol.CreateAsset.domain_id", setString(domain_id)},
         {"iroha.protocol.CreateAccount.domain_id", setString(domain_id)},
         {"iroha.protocol.CreateDomain.domain_id", setString(domain_id)},
+        {"iroha.protocol.ArchiveDomain.domain_id", setString(domain_id)},
         {"iroha.protocol.CreateAsset.asset_name", setString(asset_name)},
         {"iroha.protocol.CreateAsset.precision", setUInt32(precision)},
         {"iroha.protocol.CreateRole.permissions", addEnum(role_permission)},
+        {"iroha.protocol.AuthorizeSessionKey.permissions",
+         addEnum(role_permission)},
         {"iroha.protocol.GrantPermission.permission",
          setEnum(grantable_permission)},
         {"iroha.protocol.RevokePermission.permission",
@@ -106,17 +140,40 @@ class ValidatorsTest : public ::testing::Test {
         {"iroha.protocol.SetAccountDetail.key", setString(detail_key)},
         {"iroha.protocol.CompareAndSetAccountDetail.key",
          setString(detail_key)},
+        {"iroha.protocol.SetAccountDetailAtPath.key", setString(detail_key)},
+        {"iroha.protocol.SetAccountDetailAtPath.path", setString(detail_path)},
         {"iroha.protocol.GetAccountDetail.key", setString(detail_key)},
         {"iroha.protocol.GetAccountDetail.writer", setString(writer)},
+        {"iroha.protocol.VerifyBlob.key", setString(detail_key)},
+        {"iroha.protocol.VerifyBlob.writer", setString(writer)},
+        {"iroha.protocol.VerifyBlob.blob", setString("")},
+        {"iroha.protocol.VerifyBlob.salt", setString("")},
         {"iroha.protocol.SetAccountDetail.value", setString("")},
         {"iroha.protocol.CompareAndSetAccountDetail.value", setString("")},
         {"iroha.protocol.CompareAndSetAccountDetail.old_value", setString("")},
+        {"iroha.protocol.SetAccountDetailAtPath.value", setString("")},
+        {"iroha.protocol.SetAccountDetailAtPath.old_value", setString("")},
+        {"iroha.protocol.ProposeSettingValue.setting_key", setString("")},
+        {"iroha.protocol.ProposeSettingValue.setting_value", setString("")},
         {"iroha.protocol.GetTransactions.tx_hashes", addString(hash)},
         {"iroha.protocol.SetAccountQuorum.quorum", setUInt32(quorum)},
+        {"iroha.protocol.RotateKey.grace_period", setUInt32(grace_period)},
+        {"iroha.protocol.AuthorizeSessionKey.validity",
+         setUInt32(session_key_validity)},
+        {"iroha.protocol.LockAsset.timeout", setUInt32(lock_timeout)},
+        {"iroha.protocol.LockAsset.hash", setString(hash)},
+        {"iroha.protocol.GrantVestedAsset.cliff", setUInt32(vesting_cliff)},
+        {"iroha.protocol.GrantVestedAsset.duration",
+         setUInt32(vesting_duration)},
+        {"iroha.protocol.ClaimWithPreimage.preimage", setString(hash)},
+        {"iroha.protocol.RefundAfterTimeout.hash", setString(hash)},
         {"iroha.protocol.TransferAsset.description", setString("")},
         {"iroha.protocol.AddAssetQuantity.amount", setString(amount)},
         {"iroha.protocol.TransferAsset.amount", setString(amount)},
         {"iroha.protocol.SubtractAssetQuantity.amount", setString(amount)},
+        {"iroha.protocol.LockAsset.amount", setString(amount)},
+        {"iroha.protocol.GrantVestedAsset.amount", setString(amount)},
+        {"iroha.protocol.SetSpendingLimit.amount", setString(amount)},
         {"iroha.protocol.CallEngine.type", setEnum(engine_type)},
         {"iroha.protocol.CallEngine.caller", setString(account_id)},
         {"iroha.protocol.CallEngine.callee",
@@ -154,7 +211,17 @@ class ValidatorsTest : public ::testing::Test {
                ->CopyFrom(account_detail_pagination_meta);
          }},
         {"iroha.protocol.GetBlock.height", setUInt64(height)},
-        {"iroha.protocol.GetEngineReceipts.tx_hash", setString(hash)}};
+        {"iroha.protocol.GetEngineReceipts.tx_hash", setString(hash)},
+        {"iroha.protocol.EstimateFee.commands",
+         [&](auto refl, auto msg, auto field) {
+           auto command = static_cast<iroha::protocol::Command *>(
+               refl->AddMessage(msg, field));
+           auto transfer = command->mutable_transfer_asset();
+           transfer->set_src_account_id(account_id);
+           transfer->set_dest_account_id(dest_id);
+           transfer->set_asset_id(asset_id);
+           transfer->set_amount(amount);
+         }}};
   }
 
   /**
@@ -269,6 +336,7 @@ class ValidatorsTest : public ::testing::Test {
     account_name = "admin";
     domain_id = "ru";
     detail_key = "key";
+    detail_path = "config.limits.max";
     writer = "account@domain";
     callee = std::string(40, 'a');
     engine_type = iroha::protocol::CallEngine::EngineType::
@@ -293,6 +361,11 @@ class ValidatorsTest : public ::testing::Test {
   size_t hash_size{0};
   uint64_t counter{0};
   uint64_t height{42};
+  uint32_t grace_period{10};
+  uint32_t session_key_validity{100};
+  uint32_t lock_timeout{10};
+  uint32_t vesting_cliff{10};
+  uint32_t vesting_duration{100};
   std::string account_id;
   std::string dest_id;
   std::string asset_name;
@@ -304,9 +377,12 @@ class ValidatorsTest : public ::testing::Test {
   std::string account_name;
   std::string domain_id;
   std::string detail_key;
+  std::string detail_path;
   std::string detail_value;
   std::optional<std::string> detail_old_value;
   std::string description;
+  std::string idempotency_key;
+  std::string chain_id;
   std::string public_key;
   std::string hash;
   std::string writer;